@@ -0,0 +1,168 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CLI options of the `cargo cucumber` subcommand.
+
+use std::path::PathBuf;
+
+/// Entry point of a `cargo` subcommand, invoked by `cargo` as
+/// `cargo-cucumber cucumber [OPTIONS]`.
+#[derive(Debug, clap::Parser)]
+#[command(name = "cargo", bin_name = "cargo")]
+pub(crate) enum Cargo {
+    /// Run `cucumber` test suites of the workspace.
+    Cucumber(Opts),
+}
+
+/// CLI options of the `cargo cucumber` subcommand.
+#[derive(Clone, Debug, Default, clap::Args)]
+#[command(version, about, long_about = None)]
+#[expect(clippy::struct_excessive_bools, reason = "CLI flags")]
+pub(crate) struct Opts {
+    /// Package to run the suites of (all the workspace members depending on
+    /// `cucumber`, if omitted).
+    #[arg(short, long = "package", value_name = "SPEC")]
+    pub(crate) packages: Vec<String>,
+
+    /// Test target to run (all the `harness = false` test targets of the
+    /// selected packages, if omitted).
+    #[arg(long = "test", value_name = "NAME")]
+    pub(crate) tests: Vec<String>,
+
+    /// Space or comma separated list of features to activate.
+    #[arg(short = 'F', long, value_name = "FEATURES")]
+    pub(crate) features: Vec<String>,
+
+    /// Activate all available features.
+    #[arg(long)]
+    pub(crate) all_features: bool,
+
+    /// Regex to filter scenarios by their name.
+    #[arg(short, long, value_name = "regex")]
+    pub(crate) name: Option<String>,
+
+    /// Tag expression to filter scenarios by.
+    #[arg(short, long, value_name = "tagexpr", conflicts_with = "name")]
+    pub(crate) tags: Option<String>,
+
+    /// Formatting of the output (requires `writer::Libtest` being used).
+    #[arg(long, value_name = "json")]
+    pub(crate) format: Option<String>,
+
+    /// Print the merged JSON index of step definitions of all the suites,
+    /// instead of executing them (requires `steps-index` feature of
+    /// `cucumber`).
+    #[arg(long, conflicts_with_all = ["lint", "validate"])]
+    pub(crate) list_steps: bool,
+
+    /// Lint `.feature` files of all the suites instead of executing them.
+    #[arg(long, conflicts_with = "validate")]
+    pub(crate) lint: bool,
+
+    /// Check that every step of `.feature` files of all the suites matches
+    /// exactly one step definition, instead of executing them.
+    #[arg(long)]
+    pub(crate) validate: bool,
+
+    /// Write the merged JSON report of failed scenarios of all the suites
+    /// into the file (requires `failures-file` feature of `cucumber`).
+    #[arg(long, value_name = "path")]
+    pub(crate) failures_file: Option<PathBuf>,
+
+    /// Additional CLI options passed to every suite as is.
+    #[arg(last = true, value_name = "ARGS")]
+    pub(crate) args: Vec<String>,
+}
+
+impl Opts {
+    /// Returns `cargo test` CLI arguments selecting the features to build the
+    /// suites with.
+    pub(crate) fn cargo_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if !self.features.is_empty() {
+            args.push(format!("--features={}", self.features.join(",")));
+        }
+        if self.all_features {
+            args.push("--all-features".into());
+        }
+        args
+    }
+
+    /// Returns CLI arguments passed to every suite.
+    pub(crate) fn suite_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(name) = &self.name {
+            args.push(format!("--name={name}"));
+        }
+        if let Some(tags) = &self.tags {
+            args.push(format!("--tags={tags}"));
+        }
+        if let Some(format) = &self.format {
+            args.push(format!("--format={format}"));
+        }
+        if self.list_steps {
+            args.push("--steps-index".into());
+        }
+        if self.lint {
+            args.push("--lint".into());
+        }
+        if self.validate {
+            args.push("--validate".into());
+        }
+        args.extend(self.args.iter().cloned());
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser as _;
+
+    use super::Cargo;
+
+    fn parse(args: &[&str]) -> super::Opts {
+        let Cargo::Cucumber(opts) =
+            Cargo::try_parse_from(["cargo", "cucumber"].iter().chain(args))
+                .expect("invalid CLI options");
+        opts
+    }
+
+    #[test]
+    fn forwards_options_to_suites() {
+        let opts = parse(&[
+            "-t",
+            "@smoke and not @slow",
+            "--format=json",
+            "-F",
+            "output-json",
+            "--",
+            "--concurrency=1",
+        ]);
+
+        assert_eq!(opts.cargo_args(), ["--features=output-json"]);
+        assert_eq!(
+            opts.suite_args(),
+            ["--tags=@smoke and not @slow", "--format=json", "--concurrency=1"],
+        );
+        assert_eq!(parse(&["--list-steps"]).suite_args(), ["--steps-index"],);
+    }
+
+    #[test]
+    fn rejects_conflicting_modes() {
+        let res = Cargo::try_parse_from([
+            "cargo",
+            "cucumber",
+            "--lint",
+            "--validate",
+        ]);
+
+        assert!(res.is_err(), "`--lint` and `--validate` are accepted");
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Discovery of test [`Target`]s running `cucumber` suites.
+
+use std::{ffi::OsStr, fs, process::Command};
+
+use serde_json::Value;
+use toml::de;
+
+use crate::{Error, cli::Opts};
+
+/// Test target of a workspace package, running a `cucumber` suite.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Target {
+    /// Name of the package of this [`Target`].
+    pub(crate) package: String,
+
+    /// Name of the test target.
+    pub(crate) test: String,
+}
+
+/// Discovers test [`Target`]s selected by the provided [`Opts`] via
+/// `cargo metadata`.
+///
+/// Without explicit `--test` options, picks all the `harness = false` test
+/// targets of the workspace packages depending on `cucumber`, omitting the
+/// ones whose `required-features` aren't activated via `--features` (unless
+/// `--all-features` is used).
+pub(crate) fn discover(
+    cargo: &OsStr,
+    opts: &Opts,
+) -> Result<Vec<Target>, Error> {
+    let output = Command::new(cargo)
+        .args(["metadata", "--format-version=1", "--no-deps"])
+        .output()
+        .map_err(Error::Cargo)?;
+    if !output.status.success() {
+        return Err(Error::Metadata(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+    let metadata =
+        serde_json::from_slice::<Value>(&output.stdout).map_err(|source| {
+            Error::Json { what: "cargo metadata".into(), source }
+        })?;
+
+    let enabled = |feature: &str| {
+        opts.all_features
+            || opts
+                .features
+                .iter()
+                .flat_map(|f| f.split([',', ' ']))
+                .any(|f| f == feature)
+    };
+
+    let mut targets = Vec::new();
+    for package in metadata["packages"].as_array().into_iter().flatten() {
+        let name = package["name"].as_str().unwrap_or_default();
+        if !opts.packages.is_empty() && !opts.packages.iter().any(|p| p == name)
+        {
+            continue;
+        }
+
+        let tests = if opts.tests.is_empty() {
+            if !depends_on_cucumber(package) {
+                continue;
+            }
+            let path = package["manifest_path"].as_str().unwrap_or_default();
+            let manifest = fs::read_to_string(path).map_err(Error::Cargo)?;
+            harnessless_tests(&manifest, enabled).map_err(|source| {
+                Error::Manifest { path: path.into(), source }
+            })?
+        } else {
+            package["targets"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter(|t| {
+                    t["kind"]
+                        .as_array()
+                        .is_some_and(|k| k.contains(&"test".into()))
+                })
+                .filter_map(|t| t["name"].as_str())
+                .filter(|t| opts.tests.iter().any(|n| n == t))
+                .map(Into::into)
+                .collect()
+        };
+        targets.extend(
+            tests.into_iter().map(|test| Target { package: name.into(), test }),
+        );
+    }
+
+    if targets.is_empty() {
+        return Err(Error::NoTargets);
+    }
+    Ok(targets)
+}
+
+/// Indicates whether the provided `cargo metadata` package depends on
+/// `cucumber` (as a regular or a dev-dependency).
+fn depends_on_cucumber(package: &Value) -> bool {
+    package["dependencies"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .any(|dep| dep["name"] == "cucumber")
+}
+
+/// Returns names of the `harness = false` test targets of the provided
+/// `Cargo.toml` manifest, having all their `required-features` `enabled`.
+fn harnessless_tests(
+    manifest: &str,
+    enabled: impl Fn(&str) -> bool,
+) -> Result<Vec<String>, de::Error> {
+    let manifest = manifest.parse::<toml::Table>()?;
+    Ok(manifest
+        .get("test")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|t| {
+            t.get("harness").and_then(toml::Value::as_bool) == Some(false)
+        })
+        .filter(|t| {
+            t.get("required-features")
+                .and_then(toml::Value::as_array)
+                .into_iter()
+                .flatten()
+                .all(|f| f.as_str().is_some_and(&enabled))
+        })
+        .filter_map(|t| t.get("name")?.as_str().map(Into::into))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::harnessless_tests;
+
+    const MANIFEST: &str = r#"
+        [package]
+        name = "suite"
+
+        [[test]]
+        name = "unit"
+
+        [[test]]
+        name = "features"
+        harness = false
+
+        [[test]]
+        name = "json"
+        harness = false
+        required-features = ["output-json"]
+    "#;
+
+    #[test]
+    fn finds_harnessless_tests() {
+        assert_eq!(
+            harnessless_tests(MANIFEST, |_| false).expect("invalid manifest"),
+            ["features"],
+        );
+        assert_eq!(
+            harnessless_tests(MANIFEST, |f| f == "output-json")
+                .expect("invalid manifest"),
+            ["features", "json"],
+        );
+        assert!(
+            harnessless_tests("[package]", |_| true)
+                .expect("invalid manifest")
+                .is_empty(),
+        );
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Error`] of running `cucumber` suites.
+
+use std::{io, path::PathBuf};
+
+use derive_more::with_trait::{Display, Error as StdError};
+use toml::de;
+
+/// Error of running `cucumber` suites.
+#[derive(Debug, Display, StdError)]
+pub(crate) enum Error {
+    /// Failed to run `cargo`.
+    #[display("Failed to run `cargo`: {_0}")]
+    Cargo(io::Error),
+
+    /// `cargo metadata` has failed.
+    #[display("`cargo metadata` has failed:\n{_0}")]
+    Metadata(#[error(not(source))] String),
+
+    /// Output of a command isn't a valid JSON.
+    #[display("Invalid JSON output of `{what}`: {source}")]
+    Json {
+        /// Description of the command.
+        what: String,
+
+        /// Error of parsing the JSON.
+        source: serde_json::Error,
+    },
+
+    /// `Cargo.toml` manifest of a package is invalid.
+    #[display("Invalid `{path}` manifest: {source}")]
+    Manifest {
+        /// Path to the `Cargo.toml` manifest.
+        path: String,
+
+        /// Error of parsing the manifest.
+        source: de::Error,
+    },
+
+    /// Failed to read or write a report file.
+    #[display("Failed to write `{}` report: {source}", path.display())]
+    Report {
+        /// Path to the report file.
+        path: PathBuf,
+
+        /// Error of writing the report.
+        source: io::Error,
+    },
+
+    /// No test targets running `cucumber` suites have been found.
+    #[display(
+        "No `cucumber` test targets found, declare them with \
+         `harness = false` or select via `--test`"
+    )]
+    NoTargets,
+}
@@ -0,0 +1,253 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `cargo cucumber` subcommand running [`cucumber`] test suites of a
+//! workspace.
+//!
+//! [`cucumber`]: https://docs.rs/cucumber
+
+#![deny(nonstandard_style, rustdoc::all, trivial_casts, trivial_numeric_casts)]
+#![forbid(non_ascii_idents, unsafe_code)]
+#![warn(
+    clippy::absolute_paths,
+    clippy::allow_attributes,
+    clippy::allow_attributes_without_reason,
+    clippy::as_conversions,
+    clippy::as_pointer_underscore,
+    clippy::as_ptr_cast_mut,
+    clippy::assertions_on_result_states,
+    clippy::branches_sharing_code,
+    clippy::cfg_not_test,
+    clippy::clear_with_drain,
+    clippy::clone_on_ref_ptr,
+    clippy::coerce_container_to_any,
+    clippy::collection_is_never_read,
+    clippy::create_dir,
+    clippy::dbg_macro,
+    clippy::debug_assert_with_mut_call,
+    clippy::decimal_literal_representation,
+    clippy::default_union_representation,
+    clippy::derive_partial_eq_without_eq,
+    clippy::doc_include_without_cfg,
+    clippy::empty_drop,
+    clippy::empty_structs_with_brackets,
+    clippy::equatable_if_let,
+    clippy::empty_enum_variants_with_brackets,
+    clippy::exit,
+    clippy::expect_used,
+    clippy::fallible_impl_from,
+    clippy::filetype_is_file,
+    clippy::float_cmp_const,
+    clippy::fn_to_numeric_cast_any,
+    clippy::get_unwrap,
+    clippy::if_then_some_else_none,
+    clippy::imprecise_flops,
+    clippy::infinite_loop,
+    clippy::iter_on_empty_collections,
+    clippy::iter_on_single_items,
+    clippy::iter_over_hash_type,
+    clippy::iter_with_drain,
+    clippy::large_include_file,
+    clippy::large_stack_frames,
+    clippy::let_underscore_untyped,
+    clippy::literal_string_with_formatting_args,
+    clippy::lossy_float_literal,
+    clippy::map_err_ignore,
+    clippy::map_with_unused_argument_over_ranges,
+    clippy::mem_forget,
+    clippy::missing_assert_message,
+    clippy::missing_asserts_for_indexing,
+    clippy::missing_const_for_fn,
+    clippy::missing_docs_in_private_items,
+    clippy::module_name_repetitions,
+    clippy::multiple_inherent_impl,
+    clippy::multiple_unsafe_ops_per_block,
+    clippy::mutex_atomic,
+    clippy::mutex_integer,
+    clippy::needless_collect,
+    clippy::needless_pass_by_ref_mut,
+    clippy::needless_raw_strings,
+    clippy::non_zero_suggestions,
+    clippy::nonstandard_macro_braces,
+    clippy::option_if_let_else,
+    clippy::or_fun_call,
+    clippy::panic_in_result_fn,
+    clippy::partial_pub_fields,
+    clippy::pathbuf_init_then_push,
+    clippy::pedantic,
+    clippy::precedence_bits,
+    clippy::print_stderr,
+    clippy::print_stdout,
+    clippy::pub_without_shorthand,
+    clippy::rc_buffer,
+    clippy::rc_mutex,
+    clippy::read_zero_byte_vec,
+    clippy::redundant_clone,
+    clippy::redundant_test_prefix,
+    clippy::redundant_type_annotations,
+    clippy::renamed_function_params,
+    clippy::ref_patterns,
+    clippy::rest_pat_in_fully_bound_structs,
+    clippy::return_and_then,
+    clippy::same_name_method,
+    clippy::semicolon_inside_block,
+    clippy::set_contains_or_insert,
+    clippy::shadow_unrelated,
+    clippy::significant_drop_in_scrutinee,
+    clippy::significant_drop_tightening,
+    clippy::single_option_map,
+    clippy::str_to_string,
+    clippy::string_add,
+    clippy::string_lit_as_bytes,
+    clippy::string_lit_chars_any,
+    clippy::string_slice,
+    clippy::suboptimal_flops,
+    clippy::suspicious_operation_groupings,
+    clippy::suspicious_xor_used_as_pow,
+    clippy::tests_outside_test_module,
+    clippy::todo,
+    clippy::too_long_first_doc_paragraph,
+    clippy::trailing_empty_array,
+    clippy::transmute_undefined_repr,
+    clippy::trivial_regex,
+    clippy::try_err,
+    clippy::undocumented_unsafe_blocks,
+    clippy::unimplemented,
+    clippy::uninhabited_references,
+    clippy::unnecessary_safety_comment,
+    clippy::unnecessary_safety_doc,
+    clippy::unnecessary_self_imports,
+    clippy::unnecessary_struct_initialization,
+    clippy::unused_peekable,
+    clippy::unused_result_ok,
+    clippy::unused_trait_names,
+    clippy::unwrap_in_result,
+    clippy::unwrap_used,
+    clippy::use_debug,
+    clippy::use_self,
+    clippy::useless_let_if_seq,
+    clippy::verbose_file_reads,
+    clippy::while_float,
+    clippy::wildcard_enum_match_arm,
+    ambiguous_negative_literals,
+    closure_returning_async_block,
+    future_incompatible,
+    impl_trait_redundant_captures,
+    let_underscore_drop,
+    macro_use_extern_crate,
+    meta_variable_misuse,
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    redundant_lifetimes,
+    rust_2018_idioms,
+    single_use_lifetimes,
+    unit_bindings,
+    unnameable_types,
+    unreachable_pub,
+    unstable_features,
+    unused,
+    variant_size_differences
+)]
+
+mod cli;
+mod discover;
+mod error;
+mod report;
+
+use std::{
+    env, fs,
+    process::{Command, ExitCode, Stdio},
+};
+
+use clap::Parser as _;
+
+pub(crate) use self::error::Error;
+use self::{cli::Opts, discover::Target};
+
+fn main() -> ExitCode {
+    let cli::Cargo::Cucumber(opts) = cli::Cargo::parse();
+    run(&opts).unwrap_or_else(|e| {
+        #[expect(clippy::print_stderr, reason = "CLI output")]
+        {
+            eprintln!("error: {e}");
+        }
+        ExitCode::FAILURE
+    })
+}
+
+/// Runs all the discovered suites one by one, aggregating their reports.
+///
+/// Returns the exit code of the first failed suite, if any.
+fn run(opts: &Opts) -> Result<ExitCode, Error> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let targets = discover::discover(&cargo, opts)?;
+
+    let mut failure = None;
+    let mut indices = Vec::new();
+    for target in &targets {
+        let Target { package, test } = target;
+        let mut cmd = Command::new(&cargo);
+        _ = cmd
+            .args(["test", "--package", package, "--test", test])
+            .args(opts.cargo_args())
+            .arg("--")
+            .args(opts.suite_args());
+        if let Some(path) = &opts.failures_file {
+            let part = report::part_of(path, target);
+            _ = cmd.arg(format!("--failures-file={}", part.display()));
+        }
+
+        let status = if opts.list_steps {
+            let output =
+                cmd.stderr(Stdio::inherit()).output().map_err(Error::Cargo)?;
+            if output.status.success() {
+                indices.push(serde_json::from_slice(&output.stdout).map_err(
+                    |source| Error::Json {
+                        what: format!("{package}/{test} --steps-index"),
+                        source,
+                    },
+                )?);
+            }
+            output.status
+        } else {
+            cmd.status().map_err(Error::Cargo)?
+        };
+        if !status.success() && failure.is_none() {
+            failure = Some(status.code().unwrap_or(1));
+        }
+    }
+
+    if opts.list_steps {
+        let index = report::merge("steps", indices);
+        #[expect(clippy::print_stdout, reason = "CLI output")]
+        {
+            println!("{index:#}");
+        }
+    }
+    if let Some(path) = &opts.failures_file {
+        let parts = targets
+            .iter()
+            .map(|t| report::part_of(path, t))
+            .filter_map(|part| {
+                let json = fs::read(&part).ok()?;
+                _ = fs::remove_file(&part).ok();
+                serde_json::from_slice(&json).ok()
+            })
+            .collect::<Vec<_>>();
+        let merged = report::merge("failures", parts);
+        fs::write(path, format!("{merged:#}"))
+            .map_err(|source| Error::Report { path: path.clone(), source })?;
+    }
+
+    Ok(failure.map_or(ExitCode::SUCCESS, |code| {
+        ExitCode::from(u8::try_from(code).unwrap_or(1))
+    }))
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Aggregation of JSON reports of multiple suites.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::discover::Target;
+
+/// Merges the arrays under the `key` of the provided JSON `reports` into a
+/// single report, omitting duplicated entries (like step definitions shared
+/// between suites).
+pub(crate) fn merge(
+    key: &str,
+    reports: impl IntoIterator<Item = Value>,
+) -> Value {
+    let mut merged = Vec::new();
+    for mut report in reports {
+        let Some(Value::Array(entries)) = report.get_mut(key).map(Value::take)
+        else {
+            continue;
+        };
+        for entry in entries {
+            if !merged.contains(&entry) {
+                merged.push(entry);
+            }
+        }
+    }
+    Value::Object(Map::from_iter([(key.into(), Value::Array(merged))]))
+}
+
+/// Returns the path of the report of the provided [`Target`], to be merged
+/// into the report at the provided `path`.
+pub(crate) fn part_of(path: &Path, target: &Target) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_owned();
+    name.push(format!(".{}.{}", target.package, target.test));
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{Target, merge, part_of};
+
+    #[test]
+    fn merges_reports() {
+        let step = json!({ "keyword": "Given", "pattern": "^a cat$" });
+        let merged = merge(
+            "steps",
+            [
+                json!({ "steps": [step] }),
+                json!({}),
+                json!({ "steps": [step, { "keyword": "Then" }] }),
+            ],
+        );
+
+        assert_eq!(merged, json!({ "steps": [step, { "keyword": "Then" }] }));
+    }
+
+    #[test]
+    fn names_parts() {
+        let target = Target { package: "app".into(), test: "bdd".into() };
+
+        assert_eq!(
+            part_of(Path::new("out/failures.json"), &target),
+            Path::new("out/failures.json.app.bdd"),
+        );
+    }
+}
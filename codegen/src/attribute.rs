@@ -12,20 +12,24 @@
 
 use std::{iter, mem};
 
-use cucumber_expressions::{Expression, Parameter, SingleExpression, Spanned};
+use cucumber_expressions::{Parameter, SingleExpression, Spanned};
 use inflections::case::to_pascal_case;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
-use regex::Regex;
 use syn::{
-    parse::{Parse, ParseStream},
+    parse::{Parse, ParseStream, Parser as _},
     parse_quote,
     spanned::Spanned as _,
 };
 
-use crate::attribute_ext::{
-    detect_table_param, generate_table_injection, is_data_table_type_from_arg, 
-    is_option_data_table, validate_table_position, DataTableParam,
+use crate::{
+    attribute_ext::{
+        detect_table_param, generate_table_injection,
+        is_data_table_type_from_arg, is_option_data_table,
+        validate_table_position, DataTableParam,
+    },
+    attribute_options::Options,
+    duplicate, fixture_arg, pattern,
 };
 
 /// Names of default [`Parameter`]s.
@@ -51,6 +55,9 @@ struct Step {
     /// Argument of the attribute.
     attr_arg: AttributeArgument,
 
+    /// Options of the attribute following its argument.
+    options: Options,
+
     /// Function the attribute is applied to.
     func: syn::ItemFn,
 
@@ -59,6 +66,10 @@ struct Step {
     ///
     /// [`gherkin::Step`]: https://bit.ly/3j42hcd
     arg_name_of_step_context: Option<syn::Ident>,
+
+    /// Names of the function arguments marked with a `#[fixture]` attribute,
+    /// injected from the fixtures set up for the executed scenario.
+    fixture_args: Vec<syn::Ident>,
 }
 
 impl Step {
@@ -68,7 +79,11 @@ impl Step {
         attr: TokenStream,
         body: TokenStream,
     ) -> syn::Result<Self> {
-        let attr_arg = syn::parse2::<AttributeArgument>(attr)?;
+        let (attr_arg, options) = (|input: ParseStream<'_>| {
+            let arg = input.parse::<AttributeArgument>()?;
+            Ok((arg, Options::parse_rest(input)?))
+        })
+        .parse2(attr)?;
         let mut func = syn::parse2::<syn::ItemFn>(body)?;
 
         let step_arg_name = {
@@ -94,21 +109,133 @@ impl Step {
             })
         });
 
+        let fixture_args = {
+            let (args, _) = remove_all_attrs_if_needed("fixture", &mut func);
+            args.into_iter()
+                .map(|arg| parse_fn_arg(arg).map(|(ident, _)| ident.clone()))
+                .collect::<syn::Result<Vec<_>>>()
+        }?;
+
         Ok(Self {
             attr_name,
             attr_arg,
+            options,
             func,
             arg_name_of_step_context: step_arg_name,
+            fixture_args,
         })
     }
 
     /// Expands generated code of this [`Step`] definition.
     fn expand(self) -> syn::Result<TokenStream> {
         let func = &self.func;
-        let func_name = &func.sig.ident;
 
         let world = parse_world_from_args(&self.func.sig)?;
+        if is_generic_world(&func.sig, world) {
+            return self.expand_generic();
+        }
         let step_type = self.step_type();
+        let step_fields = self.step_fields(false)?;
+        let guard = self.duplicate_guard(world);
+
+        Ok(quote! {
+            #func
+
+            #guard
+
+            #[automatically_derived]
+            ::cucumber::codegen::submit!({
+                // TODO: Remove this, once `#![feature(more_qualified_paths)]`
+                //       is stabilized:
+                //       https://github.com/rust-lang/rust/issues/86935
+                type StepAlias =
+                    <#world as ::cucumber::codegen::WorldInventory>::#step_type;
+
+                StepAlias { #step_fields }
+            });
+        })
+    }
+
+    /// Expands generated code of this [`Step`] definition generic over its
+    /// `World`, as a module named after the function, providing its
+    /// `register()` into a `Collection` instead of submitting it to the
+    /// `inventory`.
+    ///
+    /// Other `#[given]`, `#[when]` and `#[then]` attributes of the function
+    /// are expanded here too, so the module is generated only once.
+    fn expand_generic(self) -> syn::Result<TokenStream> {
+        let mut func = self.func.clone();
+        let (others, attrs) = mem::take(&mut func.attrs)
+            .into_iter()
+            .partition::<Vec<_>, _>(|attr| step_attr_name(attr).is_some());
+        func.attrs = attrs;
+        let body = quote! { #func };
+
+        // Keeps imports of the other attributes used.
+        let imports = others
+            .iter()
+            .filter_map(|attr| attr.path().get_ident())
+            .map(|ident| quote! { use super::#ident as _; });
+
+        let mut steps = vec![self];
+        for attr in &others {
+            let Some(name) = step_attr_name(attr) else { continue };
+            let args = attr.meta.require_list()?.tokens.clone();
+            steps.push(Self::parse(name, args, body.clone())?);
+        }
+        let registrations = steps
+            .iter()
+            .map(|step| {
+                let step_type = step.step_type();
+                let step_fields = step.step_fields(true)?;
+                Ok(quote! {
+                    let collection = ::cucumber::codegen::register(
+                        collection,
+                        ::cucumber::gherkin::StepType::#step_type,
+                        &::cucumber::codegen::GenericStep { #step_fields },
+                    );
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        // The last parsed step has all the `#[step]` argument attributes
+        // removed.
+        let func = steps.last().map_or(&func, |step| &step.func);
+        let world = parse_world_from_args(&func.sig)?;
+        let func_name = &func.sig.ident;
+        let vis = &func.vis;
+        let (impl_generics, _, where_clause) =
+            func.sig.generics.split_for_impl();
+        let mod_doc = format!(
+            "Registration of the `{func_name}` step generic over its `World`.",
+        );
+
+        Ok(quote! {
+            #func
+
+            #[doc = #mod_doc]
+            #vis mod #func_name {
+                #[allow(unused_imports)]
+                use super::*;
+                #( #imports )*
+
+                /// Registers this step into the provided `Collection`.
+                #[must_use]
+                pub fn register #impl_generics (
+                    collection: ::cucumber::step::Collection<#world>,
+                ) -> ::cucumber::step::Collection<#world> #where_clause {
+                    #( #registrations )*
+                    collection
+                }
+            }
+        })
+    }
+
+    /// Generates fields of a `StepConstructor` of this [`Step`] definition,
+    /// calling the function from its generated module if it's `generic`.
+    fn step_fields(&self, generic: bool) -> syn::Result<TokenStream> {
+        let func = &self.func;
+        let func_name = &func.sig.ident;
 
         // Check for DataTable parameter
         let table_param = detect_table_param(&self.func);
@@ -123,48 +250,111 @@ impl Step {
             )?;
 
         let regex = self.gen_regex()?;
+        let priority = self.options.priority();
+        let tags = self.options.tags();
+        let normalize = self.options.normalize();
+        let doc = self.doc();
 
-        let awaiting = func.sig.asyncness.map(|_| quote! { .await });
         let unwrapping = (!self.returns_unit())
-            .then(|| quote! { .unwrap_or_else(|e| panic!("{}", e)) });
-
-        Ok(quote! {
-            #func
-
-            #[automatically_derived]
-            ::cucumber::codegen::submit!({
-                // TODO: Remove this, once `#![feature(more_qualified_paths)]`
-                //       is stabilized:
-                //       https://github.com/rust-lang/rust/issues/86935
-                type StepAlias =
-                    <#world as ::cucumber::codegen::WorldInventory>::#step_type;
+            .then(|| {
+                quote! {
+                    .unwrap_or_else(|e| {
+                        use ::cucumber::codegen::{
+                            DisplayFailure as _, ErrorChainFailure as _,
+                        };
+                        ::cucumber::step::Failure::raise(
+                            ::cucumber::codegen::Failing(e).into_failure(),
+                        )
+                    })
+                }
+            });
+        let func_path = if generic {
+            quote! { super::#func_name }
+        } else {
+            quote! { #func_name }
+        };
 
-                StepAlias {
-                    loc: ::cucumber::step::Location {
-                        path: ::std::file!(),
-                        line: ::std::line!(),
-                        column: ::std::column!(),
-                    },
-                    regex: || {
-                        static LAZY: ::std::sync::LazyLock<
-                            ::cucumber::codegen::Regex
-                        > = ::std::sync::LazyLock::new(|| { #regex });
-                        LAZY.clone()
-                    },
-                    func: |__cucumber_world, __cucumber_ctx| {
+        // Synchronous functions are called inline, without allocating a
+        // `Future` on every run.
+        let func = if func.sig.asyncness.is_some() {
+            quote! {
+                ::cucumber::step::Function::Async(
+                    |__cucumber_world, __cucumber_ctx| {
                         let f = async move {
                             #addon_parsing
-                            let _ = #func_name(__cucumber_world, #func_args)
-                                #awaiting
+                            let _ = #func_path(__cucumber_world, #func_args)
+                                .await
                                 #unwrapping;
                         };
                         ::std::boxed::Box::pin(f)
                     },
-                }
-            });
+                )
+            }
+        } else {
+            quote! {
+                ::cucumber::step::Function::Sync(
+                    |__cucumber_world, __cucumber_ctx| {
+                        #addon_parsing
+                        let _ = #func_path(__cucumber_world, #func_args)
+                            #unwrapping;
+                    },
+                )
+            }
+        };
+
+        Ok(quote! {
+            loc: ::cucumber::step::Location {
+                path: ::std::file!(),
+                line: ::std::line!(),
+                column: ::std::column!(),
+            },
+            regex: || {
+                static LAZY: ::std::sync::LazyLock<
+                    ::cucumber::codegen::Regex
+                > = ::std::sync::LazyLock::new(|| { #regex });
+                LAZY.clone()
+            },
+            func: #func,
+            priority: #priority,
+            tags: #tags,
+            normalize: #normalize,
+            doc: #doc,
         })
     }
 
+    /// Generates code of the doc comment of this [`Step::func`].
+    fn doc(&self) -> TokenStream {
+        let doc = self
+            .func
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .filter_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    value:
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(s), ..
+                        }),
+                    ..
+                }) => Some(s.value()),
+                syn::Meta::Path(_)
+                | syn::Meta::List(_)
+                | syn::Meta::NameValue(_) => None,
+            })
+            .map(|line| {
+                line.strip_prefix(' ').map_or_else(|| line.clone(), Into::into)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let doc = doc.trim();
+
+        if doc.is_empty() {
+            quote! { ::std::option::Option::None }
+        } else {
+            quote! { ::std::option::Option::Some(#doc) }
+        }
+    }
+
     /// Indicates whether this [`Step::func`] return type is `()`.
     fn returns_unit(&self) -> bool {
         match &self.func.sig.output {
@@ -203,6 +393,7 @@ impl Step {
 
         if is_regex_or_expr {
             if let Some(elem_ty) = find_first_slice(&func.sig) {
+                let elem_ty_name = type_name(elem_ty);
                 let addon_parsing = Some(quote! {
                     let mut __cucumber_matches = ::std::vec::Vec::with_capacity(
                         __cucumber_ctx.matches.len().saturating_sub(1),
@@ -254,10 +445,22 @@ impl Step {
                             .unwrap_or_default();
 
                         __cucumber_matches.push(
-                            s.parse::<#elem_ty>().unwrap_or_else(|e| panic!(
-                                "Failed to parse element at {} '{}': {}",
-                                i, s, e,
-                            ))
+                            s.parse::<#elem_ty>().unwrap_or_else(|e| {
+                                #[allow(unused_imports)]
+                                use ::cucumber::codegen::{
+                                    DebugArgError as _, DisplayArgError as _,
+                                };
+
+                                ::std::panic::panic_any(
+                                    (&::cucumber::codegen::ArgParsing(
+                                        &::std::format!("[{i}]"),
+                                        #elem_ty_name,
+                                        s,
+                                        &e,
+                                    ))
+                                    .to_arg_error(),
+                                )
+                            })
                         );
                     }
                 });
@@ -293,9 +496,11 @@ impl Step {
 
                 Ok((func_args, addon_parsing))
             }
+        } else if !self.fixture_args.is_empty() {
+            self.literal_fn_arguments()
         } else if self.arg_name_of_step_context.is_some() {
             Ok((
-                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx.step), },
+                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx), },
                 None,
             ))
         } else {
@@ -318,16 +523,84 @@ impl Step {
         }
     }
 
+    /// Generates code that prepares function's arguments of an
+    /// [`AttributeArgument::Literal`] having `#[fixture]` arguments.
+    fn literal_fn_arguments(
+        &self,
+    ) -> syn::Result<(TokenStream, Option<TokenStream>)> {
+        let mut addon_parsing = None;
+        let func_args = self
+            .func
+            .sig
+            .inputs
+            .iter()
+            .skip(1)
+            .map(|arg| {
+                let (ident, ty) = parse_fn_arg(arg)?;
+                if self.is_fixture_arg(ident) {
+                    fixture_arg::argument(ident, ty)
+                } else if self.arg_name_of_step_context.as_ref() == Some(ident)
+                {
+                    Ok(quote! {
+                        ::std::borrow::Borrow::borrow(&__cucumber_ctx),
+                    })
+                } else if let Some(param) = detect_table_param(&self.func)
+                    .filter(|param| &param.ident == ident)
+                {
+                    validate_table_position(&param)?;
+                    addon_parsing = Some(generate_table_injection(
+                        &param,
+                        &self.func.sig.ident,
+                    ));
+                    Ok(quote! { #ident, })
+                } else {
+                    Err(syn::Error::new(
+                        arg.span(),
+                        "literal step can only have `#[step]`, `#[fixture]` \
+                         and `DataTable` arguments",
+                    ))
+                }
+            })
+            .collect::<syn::Result<TokenStream>>()?;
+
+        Ok((func_args, addon_parsing))
+    }
+
+    /// Indicates whether the function argument `ident` is a `#[fixture]` one.
+    fn is_fixture_arg(&self, ident: &syn::Ident) -> bool {
+        self.fixture_args.contains(ident)
+    }
+
     /// Composes a name of the `cucumber::codegen::WorldInventory` associated
     /// type to wire this [`Step`] with.
     fn step_type(&self) -> syn::Ident {
         format_ident!("{}", to_pascal_case(self.attr_name))
     }
 
+    /// Generates a [`duplicate::guard()`] of this [`Step`] definition.
+    fn duplicate_guard(&self, world: &syn::TypePath) -> TokenStream {
+        let (pattern, span) = match &self.attr_arg {
+            AttributeArgument::Literal(l) => {
+                (format!("^{}$", regex::escape(&l.value())), l.span())
+            }
+            AttributeArgument::Regex(re) => (re.value(), re.span()),
+            AttributeArgument::Expression(expr) => {
+                (format!("expr:{}", expr.value()), expr.span())
+            }
+        };
+        let options = [
+            self.options.priority(),
+            self.options.tags(),
+            self.options.normalize(),
+        ];
+        duplicate::guard(&self.step_type(), world, &pattern, &options, span)
+    }
+
     /// Returns [`syn::Ident`] and parsing code of the given function's
     /// argument.
     ///
-    /// Function's argument type have to implement [`FromStr`].
+    /// Function's argument type have to implement [`FromStr`], unless it's a
+    /// `#[fixture]` one.
     ///
     /// [`FromStr`]: std::str::FromStr
     /// [`syn::Ident`]: struct@syn::Ident
@@ -344,10 +617,12 @@ impl Step {
         let is_data_table =
             is_data_table_type_from_arg(arg);
 
-        let decl = if is_ctx_arg {
+        let decl = if self.is_fixture_arg(ident) {
+            fixture_arg::declare(ident, ty)?
+        } else if is_ctx_arg {
             quote! {
                 let #ident =
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step);
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx);
             }
         } else if is_data_table {
             // Handle DataTable specially - extract from step
@@ -380,16 +655,8 @@ impl Step {
             };
 
             let not_found_err = format!("{ident} not found");
-            let parsing_err = format!(
-                "{ident} can not be parsed to {}",
-                ty.path
-                    .segments
-                    .last()
-                    .ok_or_else(|| {
-                        syn::Error::new(ty.path.span(), "type path expected")
-                    })?
-                    .ident,
-            );
+            let arg_name = ident.to_string();
+            let ty_name = type_name(ty);
 
             quote! {
                 let #ident = {
@@ -433,15 +700,31 @@ impl Step {
                         })
                         .unwrap_or_default()
                 };
-                let #ident = #ident.parse::<#ty>().expect(#parsing_err);
+                let #ident = match #ident.parse::<#ty>() {
+                    ::std::result::Result::Ok(v) => v,
+                    ::std::result::Result::Err(e) => {
+                        #[allow(unused_imports)]
+                        use ::cucumber::codegen::{
+                            DebugArgError as _, DisplayArgError as _,
+                        };
+
+                        ::std::panic::panic_any(
+                            (&::cucumber::codegen::ArgParsing(
+                                #arg_name, #ty_name, #ident, &e,
+                            ))
+                            .to_arg_error(),
+                        )
+                    }
+                };
             }
         };
 
         Ok((ident, decl))
     }
 
-    /// Generates code that borrows [`gherkin::Step`] from context if the given
-    /// `arg` matches `step_arg_name`, or else borrows parsed slice.
+    /// Generates code that borrows [`gherkin::Step`] (or the whole step context)
+    /// if the given `arg` matches `step_arg_name`, looks up a `#[fixture]`
+    /// argument, or else borrows parsed slice.
     ///
     /// [`gherkin::Step`]: https://bit.ly/3j42hcd
     fn borrow_step_or_slice(
@@ -453,14 +736,18 @@ impl Step {
             let (ident, _) = parse_fn_arg(arg)?;
             if name == ident {
                 return Ok(quote! {
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step),
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx),
                 });
             }
         }
 
+        let (ident, ty) = parse_fn_arg(arg)?;
+        if self.is_fixture_arg(ident) {
+            return fixture_arg::argument(ident, ty);
+        }
+
         // Check if this is a DataTable argument
         if is_data_table_type_from_arg(arg) {
-            let (ident, _) = parse_fn_arg(arg)?;
             return Ok(quote! { #ident, });
         }
 
@@ -469,12 +756,12 @@ impl Step {
         })
     }
 
-    /// Generates code constructing a [`Regex`] based on an
+    /// Generates code constructing a [`regex::Regex`] based on an
     /// [`AttributeArgument`].
     ///
     /// # Errors
     ///
-    /// - If [`AttributeArgument::Regex`] isn't a valid [`Regex`].
+    /// - If [`AttributeArgument::Regex`] isn't a valid [`regex::Regex`].
     /// - If [`AttributeArgument::Expression`] passed to
     ///   [`gen_expression_regex()`] errors.
     ///
@@ -490,9 +777,7 @@ impl Step {
                 Ok(quote! { ::cucumber::codegen::Regex::new(#lit).unwrap() })
             }
             AttributeArgument::Regex(re) => {
-                drop(Regex::new(re.value().as_str()).map_err(|e| {
-                    syn::Error::new(re.span(), format!("invalid regex: {e}"))
-                })?);
+                pattern::validate_regex(re)?;
 
                 Ok(quote! { ::cucumber::codegen::Regex::new(#re).unwrap() })
             }
@@ -502,7 +787,7 @@ impl Step {
         }
     }
 
-    /// Generates code constructing [`Regex`] for an
+    /// Generates code constructing [`regex::Regex`] for an
     /// [`AttributeArgument::Expression`].
     ///
     /// # Errors
@@ -512,11 +797,14 @@ impl Step {
         &self,
         expr: &syn::LitStr,
     ) -> syn::Result<TokenStream> {
+        let span = expr.span();
         let expr = expr.value();
         let params = Parameters::new(
             &expr,
+            span,
             &self.func,
             self.arg_name_of_step_context.as_ref(),
+            &self.fixture_args,
         )?;
 
         let provider_impl =
@@ -533,7 +821,7 @@ impl Step {
             #provider_impl
 
             // This should never fail because:
-            // 1. We checked AST correctness with `Expression::parse()`;
+            // 1. We checked AST correctness with `parse_expression()`;
             // 2. Custom `Parameter::REGEX`es are correct due to be validated
             //    in `#[derive(Parameter)]` macro expansion;
             // 3. All the parameter names are equal to the corresponding
@@ -566,21 +854,18 @@ impl<'p> Parameters<'p> {
     ///
     /// # Errors
     ///
-    /// - If [`Expression::parse()`] errors.
+    /// - If [`pattern::parse_expression()`] errors.
     /// - If [`parse_fn_arg()`] on one of the `func`'s arguments errors.
     /// - If non-default [`Parameter`] doesn't have the corresponding `func`'s
     ///   argument.
     fn new(
         expr: &'p str,
+        span: Span,
         func: &syn::ItemFn,
         step: Option<&syn::Ident>,
+        fixtures: &[syn::Ident],
     ) -> syn::Result<Self> {
-        let expr = Expression::parse(expr).map_err(|e| {
-            syn::Error::new(
-                expr.span(),
-                format!("invalid Cucumber Expression: {e}"),
-            )
-        })?;
+        let expr = pattern::parse_expression(expr, span)?;
 
         let param_tys = func
             .sig
@@ -593,7 +878,8 @@ impl<'p> Parameters<'p> {
                     Err(err) => return Some(Err(err)),
                 };
                 let is_step = step.is_some_and(|s| s == ident);
-                (!is_step).then_some(Ok(ty))
+                let is_fixture = fixtures.contains(ident);
+                (!is_step && !is_fixture).then_some(Ok(ty))
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
@@ -818,11 +1104,8 @@ fn remove_all_attrs_if_needed<'a>(
     attr_arg: &str,
     func: &'a mut syn::ItemFn,
 ) -> (Vec<&'a syn::FnArg>, Vec<syn::Attribute>) {
-    let has_other_step_arguments = func.attrs.iter().any(|attr| {
-        attr.meta.path().segments.last().is_some_and(|segment| {
-            ["given", "when", "then"].iter().any(|step| segment.ident == step)
-        })
-    });
+    let has_other_step_arguments =
+        func.attrs.iter().any(|attr| step_attr_name(attr).is_some());
 
     func.sig
         .inputs
@@ -925,6 +1208,11 @@ fn find_first_slice(sig: &syn::Signature) -> Option<&syn::TypePath> {
     })
 }
 
+/// Renders the given type as it's written in code, for error messages.
+fn type_name(ty: &syn::TypePath) -> String {
+    quote! { #ty }.to_string().replace(' ', "")
+}
+
 /// Parses `cucumber::World` from arguments of the function signature.
 fn parse_world_from_args(sig: &syn::Signature) -> syn::Result<&syn::TypePath> {
     sig.inputs
@@ -960,6 +1248,23 @@ fn parse_world_from_args(sig: &syn::Signature) -> syn::Result<&syn::TypePath> {
         })
 }
 
+/// Returns the name of the provided `#[given]`, `#[when]` or `#[then]`
+/// attribute, if it's one of them.
+pub(crate) fn step_attr_name(attr: &syn::Attribute) -> Option<&'static str> {
+    let ident = &attr.meta.path().segments.last()?.ident;
+    ["given", "when", "then"].into_iter().find(|step| ident == step)
+}
+
+/// Indicates whether the provided `world` type is a generic parameter of the
+/// provided [`syn::Signature`], so the step function is reusable across all
+/// the `World`s satisfying its bounds.
+fn is_generic_world(sig: &syn::Signature, world: &syn::TypePath) -> bool {
+    world.qself.is_none()
+        && world.path.get_ident().is_some_and(|ident| {
+            sig.generics.type_params().any(|p| p.ident == *ident)
+        })
+}
+
 /// Converts [`syn::Lit`] to [`syn::LitStr`], if possible.
 ///
 /// [`syn::Lit`]: enum@syn::Lit
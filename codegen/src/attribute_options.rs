@@ -0,0 +1,141 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Options of `#[given]`, `#[when]` and `#[then]` attribute macros, following
+//! their step argument (like `#[given("a user", priority = 10)]`).
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse::ParseStream, spanned::Spanned as _};
+
+/// Options following a step argument of an attribute.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Options {
+    /// `priority = <i32 expression>` option resolving an ambiguity in favor of
+    /// the step.
+    pub(crate) priority: Option<syn::Expr>,
+
+    /// `tags = "<tag expression>"` option scoping the step to the matching
+    /// scenarios only.
+    pub(crate) tags: Option<syn::Expr>,
+
+    /// `normalize = <bool expression>` option matching the step text ignoring
+    /// its case and extra whitespaces.
+    pub(crate) normalize: Option<syn::Expr>,
+}
+
+impl Options {
+    /// Parses comma-separated [`Options`] remaining in the `input`.
+    ///
+    /// # Errors
+    ///
+    /// If an option is unknown or duplicated.
+    pub(crate) fn parse_rest(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut options = Self::default();
+        while !input.is_empty() {
+            _ = input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let option = input.parse::<syn::MetaNameValue>()?;
+            let slot = match option.path.get_ident() {
+                Some(i) if i == "priority" => &mut options.priority,
+                Some(i) if i == "tags" => &mut options.tags,
+                Some(i) if i == "normalize" => &mut options.normalize,
+                _ => {
+                    return Err(syn::Error::new(
+                        option.path.span(),
+                        "unknown option, expected `priority`, `tags` or \
+                         `normalize`",
+                    ));
+                }
+            };
+            if option.path.is_ident("tags")
+                && !matches!(
+                    &option.value,
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(_), .. }),
+                )
+            {
+                return Err(syn::Error::new(
+                    option.value.span(),
+                    "expected a string literal of a tag expression",
+                ));
+            }
+            if slot.replace(option.value).is_some() {
+                return Err(syn::Error::new(
+                    option.path.span(),
+                    "duplicated option",
+                ));
+            }
+        }
+        Ok(options)
+    }
+
+    /// Generates code of the step priority.
+    pub(crate) fn priority(&self) -> TokenStream {
+        self.priority.as_ref().map_or_else(|| quote! { 0 }, |p| quote! { #p })
+    }
+
+    /// Generates code of the step normalization indicator.
+    pub(crate) fn normalize(&self) -> TokenStream {
+        self.normalize
+            .as_ref()
+            .map_or_else(|| quote! { false }, |n| quote! { #n })
+    }
+
+    /// Generates code of the step tag expression.
+    pub(crate) fn tags(&self) -> TokenStream {
+        self.tags.as_ref().map_or_else(
+            || quote! { ::std::option::Option::None },
+            |t| quote! { ::std::option::Option::Some(#t) },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse::Parser as _;
+
+    use super::*;
+
+    #[test]
+    fn parses_options() {
+        let options =
+            Options::parse_rest.parse2(quote! { , priority = -1, }).unwrap();
+        assert_eq!(options.priority().to_string(), "- 1");
+
+        let options = Options::parse_rest.parse2(quote! {}).unwrap();
+        assert_eq!(options.priority().to_string(), "0");
+        assert_eq!(options.normalize().to_string(), "false");
+        assert_eq!(
+            options.tags().to_string(),
+            ":: std :: option :: Option :: None",
+        );
+
+        let options = Options::parse_rest
+            .parse2(quote! { , tags = "@mock", priority = 1, normalize = true })
+            .unwrap();
+        assert_eq!(options.normalize().to_string(), "true");
+        assert_eq!(
+            options.tags().to_string(),
+            ":: std :: option :: Option :: Some (\"@mock\")",
+        );
+
+        for input in [
+            quote! { , order = 1 },
+            quote! { , priority = 1, priority = 2 },
+            quote! { priority = 1 },
+            quote! { , tags = mock },
+        ] {
+            assert!(Options::parse_rest.parse2(input).is_err());
+        }
+    }
+}
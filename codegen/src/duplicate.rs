@@ -0,0 +1,105 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compile-time detection of duplicated step patterns.
+//!
+//! Proc macros cannot share a registry between their invocations on stable
+//! Rust, so the name resolution of the compiler is used instead: every step
+//! function gets a guard item named after a hash of its pattern, making two
+//! identical patterns in the same module fail compilation with a "defined
+//! multiple times" error pointing to both of them.
+
+use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+
+/// Generates a guard item failing compilation if another step of the same
+/// `step_type` is defined with the same `pattern` and `options` for the same
+/// `world` in the same module.
+///
+/// The `span` is the one of the `pattern` literal, so both duplicates are
+/// pointed to by the error.
+pub(crate) fn guard(
+    step_type: &syn::Ident,
+    world: &syn::TypePath,
+    pattern: &str,
+    options: &[TokenStream],
+    span: Span,
+) -> TokenStream {
+    let mut hasher = DefaultHasher::new();
+    quote! { #world }.to_string().hash(&mut hasher);
+    pattern.hash(&mut hasher);
+    for opt in options {
+        opt.to_string().hash(&mut hasher);
+    }
+    let name = format_ident!(
+        "DUPLICATE_{}_STEP_{:016X}",
+        step_type.to_string().to_uppercase(),
+        hasher.finish(),
+        span = span,
+    );
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(dead_code, non_upper_case_globals)]
+        const #name: () = ();
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use proc_macro2::Span;
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::guard;
+
+    fn name(
+        ty: &str,
+        world: &syn::TypePath,
+        pattern: &str,
+        tags: &str,
+    ) -> String {
+        let step_type = syn::Ident::new(ty, Span::call_site());
+        let options = [quote! { 0 }, quote! { #tags }];
+        let item: syn::ItemConst = syn::parse2(guard(
+            &step_type,
+            world,
+            pattern,
+            &options,
+            Span::call_site(),
+        ))
+        .unwrap();
+        item.ident.to_string()
+    }
+
+    #[test]
+    fn names_duplicates_equally() {
+        let world = parse_quote! { World };
+
+        assert_eq!(
+            name("Given", &world, "^foo$", ""),
+            name("Given", &world, "^foo$", ""),
+        );
+        assert!(name("Given", &world, "^foo$", "").starts_with("DUPLICATE_"));
+    }
+
+    #[test]
+    fn names_distinct_steps_differently() {
+        let (world, other) = (parse_quote! { World }, parse_quote! { Other });
+        let given = name("Given", &world, "^foo$", "");
+
+        assert_ne!(given, name("When", &world, "^foo$", ""));
+        assert_ne!(given, name("Given", &other, "^foo$", ""));
+        assert_ne!(given, name("Given", &world, "^bar$", ""));
+        assert_ne!(given, name("Given", &world, "^foo$", "@slow"));
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `#[fixture]` arguments of step functions, injected from the fixtures set up
+//! for the executed scenario.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned as _;
+
+/// Generates code declaring the `#[fixture]` argument `ident` of type `ty`,
+/// shadowing it with a reference for `&T` arguments.
+///
+/// # Errors
+///
+/// If the `ty` is neither `&T` nor `Arc<T>`.
+pub(crate) fn declare(
+    ident: &syn::Ident,
+    ty: &syn::Type,
+) -> syn::Result<TokenStream> {
+    let (value, is_ref) = lookup(ident, ty)?;
+    let borrow = is_ref.then(|| quote! { let #ident = &*#ident; });
+    Ok(quote! {
+        let #ident = #value;
+        #borrow
+    })
+}
+
+/// Generates an expression of the `#[fixture]` argument `ident` of type `ty`,
+/// to be passed to a step function directly.
+///
+/// # Errors
+///
+/// If the `ty` is neither `&T` nor `Arc<T>`.
+pub(crate) fn argument(
+    ident: &syn::Ident,
+    ty: &syn::Type,
+) -> syn::Result<TokenStream> {
+    let (value, is_ref) = lookup(ident, ty)?;
+    Ok(if is_ref {
+        quote! { &*#value, }
+    } else {
+        quote! { #value, }
+    })
+}
+
+/// Generates an expression looking up the `Arc<T>` value of the `#[fixture]`
+/// argument `ident` of type `ty`, and indicates whether the argument is `&T`.
+///
+/// # Errors
+///
+/// If the `ty` is neither `&T` nor `Arc<T>`.
+fn lookup(
+    ident: &syn::Ident,
+    ty: &syn::Type,
+) -> syn::Result<(TokenStream, bool)> {
+    let invalid = || {
+        syn::Error::new(
+            ty.span(),
+            "`#[fixture]` argument is expected to be `&T` or `Arc<T>`",
+        )
+    };
+    let (inner, is_ref) = if let syn::Type::Reference(r) = ty {
+        if r.mutability.is_some() {
+            return Err(invalid());
+        }
+        (&*r.elem, true)
+    } else {
+        (arc_inner(ty).ok_or_else(invalid)?, false)
+    };
+    let err = format!(
+        "no `{}` fixture is set up for `{ident}` argument",
+        quote! { #inner }.to_string().replace(' ', ""),
+    );
+
+    let value = quote! {
+        __cucumber_ctx
+            .fixture::<#inner>()
+            .unwrap_or_else(|| ::std::panic!(#err))
+    };
+    Ok((value, is_ref))
+}
+
+/// Returns `T` of the provided `Arc<T>` type, if it's the one.
+fn arc_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let last = path.segments.last().filter(|s| s.ident == "Arc")?;
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(inner)) if args.args.len() == 1 => {
+            Some(inner)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use quote::{format_ident, quote};
+    use syn::parse_quote;
+
+    use super::{argument, declare};
+
+    #[test]
+    fn borrows_reference_arguments() {
+        let (ident, ty) = (format_ident!("db"), parse_quote! { &Database });
+
+        assert_eq!(
+            argument(&ident, &ty).unwrap().to_string(),
+            quote! {
+                &*__cucumber_ctx
+                    .fixture::<Database>()
+                    .unwrap_or_else(|| ::std::panic!(
+                        "no `Database` fixture is set up for `db` argument"
+                    )),
+            }
+            .to_string(),
+        );
+        assert!(
+            declare(&ident, &ty)
+                .unwrap()
+                .to_string()
+                .ends_with(&quote! { let db = &*db; }.to_string()),
+            "reference is not shadowed",
+        );
+    }
+
+    #[test]
+    fn passes_arc_arguments() {
+        let ident = format_ident!("client");
+        let ty = parse_quote! { std::sync::Arc<http::Client> };
+
+        let arg = argument(&ident, &ty).unwrap().to_string();
+
+        assert!(arg.starts_with("__cucumber_ctx"), "`Arc` is borrowed: {arg}");
+        assert!(
+            arg.contains(&quote! { .fixture::<http::Client>() }.to_string()),
+            "wrong fixture type: {arg}",
+        );
+    }
+
+    #[test]
+    fn rejects_other_types() {
+        let ident = format_ident!("db");
+
+        for ty in [
+            parse_quote! { Database },
+            parse_quote! { &mut Database },
+            parse_quote! { Arc<A, B> },
+        ] {
+            assert!(argument(&ident, &ty).is_err(), "accepted `{ty:?}`");
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `#[before]` and `#[after]` attribute macros implementation.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{ParseStream, Parser as _},
+    spanned::Spanned as _,
+};
+
+/// Generates code of `#[before]` and `#[after]` attribute macros expansion.
+pub(crate) fn hook(
+    attr_name: &'static str,
+    args: TokenStream,
+    input: TokenStream,
+) -> syn::Result<TokenStream> {
+    let tags = parse_tags.parse2(args)?;
+    let func = syn::parse2::<syn::ItemFn>(input)?;
+    let is_after = attr_name == "after";
+
+    let inputs = func.sig.inputs.iter().collect::<Vec<_>>();
+    let max_args = if is_after { 3 } else { 2 };
+    if inputs.is_empty() || inputs.len() > max_args {
+        return Err(syn::Error::new(
+            func.sig.inputs.span(),
+            format!(
+                "`#[{attr_name}]` hook expects a `World` and up to {} more \
+                 arguments",
+                max_args - 1,
+            ),
+        ));
+    }
+    let (world, optional) = parse_world(inputs[0], is_after)?;
+
+    let func_name = &func.sig.ident;
+    let awaiting = func.sig.asyncness.map(|_| quote! { .await });
+    let unwrapping = (!returns_unit(&func.sig)).then(|| {
+        quote! {
+            .unwrap_or_else(|e| {
+                use ::cucumber::codegen::{
+                    DisplayFailure as _, ErrorChainFailure as _,
+                };
+                ::cucumber::step::Failure::raise(
+                    ::cucumber::codegen::Failing(e).into_failure(),
+                )
+            })
+        }
+    });
+    let tags = tags.map_or_else(
+        || quote! { ::std::option::Option::None },
+        |t| quote! { ::std::option::Option::Some(#t) },
+    );
+    let downcast = quote! {
+        <dyn ::std::any::Any>::downcast_mut::<#world>(__cucumber_world)
+            .expect("`World` type mismatch")
+    };
+    let call_args =
+        [quote! { __cucumber_scenario }, quote! { __cucumber_finished }];
+    let call_args = &call_args[..inputs.len() - 1];
+
+    let (constructor, closure) = if is_after {
+        let call = quote! {
+            #func_name(__cucumber_world, #( #call_args ),*)
+                #awaiting
+                #unwrapping;
+        };
+        let body = if optional {
+            quote! {
+                let __cucumber_world =
+                    __cucumber_world.map(|__cucumber_world| #downcast);
+                #call
+            }
+        } else {
+            quote! {
+                if let ::std::option::Option::Some(__cucumber_world) =
+                    __cucumber_world
+                {
+                    let __cucumber_world = #downcast;
+                    #call
+                }
+            }
+        };
+        let closure = quote! {
+            |_, _, __cucumber_scenario, __cucumber_finished, __cucumber_world| {
+                ::std::boxed::Box::pin(async move { #body })
+            }
+        };
+        (quote! { after }, closure)
+    } else {
+        let closure = quote! {
+            |_, _, __cucumber_scenario, __cucumber_world| {
+                ::std::boxed::Box::pin(async move {
+                    let __cucumber_world = #downcast;
+                    #func_name(__cucumber_world, #( #call_args ),*)
+                        #awaiting
+                        #unwrapping;
+                })
+            }
+        };
+        (quote! { before }, closure)
+    };
+
+    Ok(quote! {
+        #func
+
+        #[automatically_derived]
+        ::cucumber::codegen::submit!(
+            ::cucumber::codegen::Hook::#constructor::<#world>(
+                ::cucumber::step::Location {
+                    path: ::std::file!(),
+                    line: ::std::line!(),
+                    column: ::std::column!(),
+                },
+                #tags,
+                #closure,
+            )
+        );
+    })
+}
+
+/// Parses an optional `tags = "<tag expression>"` argument of a hook
+/// attribute.
+fn parse_tags(input: ParseStream<'_>) -> syn::Result<Option<syn::LitStr>> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let ident = input.parse::<syn::Ident>()?;
+    if ident != "tags" {
+        return Err(syn::Error::new(ident.span(), "expected `tags` argument"));
+    }
+    _ = input.parse::<syn::Token![=]>()?;
+    let tags = input.parse::<syn::LitStr>()?;
+    if !input.is_empty() {
+        _ = input.parse::<syn::Token![,]>()?;
+    }
+    if !input.is_empty() {
+        return Err(input.error("unexpected arguments"));
+    }
+    Ok(Some(tags))
+}
+
+/// Parses the `World` type from the first argument of a hook function, along
+/// with an indicator whether it's an [`Option`] (allowed for `#[after]` hooks
+/// only).
+fn parse_world(
+    arg: &syn::FnArg,
+    is_after: bool,
+) -> syn::Result<(&syn::Type, bool)> {
+    let err = || {
+        let expected = if is_after {
+            "`&mut World` or `Option<&mut World>`"
+        } else {
+            "`&mut World`"
+        };
+        syn::Error::new(arg.span(), format!("expected {expected} argument"))
+    };
+    let syn::FnArg::Typed(typed) = arg else {
+        return Err(err());
+    };
+
+    let (ty, optional) = match option_inner(&typed.ty) {
+        Some(ty) if is_after => (ty, true),
+        Some(_) | None => (&*typed.ty, false),
+    };
+    if let syn::Type::Reference(r) = ty {
+        if r.mutability.is_some() {
+            return Ok((&*r.elem, optional));
+        }
+    }
+    Err(err())
+}
+
+/// Returns the inner type of the provided [`Option`] `ty`, if it's the one.
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(p) = ty else { return None };
+    let segment = p.path.segments.last().filter(|s| s.ident == "Option")?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+        Some(inner)
+    } else {
+        None
+    }
+}
+
+/// Indicates whether the provided [`syn::Signature`] returns `()`.
+fn returns_unit(sig: &syn::Signature) -> bool {
+    match &sig.output {
+        syn::ReturnType::Default => true,
+        syn::ReturnType::Type(_, ty) => {
+            matches!(&**ty, syn::Type::Tuple(t) if t.elems.is_empty())
+        }
+    }
+}
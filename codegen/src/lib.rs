@@ -160,8 +160,15 @@
 
 mod attribute;
 mod attribute_ext;
+mod attribute_options;
+mod duplicate;
+mod fixture_arg;
+mod hook;
 mod parameter;
+mod pattern;
+mod steps;
 mod world;
+mod world_env;
 
 // TODO: Remove once tests run without complains about it.
 #[cfg(test)]
@@ -226,14 +233,51 @@ macro_rules! step_attribute {
         ///   Matches the step with an **exact** literal only. Doesn't allow any
         ///   values capturing to use as function arguments.
         ///
+        /// - `#[given(regex = "regex", priority = 10)]`
+        ///
+        ///   Any of the arguments above may be followed by a `priority` (an
+        ///   `i32` expression, `0` by default). When a step matches multiple
+        ///   step functions, the one with the highest priority is run, while
+        ///   equal highest priorities still fail the step as ambiguous.
+        ///
+        /// - `#[given(regex = "regex", tags = "@mock and not @slow")]`
+        ///
+        ///   Any of the arguments above may also be followed by `tags` (a
+        ///   [tag expression][2] string literal), so the step function is
+        ///   considered only in scenarios matching it, including the tags
+        ///   inherited from their rule and feature. An invalid expression
+        ///   panics on collecting the steps.
+        ///
+        /// - `#[given(regex = "regex", normalize = true)]`
+        ///
+        ///   Any of the arguments above may also be followed by `normalize` (a
+        ///   `bool` expression, `false` by default), so the step is matched
+        ///   case-insensitively against its text with trimmed and collapsed
+        ///   whitespaces.
+        ///
         /// # Function arguments
         ///
         /// - First argument has to be mutable reference to the [`World`]
         ///   deriver.
         /// - Other argument's types have to implement [`FromStr`] or it has to
         ///   be a slice where the element type also implements [`FromStr`].
+        ///   A captured value failed to be parsed fails the step with a
+        ///   `StepError::ArgMismatch` naming the argument, its type and the
+        ///   value.
         /// - To use [`gherkin::Step`], name the argument as `step`,
-        ///   **or** mark the argument with a `#[step]` attribute.
+        ///   **or** mark the argument with a `#[step]` attribute. Such argument
+        ///   may also be a `&cucumber::step::Context`, providing metadata of
+        ///   the executed scenario as well.
+        /// - An argument marked with a `#[fixture]` attribute receives the
+        ///   value of the fixture of its type set up for the executed
+        ///   scenario, either as a `&T` or as an `Arc<T>`. The step panics if
+        ///   no such fixture is set up.
+        /// - The [`World`] may also be a generic parameter of the function
+        ///   (bounded by a capability trait, for example). Such a function
+        ///   isn't auto-wired, but gets a module of the same name, whose
+        ///   `register()` function adds it (with all its stacked attributes)
+        ///   into a `cucumber::step::Collection` of any [`World`] satisfying
+        ///   the bounds.
         ///
         /// ```rust
         /// # use std::convert::Infallible;
@@ -260,11 +304,42 @@ macro_rules! step_attribute {
         /// # }
         /// ```
         ///
+        /// # Documentation
+        ///
+        /// A doc comment of the function is recorded along with the step
+        /// definition, so it's available via
+        /// `cucumber::step::Collection::definitions()` and shown in the
+        /// `--steps-index` of the step definitions.
+        ///
+        /// # Duplicates
+        ///
+        /// Two step functions of the same type defined with the same pattern
+        /// (and the same `priority`, `tags` and `normalize` arguments) for the
+        /// same [`World`] in the same module fail the compilation, pointing to
+        /// both patterns. Duplicates across different modules are detected
+        /// only at runtime, as ambiguous step matches.
+        ///
+        /// ```rust,compile_fail
+        /// # use cucumber::{given, World};
+        /// #
+        /// # #[derive(Debug, Default, World)]
+        /// # struct MyWorld;
+        /// #
+        /// #[given("a cat")]
+        /// fn cat(_: &mut MyWorld) {}
+        ///
+        /// #[given("a cat")] // error: defined multiple times
+        /// fn another_cat(_: &mut MyWorld) {}
+        /// ```
+        ///
         /// # Return value
         ///
         /// A function may also return a [`Result`], which [`Err`] is expected
         /// to implement [`Display`], so returning it will cause the step to
-        /// fail.
+        /// fail with a `StepError::Returned` carrying its message. This allows
+        /// using `?` operator in step functions instead of unwrapping. Errors
+        /// convertible into a `Box<dyn Error>` (like `anyhow::Error`) carry
+        /// their whole chain of sources in the message.
         ///
         /// [`Display`]: std::fmt::Display
         /// [`FromStr`]: std::str::FromStr
@@ -272,6 +347,7 @@ macro_rules! step_attribute {
         /// [`gherkin::Step`]: https://bit.ly/3j42hcd
         /// [`World`]: https://bit.ly/3j0aWw7
         /// [1]: cucumber_expressions
+        /// [2]: https://cucumber.io/docs/cucumber/api#tag-expressions
         #[proc_macro_attribute]
         pub fn $name(args: TokenStream, input: TokenStream) -> TokenStream {
             attribute::step(std::stringify!($name), args.into(), input.into())
@@ -283,13 +359,180 @@ macro_rules! step_attribute {
 
 /// Helper macro for generating public shim of [`macro@given`], [`macro@when`]
 /// and [`macro@then`] attributes.
-macro_rules! steps {
+macro_rules! step_attributes {
     ($($name:ident),*) => {
         $(step_attribute!($name);)*
     }
 }
 
-steps!(given, when, then);
+step_attributes!(given, when, then);
+
+/// Attribute to auto-wire the [`macro@given`], [`macro@when`] and
+/// [`macro@then`] methods of a [`World`] implementer.
+///
+/// Allows step functions to live next to the state they mutate, taking
+/// `&mut self` instead of a mutable reference to the [`World`] as the first
+/// argument. All the other arguments, attribute arguments and return values
+/// are the same as for regular step functions.
+///
+/// # Example
+///
+/// ```rust
+/// use cucumber::{World, given, steps, when};
+///
+/// #[derive(Debug, Default, World)]
+/// struct MyWorld {
+///     foo: i32,
+/// }
+///
+/// #[steps]
+/// impl MyWorld {
+///     #[given(regex = r"(\S+) is (\d+)")]
+///     #[when(expr = "{word} is {int}")]
+///     fn assign(&mut self, param: String, num: i32) {
+///         assert_eq!(param, "foo");
+///         self.foo = num;
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     MyWorld::run("./tests/features/doctests.feature").await;
+/// }
+/// ```
+///
+/// # Restrictions
+///
+/// - The `impl` block has to be an inherent and non-generic one.
+/// - Step methods cannot be generic, and have to take `&mut self`.
+///
+/// [`World`]: https://bit.ly/3j0aWw7
+#[proc_macro_attribute]
+pub fn steps(args: TokenStream, input: TokenStream) -> TokenStream {
+    steps::expand(args.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Attribute to auto-wire a hook, run before each [scenario][0] of the
+/// [`World`] implementer.
+///
+/// # Example
+///
+/// ```rust
+/// use cucumber::{World, before, gherkin::Scenario};
+///
+/// #[derive(Debug, Default, World)]
+/// struct MyWorld {
+///     db: Vec<String>,
+/// }
+///
+/// #[before(tags = "@db")]
+/// async fn seed(w: &mut MyWorld, scenario: &Scenario) {
+///     w.db.push(scenario.name.clone());
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     MyWorld::run("./tests/features/doctests.feature").await;
+/// }
+/// ```
+///
+/// # Attribute arguments
+///
+/// - `#[before(tags = "@db and not @slow")]` (optional)
+///
+///   [Tag expression][1] a [scenario][0] should match (including the tags
+///   inherited from its rule and feature) for the hook to be run. An invalid
+///   expression panics on running the hook.
+///
+/// # Function arguments
+///
+/// - First argument has to be mutable reference to the [`World`] deriver.
+/// - Second argument (optional) is a `&gherkin::Scenario` being run.
+///
+/// Multiple hooks are run in the order of their definition (by file, line and
+/// column). A hook may be `async`, and may return a [`Result`] failing the
+/// [scenario][0] with its [`Err`].
+///
+/// __NOTE__: Setting a custom `Cucumber::before()` hook replaces the
+///           auto-wired ones, unless it calls the
+///           `cucumber::codegen::run_before_hooks()` itself.
+///
+/// [`World`]: https://bit.ly/3j0aWw7
+/// [0]: https://cucumber.io/docs/gherkin/reference#example
+/// [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
+#[proc_macro_attribute]
+pub fn before(args: TokenStream, input: TokenStream) -> TokenStream {
+    hook::hook("before", args.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Attribute to auto-wire a hook, run after each [scenario][0] of the
+/// [`World`] implementer, even after its failed or skipped steps.
+///
+/// # Example
+///
+/// ```rust
+/// use cucumber::{World, after, event::ScenarioFinished, gherkin::Scenario};
+///
+/// #[derive(Debug, Default, World)]
+/// struct MyWorld {
+///     db: Vec<String>,
+/// }
+///
+/// #[after]
+/// fn cleanup(
+///     w: Option<&mut MyWorld>,
+///     _: &Scenario,
+///     finished: &ScenarioFinished,
+/// ) {
+///     if let Some(w) = w {
+///         w.db.clear();
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     MyWorld::run("./tests/features/doctests.feature").await;
+/// }
+/// ```
+///
+/// # Attribute arguments
+///
+/// - `#[after(tags = "@db and not @slow")]` (optional)
+///
+///   [Tag expression][1] a [scenario][0] should match (including the tags
+///   inherited from its rule and feature) for the hook to be run. An invalid
+///   expression panics on running the hook.
+///
+/// # Function arguments
+///
+/// - First argument has to be mutable reference to the [`World`] deriver
+///   (so the hook is run only if the [`World`] was created), or an [`Option`]
+///   of it.
+/// - Second argument (optional) is a `&gherkin::Scenario` being run.
+/// - Third argument (optional) is a `&cucumber::event::ScenarioFinished`
+///   describing how the [scenario][0] has finished.
+///
+/// Multiple hooks are run in the reverse order of their definition (by file,
+/// line and column). A hook may be `async`, and may return a [`Result`]
+/// failing the [scenario][0] with its [`Err`].
+///
+/// __NOTE__: Setting a custom `Cucumber::after()` hook replaces the
+///           auto-wired ones, unless it calls the
+///           `cucumber::codegen::run_after_hooks()` itself.
+///
+/// [`World`]: https://bit.ly/3j0aWw7
+/// [0]: https://cucumber.io/docs/gherkin/reference#example
+/// [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
+#[proc_macro_attribute]
+pub fn after(args: TokenStream, input: TokenStream) -> TokenStream {
+    hook::hook("after", args.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
 /// Derive macro for implementing a [`World`] trait.
 ///
@@ -315,7 +558,41 @@ steps!(given, when, then);
 ///   Specified function can be either sync or `async`, and either fallible
 ///   (return [`Result`]) or infallible (return [`World`] itself). In case no
 ///   function is specified, the [`Default::default()`] will be used for
-///   construction.
+///   construction. Error returned by a fallible function (e.g. on failing to
+///   read a config or to connect to a service) doesn't panic, but fails the
+///   scenario as a failed `before` hook, rendering the error.
+///
+/// - `#[world(teardown = path::to::fn)]`
+///
+///   Path to an `async` function accepting `&mut World`, to be used for a
+///   [`World`] instance teardown once its scenario is finished (after the
+///   `after` hook). Specified function can be either fallible (return
+///   [`Result`]`<(), _>`) or infallible (return `()`). Failed teardown is
+///   reported as a failed `after` hook.
+///
+/// # Field attribute arguments
+///
+/// - `#[world(env = "VAR")]`, `#[world(env = "VAR", default = "value")]`
+///
+///   Name of an environment variable to populate the field from, parsing it
+///   via [`FromStr`], along with an optional `default` value used if the
+///   variable is not set. Other fields are constructed via
+///   [`Default::default()`], so a [`World`] doesn't need to implement
+///   [`Default`]. Missing or unparsable variable fails the construction of
+///   the [`World`]. Cannot be combined with `#[world(init)]`.
+///
+/// ```rust
+/// #[derive(Debug, cucumber::World)]
+/// struct World {
+///     #[world(env = "BASE_URL", default = "http://localhost")]
+///     base_url: String,
+///     #[world(env = "RETRIES", default = "3")]
+///     retries: u8,
+///     visits: usize,
+/// }
+/// ```
+///
+/// [`FromStr`]: std::str::FromStr
 #[proc_macro_derive(World, attributes(world))]
 pub fn world(input: TokenStream) -> TokenStream {
     world::derive(input.into())
@@ -0,0 +1,70 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compile-time validation of patterns of `#[given]`, `#[when]` and `#[then]`
+//! attribute macros, so invalid ones fail the compilation instead of panicking
+//! on collecting the steps.
+
+use cucumber_expressions::{Expression, Spanned};
+use proc_macro2::Span;
+use regex::Regex;
+
+/// Validates the provided `regex` literal to be a valid [`Regex`].
+///
+/// # Errors
+///
+/// If the `regex` is invalid, pointing at the literal.
+pub(crate) fn validate_regex(regex: &syn::LitStr) -> syn::Result<()> {
+    Regex::new(&regex.value()).map(drop).map_err(|e| {
+        syn::Error::new(regex.span(), format!("invalid regex: {e}"))
+    })
+}
+
+/// Parses the provided [Cucumber Expression][1].
+///
+/// # Errors
+///
+/// If the `expr` is invalid, pointing at the provided `span` of its literal.
+///
+/// [1]: https://github.com/cucumber/cucumber-expressions#readme
+pub(crate) fn parse_expression(
+    expr: &str,
+    span: Span,
+) -> syn::Result<Expression<Spanned<'_>>> {
+    Expression::parse(expr).map_err(|e| {
+        syn::Error::new(span, format!("invalid Cucumber Expression: {e}"))
+    })
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let lit = |s| syn::LitStr::new(s, Span::call_site());
+
+        assert!(validate_regex(&lit(r"^I have (\d+) cucumbers$")).is_ok());
+        let err = validate_regex(&lit(r"^I have (\d+ cucumbers$")).unwrap_err();
+        assert!(err.to_string().starts_with("invalid regex: "), "{err}");
+
+        assert!(
+            parse_expression("I have {int} cucumber(s)", Span::call_site())
+                .is_ok(),
+        );
+        let err = parse_expression("I have {int cucumbers", Span::call_site())
+            .map(drop)
+            .unwrap_err();
+        assert!(
+            err.to_string().starts_with("invalid Cucumber Expression: "),
+            "{err}",
+        );
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `#[steps]` attribute macro implementation.
+
+use std::mem;
+
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned as _;
+
+use crate::attribute::step_attr_name;
+
+/// Generates code of `#[steps]` attribute macro expansion.
+pub(crate) fn expand(
+    args: TokenStream,
+    input: TokenStream,
+) -> syn::Result<TokenStream> {
+    if let Some(arg) = args.into_iter().next() {
+        return Err(syn::Error::new(arg.span(), "no arguments expected"));
+    }
+    let mut item = syn::parse2::<syn::ItemImpl>(input)?;
+    if let Some((_, trait_, _)) = &item.trait_ {
+        return Err(syn::Error::new(
+            trait_.span(),
+            "`#[steps]` is expected on an inherent `impl` block",
+        ));
+    }
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "`#[steps]` cannot be applied to a generic `impl` block",
+        ));
+    }
+    let world = &*item.self_ty;
+
+    let wrappers = item
+        .items
+        .iter_mut()
+        .filter_map(|it| match it {
+            syn::ImplItem::Fn(method) => Some(method),
+            syn::ImplItem::Const(_)
+            | syn::ImplItem::Type(_)
+            | syn::ImplItem::Macro(_)
+            | syn::ImplItem::Verbatim(_)
+            | _ => None,
+        })
+        .filter(|method| {
+            method.attrs.iter().any(|a| step_attr_name(a).is_some())
+        })
+        .map(|method| wrap_method(world, method))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #item
+
+        const _: () = {
+            #( #wrappers )*
+        };
+    })
+}
+
+/// Moves `#[given]`, `#[when]` and `#[then]` attributes of the provided
+/// `method` (and attributes of its arguments) onto a generated free function
+/// calling the `method` on the `world`, so it's registered as a regular step
+/// function.
+fn wrap_method(
+    world: &syn::Type,
+    method: &mut syn::ImplItemFn,
+) -> syn::Result<TokenStream> {
+    let sig = &mut method.sig;
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            sig.generics.span(),
+            "step methods cannot be generic",
+        ));
+    }
+    match sig.receiver() {
+        Some(r) if r.reference.is_some() && r.mutability.is_some() => {}
+        _ => {
+            return Err(syn::Error::new(
+                sig.span(),
+                "step methods are expected to take `&mut self`",
+            ));
+        }
+    }
+
+    let (step_attrs, attrs) = mem::take(&mut method.attrs)
+        .into_iter()
+        .partition::<Vec<_>, _>(|attr| step_attr_name(attr).is_some());
+    method.attrs = attrs;
+    let docs = method.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+
+    let mut args = Vec::new();
+    let mut idents = Vec::new();
+    for arg in sig.inputs.iter_mut().skip(1) {
+        let syn::FnArg::Typed(arg) = arg else { continue };
+        let syn::Pat::Ident(pat) = &*arg.pat else {
+            return Err(syn::Error::new(arg.pat.span(), "expected ident"));
+        };
+        let (ident, ty) = (&pat.ident, replace_self(&arg.ty, world)?);
+        let arg_attrs = mem::take(&mut arg.attrs);
+        args.push(quote! { #( #arg_attrs )* #ident: #ty });
+        idents.push(ident.clone());
+    }
+
+    let method_name = &sig.ident;
+    let wrapper_name = format_ident!("__cucumber_{method_name}");
+    let asyncness = &sig.asyncness;
+    let awaiting = asyncness.map(|_| quote! { .await });
+    let output = match &sig.output {
+        syn::ReturnType::Default => quote! {},
+        syn::ReturnType::Type(arrow, ty) => {
+            let ty = replace_self(ty, world)?;
+            quote! { #arrow #ty }
+        }
+    };
+
+    Ok(quote! {
+        #( #docs )*
+        #( #step_attrs )*
+        #asyncness fn #wrapper_name(
+            __cucumber_world: &mut #world,
+            #( #args ),*
+        ) #output {
+            <#world>::#method_name(__cucumber_world, #( #idents ),*) #awaiting
+        }
+    })
+}
+
+/// Replaces all the `Self` occurrences in the provided `ty` with the `world`,
+/// as the generated step function is placed outside the `impl` block.
+fn replace_self(ty: &syn::Type, world: &syn::Type) -> syn::Result<syn::Type> {
+    /// Replaces `Self` identifiers in the provided [`TokenStream`].
+    fn replace(tokens: TokenStream, world: &syn::Type) -> TokenStream {
+        tokens
+            .into_iter()
+            .flat_map(|tt| match tt {
+                TokenTree::Ident(i) if i == "Self" => quote! { #world },
+                TokenTree::Group(g) => {
+                    let mut group =
+                        Group::new(g.delimiter(), replace(g.stream(), world));
+                    group.set_span(g.span());
+                    TokenTree::Group(group).into()
+                }
+                tt @ (TokenTree::Ident(_)
+                | TokenTree::Punct(_)
+                | TokenTree::Literal(_)) => tt.into(),
+            })
+            .collect()
+    }
+
+    syn::parse2(replace(quote! { #ty }, world))
+}
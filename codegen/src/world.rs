@@ -17,6 +17,8 @@ use quote::{format_ident, quote};
 use syn::parse_quote;
 use synthez::{ParseAttrs, ToTokens};
 
+use crate::world_env;
+
 /// Generates code of `#[derive(World)]` macro expansion.
 ///
 /// # Errors
@@ -37,6 +39,12 @@ struct Attrs {
     /// If [`None`] then [`Default::default()`] will be used.
     #[parse(value)]
     init: Option<syn::ExprPath>,
+
+    /// Async function to be used for a `World` teardown.
+    ///
+    /// If [`None`] then no teardown is performed.
+    #[parse(value)]
+    teardown: Option<syn::ExprPath>,
 }
 
 /// Representation of a type implementing a `World` trait, used for code
@@ -58,6 +66,14 @@ struct Definition {
     /// Function, which is used to construct `World`. Uses [`Default`] impl, in
     /// case no value is provided.
     init: Option<syn::ExprPath>,
+
+    /// Async function, which is used to tear `World` down. No teardown is
+    /// performed, in case no value is provided.
+    teardown: Option<syn::ExprPath>,
+
+    /// Fields to construct `World` from, if any of them is populated from an
+    /// environment variable.
+    fields: Vec<world_env::Field>,
 }
 
 impl TryFrom<syn::DeriveInput> for Definition {
@@ -65,12 +81,21 @@ impl TryFrom<syn::DeriveInput> for Definition {
 
     fn try_from(input: syn::DeriveInput) -> syn::Result<Self> {
         let attrs: Attrs = Attrs::parse_attrs("world", &input)?;
+        let fields = world_env::parse(&input.data)?;
+        if let Some(init) = attrs.init.as_ref().filter(|_| !fields.is_empty()) {
+            return Err(syn::Error::new_spanned(
+                init,
+                "`init` cannot be combined with `env` fields",
+            ));
+        }
 
         Ok(Self {
             ident: input.ident,
             generics: input.generics,
             vis: input.vis,
             init: attrs.init,
+            teardown: attrs.teardown,
+            fields,
         })
     }
 }
@@ -116,6 +141,40 @@ impl Definition {
         let init = self.init.clone().unwrap_or_else(
             || parse_quote! { <Self as ::std::default::Default>::default },
         );
+        let construct = if self.fields.is_empty() {
+            quote! {
+                use ::cucumber::codegen::{
+                    IntoWorldResult as _, ToWorldFuture as _,
+                };
+
+                fn as_fn_ptr<T>(v: fn() -> T) -> fn() -> T {
+                    v
+                }
+
+                (&as_fn_ptr(#init))
+                    .to_world_future()
+                    .await
+                    .into_world_result()
+                    .map_err(::std::convert::Into::into)
+            }
+        } else {
+            world_env::construct(&self.fields)
+        };
+
+        let teardown = self.teardown.as_ref().map(|teardown| {
+            quote! {
+                async fn teardown(
+                    &mut self,
+                ) -> ::std::result::Result<(), Self::Error> {
+                    use ::cucumber::codegen::IntoTeardownResult as _;
+
+                    #teardown(self)
+                        .await
+                        .into_teardown_result()
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+        });
 
         quote! {
             #[automatically_derived]
@@ -125,20 +184,10 @@ impl Definition {
                 type Error = ::cucumber::codegen::anyhow::Error;
 
                 async fn new() -> ::std::result::Result<Self, Self::Error> {
-                    use ::cucumber::codegen::{
-                        IntoWorldResult as _, ToWorldFuture as _,
-                    };
-
-                    fn as_fn_ptr<T>(v: fn() -> T) -> fn() -> T {
-                        v
-                    }
-
-                    (&as_fn_ptr(#init))
-                        .to_world_future()
-                        .await
-                        .into_world_result()
-                        .map_err(::std::convert::Into::into)
+                    #construct
                 }
+
+                #teardown
             }
         }
     }
@@ -164,7 +213,19 @@ impl Definition {
                         #world_vis regex: ::cucumber::codegen::LazyRegex,
 
                         #[doc(hidden)]
-                        #world_vis func: ::cucumber::Step<#world>,
+                        #world_vis func: ::cucumber::step::Function<#world>,
+
+                        #[doc(hidden)]
+                        #world_vis priority: i32,
+
+                        #[doc(hidden)]
+                        #world_vis tags: ::std::option::Option<&'static str>,
+
+                        #[doc(hidden)]
+                        #world_vis normalize: bool,
+
+                        #[doc(hidden)]
+                        #world_vis doc: ::std::option::Option<&'static str>,
                     }
 
                     #[automatically_derived]
@@ -175,10 +236,26 @@ impl Definition {
                         fn inner(&self) -> (
                             ::cucumber::step::Location,
                             ::cucumber::codegen::LazyRegex,
-                            ::cucumber::Step<#world>,
+                            ::cucumber::step::Function<#world>,
                         ) {
                             (self.loc, self.regex, self.func)
                         }
+
+                        fn priority(&self) -> i32 {
+                            self.priority
+                        }
+
+                        fn tags(&self) -> ::std::option::Option<&'static str> {
+                            self.tags
+                        }
+
+                        fn normalize(&self) -> bool {
+                            self.normalize
+                        }
+
+                        fn doc(&self) -> ::std::option::Option<&'static str> {
+                            self.doc
+                        }
                     }
 
                     #[automatically_derived]
@@ -248,7 +325,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -258,10 +347,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -277,7 +382,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -287,10 +404,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -306,7 +439,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -316,10 +461,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -377,7 +538,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -387,10 +560,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -406,7 +595,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -416,10 +617,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -435,7 +652,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -445,10 +674,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -507,7 +752,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -517,10 +774,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -536,7 +809,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -546,10 +831,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -565,7 +866,19 @@ mod spec {
                  pub regex: ::cucumber::codegen::LazyRegex,
 
                  #[doc(hidden)]
-                 pub func: ::cucumber::Step<World>,
+                 pub func: ::cucumber::step::Function<World>,
+
+                 #[doc(hidden)]
+                 pub priority: i32,
+
+                 #[doc(hidden)]
+                 pub tags: ::std::option::Option<&'static str>,
+
+                 #[doc(hidden)]
+                 pub normalize: bool,
+
+                 #[doc(hidden)]
+                 pub doc: ::std::option::Option<&'static str>,
             }
 
             #[automatically_derived]
@@ -575,10 +888,26 @@ mod spec {
                 fn inner(&self) -> (
                     ::cucumber::step::Location,
                     ::cucumber::codegen::LazyRegex,
-                    ::cucumber::Step<World>,
+                    ::cucumber::step::Function<World>,
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn priority(&self) -> i32 {
+                    self.priority
+                }
+
+                fn tags(&self) -> ::std::option::Option<&'static str> {
+                    self.tags
+                }
+
+                fn normalize(&self) -> bool {
+                    self.normalize
+                }
+
+                fn doc(&self) -> ::std::option::Option<&'static str> {
+                    self.doc
+                }
             }
 
             #[automatically_derived]
@@ -590,4 +919,102 @@ mod spec {
             output.to_string(),
         );
     }
+
+    #[test]
+    fn derives_impl_with_teardown_fn() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[world(teardown = Self::close)]
+            pub struct World;
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl ::cucumber::World for World {
+                type Error = ::cucumber::codegen::anyhow::Error;
+
+                async fn new() -> ::std::result::Result<Self, Self::Error> {
+                    use ::cucumber::codegen::{
+                        IntoWorldResult as _, ToWorldFuture as _,
+                    };
+
+                    fn as_fn_ptr<T>(v: fn() -> T) -> fn() -> T {
+                        v
+                    }
+
+                    (&as_fn_ptr(<Self as ::std::default::Default>::default))
+                        .to_world_future()
+                        .await
+                        .into_world_result()
+                        .map_err(::std::convert::Into::into)
+                }
+
+                async fn teardown(
+                    &mut self,
+                ) -> ::std::result::Result<(), Self::Error> {
+                    use ::cucumber::codegen::IntoTeardownResult as _;
+
+                    Self::close(self)
+                        .await
+                        .into_teardown_result()
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+        };
+
+        let definition = super::Definition::try_from(input).unwrap();
+        assert_eq!(
+            definition.impl_world().to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn derives_impl_with_env_fields() {
+        let input: syn::DeriveInput = parse_quote! {
+            pub struct World {
+                #[world(env = "PORT", default = "8080")]
+                port: u16,
+                visits: usize,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl ::cucumber::World for World {
+                type Error = ::cucumber::codegen::anyhow::Error;
+
+                async fn new() -> ::std::result::Result<Self, Self::Error> {
+                    ::std::result::Result::Ok(Self {
+                        port: ::cucumber::codegen::env_var(
+                            "PORT",
+                            ::std::option::Option::Some("8080")
+                        )?,
+                        visits: ::std::default::Default::default(),
+                    })
+                }
+            }
+        };
+
+        let definition = super::Definition::try_from(input).unwrap();
+        assert_eq!(
+            definition.impl_world().to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn errors_on_init_with_env_fields() {
+        let input = parse_quote! {
+            #[world(init = Self::new)]
+            pub struct World {
+                #[world(env = "PORT")]
+                port: u16,
+            }
+        };
+
+        assert_eq!(
+            super::derive(input).unwrap_err().to_string(),
+            "`init` cannot be combined with `env` fields",
+        );
+    }
 }
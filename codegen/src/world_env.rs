@@ -0,0 +1,192 @@
+// Copyright (c) 2020-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `#[world(env = "VAR", default = "value")]` field attributes of
+//! `#[derive(World)]` macro.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use synthez::ParseAttrs;
+
+/// Helper attributes of a `#[derive(World)]` field.
+#[derive(Debug, Default, ParseAttrs)]
+struct FieldAttrs {
+    /// Name of an environment variable to populate the field from.
+    #[parse(value)]
+    env: Option<syn::LitStr>,
+
+    /// Value to populate the field from, if the environment variable is not
+    /// set.
+    #[parse(value)]
+    default: Option<syn::LitStr>,
+}
+
+/// Field of a `World`, being constructed from environment variables.
+#[derive(Debug)]
+pub(crate) struct Field {
+    /// Name (or index) of this [`Field`].
+    member: syn::Member,
+
+    /// Environment variable to populate this [`Field`] from, along with its
+    /// default value.
+    ///
+    /// If [`None`] then [`Default::default()`] is used.
+    env: Option<(syn::LitStr, Option<syn::LitStr>)>,
+}
+
+/// Parses [`Field`]s of the provided `World` struct.
+///
+/// Returns no [`Field`]s if none of them has an `env` attribute, so the `World`
+/// is constructed as a whole.
+///
+/// # Errors
+///
+/// - If failed to parse [`FieldAttrs`].
+/// - If `default` is specified without `env`.
+pub(crate) fn parse(data: &syn::Data) -> syn::Result<Vec<Field>> {
+    let syn::Data::Struct(data) = data else {
+        return Ok(Vec::new());
+    };
+
+    let fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let attrs = FieldAttrs::parse_attrs("world", field)?;
+            let env = match (attrs.env, attrs.default) {
+                (Some(var), default) => Some((var, default)),
+                (None, Some(default)) => {
+                    return Err(syn::Error::new(
+                        default.span(),
+                        "`default` requires `env` to be specified",
+                    ));
+                }
+                (None, None) => None,
+            };
+            let member = field.ident.clone().map_or_else(
+                || syn::Member::Unnamed(i.into()),
+                syn::Member::Named,
+            );
+            Ok(Field { member, env })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(if fields.iter().any(|f| f.env.is_some()) { fields } else { Vec::new() })
+}
+
+/// Generates code constructing a `World` from the provided [`Field`]s.
+pub(crate) fn construct(fields: &[Field]) -> TokenStream {
+    let fields = fields.iter().map(|Field { member, env }| {
+        let value = env.as_ref().map_or_else(
+            || quote! { ::std::default::Default::default() },
+            |(var, default)| {
+                let default = default.as_ref().map_or_else(
+                    || quote! { ::std::option::Option::None },
+                    |d| quote! { ::std::option::Option::Some(#d) },
+                );
+                quote! { ::cucumber::codegen::env_var(#var, #default)? }
+            },
+        );
+        quote! { #member: #value, }
+    });
+
+    quote! {
+        ::std::result::Result::Ok(Self { #( #fields )* })
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::{construct, parse};
+
+    #[test]
+    fn constructs_fields_from_env() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct World {
+                #[world(env = "BASE_URL", default = "http://localhost")]
+                base_url: String,
+                #[world(env = "TOKEN")]
+                token: String,
+                visits: usize,
+            }
+        };
+
+        let output = quote! {
+            ::std::result::Result::Ok(Self {
+                base_url: ::cucumber::codegen::env_var(
+                    "BASE_URL",
+                    ::std::option::Option::Some("http://localhost")
+                )?,
+                token: ::cucumber::codegen::env_var(
+                    "TOKEN",
+                    ::std::option::Option::None
+                )?,
+                visits: ::std::default::Default::default(),
+            })
+        };
+
+        assert_eq!(
+            construct(&parse(&input.data).unwrap()).to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn constructs_tuple_fields_from_env() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct World(usize, #[world(env = "PORT")] u16);
+        };
+
+        let output = quote! {
+            ::std::result::Result::Ok(Self {
+                0: ::std::default::Default::default(),
+                1: ::cucumber::codegen::env_var(
+                    "PORT",
+                    ::std::option::Option::None
+                )?,
+            })
+        };
+
+        assert_eq!(
+            construct(&parse(&input.data).unwrap()).to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn ignores_fields_without_env() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct World {
+                visits: usize,
+            }
+        };
+
+        assert!(parse(&input.data).unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors_on_default_without_env() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct World {
+                #[world(default = "8080")]
+                port: u16,
+            }
+        };
+
+        assert_eq!(
+            parse(&input.data).unwrap_err().to_string(),
+            "`default` requires `env` to be specified",
+        );
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Access to the parsed [`Opts::custom`] CLI options during a run.
+//!
+//! [`Opts::custom`]: super::Opts::custom
+
+use std::{any::Any, cell::RefCell, pin::Pin, sync::Arc, task};
+
+use futures::Stream;
+use pin_project::pin_project;
+
+/// Type-erased [`Opts::custom`] CLI options of a run.
+///
+/// [`Opts::custom`]: super::Opts::custom
+pub(crate) type Erased = Arc<dyn Any + Send + Sync>;
+
+thread_local! {
+    /// [`Opts::custom`] CLI options of the run being polled on the current
+    /// thread.
+    ///
+    /// [`Opts::custom`]: super::Opts::custom
+    static CURRENT: RefCell<Option<Erased>> = const { RefCell::new(None) };
+}
+
+/// Returns the [`Opts::custom`] CLI options of the current run, if they're
+/// of type `T`.
+///
+/// Available while a `World` is being constructed and a
+/// [`crate::step::Step`] or hook is being executed, so may be used in
+/// [`World::new()`] to configure it with user-defined CLI options (like
+/// `--base-url` or `--headless`).
+///
+/// Returns [`None`] outside a run, or if the [`Opts::custom`] CLI options
+/// are of another type.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{World, cli};
+/// #
+/// #[derive(Clone, Debug, Default, cli::Args)]
+/// struct CustomOpts {
+///     /// Base URL of the tested service.
+///     #[arg(long, default_value = "http://localhost")]
+///     base_url: String,
+/// }
+///
+/// #[derive(Debug, World)]
+/// #[world(init = Self::new)]
+/// struct MyWorld {
+///     base_url: String,
+/// }
+///
+/// impl MyWorld {
+///     fn new() -> Self {
+///         let opts = cli::custom::<CustomOpts>().unwrap_or_default();
+///         Self { base_url: opts.base_url.clone() }
+///     }
+/// }
+/// ```
+///
+/// [`Opts::custom`]: super::Opts::custom
+/// [`World::new()`]: crate::World::new
+#[must_use]
+pub fn custom<T: Any + Send + Sync>() -> Option<Arc<T>> {
+    current()?.downcast().ok()
+}
+
+/// Returns the type-erased [`Opts::custom`] CLI options of the current run,
+/// if any.
+///
+/// [`Opts::custom`]: super::Opts::custom
+pub(crate) fn current() -> Option<Erased> {
+    CURRENT.with(|c| c.borrow().clone())
+}
+
+/// [`Stream`] making the [`Opts::custom`] CLI options available via
+/// [`custom()`] while it's being polled.
+///
+/// [`Opts::custom`]: super::Opts::custom
+#[derive(Debug)]
+#[pin_project]
+pub(crate) struct Scoped<S> {
+    /// Wrapped [`Stream`].
+    #[pin]
+    stream: S,
+
+    /// [`Opts::custom`] CLI options to provide.
+    ///
+    /// [`Opts::custom`]: super::Opts::custom
+    custom: Erased,
+}
+
+impl<S> Scoped<S> {
+    /// Wraps the provided [`Stream`] to provide the `custom` CLI options while
+    /// it's being polled.
+    pub(crate) fn new<C: Any + Send + Sync>(stream: S, custom: C) -> Self {
+        Self { stream, custom: Arc::new(custom) }
+    }
+}
+
+impl<S: Stream> Stream for Scoped<S> {
+    type Item = S::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Option<Self::Item>> {
+        let this = self.project();
+        let prev = CURRENT.with(|c| c.replace(Some(Arc::clone(this.custom))));
+        let poll = this.stream.poll_next(cx);
+        CURRENT.with(|c| *c.borrow_mut() = prev);
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{StreamExt as _, executor::block_on, stream};
+
+    use super::*;
+
+    #[test]
+    fn provides_custom_opts_only_while_polled() {
+        let opts = String::from("--headless");
+        let seen = block_on(
+            Scoped::new(
+                stream::once(async { (custom::<String>(), custom::<u32>()) }),
+                opts,
+            )
+            .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].0.as_deref().unwrap(), "--headless");
+        assert!(seen[0].1.is_none(), "wrong type");
+        assert!(custom::<String>().is_none(), "outside run");
+    }
+}
@@ -29,12 +29,14 @@
 
 mod colored;
 mod compose;
+pub(crate) mod custom;
 mod opts;
 
 // Re-exports for backward compatibility and ease of use
 pub use clap::{Args, Parser};
 pub use colored::Colored;
 pub use compose::{Compose, Empty};
+pub use custom::custom;
 pub use opts::Opts;
 
 // Re-export Coloring from writer module for convenience
@@ -14,11 +14,20 @@
 //! options from [`Parser`], [`crate::runner::Runner`], and [`crate::Writer`] components, along with
 //! filtering capabilities based on regex patterns or tag expressions.
 
+use std::env;
+
 use clap::{Args, Parser};
 use gherkin::tagexpr::TagOperation;
 use regex::Regex;
 
 use super::compose::Empty;
+#[cfg(feature = "failures-file")]
+use crate::failures;
+#[cfg(feature = "profiles")]
+use crate::profile;
+#[cfg(feature = "steps-index")]
+use crate::step_index;
+use crate::{exit_code, format, lint, list, validate};
 
 /// Root CLI (command line interface) of a top-level [`Cucumber`] executor.
 ///
@@ -68,7 +77,8 @@ use super::compose::Empty;
 #[command(
     name = "cucumber",
     about = "Run the tests, pet a dog!",
-    long_about = "Run the tests, pet a dog!"
+    long_about = "Run the tests, pet a dog!",
+    args_override_self = true
 )]
 pub struct Opts<Parser, Runner, Writer, Custom = Empty>
 where
@@ -78,6 +88,9 @@ where
     Custom: Args,
 {
     /// Regex to filter scenarios by their name.
+    ///
+    /// Scenario Outline examples are also matched by their row values,
+    /// rendered as `column=value, ...`, so individual rows may be selected.
     #[arg(
         id = "name",
         long = "name",
@@ -102,6 +115,50 @@ where
     )]
     pub tags_filter: Option<TagOperation>,
 
+    /// Fail the run on undefined and pending steps, in addition to the failed
+    /// ones.
+    #[arg(long, global = true)]
+    pub strict: bool,
+
+    /// Exit code of an unsuccessful run outcome, overriding the default `1`.
+    #[arg(
+        long = "exit-code",
+        value_name = "parsing|failed|timeout|undefined=CODE",
+        global = true
+    )]
+    pub exit_codes: Vec<exit_code::Mapping>,
+
+    /// Linting CLI options.
+    #[command(flatten)]
+    pub lint: lint::Cli,
+
+    /// Formatting CLI options.
+    #[command(flatten)]
+    pub format: format::Cli,
+
+    /// Step coverage validation CLI options.
+    #[command(flatten)]
+    pub validate: validate::Cli,
+
+    /// Scenarios listing CLI options.
+    #[command(flatten)]
+    pub list: list::Cli,
+
+    /// Step definitions index CLI options.
+    #[cfg(feature = "steps-index")]
+    #[command(flatten)]
+    pub steps_index: step_index::Cli,
+
+    /// Failures report CLI options.
+    #[cfg(feature = "failures-file")]
+    #[command(flatten)]
+    pub failures: failures::Cli,
+
+    /// Configuration profile CLI options.
+    #[cfg(feature = "profiles")]
+    #[command(flatten)]
+    pub profile: profile::Cli,
+
     /// [`Parser`] CLI options.
     ///
     /// [`Parser`]: crate::Parser
@@ -134,9 +191,23 @@ where
 {
     /// Shortcut for [`clap::Parser::parse()`], which doesn't require the trait
     /// being imported.
+    ///
+    /// With `profiles` feature, the options of the selected [`Profile`] are
+    /// applied too.
+    ///
+    /// [`Profile`]: crate::profile::Profile
     #[must_use]
     pub fn parsed() -> Self {
-        <Self as clap::Parser>::parse()
+        let args = env::args_os();
+        #[cfg(feature = "profiles")]
+        let args = profile::Profiles::discover()
+            .and_then(|profiles| profiles.expand(args))
+            .unwrap_or_else(|e| {
+                <Self as clap::CommandFactory>::command()
+                    .error(clap::error::ErrorKind::InvalidValue, e)
+                    .exit()
+            });
+        <Self as clap::Parser>::parse_from(args)
     }
 }
 
@@ -189,6 +260,81 @@ mod tests {
         assert!(opts.re_filter.is_none());
     }
 
+    #[test]
+    fn test_opts_with_strict() {
+        let args = vec!["cucumber", "--strict"];
+        let opts =
+            Opts::<MockParser, MockRunner, MockWriter, Empty>::try_parse_from(
+                args,
+            )
+            .unwrap();
+
+        assert!(opts.strict);
+        assert!(!Opts::<Empty, Empty, Empty, Empty>::default().strict);
+    }
+
+    #[cfg(feature = "profiles")]
+    #[test]
+    fn test_opts_with_profile() {
+        use crate::tag::Ext as _;
+
+        let profiles = r#"
+            [profile.smoke]
+            tags = "@smoke"
+            strict = true
+        "#
+        .parse::<profile::Profiles>()
+        .unwrap();
+
+        let args = profiles
+            .expand(["cucumber", "--profile=smoke", "-t", "@fast"])
+            .unwrap();
+        let opts =
+            Opts::<MockParser, MockRunner, MockWriter, Empty>::try_parse_from(
+                args,
+            )
+            .unwrap();
+
+        assert_eq!(opts.profile.profile.as_deref(), Some("smoke"));
+        assert!(opts.tags_filter.unwrap().eval(["fast"]));
+        assert!(opts.strict);
+    }
+
+    #[test]
+    fn test_opts_with_exit_codes() {
+        let args = vec![
+            "cucumber",
+            "--exit-code",
+            "parsing=2",
+            "--exit-code=undefined=3",
+        ];
+        let opts =
+            Opts::<MockParser, MockRunner, MockWriter, Empty>::try_parse_from(
+                args,
+            )
+            .unwrap();
+
+        assert_eq!(
+            opts.exit_codes,
+            [
+                exit_code::Mapping {
+                    outcome: exit_code::Outcome::ParsingErrors,
+                    code: 2,
+                },
+                exit_code::Mapping {
+                    outcome: exit_code::Outcome::Undefined,
+                    code: 3,
+                },
+            ],
+        );
+        assert!(
+            Opts::<MockParser, MockRunner, MockWriter, Empty>::try_parse_from(
+                ["cucumber", "--exit-code", "skipped=3"],
+            )
+            .is_err(),
+        );
+    }
+
     #[test]
     fn test_conflicting_filters() {
         let args = vec!["cucumber", "--name", "test.*", "--tags", "@smoke"];
@@ -0,0 +1,97 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing of typed arguments of [`Step`] functions.
+//!
+//! [`Step`]: crate::Step
+
+use std::fmt;
+
+use crate::step;
+
+/// Failure of parsing a typed argument of a [`Step`] function, described via
+/// [autoref-based specialization][0].
+///
+/// Prefers the [`Display`] implementation of the parsing error, falling back
+/// to its [`Debug`] one.
+///
+/// ```rust
+/// use cucumber::codegen::{
+///     ArgParsing, DebugArgError as _, DisplayArgError as _,
+/// };
+///
+/// let err = "five".parse::<u32>().unwrap_err();
+/// let e = (&ArgParsing("count", "u32", "five", &err)).to_arg_error();
+///
+/// assert_eq!(
+///     e.to_string(),
+///     "cannot parse `five` as `u32` for `count` argument: \
+///      invalid digit found in string",
+/// );
+/// ```
+///
+/// [0]: https://tinyurl.com/autoref-spec
+/// [`Debug`]: std::fmt::Debug
+/// [`Display`]: std::fmt::Display
+/// [`Step`]: crate::Step
+#[derive(Debug)]
+pub struct ArgParsing<'a, E>(
+    /// Name of the argument.
+    pub &'a str,
+    /// Type of the argument.
+    pub &'a str,
+    /// Captured value failed to be parsed.
+    pub &'a str,
+    /// Parsing error.
+    pub &'a E,
+);
+
+impl<E> ArgParsing<'_, E> {
+    /// Creates a [`step::ArgError`] with the provided `reason`.
+    fn with_reason(&self, reason: String) -> step::ArgError {
+        let Self(name, ty, value, _) = *self;
+        step::ArgError {
+            name: name.to_owned(),
+            ty: ty.to_owned(),
+            value: value.to_owned(),
+            reason,
+        }
+    }
+}
+
+/// Conversion of an [`ArgParsing`] into a [`step::ArgError`] via the
+/// [`Display`] implementation of the parsing error.
+///
+/// [`Display`]: std::fmt::Display
+pub trait DisplayArgError {
+    /// Converts this [`ArgParsing`] into a [`step::ArgError`].
+    fn to_arg_error(&self) -> step::ArgError;
+}
+
+impl<E: fmt::Display> DisplayArgError for ArgParsing<'_, E> {
+    fn to_arg_error(&self) -> step::ArgError {
+        self.with_reason(self.3.to_string())
+    }
+}
+
+/// Conversion of an [`ArgParsing`] into a [`step::ArgError`] via the
+/// [`Debug`] implementation of the parsing error.
+///
+/// [`Debug`]: std::fmt::Debug
+pub trait DebugArgError {
+    /// Converts this [`ArgParsing`] into a [`step::ArgError`].
+    fn to_arg_error(&self) -> step::ArgError;
+}
+
+impl<E: fmt::Debug> DebugArgError for &ArgParsing<'_, E> {
+    fn to_arg_error(&self) -> step::ArgError {
+        self.with_reason(format!("{:?}", self.3))
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Population of `World` fields from environment variables, specified via
+//! `#[world(env = "VAR")]` attribute of a [`World`] derive macro.
+//!
+//! [`World`]: crate::World
+
+use std::{env, error::Error as StdError, str::FromStr};
+
+use derive_more::with_trait::{Display, Error};
+
+/// Error of populating a `World` field from an environment variable.
+#[derive(Debug, Display, Error)]
+pub enum EnvVarError {
+    /// Environment variable is not set, and no default value is specified.
+    #[display("environment variable `{var}` is not set")]
+    Missing {
+        /// Name of the environment variable.
+        #[error(not(source))]
+        var: &'static str,
+    },
+
+    /// Environment variable contains invalid Unicode.
+    #[display("environment variable `{var}` is not valid Unicode")]
+    NotUnicode {
+        /// Name of the environment variable.
+        #[error(not(source))]
+        var: &'static str,
+    },
+
+    /// Value of the environment variable (or its default) cannot be parsed
+    /// into the field type.
+    #[display("cannot parse `{value}` value of `{var}` environment variable")]
+    Invalid {
+        /// Name of the environment variable.
+        var: &'static str,
+
+        /// Value failed to be parsed.
+        value: String,
+
+        /// Error of parsing the `value`.
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+/// Reads and parses the `var` environment variable, falling back to the
+/// `default` value if it's not set.
+///
+/// # Errors
+///
+/// - If the `var` isn't set and no `default` is provided.
+/// - If the `var` isn't valid Unicode.
+/// - If the value (or the `default`) cannot be parsed into `T`.
+pub fn env_var<T>(
+    var: &'static str,
+    default: Option<&'static str>,
+) -> Result<T, EnvVarError>
+where
+    T: FromStr,
+    T::Err: StdError + Send + Sync + 'static,
+{
+    let value = match env::var(var) {
+        Ok(value) => value,
+        Err(env::VarError::NotPresent) => {
+            default.ok_or(EnvVarError::Missing { var })?.to_owned()
+        }
+        Err(env::VarError::NotUnicode(_)) => {
+            return Err(EnvVarError::NotUnicode { var });
+        }
+    };
+    value.parse().map_err(|e| EnvVarError::Invalid {
+        var,
+        value,
+        source: Box::new(e),
+    })
+}
+
+#[cfg(test)]
+mod spec {
+    use std::error::Error as _;
+
+    use super::{EnvVarError, env_var};
+
+    const UNSET: &str = "CUCUMBER_CODEGEN_SPEC_UNSET_VAR";
+
+    #[test]
+    fn falls_back_to_default() {
+        assert_eq!(env_var::<u16>(UNSET, Some("8080")).unwrap(), 8080);
+    }
+
+    #[test]
+    fn reads_set_var() {
+        assert!(!env_var::<String>("PATH", None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors_on_missing_var() {
+        let err = env_var::<u16>(UNSET, None).unwrap_err();
+
+        assert!(matches!(err, EnvVarError::Missing { var: UNSET }));
+        assert_eq!(
+            err.to_string(),
+            "environment variable `CUCUMBER_CODEGEN_SPEC_UNSET_VAR` is not set",
+        );
+    }
+
+    #[test]
+    fn errors_on_unparsable_value() {
+        let err = env_var::<u16>(UNSET, Some("eighty")).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "cannot parse `eighty` value of \
+             `CUCUMBER_CODEGEN_SPEC_UNSET_VAR` environment variable",
+        );
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "invalid digit found in string",
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversion of errors returned from [`given`], [`when`] and [`then`] step
+//! functions into a [`step::Failure`].
+//!
+//! [`given`]: crate::given
+//! [`then`]: crate::then
+//! [`when`]: crate::when
+
+use std::{error::Error as StdError, fmt::Display};
+
+use itertools::Itertools as _;
+
+use crate::step;
+
+/// Error returned from a step function, to be converted into a
+/// [`step::Failure`] via [autoref-based specialization][0].
+///
+/// Errors convertible into a [`Box`]`<dyn `[`Error`]`>` (like
+/// [`anyhow::Error`] or `eyre::Report`) are rendered with their whole
+/// [`source()`] chain, while any other [`Display`]able ones via their
+/// [`Display`] implementation only.
+///
+/// ```rust
+/// # use cucumber::codegen::{DisplayFailure as _, ErrorChainFailure as _};
+/// #
+/// use anyhow::Context as _;
+/// use cucumber::codegen::Failing;
+///
+/// let err = "five".parse::<u8>().context("invalid count").unwrap_err();
+/// assert_eq!(
+///     Failing(err).into_failure().to_string(),
+///     "invalid count: invalid digit found in string",
+/// );
+/// ```
+///
+/// [`Error`]: StdError
+/// [`source()`]: StdError::source
+/// [0]: https://github.com/dtolnay/case-studies/blob/master/autoref-specialization/README.md
+#[derive(Clone, Copy, Debug)]
+pub struct Failing<E>(pub E);
+
+/// Conversion of a [`Failing`] error convertible into a
+/// [`Box`]`<dyn `[`Error`]`>` into a [`step::Failure`], rendering its whole
+/// [`source()`] chain.
+///
+/// [`Error`]: StdError
+/// [`source()`]: StdError::source
+pub trait ErrorChainFailure {
+    /// Converts this error into a [`step::Failure`].
+    fn into_failure(self) -> step::Failure;
+}
+
+impl<E: Into<Box<dyn StdError>>> ErrorChainFailure for Failing<E> {
+    fn into_failure(self) -> step::Failure {
+        let err: Box<dyn StdError> = self.0.into();
+        let chain =
+            itertools::iterate(Some(&*err), |e| e.and_then(|e| e.source()))
+                .while_some()
+                .join(": ");
+        step::Failure(chain)
+    }
+}
+
+/// Conversion of a [`Failing`] [`Display`]able error into a [`step::Failure`].
+pub trait DisplayFailure {
+    /// Converts this error into a [`step::Failure`].
+    fn into_failure(self) -> step::Failure;
+}
+
+impl<E: Display> DisplayFailure for &Failing<E> {
+    fn into_failure(self) -> step::Failure {
+        step::Failure(self.0.to_string())
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use std::fmt;
+
+    use anyhow::Context as _;
+
+    use super::{DisplayFailure as _, ErrorChainFailure as _, Failing};
+
+    #[derive(Debug)]
+    struct NotAnError;
+
+    impl fmt::Display for NotAnError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "not an error")
+        }
+    }
+
+    #[test]
+    fn renders_error_chain() {
+        let err = "five"
+            .parse::<u8>()
+            .context("invalid count")
+            .context("cannot feed the cat")
+            .unwrap_err();
+
+        assert_eq!(
+            Failing(err).into_failure().0,
+            "cannot feed the cat: invalid count: invalid digit found in string",
+        );
+        assert_eq!(Failing("plain").into_failure().0, "plain");
+    }
+
+    #[test]
+    fn renders_displayable() {
+        assert_eq!(Failing(NotAnError).into_failure().0, "not an error");
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Registration of [`crate::step::Step`]s generated by [`given`], [`when`]
+//! and [`then`] attributes into a [`Collection`].
+//!
+//! [`given`]: crate::given
+//! [`when`]: crate::when
+//! [`then`]: crate::then
+
+use gherkin::{StepType, tagexpr::TagOperation};
+
+use super::{LazyRegex, StepConstructor};
+use crate::step::{self, Collection, Function, collection};
+
+/// [`StepConstructor`] of a [`crate::step::Step`] function generic over its
+/// [`World`].
+///
+/// It's registered into a [`Collection`] explicitly via the generated
+/// `register()` function, rather than collected via [`inventory`].
+///
+/// [`World`]: crate::World
+#[derive(Debug)]
+pub struct GenericStep<W> {
+    /// [`step::Location`] of the [`crate::step::Step`] function.
+    pub loc: step::Location,
+
+    /// [`LazyRegex`] of the [`crate::step::Step`] function.
+    pub regex: LazyRegex,
+
+    /// [`crate::step::Step`] function itself.
+    pub func: Function<W>,
+
+    /// Priority of the [`crate::step::Step`] function.
+    pub priority: i32,
+
+    /// Tag expression a [`gherkin::Scenario`] should match for the
+    /// [`crate::step::Step`] function to be considered.
+    pub tags: Option<&'static str>,
+
+    /// Indicator whether the [`gherkin::Step`] text is normalized before
+    /// matching.
+    pub normalize: bool,
+
+    /// Doc comment of the [`crate::step::Step`] function.
+    pub doc: Option<&'static str>,
+}
+
+impl<W> StepConstructor<W> for GenericStep<W> {
+    fn inner(&self) -> (step::Location, LazyRegex, Function<W>) {
+        (self.loc, self.regex, self.func)
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    fn tags(&self) -> Option<&'static str> {
+        self.tags
+    }
+
+    fn normalize(&self) -> bool {
+        self.normalize
+    }
+
+    fn doc(&self) -> Option<&'static str> {
+        self.doc
+    }
+}
+
+/// Registers the provided [`StepConstructor`] of the provided [`StepType`]
+/// into the provided [`Collection`].
+///
+/// # Panics
+///
+/// If the `tags` expression of the attribute is invalid.
+#[must_use]
+pub fn register<W>(
+    collection: Collection<W>,
+    ty: StepType,
+    step: &impl StepConstructor<W>,
+) -> Collection<W> {
+    let (loc, regex, func) = step.inner();
+    collection.define(ty, Some(loc), [regex()], options(step), func)
+}
+
+/// Returns [`collection::Options`] of the provided [`crate::step::Step`]
+/// attribute.
+///
+/// # Panics
+///
+/// If the `tags` expression of the attribute is invalid.
+fn options<W>(step: &impl StepConstructor<W>) -> collection::Options {
+    let tags = step.tags().map(|expr| {
+        expr.parse::<TagOperation>().unwrap_or_else(|e| {
+            panic!("invalid step `tags = \"{expr}\"`: {e}")
+        })
+    });
+    collection::Options {
+        priority: step.priority(),
+        tags,
+        normalize: step.normalize(),
+        doc: step.doc(),
+    }
+}
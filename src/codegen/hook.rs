@@ -0,0 +1,207 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`gherkin::Scenario`] hooks [`submit`]ted by [`before`] and [`after`]
+//! attributes.
+//!
+//! [`after`]: crate::after
+//! [`before`]: crate::before
+//! [`submit`]: inventory::submit
+
+use std::any::{Any, TypeId};
+
+use futures::future::LocalBoxFuture;
+use gherkin::tagexpr::TagOperation;
+use itertools::Itertools as _;
+
+use crate::{World, event, step, tag::Tags};
+
+/// Type-erased [`before`] hook function, accepting a `World` to be downcast.
+///
+/// [`before`]: crate::before
+pub type BeforeFn = for<'a> fn(
+    &'a gherkin::Feature,
+    Option<&'a gherkin::Rule>,
+    &'a gherkin::Scenario,
+    &'a mut dyn Any,
+) -> LocalBoxFuture<'a, ()>;
+
+/// Type-erased [`after`] hook function, accepting a `World` to be downcast.
+///
+/// [`after`]: crate::after
+pub type AfterFn = for<'a> fn(
+    &'a gherkin::Feature,
+    Option<&'a gherkin::Rule>,
+    &'a gherkin::Scenario,
+    &'a event::ScenarioFinished,
+    Option<&'a mut dyn Any>,
+) -> LocalBoxFuture<'a, ()>;
+
+/// Function of a [`Hook`].
+#[derive(Clone, Copy, Debug)]
+enum HookFn {
+    /// [`BeforeFn`] of a [`before`] hook.
+    ///
+    /// [`before`]: crate::before
+    Before(BeforeFn),
+
+    /// [`AfterFn`] of an [`after`] hook.
+    ///
+    /// [`after`]: crate::after
+    After(AfterFn),
+}
+
+/// [`gherkin::Scenario`] hook [`submit`]ted by a [`before`] or [`after`]
+/// attribute.
+///
+/// [`after`]: crate::after
+/// [`before`]: crate::before
+/// [`submit`]: inventory::submit
+#[derive(Clone, Copy, Debug)]
+pub struct Hook {
+    /// [`TypeId`] of the `World` this [`Hook`] is run for.
+    world: fn() -> TypeId,
+
+    /// [`step::Location`] of the [`Hook`] function, ordering the [`Hook`]s.
+    loc: step::Location,
+
+    /// Tag expression a [`gherkin::Scenario`] should match for this [`Hook`]
+    /// to be run.
+    tags: Option<&'static str>,
+
+    /// Function of this [`Hook`].
+    func: HookFn,
+}
+
+inventory::collect!(Hook);
+
+impl Hook {
+    /// Creates a new [`before`] [`Hook`] of the `World`.
+    ///
+    /// [`before`]: crate::before
+    #[must_use]
+    pub const fn before<W: 'static>(
+        loc: step::Location,
+        tags: Option<&'static str>,
+        func: BeforeFn,
+    ) -> Self {
+        Self { world: TypeId::of::<W>, loc, tags, func: HookFn::Before(func) }
+    }
+
+    /// Creates a new [`after`] [`Hook`] of the `World`.
+    ///
+    /// [`after`]: crate::after
+    #[must_use]
+    pub const fn after<W: 'static>(
+        loc: step::Location,
+        tags: Option<&'static str>,
+        func: AfterFn,
+    ) -> Self {
+        Self { world: TypeId::of::<W>, loc, tags, func: HookFn::After(func) }
+    }
+
+    /// Indicates whether any [`before`] [`Hook`] of the `World` is submitted.
+    ///
+    /// [`before`]: crate::before
+    #[must_use]
+    pub fn any_before<W: World>() -> bool {
+        Self::of::<W>().any(|h| matches!(h.func, HookFn::Before(_)))
+    }
+
+    /// Indicates whether any [`after`] [`Hook`] of the `World` is submitted.
+    ///
+    /// [`after`]: crate::after
+    #[must_use]
+    pub fn any_after<W: World>() -> bool {
+        Self::of::<W>().any(|h| matches!(h.func, HookFn::After(_)))
+    }
+
+    /// Returns all the [`Hook`]s of the `World`, ordered by their
+    /// [`step::Location`]s.
+    fn of<W: World>() -> impl Iterator<Item = &'static Self> {
+        inventory::iter::<Self>
+            .into_iter()
+            .filter(|h| (h.world)() == TypeId::of::<W>())
+            .sorted_by_key(|h| h.loc)
+    }
+
+    /// Indicates whether this [`Hook`] should be run for a
+    /// [`gherkin::Scenario`] with the provided [`Tags`].
+    ///
+    /// # Panics
+    ///
+    /// If the `tags` expression of this [`Hook`] is invalid.
+    fn matches(&self, tags: &Tags) -> bool {
+        self.tags.is_none_or(|expr| {
+            let op = expr.parse::<TagOperation>().unwrap_or_else(|e| {
+                panic!("invalid hook `tags = \"{expr}\"`: {e}")
+            });
+            tags.matches(&op)
+        })
+    }
+}
+
+/// Runs all the [`before`] hooks of the `World` matching the
+/// [`gherkin::Scenario`], in the order of their [`step::Location`]s.
+///
+/// Set by [`World::cucumber()`] as the [`Cucumber::before()`] hook, so may be
+/// called from a custom one to keep the [`before`] hooks running.
+///
+/// [`Cucumber::before()`]: crate::Cucumber::before
+/// [`World::cucumber()`]: crate::World::cucumber
+/// [`before`]: crate::before
+#[must_use]
+pub fn run_before_hooks<'a, W: World>(
+    feature: &'a gherkin::Feature,
+    rule: Option<&'a gherkin::Rule>,
+    scenario: &'a gherkin::Scenario,
+    world: &'a mut W,
+) -> LocalBoxFuture<'a, ()> {
+    let tags = Tags::of(feature, rule, scenario);
+    Box::pin(async move {
+        for hook in Hook::of::<W>().filter(|h| h.matches(&tags)) {
+            if let HookFn::Before(f) = hook.func {
+                f(feature, rule, scenario, &mut *world).await;
+            }
+        }
+    })
+}
+
+/// Runs all the [`after`] hooks of the `World` matching the
+/// [`gherkin::Scenario`], in the reverse order of their [`step::Location`]s.
+///
+/// Set by [`World::cucumber()`] as the [`Cucumber::after()`] hook, so may be
+/// called from a custom one to keep the [`after`] hooks running.
+///
+/// [`Cucumber::after()`]: crate::Cucumber::after
+/// [`World::cucumber()`]: crate::World::cucumber
+/// [`after`]: crate::after
+#[must_use]
+pub fn run_after_hooks<'a, W: World>(
+    feature: &'a gherkin::Feature,
+    rule: Option<&'a gherkin::Rule>,
+    scenario: &'a gherkin::Scenario,
+    finished: &'a event::ScenarioFinished,
+    mut world: Option<&'a mut W>,
+) -> LocalBoxFuture<'a, ()> {
+    let tags = Tags::of(feature, rule, scenario);
+    Box::pin(async move {
+        let hooks = Hook::of::<W>().filter(|h| h.matches(&tags)).collect_vec();
+        for hook in hooks.into_iter().rev() {
+            if let HookFn::After(f) = hook.func {
+                let world = world.as_deref_mut().map(|w| {
+                    let w: &mut dyn Any = w;
+                    w
+                });
+                f(feature, rule, scenario, finished, world).await;
+            }
+        }
+    })
+}
@@ -10,6 +10,12 @@
 
 //! Helper type-level glue for [`cucumber_codegen`] crate.
 
+mod arg;
+mod env;
+mod failure;
+mod generic;
+mod hook;
+
 use std::convert::Infallible;
 
 pub use anyhow;
@@ -21,7 +27,15 @@ pub use futures::future::LocalBoxFuture;
 pub use inventory::{self, collect, submit};
 pub use regex::Regex;
 
-use crate::{Step, World, step};
+pub use self::{
+    arg::{ArgParsing, DebugArgError, DisplayArgError},
+    env::{EnvVarError, env_var},
+    failure::{DisplayFailure, ErrorChainFailure, Failing},
+    generic::{GenericStep, register},
+    hook::{AfterFn, BeforeFn, Hook, run_after_hooks, run_before_hooks},
+};
+
+use crate::{World, step};
 
 /// [`crate::World`] extension allowing to register steps in [`inventory`].
 pub trait WorldInventory: World {
@@ -51,8 +65,32 @@ pub type LazyRegex = fn() -> Regex;
 /// [`when`]: crate::when
 /// [`then`]: crate::then
 pub trait StepConstructor<W> {
-    /// Returns an inner [`crate::step::Step`] with the corresponding [`regex::Regex`].
-    fn inner(&self) -> (step::Location, LazyRegex, Step<W>);
+    /// Returns an inner [`step::Function`] with the corresponding
+    /// [`regex::Regex`].
+    fn inner(&self) -> (step::Location, LazyRegex, step::Function<W>);
+
+    /// Returns a priority of an inner [`crate::step::Step`] resolving an
+    /// ambiguity in its favor.
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Returns a tag expression a [`gherkin::Scenario`] should match for an
+    /// inner [`crate::step::Step`] to be considered.
+    fn tags(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Indicates whether an inner [`crate::step::Step`] matches the
+    /// [`gherkin::Step`] text ignoring its case and extra whitespaces.
+    fn normalize(&self) -> bool {
+        false
+    }
+
+    /// Returns a doc comment of an inner [`crate::step::Step`] function.
+    fn doc(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 /// Custom parameter of a [Cucumber Expression].
@@ -234,3 +272,40 @@ impl<W: World, E> IntoWorldResult for Result<W, E> {
         self
     }
 }
+
+/// Return-type polymorphism over fallibility for a `#[world(teardown)]`
+/// attribute of a [`#[derive(World)]`](macro@World) macro.
+///
+/// It allows to accept both fallible (returning [`Result`]) and infallible
+/// functions as an attribute's argument, by automatically wrapping `()` in a
+/// [`Result`]`<(), `[`Infallible`]`>`.
+pub trait IntoTeardownResult: Sized {
+    /// Error returned by this [`crate::World`] teardown.
+    ///
+    /// Set to [`Infallible`] in case teardown is infallible.
+    type Error;
+
+    /// Passes [`Result`]`<(), Self::Error>` as is, or wraps `()` in a
+    /// [`Result`]`<(), `[`Infallible`]`>`.
+    ///
+    /// # Errors
+    ///
+    /// In case the [`crate::World`] teardown errors.
+    fn into_teardown_result(self) -> Result<(), Self::Error>;
+}
+
+impl IntoTeardownResult for () {
+    type Error = Infallible;
+
+    fn into_teardown_result(self) -> Result<(), Self::Error> {
+        Ok(self)
+    }
+}
+
+impl<E> IntoTeardownResult for Result<(), E> {
+    type Error = E;
+
+    fn into_teardown_result(self) -> Self {
+        self
+    }
+}
@@ -0,0 +1,118 @@
+//! Running methods of Cucumber executor blocking the current thread.
+
+use futures::future::LocalBoxFuture;
+
+use super::core::Cucumber;
+use crate::{
+    Parser, ScenarioType, World, Writer, event::ScenarioFinished, runner,
+    runtime, writer,
+};
+
+impl<W, I, P, Wr, F, B, A, Cli>
+    Cucumber<W, P, I, runner::Basic<W, F, B, A>, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    Wr: Writer<W> + writer::Normalized,
+    Cli: clap::Args + Send + Sync + 'static,
+    F: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> ScenarioType
+        + 'static,
+    B: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    A: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+{
+    /// Runs [`Cucumber`] on the current thread, blocking it until finished.
+    ///
+    /// Neither requires an async runtime, nor spawns any threads (unless
+    /// [`Cucumber::resource_sampling()`] is enabled): the
+    /// [`gherkin::Scenario`]s are still run concurrently, but driven by the
+    /// [`runtime::block_on()`] executor, while step timeouts, heartbeats and
+    /// retry delays are measured by [`runtime::Blocking`] timers (overriding
+    /// the one set via [`Cucumber::runtime()`]).
+    ///
+    /// Async steps should, therefore, await only runtime-agnostic
+    /// [`Future`]s (like the ones of [`futures`] crate), rather than
+    /// [`Future`]s requiring a specific async runtime (like `tokio` timers).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # fn main() {
+    /// MyWorld::cucumber().run_blocking("tests/features/readme");
+    /// # }
+    /// ```
+    pub fn run_blocking(self, input: I) -> Wr {
+        runtime::block_on(self.runtime(runtime::Blocking).run(input))
+    }
+}
+
+impl<W, I, P, Wr, F, B, A, Cli>
+    Cucumber<W, P, I, runner::Basic<W, F, B, A>, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    Wr: writer::Stats<W> + writer::Normalized,
+    Cli: clap::Args + Send + Sync + 'static,
+    F: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> ScenarioType
+        + 'static,
+    B: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    A: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+{
+    /// Runs [`Cucumber`] on the current thread, blocking it until finished,
+    /// and exits the process on failures.
+    ///
+    /// See [`Cucumber::run_blocking()`] and [`Cucumber::run_and_exit()`] for
+    /// details.
+    ///
+    /// # Panics
+    ///
+    /// If encountered errors while parsing [`Feature`]s or at least one
+    /// [`crate::step::Step`] [`Failed`] (or is undefined or pending, in case
+    /// [`Cucumber::strict()`] is used), exiting with the code configured via
+    /// [`Cucumber::exit_codes()`].
+    ///
+    /// [`Failed`]: crate::event::Step::Failed
+    /// [`Feature`]: gherkin::Feature
+    /// [`crate::step::Step`]: gherkin::Step
+    pub fn run_and_exit_blocking(self, input: I) {
+        runtime::block_on(self.runtime(runtime::Blocking).run_and_exit(input));
+    }
+}
@@ -85,12 +85,14 @@ where
     where
         CustomCli: clap::Args,
     {
-        let Self { parser, runner, writer, .. } = self;
+        let Self { parser, runner, writer, strict, exit_codes, .. } = self;
         Cucumber {
             parser,
             runner,
             writer,
             cli: Some(cli),
+            strict,
+            exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
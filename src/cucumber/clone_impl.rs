@@ -24,6 +24,8 @@ where
             runner: self.runner.clone(),
             writer: self.writer.clone(),
             cli: self.cli.clone(),
+            strict: self.strict,
+            exit_codes: self.exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -4,7 +4,7 @@ use std::marker::PhantomData;
 
 use derive_more::with_trait::Debug;
 
-use crate::{Parser, Runner, World, Writer, cli};
+use crate::{ExitCodes, Parser, Runner, World, Writer, cli};
 
 /// Top-level [Cucumber] executor.
 ///
@@ -49,6 +49,17 @@ where
     /// If empty, then will be parsed from a command line.
     pub(super) cli: Option<cli::Opts<P::Cli, R::Cli, Wr::Cli, Cli>>,
 
+    /// Indicates whether undefined and pending [`crate::step::Step`]s should
+    /// fail the run.
+    ///
+    /// See [`Cucumber::strict()`] for details.
+    pub(super) strict: bool,
+
+    /// [`ExitCodes`] of unsuccessful run outcomes.
+    ///
+    /// See [`Cucumber::exit_codes()`] for details.
+    pub(super) exit_codes: ExitCodes,
+
     /// Type of the [`crate::World`] this [`Cucumber`] run on.
     #[debug(ignore)]
     pub(super) _world: PhantomData<W>,
@@ -75,6 +86,8 @@ where
             runner,
             writer,
             cli: None,
+            strict: false,
+            exit_codes: ExitCodes::new(),
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -89,12 +102,14 @@ where
     where
         NewP: Parser<NewI>,
     {
-        let Self { runner, writer, .. } = self;
+        let Self { runner, writer, strict, exit_codes, .. } = self;
         Cucumber {
             parser,
             runner,
             writer,
             cli: None,
+            strict,
+            exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -109,12 +124,14 @@ where
     where
         NewR: Runner<W>,
     {
-        let Self { parser, writer, .. } = self;
+        let Self { parser, writer, strict, exit_codes, .. } = self;
         Cucumber {
             parser,
             runner,
             writer,
             cli: None,
+            strict,
+            exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -129,12 +146,14 @@ where
     where
         NewWr: Writer<W>,
     {
-        let Self { parser, runner, .. } = self;
+        let Self { parser, runner, strict, exit_codes, .. } = self;
         Cucumber {
             parser,
             runner,
             writer,
             cli: None,
+            strict,
+            exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
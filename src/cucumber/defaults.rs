@@ -1,11 +1,9 @@
 //! Default implementations for Cucumber executor.
 
-use std::path::Path;
-
 use derive_more::with_trait::Debug;
 
 use super::core::Cucumber;
-use crate::{World, parser, runner, writer, writer::Ext as _};
+use crate::{Parser, World, parser, runner, writer, writer::Ext as _};
 
 /// Shortcut for the [`Cucumber`] type returned by its [`Default`] impl.
 pub type DefaultCucumber<W, I> = Cucumber<
@@ -19,7 +17,7 @@ pub type DefaultCucumber<W, I> = Cucumber<
 impl<W, I> Default for DefaultCucumber<W, I>
 where
     W: World + Debug,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     fn default() -> Self {
         Self::custom(
@@ -33,7 +31,7 @@ where
 impl<W, I> DefaultCucumber<W, I>
 where
     W: World + Debug,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     /// Creates a default [`Cucumber`] executor.
     ///
@@ -1,11 +1,14 @@
 //! Execution methods for Cucumber executor.
 
-use std::mem;
+use std::{mem, pin::pin};
 
 use futures::StreamExt as _;
 
 use super::core::Cucumber;
-use crate::{Parser, Runner, World, Writer, cli, tag::Ext as _, writer};
+use crate::{
+    Parser, Runner, World, Writer, cli, cli::custom::Scoped,
+    scenario::Ext as _, tag::Ext as _, writer,
+};
 
 impl<W, P, I, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
 where
@@ -13,7 +16,7 @@ where
     P: Parser<I>,
     R: Runner<W>,
     Wr: Writer<W> + writer::Normalized,
-    Cli: clap::Args,
+    Cli: clap::Args + Send + Sync + 'static,
 {
     /// Runs [`Cucumber`].
     ///
@@ -69,8 +72,30 @@ where
     ///     async data-autoplay="true" data-rows="14">
     /// </script>
     ///
+    /// Individual [`Examples`] rows of a [`Scenario Outline`] may be selected
+    /// via [`scenario::Ext::example_row()`], as the filter receives already
+    /// expanded [`gherkin::Scenario`]s:
+    /// ```rust
+    /// # use cucumber::{World, scenario::Ext as _};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .filter_run("tests/features/readme", |_, _, sc| {
+    ///         sc.example_row().is_none_or(|row| row.index == 0)
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Examples`]: gherkin::Examples
     /// [`Feature`]: gherkin::Feature
+    /// [`Scenario Outline`]: https://cucumber.io/docs/gherkin/reference#scenario-outline
     /// [`gherkin::Scenario`]: gherkin::Scenario
+    /// [`scenario::Ext::example_row()`]: crate::scenario::Ext::example_row
     pub async fn filter_run<F>(self, input: I, filter: F) -> Wr
     where
         F: Fn(
@@ -83,9 +108,18 @@ where
         let cli::Opts {
             re_filter,
             tags_filter,
+            lint: lint_cli,
+            format: format_cli,
+            validate: validate_cli,
+            list: listing_cli,
+            #[cfg(feature = "steps-index")]
+            steps_index: index_cli,
+            #[cfg(feature = "failures-file")]
+            failures: failures_cli,
             parser: parser_cli,
             runner: runner_cli,
             writer: writer_cli,
+            custom,
             ..
         } = self.cli.unwrap_or_else(cli::Opts::<_, _, _, _>::parsed);
 
@@ -108,160 +142,98 @@ where
                         },
                     )
                 },
-                |re| re.is_match(&scenario.name),
+                |re| {
+                    re.is_match(&scenario.name)
+                        || scenario
+                            .example_row()
+                            .is_some_and(|row| re.is_match(&row.to_string()))
+                },
             )
         };
 
         let Self { parser, runner, mut writer, .. } = self;
 
-        let features = parser.parse(input, parser_cli);
-
-        let filtered = features.map(move |feature| {
-            let mut feature = feature?;
-            let feat_scenarios = mem::take(&mut feature.scenarios);
-            feature.scenarios = feat_scenarios
-                .into_iter()
-                .filter(|s| filter(&feature, None, s))
-                .collect();
-
-            let mut rules = mem::take(&mut feature.rules);
-            for r in &mut rules {
-                let rule_scenarios = mem::take(&mut r.scenarios);
-                r.scenarios = rule_scenarios
-                    .into_iter()
-                    .filter(|s| filter(&feature, Some(r), s))
-                    .collect();
-            }
-            feature.rules = rules;
+        #[cfg(feature = "steps-index")]
+        if index_cli.steps_index {
+            return super::step_index::print_and_exit(runner.step_collection());
+        }
 
-            Ok(feature)
-        });
+        let features = parser.parse(input, parser_cli);
+        if lint_cli.lint {
+            return super::lint::run_and_exit(features, &lint_cli).await;
+        }
+        if format_cli.is_requested() {
+            return super::format::run_and_exit(features, format_cli).await;
+        }
+        if validate_cli.validate {
+            let steps = runner.step_collection();
+            return super::validate::run_and_exit(features, steps).await;
+        }
+        #[cfg(feature = "steps-index")]
+        let (features, index_file) = super::step_index::IndexFile::tap(
+            index_cli,
+            runner.step_collection(),
+            features,
+        );
+
+        let filtered = features.map(move |f| Ok(filter_scenarios(f?, &filter)));
+        if listing_cli.list_scenarios {
+            return super::list::run_and_exit(filtered).await;
+        }
 
         let events_stream = runner.run(filtered, runner_cli);
-        futures::pin_mut!(events_stream);
+        let mut events_stream = pin!(Scoped::new(events_stream, custom));
+        #[cfg(feature = "timestamps")]
+        let mut run_started = None;
+        #[cfg(feature = "failures-file")]
+        let mut failures = super::failures::ReportFile::new(failures_cli);
         while let Some(ev) = events_stream.next().await {
+            // Makes the `Event::offset`s relative to the start of the run.
+            #[cfg(feature = "timestamps")]
+            let ev = ev.map(|ev| {
+                let origin = *run_started.get_or_insert(ev.offset);
+                ev.rebase(origin)
+            });
+            #[cfg(feature = "failures-file")]
+            failures.handle_event(&ev);
             writer.handle_event(ev, &writer_cli).await;
         }
+        #[cfg(feature = "failures-file")]
+        failures.save();
+        #[cfg(feature = "steps-index")]
+        index_file.save();
         writer
     }
 }
 
-impl<W, I, P, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
+/// Leaves only the [`gherkin::Scenario`]s of the provided [`gherkin::Feature`]
+/// (and its [`gherkin::Rule`]s) satisfying the provided `filter`.
+fn filter_scenarios<F>(
+    mut feature: gherkin::Feature,
+    filter: &F,
+) -> gherkin::Feature
 where
-    W: World,
-    P: Parser<I>,
-    R: Runner<W>,
-    Wr: writer::Stats<W> + writer::Normalized,
-    Cli: clap::Args,
+    F: Fn(
+        &gherkin::Feature,
+        Option<&gherkin::Rule>,
+        &gherkin::Scenario,
+    ) -> bool,
 {
-    /// Runs [`Cucumber`].
-    ///
-    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`crate::runner::Runner`], which
-    /// produces events handled by a [`crate::Writer`].
-    ///
-    /// # Panics
-    ///
-    /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`crate::step::Step`] [`Failed`].
-    ///
-    /// [`Failed`]: crate::event::Step::Failed
-    /// [`Feature`]: gherkin::Feature
-    /// [`crate::step::Step`]: gherkin::Step
-    pub async fn run_and_exit(self, input: I) {
-        self.filter_run_and_exit(input, |_, _, _| true).await;
+    let feat_scenarios = mem::take(&mut feature.scenarios);
+    feature.scenarios = feat_scenarios
+        .into_iter()
+        .filter(|s| filter(&feature, None, s))
+        .collect();
+
+    let mut rules = mem::take(&mut feature.rules);
+    for r in &mut rules {
+        let rule_scenarios = mem::take(&mut r.scenarios);
+        r.scenarios = rule_scenarios
+            .into_iter()
+            .filter(|s| filter(&feature, Some(r), s))
+            .collect();
     }
+    feature.rules = rules;
 
-    /// Runs [`Cucumber`] with [`gherkin::Scenario`]s filter.
-    ///
-    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`crate::runner::Runner`], which
-    /// produces events handled by a [`crate::Writer`].
-    ///
-    /// # Panics
-    ///
-    /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`crate::step::Step`] [`Failed`].
-    ///
-    /// # Example
-    ///
-    /// Adjust [`Cucumber`] to run only [`gherkin::Scenario`]s marked with `@cat` tag:
-    /// ```rust
-    /// # use cucumber::World;
-    /// #
-    /// # #[derive(Debug, Default, World)]
-    /// # struct MyWorld;
-    /// #
-    /// # #[tokio::main(flavor = "current_thread")]
-    /// # async fn main() {
-    /// MyWorld::cucumber()
-    ///     .filter_run_and_exit("tests/features/readme", |_, _, sc| {
-    ///         sc.tags.iter().any(|t| t == "cat")
-    ///     })
-    ///     .await;
-    /// # }
-    /// ```
-    /// ```gherkin
-    /// Feature: Animal feature
-    ///
-    ///   @cat
-    ///   Scenario: If we feed a hungry cat it will no longer be hungry
-    ///     Given a hungry cat
-    ///     When I feed the cat
-    ///     Then the cat is not hungry
-    ///
-    ///   @dog
-    ///   Scenario: If we feed a satiated dog it will not become hungry
-    ///     Given a satiated dog
-    ///     When I feed the dog
-    ///     Then the dog is not hungry
-    /// ```
-    /// <script
-    ///     id="asciicast-0KvTxnfaMRjsvsIKsalS611Ta"
-    ///     src="https://asciinema.org/a/0KvTxnfaMRjsvsIKsalS611Ta.js"
-    ///     async data-autoplay="true" data-rows="14">
-    /// </script>
-    ///
-    /// [`Failed`]: crate::event::Step::Failed
-    /// [`Feature`]: gherkin::Feature
-    /// [`gherkin::Scenario`]: gherkin::Scenario
-    pub async fn filter_run_and_exit<Filter>(self, input: I, filter: Filter)
-    where
-        Filter: Fn(
-                &gherkin::Feature,
-                Option<&gherkin::Rule>,
-                &gherkin::Scenario,
-            ) -> bool
-            + 'static,
-    {
-        let writer = self.filter_run(input, filter).await;
-        if writer.execution_has_failed() {
-            let mut msg = Vec::with_capacity(3);
-
-            let failed_steps = writer.failed_steps();
-            if failed_steps > 0 {
-                msg.push(format!(
-                    "{failed_steps} step{} failed",
-                    if failed_steps > 1 { "s" } else { "" },
-                ));
-            }
-
-            let parsing_errors = writer.parsing_errors();
-            if parsing_errors > 0 {
-                msg.push(format!(
-                    "{parsing_errors} parsing error{}",
-                    if parsing_errors > 1 { "s" } else { "" },
-                ));
-            }
-
-            let hook_errors = writer.hook_errors();
-            if hook_errors > 0 {
-                msg.push(format!(
-                    "{hook_errors} hook error{}",
-                    if hook_errors > 1 { "s" } else { "" },
-                ));
-            }
-
-            eprintln!("{}", msg.join(", "));
-            std::process::exit(1);
-        }
-    }
+    feature
 }
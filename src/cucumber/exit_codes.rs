@@ -0,0 +1,49 @@
+//! Exit codes configuration of Cucumber executor.
+
+use super::core::Cucumber;
+use crate::{ExitCodes, Parser, Runner, World, Writer};
+
+impl<W, P, I, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    R: Runner<W>,
+    Wr: Writer<W>,
+    Cli: clap::Args,
+{
+    /// Sets the [`ExitCodes`] [`Cucumber::run_and_exit()`] exits the process
+    /// with, depending on the [`Outcome`] of the run, so CI pipelines may
+    /// branch on the kind of failure.
+    ///
+    /// Each of them may be overridden by specifying the
+    /// `--exit-code <outcome>=<code>` CLI option.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{ExitCodes, World, exit_code::Outcome};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .exit_codes(
+    ///         ExitCodes::new()
+    ///             .with(Outcome::ParsingErrors, 2)
+    ///             .with(Outcome::Undefined, 3)
+    ///             .with(Outcome::TimedOut, 4),
+    ///     )
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Outcome`]: crate::exit_code::Outcome
+    #[must_use]
+    pub const fn exit_codes(mut self, codes: ExitCodes) -> Self {
+        self.exit_codes = codes;
+        self
+    }
+}
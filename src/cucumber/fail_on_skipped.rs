@@ -79,6 +79,8 @@ where
             runner: self.runner,
             writer: self.writer.fail_on_skipped(),
             cli: self.cli,
+            strict: self.strict,
+            exit_codes: self.exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -169,6 +171,8 @@ where
             runner: self.runner,
             writer: self.writer.fail_on_skipped_with(filter),
             cli: self.cli,
+            strict: self.strict,
+            exit_codes: self.exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -0,0 +1,48 @@
+//! Failures report of Cucumber executor.
+
+use std::path::PathBuf;
+
+use crate::{Event, event, failures, parser};
+
+/// Writer of a [`failures::Report`] into the file requested via
+/// [`failures::Cli`], if any.
+#[derive(Debug)]
+pub(super) struct ReportFile(Option<(PathBuf, failures::Collector)>);
+
+impl ReportFile {
+    /// Creates a new [`ReportFile`] out of the provided [`failures::Cli`]
+    /// options.
+    pub(super) fn new(cli: failures::Cli) -> Self {
+        Self(cli.failures_file.map(|p| (p, failures::Collector::new())))
+    }
+
+    /// Collects the provided event into the [`failures::Report`], if it's
+    /// requested.
+    pub(super) fn handle_event<W>(
+        &mut self,
+        ev: &parser::Result<Event<event::Cucumber<W>>>,
+    ) {
+        if let (Some((_, collector)), Ok(ev)) = (&mut self.0, ev) {
+            collector.handle_event(ev);
+        }
+    }
+
+    /// Writes the collected [`failures::Report`] into the requested file, if
+    /// any.
+    ///
+    /// Failing to write it is reported, but doesn't fail the whole run.
+    pub(super) fn save(self) {
+        let Some((path, collector)) = self.0 else {
+            return;
+        };
+        if let Err(e) = collector.into_report().save(&path) {
+            #[expect(clippy::print_stderr, reason = "no other way to report")]
+            {
+                eprintln!(
+                    "Failed to write failures report to `{}`: {e}",
+                    path.display(),
+                );
+            }
+        }
+    }
+}
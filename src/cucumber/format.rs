@@ -0,0 +1,62 @@
+//! Formatting mode of Cucumber executor.
+
+use std::{collections::BTreeSet, fmt, fs, process};
+
+use futures::{Stream, StreamExt as _};
+
+use crate::{event, format, parser};
+
+/// Formats the `.feature` files of the provided parsed `features` instead of
+/// executing them (or checks them being formatted), and exits the process.
+///
+/// Exits with `1` status code if any file fails to be parsed, read or
+/// written, or isn't formatted while checking, or `0` otherwise.
+pub(super) async fn run_and_exit<T>(
+    features: impl Stream<Item = parser::Result<gherkin::Feature>>,
+    cli: format::Cli,
+) -> T {
+    let formatter = format::Formatter::new();
+    let mut failed = false;
+    let mut paths = BTreeSet::new();
+    for feature in features.collect::<Vec<_>>().await {
+        match feature {
+            Ok(f) => paths.extend(f.path),
+            Err(e) => {
+                failed = true;
+                report(&event::ParsingFailed::from(&e));
+            }
+        }
+    }
+
+    for path in paths {
+        let result = if cli.fmt_check {
+            fs::read_to_string(&path).map(|s| formatter.format(&s) != s)
+        } else {
+            formatter.format_file(&path)
+        };
+        match result {
+            Ok(false) => {}
+            Ok(true) => {
+                failed |= cli.fmt_check;
+                report(&path.display());
+            }
+            Err(e) => {
+                failed = true;
+                report(&format_args!("{}: {e}", path.display()));
+            }
+        }
+    }
+
+    #[expect(clippy::exit, reason = "formatting replaces the execution")]
+    process::exit(i32::from(failed));
+}
+
+/// Reports the provided `msg` into [`io::stderr`].
+///
+/// [`io::stderr`]: std::io::stderr
+fn report(msg: &dyn fmt::Display) {
+    #[expect(clippy::print_stderr, reason = "formatting report is the output")]
+    {
+        eprintln!("{msg}");
+    }
+}
@@ -56,12 +56,14 @@ where
             ) -> LocalBoxFuture<'a, ()>
             + 'static,
     {
-        let Self { parser, runner, writer, cli, .. } = self;
+        let Self { parser, runner, writer, cli, strict, exit_codes, .. } = self;
         Cucumber {
             parser,
             runner: runner.before(func),
             writer,
             cli,
+            strict,
+            exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -93,12 +95,14 @@ where
             ) -> LocalBoxFuture<'a, ()>
             + 'static,
     {
-        let Self { parser, runner, writer, cli, .. } = self;
+        let Self { parser, runner, writer, cli, strict, exit_codes, .. } = self;
         Cucumber {
             parser,
             runner: runner.after(func),
             writer,
             cli,
+            strict,
+            exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
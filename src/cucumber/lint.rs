@@ -0,0 +1,27 @@
+//! Linting mode of Cucumber executor.
+
+use std::process;
+
+use futures::{Stream, StreamExt as _};
+
+use crate::{lint, parser};
+
+/// Lints the provided parsed `features` instead of executing them, printing
+/// the [`lint::Report`] and exiting the process.
+///
+/// Exits with `1` status code if the [`lint::Report`] contains any
+/// [`lint::Severity::Error`] finding, or `0` otherwise.
+pub(super) async fn run_and_exit<T>(
+    features: impl Stream<Item = parser::Result<gherkin::Feature>>,
+    cli: &lint::Cli,
+) -> T {
+    let features = features.collect::<Vec<_>>().await;
+    let report = cli.linter().lint(features);
+
+    #[expect(clippy::print_stdout, reason = "lint report is the output")]
+    {
+        print!("{}", cli.render(&report));
+    }
+    #[expect(clippy::exit, reason = "linting replaces the execution")]
+    process::exit(i32::from(report.has_errors()));
+}
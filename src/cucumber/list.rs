@@ -0,0 +1,42 @@
+//! Scenarios listing mode of Cucumber executor.
+
+use std::process;
+
+use futures::{Stream, StreamExt as _};
+
+use crate::{event, list, parser};
+
+/// Lists [`gherkin::Scenario`]s of the provided parsed and filtered `features`
+/// instead of executing them, printing the [`list::Listing`] and exiting the
+/// process.
+///
+/// Exits with `1` status code if any of the `features` failed to be parsed,
+/// or `0` otherwise.
+pub(super) async fn run_and_exit<T>(
+    features: impl Stream<Item = parser::Result<gherkin::Feature>>,
+) -> T {
+    let mut parsed = vec![];
+    let mut failed = false;
+    for feature in features.collect::<Vec<_>>().await {
+        match feature {
+            Ok(f) => parsed.push(f),
+            Err(e) => {
+                failed = true;
+                #[expect(
+                    clippy::print_stderr,
+                    reason = "no other way to report"
+                )]
+                {
+                    eprintln!("{}", event::ParsingFailed::from(&e));
+                }
+            }
+        }
+    }
+
+    #[expect(clippy::print_stdout, reason = "listing is the output")]
+    {
+        print!("{}", list::list(&parsed));
+    }
+    #[expect(clippy::exit, reason = "listing replaces the execution")]
+    process::exit(i32::from(failed));
+}
@@ -6,16 +6,33 @@
 pub(crate) mod core;
 
 // Feature modules
+#[cfg(all(
+    feature = "blocking",
+    not(all(target_family = "wasm", target_os = "unknown"))
+))]
+mod blocking;
 mod cli;
 mod clone_impl;
 mod defaults;
 mod execution;
+mod exit_codes;
 mod fail_on_skipped;
+#[cfg(feature = "failures-file")]
+mod failures;
+mod format;
 mod hooks;
+mod lint;
+mod list;
+mod monitoring;
 mod parser;
 mod repeat;
+mod run_and_exit;
 mod runner;
+#[cfg(feature = "steps-index")]
+mod step_index;
 mod steps;
+mod strict;
+mod validate;
 
 // Re-export the main type and public API
 pub use core::Cucumber;
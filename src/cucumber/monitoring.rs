@@ -0,0 +1,85 @@
+//! Monitoring of long-running steps, resources usage and duration SLAs, and
+//! graceful stop of the run for Cucumber executor.
+
+use std::time::Duration;
+
+use futures::future::LocalBoxFuture;
+
+use super::core::Cucumber;
+use crate::{Parser, World, Writer, event, runner};
+
+impl<W, I, P, Wr, F, B, A, Cli>
+    Cucumber<W, P, I, runner::Basic<W, F, B, A>, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    Wr: Writer<W>,
+    Cli: clap::Args,
+    F: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> crate::ScenarioType
+        + 'static,
+    B: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    A: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+{
+    /// Makes every [`crate::step::Step`] running longer than the provided
+    /// `interval` to emit an [`event::Step::Heartbeat`] every `interval`.
+    ///
+    /// See [`runner::Basic::heartbeat()`] for details.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub fn heartbeat(mut self, interval: impl Into<Option<Duration>>) -> Self {
+        self.runner = self.runner.heartbeat(interval);
+        self
+    }
+
+    /// Samples the process resources every `interval` while running each
+    /// [`gherkin::Scenario`], attaching the [`event::ResourceUsage`] to its
+    /// [`event::Scenario::Finished`] event.
+    ///
+    /// See [`runner::Basic::resource_sampling()`] for details.
+    #[must_use]
+    pub fn resource_sampling(
+        mut self,
+        interval: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.runner = self.runner.resource_sampling(interval);
+        self
+    }
+
+    /// Stops the run gracefully once the provided [`runner::basic::StopSignal`]
+    /// is requested.
+    ///
+    /// See [`runner::Basic::stop_signal()`] for details.
+    #[must_use]
+    pub fn stop_signal(mut self, signal: runner::basic::StopSignal) -> Self {
+        self.runner = self.runner.stop_signal(signal);
+        self
+    }
+
+    /// Enforces the provided duration [`runner::basic::Sla`] of
+    /// [`gherkin::Scenario`]s, configured per tag.
+    ///
+    /// See [`runner::Basic::sla()`] for details.
+    #[must_use]
+    pub fn sla(mut self, sla: runner::basic::Sla) -> Self {
+        self.runner = self.runner.sla(sla);
+        self
+    }
+}
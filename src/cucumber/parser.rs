@@ -1,9 +1,9 @@
 //! Parser configuration methods for Cucumber executor.
 
-use std::{borrow::Cow, path::Path};
+use std::borrow::Cow;
 
 use super::core::Cucumber;
-use crate::{Runner, World, Writer, parser};
+use crate::{Parser, Runner, World, Writer, parser};
 
 impl<W, I, R, Wr, Cli> Cucumber<W, parser::Basic, I, R, Wr, Cli>
 where
@@ -11,7 +11,7 @@ where
     R: Runner<W>,
     Wr: Writer<W>,
     Cli: clap::Args,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     /// Sets the provided language of [`gherkin`] files.
     ///
@@ -25,4 +25,16 @@ where
         self.parser = self.parser.language(name)?;
         Ok(self)
     }
+
+    /// Sets the [`Order`] to execute discovered `.feature` files in.
+    ///
+    /// Use [`Order::custom()`] to order them with a custom comparator.
+    ///
+    /// [`Order`]: parser::basic::Order
+    /// [`Order::custom()`]: parser::basic::Order::custom
+    #[must_use]
+    pub fn feature_order(mut self, order: parser::basic::Order) -> Self {
+        self.parser = self.parser.order(order);
+        self
+    }
 }
@@ -61,6 +61,8 @@ where
             runner: self.runner,
             writer: self.writer.repeat_skipped(),
             cli: self.cli,
+            strict: self.strict,
+            exit_codes: self.exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -126,6 +128,8 @@ where
             runner: self.runner,
             writer: self.writer.repeat_failed(),
             cli: self.cli,
+            strict: self.strict,
+            exit_codes: self.exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -169,36 +173,23 @@ where
     /// # async fn main() {
     /// MyWorld::cucumber()
     ///     .repeat_if(|ev| {
-    ///         use cucumber::event::{
-    ///             Cucumber, Feature, RetryableScenario, Rule, Scenario, Step,
-    ///         };
+    ///         use cucumber::event::{Cucumber, Feature, Rule, Scenario, Step};
     ///
-    ///         matches!(
-    ///             ev.as_deref(),
+    ///         let scenario = match ev.as_deref() {
+    ///             Ok(Cucumber::Feature(_, Feature::Scenario(_, sc))) => {
+    ///                 &sc.event
+    ///             }
     ///             Ok(Cucumber::Feature(
     ///                 _,
-    ///                 Feature::Rule(
-    ///                     _,
-    ///                     Rule::Scenario(
-    ///                         _,
-    ///                         RetryableScenario {
-    ///                             event: Scenario::Step(_, Step::Failed { .. })
-    ///                                 | Scenario::Background(
-    ///                                     _,
-    ///                                     Step::Failed { .. },
-    ///                                 ),
-    ///                             retries: _
-    ///                         }
-    ///                     )
-    ///                 ) | Feature::Scenario(
-    ///                     _,
-    ///                     RetryableScenario {
-    ///                         event: Scenario::Step(_, Step::Failed { .. })
-    ///                             | Scenario::Background(_, Step::Failed { .. }),
-    ///                         retries: _
-    ///                     }
-    ///                 )
-    ///             )) | Err(_)
+    ///                 Feature::Rule(_, Rule::Scenario(_, sc)),
+    ///             )) => &sc.event,
+    ///             Ok(_) => return false,
+    ///             Err(_) => return true,
+    ///         };
+    ///         matches!(
+    ///             scenario,
+    ///             Scenario::Step(_, Step::Failed { .. })
+    ///                 | Scenario::Background(_, Step::Failed { .. }),
     ///         )
     ///     })
     ///     .fail_on_skipped()
@@ -227,6 +218,8 @@ where
             runner: self.runner,
             writer: self.writer.repeat_if(filter),
             cli: self.cli,
+            strict: self.strict,
+            exit_codes: self.exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
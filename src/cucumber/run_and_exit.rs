@@ -0,0 +1,177 @@
+//! Running methods of Cucumber executor, exiting the process on failures.
+
+use super::core::Cucumber;
+use crate::{Parser, Runner, World, cli, exit_code, writer};
+
+impl<W, I, P, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    R: Runner<W>,
+    Wr: writer::Stats<W> + writer::Normalized,
+    Cli: clap::Args + Send + Sync + 'static,
+{
+    /// Runs [`Cucumber`].
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`crate::runner::Runner`], which
+    /// produces events handled by a [`crate::Writer`].
+    ///
+    /// # Panics
+    ///
+    /// If encountered errors while parsing [`Feature`]s or at least one
+    /// [`crate::step::Step`] [`Failed`] (or is undefined or pending, in case
+    /// [`Cucumber::strict()`] is used), exiting with the code configured via
+    /// [`Cucumber::exit_codes()`].
+    ///
+    /// [`Failed`]: crate::event::Step::Failed
+    /// [`Feature`]: gherkin::Feature
+    /// [`crate::step::Step`]: gherkin::Step
+    pub async fn run_and_exit(self, input: I) {
+        self.filter_run_and_exit(input, |_, _, _| true).await;
+    }
+
+    /// Runs [`Cucumber`] with [`gherkin::Scenario`]s filter.
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`crate::runner::Runner`], which
+    /// produces events handled by a [`crate::Writer`].
+    ///
+    /// # Panics
+    ///
+    /// If encountered errors while parsing [`Feature`]s or at least one
+    /// [`crate::step::Step`] [`Failed`] (or is undefined or pending, in case
+    /// [`Cucumber::strict()`] is used), exiting with the code configured via
+    /// [`Cucumber::exit_codes()`].
+    ///
+    /// # Example
+    ///
+    /// Adjust [`Cucumber`] to run only [`gherkin::Scenario`]s marked with `@cat` tag:
+    /// ```rust
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .filter_run_and_exit("tests/features/readme", |_, _, sc| {
+    ///         sc.tags.iter().any(|t| t == "cat")
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    /// ```gherkin
+    /// Feature: Animal feature
+    ///
+    ///   @cat
+    ///   Scenario: If we feed a hungry cat it will no longer be hungry
+    ///     Given a hungry cat
+    ///     When I feed the cat
+    ///     Then the cat is not hungry
+    ///
+    ///   @dog
+    ///   Scenario: If we feed a satiated dog it will not become hungry
+    ///     Given a satiated dog
+    ///     When I feed the dog
+    ///     Then the dog is not hungry
+    /// ```
+    /// <script
+    ///     id="asciicast-0KvTxnfaMRjsvsIKsalS611Ta"
+    ///     src="https://asciinema.org/a/0KvTxnfaMRjsvsIKsalS611Ta.js"
+    ///     async data-autoplay="true" data-rows="14">
+    /// </script>
+    ///
+    /// [`Failed`]: crate::event::Step::Failed
+    /// [`Feature`]: gherkin::Feature
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    pub async fn filter_run_and_exit<Filter>(mut self, input: I, filter: Filter)
+    where
+        Filter: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + 'static,
+    {
+        let cli = self.cli.take().unwrap_or_else(cli::Opts::parsed);
+        let strict = self.strict || cli.strict;
+        let exit_codes = cli
+            .exit_codes
+            .iter()
+            .fold(self.exit_codes, |codes, m| codes.with(m.outcome, m.code));
+        self.cli = Some(cli);
+
+        let writer = self.filter_run(input, filter).await;
+        if let Some(outcome) = exit_code::Outcome::of(&writer, strict) {
+            let (undefined_steps, pending_steps) =
+                (writer.undefined_steps(), writer.pending_steps());
+
+            let mut msg = Vec::with_capacity(7);
+
+            let failed_steps = writer.failed_steps();
+            if failed_steps > 0 {
+                msg.push(format!(
+                    "{failed_steps} step{} failed",
+                    if failed_steps > 1 { "s" } else { "" },
+                ));
+            }
+
+            let timed_out_steps = writer.timed_out_steps();
+            if timed_out_steps > 0 {
+                msg.push(format!(
+                    "{timed_out_steps} step{} timed out",
+                    if timed_out_steps > 1 { "s" } else { "" },
+                ));
+            }
+
+            if strict && undefined_steps > 0 {
+                msg.push(format!(
+                    "{undefined_steps} step{} undefined",
+                    if undefined_steps > 1 { "s" } else { "" },
+                ));
+            }
+
+            if strict && pending_steps > 0 {
+                msg.push(format!(
+                    "{pending_steps} step{} pending",
+                    if pending_steps > 1 { "s" } else { "" },
+                ));
+            }
+
+            let parsing_errors = writer.parsing_errors();
+            if parsing_errors > 0 {
+                msg.push(format!(
+                    "{parsing_errors} parsing error{}",
+                    if parsing_errors > 1 { "s" } else { "" },
+                ));
+            }
+
+            let hook_errors = writer.hook_errors();
+            if hook_errors > 0 {
+                msg.push(format!(
+                    "{hook_errors} hook error{}",
+                    if hook_errors > 1 { "s" } else { "" },
+                ));
+            }
+
+            let sla_violations = writer.sla_violations();
+            if sla_violations > 0 {
+                msg.push(format!(
+                    "{sla_violations} SLA violation{}",
+                    if sla_violations > 1 { "s" } else { "" },
+                ));
+            }
+
+            let code = exit_codes.code(outcome);
+            // There is no process to exit on `wasm32-unknown-unknown` target,
+            // so panic instead, failing the `wasm-bindgen-test` being run.
+            #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+            panic!("{} (exit code {code})", msg.join(", "));
+            #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+            {
+                eprintln!("{}", msg.join(", "));
+                std::process::exit(code);
+            }
+        }
+    }
+}
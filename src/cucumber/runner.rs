@@ -1,6 +1,6 @@
 //! Runner configuration methods for Cucumber executor.
 
-use std::{marker::PhantomData, time::Duration};
+use std::{any::Any, marker::PhantomData, path::PathBuf, time::Duration};
 
 use futures::future::LocalBoxFuture;
 use gherkin::tagexpr::TagOperation;
@@ -9,7 +9,12 @@ use super::core::Cucumber;
 use crate::{
     Parser, ScenarioType, World, Writer,
     event::ScenarioFinished,
-    runner::{self, basic::RetryOptions},
+    fixture::Fixtures,
+    runner::{
+        self,
+        basic::{AfterPanic, RetryOptions},
+    },
+    runtime::Runtime,
 };
 
 impl<W, I, P, Wr, F, B, A, Cli>
@@ -78,6 +83,57 @@ where
         self
     }
 
+    /// Makes [`Backtrace`]s of panicking [`crate::step::Step`]s to be always
+    /// captured, regardless of the `RUST_BACKTRACE` environment variable.
+    ///
+    /// See [`runner::Basic::force_backtraces()`] for details.
+    ///
+    /// [`Backtrace`]: std::backtrace::Backtrace
+    #[must_use]
+    pub fn force_backtraces(mut self, force: bool) -> Self {
+        self.runner = self.runner.force_backtraces(force);
+        self
+    }
+
+    /// Makes every [`crate::step::Step`] running longer than the provided
+    /// `timeout` to fail with [`StepError::Timeout`].
+    ///
+    /// See [`runner::Basic::step_timeout()`] for details.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [`StepError::Timeout`]: crate::event::StepError::Timeout
+    #[must_use]
+    pub fn step_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.runner = self.runner.step_timeout(timeout);
+        self
+    }
+
+    /// Sets the [`Runtime`] measuring step timeouts, heartbeats and retry
+    /// delays.
+    ///
+    /// See [`runner::Basic::runtime()`] for details.
+    #[must_use]
+    pub fn runtime(mut self, runtime: impl Runtime) -> Self {
+        self.runner = self.runner.runtime(runtime);
+        self
+    }
+
+    /// Sets the [`AfterPanic`] behavior regarding the remaining
+    /// [`crate::step::Step`]s of a [`gherkin::Scenario`] after one of them
+    /// fails.
+    ///
+    /// See [`runner::Basic::after_panic()`] for details.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub fn after_panic(mut self, behavior: AfterPanic) -> Self {
+        self.runner = self.runner.after_panic(behavior);
+        self
+    }
+
     /// Makes failed [`gherkin::Scenario`]s being retried after the specified
     /// [`Duration`] passes.
     ///
@@ -120,12 +176,14 @@ where
             ) -> ScenarioType
             + 'static,
     {
-        let Self { parser, runner, writer, cli, .. } = self;
+        let Self { parser, runner, writer, cli, strict, exit_codes, .. } = self;
         Cucumber {
             parser,
             runner: runner.which_scenario(func),
             writer,
             cli,
+            strict,
+            exit_codes,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -148,4 +206,129 @@ where
         self.runner = self.runner.retry_options(func);
         self
     }
+
+    /// Enables caching of [`Background`] [`crate::step::Step`]s for
+    /// [`gherkin::Feature`]s and [`gherkin::Rule`]s tagged with
+    /// `@cached-background`, using the provided `func` to snapshot and
+    /// restore the `World`.
+    ///
+    /// See [`runner::Basic::cached_background()`] for details.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub fn cached_background(
+        mut self,
+        func: impl Fn(&W) -> W + 'static,
+    ) -> Self {
+        self.runner = self.runner.cached_background(func);
+        self
+    }
+
+    /// Makes concurrent [`gherkin::Scenario`]s to be dispatched in the order
+    /// of their historical duration (longest first), using the
+    /// [`RunHistory`] stored at the provided `path`.
+    ///
+    /// See [`runner::Basic::duration_history()`] for details.
+    ///
+    /// [`RunHistory`]: runner::basic::RunHistory
+    #[must_use]
+    pub fn duration_history(mut self, path: impl Into<PathBuf>) -> Self {
+        self.runner = self.runner.duration_history(path);
+        self
+    }
+
+    /// Sets the `state` created once per run and shared between all the
+    /// [`crate::step::Step`]s, accessible via [`step::Context::shared()`].
+    ///
+    /// See [`runner::Basic::shared_state()`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{World, given, step::Context};
+    /// #
+    /// #[derive(Debug)]
+    /// struct Config {
+    ///     base_url: String,
+    /// }
+    ///
+    /// #[derive(Debug, Default, World)]
+    /// struct MyWorld;
+    ///
+    /// #[given("a configured service")]
+    /// fn configured(_: &mut MyWorld, #[step] ctx: &Context) {
+    ///     let config = ctx.shared::<Config>().expect("no `Config`");
+    ///     assert_eq!(config.base_url, "http://localhost");
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .shared_state(Config { base_url: "http://localhost".into() })
+    ///     .run("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`step::Context::shared()`]: crate::step::Context::shared
+    #[must_use]
+    pub fn shared_state<T: Any + Send + Sync>(mut self, state: T) -> Self {
+        self.runner = self.runner.shared_state(state);
+        self
+    }
+
+    /// Sets the [`Fixtures`] to be set up for the [`gherkin::Scenario`]s
+    /// requesting them via `@fixture:<name>` tags, accessible via
+    /// [`step::Context::fixture()`].
+    ///
+    /// See [`runner::Basic::fixtures()`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// #
+    /// # use cucumber::{
+    /// #     World,
+    /// #     fixture::{Fixture, Fixtures, Scope},
+    /// #     given,
+    /// #     step::Context,
+    /// # };
+    /// #
+    /// #[derive(Debug)]
+    /// struct Database {
+    ///     url: String,
+    /// }
+    ///
+    /// #[derive(Debug, Default, World)]
+    /// struct MyWorld;
+    ///
+    /// #[given("a database")]
+    /// fn database(_: &mut MyWorld, #[step] ctx: &Context) {
+    ///     let db = ctx.fixture::<Database>().expect("no `@fixture:db` tag");
+    ///     assert_eq!(db.url, "postgres://localhost");
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .fixtures(Fixtures::new().fixture(
+    ///         Fixture::new("db", async |_| {
+    ///             let url = "postgres://localhost".into();
+    ///             Ok::<_, Infallible>(Database { url })
+    ///         })
+    ///         .scope(Scope::Run),
+    ///     ))
+    ///     .run("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`step::Context::fixture()`]: crate::step::Context::fixture
+    #[must_use]
+    pub fn fixtures(mut self, fixtures: Fixtures) -> Self {
+        self.runner = self.runner.fixtures(fixtures);
+        self
+    }
 }
@@ -0,0 +1,103 @@
+//! Step definitions index mode of Cucumber executor.
+
+use std::{
+    mem,
+    path::PathBuf,
+    process,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use futures::{Stream, StreamExt as _};
+
+use crate::{parser, step, step_index};
+
+/// Prints the [`step_index::Index`] of the provided [`step::Collection`]
+/// instead of executing it, and exits the process.
+///
+/// Exits with `2` status code if there is no [`step::Collection`] to index,
+/// or `0` otherwise.
+pub(super) fn print_and_exit<W, T>(steps: Option<&step::Collection<W>>) -> T {
+    let Some(steps) = steps else {
+        #[expect(clippy::print_stderr, reason = "no other way to report")]
+        {
+            eprintln!("Runner doesn't expose steps to index");
+        }
+        #[expect(clippy::exit, reason = "indexing replaces the execution")]
+        process::exit(2);
+    };
+
+    #[expect(clippy::print_stdout, reason = "index is the output")]
+    {
+        println!("{}", step_index::Index::of(steps));
+    }
+    #[expect(clippy::exit, reason = "indexing replaces the execution")]
+    process::exit(0);
+}
+
+/// [`gherkin::Feature`]s collected for a [`step_index::Index`].
+type Features = Arc<Mutex<Vec<gherkin::Feature>>>;
+
+/// Writer of a [`step_index::Index`] of the executed [`gherkin::Feature`]s
+/// into the file requested via [`step_index::Cli`], if any.
+pub(super) struct IndexFile<W>(
+    Option<(PathBuf, Option<step::Collection<W>>, Features)>,
+);
+
+impl<W> IndexFile<W> {
+    /// Creates a new [`IndexFile`] out of the provided [`step_index::Cli`]
+    /// options, collecting the [`gherkin::Feature`]s of the provided `features`
+    /// [`Stream`] to be indexed, if it's requested.
+    pub(super) fn tap<S>(
+        cli: step_index::Cli,
+        steps: Option<&step::Collection<W>>,
+        features: S,
+    ) -> (impl Stream<Item = S::Item> + 'static, Self)
+    where
+        S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
+    {
+        let file = cli
+            .steps_index_file
+            .map(|path| (path, steps.cloned(), Features::default()));
+        let collected = file.as_ref().map(|(.., f)| Arc::clone(f));
+        let features = features.inspect(move |feature| {
+            if let (Some(collected), Ok(feature)) = (&collected, feature) {
+                collected
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(feature.clone());
+            }
+        });
+        (features, Self(file))
+    }
+
+    /// Writes the [`step_index::Index`] of the collected
+    /// [`gherkin::Feature`]s into the requested file, if any.
+    ///
+    /// Failing to write it is reported, but doesn't fail the whole run.
+    pub(super) fn save(self) {
+        let Some((path, steps, features)) = self.0 else {
+            return;
+        };
+        let Some(steps) = steps else {
+            #[expect(clippy::print_stderr, reason = "no other way to report")]
+            {
+                eprintln!("Runner doesn't expose steps to index");
+            }
+            return;
+        };
+
+        let features = mem::take(
+            &mut *features.lock().unwrap_or_else(PoisonError::into_inner),
+        );
+        let index = step_index::Index::with_features(&steps, &features);
+        if let Err(e) = index.save(&path) {
+            #[expect(clippy::print_stderr, reason = "no other way to report")]
+            {
+                eprintln!(
+                    "Failed to write steps index to `{}`: {e}",
+                    path.display(),
+                );
+            }
+        }
+    }
+}
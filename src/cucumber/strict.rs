@@ -0,0 +1,58 @@
+//! Strict mode functionality for Cucumber executor.
+
+use super::core::Cucumber;
+use crate::{Parser, Runner, World, Writer};
+
+impl<W, P, I, R, Wr, Cli> Cucumber<W, P, I, R, Wr, Cli>
+where
+    W: World,
+    P: Parser<I>,
+    R: Runner<W>,
+    Wr: Writer<W>,
+    Cli: clap::Args,
+{
+    /// Makes [`Cucumber::run_and_exit()`] to fail on undefined
+    /// [`crate::step::Step`]s (the ones not matching any function) and the
+    /// ones marked as pending (via [`step::Pending::raise()`]), even if no
+    /// assertions have failed.
+    ///
+    /// Both are reported separately from the failed ones in the summary.
+    ///
+    /// The same may be achieved by specifying the `--strict` CLI option.
+    ///
+    /// # Example
+    ///
+    /// ```rust,should_panic
+    /// # use cucumber::{given, step, then, when, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// #[given(regex = r"^(\S+) is hungry$")]
+    /// fn is_hungry(_: &mut MyWorld, _who: String) {}
+    ///
+    /// #[when(regex = r"^she eats (\d+) cucumbers$")]
+    /// fn eats(_: &mut MyWorld, _count: u32) {}
+    ///
+    /// #[then("she is full")]
+    /// fn is_full(_: &mut MyWorld) {
+    ///     step::Pending::raise("fullness isn't measurable yet");
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .strict()
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`step::Pending::raise()`]: crate::step::Pending::raise
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub const fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+}
@@ -0,0 +1,38 @@
+//! Step coverage validation mode of Cucumber executor.
+
+use std::process;
+
+use futures::{Stream, StreamExt as _};
+
+use crate::{parser, step, validate};
+
+/// Validates [`gherkin::Step`]s of the provided parsed `features` being
+/// covered by the provided [`step::Collection`] instead of executing them,
+/// printing the [`validate::Report`] and exiting the process.
+///
+/// Exits with `1` status code if the [`validate::Report`] contains any
+/// [`validate::Issue`], with `2` if there is no [`step::Collection`] to
+/// validate against, or `0` otherwise.
+pub(super) async fn run_and_exit<W, T>(
+    features: impl Stream<Item = parser::Result<gherkin::Feature>>,
+    steps: Option<&step::Collection<W>>,
+) -> T {
+    let Some(steps) = steps else {
+        #[expect(clippy::print_stderr, reason = "no other way to report")]
+        {
+            eprintln!("Runner doesn't expose steps to validate against");
+        }
+        #[expect(clippy::exit, reason = "validation replaces the execution")]
+        process::exit(2);
+    };
+
+    let features = features.collect::<Vec<_>>().await;
+    let report = validate::validate(steps, features);
+
+    #[expect(clippy::print_stdout, reason = "validation report is the output")]
+    {
+        print!("{report}");
+    }
+    #[expect(clippy::exit, reason = "validation replaces the execution")]
+    process::exit(i32::from(!report.is_empty()));
+}
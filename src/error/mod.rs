@@ -136,12 +136,9 @@ mod integration_tests {
             Arc::new("test &str");
         assert_eq!(str_payload.to_readable_string(), "test &str");
 
-        let unknown_payload: Arc<dyn std::any::Any + Send + 'static> =
+        let int_payload: Arc<dyn std::any::Any + Send + 'static> =
             Arc::new(42i32);
-        assert_eq!(
-            unknown_payload.to_readable_string(),
-            "Unknown panic payload"
-        );
+        assert_eq!(int_payload.to_readable_string(), "42 (i32)");
     }
 
     #[test]
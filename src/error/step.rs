@@ -17,6 +17,8 @@ use std::{sync::Arc, time::Duration};
 
 use derive_more::with_trait::{Display, Error};
 
+use crate::writer::basic::coerce_error;
+
 /// Errors that can occur during step execution.
 #[derive(Debug, Display, Error)]
 pub enum StepError {
@@ -128,13 +130,7 @@ pub trait PanicPayloadExt {
 
 impl PanicPayloadExt for Arc<dyn std::any::Any + Send + 'static> {
     fn to_readable_string(&self) -> String {
-        if let Some(s) = self.downcast_ref::<String>() {
-            s.clone()
-        } else if let Some(s) = self.downcast_ref::<&str>() {
-            (*s).to_string()
-        } else {
-            "Unknown panic payload".to_string()
-        }
+        coerce_error(self).into_owned()
     }
 }
 
@@ -222,11 +218,28 @@ mod tests {
             Arc::new("str panic");
         assert_eq!(str_payload.to_readable_string(), "str panic");
 
-        let unknown_payload: Arc<dyn std::any::Any + Send + 'static> =
+        let int_payload: Arc<dyn std::any::Any + Send + 'static> =
             Arc::new(42i32);
-        assert_eq!(
-            unknown_payload.to_readable_string(),
-            "Unknown panic payload"
+        assert_eq!(int_payload.to_readable_string(), "42 (i32)");
+
+        let error_payload: Arc<dyn std::any::Any + Send + 'static> =
+            Arc::new(Box::<dyn std::error::Error + Send + Sync>::from(
+                "boxed error",
+            ));
+        assert_eq!(error_payload.to_readable_string(), "boxed error");
+
+        let caught: Box<dyn std::any::Any + Send> = Box::new(7u8);
+        let caught_payload: Arc<dyn std::any::Any + Send + 'static> =
+            Arc::new(caught);
+        assert_eq!(caught_payload.to_readable_string(), "7 (u8)");
+
+        struct Unknown;
+        let unknown_payload: Arc<dyn std::any::Any + Send + 'static> =
+            Arc::new(Unknown);
+        assert!(
+            unknown_payload
+                .to_readable_string()
+                .starts_with("(Could not resolve panic payload of TypeId("),
         );
     }
 
@@ -1,11 +1,21 @@
 //! Top-level Cucumber execution events.
 
-use super::{Feature, RetryableScenario, Rule, Source};
+use super::{Feature, Report, RetryableScenario, Rule, Source};
 
 /// Top-level [Cucumber] run event.
 ///
 /// [Cucumber]: https://cucumber.io
+#[expect( // intentional
+    clippy::large_enum_variant,
+    reason = "`Feature` events are the most frequent ones, so boxing them \
+              would cost an allocation for almost every event"
+)]
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "", rename_all = "snake_case")
+)]
 pub enum Cucumber<World> {
     /// [`Cucumber`] execution being started.
     Started,
@@ -43,6 +53,12 @@ pub enum Cucumber<World> {
         parser_errors: usize,
     },
 
+    /// [`Report`] about the whole [`Cucumber`] run, emitted right before it's
+    /// [`Finished`].
+    ///
+    /// [`Finished`]: Cucumber::Finished
+    Report(Report),
+
     /// [`Cucumber`] execution being finished.
     Finished,
 }
@@ -67,6 +83,7 @@ impl<World> Clone for Cucumber<World> {
                 steps: *steps,
                 parser_errors: *parser_errors,
             },
+            Self::Report(r) => Self::Report(r.clone()),
             Self::Finished => Self::Finished,
         }
     }
@@ -122,7 +139,10 @@ impl<World> Cucumber<World> {
         Self::Feature(
             feat.into(),
             if let Some(r) = rule {
-                Feature::Rule(r.into(), Rule::Scenario(scenario.into(), event))
+                Feature::Rule(
+                    r.into(),
+                    Rule::Scenario(scenario.into(), Box::new(event)),
+                )
             } else {
                 Feature::Scenario(scenario.into(), event)
             },
@@ -0,0 +1,74 @@
+//! User-defined events published from step functions.
+
+use std::fmt;
+
+/// User-defined event published from a [`crate::step::Step`] function via
+/// [`step::Context::publish()`], describing a domain milestone (like
+/// `order placed id=123`) to appear in reports.
+///
+/// [`crate::step::Step`]: gherkin::Step
+/// [`step::Context::publish()`]: crate::step::Context::publish
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Custom {
+    /// Name of this [`Custom`] event.
+    pub name: String,
+
+    /// Named fields of this [`Custom`] event, in the order they were added.
+    pub fields: Vec<(String, String)>,
+}
+
+impl Custom {
+    /// Creates a new [`Custom`] event with the provided `name` and no fields.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), fields: Vec::new() }
+    }
+
+    /// Adds a field with the provided `key` and `value` to this [`Custom`]
+    /// event.
+    #[must_use]
+    pub fn field(
+        mut self,
+        key: impl Into<String>,
+        value: impl fmt::Display,
+    ) -> Self {
+        self.fields.push((key.into(), value.to_string()));
+        self
+    }
+}
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for (key, value) in &self.fields {
+            write!(f, " {key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for Custom {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for Custom {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Custom;
+
+    #[test]
+    fn displays_name_with_fields() {
+        let ev = Custom::new("order placed").field("id", 123).field("qty", 2);
+
+        assert_eq!(ev.to_string(), "order placed id=123 qty=2");
+        assert_eq!(Custom::from("cart emptied").to_string(), "cart emptied");
+    }
+}
@@ -1,10 +1,13 @@
 //! Core Event struct and implementations.
 
 #[cfg(feature = "timestamps")]
-use std::time::SystemTime;
+use std::{sync::LazyLock, time::Duration};
 
 use derive_more::with_trait::{AsRef, Debug, Deref, DerefMut};
 
+#[cfg(feature = "timestamps")]
+use crate::time::{Instant, SystemTime};
+
 /// Alias for a [`catch_unwind()`] error.
 ///
 /// [`catch_unwind()`]: std::panic::catch_unwind()
@@ -13,7 +16,8 @@ pub type Info = std::sync::Arc<dyn std::any::Any + Send + 'static>;
 /// Arbitrary event, optionally paired with additional metadata.
 ///
 /// Any metadata is added by enabling the correspondent library feature:
-/// - `timestamps`: adds time of when this [`Event`] has happened.
+/// - `timestamps`: adds wall-clock time of when this [`Event`] has happened,
+///   along with its monotonic offset from the start of the run.
 #[derive(AsRef, Clone, Copy, Debug, Deref, DerefMut)]
 #[non_exhaustive]
 pub struct Event<T: ?Sized> {
@@ -21,6 +25,14 @@ pub struct Event<T: ?Sized> {
     #[cfg(feature = "timestamps")]
     pub at: SystemTime,
 
+    /// Monotonic offset of this [`Event`] from the start of the run (the
+    /// [`Cucumber::Started`] event), unaffected by the system clock
+    /// adjustments.
+    ///
+    /// [`Cucumber::Started`]: crate::event::Cucumber::Started
+    #[cfg(feature = "timestamps")]
+    pub offset: Duration,
+
     /// Actual value of this [`Event`].
     #[as_ref]
     #[deref]
@@ -39,10 +51,32 @@ impl<T> Event<T> {
         Self {
             #[cfg(feature = "timestamps")]
             at: SystemTime::now(),
+            #[cfg(feature = "timestamps")]
+            offset: monotonic_offset(),
             value,
         }
     }
 
+    /// Returns the monotonic [`Duration`] elapsed between the `earlier`
+    /// [`Event`] and this one (or zero, if the `earlier` one happened later).
+    #[cfg(feature = "timestamps")]
+    #[must_use]
+    pub const fn duration_since<U: ?Sized>(
+        &self,
+        earlier: &Event<U>,
+    ) -> Duration {
+        self.offset.saturating_sub(earlier.offset)
+    }
+
+    /// Rebases the [`Event::offset`] onto the provided `origin` one (the
+    /// [`Event::offset`] of the run start).
+    #[cfg(feature = "timestamps")]
+    #[must_use]
+    pub(crate) const fn rebase(mut self, origin: Duration) -> Self {
+        self.offset = self.offset.saturating_sub(origin);
+        self
+    }
+
     /// Unwraps the inner [`Event::value`] loosing all the attached metadata.
     #[must_use]
     pub fn into_inner(self) -> T {
@@ -77,6 +111,8 @@ impl<T> Event<T> {
         let event = Event {
             #[cfg(feature = "timestamps")]
             at: self.at,
+            #[cfg(feature = "timestamps")]
+            offset: self.offset,
             value,
         };
         (self.value, event)
@@ -93,3 +129,13 @@ impl Metadata {
         self.replace(value).1
     }
 }
+
+/// Returns the monotonic offset of the current moment from the origin shared by
+/// all the [`Event`]s in this process.
+#[cfg(feature = "timestamps")]
+fn monotonic_offset() -> Duration {
+    /// Monotonic origin of all the [`Event::offset`]s in this process.
+    static ORIGIN: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+    ORIGIN.elapsed()
+}
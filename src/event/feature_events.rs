@@ -6,6 +6,11 @@ use super::{RetryableScenario, Rule, Source};
 ///
 /// [Feature]: https://cucumber.io/docs/gherkin/reference#feature
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "", rename_all = "snake_case")
+)]
 pub enum Feature<World> {
     /// [`Feature`] execution being started.
     ///
@@ -105,6 +110,7 @@ mod tests {
         let scenario_event = RetryableScenario {
             event: Scenario::<TestWorld>::Started,
             retries: None,
+            id: None,
         };
         let event =
             Feature::Scenario(Source::new(scenario.clone()), scenario_event);
@@ -112,7 +118,7 @@ mod tests {
         match event {
             Feature::Scenario(
                 s,
-                RetryableScenario { event: Scenario::Started, retries },
+                RetryableScenario { event: Scenario::Started, retries, .. },
             ) => {
                 assert_eq!(s.name, "Test Scenario");
                 assert!(retries.is_none());
@@ -126,15 +132,18 @@ mod tests {
         let scenario = create_test_scenario();
         let retries = Retries { current: 1, left: 2 };
         let scenario_event = RetryableScenario {
-            event: Scenario::<TestWorld>::Finished,
+            event: Scenario::<TestWorld>::Finished(None),
             retries: Some(retries),
+            id: None,
         };
         let event = Feature::Scenario(Source::new(scenario), scenario_event);
 
         match event {
             Feature::Scenario(
                 _,
-                RetryableScenario { event: Scenario::Finished, retries },
+                RetryableScenario {
+                    event: Scenario::Finished(_), retries, ..
+                },
             ) => {
                 assert!(retries.is_some());
                 let r = retries.unwrap();
@@ -156,7 +165,11 @@ mod tests {
             Feature::Rule(Source::new(create_test_rule()), Rule::Finished),
             Feature::Scenario(
                 Source::new(create_test_scenario()),
-                RetryableScenario { event: Scenario::Started, retries: None },
+                RetryableScenario {
+                    event: Scenario::Started,
+                    retries: None,
+                    id: None,
+                },
             ),
         ];
 
@@ -190,10 +203,11 @@ mod tests {
         // Create a Rule event containing a Scenario
         let rule_event = Rule::Scenario(
             Source::new(scenario.clone()),
-            RetryableScenario {
+            Box::new(RetryableScenario {
                 event: Scenario::<TestWorld>::Started,
                 retries: None,
-            },
+                id: None,
+            }),
         );
 
         let feature_event = Feature::Rule(Source::new(rule), rule_event);
@@ -219,13 +233,18 @@ mod tests {
             Feature::Rule(Source::new(rule.clone()), Rule::Finished),
             Feature::Scenario(
                 Source::new(scenario.clone()),
-                RetryableScenario { event: Scenario::Started, retries: None },
+                RetryableScenario {
+                    event: Scenario::Started,
+                    retries: None,
+                    id: None,
+                },
             ),
             Feature::Scenario(
                 Source::new(scenario.clone()),
                 RetryableScenario {
-                    event: Scenario::Finished,
+                    event: Scenario::Finished(None),
                     retries: Some(Retries { current: 1, left: 0 }),
+                    id: None,
                 },
             ),
             Feature::Finished,
@@ -0,0 +1,151 @@
+//! Metadata of a [`gherkin::Feature`], declared in its header comments.
+
+use std::{collections::BTreeMap, collections::btree_map, fs};
+
+/// Metadata of a [`gherkin::Feature`], declared as `# key: value` comments at
+/// the top of its `.feature` file, before the `Feature` keyword.
+///
+/// ```gherkin
+/// # owner: payments-team
+/// # epic: FOO-12
+/// @serial
+/// Feature: Refunds
+/// ```
+///
+/// Keys consist of ASCII alphanumerics, `-`, `_` and `.` only, so regular
+/// prose comments are not mistaken for metadata. The `# language:` directive
+/// is not considered a metadata. If the same key is declared several times,
+/// the last value wins.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::event::FeatureMetadata;
+/// #
+/// let meta = FeatureMetadata::parse(concat!(
+///     "# owner: payments-team\n",
+///     "# TODO: split this feature\n",
+///     "# epic: FOO-12\n",
+///     "Feature: Refunds\n",
+/// ));
+///
+/// assert_eq!(meta.get("owner"), Some("payments-team"));
+/// assert_eq!(meta.get("epic"), Some("FOO-12"));
+/// assert_eq!(meta.len(), 3);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct FeatureMetadata(BTreeMap<String, String>);
+
+impl FeatureMetadata {
+    /// Parses [`FeatureMetadata`] from the header comments of the provided
+    /// `.feature` file `source`.
+    #[must_use]
+    pub fn parse(source: &str) -> Self {
+        let entries = source
+            .lines()
+            .map(str::trim)
+            .take_while(|l| {
+                l.is_empty() || l.starts_with('#') || l.starts_with('@')
+            })
+            .filter_map(|l| {
+                let (key, value) = l.strip_prefix('#')?.split_once(':')?;
+                let key = key.trim();
+                let is_key = !key.is_empty()
+                    && key.chars().all(|c| {
+                        c.is_ascii_alphanumeric() || "-_.".contains(c)
+                    });
+                (is_key && key != "language")
+                    .then(|| (key.to_owned(), value.trim().to_owned()))
+            });
+        Self(entries.collect())
+    }
+
+    /// Reads [`FeatureMetadata`] of the provided [`gherkin::Feature`] from its
+    /// `.feature` file.
+    ///
+    /// Empty, if the [`gherkin::Feature`] has no path, or its file cannot be
+    /// read.
+    #[must_use]
+    pub fn of(feature: &gherkin::Feature) -> Self {
+        feature
+            .path
+            .as_ref()
+            .and_then(|p| fs::read_to_string(p).ok())
+            .map(|s| Self::parse(&s))
+            .unwrap_or_default()
+    }
+
+    /// Returns the value of the provided metadata `key`, if declared.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Returns the number of declared metadata entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Indicates whether no metadata entries are declared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an [`Iterator`] over all the declared `(key, value)` metadata
+    /// entries, ordered by keys.
+    pub fn iter(&self) -> btree_map::Iter<'_, String, String> {
+        self.0.iter()
+    }
+}
+
+impl<'m> IntoIterator for &'m FeatureMetadata {
+    type Item = (&'m String, &'m String);
+    type IntoIter = btree_map::Iter<'m, String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_header_comments_only() {
+        let meta = FeatureMetadata::parse(
+            "# language: en\n\
+             \n\
+             # owner: core\n\
+             # see https://example.com\n\
+             @serial\n\
+             # owner: payments-team\n\
+             Feature: F\n\
+             \x20 # epic: FOO-12\n\
+             \x20 Scenario: S\n",
+        );
+
+        assert_eq!(
+            meta.iter().collect::<Vec<_>>(),
+            [(&"owner".to_owned(), &"payments-team".to_owned())],
+        );
+    }
+
+    #[test]
+    fn reads_nothing_without_path() {
+        let feature = gherkin::Feature::parse(
+            "# owner: core\nFeature: F\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap();
+
+        assert!(FeatureMetadata::of(&feature).is_empty());
+    }
+}
@@ -12,6 +12,11 @@ use super::event_struct::Info;
 /// [`crate::step::Step`]: gherkin::Step
 #[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
 #[display("{self:?}")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum HookType {
     /// Executing on each [`gherkin::Scenario`] before running all [`crate::step::Step`]s.
     ///
@@ -26,11 +31,53 @@ pub enum HookType {
     After,
 }
 
+/// Scope of a [`Before`] or [`After`] hook, describing what it sets up or
+/// tears down for a [`gherkin::Scenario`].
+///
+/// [`After`]: HookType::After
+/// [`Before`]: HookType::Before
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum HookScope {
+    /// Hook function registered via [`Cucumber::before()`] or
+    /// [`Cucumber::after()`].
+    ///
+    /// [`Cucumber::after()`]: crate::Cucumber::after
+    /// [`Cucumber::before()`]: crate::Cucumber::before
+    #[display("hook")]
+    Function,
+
+    /// Construction ([`World::new()`]) or teardown ([`World::teardown()`]) of
+    /// the [`World`].
+    ///
+    /// [`World`]: crate::World
+    /// [`World::new()`]: crate::World::new
+    /// [`World::teardown()`]: crate::World::teardown
+    #[display("World")]
+    World,
+
+    /// Setup or teardown of the [`Fixture`]s requested by the
+    /// [`gherkin::Scenario`].
+    ///
+    /// [`Fixture`]: crate::fixture::Fixture
+    #[display("fixtures")]
+    Fixtures,
+}
+
 /// Event of running [`Before`] or [`After`] hook.
 ///
 /// [`After`]: HookType::After
 /// [`Before`]: HookType::Before
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "", rename_all = "snake_case")
+)]
 pub enum Hook<World> {
     /// Hook execution being started.
     Started,
@@ -39,7 +86,14 @@ pub enum Hook<World> {
     Passed,
 
     /// Hook failed.
-    Failed(Option<Arc<World>>, Info),
+    Failed(
+        #[cfg_attr(feature = "serde", serde(skip))] Option<Arc<World>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "super::serialization::info")
+        )]
+        Info,
+    ),
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
@@ -16,19 +16,37 @@ pub mod source;
 
 // Event type modules
 pub mod cucumber_events;
+pub mod custom;
 pub mod feature_events;
+pub mod feature_metadata;
 pub mod hook_events;
+pub mod parsing_events;
+pub mod report_events;
+pub mod resource_usage;
 pub mod rule_events;
 pub mod scenario_events;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod sla_events;
 pub mod step_events;
+pub mod suite;
 
 // Re-export public API
 pub use cucumber_events::Cucumber;
+pub use custom::Custom;
 pub use event_struct::{Event, Info, Metadata};
 pub use feature_events::Feature;
-pub use hook_events::{Hook, HookType};
+pub use feature_metadata::FeatureMetadata;
+pub use hook_events::{Hook, HookScope, HookType};
+pub use parsing_events::ParsingFailed;
+pub use report_events::{IterationFailed, Report};
+pub use resource_usage::{ResourceUsage, Sampled};
 pub use retries::Retries;
 pub use rule_events::Rule;
 pub use scenario_events::{RetryableScenario, Scenario, ScenarioFinished};
+#[cfg(feature = "serde")]
+pub use serialization::SCHEMA_VERSION;
+pub use sla_events::SlaViolation;
 pub use source::Source;
-pub use step_events::{Step, StepError};
+pub use step_events::{Step, StepError, StepMatch, StepTiming};
+pub use suite::Suite;
@@ -0,0 +1,199 @@
+//! Parsing-related events and types.
+
+use std::{fmt, fs, path::PathBuf};
+
+use crate::{parser, writer::basic::trim_path};
+
+/// Structured diagnostic of a [`parser::Error`], pointing to the exact place
+/// in a `.feature` file it happened at.
+///
+/// Every `.feature` file failing to parse produces its own [`parser::Error`],
+/// so all of them are reported at once, rather than only the first one.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{event, parser};
+/// #
+/// let source = "Feature: A\n  Scenario: S\n    Given a step\n  @tag\n";
+/// let error = parser::Error::from(gherkin::ParseFileError::Parsing {
+///     path: "virtual.feature".into(),
+///     error: None,
+///     source: gherkin::Feature::parse(source, Default::default()).unwrap_err(),
+/// });
+/// let diagnostic = event::ParsingFailed::from(&error);
+///
+/// assert_eq!(diagnostic.position.map(|p| p.line), Some(5));
+/// assert!(!diagnostic.expected.is_empty());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ParsingFailed {
+    /// Path to the file that failed to be parsed, if known.
+    pub path: Option<PathBuf>,
+
+    /// Position in the file the failure happened at, if known.
+    pub position: Option<gherkin::LineCol>,
+
+    /// Human-readable message describing the failure.
+    pub message: String,
+
+    /// Tokens expected at the [`ParsingFailed::position`], sorted.
+    pub expected: Vec<String>,
+
+    /// Caret-style excerpt of the file at the [`ParsingFailed::position`],
+    /// if the file is readable.
+    pub excerpt: Option<String>,
+}
+
+impl ParsingFailed {
+    /// Sets the [`ParsingFailed::excerpt`] extracted from the provided
+    /// `source` of the file at the [`ParsingFailed::position`].
+    #[must_use]
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.excerpt = self.position.map(|pos| excerpt(source, pos));
+        self
+    }
+}
+
+impl From<&parser::Error> for ParsingFailed {
+    fn from(error: &parser::Error) -> Self {
+        let (path, position, expected) = match error {
+            parser::Error::Parsing(e) => match &**e {
+                gherkin::ParseFileError::Reading { path, .. } => {
+                    (Some(path.clone()), None, vec![])
+                }
+                gherkin::ParseFileError::Parsing { path, source, .. } => {
+                    let (pos, expected) = location(source).unzip();
+                    (Some(path.clone()), pos, expected.unwrap_or_default())
+                }
+            },
+            parser::Error::ExampleExpansion(e) => {
+                (e.path.clone(), Some(e.pos), vec![])
+            }
+            parser::Error::Fetching { .. } => (None, None, vec![]),
+        };
+        let diagnostic = Self {
+            path,
+            position,
+            message: error.to_string(),
+            expected,
+            excerpt: None,
+        };
+        match diagnostic.path.as_ref().map(fs::read_to_string) {
+            Some(Ok(source)) => diagnostic.with_source(&source),
+            Some(Err(_)) | None => diagnostic,
+        }
+    }
+}
+
+impl fmt::Display for ParsingFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let (Some(path), Some(pos)) = (&self.path, self.position) {
+            let path = path.display().to_string();
+            write!(f, "\n --> {}:{}:{}", trim_path(&path), pos.line, pos.col)?;
+        }
+        if let Some(excerpt) = &self.excerpt {
+            write!(f, "\n{excerpt}")?;
+        }
+        if !self.expected.is_empty() {
+            write!(f, "\n expected one of: {}", self.expected.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Extracts the position and the sorted expected tokens from the provided
+/// [`gherkin::ParseError`], which exposes them via its [`fmt::Display`] only.
+fn location(
+    error: &gherkin::ParseError,
+) -> Option<(gherkin::LineCol, Vec<String>)> {
+    let error = error.to_string();
+    let (at, expected) = error.strip_prefix("Error at ")?.split_once(": ")?;
+    let (line, col) = at.split_once(':')?;
+    let pos =
+        gherkin::LineCol { line: line.parse().ok()?, col: col.parse().ok()? };
+
+    let mut expected = expected
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(", ")
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            let t = t.strip_prefix('"').unwrap_or(t);
+            let t = t.strip_suffix('"').unwrap_or(t).replace(r#"\""#, "\"");
+            format!("`{}`", t.replace(r"\\", r"\"))
+        })
+        .collect::<Vec<_>>();
+    expected.sort();
+    expected.dedup();
+    Some((pos, expected))
+}
+
+/// Renders a caret-style excerpt of the provided `source` line at the
+/// provided `pos`ition.
+fn excerpt(source: &str, pos: gherkin::LineCol) -> String {
+    let text = source.lines().nth(pos.line.saturating_sub(1)).unwrap_or("");
+    let pad = text
+        .chars()
+        .take(pos.col.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect::<String>();
+    let gutter = " ".repeat(pos.line.to_string().len());
+    format!("{gutter} |\n{} | {text}\n{gutter} | {pad}^", pos.line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_caret_excerpt() {
+        let source = "Feature: A\n\n  Scenario: S\n\tGiven <x>\n";
+
+        assert_eq!(
+            excerpt(source, gherkin::LineCol { line: 4, col: 8 }),
+            "  |\n4 | \tGiven <x>\n  | \t      ^",
+        );
+        assert_eq!(
+            excerpt(source, gherkin::LineCol { line: 10, col: 1 }),
+            "   |\n10 | \n   | ^",
+        );
+    }
+
+    #[test]
+    fn locates_gherkin_errors() {
+        let error = gherkin::Feature::parse(
+            "Feature: A\n  Scenario: S\n    Given a step\n\n  @tag\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap_err();
+
+        let (pos, expected) = location(&error).unwrap();
+
+        assert_eq!(pos, gherkin::LineCol { line: 6, col: 1 });
+        assert!(expected.contains(&"`\"@\"`".to_owned()), "{expected:?}");
+        assert!(!expected.is_empty());
+        assert!(expected.windows(2).all(|w| w[0] < w[1]), "sorted");
+    }
+
+    #[test]
+    fn reports_example_expansion_errors() {
+        let error = parser::Error::from(crate::feature::ExpandExamplesError {
+            pos: gherkin::LineCol { line: 2, col: 5 },
+            name: "x".into(),
+            path: Some("missing.feature".into()),
+        });
+
+        let diagnostic = ParsingFailed::from(&error)
+            .with_source("Feature: A\n    Given <x>");
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "Failed to expand examples: Failed to resolve <x> at \
+             missing.feature:2:5\n \
+             --> missing.feature:2:5\n  |\n2 |     Given <x>\n  |     ^",
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Reports about the whole [Cucumber] run.
+//!
+//! [Cucumber]: https://cucumber.io
+
+use std::{fmt, path::PathBuf};
+
+/// Report about the whole [Cucumber] run, emitted once all the
+/// [`gherkin::Feature`]s are finished, right before the
+/// [`Cucumber::Finished`] event.
+///
+/// [`Cucumber::Finished`]: super::Cucumber::Finished
+/// [Cucumber]: https://cucumber.io
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Report {
+    /// Iteration of the run-until-failure mode has failed.
+    IterationFailed(IterationFailed),
+
+    /// Run history of [`gherkin::Scenario`]s durations failed to be stored.
+    ///
+    /// It doesn't fail the run, as only affects ordering of the next runs.
+    HistoryNotSaved {
+        /// Path to the run history file.
+        path: PathBuf,
+
+        /// Description of the error happened while storing the file.
+        error: String,
+    },
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IterationFailed(ev) => ev.fmt(f),
+            Self::HistoryNotSaved { path, error } => write!(
+                f,
+                "Failed to save run history to `{}`: {error}",
+                path.display(),
+            ),
+        }
+    }
+}
+
+/// Failed iteration of the run-until-failure mode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct IterationFailed {
+    /// Number of the failed iteration, starting from `1`.
+    pub iteration: usize,
+
+    /// Seed the [`gherkin::Scenario`]s of the failed iteration have been
+    /// shuffled with.
+    pub seed: u64,
+
+    /// `path:line` locations of the [`gherkin::Scenario`]s in the order
+    /// they've been started in the failed iteration.
+    pub scenarios: Vec<String>,
+}
+
+impl fmt::Display for IterationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed on iteration {} with seed {}, scenarios started in order:",
+            self.iteration, self.seed,
+        )?;
+        for scenario in &self.scenarios {
+            write!(f, "\n  {scenario}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IterationFailed, Report};
+
+    #[test]
+    fn displays_iteration_failed() {
+        let report = Report::IterationFailed(IterationFailed {
+            iteration: 2,
+            seed: 42,
+            scenarios: vec!["a.feature:3".into(), "b.feature:7".into()],
+        });
+
+        assert_eq!(
+            report.to_string(),
+            "Failed on iteration 2 with seed 42, scenarios started in order:\n  \
+             a.feature:3\n  \
+             b.feature:7",
+        );
+    }
+
+    #[test]
+    fn displays_history_not_saved() {
+        let report = Report::HistoryNotSaved {
+            path: "history.txt".into(),
+            error: "permission denied".into(),
+        };
+
+        assert_eq!(
+            report.to_string(),
+            "Failed to save run history to `history.txt`: permission denied",
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Resources usage sampled during a [`gherkin::Scenario`].
+
+/// Usage of the process resources, sampled periodically while running a
+/// [`gherkin::Scenario`].
+///
+/// Resources are sampled for the whole process, so the usage of the
+/// [`gherkin::Scenario`]s running concurrently is attributed to each of them.
+/// Run them with `--concurrency=1` for a precise attribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ResourceUsage {
+    /// Number of the taken samples.
+    pub samples: usize,
+
+    /// Resident set size of the process, in bytes.
+    pub memory: Sampled,
+
+    /// CPU usage of the process between consecutive samples, in percents of
+    /// a single CPU core.
+    pub cpu: Sampled,
+
+    /// Number of the process threads.
+    pub threads: Sampled,
+}
+
+/// Minimum, maximum and average of the sampled values.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Sampled {
+    /// Minimum of the sampled values.
+    pub min: f64,
+
+    /// Maximum of the sampled values.
+    pub max: f64,
+
+    /// Average of the sampled values.
+    pub avg: f64,
+}
+
+impl Sampled {
+    /// Summarizes the provided `values`, if there are any.
+    #[must_use]
+    pub fn of(values: impl IntoIterator<Item = f64>) -> Option<Self> {
+        let (count, sum, min, max) = values.into_iter().fold(
+            (0_u32, 0.0, f64::INFINITY, f64::NEG_INFINITY),
+            |(count, sum, min, max), v| {
+                (count + 1, sum + v, min.min(v), max.max(v))
+            },
+        );
+        (count > 0).then(|| Self { min, max, avg: sum / f64::from(count) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sampled;
+
+    #[test]
+    fn summarizes_values() {
+        assert_eq!(
+            Sampled::of([3.0, 1.0, 2.0]),
+            Some(Sampled { min: 1.0, max: 3.0, avg: 2.0 }),
+        );
+        assert_eq!(Sampled::of([]), None);
+    }
+}
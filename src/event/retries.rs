@@ -6,6 +6,7 @@ use std::hash::Hash;
 ///
 /// [`gherkin::Scenario`]: gherkin::Scenario
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Retries {
     /// Current retry attempt.
     pub current: usize,
@@ -6,6 +6,11 @@ use super::{RetryableScenario, Source};
 ///
 /// [Rule]: https://cucumber.io/docs/gherkin/reference#rule
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "", rename_all = "snake_case")
+)]
 pub enum Rule<World> {
     /// [`Rule`] execution being started.
     ///
@@ -13,7 +18,10 @@ pub enum Rule<World> {
     Started,
 
     /// [`gherkin::Scenario`] event.
-    Scenario(Source<gherkin::Scenario>, RetryableScenario<World>),
+    ///
+    /// [`RetryableScenario`] is boxed, as it's much larger than other
+    /// variants.
+    Scenario(Source<gherkin::Scenario>, Box<RetryableScenario<World>>),
 
     /// [`Rule`] execution being finished.
     ///
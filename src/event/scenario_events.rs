@@ -3,23 +3,28 @@
 use std::sync::Arc;
 
 use super::{
-    Hook, HookType, Source, Step, StepError, event_struct::Info,
-    retries::Retries,
+    Hook, HookScope, HookType, ResourceUsage, SlaViolation, Source, Step,
+    StepError, StepTiming, event_struct::Info, retries::Retries,
 };
-use crate::step;
+use crate::{runner::basic::ScenarioId, step};
 
 /// Event specific to a particular [Scenario].
 ///
 /// [Scenario]: https://cucumber.io/docs/gherkin/reference#example
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "", rename_all = "snake_case")
+)]
 pub enum Scenario<World> {
     /// [`gherkin::Scenario`] execution being started.
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
     Started,
 
-    /// [`Hook`] event.
-    Hook(HookType, Hook<World>),
+    /// [`Hook`] event of the [`HookScope`].
+    Hook(HookType, HookScope, Hook<World>),
 
     /// [`Background`] [`crate::step::Step`] event.
     ///
@@ -32,10 +37,17 @@ pub enum Scenario<World> {
     /// [`gherkin::Scenario`]'s log entry is emitted.
     Log(String),
 
+    /// [`gherkin::Scenario`] has run longer than the duration SLA of one of
+    /// its tags.
+    SlaViolated(SlaViolation),
+
     /// [`gherkin::Scenario`] execution being finished.
     ///
+    /// Carries the [`ResourceUsage`] sampled during the execution, if the
+    /// resources sampling is enabled and supported on the current platform.
+    ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
-    Finished,
+    Finished(Option<ResourceUsage>),
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
@@ -44,13 +56,14 @@ impl<World> Clone for Scenario<World> {
     fn clone(&self) -> Self {
         match self {
             Self::Started => Self::Started,
-            Self::Hook(ty, ev) => Self::Hook(*ty, ev.clone()),
+            Self::Hook(ty, scope, ev) => Self::Hook(*ty, *scope, ev.clone()),
             Self::Background(bg, ev) => {
                 Self::Background(bg.clone(), ev.clone())
             }
             Self::Step(st, ev) => Self::Step(st.clone(), ev.clone()),
             Self::Log(msg) => Self::Log(msg.clone()),
-            Self::Finished => Self::Finished,
+            Self::SlaViolated(v) => Self::SlaViolated(v.clone()),
+            Self::Finished(usage) => Self::Finished(*usage),
         }
     }
 }
@@ -60,16 +73,16 @@ impl<World> Scenario<World> {
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
     #[must_use]
-    pub const fn hook_started(which: HookType) -> Self {
-        Self::Hook(which, Hook::Started)
+    pub const fn hook_started(which: HookType, scope: HookScope) -> Self {
+        Self::Hook(which, scope, Hook::Started)
     }
 
     /// Constructs an event of a passed [`gherkin::Scenario`] hook.
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
     #[must_use]
-    pub const fn hook_passed(which: HookType) -> Self {
-        Self::Hook(which, Hook::Passed)
+    pub const fn hook_passed(which: HookType, scope: HookScope) -> Self {
+        Self::Hook(which, scope, Hook::Passed)
     }
 
     /// Constructs an event of a failed [`gherkin::Scenario`] hook.
@@ -78,10 +91,11 @@ impl<World> Scenario<World> {
     #[must_use]
     pub fn hook_failed(
         which: HookType,
+        scope: HookScope,
         world: Option<Arc<World>>,
         info: Info,
     ) -> Self {
-        Self::Hook(which, Hook::Failed(world, info))
+        Self::Hook(which, scope, Hook::Failed(world, info))
     }
 
     /// Constructs an event of a [`crate::step::Step`] being started.
@@ -112,7 +126,14 @@ impl<World> Scenario<World> {
         captures: regex::CaptureLocations,
         loc: Option<step::Location>,
     ) -> Self {
-        Self::Step(step.into(), Step::Passed { captures, location: loc })
+        Self::Step(
+            step.into(),
+            Step::Passed {
+                captures,
+                location: loc,
+                timing: StepTiming::default(),
+            },
+        )
     }
 
     /// Constructs an event of a passed [`Background`] [`crate::step::Step`].
@@ -125,7 +146,14 @@ impl<World> Scenario<World> {
         captures: regex::CaptureLocations,
         loc: Option<step::Location>,
     ) -> Self {
-        Self::Background(step.into(), Step::Passed { captures, location: loc })
+        Self::Background(
+            step.into(),
+            Step::Passed {
+                captures,
+                location: loc,
+                timing: StepTiming::default(),
+            },
+        )
     }
 
     /// Constructs an event of a skipped [`crate::step::Step`].
@@ -133,7 +161,7 @@ impl<World> Scenario<World> {
     /// [`crate::step::Step`]: gherkin::Step
     #[must_use]
     pub fn step_skipped(step: impl Into<Source<gherkin::Step>>) -> Self {
-        Self::Step(step.into(), Step::Skipped)
+        Self::Step(step.into(), Step::Skipped(None))
     }
     /// Constructs an event of a skipped [`Background`] [`crate::step::Step`].
     ///
@@ -143,7 +171,7 @@ impl<World> Scenario<World> {
     pub fn background_step_skipped(
         step: impl Into<Source<gherkin::Step>>,
     ) -> Self {
-        Self::Background(step.into(), Step::Skipped)
+        Self::Background(step.into(), Step::Skipped(None))
     }
 
     /// Constructs an event of a failed [`crate::step::Step`].
@@ -159,7 +187,16 @@ impl<World> Scenario<World> {
     ) -> Self {
         Self::Step(
             step.into(),
-            Step::Failed { captures, location: loc, world, error: info.into() },
+            Step::Failed {
+                captures,
+                location: loc,
+                matched: None,
+                world,
+                error: info.into(),
+                owner: None,
+                timing: StepTiming::default(),
+                backtrace: None,
+            },
         )
     }
 
@@ -177,7 +214,16 @@ impl<World> Scenario<World> {
     ) -> Self {
         Self::Background(
             step.into(),
-            Step::Failed { captures, location: loc, world, error: info.into() },
+            Step::Failed {
+                captures,
+                location: loc,
+                matched: None,
+                world,
+                error: info.into(),
+                owner: None,
+                timing: StepTiming::default(),
+                backtrace: None,
+            },
         )
     }
 
@@ -187,7 +233,7 @@ impl<World> Scenario<World> {
         self,
         retries: Option<Retries>,
     ) -> RetryableScenario<World> {
-        RetryableScenario { event: self, retries }
+        RetryableScenario { event: self, retries, id: None }
     }
 }
 
@@ -195,19 +241,30 @@ impl<World> Scenario<World> {
 ///
 /// [Scenario]: https://cucumber.io/docs/gherkin/reference#example
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "")
+)]
 pub struct RetryableScenario<World> {
     /// Happened [`gherkin::Scenario`] event.
     pub event: Scenario<World>,
 
     /// Number of [`Retries`].
     pub retries: Option<Retries>,
+
+    /// [`ScenarioId`] of this [`gherkin::Scenario`] attempt, correlating all
+    /// its events (if provided by the [`Runner`]).
+    ///
+    /// [`Runner`]: crate::Runner
+    pub id: Option<ScenarioId>,
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
 // bound imposed by `#[derive(Clone)]`.
 impl<World> Clone for RetryableScenario<World> {
     fn clone(&self) -> Self {
-        Self { event: self.event.clone(), retries: self.retries }
+        Self { event: self.event.clone(), retries: self.retries, id: self.id }
     }
 }
 
@@ -215,11 +272,22 @@ impl<World> Clone for RetryableScenario<World> {
 ///
 /// [Scenario]: https://cucumber.io/docs/gherkin/reference#example
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum ScenarioFinished {
     /// [`Before`] [`Hook::Failed`].
     ///
     /// [`Before`]: HookType::Before
-    BeforeHookFailed(Info),
+    BeforeHookFailed(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "super::serialization::info")
+        )]
+        Info,
+    ),
 
     /// [`Step::Passed`].
     StepPassed,
@@ -229,6 +297,10 @@ pub enum ScenarioFinished {
 
     /// [`Step::Failed`].
     StepFailed(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "super::serialization::captures::option")
+        )]
         Option<regex::CaptureLocations>,
         Option<step::Location>,
         StepError,
@@ -268,9 +340,9 @@ mod tests {
 
     #[test]
     fn test_scenario_finished_event() {
-        let event: Scenario<TestWorld> = Scenario::Finished;
+        let event: Scenario<TestWorld> = Scenario::Finished(None);
         match event {
-            Scenario::Finished => {}
+            Scenario::Finished(_) => {}
             _ => panic!("Expected Finished event"),
         }
     }
@@ -289,20 +361,21 @@ mod tests {
     fn test_scenario_clone() {
         let events = vec![
             Scenario::<TestWorld>::Started,
-            Scenario::Finished,
+            Scenario::Finished(None),
             Scenario::Log("test".to_string()),
-            Scenario::hook_started(HookType::Before),
-            Scenario::hook_passed(HookType::After),
+            Scenario::hook_started(HookType::Before, HookScope::Function),
+            Scenario::hook_passed(HookType::After, HookScope::World),
         ];
 
         for event in events {
             let cloned = event.clone();
             match (&event, &cloned) {
                 (Scenario::Started, Scenario::Started) => {}
-                (Scenario::Finished, Scenario::Finished) => {}
+                (Scenario::Finished(_), Scenario::Finished(_)) => {}
                 (Scenario::Log(a), Scenario::Log(b)) => assert_eq!(a, b),
-                (Scenario::Hook(t1, h1), Scenario::Hook(t2, h2)) => {
+                (Scenario::Hook(t1, s1, h1), Scenario::Hook(t2, s2, h2)) => {
                     assert_eq!(t1, t2);
+                    assert_eq!(s1, s2);
                     match (h1, h2) {
                         (Hook::Started, Hook::Started) => {}
                         (Hook::Passed, Hook::Passed) => {}
@@ -316,28 +389,43 @@ mod tests {
 
     #[test]
     fn test_hook_events() {
-        let started = Scenario::<TestWorld>::hook_started(HookType::Before);
+        let started = Scenario::<TestWorld>::hook_started(
+            HookType::Before,
+            HookScope::Function,
+        );
         assert!(matches!(
             started,
-            Scenario::Hook(HookType::Before, Hook::Started)
+            Scenario::Hook(
+                HookType::Before,
+                HookScope::Function,
+                Hook::Started
+            )
         ));
 
-        let passed = Scenario::<TestWorld>::hook_passed(HookType::After);
+        let passed = Scenario::<TestWorld>::hook_passed(
+            HookType::After,
+            HookScope::Fixtures,
+        );
         assert!(matches!(
             passed,
-            Scenario::Hook(HookType::After, Hook::Passed)
+            Scenario::Hook(HookType::After, HookScope::Fixtures, Hook::Passed)
         ));
 
         let world = Arc::new(TestWorld { value: "test".to_string() });
         let info = Arc::new("Hook failed".to_string());
         let failed = Scenario::hook_failed(
             HookType::Before,
+            HookScope::World,
             Some(world.clone()),
             info.clone(),
         );
 
         match failed {
-            Scenario::Hook(HookType::Before, Hook::Failed(w, failure_info)) => {
+            Scenario::Hook(
+                HookType::Before,
+                HookScope::World,
+                Hook::Failed(w, failure_info),
+            ) => {
                 assert!(w.is_some());
                 assert_eq!(w.unwrap().value, "test");
                 // Test that failure info contains the expected error information
@@ -401,13 +489,13 @@ mod tests {
 
         let step_skipped =
             Scenario::<TestWorld>::step_skipped(Source::new(step.clone()));
-        assert!(matches!(step_skipped, Scenario::Step(_, Step::Skipped)));
+        assert!(matches!(step_skipped, Scenario::Step(_, Step::Skipped(None))));
 
         let bg_step_skipped =
             Scenario::<TestWorld>::background_step_skipped(Source::new(step));
         assert!(matches!(
             bg_step_skipped,
-            Scenario::Background(_, Step::Skipped)
+            Scenario::Background(_, Step::Skipped(None))
         ));
     }
 
@@ -464,7 +552,7 @@ mod tests {
         assert_eq!(retryable.retries.unwrap().current, 1);
         assert_eq!(retryable.retries.unwrap().left, 2);
 
-        let no_retry = Scenario::<TestWorld>::Finished.with_retries(None);
+        let no_retry = Scenario::<TestWorld>::Finished(None).with_retries(None);
         assert!(no_retry.retries.is_none());
     }
 
@@ -474,6 +562,7 @@ mod tests {
         let retryable = RetryableScenario {
             event: Scenario::<TestWorld>::Started,
             retries: Some(retries),
+            id: None,
         };
 
         let cloned = retryable.clone();
@@ -0,0 +1,149 @@
+//! Recording and restoring of [`CaptureLocations`].
+//!
+//! [`CaptureLocations`] can only be obtained by matching a [`Regex`], so they
+//! are recorded as spans of the non-nested capture groups (the only ones
+//! highlighted by the [`writer::Basic`]), and restored by matching a
+//! [`Regex`] reproducing these spans.
+//!
+//! [`writer::Basic`]: crate::writer::Basic
+
+use regex::{CaptureLocations, Regex};
+use serde::{Deserialize as _, Deserializer, Serialize as _, Serializer};
+
+/// Serializes the provided [`CaptureLocations`] as their [`spans()`].
+pub(crate) fn serialize<S: Serializer>(
+    captures: &CaptureLocations,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    spans(captures).serialize(serializer)
+}
+
+/// Deserializes [`CaptureLocations`] from their [`spans()`].
+///
+/// As the matched text is not serialized, the [`CaptureLocations`] are
+/// [`restore()`]d against a blank text of the sufficient length, keeping the
+/// offsets only.
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<CaptureLocations, D::Error> {
+    let spans = Vec::<(usize, usize)>::deserialize(deserializer)?;
+    let len = spans.iter().map(|&(_, end)| end).max().unwrap_or_default();
+    Ok(restore(&" ".repeat(len), &spans))
+}
+
+/// [`serialize()`] and [`deserialize()`] of optional [`CaptureLocations`].
+pub(crate) mod option {
+    use regex::CaptureLocations;
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    /// Serializes the provided optional [`CaptureLocations`] as their
+    /// [`spans()`].
+    ///
+    /// [`spans()`]: super::spans
+    #[expect(clippy::ref_option, reason = "`serde(with)` signature")]
+    pub(crate) fn serialize<S: Serializer>(
+        captures: &Option<CaptureLocations>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match captures {
+            Some(c) => serializer.serialize_some(&super::spans(c)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes optional [`CaptureLocations`] from their [`spans()`].
+    ///
+    /// [`spans()`]: super::spans
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<CaptureLocations>, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(transparent)]
+        /// Wrapper deserializing [`CaptureLocations`] via
+        /// [`super::deserialize()`].
+        struct Wrapper(
+            #[serde(deserialize_with = "super::deserialize")] CaptureLocations,
+        );
+
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|w| w.0))
+    }
+}
+
+/// Returns the spans of the non-nested capture groups of the provided
+/// [`CaptureLocations`].
+pub(crate) fn spans(captures: &CaptureLocations) -> Vec<(usize, usize)> {
+    let mut last = 0;
+    (1..captures.len())
+        .filter_map(|group| captures.get(group))
+        .filter(|&(start, end)| {
+            let nested = last > start;
+            if !nested {
+                last = end;
+            }
+            !nested
+        })
+        .collect()
+}
+
+/// Restores [`CaptureLocations`] having the provided `spans` of the `text`.
+///
+/// Invalid `spans` result in [`CaptureLocations`] without any groups.
+pub(crate) fn restore(
+    text: &str,
+    spans: &[(usize, usize)],
+) -> CaptureLocations {
+    let pattern = pattern(text, spans)
+        .unwrap_or_else(|| format!("^{}$", regex::escape(text)));
+    #[expect(clippy::unwrap_used, reason = "escaped pattern is always valid")]
+    let regex = Regex::new(&pattern).unwrap();
+    let mut captures = regex.capture_locations();
+    _ = regex.captures_read(&mut captures, text);
+    captures
+}
+
+/// Builds a [`Regex`] pattern matching the whole `text` and capturing the
+/// provided `spans` of it.
+fn pattern(text: &str, spans: &[(usize, usize)]) -> Option<String> {
+    let mut pattern = String::from("^");
+    let mut last = 0;
+    for &(start, end) in spans {
+        pattern.push_str(&regex::escape(text.get(last..start)?));
+        pattern.push('(');
+        pattern.push_str(&regex::escape(text.get(start..end)?));
+        pattern.push(')');
+        last = end;
+    }
+    pattern.push_str(&regex::escape(text.get(last..)?));
+    pattern.push('$');
+    Some(pattern)
+}
+
+#[cfg(test)]
+mod spec {
+    use regex::Regex;
+
+    use super::{restore, spans};
+
+    #[test]
+    fn restores_non_nested_groups() {
+        let text = "I have 12 cucumbers in 3 (big) bags";
+        let regex =
+            Regex::new(r"I have ((\d)+) cucumbers in (\d) \((.*)\) bags")
+                .unwrap();
+        let mut original = regex.capture_locations();
+        _ = regex.captures_read(&mut original, text).unwrap();
+
+        let recorded = spans(&original);
+        assert_eq!(recorded, [(7, 9), (23, 24), (26, 29)]);
+
+        let restored = restore(text, &recorded);
+        assert_eq!(spans(&restored), recorded);
+    }
+
+    #[test]
+    fn ignores_invalid_spans() {
+        let restored = restore("abc", &[(2, 10)]);
+
+        assert_eq!(restored.len(), 1);
+    }
+}
@@ -0,0 +1,25 @@
+//! [`serde`] representation of [`Info`] panic payloads.
+//!
+//! As a panic payload may be of any type, it's serialized as its
+//! [`coerce_error()`] message, and deserialized as a [`String`] one.
+
+use std::sync::Arc;
+
+use serde::{Deserialize as _, Deserializer, Serializer};
+
+use crate::{event::Info, writer::basic::coerce_error};
+
+/// Serializes the provided [`Info`] as its [`coerce_error()`] message.
+pub(crate) fn serialize<S: Serializer>(
+    info: &Info,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&coerce_error(info))
+}
+
+/// Deserializes an [`Info`] holding the serialized [`String`] message.
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Info, D::Error> {
+    String::deserialize(deserializer).map(|msg| -> Info { Arc::new(msg) })
+}
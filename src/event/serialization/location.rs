@@ -0,0 +1,49 @@
+//! [`serde`] deserialization of [`step::Location`]s.
+
+use std::{
+    collections::HashSet,
+    sync::{LazyLock, Mutex, PoisonError},
+};
+
+use serde::{Deserialize, Deserializer};
+
+use crate::step;
+
+/// Already deserialized [`step::Location::path`]s, so every distinct one is
+/// leaked only once.
+static PATHS: LazyLock<Mutex<HashSet<&'static str>>> =
+    LazyLock::new(Mutex::default);
+
+/// Owned [`serde`] representation of a [`step::Location`].
+#[derive(Deserialize)]
+struct Owned {
+    /// [`step::Location::path`].
+    path: String,
+
+    /// [`step::Location::line`].
+    line: u32,
+
+    /// [`step::Location::column`].
+    column: u32,
+}
+
+/// Returns the `'static` version of the provided `path`, leaking it if not
+/// deserialized before.
+fn intern(path: String) -> &'static str {
+    let mut paths = PATHS.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(&interned) = paths.get(path.as_str()) {
+        return interned;
+    }
+    let leaked: &'static str = Box::leak(path.into_boxed_str());
+    _ = paths.insert(leaked);
+    leaked
+}
+
+impl<'de> Deserialize<'de> for step::Location {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let Owned { path, line, column } = Owned::deserialize(deserializer)?;
+        Ok(Self::new(intern(path), line, column))
+    }
+}
@@ -0,0 +1,241 @@
+//! [`serde`] representation of the [`event`]s.
+//!
+//! Every [`Event`] is serialized along with the [`SCHEMA_VERSION`] it's
+//! represented in, so external tooling may detect incompatible changes.
+//! Values not representable on the wire are reduced:
+//! - [`World`]s are omitted (and deserialized as [`None`]);
+//! - panic payloads ([`Info`]) are stringified;
+//! - [`CaptureLocations`] are reduced to the spans of their non-nested
+//!   capture groups;
+//! - [`Source`]s are serialized as the [`gherkin`] values they wrap, so
+//!   deserialized events don't share them anymore.
+//!
+//! [`CaptureLocations`]: regex::CaptureLocations
+//! [`event`]: crate::event
+//! [`Info`]: super::Info
+//! [`World`]: crate::World
+
+pub(crate) mod captures;
+pub(crate) mod info;
+mod location;
+
+#[cfg(feature = "timestamps")]
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use super::{Event, Source};
+use crate::step::HashableRegex;
+#[cfg(feature = "timestamps")]
+use crate::time::SystemTime;
+
+/// Version of the [`serde`] representation of the [`event`]s, serialized
+/// along with every [`Event`].
+///
+/// It's increased on every incompatible change of the representation.
+///
+/// [`event`]: crate::event
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Borrowed [`serde`] representation of an [`Event`].
+#[derive(Serialize)]
+struct VersionedRef<'e, T: ?Sized> {
+    /// [`SCHEMA_VERSION`] of the representation.
+    schema_version: u32,
+
+    /// [`Event::at`] time.
+    #[cfg(feature = "timestamps")]
+    at: &'e SystemTime,
+
+    /// [`Event::offset`] from the start of the run.
+    #[cfg(feature = "timestamps")]
+    offset: &'e Duration,
+
+    /// [`Event::value`].
+    value: &'e T,
+}
+
+/// Owned [`serde`] representation of an [`Event`].
+#[derive(Deserialize)]
+struct Versioned<T> {
+    /// [`SCHEMA_VERSION`] of the representation.
+    schema_version: u32,
+
+    /// [`Event::at`] time.
+    #[cfg(feature = "timestamps")]
+    at: SystemTime,
+
+    /// [`Event::offset`] from the start of the run.
+    #[cfg(feature = "timestamps")]
+    #[serde(default)]
+    offset: Duration,
+
+    /// [`Event::value`].
+    value: T,
+}
+
+impl<T: Serialize + ?Sized> Serialize for Event<T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        VersionedRef {
+            schema_version: SCHEMA_VERSION,
+            #[cfg(feature = "timestamps")]
+            at: &self.at,
+            #[cfg(feature = "timestamps")]
+            offset: &self.offset,
+            value: &self.value,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Event<T> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let versioned = Versioned::<T>::deserialize(deserializer)?;
+        if versioned.schema_version != SCHEMA_VERSION {
+            return Err(de::Error::custom(format!(
+                "unsupported event schema version {}, expected {}",
+                versioned.schema_version, SCHEMA_VERSION,
+            )));
+        }
+        Ok(Self {
+            #[cfg(feature = "timestamps")]
+            at: versioned.at,
+            #[cfg(feature = "timestamps")]
+            offset: versioned.offset,
+            value: versioned.value,
+        })
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for Source<T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Source<T> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Serialize for HashableRegex {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for HashableRegex {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        regex::Regex::new(&pattern).map(Self::new).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use std::sync::Arc;
+
+    use super::SCHEMA_VERSION;
+    use crate::{
+        event::{self, Event, Source},
+        step,
+    };
+
+    #[test]
+    fn roundtrips_step_event() {
+        let regex = regex::Regex::new(r"^(\d+) (cats?)$").unwrap();
+        let mut captures = regex.capture_locations();
+        _ = regex.captures_read(&mut captures, "2 cats");
+        let ev = Event::new(event::Scenario::<()>::Step(
+            Source::new(gherkin::Step {
+                keyword: "Given ".into(),
+                ty: gherkin::StepType::Given,
+                value: "2 cats".into(),
+                docstring: None,
+                table: None,
+                span: gherkin::Span { start: 0, end: 12 },
+                position: gherkin::LineCol { line: 3, col: 5 },
+            }),
+            event::Step::Failed {
+                captures: Some(captures),
+                location: Some(step::Location::new("steps.rs", 7, 1)),
+                matched: Some(Arc::new(event::StepMatch {
+                    pattern: r"^(\d+) (cats?)$".into(),
+                    captures: vec![
+                        (None, "2".into()),
+                        (None, "cats".into()),
+                    ],
+                })),
+                world: Some(Arc::new(())),
+                error: event::StepError::Panic(Arc::new("boom")),
+                owner: None,
+                timing: event::StepTiming::default(),
+                backtrace: Some("0: steps::boom".into()),
+            },
+        ));
+
+        let json = serde_json::to_value(&ev).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+
+        let back: Event<event::Scenario<()>> =
+            serde_json::from_value(json).unwrap();
+        #[cfg(feature = "timestamps")]
+        assert_eq!((back.at, back.offset), (ev.at, ev.offset));
+        let event::Scenario::Step(
+            s,
+            event::Step::Failed {
+                captures,
+                location,
+                matched,
+                world,
+                error,
+                backtrace,
+                ..
+            },
+        ) = back.value
+        else {
+            panic!("unexpected event");
+        };
+        assert_eq!(s.value, "2 cats");
+        let captures = captures.unwrap();
+        assert_eq!(
+            (captures.get(1), captures.get(2)),
+            (Some((0, 1)), Some((2, 6)))
+        );
+        assert_eq!(location, Some(step::Location::new("steps.rs", 7, 1)));
+        let matched = matched.unwrap();
+        assert_eq!(matched.pattern, r"^(\d+) (cats?)$");
+        assert_eq!(matched.captures[1], (None, "cats".into()));
+        assert!(world.is_none());
+        assert_eq!(error.to_string(), "Step panicked. Captured output: boom");
+        assert_eq!(backtrace.as_deref(), Some("0: steps::boom"));
+    }
+
+    #[test]
+    fn rejects_unknown_schema_version() {
+        let mut json =
+            serde_json::to_value(Event::new(event::Cucumber::<()>::Started))
+                .unwrap();
+        json["schema_version"] = (SCHEMA_VERSION + 1).into();
+
+        assert!(
+            serde_json::from_value::<Event<event::Cucumber<()>>>(json).is_err(),
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Violations of [`gherkin::Scenario`] duration SLAs.
+
+use std::{fmt, time::Duration};
+
+/// Violation of a duration SLA by a [`gherkin::Scenario`], running longer than
+/// the budget configured for one of its tags.
+///
+/// It's reported separately from the [`Step`] failures, so the slow
+/// [`gherkin::Scenario`]s are distinguishable from the broken ones.
+///
+/// [`Step`]: super::Step
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SlaViolation {
+    /// Tag (without the leading `@`) the violated budget is configured for.
+    pub tag: String,
+
+    /// Violated duration budget.
+    pub budget: Duration,
+
+    /// Actual duration of the [`gherkin::Scenario`].
+    pub elapsed: Duration,
+
+    /// Indicator whether this [`SlaViolation`] fails the
+    /// [`gherkin::Scenario`], or only the whole run.
+    pub fails_scenario: bool,
+}
+
+impl fmt::Display for SlaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "@{} SLA of {} exceeded: took {}",
+            self.tag,
+            humantime::format_duration(self.budget),
+            humantime::format_duration(Duration::from_millis(
+                self.elapsed.as_millis().try_into().unwrap_or(u64::MAX),
+            )),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::SlaViolation;
+
+    #[test]
+    fn displays_rounded_to_millis() {
+        let violation = SlaViolation {
+            tag: "fast".into(),
+            budget: Duration::from_secs(2),
+            elapsed: Duration::from_micros(2_500_700),
+            fails_scenario: true,
+        };
+
+        assert_eq!(
+            violation.to_string(),
+            "@fast SLA of 2s exceeded: took 2s 500ms",
+        );
+    }
+}
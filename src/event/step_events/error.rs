@@ -0,0 +1,120 @@
+//! Errors of executing [`crate::step::Step`]s, including their timeouts.
+//!
+//! [`crate::step::Step`]: gherkin::Step
+
+use std::time::Duration;
+
+use derive_more::with_trait::{Display, Error, From};
+
+use crate::{event::event_struct::Info, step, writer::basic::coerce_error};
+
+/// Error of executing a [`crate::step::Step`].
+///
+/// [`crate::step::Step`]: gherkin::Step
+#[derive(Clone, Debug, Display, Error, From)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum StepError {
+    /// [`crate::step::Step`] doesn't match any [`regex::Regex`].
+    ///
+    /// It's emitted whenever a [`Step::Skipped`] event cannot be tolerated
+    /// (such as when [`fail_on_skipped()`] is used).
+    ///
+    /// Counted separately from other failures by [`writer::Stats`], and fails
+    /// the run only if [`Cucumber::strict()`] is used.
+    ///
+    /// [`Cucumber::strict()`]: crate::Cucumber::strict
+    /// [`Step::Skipped`]: super::Step::Skipped
+    /// [`regex::Regex`]: regex::Regex
+    /// [`fail_on_skipped()`]: crate::WriterExt::fail_on_skipped()
+    /// [`writer::Stats`]: crate::writer::Stats
+    #[display("Step doesn't match any function")]
+    NotFound,
+
+    /// [`crate::step::Step`] matches multiple [`regex::Regex`]es.
+    ///
+    /// [`regex::Regex`]: regex::Regex
+    /// [`crate::step::Step`]: gherkin::Step
+    #[display("Step match is ambiguous: {_0}")]
+    AmbiguousMatch(step::AmbiguousMatchError),
+
+    /// [`crate::step::Step`] panicked.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[display("Step panicked. Captured output: {}", coerce_error(_0))]
+    Panic(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::event::serialization::info")
+        )]
+        #[error(not(source))]
+        Info,
+    ),
+
+    /// Capture group of a [`crate::step::Step`] doesn't match a typed argument
+    /// of its function.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[display("Step argument mismatch: {_0}")]
+    ArgMismatch(Box<step::ArgError>),
+
+    /// [`crate::step::Step`] function returned an [`Err`].
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[display("Step returned an error: {_0}")]
+    Returned(step::Failure),
+
+    /// Soft assertions of a [`crate::step::Step`] function failed.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[display("Step checks failed:\n{_0}")]
+    Checks(step::CheckFailures),
+
+    /// [`crate::step::Step`] function didn't finish within its timeout (set
+    /// via [`Basic::step_timeout()`] or `@timeout(<duration>)` tag), and so
+    /// was cancelled.
+    ///
+    /// Counted separately from other failures by [`writer::Stats`].
+    ///
+    /// [`Basic::step_timeout()`]: crate::runner::Basic::step_timeout
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [`writer::Stats`]: crate::writer::Stats
+    #[display("Step timed out after {}", humantime::format_duration(*_0))]
+    #[from(skip)]
+    Timeout(#[error(not(source))] Duration),
+
+    /// [`crate::step::Step`] function is marked as not implemented yet (via
+    /// [`step::Pending::raise()`]).
+    ///
+    /// Counted separately from other failures by [`writer::Stats`], and fails
+    /// the run only if [`Cucumber::strict()`] is used.
+    ///
+    /// [`Cucumber::strict()`]: crate::Cucumber::strict
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [`writer::Stats`]: crate::writer::Stats
+    #[display("Step is pending: {_0}")]
+    Pending(step::Pending),
+}
+
+impl StepError {
+    /// Indicates whether this [`StepError`] is a [`StepError::Timeout`].
+    #[must_use]
+    pub const fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout(_))
+    }
+
+    /// Indicates whether this [`StepError`] is a [`StepError::NotFound`].
+    #[must_use]
+    pub const fn is_undefined(&self) -> bool {
+        matches!(self, Self::NotFound)
+    }
+
+    /// Indicates whether this [`StepError`] is a [`StepError::Pending`].
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending(_))
+    }
+}
@@ -0,0 +1,199 @@
+//! Step-level events and errors.
+
+mod error;
+mod timing;
+
+use std::{sync::Arc, time::Duration};
+
+pub use self::{
+    error::StepError,
+    timing::{StepMatch, StepTiming},
+};
+use super::Custom;
+use crate::step;
+
+/// Event specific to a particular [Step].
+///
+/// [Step]: https://cucumber.io/docs/gherkin/reference#step
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "", rename_all = "snake_case")
+)]
+pub enum Step<World> {
+    /// [`crate::step::Step`] execution being started.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    Started,
+
+    /// [`crate::step::Step`] still being executed, emitted periodically for
+    /// long-running [`crate::step::Step`]s only if a heartbeat is configured
+    /// for the [`runner::Basic`].
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [`runner::Basic`]: crate::runner::Basic
+    Heartbeat {
+        /// Time elapsed since the [`crate::step::Step`] execution has started.
+        ///
+        /// [`crate::step::Step`]: gherkin::Step
+        elapsed: Duration,
+    },
+
+    /// [`Custom`] event published by the [`crate::step::Step`] function via
+    /// [`step::Context::publish()`] while being executed.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    Published(Custom),
+
+    /// [`crate::step::Step`] being skipped.
+    ///
+    /// That means either there is no [`regex::Regex`] matching
+    /// [`crate::step::Step`] in a [`step::Collection`], or a previous
+    /// [`crate::step::Step`] has been skipped, or its function has skipped
+    /// itself with the provided [`step::Skip`] reason.
+    ///
+    /// [`regex::Regex`]: regex::Regex
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [`step::Collection`]: crate::step::Collection
+    Skipped(Option<step::Skip>),
+
+    /// [`crate::step::Step`] not being run, because a previous one has failed,
+    /// while [`runner::Basic`] is configured to mark the rest ones explicitly
+    /// (see [`AfterPanic::NotRun`]).
+    ///
+    /// [`AfterPanic::NotRun`]: crate::runner::basic::AfterPanic::NotRun
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [`runner::Basic`]: crate::runner::Basic
+    NotRun,
+
+    /// [`crate::step::Step`] passed.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    Passed {
+        /// [`regex::Regex`] [`CaptureLocations`] of the matched [`crate::step::Step`].
+        ///
+        /// [`CaptureLocations`]: regex::CaptureLocations
+        /// [`regex::Regex`]: regex::Regex
+        /// [`crate::step::Step`]: gherkin::Step
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "super::serialization::captures")
+        )]
+        captures: regex::CaptureLocations,
+
+        /// [`Location`] of the [`fn`] that matched this [`crate::step::Step`].
+        ///
+        /// [`Location`]: step::Location
+        /// [`crate::step::Step`]: gherkin::Step
+        location: Option<step::Location>,
+
+        /// [`StepTiming`] of this [`crate::step::Step`].
+        ///
+        /// [`crate::step::Step`]: gherkin::Step
+        #[cfg_attr(feature = "serde", serde(default))]
+        timing: StepTiming,
+    },
+
+    /// [`crate::step::Step`] failed.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    Failed {
+        /// [`regex::Regex`] [`CaptureLocations`] of the matched [`crate::step::Step`] (if any).
+        ///
+        /// [`CaptureLocations`]: regex::CaptureLocations
+        /// [`regex::Regex`]: regex::Regex
+        /// [`crate::step::Step`]: gherkin::Step
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "super::serialization::captures::option")
+        )]
+        captures: Option<regex::CaptureLocations>,
+
+        /// [`Location`] of the [`fn`] that matched this [`crate::step::Step`] (if any).
+        ///
+        /// [`Location`]: step::Location
+        /// [`crate::step::Step`]: gherkin::Step
+        location: Option<step::Location>,
+
+        /// [`StepMatch`] of the [`fn`] that matched this [`crate::step::Step`]
+        /// (if any).
+        ///
+        /// [`crate::step::Step`]: gherkin::Step
+        #[cfg_attr(feature = "serde", serde(default))]
+        matched: Option<Arc<StepMatch>>,
+
+        /// [`crate::World`] at the time [`crate::step::Step`] has failed (if any).
+        ///
+        /// [`crate::step::Step`]: gherkin::Step
+        #[cfg_attr(feature = "serde", serde(skip))]
+        world: Option<Arc<World>>,
+
+        /// Error that caused the [`crate::step::Step`] to fail.
+        ///
+        /// [`crate::step::Step`]: gherkin::Step
+        error: StepError,
+
+        /// [`step::Ownership`] of the [`fn`] that matched this
+        /// [`crate::step::Step`] (if recorded).
+        ///
+        /// [`crate::step::Step`]: gherkin::Step
+        owner: Option<Arc<step::Ownership>>,
+
+        /// [`StepTiming`] of this [`crate::step::Step`].
+        ///
+        /// [`crate::step::Step`]: gherkin::Step
+        #[cfg_attr(feature = "serde", serde(default))]
+        timing: StepTiming,
+
+        /// Rendered [`Backtrace`] of the [`StepError::Panic`] (if captured).
+        ///
+        /// Captured only when enabled via `RUST_BACKTRACE` environment
+        /// variable, or forced via [`Basic::force_backtraces()`].
+        ///
+        /// [`Backtrace`]: std::backtrace::Backtrace
+        /// [`Basic::force_backtraces()`]: crate::runner::Basic::force_backtraces
+        #[cfg_attr(feature = "serde", serde(default))]
+        backtrace: Option<Arc<str>>,
+    },
+}
+
+// Manual implementation is required to omit the redundant `World: Clone` trait
+// bound imposed by `#[derive(Clone)]`.
+impl<World> Clone for Step<World> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Started => Self::Started,
+            Self::Heartbeat { elapsed } => {
+                Self::Heartbeat { elapsed: *elapsed }
+            }
+            Self::Published(ev) => Self::Published(ev.clone()),
+            Self::Skipped(reason) => Self::Skipped(reason.clone()),
+            Self::NotRun => Self::NotRun,
+            Self::Passed { captures, location, timing } => Self::Passed {
+                captures: captures.clone(),
+                location: *location,
+                timing: *timing,
+            },
+            Self::Failed {
+                captures,
+                location,
+                matched,
+                world,
+                error,
+                owner,
+                timing,
+                backtrace,
+            } => Self::Failed {
+                captures: captures.clone(),
+                location: *location,
+                matched: matched.clone(),
+                world: world.clone(),
+                error: error.clone(),
+                owner: owner.clone(),
+                timing: *timing,
+                backtrace: backtrace.clone(),
+            },
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Timing and matching details of [`crate::step::Step`]s.
+//!
+//! [`crate::step::Step`]: gherkin::Step
+
+use std::time::Duration;
+
+use crate::step;
+
+/// Breakdown of the time spent on a [`crate::step::Step`], so a slow
+/// [`step::Collection::find()`] may be distinguished from a slow step
+/// function.
+///
+/// Durations of the [`Before`] and [`After`] hooks are not included, being
+/// measured by their own [`Hook`] events.
+///
+/// [`After`]: super::super::HookType::After
+/// [`Before`]: super::super::HookType::Before
+/// [`Hook`]: super::super::Hook
+/// [`crate::step::Step`]: gherkin::Step
+/// [`step::Collection::find()`]: crate::step::Collection::find
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StepTiming {
+    /// Time spent on matching the [`crate::step::Step`] against the step
+    /// functions' patterns.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub matching: Duration,
+
+    /// Time spent on executing the matched step function.
+    ///
+    /// Zero, if no step function has been matched.
+    pub execution: Duration,
+}
+
+/// Step function definition a [`crate::step::Step`] has been matched against,
+/// along with the values resolved from its capture groups.
+///
+/// [`crate::step::Step`]: gherkin::Step
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StepMatch {
+    /// [`regex::Regex`] pattern of the matched step function.
+    ///
+    /// [`regex::Regex`]: regex::Regex
+    pub pattern: String,
+
+    /// Values of the capture groups (the whole match excluded), along with
+    /// their names (if any).
+    pub captures: Vec<(step::CaptureName, String)>,
+}
+
+impl StepMatch {
+    /// Extracts the [`StepMatch`] out of the provided [`step::Context`], if
+    /// its matched pattern is known.
+    #[must_use]
+    pub fn of(ctx: &step::Context) -> Option<Self> {
+        ctx.pattern().map(|pattern| Self {
+            pattern: pattern.to_owned(),
+            captures: ctx.matches().iter().skip(1).cloned().collect(),
+        })
+    }
+}
@@ -0,0 +1,101 @@
+//! Suite hierarchy of a [`gherkin::Feature`], mapped from its directory.
+
+use std::{
+    fmt,
+    path::{Component, Path},
+};
+
+use crate::writer::basic::trim_path;
+
+/// Suite hierarchy of a [`gherkin::Feature`]: names of the directories its
+/// `.feature` file is placed in, relative to the project directory.
+///
+/// So large repositories organized as `features/<domain>/<team>/...` may be
+/// reported as a tree of suites instead of a flat list of
+/// [`gherkin::Feature`]s.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::event::Suite;
+/// #
+/// let suite = Suite::from_path("features/payments/refunds/full.feature");
+///
+/// assert_eq!(suite.dirs(), ["features", "payments", "refunds"]);
+/// assert_eq!(suite.to_string(), "features/payments/refunds");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct Suite(Vec<String>);
+
+impl Suite {
+    /// Maps the provided `.feature` file `path` to its [`Suite`].
+    #[must_use]
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let path = path.to_str().map_or(path, |p| Path::new(trim_path(p)));
+        let dirs = path
+            .parent()
+            .into_iter()
+            .flat_map(Path::components)
+            .filter_map(|c| match c {
+                Component::Normal(dir) => Some(dir.to_string_lossy().into()),
+                Component::Prefix(_)
+                | Component::RootDir
+                | Component::CurDir
+                | Component::ParentDir => None,
+            });
+        Self(dirs.collect())
+    }
+
+    /// Returns the [`Suite`] of the provided [`gherkin::Feature`].
+    ///
+    /// Empty, if the [`gherkin::Feature`] has no path.
+    #[must_use]
+    pub fn of(feature: &gherkin::Feature) -> Self {
+        feature.path.as_ref().map(Self::from_path).unwrap_or_default()
+    }
+
+    /// Returns the directory names of this [`Suite`], from the outermost one.
+    #[must_use]
+    pub fn dirs(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Returns the number of leading directories this [`Suite`] shares with
+    /// the `other` one.
+    #[must_use]
+    pub fn common_depth(&self, other: &Self) -> usize {
+        self.0.iter().zip(&other.0).take_while(|(a, b)| a == b).count()
+    }
+}
+
+impl fmt::Display for Suite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_directories_of_path() {
+        let suite =
+            Suite::from_path("./tests/features/wait/nested/rule.feature");
+
+        assert_eq!(suite.dirs(), ["tests", "features", "wait", "nested"]);
+        assert_eq!(Suite::from_path("rule.feature"), Suite::default());
+        assert_eq!(
+            suite.common_depth(&Suite::from_path(
+                "tests/features/wait/a.feature"
+            )),
+            3,
+        );
+    }
+}
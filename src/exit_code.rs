@@ -0,0 +1,236 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Mapping of run [`Outcome`]s to process exit codes.
+//!
+//! Configured via [`Cucumber::exit_codes()`] or the `--exit-code` CLI option,
+//! so CI pipelines may branch on the kind of failure.
+//!
+//! [`Cucumber::exit_codes()`]: crate::Cucumber::exit_codes
+
+use std::str::FromStr;
+
+use derive_more::with_trait::{Display, Error};
+
+use crate::writer;
+
+/// Kind of an unsuccessful run of [`Cucumber::run_and_exit()`].
+///
+/// [`Cucumber::run_and_exit()`]: crate::Cucumber::run_and_exit
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    /// Errors were encountered while parsing [`gherkin::Feature`]s.
+    #[display("parsing")]
+    ParsingErrors,
+
+    /// At least one [`gherkin::Step`] or hook has failed.
+    #[display("failed")]
+    Failed,
+
+    /// At least one [`gherkin::Step`] has timed out, or a
+    /// [`gherkin::Scenario`] has violated its duration SLA.
+    #[display("timeout")]
+    TimedOut,
+
+    /// At least one [`gherkin::Step`] is undefined or pending, while
+    /// [`Cucumber::strict()`] is used.
+    ///
+    /// [`Cucumber::strict()`]: crate::Cucumber::strict
+    #[display("undefined")]
+    Undefined,
+}
+
+impl Outcome {
+    /// All the existing [`Outcome`]s, in the order of their precedence.
+    pub const ALL: [Self; 4] =
+        [Self::ParsingErrors, Self::Failed, Self::TimedOut, Self::Undefined];
+
+    /// Determines the [`Outcome`] of a run from the provided
+    /// [`writer::Stats`], if it's unsuccessful.
+    ///
+    /// If several [`Outcome`]s apply, the first one of [`Outcome::ALL`] is
+    /// returned. Any other failure reported via
+    /// [`writer::Stats::execution_has_failed()`] is considered as
+    /// [`Outcome::Failed`].
+    #[must_use]
+    pub fn of<W>(writer: &impl writer::Stats<W>, strict: bool) -> Option<Self> {
+        if writer.parsing_errors() > 0 {
+            Some(Self::ParsingErrors)
+        } else if writer.failed_steps() > 0 || writer.hook_errors() > 0 {
+            Some(Self::Failed)
+        } else if writer.timed_out_steps() > 0 || writer.sla_violations() > 0 {
+            Some(Self::TimedOut)
+        } else if strict
+            && writer.undefined_steps() + writer.pending_steps() > 0
+        {
+            Some(Self::Undefined)
+        } else if writer.execution_has_failed() {
+            Some(Self::Failed)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = UnknownOutcomeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|o| o.to_string() == s)
+            .ok_or_else(|| UnknownOutcomeError(s.to_owned()))
+    }
+}
+
+/// Error of parsing an unknown [`Outcome`] name.
+#[derive(Clone, Debug, Display, Error)]
+#[display(
+    "Unknown run outcome `{_0}`, expected `parsing`, `failed`, `timeout` or \
+     `undefined`"
+)]
+pub struct UnknownOutcomeError(#[error(not(source))] pub String);
+
+/// Process exit codes of unsuccessful run [`Outcome`]s.
+///
+/// Successful run always exits with `0`, while every [`Outcome`] exits with
+/// `1` by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{ExitCodes, exit_code::Outcome};
+/// #
+/// let codes = ExitCodes::new()
+///     .with(Outcome::ParsingErrors, 2)
+///     .with(Outcome::Undefined, 3)
+///     .with(Outcome::TimedOut, 4);
+///
+/// assert_eq!(codes.code(Outcome::Failed), 1);
+/// assert_eq!(codes.code(Outcome::Undefined), 3);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExitCodes {
+    /// Exit code of [`Outcome::ParsingErrors`].
+    pub parsing_errors: i32,
+
+    /// Exit code of [`Outcome::Failed`].
+    pub failed: i32,
+
+    /// Exit code of [`Outcome::TimedOut`].
+    pub timed_out: i32,
+
+    /// Exit code of [`Outcome::Undefined`].
+    pub undefined: i32,
+}
+
+impl Default for ExitCodes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExitCodes {
+    /// Creates new [`ExitCodes`] exiting with `1` on any [`Outcome`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { parsing_errors: 1, failed: 1, timed_out: 1, undefined: 1 }
+    }
+
+    /// Sets the exit `code` of the provided [`Outcome`].
+    #[must_use]
+    pub const fn with(mut self, outcome: Outcome, code: i32) -> Self {
+        match outcome {
+            Outcome::ParsingErrors => self.parsing_errors = code,
+            Outcome::Failed => self.failed = code,
+            Outcome::TimedOut => self.timed_out = code,
+            Outcome::Undefined => self.undefined = code,
+        }
+        self
+    }
+
+    /// Returns the exit code of the provided [`Outcome`].
+    #[must_use]
+    pub const fn code(&self, outcome: Outcome) -> i32 {
+        match outcome {
+            Outcome::ParsingErrors => self.parsing_errors,
+            Outcome::Failed => self.failed,
+            Outcome::TimedOut => self.timed_out,
+            Outcome::Undefined => self.undefined,
+        }
+    }
+}
+
+/// `OUTCOME=CODE` override of an [`Outcome`]'s exit code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mapping {
+    /// [`Outcome`] to override the exit code of.
+    pub outcome: Outcome,
+
+    /// Exit code to use.
+    pub code: i32,
+}
+
+impl FromStr for Mapping {
+    type Err = InvalidMappingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || InvalidMappingError(s.to_owned());
+        let (outcome, code) = s.split_once('=').ok_or_else(err)?;
+        let (Ok(outcome), Ok(code)) =
+            (outcome.trim().parse(), code.trim().parse())
+        else {
+            return Err(err());
+        };
+        Ok(Self { outcome, code })
+    }
+}
+
+/// Error of parsing an invalid [`Mapping`].
+#[derive(Clone, Debug, Display, Error)]
+#[display(
+    "Expected `parsing|failed|timeout|undefined=CODE` exit code mapping, \
+     found `{_0}`"
+)]
+pub struct InvalidMappingError(#[error(not(source))] pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mappings() {
+        assert_eq!(
+            "undefined=3".parse::<Mapping>().unwrap(),
+            Mapping { outcome: Outcome::Undefined, code: 3 },
+        );
+        assert_eq!(
+            " timeout = 4 ".parse::<Mapping>().unwrap(),
+            Mapping { outcome: Outcome::TimedOut, code: 4 },
+        );
+        assert!("undefined".parse::<Mapping>().is_err());
+        assert!("undefined=three".parse::<Mapping>().is_err());
+        assert!("skipped=3".parse::<Mapping>().is_err());
+    }
+
+    #[test]
+    fn overrides_codes() {
+        let codes = Outcome::ALL
+            .into_iter()
+            .zip(2..)
+            .fold(ExitCodes::new(), |c, (o, code)| c.with(o, code));
+
+        assert_eq!(ExitCodes::default().code(Outcome::TimedOut), 1);
+        assert_eq!(codes.code(Outcome::ParsingErrors), 2);
+        assert_eq!(codes.code(Outcome::Failed), 3);
+        assert_eq!(codes.code(Outcome::TimedOut), 4);
+        assert_eq!(codes.code(Outcome::Undefined), 5);
+    }
+}
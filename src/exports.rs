@@ -18,7 +18,9 @@
 // External crate re-exports
 #[cfg(feature = "macros")]
 #[doc(inline)]
-pub use cucumber_codegen::{Parameter, World, given, then, when};
+pub use cucumber_codegen::{
+    Parameter, World, after, before, given, steps, then, when,
+};
 pub use gherkin;
 
 // Internal module re-exports with feature-dependent items
@@ -32,9 +34,11 @@ pub use crate::{
     data_table::DataTable,
     error::{CucumberError, Result},
     event::Event,
-    parser::Parser,
+    exit_code::ExitCodes,
+    parser::{Ext as ParserExt, Parser},
     runner::{Runner, ScenarioType},
     step::Step,
+    suite::Suite,
     writer::{
         Arbitrary as ArbitraryWriter, Ext as WriterExt, Stats as StatsWriter,
         Writer,
@@ -51,7 +55,9 @@ pub use std::future::Future;
 /// Provides convenient access to commonly used types in one import.
 pub mod prelude {
     #[cfg(feature = "macros")]
-    pub use cucumber_codegen::{Parameter, World, given, then, when};
+    pub use cucumber_codegen::{
+        Parameter, World, after, before, given, steps, then, when,
+    };
 
     #[cfg(feature = "macros")]
     pub use crate::codegen::Parameter;
@@ -59,7 +65,7 @@ pub mod prelude {
         cucumber::Cucumber,
         error::{CucumberError, Result},
         event::Event,
-        parser::Parser,
+        parser::{Ext as ParserExt, Parser},
         runner::{Runner, ScenarioType},
         step::Step,
         world::World,
@@ -0,0 +1,24 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CLI options of writing the JSON report of failed scenarios.
+
+use std::path::PathBuf;
+
+/// CLI options of writing the JSON report of failed scenarios.
+#[derive(Clone, Debug, Default, clap::Args)]
+#[group(skip)]
+pub struct Cli {
+    /// Write the JSON report of all the failed scenarios (their location,
+    /// failed step, error, retries and published artifacts) into the file at
+    /// the end of the run.
+    #[arg(long, value_name = "path", global = true)]
+    pub failures_file: Option<PathBuf>,
+}
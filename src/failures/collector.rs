@@ -0,0 +1,189 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Collector`] of a [`Report`] from the events of a run.
+
+use std::collections::HashMap;
+
+use super::{Artifact, Failure, Report, Step};
+use crate::{
+    event::{self, Source},
+    writer::basic::{coerce_error, trim_path},
+};
+
+/// Key of a [`gherkin::Scenario`] being executed.
+type ScenarioKey = (
+    Source<gherkin::Feature>,
+    Option<Source<gherkin::Rule>>,
+    Source<gherkin::Scenario>,
+);
+
+/// State of a [`gherkin::Scenario`] attempt being executed.
+#[derive(Debug, Default)]
+struct Attempt {
+    /// [`Artifact`]s published so far.
+    artifacts: Vec<Artifact>,
+
+    /// The first [`Failure`] of this [`Attempt`], along with whether it's
+    /// going to be retried.
+    failure: Option<(Failure, bool)>,
+}
+
+/// Collector of a [`Report`] from the events of a run.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{event, failures::Collector};
+/// #
+/// # struct World;
+/// #
+/// let mut collector = Collector::new();
+/// collector.handle_event(&event::Cucumber::<World>::Started);
+///
+/// assert!(collector.into_report().failures.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct Collector {
+    /// [`Attempt`]s of the [`gherkin::Scenario`]s being executed.
+    running: HashMap<ScenarioKey, Attempt>,
+
+    /// [`Report`] of the already finished [`gherkin::Scenario`]s.
+    report: Report,
+}
+
+impl Collector {
+    /// Creates a new empty [`Collector`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the provided [`event::Cucumber`] into the [`Report`].
+    pub fn handle_event<W>(&mut self, ev: &event::Cucumber<W>) {
+        let event::Cucumber::Feature(feature, ev) = ev else {
+            return;
+        };
+        let (rule, scenario, ev) = match ev {
+            event::Feature::Scenario(sc, ev) => (None, sc, ev),
+            event::Feature::Rule(r, event::Rule::Scenario(sc, ev)) => {
+                (Some(r), sc, &**ev)
+            }
+            event::Feature::Started
+            | event::Feature::Finished
+            | event::Feature::Rule(..) => return,
+        };
+        let key = (feature.clone(), rule.cloned(), scenario.clone());
+
+        match &ev.event {
+            event::Scenario::Started => {
+                drop(self.running.insert(key, Attempt::default()));
+            }
+            event::Scenario::Step(step, st)
+            | event::Scenario::Background(step, st) => {
+                let attempt = self.running.entry(key).or_default();
+                match st {
+                    event::Step::Published(custom) => {
+                        attempt.artifacts.push(Artifact {
+                            name: custom.name.clone(),
+                            fields: custom.fields.clone(),
+                        });
+                    }
+                    event::Step::Failed { error, .. } => {
+                        let retried = ev.retries.is_some_and(|r| r.left > 0)
+                            && !error.is_undefined();
+                        _ = attempt.failure.get_or_insert_with(|| {
+                            let mut failure = Self::failure(
+                                feature,
+                                rule,
+                                scenario,
+                                ev,
+                                error.to_string(),
+                            );
+                            failure.step = Some(Step {
+                                keyword: step.keyword.trim().to_owned(),
+                                text: step.value.clone(),
+                                line: step.position.line,
+                            });
+                            (failure, retried)
+                        });
+                    }
+                    event::Step::Started
+                    | event::Step::Heartbeat { .. }
+                    | event::Step::Passed { .. }
+                    | event::Step::Skipped(_)
+                    | event::Step::NotRun => {}
+                }
+            }
+            event::Scenario::Hook(ty, _, event::Hook::Failed(_, info)) => {
+                let attempt = self.running.entry(key).or_default();
+                let retried = ev.retries.is_some_and(|r| r.left > 0);
+                _ = attempt.failure.get_or_insert_with(|| {
+                    let mut failure = Self::failure(
+                        feature,
+                        rule,
+                        scenario,
+                        ev,
+                        coerce_error(info).into_owned(),
+                    );
+                    failure.hook = Some(match ty {
+                        event::HookType::Before => "before",
+                        event::HookType::After => "after",
+                    });
+                    (failure, retried)
+                });
+            }
+            event::Scenario::Finished(_) => {
+                if let Some(Attempt { artifacts, failure: Some((f, false)) }) =
+                    self.running.remove(&key)
+                {
+                    self.report.failures.push(Failure { artifacts, ..f });
+                }
+            }
+            event::Scenario::Hook(..)
+            | event::Scenario::Log(_)
+            | event::Scenario::SlaViolated(_) => {}
+        }
+    }
+
+    /// Returns the collected [`Report`].
+    #[must_use]
+    pub fn into_report(self) -> Report {
+        self.report
+    }
+
+    /// Creates a new [`Failure`] of the provided [`gherkin::Scenario`]
+    /// attempt, not bound to any [`Step`] or hook yet.
+    fn failure<W>(
+        feature: &gherkin::Feature,
+        rule: Option<&Source<gherkin::Rule>>,
+        scenario: &gherkin::Scenario,
+        ev: &event::RetryableScenario<W>,
+        error: String,
+    ) -> Failure {
+        Failure {
+            path: feature
+                .path
+                .as_ref()
+                .and_then(|p| p.to_str())
+                .map(|p| trim_path(p).to_owned()),
+            line: scenario.position.line,
+            feature: feature.name.clone(),
+            rule: rule.map(|r| r.name.clone()),
+            scenario: scenario.name.clone(),
+            step: None,
+            hook: None,
+            error,
+            retries: ev.retries.map_or(0, |r| r.current),
+            scenario_id: ev.id.map(|id| id.0),
+            artifacts: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Machine-readable JSON [`Report`] of failed [`gherkin::Scenario`]s for
+//! triage tooling, independent of the [`Writer`] being used.
+//!
+//! Written at the end of a run via the `--failures-file` CLI option, or
+//! collected programmatically from events via a [`Collector`].
+//!
+//! [`Writer`]: crate::Writer
+
+mod cli;
+mod collector;
+#[cfg(test)]
+mod tests;
+
+use std::{fmt, fs, io, path::Path};
+
+use serde::{Serialize, Serializer};
+
+#[doc(inline)]
+pub use self::{cli::Cli, collector::Collector};
+
+/// [`gherkin::Step`] a [`Failure`] has happened on.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Step {
+    /// Keyword of the [`gherkin::Step`] (like `Given`).
+    pub keyword: String,
+
+    /// Text of the [`gherkin::Step`], without its keyword.
+    pub text: String,
+
+    /// Line of the [`gherkin::Step`] in its `.feature` file.
+    pub line: usize,
+}
+
+/// [`event::Custom`] event published by a [`gherkin::Scenario`] before it
+/// has failed (like a screenshot or a log file path), attached to its
+/// [`Failure`].
+///
+/// [`event::Custom`]: crate::event::Custom
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Artifact {
+    /// Name of the [`event::Custom`] event.
+    ///
+    /// [`event::Custom`]: crate::event::Custom
+    pub name: String,
+
+    /// Named fields of the [`event::Custom`] event, in the order they were
+    /// added.
+    ///
+    /// [`event::Custom`]: crate::event::Custom
+    #[serde(serialize_with = "serialize_fields")]
+    pub fields: Vec<(String, String)>,
+}
+
+/// Single failed [`gherkin::Scenario`] of a [`Report`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Failure {
+    /// Path to the `.feature` file of the [`gherkin::Scenario`], if known.
+    pub path: Option<String>,
+
+    /// Line of the [`gherkin::Scenario`] in its `.feature` file.
+    pub line: usize,
+
+    /// Name of the [`gherkin::Feature`] of the [`gherkin::Scenario`].
+    pub feature: String,
+
+    /// Name of the [`gherkin::Rule`] of the [`gherkin::Scenario`], if any.
+    pub rule: Option<String>,
+
+    /// Name of the [`gherkin::Scenario`].
+    pub scenario: String,
+
+    /// [`Step`] the [`gherkin::Scenario`] has failed on, or [`None`] if it
+    /// has failed on a hook.
+    pub step: Option<Step>,
+
+    /// Kind of the failed hook (`before` or `after`), if the
+    /// [`gherkin::Scenario`] has failed on it.
+    pub hook: Option<&'static str>,
+
+    /// Rendered error of the failure.
+    pub error: String,
+
+    /// Number of retries made before the final failure.
+    pub retries: usize,
+
+    /// [`ScenarioId`] of the failed attempt, if provided by the [`Runner`].
+    ///
+    /// [`Runner`]: crate::Runner
+    /// [`ScenarioId`]: crate::runner::basic::ScenarioId
+    pub scenario_id: Option<u64>,
+
+    /// [`Artifact`]s published by the failed attempt.
+    pub artifacts: Vec<Artifact>,
+}
+
+/// JSON report of all the [`Failure`]s of a run.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct Report {
+    /// All the [`Failure`]s of this [`Report`], in the order the
+    /// [`gherkin::Scenario`]s have finished.
+    pub failures: Vec<Failure>,
+}
+
+impl Report {
+    /// Writes this [`Report`] as JSON into the file at the provided `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[expect(
+            clippy::map_err_ignore,
+            reason = "`fmt::Error` cannot carry `serde_json::Error`"
+        )]
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        write!(f, "{json}")
+    }
+}
+
+/// Serializes the `fields` of an [`Artifact`] as a JSON object.
+fn serialize_fields<S: Serializer>(
+    fields: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(fields.iter().map(|(k, v)| (k, v)))
+}
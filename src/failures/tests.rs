@@ -0,0 +1,44 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests of [`Report`] serialization.
+
+use super::*;
+
+#[test]
+fn serializes_artifacts_fields_as_object() {
+    let report = Report {
+        failures: vec![Failure {
+            path: Some("tests/features/cat.feature".into()),
+            line: 3,
+            feature: "Cat".into(),
+            rule: None,
+            scenario: "Feeding".into(),
+            step: Some(Step {
+                keyword: "Then".into(),
+                text: "the cat purrs".into(),
+                line: 5,
+            }),
+            hook: None,
+            error: "Step panicked. Captured output: no purr".into(),
+            retries: 1,
+            scenario_id: Some(42),
+            artifacts: vec![Artifact {
+                name: "screenshot".into(),
+                fields: vec![("path".into(), "cat.png".into())],
+            }],
+        }],
+    };
+
+    assert_eq!(
+        report.to_string(),
+        r#"{"failures":[{"path":"tests/features/cat.feature","line":3,"feature":"Cat","rule":null,"scenario":"Feeding","step":{"keyword":"Then","text":"the cat purrs","line":5},"hook":null,"error":"Step panicked. Captured output: no purr","retries":1,"scenario_id":42,"artifacts":[{"name":"screenshot","fields":{"path":"cat.png"}}]}]}"#,
+    );
+}
@@ -0,0 +1,194 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Expansion of [`Scenario Outline`][1] [`Examples`][2].
+//!
+//! [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+//! [2]: https://cucumber.io/docs/gherkin/reference#examples
+
+use std::{iter, path::PathBuf, sync::LazyLock};
+
+use regex::Regex;
+
+use super::ExpandExamplesError;
+
+/// Expands [`gherkin::Scenario`] [`Examples`], if any.
+///
+/// # Errors
+///
+/// See [`ExpandExamplesError`] for details.
+///
+/// [`Examples`]: gherkin::Examples
+/// [`gherkin::Scenario`]: gherkin::Scenario
+pub(super) fn expand_scenario(
+    scenario: gherkin::Scenario,
+    path: Option<&PathBuf>,
+) -> Vec<Result<gherkin::Scenario, ExpandExamplesError>> {
+    /// [`regex::Regex`] matching placeholders [`Examples`] should expand into.
+    ///
+    /// Placeholders may contain whitespaces, as [`Examples`] columns may do.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    // TODO: Switch back to `lazy-regex::regex!()` once it migrates to `std`:
+    //       https://github.com/Canop/lazy-regex/issues/10
+    #[expect(clippy::unwrap_used, reason = "regex is valid")]
+    static TEMPLATE_REGEX: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"<([^<>\n]+)>").unwrap());
+
+    if scenario.examples.is_empty() {
+        return vec![Ok(scenario)];
+    }
+
+    scenario
+        .examples
+        .iter()
+        .filter_map(|ex| {
+            ex.table.as_ref()?.rows.split_first().map(|(h, v)| (h, v, ex))
+        })
+        .flat_map(|(header, vals, example)| {
+            vals.iter()
+                .map(|v| header.iter().zip(v))
+                .enumerate()
+                .zip(iter::repeat(example))
+        })
+        .map(|((id, row), example)| {
+            let (position, tags) = (example.position, example.tags.iter());
+
+            // Unknown placeholders are kept as is in doc strings and tables
+            // (so `<xml>` payloads stay valid), and in any text for names
+            // containing whitespaces.
+            let replace_templates = |str: &str, pos, lenient: bool| {
+                let mut err = None;
+                let replaced = TEMPLATE_REGEX
+                    .replace_all(str, |cap: &regex::Captures<'_>| {
+                        #[expect( // intentional
+                            clippy::unwrap_used,
+                            reason = "`TEMPLATE_REGEX` contains this capture \
+                                      group"
+                        )]
+                        let name = cap.get(1).unwrap().as_str();
+
+                        if let Some(value) = row.clone().find_map(|(k, v)| {
+                            (name == k).then_some(v.as_str())
+                        }) {
+                            return value.to_owned();
+                        }
+                        if !lenient && !name.contains(char::is_whitespace) {
+                            err = Some(ExpandExamplesError {
+                                pos,
+                                name: name.to_owned(),
+                                path: path.cloned(),
+                            });
+                        }
+                        format!("<{name}>")
+                    })
+                    .into_owned();
+
+                err.map_or_else(|| Ok(replaced), Err)
+            };
+
+            let mut expanded = scenario.clone();
+
+            // This is done to differentiate `Hash`es of `Scenario Outline`s
+            // with the same `Examples`.
+            expanded.position = position;
+            expanded.position.line += id + 2;
+
+            expanded.tags.extend(tags.cloned());
+
+            expanded.name =
+                replace_templates(&expanded.name, expanded.position, false)?;
+            for s in &mut expanded.steps {
+                s.value = replace_templates(&s.value, s.position, false)?;
+                for value in
+                    s.docstring.iter_mut().chain(s.table.iter_mut().flat_map(
+                        |t| t.rows.iter_mut().flat_map(|r| r.iter_mut()),
+                    ))
+                {
+                    *value = replace_templates(value, s.position, true)?;
+                }
+            }
+
+            let mut expanded_example = example.clone();
+            if let Some(table) = &mut expanded_example.table {
+                table.rows.resize(2, Vec::new());
+                if let Some(r) = table.rows.get_mut(1) {
+                    *r = row.map(|(_, v)| v.clone()).collect();
+                }
+            }
+            expanded.examples = vec![expanded_example];
+
+            Ok(expanded)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(source: &str) -> Vec<Result<gherkin::Scenario, String>> {
+        let feature =
+            gherkin::Feature::parse(source, gherkin::GherkinEnv::default())
+                .unwrap();
+        feature
+            .scenarios
+            .into_iter()
+            .flat_map(|s| expand_scenario(s, None))
+            .map(|s| s.map_err(|e| e.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn substitutes_docstrings_and_tables() {
+        let scenarios = expand(
+            "Feature: F\n\
+             \x20 Scenario Outline: <id>\n\
+             \x20   Given a request\n\
+             \x20     \"\"\"\n\
+             \x20     {\"id\": <id>, \"name\": \"<first name>\", \"<b>\": 1}\n\
+             \x20     \"\"\"\n\
+             \x20   Then a user\n\
+             \x20     | <id> | <first name> | <i>x</i> |\n\
+             \x20   Examples:\n\
+             \x20     | id | first name |\n\
+             \x20     | 7  | Zoë        |\n",
+        );
+
+        let sc = scenarios[0].as_ref().unwrap();
+        assert_eq!(sc.name, "7");
+        assert_eq!(
+            sc.steps[0].docstring.as_deref().map(str::trim),
+            Some("{\"id\": 7, \"name\": \"Zoë\", \"<b>\": 1}"),
+        );
+        assert_eq!(
+            sc.steps[1].table.as_ref().unwrap().rows,
+            [["7", "Zoë", "<i>x</i>"]],
+        );
+    }
+
+    #[test]
+    fn errors_on_unknown_step_placeholders_only() {
+        let source = "Feature: F\n\
+                      \x20 Scenario Outline: S\n\
+                      \x20   Given <x> and <not a column>\n\
+                      \x20   Then <y>\n\
+                      \x20   Examples:\n\
+                      \x20     | x |\n\
+                      \x20     | 1 |\n";
+
+        let scenarios = expand(source);
+
+        assert_eq!(
+            scenarios,
+            [Err("Failed to resolve <y> at :4:5".to_owned())],
+        );
+    }
+}
@@ -10,17 +10,21 @@
 
 //! [`gherkin::Feature`] extension.
 
+mod expand;
+
 use std::{
-    iter, mem,
+    mem,
     path::{Path, PathBuf},
-    sync::LazyLock,
 };
 
 use derive_more::with_trait::{Display, Error};
-use regex::Regex;
 use sealed::sealed;
 
-use crate::writer::basic::trim_path;
+use self::expand::expand_scenario;
+use crate::{
+    event::{FeatureMetadata, Suite},
+    writer::basic::trim_path,
+};
 
 /// Helper methods to operate on [`gherkin::Feature`]s.
 #[sealed]
@@ -91,6 +95,20 @@ pub trait Ext: Sized {
     /// [`crate::step::Step`]: gherkin::Step
     #[must_use]
     fn count_steps(&self) -> usize;
+
+    /// Reads the [`FeatureMetadata`] declared in the header comments of this
+    /// [`Feature`]'s file.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    fn metadata(&self) -> FeatureMetadata;
+
+    /// Maps the directory of this [`Feature`]'s file to its [`Suite`]
+    /// hierarchy.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    fn suite(&self) -> Suite;
 }
 
 #[sealed]
@@ -126,111 +144,14 @@ impl Ext for gherkin::Feature {
                 .map(|s| s.steps.len())
                 .sum::<usize>()
     }
-}
 
-/// Expands [`gherkin::Scenario`] [`Examples`], if any.
-///
-/// # Errors
-///
-/// See [`ExpandExamplesError`] for details.
-///
-/// [`Examples`]: gherkin::Examples
-/// [`gherkin::Scenario`]: gherkin::Scenario
-fn expand_scenario(
-    scenario: gherkin::Scenario,
-    path: Option<&PathBuf>,
-) -> Vec<Result<gherkin::Scenario, ExpandExamplesError>> {
-    /// [`regex::Regex`] matching placeholders [`Examples`] should expand into.
-    ///
-    /// [`Examples`]: gherkin::Examples
-    // TODO: Switch back to `lazy-regex::regex!()` once it migrates to `std`:
-    //       https://github.com/Canop/lazy-regex/issues/10
-    #[expect(clippy::unwrap_used, reason = "regex is valid")]
-    static TEMPLATE_REGEX: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"<([^>\s]+)>").unwrap());
-
-    if scenario.examples.is_empty() {
-        return vec![Ok(scenario)];
+    fn metadata(&self) -> FeatureMetadata {
+        FeatureMetadata::of(self)
     }
 
-    scenario
-        .examples
-        .iter()
-        .filter_map(|ex| {
-            ex.table.as_ref()?.rows.split_first().map(|(h, v)| (h, v, ex))
-        })
-        .flat_map(|(header, vals, example)| {
-            vals.iter()
-                .map(|v| header.iter().zip(v))
-                .enumerate()
-                .zip(iter::repeat(example))
-        })
-        .map(|((id, row), example)| {
-            let (position, tags) = (example.position, example.tags.iter());
-
-            let replace_templates = |str: &str, pos| {
-                let mut err = None;
-                let replaced = TEMPLATE_REGEX
-                    .replace_all(str, |cap: &regex::Captures<'_>| {
-                        #[expect( // intentional
-                            clippy::unwrap_used,
-                            reason = "`TEMPLATE_REGEX` contains this capture \
-                                      group"
-                        )]
-                        let name = cap.get(1).unwrap().as_str();
-
-                        row.clone()
-                            .find_map(|(k, v)| {
-                                (name == k).then_some(v.as_str())
-                            })
-                            .unwrap_or_else(|| {
-                                err = Some(ExpandExamplesError {
-                                    pos,
-                                    name: name.to_owned(),
-                                    path: path.cloned(),
-                                });
-                                ""
-                            })
-                    })
-                    .into_owned();
-
-                err.map_or_else(|| Ok(replaced), Err)
-            };
-
-            let mut expanded = scenario.clone();
-
-            // This is done to differentiate `Hash`es of `Scenario Outline`s
-            // with the same `Examples`.
-            expanded.position = position;
-            expanded.position.line += id + 2;
-
-            expanded.tags.extend(tags.cloned());
-
-            expanded.name =
-                replace_templates(&expanded.name, expanded.position)?;
-            for s in &mut expanded.steps {
-                for value in iter::once(&mut s.value)
-                    .chain(s.docstring.iter_mut())
-                    .chain(s.table.iter_mut().flat_map(|t| {
-                        t.rows.iter_mut().flat_map(|r| r.iter_mut())
-                    }))
-                {
-                    *value = replace_templates(value, s.position)?;
-                }
-            }
-
-            let mut expanded_example = example.clone();
-            if let Some(table) = &mut expanded_example.table {
-                table.rows.resize(2, Vec::new());
-                if let Some(r) = table.rows.get_mut(1) {
-                    *r = row.map(|(_, v)| v.clone()).collect();
-                }
-            }
-            expanded.examples = vec![expanded_example];
-
-            Ok(expanded)
-        })
-        .collect()
+    fn suite(&self) -> Suite {
+        Suite::of(self)
+    }
 }
 
 /// Error of [`Scenario Outline`][1] expansion encountering an unknown template.
@@ -0,0 +1,176 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Fixture`] definition and its type-erased form.
+
+use std::{any::Any, fmt, future::Future, marker::PhantomData, sync::Arc};
+
+use futures::{
+    FutureExt as _,
+    future::{self, LocalBoxFuture},
+};
+
+use super::{Instances, Scope};
+
+/// Type-erased value of a set up [`Fixture`].
+pub(crate) type Value = Arc<dyn Any + Send + Sync>;
+
+/// Type-erased setup function of a [`Fixture`].
+type SetupFn = Arc<
+    dyn Fn(Instances) -> LocalBoxFuture<'static, Result<Value, String>>
+        + Send
+        + Sync,
+>;
+
+/// Type-erased teardown function of a [`Fixture`].
+type TeardownFn =
+    Arc<dyn Fn(Value) -> LocalBoxFuture<'static, ()> + Send + Sync>;
+
+/// Named asynchronous setup (and optional teardown) of a `T` value, requested
+/// by [`gherkin::Scenario`]s via `@fixture:<name>` tag.
+pub struct Fixture<T> {
+    /// Type-erased [`Definition`] of this [`Fixture`].
+    definition: Definition,
+
+    /// Type of the value set up by this [`Fixture`].
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> fmt::Debug for Fixture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.definition.fmt(f)
+    }
+}
+
+impl<T: Any + Send + Sync> Fixture<T> {
+    /// Creates a new [`Fixture`] with the provided `name` and `setup` function.
+    ///
+    /// The `setup` function receives [`Instances`] of the [`Fixture`]s this
+    /// one [depends on](Fixture::depends_on). Its error fails every
+    /// [`gherkin::Scenario`] requesting this [`Fixture`].
+    #[must_use]
+    pub fn new<F, Fut, E>(name: impl Into<String>, setup: F) -> Self
+    where
+        F: Fn(Instances) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        E: fmt::Display,
+    {
+        let setup: SetupFn = Arc::new(move |deps| {
+            setup(deps)
+                .map(|res| {
+                    res.map(|v| -> Value { Arc::new(v) })
+                        .map_err(|e| e.to_string())
+                })
+                .boxed_local()
+        });
+        Self {
+            definition: Definition {
+                name: name.into(),
+                scope: Scope::default(),
+                dependencies: Vec::new(),
+                setup,
+                teardown: None,
+            },
+            _value: PhantomData,
+        }
+    }
+
+    /// Sets the [`Scope`] of this [`Fixture`].
+    ///
+    /// [`Scope::Scenario`] by default.
+    #[must_use]
+    pub const fn scope(mut self, scope: Scope) -> Self {
+        self.definition.scope = scope;
+        self
+    }
+
+    /// Makes this [`Fixture`] depend on the one with the provided `name`, so
+    /// it's set up before this one.
+    ///
+    /// The dependency must live at least as long as this [`Fixture`], so a
+    /// [`Scope::Run`] [`Fixture`] cannot depend on a [`Scope::Scenario`] one.
+    #[must_use]
+    pub fn depends_on(mut self, name: impl Into<String>) -> Self {
+        self.definition.dependencies.push(name.into());
+        self
+    }
+
+    /// Sets the `teardown` function of this [`Fixture`], being run once its
+    /// [`Scope`] ends, in the reverse order of setups.
+    ///
+    /// Panics of the `teardown` fail the [`gherkin::Scenario`] for the
+    /// [`Scope::Scenario`] [`Fixture`]s, and are ignored for the other ones.
+    #[must_use]
+    pub fn teardown<F, Fut>(mut self, teardown: F) -> Self
+    where
+        F: Fn(Arc<T>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.definition.teardown = Some(Arc::new(move |value: Value| {
+            // The `value` is always set up by this `Fixture`, so is a `T`.
+            value.downcast::<T>().map_or_else(
+                |_| future::ready(()).boxed_local(),
+                |v| teardown(v).boxed_local(),
+            )
+        }));
+        self
+    }
+
+    /// Erases the type of this [`Fixture`].
+    pub(super) fn into_definition(self) -> Definition {
+        self.definition
+    }
+}
+
+/// Type-erased [`Fixture`].
+pub(crate) struct Definition {
+    /// Name of this [`Fixture`], as requested via `@fixture:<name>` tag.
+    pub(crate) name: String,
+
+    /// [`Scope`] of this [`Fixture`].
+    pub(crate) scope: Scope,
+
+    /// Names of the [`Fixture`]s this one depends on.
+    pub(crate) dependencies: Vec<String>,
+
+    /// Setup function of this [`Fixture`].
+    setup: SetupFn,
+
+    /// Teardown function of this [`Fixture`], if any.
+    teardown: Option<TeardownFn>,
+}
+
+impl fmt::Debug for Definition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fixture")
+            .field("name", &self.name)
+            .field("scope", &self.scope)
+            .field("dependencies", &self.dependencies)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Definition {
+    /// Sets up this [`Fixture`] with the provided [`Instances`] of its
+    /// dependencies.
+    pub(crate) fn setup(
+        &self,
+        deps: Instances,
+    ) -> LocalBoxFuture<'static, Result<Value, String>> {
+        (self.setup)(deps)
+    }
+
+    /// Tears down the `value` set up by this [`Fixture`].
+    pub(crate) async fn teardown(&self, value: Value) {
+        if let Some(teardown) = &self.teardown {
+            teardown(value).await;
+        }
+    }
+}
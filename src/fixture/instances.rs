@@ -0,0 +1,91 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Values of set up [`Fixture`]s.
+//!
+//! [`Fixture`]: super::Fixture
+
+use std::{any::Any, sync::Arc};
+
+use super::definition::Value;
+
+/// Values of the [`Fixture`]s set up for a [`gherkin::Scenario`] (or for
+/// another [`Fixture`] depending on them), in the order of their setup.
+///
+/// [`Fixture`]: super::Fixture
+#[derive(Clone, Debug, Default)]
+pub struct Instances(Vec<(String, Value)>);
+
+impl Instances {
+    /// Returns the value of the first set up [`Fixture`] of type `T`, if any.
+    ///
+    /// [`Fixture`]: super::Fixture
+    #[must_use]
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.0.iter().find_map(|(_, v)| Arc::clone(v).downcast().ok())
+    }
+
+    /// Returns the value of the [`Fixture`] with the provided `name`, if it's
+    /// set up and of type `T`.
+    ///
+    /// [`Fixture`]: super::Fixture
+    #[must_use]
+    pub fn named<T: Any + Send + Sync>(&self, name: &str) -> Option<Arc<T>> {
+        let (_, v) = self.0.iter().find(|(n, _)| n == name)?;
+        Arc::clone(v).downcast().ok()
+    }
+
+    /// Indicates whether no [`Fixture`]s are set up.
+    ///
+    /// [`Fixture`]: super::Fixture
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds the `value` of the set up [`Fixture`] with the provided `name`.
+    ///
+    /// [`Fixture`]: super::Fixture
+    pub(super) fn push(&mut self, name: String, value: Value) {
+        self.0.push((name, value));
+    }
+
+    /// Returns only the [`Instances`] of the [`Fixture`]s with the provided
+    /// `names`.
+    ///
+    /// [`Fixture`]: super::Fixture
+    pub(super) fn only(&self, names: &[String]) -> Self {
+        Self(
+            self.0.iter().filter(|(n, _)| names.contains(n)).cloned().collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use std::sync::Arc;
+
+    use super::Instances;
+
+    #[test]
+    fn looks_up_by_type_and_name() {
+        let mut instances = Instances::default();
+        instances.push("url".into(), Arc::new(String::from("localhost")));
+        instances.push("port".into(), Arc::new(5432_u16));
+
+        assert_eq!(instances.get::<u16>().as_deref(), Some(&5432));
+        assert_eq!(
+            instances.named::<String>("url").as_deref().map(String::as_str),
+            Some("localhost"),
+        );
+        assert!(instances.named::<u16>("url").is_none(), "wrong type");
+        assert!(instances.get::<u32>().is_none(), "unknown type");
+    }
+}
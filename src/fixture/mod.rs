@@ -0,0 +1,189 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Named [`Fixture`]s set up for [`gherkin::Scenario`]s tagged with
+//! `@fixture:<name>`.
+//!
+//! Unlike a `Before` hook, a [`Fixture`] is set up only for the
+//! [`gherkin::Scenario`]s requesting it (directly, via their [`Rule`] or
+//! [`Feature`], or as a dependency of another [`Fixture`]), and may be shared
+//! between all the [`gherkin::Scenario`]s of a [`Feature`] or of a whole run,
+//! according to its [`Scope`].
+//!
+//! [`Feature`]: gherkin::Feature
+//! [`Rule`]: gherkin::Rule
+
+mod definition;
+mod instances;
+mod resolve;
+mod runtime;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+
+use std::{any::Any, collections::HashMap, fmt, sync::Arc};
+
+use derive_more::with_trait::{Display, Error};
+
+pub(crate) use self::{
+    definition::Definition,
+    runtime::{Runtime, Setup},
+};
+#[doc(inline)]
+pub use self::{definition::Fixture, instances::Instances};
+
+/// Name of the tag requesting a [`Fixture`], as in `@fixture:postgres`.
+pub const TAG: &str = "fixture";
+
+/// Lifetime of a set up [`Fixture`].
+#[derive(
+    Clone, Copy, Debug, Default, Display, Eq, Hash, Ord, PartialEq, PartialOrd,
+)]
+pub enum Scope {
+    /// [`Fixture`] is set up for every [`gherkin::Scenario`] requesting it, and
+    /// is torn down once the [`gherkin::Scenario`] finishes.
+    #[default]
+    #[display("scenario")]
+    Scenario,
+
+    /// [`Fixture`] is set up once per [`Feature`], and is torn down once all
+    /// its [`gherkin::Scenario`]s finish.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[display("feature")]
+    Feature,
+
+    /// [`Fixture`] is set up once per run, and is torn down once the run
+    /// finishes.
+    #[display("run")]
+    Run,
+}
+
+/// Registry of [`Fixture`]s, requested by [`gherkin::Scenario`]s via
+/// `@fixture:<name>` tags.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::convert::Infallible;
+/// #
+/// # use cucumber::fixture::{Fixture, Fixtures, Scope};
+/// #
+/// # struct Pool;
+/// # struct App { pool: std::sync::Arc<Pool> }
+/// #
+/// let fixtures = Fixtures::new()
+///     .fixture(
+///         Fixture::new("postgres", async |_| Ok::<_, Infallible>(Pool))
+///             .scope(Scope::Run),
+///     )
+///     .fixture(
+///         Fixture::new("app", async |deps| {
+///             let pool = deps.get::<Pool>().ok_or("no `Pool`")?;
+///             Ok::<_, &str>(App { pool })
+///         })
+///         .depends_on("postgres"),
+///     );
+/// ```
+#[derive(Clone, Default)]
+pub struct Fixtures {
+    /// Registered [`Definition`]s, by their names.
+    definitions: HashMap<String, Arc<Definition>>,
+}
+
+impl fmt::Debug for Fixtures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.definitions.keys().collect::<Vec<_>>();
+        names.sort_unstable();
+        f.debug_struct("Fixtures").field("names", &names).finish()
+    }
+}
+
+impl Fixtures {
+    /// Creates a new empty [`Fixtures`] registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the provided [`Fixture`].
+    ///
+    /// # Panics
+    ///
+    /// If a [`Fixture`] with the same name is registered already.
+    #[must_use]
+    pub fn fixture<T: Any + Send + Sync>(
+        mut self,
+        fixture: Fixture<T>,
+    ) -> Self {
+        let definition = fixture.into_definition();
+        let name = definition.name.clone();
+        assert!(
+            self.definitions
+                .insert(name.clone(), Arc::new(definition))
+                .is_none(),
+            "fixture `{name}` is registered twice",
+        );
+        self
+    }
+
+    /// Returns the [`Definition`] of the [`Fixture`] with the provided `name`.
+    fn get(&self, name: &str) -> Option<&Arc<Definition>> {
+        self.definitions.get(name)
+    }
+}
+
+/// Error of setting up [`Fixture`]s for a [`gherkin::Scenario`].
+#[derive(Debug, Display, Error)]
+pub(crate) enum SetupError {
+    /// [`Fixture`] is not registered.
+    #[display("unknown fixture `{name}`")]
+    Unknown {
+        /// Name of the requested [`Fixture`].
+        #[error(not(source))]
+        name: String,
+    },
+
+    /// [`Fixture`]s depend on each other.
+    #[display("dependency cycle between fixtures: {}", cycle.join(" -> "))]
+    Cycle {
+        /// Names of the [`Fixture`]s forming the cycle.
+        #[error(not(source))]
+        cycle: Vec<String>,
+    },
+
+    /// [`Fixture`] depends on another one living shorter than itself.
+    #[display(
+        "fixture `{name}` of `{scope}` scope cannot depend on \
+         fixture `{dependency}` of `{dependency_scope}` scope"
+    )]
+    Scope {
+        /// Name of the dependent [`Fixture`].
+        name: String,
+
+        /// [`Scope`] of the dependent [`Fixture`].
+        scope: Scope,
+
+        /// Name of the dependency [`Fixture`].
+        dependency: String,
+
+        /// [`Scope`] of the dependency [`Fixture`].
+        dependency_scope: Scope,
+    },
+
+    /// Setup of a [`Fixture`] has failed.
+    #[display("failed to set up fixture `{name}`: {message}")]
+    Failed {
+        /// Name of the failed [`Fixture`].
+        name: String,
+
+        /// Error message of the setup.
+        message: String,
+    },
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ordering of [`Fixture`]s by their dependencies.
+//!
+//! [`Fixture`]: super::Fixture
+
+use std::sync::Arc;
+
+use super::{Definition, Fixtures, SetupError};
+
+/// Returns [`Definition`]s of the `requested` [`Fixture`]s along with all their
+/// dependencies, ordered so that every dependency goes before its dependents.
+///
+/// # Errors
+///
+/// - If any of the [`Fixture`]s is not registered.
+/// - If the [`Fixture`]s depend on each other.
+/// - If a [`Fixture`] depends on one of a narrower [`Scope`].
+///
+/// [`Fixture`]: super::Fixture
+/// [`Scope`]: super::Scope
+pub(super) fn order(
+    fixtures: &Fixtures,
+    requested: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<Vec<&Arc<Definition>>, SetupError> {
+    let mut ordered = Vec::new();
+    for name in requested {
+        visit(fixtures, name.as_ref(), &mut Vec::new(), &mut ordered)?;
+    }
+    Ok(ordered)
+}
+
+/// Adds the [`Definition`] of the [`Fixture`] with the provided `name` to the
+/// `ordered` ones after all its dependencies, if it's not there already.
+///
+/// [`Fixture`]: super::Fixture
+fn visit<'f>(
+    fixtures: &'f Fixtures,
+    name: &str,
+    path: &mut Vec<&'f str>,
+    ordered: &mut Vec<&'f Arc<Definition>>,
+) -> Result<(), SetupError> {
+    let def = fixtures
+        .get(name)
+        .ok_or_else(|| SetupError::Unknown { name: name.to_owned() })?;
+    if ordered.iter().any(|d| d.name == def.name) {
+        return Ok(());
+    }
+    if let Some(pos) = path.iter().position(|n| *n == def.name) {
+        let mut cycle =
+            path.iter().skip(pos).map(|n| (*n).to_owned()).collect::<Vec<_>>();
+        cycle.push(def.name.clone());
+        return Err(SetupError::Cycle { cycle });
+    }
+
+    path.push(&def.name);
+    for dep_name in &def.dependencies {
+        visit(fixtures, dep_name, path, ordered)?;
+        if let Some(dep) =
+            fixtures.get(dep_name).filter(|d| d.scope < def.scope)
+        {
+            return Err(SetupError::Scope {
+                name: def.name.clone(),
+                scope: def.scope,
+                dependency: dep.name.clone(),
+                dependency_scope: dep.scope,
+            });
+        }
+    }
+    _ = path.pop();
+
+    ordered.push(def);
+    Ok(())
+}
+
+#[cfg(test)]
+mod spec {
+    use std::convert::Infallible;
+
+    use super::{super::Fixture, Fixtures, order};
+    use crate::fixture::Scope;
+
+    fn fixture(name: &str, scope: Scope, deps: &[&str]) -> Fixture<()> {
+        deps.iter().fold(
+            Fixture::new(name, async |_| Ok::<_, Infallible>(())).scope(scope),
+            |f, dep| f.depends_on(*dep),
+        )
+    }
+
+    fn names(fixtures: &Fixtures, requested: &[&str]) -> Vec<String> {
+        order(fixtures, requested.iter().copied())
+            .unwrap()
+            .into_iter()
+            .map(|d| d.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn orders_dependencies_first() {
+        let fixtures = Fixtures::new()
+            .fixture(fixture("app", Scope::Scenario, &["db", "cache"]))
+            .fixture(fixture("db", Scope::Run, &["network"]))
+            .fixture(fixture("cache", Scope::Feature, &["network"]))
+            .fixture(fixture("network", Scope::Run, &[]));
+
+        assert_eq!(
+            names(&fixtures, &["app", "db"]),
+            ["network", "db", "cache", "app"],
+        );
+        assert_eq!(names(&fixtures, &["cache"]), ["network", "cache"]);
+    }
+
+    #[test]
+    fn errors_on_unknown_fixture() {
+        let fixtures =
+            Fixtures::new().fixture(fixture("app", Scope::Scenario, &["db"]));
+
+        assert_eq!(
+            order(&fixtures, ["app"]).unwrap_err().to_string(),
+            "unknown fixture `db`",
+        );
+    }
+
+    #[test]
+    fn errors_on_dependency_cycle() {
+        let fixtures = Fixtures::new()
+            .fixture(fixture("app", Scope::Scenario, &["db"]))
+            .fixture(fixture("db", Scope::Scenario, &["migrations"]))
+            .fixture(fixture("migrations", Scope::Scenario, &["db"]));
+
+        assert_eq!(
+            order(&fixtures, ["app"]).unwrap_err().to_string(),
+            "dependency cycle between fixtures: db -> migrations -> db",
+        );
+    }
+
+    #[test]
+    fn errors_on_narrower_dependency() {
+        let fixtures = Fixtures::new()
+            .fixture(fixture("db", Scope::Run, &["tx"]))
+            .fixture(fixture("tx", Scope::Scenario, &[]));
+
+        assert_eq!(
+            order(&fixtures, ["db"]).unwrap_err().to_string(),
+            "fixture `db` of `run` scope cannot depend on \
+             fixture `tx` of `scenario` scope",
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Setup and teardown of [`Fixture`]s during a run, according to their
+//! [`Scope`]s.
+//!
+//! [`Fixture`]: super::Fixture
+
+#[cfg(test)]
+mod spec;
+
+use std::{
+    any::Any, cell::RefCell, collections::HashMap, mem,
+    panic::AssertUnwindSafe, path::PathBuf, sync::Arc,
+};
+
+use futures::{
+    FutureExt as _,
+    future::{LocalBoxFuture, Shared},
+};
+
+use super::{
+    Definition, Fixtures, Instances, Scope, SetupError, TAG, definition::Value,
+    resolve,
+};
+use crate::tag::Tags;
+
+/// [`Fixture`] of a [`Scope::Run`] or a [`Scope::Feature`], being set up (or
+/// already set up) once for all the [`gherkin::Scenario`]s requesting it.
+///
+/// [`Fixture`]: super::Fixture
+type SharedInstance =
+    (Arc<Definition>, Shared<LocalBoxFuture<'static, Result<Value, String>>>);
+
+/// Key identifying a [`gherkin::Feature`].
+type FeatureKey = (Option<PathBuf>, String);
+
+/// Returns the [`FeatureKey`] of the provided `feature`.
+fn key(feature: &gherkin::Feature) -> FeatureKey {
+    (feature.path.clone(), feature.name.clone())
+}
+
+/// Sets up and tears down [`Fixture`]s of the [`Fixtures`] registry during a
+/// run.
+///
+/// [`Fixture`]: super::Fixture
+#[derive(Debug)]
+pub(crate) struct Runtime {
+    /// Registered [`Fixture`]s.
+    ///
+    /// [`Fixture`]: super::Fixture
+    fixtures: Fixtures,
+
+    /// Set up [`Scope::Run`] [`Fixture`]s, in the order of their setup.
+    ///
+    /// [`Fixture`]: super::Fixture
+    run: RefCell<Vec<SharedInstance>>,
+
+    /// Set up [`Scope::Feature`] [`Fixture`]s of every running
+    /// [`gherkin::Feature`], in the order of their setup.
+    ///
+    /// [`Fixture`]: super::Fixture
+    features: RefCell<HashMap<FeatureKey, Vec<SharedInstance>>>,
+}
+
+impl Runtime {
+    /// Creates a new [`Runtime`] of the provided [`Fixtures`].
+    pub(crate) fn new(fixtures: Fixtures) -> Self {
+        Self { fixtures, run: RefCell::default(), features: RefCell::default() }
+    }
+
+    /// Sets up all the [`Fixture`]s requested by the provided
+    /// [`gherkin::Scenario`], reusing the already set up [`Scope::Run`] and
+    /// [`Scope::Feature`] ones.
+    ///
+    /// # Errors
+    ///
+    /// If the requested [`Fixture`]s cannot be ordered, or any of them fails
+    /// to set up. The already set up [`Scope::Scenario`] [`Fixture`]s are
+    /// torn down in such case.
+    ///
+    /// [`Fixture`]: super::Fixture
+    pub(crate) async fn setup(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> Result<Setup, SetupError> {
+        let tags = Tags::of(feature, rule, scenario);
+        // `Tags` go from the most specific ones, while the `Feature` ones
+        // should be set up first.
+        let requested = tags
+            .iter()
+            .rev()
+            .filter(|t| t.name == TAG)
+            .filter_map(|t| t.value.as_deref());
+
+        let mut setup = Setup::default();
+        for def in resolve::order(&self.fixtures, requested)? {
+            let deps = setup.instances.only(&def.dependencies);
+            let value = match def.scope {
+                Scope::Scenario => def.setup(deps).await,
+                Scope::Feature => {
+                    let instance = cached(
+                        self.features
+                            .borrow_mut()
+                            .entry(key(feature))
+                            .or_default(),
+                        def,
+                        deps,
+                    );
+                    instance.await
+                }
+                Scope::Run => {
+                    let instance =
+                        cached(&mut self.run.borrow_mut(), def, deps);
+                    instance.await
+                }
+            };
+            match value {
+                Ok(value) => {
+                    if def.scope == Scope::Scenario {
+                        setup.owned.push((Arc::clone(def), Arc::clone(&value)));
+                    }
+                    setup.instances.push(def.name.clone(), value);
+                }
+                Err(message) => {
+                    drop(setup.teardown().await);
+                    return Err(SetupError::Failed {
+                        name: def.name.clone(),
+                        message,
+                    });
+                }
+            }
+        }
+        Ok(setup)
+    }
+
+    /// Tears down the [`Scope::Feature`] [`Fixture`]s of the provided finished
+    /// `feature`.
+    ///
+    /// [`Fixture`]: super::Fixture
+    pub(crate) async fn finish_feature(&self, feature: &gherkin::Feature) {
+        let instances = self.features.borrow_mut().remove(&key(feature));
+        teardown_shared(instances.unwrap_or_default()).await;
+    }
+
+    /// Tears down all the remaining [`Scope::Feature`] [`Fixture`]s, and then
+    /// all the [`Scope::Run`] ones, once the run finishes.
+    ///
+    /// [`Fixture`]: super::Fixture
+    pub(crate) async fn finish(&self) {
+        let features = mem::take(&mut *self.features.borrow_mut());
+        for instances in features.into_values() {
+            teardown_shared(instances).await;
+        }
+        let run = mem::take(&mut *self.run.borrow_mut());
+        teardown_shared(run).await;
+    }
+}
+
+/// Returns the [`SharedInstance`] of the provided [`Definition`] from the
+/// `instances`, starting its setup if there is none yet.
+fn cached(
+    instances: &mut Vec<SharedInstance>,
+    def: &Arc<Definition>,
+    deps: Instances,
+) -> Shared<LocalBoxFuture<'static, Result<Value, String>>> {
+    if let Some((_, instance)) =
+        instances.iter().find(|(d, _)| d.name == def.name)
+    {
+        return instance.clone();
+    }
+    let instance = def.setup(deps).shared();
+    instances.push((Arc::clone(def), instance.clone()));
+    instance
+}
+
+/// Tears down the successfully set up `instances` in the reverse order,
+/// ignoring their panics.
+async fn teardown_shared(instances: Vec<SharedInstance>) {
+    for (def, instance) in instances.into_iter().rev() {
+        if let Some(Ok(value)) = instance.peek().cloned() {
+            drop(AssertUnwindSafe(def.teardown(value)).catch_unwind().await);
+        }
+    }
+}
+
+/// [`Fixture`]s set up for a single [`gherkin::Scenario`].
+///
+/// [`Fixture`]: super::Fixture
+#[derive(Debug, Default)]
+pub(crate) struct Setup {
+    /// [`Instances`] of all the set up [`Fixture`]s.
+    ///
+    /// [`Fixture`]: super::Fixture
+    pub(crate) instances: Instances,
+
+    /// [`Scope::Scenario`] [`Fixture`]s to be torn down once the
+    /// [`gherkin::Scenario`] finishes, in the order of their setup.
+    ///
+    /// [`Fixture`]: super::Fixture
+    owned: Vec<(Arc<Definition>, Value)>,
+}
+
+impl Setup {
+    /// Tears down the [`Scope::Scenario`] [`Fixture`]s in the reverse order.
+    ///
+    /// # Errors
+    ///
+    /// With the payload of the first panicked teardown, if any.
+    ///
+    /// [`Fixture`]: super::Fixture
+    pub(crate) async fn teardown(self) -> Result<(), Box<dyn Any + Send>> {
+        let mut result = Ok(());
+        for (def, value) in self.owned.into_iter().rev() {
+            let torn =
+                AssertUnwindSafe(def.teardown(value)).catch_unwind().await;
+            result = result.and(torn);
+        }
+        result
+    }
+}
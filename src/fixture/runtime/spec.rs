@@ -0,0 +1,92 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests of [`Runtime`] setups and teardowns of fixtures.
+
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+use gherkin::GherkinEnv;
+
+use super::Runtime;
+use crate::fixture::{Fixture, Fixtures, Scope};
+
+const FEATURE: &str = "\
+@fixture:db
+Feature: Fixtures
+  @fixture:app
+  Scenario: first
+    Given a step
+
+  Scenario: second
+    Given a step
+";
+
+/// Returns [`Fixtures`] logging their setups and teardowns.
+fn fixtures(log: &Arc<Mutex<Vec<String>>>) -> Fixtures {
+    let fixture = |name: &'static str, scope| {
+        let (setup, teardown) = (Arc::clone(log), Arc::clone(log));
+        Fixture::new(name, move |_| {
+            setup.lock().unwrap().push(format!("+{name}"));
+            async move { Ok::<_, Infallible>(name) }
+        })
+        .scope(scope)
+        .teardown(move |_| {
+            teardown.lock().unwrap().push(format!("-{name}"));
+            async {}
+        })
+    };
+    Fixtures::new()
+        .fixture(fixture("db", Scope::Feature))
+        .fixture(fixture("app", Scope::Scenario).depends_on("network"))
+        .fixture(fixture("network", Scope::Run))
+}
+
+#[tokio::test]
+async fn sets_up_and_tears_down_by_scope() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let runtime = Runtime::new(fixtures(&log));
+    let feature =
+        gherkin::Feature::parse(FEATURE, GherkinEnv::default()).unwrap();
+
+    for scenario in &feature.scenarios {
+        let setup = runtime.setup(&feature, None, scenario).await.unwrap();
+        assert_eq!(setup.instances.named::<&str>("db").as_deref(), Some(&"db"));
+        setup.teardown().await.unwrap();
+    }
+    runtime.finish_feature(&feature).await;
+    runtime.finish().await;
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["+db", "+network", "+app", "-app", "-db", "-network"],
+    );
+}
+
+#[tokio::test]
+async fn reports_failed_setup() {
+    let fixtures = Fixtures::new().fixture(Fixture::new("db", async |_| {
+        Err::<(), _>("connection refused")
+    }));
+    let feature =
+        gherkin::Feature::parse(FEATURE, GherkinEnv::default()).unwrap();
+
+    let err = Runtime::new(fixtures)
+        .setup(&feature, None, &feature.scenarios[1])
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "failed to set up fixture `db`: connection refused",
+    );
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Fixture`] of a [`sqlx`] transaction rolled back after every
+//! [`gherkin::Scenario`].
+
+use std::{fmt, sync::Arc};
+
+use ::sqlx::{Database, Pool};
+use futures::lock::{MappedMutexGuard, Mutex, MutexGuard};
+
+use super::{Fixture, Scope};
+
+/// Type of the wrapped [`sqlx::Transaction`].
+type Inner<DB> = ::sqlx::Transaction<'static, DB>;
+
+/// [`sqlx::Transaction`] begun for a single [`gherkin::Scenario`] by the
+/// [`transaction()`] [`Fixture`], and rolled back once it finishes.
+pub struct Transaction<DB: Database>(Mutex<Option<Inner<DB>>>);
+
+impl<DB: Database> fmt::Debug for Transaction<DB> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Transaction").finish_non_exhaustive()
+    }
+}
+
+impl<DB: Database> Transaction<DB> {
+    /// Locks this [`Transaction`] for executing queries on it, until the
+    /// returned guard is dropped.
+    ///
+    /// # Panics
+    ///
+    /// If this [`Transaction`] is already rolled back, which happens only
+    /// once its [`gherkin::Scenario`] finishes.
+    pub async fn lock(
+        &self,
+    ) -> MappedMutexGuard<'_, Option<Inner<DB>>, Inner<DB>> {
+        MutexGuard::map(self.0.lock().await, |tx| {
+            #[expect(clippy::expect_used, reason = "rolled back after scenario")]
+            tx.as_mut().expect("`Transaction` is already rolled back")
+        })
+    }
+
+    /// Rolls back this [`Transaction`], if it's not rolled back yet.
+    ///
+    /// # Panics
+    ///
+    /// If the rollback fails, so the [`gherkin::Scenario`] fails.
+    async fn rollback(&self) {
+        let Some(tx) = self.0.lock().await.take() else {
+            return;
+        };
+        if let Err(e) = tx.rollback().await {
+            panic!("failed to roll back `Transaction`: {e}");
+        }
+    }
+}
+
+/// Creates a [`Scope::Scenario`] [`Fixture`] with the provided `name`,
+/// beginning a [`Transaction`] on the `pool` for every [`gherkin::Scenario`]
+/// requesting it.
+///
+/// The [`Transaction`] is rolled back once the [`gherkin::Scenario`] finishes,
+/// even if any of its steps panics, so the data it writes never leaks into
+/// other [`gherkin::Scenario`]s. A failed rollback fails the
+/// [`gherkin::Scenario`] the same way a failed `After` hook does.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use cucumber::{
+/// #     World,
+/// #     fixture::{
+/// #         Fixtures,
+/// #         sqlx::{Transaction, transaction},
+/// #     },
+/// #     given,
+/// # };
+/// # use sqlx::{Sqlite, SqlitePool};
+/// #
+/// #[derive(Debug, Default, World)]
+/// struct MyWorld;
+///
+/// #[given(expr = "a user {word}")]
+/// async fn user(
+///     _: &mut MyWorld,
+///     #[fixture] tx: &Transaction<Sqlite>,
+///     name: String,
+/// ) {
+///     sqlx::query("INSERT INTO users (name) VALUES (?)")
+///         .bind(name)
+///         .execute(&mut **tx.lock().await)
+///         .await
+///         .unwrap();
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let pool = SqlitePool::connect("sqlite://app.db").await.unwrap();
+/// MyWorld::cucumber()
+///     .fixtures(Fixtures::new().fixture(transaction("db", pool)))
+///     .run("tests/features/readme")
+///     .await;
+/// # }
+/// ```
+#[must_use]
+pub fn transaction<DB: Database>(
+    name: impl Into<String>,
+    pool: Pool<DB>,
+) -> Fixture<Transaction<DB>> {
+    Fixture::new(name, move |_| {
+        let pool = pool.clone();
+        async move {
+            let tx = pool.begin().await?;
+            Ok::<_, ::sqlx::Error>(Transaction(Mutex::new(Some(tx))))
+        }
+    })
+    .scope(Scope::Scenario)
+    .teardown(async |tx: Arc<Transaction<DB>>| tx.rollback().await)
+}
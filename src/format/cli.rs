@@ -0,0 +1,33 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//! CLI options of formatting `.feature` files.
+
+/// CLI options of formatting `.feature` files.
+#[derive(Clone, Copy, Debug, Default, clap::Args)]
+#[group(skip)]
+pub struct Cli {
+    /// Format `.feature` files in place instead of executing them.
+    #[arg(long = "fmt", global = true)]
+    pub fmt: bool,
+
+    /// Check `.feature` files being formatted instead of executing them,
+    /// failing if any of them isn't.
+    #[arg(long = "fmt-check", conflicts_with = "fmt", global = true)]
+    pub fmt_check: bool,
+}
+
+impl Cli {
+    /// Indicates whether formatting (or checking it) is requested instead of
+    /// executing `.feature` files.
+    #[must_use]
+    pub const fn is_requested(&self) -> bool {
+        self.fmt || self.fmt_check
+    }
+}
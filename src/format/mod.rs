@@ -0,0 +1,296 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Canonical formatting of `.feature` files.
+//!
+//! Run via the `--fmt` CLI option, or programmatically via a [`Formatter`].
+
+mod cli;
+mod render;
+mod table;
+
+use std::{fs, io, path::Path};
+
+use self::render::{Line, render, strip_indent};
+
+#[doc(inline)]
+pub use self::cli::Cli;
+
+/// English keywords, along with their canonical forms and nesting [`Kind`]s.
+const KEYWORDS: [(&str, &str, Kind); 16] = [
+    ("Feature:", "Feature:", Kind::Feature),
+    ("Business Need:", "Feature:", Kind::Feature),
+    ("Ability:", "Feature:", Kind::Feature),
+    ("Rule:", "Rule:", Kind::Rule),
+    ("Background:", "Background:", Kind::Scenario),
+    ("Scenario Outline:", "Scenario Outline:", Kind::Scenario),
+    ("Scenario Template:", "Scenario Outline:", Kind::Scenario),
+    ("Scenario:", "Scenario:", Kind::Scenario),
+    ("Example:", "Scenario:", Kind::Scenario),
+    ("Examples:", "Examples:", Kind::Examples),
+    ("Scenarios:", "Examples:", Kind::Examples),
+    ("Given ", "Given", Kind::Step),
+    ("When ", "When", Kind::Step),
+    ("Then ", "Then", Kind::Step),
+    ("And ", "And", Kind::Step),
+    ("But ", "But", Kind::Step),
+];
+
+/// Nesting kind of a keyword line.
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    /// `Feature` keyword.
+    Feature,
+
+    /// `Rule` keyword.
+    Rule,
+
+    /// `Scenario` or `Background` keyword.
+    Scenario,
+
+    /// `Examples` keyword.
+    Examples,
+
+    /// Step keyword.
+    Step,
+}
+
+/// Formatter of `.feature` files with canonical indentation, aligned tables
+/// and normalized keywords.
+///
+/// Only English keywords are normalized and re-indented: files in other
+/// languages (with a `# language:` header) keep their indentation, while
+/// having their tables aligned. Comments and doc strings are preserved.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::format::Formatter;
+/// #
+/// let formatted = Formatter::new().format(
+///     "Feature: Cats\n\
+///      @cat\n\
+///      Scenario Template:   hungry <cat>\n\
+///      Given   a hungry <cat>\n\
+///      Scenarios:\n\
+///      |cat|\n\
+///      |Tom|\n",
+/// );
+///
+/// assert_eq!(
+///     formatted,
+///     "Feature: Cats\n\
+///      \x20 @cat\n\
+///      \x20 Scenario Outline: hungry <cat>\n\
+///      \x20   Given a hungry <cat>\n\
+///      \x20   Examples:\n\
+///      \x20     | cat |\n\
+///      \x20     | Tom |\n",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Formatter {
+    /// Number of spaces for a single indentation level.
+    indent: usize,
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Formatter {
+    /// Creates a new [`Formatter`] indenting with 2 spaces.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { indent: 2 }
+    }
+
+    /// Sets the number of spaces for a single indentation level.
+    #[must_use]
+    pub const fn indent(mut self, spaces: usize) -> Self {
+        self.indent = spaces;
+        self
+    }
+
+    /// Formats the `.feature` file by the provided `path` in place.
+    ///
+    /// Returns whether the file has been changed.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or written.
+    pub fn format_file(self, path: impl AsRef<Path>) -> io::Result<bool> {
+        let source = fs::read_to_string(&path)?;
+        let formatted = self.format(&source);
+        if formatted == source {
+            return Ok(false);
+        }
+        fs::write(path, formatted)?;
+        Ok(true)
+    }
+
+    /// Formats the provided `.feature` file `source`.
+    #[must_use]
+    pub fn format(self, source: &str) -> String {
+        let english = source
+            .lines()
+            .map(str::trim)
+            .take_while(|l| l.is_empty() || l.starts_with('#'))
+            .find_map(|l| l.strip_prefix('#')?.trim().strip_prefix("language:"))
+            .is_none_or(|lang| lang.trim() == "en");
+
+        let mut lines = vec![];
+        let (mut rule, mut body) = (0, 0);
+        let mut docstring = None::<(&str, usize, String)>;
+        for raw in source.lines() {
+            let text = raw.trim();
+            if let Some((delim, orig, indent)) = &docstring {
+                if text == *delim {
+                    lines.push(Line::Text(indent.clone(), text.into()));
+                    docstring = None;
+                } else {
+                    let content = strip_indent(raw, *orig).trim_end();
+                    lines.push(Line::Text(indent.clone(), content.into()));
+                }
+                continue;
+            }
+
+            let original = raw.len() - raw.trim_start().len();
+            let indent = if english {
+                self.spaces(body)
+            } else {
+                raw.split_at_checked(original).unwrap_or_default().0.into()
+            };
+            if text.is_empty() {
+                lines.push(Line::Blank);
+            } else if english
+                && (text.starts_with('#') || text.starts_with('@'))
+            {
+                lines.push(Line::Floating(text.into()));
+            } else if text.starts_with('|') {
+                lines.push(Line::Table(indent, text.into()));
+            } else if let Some(delim) =
+                ["\"\"\"", "```"].into_iter().find(|d| text.starts_with(d))
+            {
+                docstring = Some((delim, original, indent.clone()));
+                lines.push(Line::Text(indent, text.into()));
+            } else if let Some((canonical, rest, kind)) =
+                keyword(text).filter(|_| english)
+            {
+                let level = match kind {
+                    Kind::Feature => {
+                        rule = 0;
+                        0
+                    }
+                    Kind::Rule => {
+                        rule = 1;
+                        1
+                    }
+                    Kind::Scenario => 1 + rule,
+                    Kind::Examples | Kind::Step => 2 + rule,
+                };
+                body = level + 1;
+                let line = if rest.is_empty() {
+                    canonical.into()
+                } else {
+                    format!("{canonical} {rest}")
+                };
+                lines.push(Line::Text(self.spaces(level), line));
+            } else {
+                let (indent, line) = if english {
+                    (indent, text)
+                } else {
+                    (String::new(), raw.trim_end())
+                };
+                lines.push(Line::Text(indent, line.into()));
+            }
+        }
+
+        render(lines)
+    }
+
+    /// Returns the indentation of the provided nesting `level`.
+    fn spaces(self, level: usize) -> String {
+        " ".repeat(level * self.indent)
+    }
+}
+
+/// Parses the provided trimmed `text` line starting with an English keyword
+/// into its canonical keyword, the rest of the line and its [`Kind`].
+fn keyword(text: &str) -> Option<(&'static str, &str, Kind)> {
+    if let Some(rest) = text.strip_prefix("* ") {
+        return Some(("*", rest.trim(), Kind::Step));
+    }
+    KEYWORDS.iter().find_map(|&(kw, canonical, kind)| {
+        Some((canonical, text.strip_prefix(kw)?.trim(), kind))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indents_rules_docstrings_and_comments() {
+        let source = "# a comment\n\n\n\
+                      Feature: F\n\
+                      Some description\n\
+                      Rule: R\n\
+                      Example:S   \n\
+                      * a step\n\
+                      \t\t\"\"\"json\n\
+                      \t\t{\n\
+                      \t\t  \"a\": 1\n\
+                      \n\
+                      \t\t}\n\
+                      \t\t\"\"\"\n\
+                      # trailing\n\n";
+
+        assert_eq!(
+            Formatter::new().format(source),
+            "# a comment\n\
+             \n\
+             Feature: F\n\
+             \x20 Some description\n\
+             \x20 Rule: R\n\
+             \x20   Scenario: S\n\
+             \x20     * a step\n\
+             \x20       \"\"\"json\n\
+             \x20       {\n\
+             \x20         \"a\": 1\n\
+             \n\
+             \x20       }\n\
+             \x20       \"\"\"\n\
+             # trailing\n",
+        );
+    }
+
+    #[test]
+    fn keeps_non_english_indentation() {
+        let source = "# language: ru\n\
+                      Функция: Ф\n\
+                      \x20   Сценарий: С\n\
+                      \x20     Дано |x|\n\
+                      \x20       |a|bb|\n\
+                      \x20       |ccc|d|\n";
+
+        assert_eq!(
+            Formatter::new().indent(4).format(source),
+            "# language: ru\n\
+             Функция: Ф\n\
+             \x20   Сценарий: С\n\
+             \x20     Дано |x|\n\
+             \x20       | a   | bb |\n\
+             \x20       | ccc | d  |\n",
+        );
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Rendering of formatted `.feature` file lines.
+
+use std::{fmt::Write as _, mem};
+
+use super::table;
+
+/// Single formatted line, before resolving indentation of tags and comments,
+/// and aligning tables.
+#[derive(Debug)]
+pub(super) enum Line {
+    /// Empty line.
+    Blank,
+
+    /// Tags or comment line, indented as the following line.
+    Floating(String),
+
+    /// Table row with its indentation.
+    Table(String, String),
+
+    /// Any other line with its indentation.
+    Text(String, String),
+}
+
+/// Strips at most `n` bytes of leading whitespace from the provided `line`.
+pub(super) fn strip_indent(line: &str, n: usize) -> &str {
+    let whitespace = line.len() - line.trim_start().len();
+    line.split_at_checked(n.min(whitespace)).map_or(line, |(_, rest)| rest)
+}
+
+/// Renders the provided `lines`, indenting tags and comments as their
+/// following lines, aligning tables and collapsing blank lines.
+pub(super) fn render(mut lines: Vec<Line>) -> String {
+    let mut next = String::new();
+    for line in lines.iter_mut().rev() {
+        match line {
+            Line::Floating(text) => {
+                *line = Line::Text(next.clone(), mem::take(text));
+            }
+            Line::Table(indent, _) | Line::Text(indent, _) => {
+                next.clone_from(indent);
+            }
+            Line::Blank => {}
+        }
+    }
+
+    let mut out = String::new();
+    let mut table = (String::new(), vec![]);
+    for line in lines {
+        if let Line::Table(indent, row) = line {
+            table.0 = indent;
+            table.1.push(row);
+            continue;
+        }
+        flush_table(&mut out, &mut table);
+        match line {
+            Line::Blank if out.is_empty() || out.ends_with("\n\n") => {}
+            Line::Blank => out.push('\n'),
+            Line::Text(_, text) if text.is_empty() => out.push('\n'),
+            Line::Text(indent, text) => {
+                _ = writeln!(out, "{indent}{text}");
+            }
+            // Already resolved above.
+            Line::Floating(_) | Line::Table(..) => {}
+        }
+    }
+    flush_table(&mut out, &mut table);
+
+    out.truncate(out.trim_end_matches('\n').len());
+    out.push('\n');
+    out
+}
+
+/// Writes the aligned rows of the provided pending `table` into the `out`put.
+fn flush_table(out: &mut String, (indent, rows): &mut (String, Vec<String>)) {
+    let rows = mem::take(rows);
+    let rows = rows.iter().map(String::as_str).collect::<Vec<_>>();
+    for row in table::align(&rows) {
+        _ = writeln!(out, "{indent}{row}");
+    }
+}
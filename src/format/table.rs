@@ -0,0 +1,81 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Alignment of [Gherkin] tables.
+//!
+//! [Gherkin]: https://cucumber.io/docs/gherkin/reference
+
+use std::mem;
+
+/// Aligns the provided table `rows` (each being a trimmed `| a | b |` line),
+/// padding every cell to the widest one in its column.
+pub(super) fn align(rows: &[&str]) -> Vec<String> {
+    let rows = rows.iter().map(|r| cells(r)).collect::<Vec<_>>();
+
+    let mut widths = Vec::<usize>::new();
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            let len = cell.chars().count();
+            match widths.get_mut(i) {
+                Some(w) => *w = (*w).max(len),
+                None => widths.push(len),
+            }
+        }
+    }
+
+    rows.iter()
+        .map(|row| {
+            let cells = row.iter().zip(&widths).map(|(cell, &width)| {
+                let pad = width - cell.chars().count();
+                format!("{cell}{}", " ".repeat(pad))
+            });
+            format!("| {} |", cells.collect::<Vec<_>>().join(" | "))
+        })
+        .collect()
+}
+
+/// Splits the provided `row` into its trimmed cells, keeping the escaped
+/// `\|` and `\\` sequences as is.
+fn cells(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+
+    let (mut cells, mut cell) = (vec![], String::new());
+    let mut chars = row.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                cell.push(c);
+                cell.extend(chars.next());
+            }
+            '|' => cells.push(mem::take(&mut cell).trim().to_owned()),
+            c => cell.push(c),
+        }
+    }
+    if !cell.trim().is_empty() {
+        cells.push(cell.trim().to_owned());
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_columns() {
+        let rows = align(&["|name|age|", "| Zoë  |  7 |", r"| a\|b | 12|"]);
+
+        assert_eq!(
+            rows,
+            ["| name | age |", "| Zoë  | 7   |", r"| a\|b | 12  |"],
+        );
+    }
+}
@@ -178,12 +178,28 @@ mod cucumber_ext;
 pub mod data_table;
 pub mod error;
 pub mod event;
+pub mod exit_code;
+#[cfg(feature = "failures-file")]
+pub mod failures;
 pub mod feature;
+pub mod fixture;
+pub mod format;
 pub(crate) mod future;
+pub mod lint;
+pub mod list;
 pub mod parser;
+#[cfg(feature = "profiles")]
+pub mod profile;
 pub mod runner;
+pub mod runtime;
+pub mod scenario;
 pub mod step;
+#[cfg(feature = "steps-index")]
+pub mod step_index;
+pub mod suite;
 pub mod tag;
+pub(crate) mod time;
+pub mod validate;
 pub mod writer;
 
 /// Prelude module with commonly used types for external integrations
@@ -0,0 +1,155 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CLI options of linting `.feature` files.
+
+use std::str::FromStr;
+
+use derive_more::with_trait::{Display, Error};
+
+use super::{Linter, Report, Rule, Severity};
+
+/// CLI options of linting `.feature` files.
+#[derive(Clone, Debug, Default, clap::Args)]
+#[group(skip)]
+pub struct Cli {
+    /// Lint `.feature` files for common problems instead of executing them.
+    #[arg(long, global = true)]
+    pub lint: bool,
+
+    /// Severity of a lint rule, overriding its default one.
+    #[arg(long = "lint-rule", value_name = "RULE=SEVERITY", global = true)]
+    pub lint_rules: Vec<RuleSeverity>,
+
+    /// Known tags, any other ones are reported by the `unused-tag` lint rule.
+    #[arg(
+        long = "lint-known-tags",
+        value_name = "tags",
+        value_delimiter = ',',
+        global = true
+    )]
+    pub lint_known_tags: Vec<String>,
+
+    /// Maximum number of steps in a scenario for the `long-scenario` lint
+    /// rule.
+    #[arg(long = "lint-max-steps", value_name = "int", global = true)]
+    pub lint_max_steps: Option<usize>,
+
+    /// Output format of lint findings.
+    #[arg(
+        long = "lint-format",
+        value_name = "text|json",
+        default_value = "text",
+        global = true
+    )]
+    pub lint_format: Format,
+}
+
+impl Cli {
+    /// Creates a [`Linter`] configured by these [`Cli`] options.
+    #[must_use]
+    pub fn linter(&self) -> Linter {
+        let mut linter = Linter::new();
+        if !self.lint_known_tags.is_empty() {
+            linter = linter.known_tags(&self.lint_known_tags);
+        }
+        if let Some(max) = self.lint_max_steps {
+            linter = linter.max_steps(max);
+        }
+        self.lint_rules
+            .iter()
+            .fold(linter, |l, r| l.severity(r.rule, r.severity))
+    }
+
+    /// Renders the provided [`Report`] in the configured [`Format`].
+    #[must_use]
+    pub fn render(&self, report: &Report) -> String {
+        match self.lint_format {
+            Format::Text => report.to_string(),
+            Format::Json => report.to_json(),
+        }
+    }
+}
+
+/// Output format of a lint [`Report`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    /// `path:line:col: severity[rule]: message` line per finding.
+    #[default]
+    Text,
+
+    /// JSON array of findings.
+    Json,
+}
+
+/// `RULE=SEVERITY` override of a [`Rule`]'s [`Severity`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RuleSeverity {
+    /// [`Rule`] to override the [`Severity`] of.
+    pub rule: Rule,
+
+    /// [`Severity`] to use.
+    pub severity: Severity,
+}
+
+impl FromStr for RuleSeverity {
+    type Err = InvalidRuleSeverityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || InvalidRuleSeverityError(s.to_owned());
+        let (rule, severity) = s.split_once('=').ok_or_else(err)?;
+        let (Ok(rule), Ok(severity)) =
+            (rule.trim().parse(), severity.trim().parse())
+        else {
+            return Err(err());
+        };
+        Ok(Self { rule, severity })
+    }
+}
+
+/// Error of parsing an invalid [`RuleSeverity`].
+#[derive(Clone, Debug, Display, Error)]
+#[display("Expected `RULE=off|warning|error` lint rule severity, found `{_0}`")]
+pub struct InvalidRuleSeverityError(#[error(not(source))] pub String);
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser as _;
+
+    use super::*;
+
+    #[derive(clap::Parser)]
+    struct Opts {
+        #[command(flatten)]
+        lint: Cli,
+    }
+
+    #[test]
+    fn configures_linter() {
+        let cli = Opts::try_parse_from([
+            "test",
+            "--lint",
+            "--lint-rule",
+            "step-order=error",
+            "--lint-max-steps=3",
+            "--lint-format=json",
+        ])
+        .unwrap()
+        .lint;
+
+        let linter = cli.linter();
+
+        assert!(cli.lint);
+        assert_eq!(cli.lint_format, Format::Json);
+        assert_eq!(linter.severity_of(Rule::StepOrder), Severity::Error);
+        assert_eq!(linter.severity_of(Rule::LongScenario), Severity::Warning);
+        assert!("step-order=fatal".parse::<RuleSeverity>().is_err());
+    }
+}
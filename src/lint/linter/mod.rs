@@ -0,0 +1,273 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Linter`] checking `.feature` files.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+    path::PathBuf,
+};
+
+use super::{Finding, Report, Rule, Severity};
+use crate::{event, parser, scenario, tag};
+
+/// Tags having a meaning for this crate itself, so always known.
+const BUILTIN_TAGS: [&str; 6] = [
+    "serial",
+    "allow.skipped",
+    "retry",
+    "file",
+    "cached-background",
+    scenario::WARMUP_TAG,
+];
+
+/// Checker of `.feature` files for common problems, described by [`Rule`]s.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::lint::{Linter, Rule, Severity};
+/// #
+/// let feature = gherkin::Feature::parse(
+///     "Feature: F\n  Scenario: S\n  Scenario: S\n    Given a step\n",
+///     gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap();
+///
+/// let report = Linter::new()
+///     .severity(Rule::EmptyScenario, Severity::Warning)
+///     .lint([Ok(feature)]);
+///
+/// let rules = report.findings().iter().map(|f| f.rule).collect::<Vec<_>>();
+/// assert_eq!(rules, [Rule::EmptyScenario, Rule::DuplicateScenarioName]);
+/// assert!(report.has_errors());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Linter {
+    /// [`Severity`]s overriding the [`Rule::default_severity()`]s.
+    severities: HashMap<Rule, Severity>,
+
+    /// Tags considered known by the [`Rule::UnusedTag`].
+    ///
+    /// [`None`] disables the [`Rule::UnusedTag`] check.
+    known_tags: Option<HashSet<String>>,
+
+    /// Maximum number of [`gherkin::Step`]s in a [`gherkin::Scenario`] for the
+    /// [`Rule::LongScenario`].
+    max_steps: usize,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Linter {
+    /// Creates a new [`Linter`] with the default [`Rule`] severities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { severities: HashMap::new(), known_tags: None, max_steps: 10 }
+    }
+
+    /// Sets the [`Severity`] of the provided [`Rule`].
+    #[must_use]
+    pub fn severity(mut self, rule: Rule, severity: Severity) -> Self {
+        _ = self.severities.insert(rule, severity);
+        self
+    }
+
+    /// Adds the provided tags (with or without a leading `@`) to the known
+    /// ones, enabling the [`Rule::UnusedTag`] check.
+    ///
+    /// Any other tag, except the ones used by this crate itself (like
+    /// `@serial` or `@retry`), is reported as an unused one.
+    #[must_use]
+    pub fn known_tags<T: AsRef<str>>(
+        mut self,
+        tags: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.known_tags
+            .get_or_insert_default()
+            .extend(tags.into_iter().map(|t| tag::Tag::parse(t.as_ref()).name));
+        self
+    }
+
+    /// Sets the maximum number of [`gherkin::Step`]s in a
+    /// [`gherkin::Scenario`] for the [`Rule::LongScenario`].
+    ///
+    /// Default is `10`.
+    #[must_use]
+    pub const fn max_steps(mut self, max: usize) -> Self {
+        self.max_steps = max;
+        self
+    }
+
+    /// Returns the configured [`Severity`] of the provided [`Rule`].
+    #[must_use]
+    pub fn severity_of(&self, rule: Rule) -> Severity {
+        self.severities
+            .get(&rule)
+            .copied()
+            .unwrap_or_else(|| rule.default_severity())
+    }
+
+    /// Lints the provided parsed [`gherkin::Feature`]s, reporting the
+    /// [`parser::Error`]s as [`Rule::Parsing`] violations.
+    pub fn lint(
+        &self,
+        features: impl IntoIterator<Item = parser::Result<gherkin::Feature>>,
+    ) -> Report {
+        let mut report = Report::default();
+        for feature in features {
+            match feature {
+                Ok(f) => self.lint_feature(&f, &mut report),
+                Err(e) => {
+                    let e = event::ParsingFailed::from(&e);
+                    report.push(self.finding(
+                        Rule::Parsing,
+                        e.path.as_ref(),
+                        e.position,
+                        e.message,
+                    ));
+                }
+            }
+        }
+        report
+    }
+
+    /// Lints the provided [`gherkin::Feature`] into the provided [`Report`].
+    fn lint_feature(&self, feature: &gherkin::Feature, report: &mut Report) {
+        let path = feature.path.as_ref();
+        let mut push = |rule, pos, msg: String| {
+            report.push(self.finding(rule, path, Some(pos), msg));
+        };
+
+        self.check_tags(&feature.tags, feature.position, &mut push);
+        for rule in &feature.rules {
+            self.check_tags(&rule.tags, rule.position, &mut push);
+        }
+
+        for scenarios in iter_scenarios(feature) {
+            // Expanded `Scenario Outline`s share the `span` of the original.
+            let mut seen = HashSet::new();
+            let mut names = HashMap::new();
+            for sc in scenarios.iter().filter(|sc| seen.insert(sc.span.start)) {
+                if let Some(pos) = names.insert(&sc.name, sc.position) {
+                    push(
+                        Rule::DuplicateScenarioName,
+                        sc.position,
+                        format!(
+                            "scenario `{}` is already defined at line {}",
+                            sc.name, pos.line,
+                        ),
+                    );
+                }
+                self.check_scenario(sc, &mut push);
+            }
+        }
+    }
+
+    /// Checks the provided [`gherkin::Scenario`] on its own.
+    fn check_scenario(
+        &self,
+        sc: &gherkin::Scenario,
+        push: &mut impl FnMut(Rule, gherkin::LineCol, String),
+    ) {
+        self.check_tags(&sc.tags, sc.position, push);
+
+        if sc.steps.is_empty() {
+            push(
+                Rule::EmptyScenario,
+                sc.position,
+                format!("scenario `{}` has no steps", sc.name),
+            );
+        }
+        if sc.steps.len() > self.max_steps {
+            push(
+                Rule::LongScenario,
+                sc.position,
+                format!(
+                    "scenario `{}` has {} steps, while at most {} are allowed",
+                    sc.name,
+                    sc.steps.len(),
+                    self.max_steps,
+                ),
+            );
+        }
+        let mut acted = false;
+        for step in &sc.steps {
+            match step.ty {
+                gherkin::StepType::Given if acted => push(
+                    Rule::StepOrder,
+                    step.position,
+                    format!(
+                        "`Given {}` step follows a `When`/`Then`",
+                        step.value
+                    ),
+                ),
+                gherkin::StepType::Given => {}
+                gherkin::StepType::When | gherkin::StepType::Then => {
+                    acted = true;
+                }
+            }
+        }
+    }
+
+    /// Checks the provided `tags` being known ones.
+    fn check_tags(
+        &self,
+        tags: &[String],
+        pos: gherkin::LineCol,
+        push: &mut impl FnMut(Rule, gherkin::LineCol, String),
+    ) {
+        let Some(known) = &self.known_tags else {
+            return;
+        };
+        for t in tags {
+            let name = tag::Tag::parse(t).name;
+            if !known.contains(&name) && !BUILTIN_TAGS.contains(&name.as_str())
+            {
+                push(Rule::UnusedTag, pos, format!("tag `@{t}` is unknown"));
+            }
+        }
+    }
+
+    /// Creates a new [`Finding`] of the provided [`Rule`] with its configured
+    /// [`Severity`].
+    fn finding(
+        &self,
+        rule: Rule,
+        path: Option<&PathBuf>,
+        position: Option<gherkin::LineCol>,
+        message: String,
+    ) -> Finding {
+        Finding {
+            rule,
+            severity: self.severity_of(rule),
+            path: path.cloned(),
+            position,
+            message,
+        }
+    }
+}
+
+/// Returns an [`Iterator`] over [`gherkin::Scenario`]s groups of the provided
+/// [`gherkin::Feature`], where names should be unique.
+fn iter_scenarios(
+    feature: &gherkin::Feature,
+) -> impl Iterator<Item = &[gherkin::Scenario]> {
+    iter::once(feature.scenarios.as_slice())
+        .chain(feature.rules.iter().map(|r| r.scenarios.as_slice()))
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests of [`Linter`] checks.
+
+use super::*;
+use crate::feature::Ext as _;
+
+fn lint(linter: &Linter, source: &str) -> Vec<(Rule, usize)> {
+    let feature =
+        gherkin::Feature::parse(source, gherkin::GherkinEnv::default())
+            .unwrap()
+            .expand_examples()
+            .map_err(parser::Error::from);
+    linter
+        .lint([feature])
+        .findings()
+        .iter()
+        .map(|f| (f.rule, f.position.map_or(0, |p| p.line)))
+        .collect()
+}
+
+#[test]
+fn reports_step_order_and_long_scenarios() {
+    let found = lint(
+        &Linter::new().max_steps(2),
+        "Feature: F\n\
+         \x20 Scenario: S\n    When a\n    And b\n    Given c\n",
+    );
+
+    assert_eq!(found, [(Rule::LongScenario, 2), (Rule::StepOrder, 5)]);
+}
+
+#[test]
+fn reports_outlines_once() {
+    let found = lint(
+        &Linter::new(),
+        "Feature: F\n\
+         \x20 Scenario Outline: S\n    Given <x>\n    Given <x>\n\
+         \x20   Examples:\n      | x |\n      | 1 |\n      | 2 |\n",
+    );
+
+    assert_eq!(found, []);
+}
+
+#[test]
+fn reports_unknown_tags_only_when_configured() {
+    let source = "@owner:core @serial\nFeature: F\n\
+                  \x20 @wip\n  Scenario: S\n    Given a\n";
+
+    assert_eq!(lint(&Linter::new(), source), []);
+    assert_eq!(
+        lint(&Linter::new().known_tags(["@owner"]), source),
+        [(Rule::UnusedTag, 4)],
+    );
+    assert_eq!(
+        lint(
+            &Linter::new()
+                .known_tags(["owner"])
+                .severity(Rule::UnusedTag, Severity::Off),
+            source,
+        ),
+        [],
+    );
+}
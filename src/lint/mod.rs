@@ -0,0 +1,165 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Linting of `.feature` files for common problems, without executing them.
+//!
+//! Run via the `--lint` CLI option, or programmatically via a [`Linter`].
+
+mod cli;
+mod linter;
+mod report;
+
+use std::{fmt, str::FromStr};
+
+use derive_more::with_trait::{Display, Error};
+
+#[doc(inline)]
+pub use self::{
+    cli::{Cli, Format, InvalidRuleSeverityError, RuleSeverity},
+    linter::Linter,
+    report::{Finding, Report},
+};
+
+/// Problem a [`Linter`] checks `.feature` files for.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    /// `.feature` file failing to be parsed.
+    Parsing,
+
+    /// Tag not being among the known ones of a [`Linter`].
+    UnusedTag,
+
+    /// Several [`gherkin::Scenario`]s with the same name in a
+    /// [`gherkin::Feature`] or [`gherkin::Rule`].
+    DuplicateScenarioName,
+
+    /// [`gherkin::Scenario`] without any [`gherkin::Step`]s.
+    EmptyScenario,
+
+    /// `Given` [`gherkin::Step`] following a `When` or `Then` one.
+    StepOrder,
+
+    /// [`gherkin::Scenario`] having more [`gherkin::Step`]s than allowed.
+    LongScenario,
+}
+
+impl Rule {
+    /// All the existing [`Rule`]s.
+    pub const ALL: [Self; 6] = [
+        Self::Parsing,
+        Self::UnusedTag,
+        Self::DuplicateScenarioName,
+        Self::EmptyScenario,
+        Self::StepOrder,
+        Self::LongScenario,
+    ];
+
+    /// Returns the kebab-case name of this [`Rule`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Parsing => "parsing",
+            Self::UnusedTag => "unused-tag",
+            Self::DuplicateScenarioName => "duplicate-scenario-name",
+            Self::EmptyScenario => "empty-scenario",
+            Self::StepOrder => "step-order",
+            Self::LongScenario => "long-scenario",
+        }
+    }
+
+    /// Returns the default [`Severity`] of this [`Rule`].
+    #[must_use]
+    pub const fn default_severity(self) -> Severity {
+        match self {
+            Self::Parsing
+            | Self::DuplicateScenarioName
+            | Self::EmptyScenario => Severity::Error,
+            Self::UnusedTag | Self::StepOrder | Self::LongScenario => {
+                Severity::Warning
+            }
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Rule {
+    type Err = UnknownRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|r| r.name() == s)
+            .ok_or_else(|| UnknownRuleError(s.to_owned()))
+    }
+}
+
+/// Error of parsing an unknown [`Rule`] name.
+#[derive(Clone, Debug, Display, Error)]
+#[display("Unknown lint rule `{_0}`")]
+pub struct UnknownRuleError(#[error(not(source))] pub String);
+
+/// Severity of a [`Rule`] violation.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// [`Rule`] isn't checked at all.
+    #[display("off")]
+    Off,
+
+    /// Violation is reported, but doesn't fail the linting.
+    #[display("warning")]
+    Warning,
+
+    /// Violation is reported and fails the linting.
+    #[display("error")]
+    Error,
+}
+
+impl FromStr for Severity {
+    type Err = UnknownSeverityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "warn" | "warning" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            _ => Err(UnknownSeverityError(s.to_owned())),
+        }
+    }
+}
+
+/// Error of parsing an unknown [`Severity`] name.
+#[derive(Clone, Debug, Display, Error)]
+#[display("Unknown lint severity `{_0}`, expected `off`, `warning` or `error`")]
+pub struct UnknownSeverityError(#[error(not(source))] pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rule_names() {
+        for rule in Rule::ALL {
+            assert_eq!(rule.name().parse::<Rule>().unwrap(), rule);
+        }
+        assert!("unknown".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn parses_severities() {
+        assert_eq!("warn".parse::<Severity>().unwrap(), Severity::Warning);
+        assert_eq!("off".parse::<Severity>().unwrap(), Severity::Off);
+        assert!("fatal".parse::<Severity>().is_err());
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Report`] of linting `.feature` files.
+
+use std::{fmt, fmt::Write as _, path::PathBuf, slice};
+
+use super::{Rule, Severity};
+use crate::writer::basic::trim_path;
+
+/// Single [`Rule`] violation found by a [`Linter`].
+///
+/// [`Linter`]: super::Linter
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Finding {
+    /// Violated [`Rule`].
+    pub rule: Rule,
+
+    /// [`Severity`] of the violation.
+    pub severity: Severity,
+
+    /// Path to the `.feature` file of the violation, if known.
+    pub path: Option<PathBuf>,
+
+    /// Position of the violation in the `.feature` file, if known.
+    pub position: Option<gherkin::LineCol>,
+
+    /// Human-readable description of the violation.
+    pub message: String,
+}
+
+impl Finding {
+    /// Returns the `path:line:col` location of this [`Finding`].
+    fn location(&self) -> String {
+        let path = self
+            .path
+            .as_ref()
+            .map(|p| trim_path(&p.display().to_string()).to_owned())
+            .unwrap_or_default();
+        match self.position {
+            Some(pos) => format!("{path}:{}:{}", pos.line, pos.col),
+            None => path,
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}[{}]: {}",
+            self.location(),
+            self.severity,
+            self.rule,
+            self.message,
+        )
+    }
+}
+
+/// All the [`Finding`]s of linting `.feature` files.
+///
+/// [`fmt::Display`]s as one `path:line:col: severity[rule]: message` line
+/// per [`Finding`], as understood by most editors and CI systems.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report(Vec<Finding>);
+
+impl Report {
+    /// Adds the provided [`Finding`] to this [`Report`], unless its
+    /// [`Severity`] is [`Severity::Off`].
+    pub fn push(&mut self, finding: Finding) {
+        if finding.severity != Severity::Off {
+            self.0.push(finding);
+        }
+    }
+
+    /// Returns all the [`Finding`]s of this [`Report`].
+    #[must_use]
+    pub fn findings(&self) -> &[Finding] {
+        &self.0
+    }
+
+    /// Returns an [`Iterator`] over all the [`Finding`]s of this [`Report`].
+    pub fn iter(&self) -> slice::Iter<'_, Finding> {
+        self.0.iter()
+    }
+
+    /// Indicates whether this [`Report`] contains [`Severity::Error`]
+    /// [`Finding`]s.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.0.iter().any(|f| f.severity == Severity::Error)
+    }
+
+    /// Renders this [`Report`] as a JSON array of [`Finding`] objects.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("[");
+        for (i, f) in self.0.iter().enumerate() {
+            let path = f.path.as_ref().map(|p| p.display().to_string());
+            _ = write!(
+                out,
+                "{}{{\"rule\":\"{}\",\"severity\":\"{}\",\"path\":{},\
+                 \"line\":{},\"column\":{},\"message\":{}}}",
+                if i == 0 { "" } else { "," },
+                f.rule,
+                f.severity,
+                path.as_deref().map_or_else(|| "null".into(), json_str),
+                f.position
+                    .map_or_else(|| "null".into(), |p| p.line.to_string()),
+                f.position.map_or_else(|| "null".into(), |p| p.col.to_string()),
+                json_str(&f.message),
+            );
+        }
+        out.push(']');
+        out
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for finding in &self.0 {
+            writeln!(f, "{finding}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'r> IntoIterator for &'r Report {
+    type Item = &'r Finding;
+    type IntoIter = slice::Iter<'r, Finding>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Renders the provided `s` as a JSON string literal.
+fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => _ = write!(out, "\\u{:04x}", u32::from(c)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(severity: Severity) -> Finding {
+        Finding {
+            rule: Rule::EmptyScenario,
+            severity,
+            path: Some("a.feature".into()),
+            position: Some(gherkin::LineCol { line: 3, col: 3 }),
+            message: "scenario \"S\"\thas no steps".into(),
+        }
+    }
+
+    #[test]
+    fn renders_text_and_json() {
+        let mut report = Report::default();
+        report.push(finding(Severity::Error));
+        report.push(finding(Severity::Off));
+
+        assert_eq!(report.findings().len(), 1);
+        assert!(report.has_errors());
+        assert_eq!(
+            report.to_string(),
+            "a.feature:3:3: error[empty-scenario]: scenario \"S\"\thas no \
+             steps\n",
+        );
+        assert_eq!(
+            report.to_json(),
+            r#"[{"rule":"empty-scenario","severity":"error","#.to_owned()
+                + r#""path":"a.feature","line":3,"column":3,"#
+                + r#""message":"scenario \"S\"\thas no steps"}]"#,
+        );
+    }
+}
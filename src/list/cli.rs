@@ -0,0 +1,21 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CLI options of listing [`gherkin::Scenario`]s.
+
+/// CLI options of listing [`gherkin::Scenario`]s.
+#[derive(Clone, Copy, Debug, Default, clap::Args)]
+#[group(skip)]
+pub struct Cli {
+    /// Print the scenarios to be run with the current filters (along with
+    /// their tags, locations and examples counts), instead of executing them.
+    #[arg(long, global = true)]
+    pub list_scenarios: bool,
+}
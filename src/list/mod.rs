@@ -0,0 +1,247 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Listing of [`gherkin::Scenario`]s to be run, without executing them.
+//!
+//! Run via the `--list-scenarios` CLI option, or programmatically via
+//! [`list()`].
+
+mod cli;
+
+use std::{fmt, path::PathBuf, slice};
+
+use itertools::Itertools as _;
+
+#[doc(inline)]
+pub use self::cli::Cli;
+use crate::{scenario::Ext as _, writer::basic::trim_path};
+
+/// Single [`gherkin::Scenario`] (or [`Scenario Outline`][1]) of a [`Listing`].
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// Path to the `.feature` file of this [`Entry`], if known.
+    pub path: Option<PathBuf>,
+
+    /// Line of this [`Entry`] in the `.feature` file.
+    ///
+    /// For a [`Scenario Outline`][1] it's the line of its first selected
+    /// [`Examples`] row.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    pub line: usize,
+
+    /// Keyword of this [`Entry`] (like `Scenario` or `Scenario Outline`).
+    pub keyword: String,
+
+    /// Name of this [`Entry`].
+    pub name: String,
+
+    /// Tags of this [`Entry`], including the ones inherited from its
+    /// [`gherkin::Rule`] and [`gherkin::Feature`].
+    pub tags: Vec<String>,
+
+    /// Number of selected [`Examples`] rows, if this [`Entry`] is a
+    /// [`Scenario Outline`][1].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    pub examples: Option<usize>,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self
+            .path
+            .as_ref()
+            .map(|p| trim_path(&p.display().to_string()).to_owned())
+            .unwrap_or_default();
+        write!(f, "{path}:{}: {}: {}", self.line, self.keyword, self.name)?;
+        if let Some(count) = self.examples {
+            let s = if count == 1 { "" } else { "s" };
+            write!(f, " ({count} example{s})")?;
+        }
+        for tag in &self.tags {
+            write!(f, " @{tag}")?;
+        }
+        Ok(())
+    }
+}
+
+/// All the [`Entry`]s found by [`list()`].
+///
+/// [`fmt::Display`]s as one `path:line: Keyword: name (N examples) @tags` line
+/// per [`Entry`], followed by a summary line.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Listing(Vec<Entry>);
+
+impl Listing {
+    /// Returns all the [`Entry`]s of this [`Listing`].
+    #[must_use]
+    pub fn entries(&self) -> &[Entry] {
+        &self.0
+    }
+
+    /// Returns an [`Iterator`] over all the [`Entry`]s of this [`Listing`].
+    pub fn iter(&self) -> slice::Iter<'_, Entry> {
+        self.0.iter()
+    }
+
+    /// Returns the total number of [`gherkin::Scenario`]s to be run,
+    /// counting every [`Examples`] row separately.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    #[must_use]
+    pub fn runs(&self) -> usize {
+        self.0.iter().map(|e| e.examples.unwrap_or(1)).sum()
+    }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.0 {
+            writeln!(f, "{entry}")?;
+        }
+        writeln!(f, "{} scenarios ({} runs)", self.0.len(), self.runs())
+    }
+}
+
+impl<'l> IntoIterator for &'l Listing {
+    type Item = &'l Entry;
+    type IntoIter = slice::Iter<'l, Entry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Lists all the [`gherkin::Scenario`]s of the provided parsed (and filtered)
+/// [`gherkin::Feature`]s.
+///
+/// Expanded [`Scenario Outline`][1]s are collapsed back into a single
+/// [`Entry`] counting their [`Examples`] rows.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{feature::Ext as _, list};
+/// #
+/// let feature = gherkin::Feature::parse(
+///     "@smoke\n\
+///      Feature: F\n\
+///      \x20 Scenario: one\n\
+///      \x20   Given a cat\n\
+///      \x20 @slow\n\
+///      \x20 Scenario Outline: two\n\
+///      \x20   Given a <pet>\n\
+///      \x20   Examples:\n\
+///      \x20     | pet |\n\
+///      \x20     | cat |\n\
+///      \x20     | dog |\n",
+///     gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap()
+/// .expand_examples()
+/// .unwrap();
+///
+/// let listing = list::list(&[feature]);
+///
+/// assert_eq!(
+///     listing.to_string(),
+///     ":3: Scenario: one @smoke\n\
+///      :10: Scenario Outline: two (2 examples) @smoke @slow\n\
+///      2 scenarios (3 runs)\n",
+/// );
+/// ```
+///
+/// [`Examples`]: gherkin::Examples
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[must_use]
+pub fn list(features: &[gherkin::Feature]) -> Listing {
+    let mut entries = vec![];
+    for feature in features {
+        let scenarios = feature.scenarios.iter().map(|s| (None, s)).chain(
+            feature
+                .rules
+                .iter()
+                .flat_map(|r| r.scenarios.iter().map(move |s| (Some(r), s))),
+        );
+        // Expanded `Scenario Outline`s keep the `gherkin::Span` of the
+        // original one, and are placed consequently.
+        for (_, mut group) in &scenarios.chunk_by(|(_, s)| s.span.start) {
+            let Some((rule, scenario)) = group.next() else {
+                continue;
+            };
+            let examples =
+                scenario.example_row().is_some().then(|| group.count() + 1);
+            let tags = feature
+                .tags
+                .iter()
+                .chain(rule.iter().flat_map(|r| &r.tags))
+                .chain(&scenario.tags)
+                .unique()
+                .cloned()
+                .collect();
+            entries.push(Entry {
+                path: feature.path.clone(),
+                line: scenario.position.line,
+                keyword: scenario.keyword.clone(),
+                name: scenario.name.clone(),
+                tags,
+                examples,
+            });
+        }
+    }
+    Listing(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::Ext as _;
+
+    #[test]
+    fn collapses_filtered_outlines_per_rule() {
+        let mut feature = gherkin::Feature::parse(
+            "Feature: F\n\
+             \x20 @billing\n\
+             \x20 Rule: R\n\
+             \x20   @slow\n\
+             \x20   Scenario Outline: refund <n>\n\
+             \x20     Given <n>\n\
+             \x20     Examples:\n\
+             \x20       | n |\n\
+             \x20       | 1 |\n\
+             \x20       | 2 |\n\
+             \x20       | 3 |\n\
+             \x20   @billing\n\
+             \x20   Scenario: pay\n\
+             \x20     Given a\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+        .expand_examples()
+        .unwrap();
+        feature.rules[0].scenarios.retain(|s| s.name != "refund 2");
+
+        let listing = list(&[feature]);
+
+        assert_eq!(listing.runs(), 3);
+        assert_eq!(
+            listing.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                ":9: Scenario Outline: refund 1 (2 examples) @billing @slow",
+                ":13: Scenario: pay @billing",
+            ],
+        );
+    }
+}
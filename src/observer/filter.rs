@@ -0,0 +1,181 @@
+//! [`EventFilter`] narrowing the events an observer is notified about.
+
+use super::ObservationContext;
+use crate::event;
+
+/// Declarative filter of the events an observer registered via
+/// [`ObserverRegistry::register_filtered()`] is notified about.
+///
+/// All the conditions of an [`EventFilter`] have to hold for an event to be
+/// passed to the observer.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::observer::EventFilter;
+/// #
+/// let filter = EventFilter::failures_only().for_tags("@critical");
+/// ```
+///
+/// [`ObserverRegistry::register_filtered()`]: super::ObserverRegistry::register_filtered
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    /// Indicates whether only failure events pass this [`EventFilter`].
+    failures_only: bool,
+
+    /// Tags (without `@`) of the [`gherkin::Scenario`]s, events of which pass
+    /// this [`EventFilter`], if any of them is present.
+    tags: Vec<String>,
+}
+
+impl EventFilter {
+    /// Creates a new [`EventFilter`] passing all the events.
+    #[must_use]
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`EventFilter`] passing only failed steps and hooks
+    /// events.
+    #[must_use]
+    pub fn failures_only() -> Self {
+        Self { failures_only: true, ..Self::default() }
+    }
+
+    /// Makes this [`EventFilter`] pass only events of the
+    /// [`gherkin::Scenario`]s having any of the provided whitespace-separated
+    /// `tags` (like `"@critical @smoke"`).
+    #[must_use]
+    pub fn for_tags(mut self, tags: impl AsRef<str>) -> Self {
+        self.tags.extend(
+            tags.as_ref()
+                .split_whitespace()
+                .map(|t| t.strip_prefix('@').unwrap_or(t).to_owned()),
+        );
+        self
+    }
+
+    /// Indicates whether the provided `event` happened in the provided
+    /// [`ObservationContext`] passes this [`EventFilter`].
+    #[must_use]
+    pub fn matches<W>(
+        &self,
+        event: &event::Cucumber<W>,
+        context: &ObservationContext,
+    ) -> bool {
+        let tagged = self.tags.is_empty()
+            || context.tags.iter().any(|t| {
+                self.tags.iter().any(|f| t.strip_prefix('@').unwrap_or(t) == f)
+            });
+        tagged && (!self.failures_only || is_failure(event))
+    }
+}
+
+/// Indicates whether the provided `event` is a failure of a step or a hook.
+fn is_failure<W>(event: &event::Cucumber<W>) -> bool {
+    let scenario = match event {
+        event::Cucumber::Feature(_, event::Feature::Scenario(_, sc)) => {
+            &sc.event
+        }
+        event::Cucumber::Feature(
+            _,
+            event::Feature::Rule(_, event::Rule::Scenario(_, sc)),
+        ) => &sc.event,
+        event::Cucumber::Started
+        | event::Cucumber::ParsingFinished { .. }
+        | event::Cucumber::Feature(..)
+        | event::Cucumber::Report(_)
+        | event::Cucumber::Finished => return false,
+    };
+    matches!(
+        scenario,
+        event::Scenario::Hook(_, _, event::Hook::Failed(..))
+            | event::Scenario::Background(_, event::Step::Failed { .. })
+            | event::Scenario::Step(_, event::Step::Failed { .. }),
+    )
+}
+
+#[cfg(test)]
+mod spec {
+    use std::time::Instant;
+
+    use super::{EventFilter, ObservationContext};
+    use crate::{event, event::source::Source, test_utils::common::TestWorld};
+
+    fn context(tags: &[&str]) -> ObservationContext {
+        ObservationContext {
+            scenario_id: None,
+            feature_name: String::new(),
+            rule_name: None,
+            scenario_name: String::new(),
+            retry_info: None,
+            tags: tags.iter().map(ToString::to_string).collect(),
+            timestamp: Instant::now(),
+        }
+    }
+
+    fn hook(ev: event::Hook<TestWorld>) -> event::Cucumber<TestWorld> {
+        let feature = gherkin::Feature {
+            keyword: String::new(),
+            name: String::new(),
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 0, col: 0 },
+            path: None,
+            description: None,
+            background: None,
+            scenarios: vec![],
+            rules: vec![],
+            tags: vec![],
+        };
+        let scenario = gherkin::Scenario {
+            keyword: String::new(),
+            name: String::new(),
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 0, col: 0 },
+            description: None,
+            steps: vec![],
+            examples: vec![],
+            tags: vec![],
+        };
+        event::Cucumber::scenario(
+            Source::new(feature),
+            None::<Source<gherkin::Rule>>,
+            Source::new(scenario),
+            event::RetryableScenario {
+                event: event::Scenario::Hook(
+                    event::HookType::Before,
+                    event::HookScope::Function,
+                    ev,
+                ),
+                retries: None,
+                id: None,
+            },
+        )
+    }
+
+    #[test]
+    fn passes_only_failures() {
+        let filter = EventFilter::failures_only();
+        let failed = hook(event::Hook::Failed(None, std::sync::Arc::new("")));
+
+        assert!(filter.matches(&failed, &context(&[])));
+        assert!(!filter.matches(&hook(event::Hook::Passed), &context(&[])));
+        let started = event::Cucumber::<TestWorld>::Started;
+        assert!(!filter.matches(&started, &context(&[])));
+        assert!(
+            EventFilter::all()
+                .matches(&hook(event::Hook::Passed), &context(&[]))
+        );
+    }
+
+    #[test]
+    fn passes_only_tagged_scenarios() {
+        let filter = EventFilter::all().for_tags("@critical @smoke");
+        let ev = event::Cucumber::<TestWorld>::Started;
+
+        assert!(filter.matches(&ev, &context(&["smoke"])));
+        assert!(filter.matches(&ev, &context(&["slow", "@critical"])));
+        assert!(!filter.matches(&ev, &context(&["slow"])));
+        assert!(!filter.matches(&ev, &context(&[])));
+    }
+}
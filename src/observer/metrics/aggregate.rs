@@ -0,0 +1,266 @@
+//! Aggregation of the observed events into [`Summary`]s.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::summary::{Status, Summary};
+use crate::{
+    event, event::source::Source, observer::ObservationContext, time::Instant,
+};
+
+/// [`gherkin::Scenario`] being executed at the moment.
+#[derive(Clone, Copy, Debug)]
+struct Running {
+    /// [`Instant`] when the [`gherkin::Scenario`] has started.
+    started: Instant,
+
+    /// [`Instant`] when its current [`gherkin::Step`] has started, if any.
+    step_started: Option<Instant>,
+
+    /// [`Status`] of the [`gherkin::Scenario`] so far.
+    status: Status,
+}
+
+/// Metrics aggregated from the observed events.
+#[derive(Debug, Default)]
+pub(super) struct Aggregate {
+    /// [`Summary`]s of the finished [`gherkin::Scenario`]s by
+    /// [`gherkin::Feature`] names.
+    pub(super) features: BTreeMap<String, Summary>,
+
+    /// [`Summary`]s of the finished [`gherkin::Scenario`]s by their tags.
+    pub(super) tags: BTreeMap<String, Summary>,
+
+    /// [`Summary`]s of the finished [`gherkin::Step`]s by [`gherkin::Feature`]
+    /// names.
+    pub(super) steps: BTreeMap<String, Summary>,
+
+    /// [`gherkin::Scenario`]s being executed.
+    running: HashMap<Source<gherkin::Scenario>, Running>,
+}
+
+impl Aggregate {
+    /// Records the provided `event` happened in the provided `context`.
+    pub(super) fn record<W>(
+        &mut self,
+        event: &event::Cucumber<W>,
+        context: &ObservationContext,
+    ) {
+        let (scenario, sc) = match event {
+            event::Cucumber::Feature(
+                _,
+                event::Feature::Scenario(scenario, sc),
+            ) => (scenario, sc),
+            event::Cucumber::Feature(
+                _,
+                event::Feature::Rule(_, event::Rule::Scenario(scenario, sc)),
+            ) => (scenario, &**sc),
+            event::Cucumber::Started
+            | event::Cucumber::ParsingFinished { .. }
+            | event::Cucumber::Feature(..)
+            | event::Cucumber::Report(_)
+            | event::Cucumber::Finished => return,
+        };
+        let now = context.timestamp;
+
+        match &sc.event {
+            event::Scenario::Started => {
+                let running = Running {
+                    started: now,
+                    step_started: None,
+                    status: Status::Passed,
+                };
+                _ = self.running.insert(scenario.clone(), running);
+            }
+            event::Scenario::Step(_, step)
+            | event::Scenario::Background(_, step) => {
+                self.record_step(scenario, step, context);
+            }
+            event::Scenario::Hook(_, _, event::Hook::Failed(..)) => {
+                if let Some(running) = self.running.get_mut(scenario) {
+                    running.status = Status::Failed;
+                }
+            }
+            event::Scenario::Finished(_) => {
+                let Some(running) = self.running.remove(scenario) else {
+                    return;
+                };
+                let duration = now.checked_duration_since(running.started);
+                self.features
+                    .entry(context.feature_name.clone())
+                    .or_default()
+                    .record(running.status, duration);
+                for tag in &context.tags {
+                    let tag = tag.strip_prefix('@').unwrap_or(tag);
+                    self.tags
+                        .entry(tag.to_owned())
+                        .or_default()
+                        .record(running.status, duration);
+                }
+            }
+            event::Scenario::Hook(..)
+            | event::Scenario::Log(_)
+            | event::Scenario::SlaViolated(_) => {}
+        }
+    }
+
+    /// Records the provided [`gherkin::Step`] `event` of the `scenario`.
+    fn record_step<W>(
+        &mut self,
+        scenario: &Source<gherkin::Scenario>,
+        event: &event::Step<W>,
+        context: &ObservationContext,
+    ) {
+        let Some(running) = self.running.get_mut(scenario) else {
+            return;
+        };
+        let status = match event {
+            event::Step::Started => {
+                running.step_started = Some(context.timestamp);
+                return;
+            }
+            event::Step::Heartbeat { .. } | event::Step::Published(_) => {
+                return;
+            }
+            event::Step::Passed { .. } => Status::Passed,
+            event::Step::Failed { .. } => Status::Failed,
+            event::Step::Skipped(_) | event::Step::NotRun => Status::Skipped,
+        };
+        if status != Status::Passed && running.status != Status::Failed {
+            running.status = status;
+        }
+        let duration = running
+            .step_started
+            .take()
+            .and_then(|at| context.timestamp.checked_duration_since(at))
+            .filter(|_| status != Status::Skipped);
+
+        self.steps
+            .entry(context.feature_name.clone())
+            .or_default()
+            .record(status, duration);
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use std::time::{Duration, Instant};
+
+    use super::{Aggregate, Status};
+    use crate::{
+        event, event::source::Source, observer::ObservationContext,
+        test_utils::common::TestWorld,
+    };
+
+    fn scenario(
+        scenario: &Source<gherkin::Scenario>,
+        ev: event::Scenario<TestWorld>,
+    ) -> event::Cucumber<TestWorld> {
+        let feature = gherkin::Feature {
+            keyword: String::new(),
+            name: String::new(),
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 0, col: 0 },
+            path: None,
+            description: None,
+            background: None,
+            scenarios: vec![],
+            rules: vec![],
+            tags: vec![],
+        };
+        event::Cucumber::scenario(
+            Source::new(feature),
+            None::<Source<gherkin::Rule>>,
+            scenario.clone(),
+            event::RetryableScenario { event: ev, retries: None, id: None },
+        )
+    }
+
+    fn new_scenario() -> Source<gherkin::Scenario> {
+        Source::new(gherkin::Scenario {
+            keyword: String::new(),
+            name: String::new(),
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 0, col: 0 },
+            description: None,
+            steps: vec![],
+            examples: vec![],
+            tags: vec![],
+        })
+    }
+
+    fn step(
+        sc: &Source<gherkin::Scenario>,
+        ev: event::Step<TestWorld>,
+    ) -> event::Cucumber<TestWorld> {
+        let step = gherkin::Step {
+            keyword: "Given ".to_owned(),
+            ty: gherkin::StepType::Given,
+            value: String::new(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        };
+        scenario(sc, event::Scenario::Step(Source::new(step), ev))
+    }
+
+    fn context(at: Instant) -> ObservationContext {
+        ObservationContext {
+            scenario_id: None,
+            feature_name: "checkout".into(),
+            rule_name: None,
+            scenario_name: String::new(),
+            retry_info: None,
+            tags: vec!["@critical".into()],
+            timestamp: at,
+        }
+    }
+
+    #[test]
+    fn aggregates_scenarios_and_steps() {
+        let (mut agg, start) = (Aggregate::default(), Instant::now());
+        let at = |ms| start + Duration::from_millis(ms);
+        let passed = event::Step::Passed {
+            captures: regex::Regex::new("").unwrap().capture_locations(),
+            location: None,
+            timing: event::StepTiming::default(),
+        };
+
+        let (first, second) = (new_scenario(), new_scenario());
+
+        // Scenarios are running concurrently.
+        agg.record(
+            &scenario(&first, event::Scenario::Started),
+            &context(at(0)),
+        );
+        agg.record(
+            &scenario(&second, event::Scenario::Started),
+            &context(at(0)),
+        );
+        agg.record(&step(&first, event::Step::Started), &context(at(10)));
+        agg.record(
+            &step(&second, event::Step::Skipped(None)),
+            &context(at(15)),
+        );
+        agg.record(&step(&first, passed), &context(at(30)));
+        agg.record(
+            &scenario(&second, event::Scenario::Finished(None)),
+            &context(at(35)),
+        );
+        agg.record(
+            &scenario(&first, event::Scenario::Finished(None)),
+            &context(at(40)),
+        );
+
+        let feature = &agg.features["checkout"];
+        assert_eq!(feature.count(Status::Passed), 1);
+        assert_eq!(feature.count(Status::Skipped), 1);
+        assert_eq!(feature.durations().len(), 2);
+        assert_eq!(agg.tags["critical"].count(Status::Passed), 1);
+
+        let steps = &agg.steps["checkout"];
+        assert_eq!(steps.count(Status::Passed), 1);
+        assert_eq!(steps.count(Status::Skipped), 1);
+        assert_eq!(steps.durations(), [Duration::from_millis(20)]);
+    }
+}
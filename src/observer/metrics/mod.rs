@@ -0,0 +1,96 @@
+//! [`MetricsObserver`] aggregating metrics of the run while it progresses.
+
+mod aggregate;
+mod render;
+mod server;
+mod summary;
+
+use std::{
+    io,
+    net::ToSocketAddrs,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use self::aggregate::Aggregate;
+use super::{ObservationContext, TestObserver};
+use crate::{Event, World, event};
+
+pub use self::server::MetricsServer;
+
+/// Observer aggregating counts, durations and their percentiles of the
+/// finished [`gherkin::Scenario`]s and [`gherkin::Step`]s per
+/// [`gherkin::Feature`] and tag, while the run progresses.
+///
+/// The aggregated metrics are [rendered](MetricsObserver::render) in
+/// [Prometheus text format][1], and may be [served](MetricsObserver::serve)
+/// on a local `/metrics` endpoint to be scraped during the run.
+///
+/// Clones of a [`MetricsObserver`] share the same metrics, so one may be
+/// registered in the runner while another one is used for reading.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use cucumber::{World, observer::metrics::MetricsObserver, runner::Basic};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # fn main() -> std::io::Result<()> {
+/// let metrics = MetricsObserver::new();
+/// let _server = metrics.serve("127.0.0.1:9464")?;
+///
+/// let runner = Basic::<MyWorld>::default()
+///     .register_observer(Box::new(metrics.clone()));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [1]: https://prometheus.io/docs/instrumenting/exposition_formats
+#[derive(Clone, Debug, Default)]
+pub struct MetricsObserver {
+    /// Metrics aggregated so far.
+    state: Arc<Mutex<Aggregate>>,
+}
+
+impl MetricsObserver {
+    /// Creates a new [`MetricsObserver`] without any metrics.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders the metrics aggregated so far in [Prometheus text format][1].
+    ///
+    /// [1]: https://prometheus.io/docs/instrumenting/exposition_formats
+    #[must_use]
+    pub fn render(&self) -> String {
+        render::render(
+            &self.state.lock().unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// Serves the metrics of this [`MetricsObserver`] on the `/metrics`
+    /// endpoint of the provided `addr`, until the returned [`MetricsServer`] is
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the `addr` cannot be bound.
+    pub fn serve(&self, addr: impl ToSocketAddrs) -> io::Result<MetricsServer> {
+        MetricsServer::bind(addr, self.clone())
+    }
+}
+
+impl<W: World> TestObserver<W> for MetricsObserver {
+    fn on_event(
+        &mut self,
+        event: &Event<event::Cucumber<W>>,
+        context: &ObservationContext,
+    ) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .record(&event.value, context);
+    }
+}
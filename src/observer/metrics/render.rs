@@ -0,0 +1,124 @@
+//! Rendering of an [`Aggregate`] in [Prometheus text format][1].
+//!
+//! [1]: https://prometheus.io/docs/instrumenting/exposition_formats
+
+use std::{collections::BTreeMap, fmt::Write as _, time::Duration};
+
+use super::{
+    aggregate::Aggregate,
+    summary::{Status, Summary},
+};
+
+/// Quantiles of the rendered durations.
+const QUANTILES: [f64; 3] = [0.5, 0.9, 0.99];
+
+/// Renders the provided [`Aggregate`] in [Prometheus text format][1].
+///
+/// [1]: https://prometheus.io/docs/instrumenting/exposition_formats
+pub(super) fn render(agg: &Aggregate) -> String {
+    let mut out = String::new();
+    family(
+        &mut out,
+        "cucumber_scenarios",
+        "scenarios",
+        "feature",
+        &agg.features,
+    );
+    family(&mut out, "cucumber_tag_scenarios", "scenarios", "tag", &agg.tags);
+    family(&mut out, "cucumber_steps", "steps", "feature", &agg.steps);
+    out
+}
+
+/// Renders the counters and durations of the provided [`Summary`]s of the
+/// finished `items` as the metrics family of the provided `name`, labeled with
+/// the `label`.
+fn family(
+    out: &mut String,
+    name: &str,
+    items: &str,
+    label: &str,
+    summaries: &BTreeMap<String, Summary>,
+) {
+    _ = writeln!(out, "# HELP {name}_total Finished {items} by {label}.");
+    _ = writeln!(out, "# TYPE {name}_total counter");
+    for (value, summary) in summaries {
+        let value = escape(value);
+        for status in Status::ALL {
+            _ = writeln!(
+                out,
+                "{name}_total{{{label}=\"{value}\",status=\"{}\"}} {}",
+                status.as_str(),
+                summary.count(status),
+            );
+        }
+    }
+
+    let name = format!("{name}_duration_seconds");
+    _ = writeln!(
+        out,
+        "# HELP {name} Durations of finished {items} by {label}."
+    );
+    _ = writeln!(out, "# TYPE {name} summary");
+    for (value, summary) in summaries {
+        let value = escape(value);
+        let mut durations = summary.durations().to_vec();
+        durations.sort_unstable();
+        for q in QUANTILES {
+            if let Some(d) = quantile(&durations, q) {
+                _ = writeln!(
+                    out,
+                    "{name}{{{label}=\"{value}\",quantile=\"{q}\"}} {}",
+                    d.as_secs_f64(),
+                );
+            }
+        }
+        let sum = durations.iter().sum::<Duration>().as_secs_f64();
+        _ = writeln!(out, "{name}_sum{{{label}=\"{value}\"}} {sum}");
+        _ = writeln!(
+            out,
+            "{name}_count{{{label}=\"{value}\"}} {}",
+            durations.len(),
+        );
+    }
+}
+
+/// Returns the `q` quantile of the `sorted` durations, using the nearest-rank
+/// method.
+fn quantile(sorted: &[Duration], q: f64) -> Option<Duration> {
+    #[expect( // intentional
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss,
+        reason = "`q` is in `0.0..=1.0` range, so the rank fits the `len`"
+    )]
+    let rank = (q * sorted.len() as f64).ceil() as usize;
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+/// Escapes the provided label `value`.
+fn escape(value: &str) -> String {
+    value.replace('\\', r"\\").replace('"', r#"\""#).replace('\n', r"\n")
+}
+
+#[cfg(test)]
+mod spec {
+    use std::time::Duration;
+
+    use super::{escape, quantile};
+
+    #[test]
+    fn computes_nearest_rank_quantiles() {
+        let sorted = (1..=10).map(Duration::from_secs).collect::<Vec<_>>();
+
+        assert_eq!(quantile(&sorted, 0.5), Some(Duration::from_secs(5)));
+        assert_eq!(quantile(&sorted, 0.9), Some(Duration::from_secs(9)));
+        assert_eq!(quantile(&sorted, 0.99), Some(Duration::from_secs(10)));
+        assert_eq!(quantile(&[], 0.5), None);
+    }
+
+    #[test]
+    fn escapes_label_values() {
+        assert_eq!(escape("a \"b\"\n\\c"), r#"a \"b\"\n\\c"#);
+    }
+}
@@ -0,0 +1,178 @@
+//! [`MetricsServer`] serving the aggregated metrics over HTTP.
+
+use std::{
+    io::{self, BufRead as _, BufReader, Read as _, Write as _},
+    net::{
+        Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream, ToSocketAddrs,
+    },
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use super::MetricsObserver;
+
+/// Timeout of reading a request from or writing a response into a single
+/// connection, so a stalled client cannot hold the serving thread.
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum length of a request line being read, so a misbehaving client
+/// cannot make the serving thread buffer endlessly.
+const MAX_REQUEST_LINE: u64 = 8 * 1024;
+
+/// HTTP server serving the metrics of a [`MetricsObserver`] on the
+/// `/metrics` path, in [Prometheus text format][1].
+///
+/// The server runs in a background thread until dropped.
+///
+/// [1]: https://prometheus.io/docs/instrumenting/exposition_formats
+#[derive(Debug)]
+pub struct MetricsServer {
+    /// [`SocketAddr`] this [`MetricsServer`] listens on.
+    addr: SocketAddr,
+
+    /// Indicator to stop serving.
+    stop: Arc<AtomicBool>,
+
+    /// Background thread serving the requests.
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MetricsServer {
+    /// Binds a new [`MetricsServer`] to the provided `addr`, serving the
+    /// metrics of the provided [`MetricsObserver`].
+    ///
+    /// # Errors
+    ///
+    /// If the `addr` cannot be bound.
+    pub(super) fn bind(
+        addr: impl ToSocketAddrs,
+        metrics: MetricsObserver,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let local = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let stopped = Arc::clone(&stop);
+        let thread = thread::Builder::new()
+            .name("cucumber-metrics".into())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::Acquire) {
+                        break;
+                    }
+                    // Failed connections don't affect the next ones.
+                    drop(stream.and_then(|s| respond(s, &metrics)));
+                }
+            })?;
+
+        Ok(Self { addr: local, stop, thread: Some(thread) })
+    }
+
+    /// Returns the [`SocketAddr`] this [`MetricsServer`] listens on.
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+
+        // Wakes up the blocked `accept()` to notice the `stop`.
+        let mut wake = self.addr;
+        if wake.ip().is_unspecified() {
+            wake.set_ip(if wake.is_ipv4() {
+                Ipv4Addr::LOCALHOST.into()
+            } else {
+                Ipv6Addr::LOCALHOST.into()
+            });
+        }
+        let woken = TcpStream::connect_timeout(&wake, IO_TIMEOUT).is_ok();
+
+        // The thread notices the `stop` only once woken up, so joining it
+        // otherwise would block forever, and it's detached instead.
+        if let Some(thread) = self.thread.take().filter(|_| woken) {
+            drop(thread.join());
+        }
+    }
+}
+
+/// Responds to the HTTP request read from the provided `stream`.
+fn respond(mut stream: TcpStream, metrics: &MetricsObserver) -> io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    let mut request = String::new();
+    _ = BufReader::new((&stream).take(MAX_REQUEST_LINE))
+        .read_line(&mut request)?;
+
+    let (status, body) = if !request.ends_with('\n') {
+        ("400 Bad Request", String::new())
+    } else if request.split_whitespace().nth(1) == Some("/metrics") {
+        ("200 OK", metrics.render())
+    } else {
+        ("404 Not Found", String::new())
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: text/plain; version=0.0.4\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\
+         \r\n\
+         {body}",
+        body.len(),
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod spec {
+    use std::{
+        io::{Read as _, Write as _},
+        net::{Shutdown, TcpStream},
+    };
+
+    use super::super::MetricsObserver;
+
+    fn get(addr: std::net::SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        _ = stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn serves_metrics() {
+        let server = MetricsObserver::new().serve("127.0.0.1:0").unwrap();
+
+        let response = get(server.local_addr(), "/metrics");
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+        assert!(
+            response.contains("# TYPE cucumber_scenarios_total counter"),
+            "{response}",
+        );
+
+        let response = get(server.local_addr(), "/other");
+        assert!(response.starts_with("HTTP/1.1 404"), "{response}");
+    }
+
+    #[test]
+    fn rejects_incomplete_request_line() {
+        let server = MetricsObserver::new().serve("127.0.0.1:0").unwrap();
+
+        let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+        write!(stream, "GET /metrics").unwrap();
+        stream.shutdown(Shutdown::Write).unwrap();
+        let mut response = String::new();
+        _ = stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 400"), "{response}");
+    }
+}
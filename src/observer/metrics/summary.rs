@@ -0,0 +1,70 @@
+//! [`Summary`] of the finished items sharing the same label.
+
+use std::time::Duration;
+
+/// Outcome of a finished [`gherkin::Scenario`] or [`gherkin::Step`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Status {
+    /// Passed without any failures or skips.
+    Passed,
+
+    /// Failed (or had a failed hook).
+    Failed,
+
+    /// Skipped (or had a skipped step).
+    Skipped,
+}
+
+impl Status {
+    /// All the [`Status`]es, in the order of their rendering.
+    pub(super) const ALL: [Self; 3] =
+        [Self::Passed, Self::Failed, Self::Skipped];
+
+    /// Returns the label value of this [`Status`].
+    pub(super) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+/// Counts and durations of the finished [`gherkin::Scenario`]s or
+/// [`gherkin::Step`]s sharing the same label.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Summary {
+    /// Counts of the finished items by their [`Status`], in the
+    /// [`Status::ALL`] order.
+    counts: [u64; 3],
+
+    /// Durations of the finished items.
+    durations: Vec<Duration>,
+}
+
+impl Summary {
+    /// Records a finished item of the provided [`Status`] and `duration`.
+    pub(super) fn record(
+        &mut self,
+        status: Status,
+        duration: Option<Duration>,
+    ) {
+        if let Some(i) = Status::ALL.iter().position(|s| *s == status) {
+            self.counts[i] += 1;
+        }
+        self.durations.extend(duration);
+    }
+
+    /// Returns the number of the finished items of the provided [`Status`].
+    pub(super) fn count(&self, status: Status) -> u64 {
+        Status::ALL
+            .iter()
+            .position(|s| *s == status)
+            .map_or(0, |i| self.counts[i])
+    }
+
+    /// Returns the durations of the finished items.
+    pub(super) fn durations(&self) -> &[Duration] {
+        &self.durations
+    }
+}
@@ -0,0 +1,63 @@
+//! Observer trait for external systems like ObservaBDD
+//!
+//! This provides a lightweight integration point for observability
+//! without adding runtime overhead when not in use.
+
+mod filter;
+pub mod metrics;
+mod registry;
+
+use crate::{Event, World, event};
+
+pub use self::{
+    filter::EventFilter,
+    registry::{ObserverId, ObserverRegistry},
+};
+
+/// Context provided to observers containing execution metadata
+#[derive(Clone, Debug)]
+pub struct ObservationContext {
+    /// Unique identifier for the scenario being observed
+    pub scenario_id: Option<u64>,
+    /// Name of the feature containing the scenario
+    pub feature_name: String,
+    /// Name of the rule containing the scenario (if any)
+    pub rule_name: Option<String>,
+    /// Name of the scenario being executed
+    pub scenario_name: String,
+    /// Information about retry attempts for this scenario execution
+    pub retry_info: Option<event::Retries>,
+    /// Tags associated with the scenario for filtering and categorization
+    pub tags: Vec<String>,
+    /// Timestamp when this observation context was created
+    pub timestamp: crate::time::Instant,
+}
+
+/// Observer trait for monitoring test execution
+pub trait TestObserver<W: World>: Send + Sync {
+    /// Called when an event occurs
+    fn on_event(
+        &mut self,
+        event: &Event<event::Cucumber<W>>,
+        context: &ObservationContext,
+    );
+
+    /// Called when execution starts
+    fn on_start(&mut self) {}
+
+    /// Called when execution completes
+    fn on_finish(&mut self) {}
+}
+
+/// No-op observer for when observation is disabled
+#[derive(Clone, Copy, Debug)]
+pub struct NullObserver;
+
+impl<W: World> TestObserver<W> for NullObserver {
+    fn on_event(
+        &mut self,
+        _: &Event<event::Cucumber<W>>,
+        _: &ObservationContext,
+    ) {
+    }
+}
@@ -0,0 +1,283 @@
+//! [`ObserverRegistry`] dispatching events to the registered observers.
+
+use std::{
+    any::Any,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+};
+
+use super::{EventFilter, ObservationContext, TestObserver};
+use crate::{Event, World, error::step::PanicPayloadExt as _, event};
+
+/// Identifier of an observer registered in an [`ObserverRegistry`], used to
+/// [unregister](ObserverRegistry::unregister) it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ObserverId(u64);
+
+/// Observer registered in an [`ObserverRegistry`].
+struct Entry<W> {
+    /// [`ObserverId`] of this observer.
+    id: ObserverId,
+
+    /// Priority of this observer. Higher ones are notified first.
+    priority: i32,
+
+    /// [`EventFilter`] of the events this observer is notified about.
+    filter: EventFilter,
+
+    /// The observer itself.
+    observer: Box<dyn TestObserver<W>>,
+}
+
+/// Registry for managing multiple observers
+///
+/// Provides efficient batch notification to all registered observers
+/// with minimal overhead when no observers are registered.
+///
+/// Observers may be registered and unregistered while the run progresses,
+/// via [`Basic::observer_registry()`]. They're notified in the order of their
+/// priorities (higher first), and then of their registration. A panicking
+/// observer is logged and removed, without affecting the run or the other
+/// observers.
+///
+/// [`Basic::observer_registry()`]: crate::runner::Basic::observer_registry
+pub struct ObserverRegistry<W> {
+    /// Registered observers, ordered by their priorities.
+    observers: Vec<Entry<W>>,
+
+    /// Indicates whether there are any observers to notify.
+    enabled: bool,
+
+    /// [`ObserverId`] of the next registered observer.
+    next_id: u64,
+}
+
+impl<W> ObserverRegistry<W> {
+    /// Creates a new empty observer registry
+    ///
+    /// The registry starts with no observers and is initially disabled
+    /// for optimal performance when observation is not needed.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { observers: Vec::new(), enabled: false, next_id: 0 }
+    }
+
+    /// Registers a new observer with the registry
+    ///
+    /// Once an observer is registered, the registry is automatically
+    /// enabled and will notify all observers of future events.
+    pub fn register(&mut self, observer: Box<dyn TestObserver<W>>) -> ObserverId
+    where
+        W: World,
+    {
+        self.register_with_priority(observer, 0)
+    }
+
+    /// Registers a new observer with the provided `priority`, so it's notified
+    /// before all the observers of lower priorities.
+    ///
+    /// Observers of equal priorities are notified in their registration order.
+    pub fn register_with_priority(
+        &mut self,
+        observer: Box<dyn TestObserver<W>>,
+        priority: i32,
+    ) -> ObserverId
+    where
+        W: World,
+    {
+        self.insert(observer, priority, EventFilter::all())
+    }
+
+    /// Registers a new observer notified only about the events passing the
+    /// provided [`EventFilter`].
+    pub fn register_filtered(
+        &mut self,
+        observer: Box<dyn TestObserver<W>>,
+        filter: EventFilter,
+    ) -> ObserverId
+    where
+        W: World,
+    {
+        self.insert(observer, 0, filter)
+    }
+
+    /// Inserts a new observer according to its `priority`.
+    fn insert(
+        &mut self,
+        observer: Box<dyn TestObserver<W>>,
+        priority: i32,
+        filter: EventFilter,
+    ) -> ObserverId {
+        let id = ObserverId(self.next_id);
+        self.next_id += 1;
+
+        let pos = self.observers.partition_point(|e| e.priority >= priority);
+        self.observers.insert(pos, Entry { id, priority, filter, observer });
+        self.enabled = true;
+        id
+    }
+
+    /// Unregisters the observer with the provided [`ObserverId`], returning
+    /// it, if it's still registered.
+    pub fn unregister(
+        &mut self,
+        id: ObserverId,
+    ) -> Option<Box<dyn TestObserver<W>>> {
+        let pos = self.observers.iter().position(|e| e.id == id)?;
+        let entry = self.observers.remove(pos);
+        self.enabled = !self.observers.is_empty();
+        Some(entry.observer)
+    }
+
+    /// Returns the number of registered observers.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Indicates whether no observers are registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    /// Notifies all registered observers about an event
+    ///
+    /// This method is optimized to skip all processing when no
+    /// observers are registered, providing zero-cost observation
+    /// when not in use.
+    ///
+    /// An observer panicking while being notified is logged and unregistered.
+    #[inline]
+    pub fn notify(
+        &mut self,
+        event: &Event<event::Cucumber<W>>,
+        context: &ObservationContext,
+    ) where
+        W: World,
+    {
+        if self.enabled {
+            self.observers.retain_mut(|entry| {
+                if !entry.filter.matches(&event.value, context) {
+                    return true;
+                }
+                let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                    entry.observer.on_event(event, context);
+                }));
+                res.map_err(|panic| {
+                    let panic: Arc<dyn Any + Send> = Arc::from(panic);
+                    let msg = panic.to_readable_string();
+                    #[expect(
+                        clippy::print_stderr,
+                        reason = "no other way to report"
+                    )]
+                    {
+                        eprintln!(
+                            "Warning: Observer #{} panicked and is removed: \
+                             {msg}",
+                            entry.id.0,
+                        );
+                    }
+                })
+                .is_ok()
+            });
+            self.enabled = !self.observers.is_empty();
+        }
+    }
+}
+
+impl<W: World> Default for ObserverRegistry<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> fmt::Debug for ObserverRegistry<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObserverRegistry")
+            .field("observer_count", &self.observers.len())
+            .field("enabled", &self.enabled)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use std::sync::{Arc, Mutex};
+
+    use super::{ObservationContext, ObserverRegistry, TestObserver};
+    use crate::{Event, event, test_utils::common::TestWorld};
+
+    /// Observer recording its `name` into a shared log, or panicking.
+    struct Named(&'static str, Arc<Mutex<Vec<&'static str>>>);
+
+    impl TestObserver<TestWorld> for Named {
+        fn on_event(
+            &mut self,
+            _: &Event<event::Cucumber<TestWorld>>,
+            _: &ObservationContext,
+        ) {
+            assert_ne!(self.0, "panicking", "observer panicked");
+            self.1.lock().unwrap().push(self.0);
+        }
+    }
+
+    fn notify(registry: &mut ObserverRegistry<TestWorld>) {
+        let ctx = ObservationContext {
+            scenario_id: None,
+            feature_name: String::new(),
+            rule_name: None,
+            scenario_name: String::new(),
+            retry_info: None,
+            tags: Vec::new(),
+            timestamp: std::time::Instant::now(),
+        };
+        registry.notify(&Event::new(event::Cucumber::Started), &ctx);
+    }
+
+    #[test]
+    fn notifies_by_priority_then_registration() {
+        let log = Arc::default();
+        let mut registry = ObserverRegistry::new();
+        _ = registry.register(Box::new(Named("a", Arc::clone(&log))));
+        _ = registry
+            .register_with_priority(Box::new(Named("b", Arc::clone(&log))), 5);
+        _ = registry.register(Box::new(Named("c", Arc::clone(&log))));
+        _ = registry
+            .register_with_priority(Box::new(Named("d", Arc::clone(&log))), -1);
+
+        notify(&mut registry);
+
+        assert_eq!(*log.lock().unwrap(), ["b", "a", "c", "d"]);
+    }
+
+    #[test]
+    fn unregisters_observers() {
+        let log = Arc::default();
+        let mut registry = ObserverRegistry::new();
+        let a = registry.register(Box::new(Named("a", Arc::clone(&log))));
+        _ = registry.register(Box::new(Named("b", Arc::clone(&log))));
+
+        assert!(registry.unregister(a).is_some(), "`a` is not registered");
+        assert!(registry.unregister(a).is_none(), "`a` is unregistered twice");
+        notify(&mut registry);
+
+        assert_eq!(*log.lock().unwrap(), ["b"]);
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn removes_panicking_observers() {
+        let log = Arc::default();
+        let mut registry = ObserverRegistry::new();
+        _ = registry.register(Box::new(Named("panicking", Arc::clone(&log))));
+        _ = registry.register(Box::new(Named("a", Arc::clone(&log))));
+
+        notify(&mut registry);
+        notify(&mut registry);
+
+        assert_eq!(*log.lock().unwrap(), ["a", "a"]);
+        assert_eq!(registry.len(), 1);
+    }
+}
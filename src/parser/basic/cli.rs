@@ -0,0 +1,83 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CLI options of a [`Basic`] [`Parser`].
+//!
+//! [`Basic`]: super::Basic
+//! [`Parser`]: crate::Parser
+
+use std::{borrow::Cow, path::PathBuf};
+
+use super::{Order, TemplateVar, UnsupportedLanguageError, Walker};
+
+/// CLI options of a [`Basic`] [`Parser`].
+///
+/// [`Basic`]: super::Basic
+/// [`Parser`]: crate::Parser
+#[derive(Clone, Debug, Default, clap::Args)]
+#[group(skip)]
+pub struct Cli {
+    /// Glob pattern to look for feature files with. If not specified, looks for
+    /// `*.feature` files in the path configured in the test runner.
+    #[arg(
+        id = "input",
+        long = "input",
+        short = 'i',
+        value_name = "glob",
+        global = true
+    )]
+    pub features: Option<Walker>,
+
+    /// Paths to feature files (or directories) to run, overriding the ones
+    /// configured in the test runner. A path may be followed by `:line`
+    /// numbers to run only the scenarios at these lines, like
+    /// `features/checkout.feature:42:77`.
+    #[arg(value_name = "path[:line]...", conflicts_with = "input")]
+    pub locations: Vec<PathBuf>,
+
+    /// Language of feature files keywords. If not specified, uses the value
+    /// configured in the test runner, or English by default. A
+    /// `# language:` header in a feature file always takes precedence.
+    #[arg(
+        long,
+        value_name = "lang",
+        value_parser = parse_language,
+        global = true
+    )]
+    pub language: Option<Cow<'static, str>>,
+
+    /// Template variable to replace `{{KEY}}` placeholders in feature files
+    /// with. Overrides the value configured in the test runner.
+    #[arg(long = "template-var", value_name = "KEY=VALUE", global = true)]
+    pub template_vars: Vec<TemplateVar>,
+
+    /// Order to execute feature files in: `discovered` (order of inputs, the
+    /// default one), `path` (sorted by paths), `mtime` (most recently modified
+    /// first), or `list:<file>` (as listed in the file). Overrides the order
+    /// configured in the test runner.
+    #[arg(
+        long,
+        value_name = "discovered|path|mtime|list:<file>",
+        global = true
+    )]
+    pub order: Option<Order>,
+}
+
+/// Parses a language of [`gherkin`] keywords, checking it's supported.
+fn parse_language(
+    name: &str,
+) -> Result<Cow<'static, str>, UnsupportedLanguageError> {
+    let name = Cow::Owned(name.to_owned());
+    if gherkin::is_language_supported(&name) {
+        Ok(name)
+    } else {
+        Err(UnsupportedLanguageError(name))
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Default [`Parser`] implementation.
+
+mod cli;
+
+#[cfg(feature = "remote")]
+use std::io;
+use std::{borrow::Cow, collections::BTreeSet, iter, path::Path};
+
+use derive_more::with_trait::{Display, Error};
+use futures::stream;
+use gherkin::GherkinEnv;
+
+pub use self::cli::Cli;
+#[cfg(feature = "remote")]
+use super::Remote;
+use super::{
+    Error as ParseError, Parser,
+    embedded::Embedded,
+    external_examples,
+    inputs::{self, Inputs},
+    location::{self, Selection},
+    template,
+};
+pub use super::{
+    inputs::Walker,
+    order::{Comparator, Order},
+    template::{InvalidTemplateVarError, TemplateVar},
+};
+use crate::feature::Ext as _;
+
+/// [`Stream`] of [`gherkin::Feature`]s parsed by a [`Basic`] [`Parser`].
+///
+/// Files are discovered eagerly, while being parsed lazily one by one, so the
+/// already parsed [`gherkin::Feature`]s may be executed while the remaining
+/// ones are still being parsed.
+///
+/// [`Stream`]: futures::Stream
+pub type Features = stream::Iter<
+    Box<dyn Iterator<Item = Result<gherkin::Feature, ParseError>> + Send>,
+>;
+
+/// Default [`Parser`].
+///
+/// As there is no async runtime-agnostic way to interact with IO, this
+/// [`Parser`] is blocking. However, it parses `.feature` files lazily, once
+/// the [`Features`] are polled.
+#[derive(Clone, Debug, Default)]
+pub struct Basic {
+    /// Optional custom language of [`gherkin`] keywords.
+    ///
+    /// Default is English.
+    language: Option<Cow<'static, str>>,
+
+    /// Variables to replace `{{placeholders}}` in `.feature` files with.
+    ///
+    /// No templating is done if empty.
+    template_vars: template::Vars,
+
+    /// [`Order`] to execute discovered `.feature` files in.
+    order: Order,
+}
+
+impl<I: AsRef<Path>> Parser<I> for Basic {
+    type Cli = Cli;
+
+    type Output = Features;
+
+    fn parse(self, input: I, cli: Self::Cli) -> Self::Output {
+        self.parse_inputs(iter::once(input), cli)
+    }
+}
+
+impl Parser<Inputs> for Basic {
+    type Cli = Cli;
+
+    type Output = Features;
+
+    fn parse(self, input: Inputs, cli: Self::Cli) -> Self::Output {
+        self.parse_inputs(input, cli)
+    }
+}
+
+impl Parser<Embedded> for Basic {
+    type Cli = Cli;
+
+    type Output = Features;
+
+    fn parse(self, input: Embedded, cli: Self::Cli) -> Self::Output {
+        if cli.features.is_some() {
+            return self.parse_inputs(iter::empty::<&Path>(), cli);
+        }
+
+        let language = cli.language.or(self.language);
+        let env = move || Self::env(language.as_deref());
+        let vars = Self::vars(self.template_vars, cli.template_vars);
+
+        lazy(input.into_iter().map(move |(name, source)| {
+            let feature = template::parse_str(name, &source, env(), &vars)?;
+            Self::expand(feature, None)
+        }))
+    }
+}
+
+#[cfg(feature = "remote")]
+impl Parser<Remote> for Basic {
+    type Cli = Cli;
+
+    type Output = Features;
+
+    fn parse(self, input: Remote, cli: Self::Cli) -> Self::Output {
+        let fetching_err = |cached, e: io::Error| ParseError::Fetching {
+            location: input.location(),
+            cached,
+            source: e.into(),
+        };
+        match input.fetch() {
+            Ok((path, stale)) => {
+                let stale = stale.map(|e| Err(fetching_err(true, e)));
+                let features =
+                    self.parse_inputs(iter::once(path), cli).into_inner();
+                lazy(stale.into_iter().chain(features))
+            }
+            Err(e) => lazy(iter::once(Err(fetching_err(false, e)))),
+        }
+    }
+}
+
+impl Basic {
+    /// Parses `.feature` files discovered from the provided `inputs`, or from
+    /// the [`Cli`] glob pattern, if specified.
+    fn parse_inputs(
+        self,
+        inputs: impl IntoIterator<Item = impl AsRef<Path>>,
+        cli: Cli,
+    ) -> Features {
+        let language = cli.language.or(self.language);
+        let env = move || Self::env(language.as_deref());
+        let vars = Self::vars(self.template_vars, cli.template_vars);
+
+        let order = cli.order.unwrap_or(self.order);
+        let (files, selected) = if let Some(walker) = cli.features {
+            (inputs::glob(&walker.0), location::Selected::new())
+        } else {
+            let (paths, selected) = if cli.locations.is_empty() {
+                location::split_all(inputs)
+            } else {
+                location::split_all(cli.locations)
+            };
+            (inputs::discover_all(paths), selected)
+        };
+
+        lazy(order.apply(files).into_iter().map(move |file| {
+            let file = file?;
+            let lines = selected.get(&file);
+            Self::expand(template::parse_path(file, env(), &vars)?, lines)
+        }))
+    }
+
+    /// Expands [`Examples`] of the provided parsed [`gherkin::Feature`],
+    /// loading the ones referencing external files, and retains only the
+    /// [`gherkin::Scenario`]s at the provided `lines`, if any.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    fn expand(
+        feature: gherkin::Feature,
+        lines: Option<&BTreeSet<usize>>,
+    ) -> Result<gherkin::Feature, ParseError> {
+        let feature = external_examples::load(feature)?;
+        let selection = lines.map(|l| Selection::new(&feature, l));
+        let feature = feature.expand_examples()?;
+        Ok(match selection {
+            Some(s) => s.retain(feature),
+            None => feature,
+        })
+    }
+
+    /// Merges the configured template `vars` with the [`Cli`] ones,
+    /// overriding the former.
+    fn vars(mut vars: template::Vars, cli: Vec<TemplateVar>) -> template::Vars {
+        vars.extend(cli.into_iter().map(|v| (v.key, v.value)));
+        vars
+    }
+
+    /// Creates a new [`GherkinEnv`] for the provided `language`, or the
+    /// default one.
+    fn env(language: Option<&str>) -> GherkinEnv {
+        language.and_then(|l| GherkinEnv::new(l).ok()).unwrap_or_default()
+    }
+
+    /// Creates a new [`Basic`] [`Parser`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            language: None,
+            template_vars: template::Vars::new(),
+            order: Order::Discovered,
+        }
+    }
+
+    /// Adds a template variable to replace `{{key}}` placeholders in
+    /// `.feature` files with, before parsing them.
+    ///
+    /// Allows targeting different data sets (staging or production, for
+    /// example) with the same `.feature` files. Once any variable is set, an
+    /// unknown placeholder fails parsing of its `.feature` file.
+    #[must_use]
+    pub fn template_var(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        drop(self.template_vars.insert(key.into(), value.into()));
+        self
+    }
+
+    /// Sets the [`Order`] to execute discovered `.feature` files in, instead
+    /// of the [`Order::Discovered`] one.
+    #[must_use]
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the provided language to parse [`gherkin`] files with instead of
+    /// the default one (English).
+    ///
+    /// A `# language:` header in a `.feature` file still overrides it for
+    /// that file.
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, UnsupportedLanguageError> {
+        let name = name.into();
+        if !gherkin::is_language_supported(&name) {
+            return Err(UnsupportedLanguageError(name));
+        }
+        self.language = Some(name);
+        Ok(self)
+    }
+}
+
+/// Wraps the provided lazy `features` [`Iterator`] into [`Features`].
+fn lazy(
+    features: impl Iterator<Item = Result<gherkin::Feature, ParseError>>
+    + Send
+    + 'static,
+) -> Features {
+    let features: Box<dyn Iterator<Item = _> + Send> = Box::new(features);
+    stream::iter(features)
+}
+
+/// Error of [`gherkin`] not supporting keywords in some language.
+#[derive(Clone, Debug, Display, Error)]
+#[display("Language {_0} isn't supported")]
+pub struct UnsupportedLanguageError(
+    #[error(not(source))] pub Cow<'static, str>,
+);
@@ -0,0 +1,94 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Chaining [`Feature`]s of multiple [`Parser`]s.
+//!
+//! [`Feature`]: gherkin::Feature
+
+use futures::{StreamExt as _, stream};
+
+use super::Parser;
+use crate::cli;
+
+/// Wrapper for chaining [`Feature`]s of two [`Parser`]s, emitting all the
+/// [`Feature`]s of the `left` one first, and then of the `right` one.
+///
+/// Accepts a tuple of both [`Parser`]s inputs.
+///
+/// # CLI options
+///
+/// CLI options of both [`Parser`]s are [`cli::Compose`]d, so chaining two
+/// [`Parser`]s with overlapping ones (like two [`parser::Basic`]s) requires
+/// discarding CLI options of one of them via [`ParserExt::discard_cli()`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`parser::Basic`]: super::Basic
+/// [`ParserExt::discard_cli()`]: super::Ext::discard_cli
+#[derive(Clone, Copy, Debug)]
+pub struct Chain<L, R> {
+    /// Left [`Parser`].
+    left: L,
+
+    /// Right [`Parser`].
+    right: R,
+}
+
+impl<L, R> Chain<L, R> {
+    /// Creates a new [`Chain`] [`Parser`], emitting [`Feature`]s of the
+    /// `left` [`Parser`] first, and then of the `right` one.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub const fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<L, R, IL, IR> Parser<(IL, IR)> for Chain<L, R>
+where
+    L: Parser<IL>,
+    R: Parser<IR>,
+{
+    type Cli = cli::Compose<L::Cli, R::Cli>;
+
+    type Output = stream::Chain<L::Output, R::Output>;
+
+    fn parse(self, (left, right): (IL, IR), cli: Self::Cli) -> Self::Output {
+        let (left_cli, right_cli) = cli.into_inner();
+        self.left
+            .parse(left, left_cli)
+            .chain(self.right.parse(right, right_cli))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Basic, Embedded, Ext as _};
+
+    fn source(name: &str) -> Embedded {
+        Embedded::new().with(
+            format!("{name}.feature"),
+            format!("Feature: {name}\n  Scenario: S\n    Given a step\n"),
+        )
+    }
+
+    #[tokio::test]
+    async fn emits_left_features_first() {
+        let features = Basic::new()
+            .chain(Basic::new().discard_cli())
+            .parse((source("left"), source("right")), cli::Compose::default())
+            .map(|f| f.unwrap().name)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(features, ["left", "right"]);
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Wrappers providing no-op implementations.
+
+use derive_more::with_trait::{Deref, DerefMut};
+
+use super::Parser;
+use crate::cli;
+
+/// Wrapper discarding CLI options of a [`Parser`], so it's always provided
+/// with the default ones.
+///
+/// Intended to be used for feeding a [`Parser`] into a [`parser::Chain`]
+/// with another one having overlapping CLI options.
+///
+/// [`parser::Chain`]: super::Chain
+#[derive(Clone, Copy, Debug, Deref, DerefMut)]
+pub struct Cli<P: ?Sized>(P);
+
+impl<P> Cli<P> {
+    /// Wraps the given [`Parser`] into a [`discard::Cli`] one.
+    ///
+    /// [`discard::Cli`]: Cli
+    #[must_use]
+    pub const fn wrap(parser: P) -> Self {
+        Self(parser)
+    }
+}
+
+impl<I, P> Parser<I> for Cli<P>
+where
+    P: Parser<I>,
+    P::Cli: Default,
+{
+    type Cli = cli::Empty;
+
+    type Output = P::Output;
+
+    fn parse(self, input: I, _: Self::Cli) -> Self::Output {
+        self.0.parse(input, P::Cli::default())
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! In-memory [Gherkin] sources for a [`Basic`] [`Parser`].
+//!
+//! [`Basic`]: super::Basic
+//! [`Parser`]: super::Parser
+//! [Gherkin]: https://cucumber.io/docs/gherkin/reference
+
+use std::{borrow::Cow, path::PathBuf, vec};
+
+use gherkin::GherkinEnv;
+
+/// In-memory [Gherkin] sources of a [`Basic`] [`Parser`], each having a
+/// virtual file name.
+///
+/// Allows shipping `.feature` files along with a library crate (via
+/// [`include_str!`], for example) or running generated ones, without
+/// requiring them to be present on disk at test time.
+///
+/// Virtual file names are used as [`gherkin::Feature::path`]s, so are shown
+/// in the output and errors the same way as the real ones.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{World, parser};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .run(parser::Embedded::from([(
+///         "readme/eating.feature",
+///         include_str!("../../tests/features/readme/eating.feature"),
+///     )]))
+///     .await;
+/// # }
+/// ```
+///
+/// [`Basic`]: super::Basic
+/// [`Parser`]: super::Parser
+/// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Embedded(Vec<(PathBuf, Cow<'static, str>)>);
+
+impl Embedded {
+    /// Creates new empty [`Embedded`] sources.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds the provided [Gherkin] `source` with the provided virtual file
+    /// `name` to these [`Embedded`] sources.
+    ///
+    /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+    #[must_use]
+    pub fn with(
+        mut self,
+        name: impl Into<PathBuf>,
+        source: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.0.push((name.into(), source.into()));
+        self
+    }
+
+    /// Returns an [`Iterator`] over virtual file names of these [`Embedded`]
+    /// sources.
+    pub fn names(&self) -> impl Iterator<Item = &PathBuf> {
+        self.0.iter().map(|(name, _)| name)
+    }
+}
+
+impl IntoIterator for Embedded {
+    type Item = (PathBuf, Cow<'static, str>);
+    type IntoIter = vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<N, S> FromIterator<(N, S)> for Embedded
+where
+    N: Into<PathBuf>,
+    S: Into<Cow<'static, str>>,
+{
+    fn from_iter<T: IntoIterator<Item = (N, S)>>(iter: T) -> Self {
+        Self(iter.into_iter().map(|(n, s)| (n.into(), s.into())).collect())
+    }
+}
+
+impl<N, S, const L: usize> From<[(N, S); L]> for Embedded
+where
+    N: Into<PathBuf>,
+    S: Into<Cow<'static, str>>,
+{
+    fn from(sources: [(N, S); L]) -> Self {
+        sources.into_iter().collect()
+    }
+}
+
+impl<N, S> From<Vec<(N, S)>> for Embedded
+where
+    N: Into<PathBuf>,
+    S: Into<Cow<'static, str>>,
+{
+    fn from(sources: Vec<(N, S)>) -> Self {
+        sources.into_iter().collect()
+    }
+}
+
+/// Parses the provided in-memory [Gherkin] `source`, using its virtual file
+/// `name` as the [`gherkin::Feature::path`].
+///
+/// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+pub(super) fn parse(
+    name: PathBuf,
+    source: &str,
+    env: GherkinEnv,
+) -> Result<gherkin::Feature, gherkin::ParseFileError> {
+    match gherkin::Feature::parse(source, env) {
+        Ok(mut feature) => {
+            feature.path = Some(name);
+            Ok(feature)
+        }
+        Err(e) => Err(gherkin::ParseFileError::Parsing {
+            path: name,
+            error: None,
+            source: e,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn uses_virtual_name_as_path() {
+        let feature = parse(
+            "virtual/a.feature".into(),
+            "Feature: A\n  Scenario: S\n    Given a step",
+            GherkinEnv::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            feature.path.as_deref(),
+            Some(Path::new("virtual/a.feature"))
+        );
+        assert_eq!(feature.scenarios.len(), 1);
+    }
+
+    #[test]
+    fn reports_virtual_name_on_error() {
+        let err = parse(
+            "virtual/broken.feature".into(),
+            "Not a feature",
+            GherkinEnv::default(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            gherkin::ParseFileError::Parsing { path, .. }
+                if path == Path::new("virtual/broken.feature"),
+        ));
+    }
+}
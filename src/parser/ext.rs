@@ -0,0 +1,78 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Extension of a [`Parser`] allowing its composition.
+
+use sealed::sealed;
+
+use super::{Chain, discard};
+
+/// Extension of a [`Parser`] allowing its composition with other ones.
+///
+/// [`Parser`]: super::Parser
+#[sealed]
+pub trait Ext: Sized {
+    /// Chains this [`Parser`] with the `other` one, emitting all the
+    /// [`Feature`]s of this [`Parser`] first, and then of the `other` one.
+    ///
+    /// Allows combining [`Feature`]s from multiple sources (like files on
+    /// disk and generated ones).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{ParserExt as _, World, parser};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let generated = parser::Embedded::new().with(
+    ///     "generated.feature",
+    ///     "Feature: Generated\n  Scenario: empty\n",
+    /// );
+    ///
+    /// let parser = parser::Basic::new().chain(parser::Basic::new().discard_cli());
+    ///
+    /// MyWorld::cucumber::<&str>()
+    ///     .with_parser(parser)
+    ///     .run(("tests/features/readme", generated))
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Parser`]: super::Parser
+    #[must_use]
+    fn chain<P>(self, other: P) -> Chain<Self, P>;
+
+    /// Wraps this [`Parser`] into a [`discard::Cli`] one, always providing
+    /// it with the default CLI options.
+    ///
+    /// Intended to be used for feeding a [`Parser`] into a [`chain()`] with
+    /// another one having overlapping CLI options.
+    ///
+    /// [`chain()`]: Ext::chain
+    /// [`Parser`]: super::Parser
+    #[must_use]
+    fn discard_cli(self) -> discard::Cli<Self>;
+}
+
+#[sealed]
+impl<T> Ext for T {
+    fn chain<P>(self, other: P) -> Chain<Self, P> {
+        Chain::new(self, other)
+    }
+
+    fn discard_cli(self) -> discard::Cli<Self> {
+        discard::Cli::wrap(self)
+    }
+}
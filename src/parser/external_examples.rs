@@ -0,0 +1,282 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Examples`] tables loaded from external CSV or JSON files.
+//!
+//! An [`Examples`] block references a file either via its `@file(path)` tag,
+//! or via its name:
+//! ```gherkin
+//! Scenario Outline: signing in
+//!   Given <user> signs in with <password>
+//!
+//!   Examples: @file(data/users.csv)
+//! ```
+//!
+//! Relative paths are resolved against the directory of the `.feature` file.
+//! The first CSV row (or the keys of JSON objects) forms the table header.
+//!
+//! [`Examples`]: gherkin::Examples
+
+use std::{
+    fs, io, mem,
+    path::{Path, PathBuf},
+};
+
+/// Loads the [`Examples`] tables of the provided [`gherkin::Feature`]
+/// referencing external files.
+///
+/// # Errors
+///
+/// If a referenced file cannot be read, or its contents are malformed.
+///
+/// [`Examples`]: gherkin::Examples
+pub(super) fn load(
+    mut feature: gherkin::Feature,
+) -> Result<gherkin::Feature, gherkin::ParseFileError> {
+    let base = feature
+        .path
+        .as_deref()
+        .and_then(Path::parent)
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    for examples in feature
+        .scenarios
+        .iter_mut()
+        .chain(feature.rules.iter_mut().flat_map(|r| &mut r.scenarios))
+        .flat_map(|s| &mut s.examples)
+    {
+        let Some(file) = reference(examples).map(|f| base.join(f)) else {
+            continue;
+        };
+        let error = |kind, msg: String| gherkin::ParseFileError::Reading {
+            path: file.clone(),
+            source: io::Error::new(kind, msg),
+        };
+
+        if examples.table.is_some() {
+            return Err(error(
+                io::ErrorKind::InvalidInput,
+                "`Examples` can't have both an inline table and a file".into(),
+            ));
+        }
+        let contents = fs::read_to_string(&file).map_err(|e| {
+            gherkin::ParseFileError::Reading { path: file.clone(), source: e }
+        })?;
+        let rows = parse(&file, &contents)
+            .and_then(check_widths)
+            .map_err(|e| error(io::ErrorKind::InvalidData, e))?;
+
+        examples.table = Some(gherkin::Table {
+            rows,
+            span: examples.span,
+            position: gherkin::LineCol {
+                line: examples.position.line + 1,
+                col: examples.position.col,
+            },
+        });
+    }
+
+    Ok(feature)
+}
+
+/// Returns the path of the file referenced by the provided [`Examples`], if
+/// any.
+///
+/// [`Examples`]: gherkin::Examples
+fn reference(examples: &gherkin::Examples) -> Option<PathBuf> {
+    examples
+        .tags
+        .iter()
+        .map(String::as_str)
+        .chain(examples.name.as_deref().and_then(|n| n.strip_prefix('@')))
+        .find_map(|t| t.strip_prefix("file(")?.strip_suffix(')'))
+        .map(PathBuf::from)
+}
+
+/// Parses the provided `contents` of the `file` into table rows, according
+/// to its extension.
+fn parse(file: &Path, contents: &str) -> Result<Vec<Vec<String>>, String> {
+    let ext = file.extension().and_then(|e| e.to_str()).unwrap_or_default();
+    match ext.to_ascii_lowercase().as_str() {
+        "csv" => parse_csv(contents),
+        "json" => parse_json(contents),
+        _ => Err(format!("unsupported `Examples` file format: `{ext}`")),
+    }
+}
+
+/// Checks all the `rows` having the same width as the header one.
+fn check_widths(rows: Vec<Vec<String>>) -> Result<Vec<Vec<String>>, String> {
+    let width = rows.first().map(Vec::len).ok_or("no header row")?;
+    if let Some((i, row)) =
+        rows.iter().enumerate().find(|(_, r)| r.len() != width)
+    {
+        return Err(format!(
+            "row {} has {} columns, while the header has {width}",
+            i + 1,
+            row.len(),
+        ));
+    }
+    Ok(rows)
+}
+
+/// Parses CSV `contents` into rows, skipping blank lines.
+fn parse_csv(contents: &str) -> Result<Vec<Vec<String>>, String> {
+    let (mut rows, mut row, mut field) = (vec![], vec![], String::new());
+    let (mut quoted, mut line) = (false, 1);
+
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+            (true, '"') => quoted = false,
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => row.push(mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                row.push(mem::take(&mut field));
+                rows.push(mem::take(&mut row));
+                line += 1;
+            }
+            (_, c) => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if quoted {
+        return Err(format!("unterminated quoted field at line {line}"));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows.retain(|r| !matches!(r.as_slice(), [f] if f.trim().is_empty()));
+    Ok(rows)
+}
+
+/// Parses JSON `contents` being an array of either objects (their keys form
+/// the header), or arrays (the first one is the header).
+#[cfg(feature = "examples-json")]
+fn parse_json(contents: &str) -> Result<Vec<Vec<String>>, String> {
+    use std::iter;
+
+    use serde_json::Value;
+
+    let cell = |v: &Value| match v {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        v @ (Value::Bool(_)
+        | Value::Number(_)
+        | Value::Array(_)
+        | Value::Object(_)) => v.to_string(),
+    };
+
+    let value = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+    let Value::Array(items) = value else {
+        return Err("expected an array of objects or arrays".into());
+    };
+
+    if !items.iter().all(Value::is_object) {
+        return items
+            .iter()
+            .map(|item| {
+                item.as_array()
+                    .map(|cells| cells.iter().map(cell).collect())
+                    .ok_or_else(|| "expected an array of arrays".to_owned())
+            })
+            .collect();
+    }
+
+    let mut header = Vec::<String>::new();
+    for key in items.iter().filter_map(Value::as_object).flat_map(|o| o.keys())
+    {
+        if !header.contains(key) {
+            header.push(key.clone());
+        }
+    }
+    let rows = items.iter().filter_map(Value::as_object).map(|o| {
+        header.iter().map(|k| o.get(k).map(cell).unwrap_or_default()).collect()
+    });
+    Ok(iter::once(header.clone()).chain(rows).collect())
+}
+
+/// Fails, as JSON `Examples` files require the `examples-json` feature.
+#[cfg(not(feature = "examples-json"))]
+fn parse_json(_: &str) -> Result<Vec<Vec<String>>, String> {
+    Err("JSON `Examples` files require the `examples-json` feature".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_csv() {
+        let rows = parse_csv(
+            "user,password\r\nalice,\"p,\"\"w\"\"d\"\n\nbob,\"multi\nline\"",
+        )
+        .unwrap();
+
+        assert_eq!(
+            rows,
+            [
+                ["user", "password"],
+                ["alice", "p,\"w\"d"],
+                ["bob", "multi\nline"],
+            ],
+        );
+        assert!(parse_csv("a,\"b\n").is_err());
+    }
+
+    #[test]
+    fn checks_row_widths() {
+        let rows = vec![vec!["a".into(), "b".into()], vec!["1".into()]];
+
+        assert_eq!(
+            check_widths(rows).unwrap_err(),
+            "row 2 has 1 columns, while the header has 2",
+        );
+    }
+
+    #[test]
+    fn loads_referenced_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("users.csv"), "user\nalice\nbob\n").unwrap();
+        let mut feature = gherkin::Feature::parse(
+            "Feature: F\n\
+             \x20 Scenario Outline: S\n    Given <user>\n\
+             \x20   Examples: @file(users.csv)\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap();
+        feature.path = Some(dir.path().join("f.feature"));
+
+        let feature = load(feature).unwrap();
+
+        let table = feature.scenarios[0].examples[0].table.as_ref().unwrap();
+        assert_eq!(table.rows, [["user"], ["alice"], ["bob"]]);
+    }
+
+    #[cfg(feature = "examples-json")]
+    #[test]
+    fn parses_json() {
+        let objects =
+            parse_json(r#"[{"user": "alice", "age": 30}, {"user": "bob"}]"#)
+                .unwrap();
+        let arrays = parse_json(r#"[["user"], ["alice"]]"#).unwrap();
+
+        assert_eq!(objects, [["age", "user"], ["30", "alice"], ["", "bob"]]);
+        assert_eq!(arrays, [["user"], ["alice"]]);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support of [`IGNORE_FILE`]s during `.feature` files discovery.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use ignore::{Match, gitignore::Gitignore};
+
+/// Name of a file listing (in a gitignore syntax) paths to be skipped during
+/// `.feature` files discovery.
+///
+/// Applies to the directory it's placed in and all its subdirectories, with
+/// the deeper files taking precedence.
+pub(super) const IGNORE_FILE: &str = ".cucumberignore";
+
+/// [`IGNORE_FILE`]s found under a discovery root directory.
+#[derive(Debug)]
+pub(super) struct IgnoreFiles {
+    /// Root directory of the discovery.
+    root: PathBuf,
+
+    /// Lazily loaded [`IGNORE_FILE`] matchers by their directories.
+    matchers: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl IgnoreFiles {
+    /// Creates new [`IgnoreFiles`] for the provided `root` directory.
+    pub(super) fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into(), matchers: HashMap::new() }
+    }
+
+    /// Retains only the `files` not ignored by any [`IGNORE_FILE`].
+    pub(super) fn retain(mut self, files: Vec<PathBuf>) -> Vec<PathBuf> {
+        files.into_iter().filter(|f| !self.is_ignored(f)).collect()
+    }
+
+    /// Indicates whether the provided `file` is ignored by an [`IGNORE_FILE`]
+    /// in any of its directories up to the root one.
+    fn is_ignored(&mut self, file: &Path) -> bool {
+        let Ok(relative) = file.strip_prefix(&self.root) else {
+            return false;
+        };
+
+        // From the deepest directory up to the root one.
+        for dir in file.ancestors().skip(1).take(relative.components().count())
+        {
+            let matcher =
+                self.matchers.entry(dir.to_path_buf()).or_insert_with(|| {
+                    let path = dir.join(IGNORE_FILE);
+                    path.is_file().then(|| Gitignore::new(path).0)
+                });
+            match matcher
+                .as_ref()
+                .map(|m| m.matched_path_or_any_parents(file, false))
+            {
+                Some(Match::Ignore(_)) => return true,
+                Some(Match::Whitelist(_)) => return false,
+                Some(Match::None) | None => {}
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn skips_ignored_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("wip")).unwrap();
+        fs::create_dir_all(root.join("nested")).unwrap();
+        fs::write(root.join(IGNORE_FILE), "wip/\n*.template.feature\n")
+            .unwrap();
+        fs::write(
+            root.join("nested").join(IGNORE_FILE),
+            "!keep.template.feature\n",
+        )
+        .unwrap();
+
+        let files = [
+            "a.feature",
+            "a.template.feature",
+            "wip/b.feature",
+            "nested/c.feature",
+            "nested/keep.template.feature",
+        ]
+        .map(|f| root.join(f));
+
+        let retained = IgnoreFiles::new(root).retain(files.to_vec());
+
+        assert_eq!(
+            retained,
+            [files[0].clone(), files[3].clone(), files[4].clone()],
+        );
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Discovery of `.feature` files for a [`Basic`] [`Parser`].
+//!
+//! [`Basic`]: super::Basic
+//! [`Parser`]: super::Parser
+
+use std::{
+    collections::HashSet,
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    vec,
+};
+
+use globwalk::{GlobWalker, GlobWalkerBuilder};
+use itertools::Itertools as _;
+
+use super::ignore_file::IgnoreFiles;
+
+/// Multiple inputs of a [`Basic`] [`Parser`].
+///
+/// Each input is either a path to a `.feature` file, a path to a directory to
+/// look for `*.feature` files in, or a glob pattern (like
+/// `features/**/smoke/*.feature`).
+///
+/// Files are discovered in the order of inputs, and sorted by their paths
+/// within a single input. Files matched by several inputs are parsed only
+/// once.
+///
+/// Files listed (in a gitignore syntax) in a `.cucumberignore` file placed in
+/// a directory being walked (or any of its subdirectories) are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{World, parser};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .run(parser::Inputs::from([
+///         "tests/features/readme",
+///         "tests/features/book/**/*.feature",
+///     ]))
+///     .await;
+/// # }
+/// ```
+///
+/// [`Basic`]: super::Basic
+/// [`Parser`]: super::Parser
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Inputs(Vec<PathBuf>);
+
+impl Inputs {
+    /// Creates new empty [`Inputs`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds the provided `input` to these [`Inputs`].
+    #[must_use]
+    pub fn with(mut self, input: impl Into<PathBuf>) -> Self {
+        self.0.push(input.into());
+        self
+    }
+
+    /// Returns an [`Iterator`] over paths and glob patterns of these
+    /// [`Inputs`].
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.0.iter().map(PathBuf::as_path)
+    }
+}
+
+impl IntoIterator for Inputs {
+    type Item = PathBuf;
+    type IntoIter = vec::IntoIter<PathBuf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<P: Into<PathBuf>> FromIterator<P> for Inputs {
+    fn from_iter<T: IntoIterator<Item = P>>(iter: T) -> Self {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<P: Into<PathBuf>, const N: usize> From<[P; N]> for Inputs {
+    fn from(inputs: [P; N]) -> Self {
+        inputs.into_iter().collect()
+    }
+}
+
+impl<P: Into<PathBuf>> From<Vec<P>> for Inputs {
+    fn from(inputs: Vec<P>) -> Self {
+        inputs.into_iter().collect()
+    }
+}
+
+/// Wrapper over [`GlobWalker`] implementing a [`FromStr`].
+///
+/// [`GlobWalker`]: globwalk::GlobWalker
+#[derive(Clone, Debug)]
+pub struct Walker(pub(super) String);
+
+impl FromStr for Walker {
+    type Err = globwalk::GlobError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        globwalk::glob(s).map(|_| Self(s.to_owned()))
+    }
+}
+
+/// Result of a `.feature` file discovery.
+pub(super) type Discovered = Result<PathBuf, gherkin::ParseFileError>;
+
+/// Discovers `.feature` files of all the provided `inputs`, omitting
+/// duplicates.
+pub(super) fn discover_all(
+    inputs: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> Vec<Discovered> {
+    let mut seen = HashSet::new();
+    inputs
+        .into_iter()
+        .flat_map(|i| discover(i.as_ref()))
+        .filter(|f| f.as_ref().map_or(true, |p| seen.insert(p.clone())))
+        .collect()
+}
+
+/// Discovers `.feature` files of the provided `input`, being either a path or
+/// a glob pattern.
+pub(super) fn discover(input: &Path) -> Vec<Discovered> {
+    if let Some(pattern) = input.to_str().filter(|s| is_glob(s)) {
+        return glob(pattern);
+    }
+
+    let path = match canonicalize(input) {
+        Ok(p) => p,
+        Err(e) => return vec![Err(e)],
+    };
+    if path.is_file() {
+        return vec![Ok(path)];
+    }
+
+    let walker = GlobWalkerBuilder::new(&path, "*.feature")
+        .case_insensitive(true)
+        .build()
+        .unwrap_or_else(|e| unreachable!("`GlobWalkerBuilder` panicked: {e}"));
+    IgnoreFiles::new(path).retain(walk(walker)).into_iter().map(Ok).collect()
+}
+
+/// Discovers `.feature` files matching the provided glob `pattern`.
+///
+/// A pattern matching no files is reported as an error.
+pub(super) fn glob(pattern: &str) -> Vec<Discovered> {
+    let reading_error = |kind, msg: String| gherkin::ParseFileError::Reading {
+        path: PathBuf::from(pattern),
+        source: io::Error::new(kind, msg),
+    };
+
+    let walker = match globwalk::glob(pattern) {
+        Ok(w) => w,
+        Err(e) => {
+            return vec![Err(reading_error(
+                io::ErrorKind::InvalidInput,
+                format!("invalid glob pattern: {e}"),
+            ))];
+        }
+    };
+    let mut files = walk(walker);
+    if let Ok(base) = canonicalize(&glob_base(pattern)) {
+        files = IgnoreFiles::new(base).retain(files);
+    }
+    if files.is_empty() {
+        return vec![Err(reading_error(
+            io::ErrorKind::NotFound,
+            "no files match the glob pattern".into(),
+        ))];
+    }
+    files.into_iter().map(Ok).collect()
+}
+
+/// Returns the base directory of the provided glob `pattern` (its leading
+/// components without any glob syntax).
+fn glob_base(pattern: &str) -> PathBuf {
+    let base = Path::new(pattern)
+        .components()
+        .take_while(|c| !c.as_os_str().to_str().is_some_and(is_glob))
+        .collect::<PathBuf>();
+    if base.as_os_str().is_empty() { PathBuf::from(".") } else { base }
+}
+
+/// Indicates whether the provided `input` is a glob pattern.
+fn is_glob(input: &str) -> bool {
+    input.contains(['*', '?', '[', '{'])
+}
+
+/// Collects canonicalized files of the provided [`GlobWalker`] sorted by their
+/// paths.
+fn walk(walker: GlobWalker) -> Vec<PathBuf> {
+    walker
+        .filter_map(Result::ok)
+        .map(|e| e.path().canonicalize().unwrap_or_else(|_| e.into_path()))
+        .sorted()
+        .collect()
+}
+
+/// Canonicalizes the provided `path`, resolving it relatively to the crate
+/// root, if it doesn't exist relatively to the current directory.
+pub(super) fn canonicalize(path: &Path) -> Discovered {
+    path.canonicalize()
+        .or_else(|_| {
+            let buf = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(
+                path.strip_prefix("/")
+                    .or_else(|_| path.strip_prefix("./"))
+                    .unwrap_or(path),
+            );
+            buf.as_path().canonicalize()
+        })
+        .map_err(|e| gherkin::ParseFileError::Reading {
+            path: path.to_path_buf(),
+            source: e,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(discovered: Vec<Discovered>) -> Vec<String> {
+        discovered
+            .into_iter()
+            .map(|f| f.unwrap().file_name().unwrap().display().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn detects_glob_patterns() {
+        assert!(is_glob("features/**/smoke/*.feature"));
+        assert!(is_glob("features/{a,b}.feature"));
+        assert!(!is_glob("features/smoke"));
+    }
+
+    #[test]
+    fn discovers_in_inputs_order_without_duplicates() {
+        let files = discover_all(Inputs::from([
+            "tests/features/language/header.feature",
+            "tests/features/language/*.feature",
+        ]));
+
+        assert_eq!(names(files), ["header.feature", "default.feature"]);
+    }
+
+    #[test]
+    fn detects_glob_base() {
+        assert_eq!(
+            glob_base("tests/features/**/smoke/*.feature"),
+            Path::new("tests/features"),
+        );
+        assert_eq!(glob_base("*.feature"), Path::new("."));
+    }
+
+    #[test]
+    fn reports_unmatched_glob() {
+        let files = glob("tests/features/**/unknown-*.feature");
+
+        assert_eq!(files.len(), 1);
+        assert!(matches!(
+            &files[0],
+            Err(gherkin::ParseFileError::Reading { source, .. })
+                if source.kind() == io::ErrorKind::NotFound,
+        ));
+    }
+}
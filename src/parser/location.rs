@@ -0,0 +1,206 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Selection of [`gherkin::Scenario`]s by their `path:line` locations (like
+//! `features/checkout.feature:42:77`).
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    iter,
+    path::{Path, PathBuf},
+};
+
+use super::inputs;
+
+/// Line numbers of the selected [`gherkin::Scenario`]s in `.feature` files.
+pub(super) type Selected = HashMap<PathBuf, BTreeSet<usize>>;
+
+/// Splits the provided `inputs` into paths and the line numbers selected in
+/// the `.feature` files of these paths.
+pub(super) fn split_all(
+    inputs: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> (Vec<PathBuf>, Selected) {
+    let mut selected = Selected::new();
+    let paths = inputs
+        .into_iter()
+        .map(|input| {
+            let (path, lines) = split(input.as_ref());
+            if !lines.is_empty() {
+                if let Ok(file) = inputs::canonicalize(&path) {
+                    selected.entry(file).or_default().extend(lines);
+                }
+            }
+            path
+        })
+        .collect();
+    (paths, selected)
+}
+
+/// Splits the provided `input` into its path and its trailing `:line` numbers,
+/// if any.
+///
+/// An existing path is never split.
+fn split(input: &Path) -> (PathBuf, BTreeSet<usize>) {
+    let mut lines = BTreeSet::new();
+    let Some(mut path) = input.to_str().filter(|_| !input.exists()) else {
+        return (input.to_path_buf(), lines);
+    };
+    while let Some((rest, line)) = path.rsplit_once(':') {
+        let Ok(line) = line.parse() else {
+            break;
+        };
+        _ = lines.insert(line);
+        path = rest;
+    }
+    (PathBuf::from(path), lines)
+}
+
+/// Selection of [`gherkin::Scenario`]s of a single [`gherkin::Feature`] by
+/// line numbers.
+///
+/// A [`gherkin::Scenario`] is selected by the line of its keyword or any of its
+/// [`gherkin::Step`]s. An expanded [`Scenario Outline`][1] is also selected by
+/// the line of its original keyword, its [`Examples`][2] keyword or its row.
+/// The line of a [`gherkin::Rule`] or [`gherkin::Feature`] keyword selects all
+/// their [`gherkin::Scenario`]s.
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+/// [2]: https://cucumber.io/docs/gherkin/reference#examples
+#[derive(Debug)]
+pub(super) struct Selection<'l> {
+    /// Selected line numbers.
+    lines: &'l BTreeSet<usize>,
+
+    /// Original lines of [`Scenario Outline`][1]s' keywords by their
+    /// [`gherkin::Span`]s starts, which are kept on expansion.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    outlines: HashMap<usize, usize>,
+}
+
+impl<'l> Selection<'l> {
+    /// Creates a new [`Selection`] of the provided `lines` in the provided
+    /// [`gherkin::Feature`] before expanding its [`Examples`].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    pub(super) fn new(
+        feature: &gherkin::Feature,
+        lines: &'l BTreeSet<usize>,
+    ) -> Self {
+        let outlines = feature
+            .scenarios
+            .iter()
+            .chain(feature.rules.iter().flat_map(|r| &r.scenarios))
+            .filter(|s| !s.examples.is_empty())
+            .map(|s| (s.span.start, s.position.line))
+            .collect();
+        Self { lines, outlines }
+    }
+
+    /// Retains only the selected [`gherkin::Scenario`]s in the provided
+    /// [`gherkin::Feature`] with expanded [`Examples`].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    pub(super) fn retain(
+        &self,
+        mut feature: gherkin::Feature,
+    ) -> gherkin::Feature {
+        if self.lines.contains(&feature.position.line) {
+            return feature;
+        }
+        feature.scenarios.retain(|s| self.selects(s));
+        feature.rules.retain_mut(|r| {
+            if !self.lines.contains(&r.position.line) {
+                r.scenarios.retain(|s| self.selects(s));
+            }
+            !r.scenarios.is_empty()
+        });
+        feature
+    }
+
+    /// Indicates whether the provided [`gherkin::Scenario`] is selected.
+    fn selects(&self, scenario: &gherkin::Scenario) -> bool {
+        iter::once(scenario.position.line)
+            .chain(self.outlines.get(&scenario.span.start).copied())
+            .chain(scenario.examples.iter().map(|e| e.position.line))
+            .chain(scenario.steps.iter().map(|s| s.position.line))
+            .any(|l| self.lines.contains(&l))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::Ext as _;
+
+    const FEATURE: &str = "Feature: F\n\
+                           \x20 Scenario: one\n\
+                           \x20   Given a\n\
+                           \x20 Scenario Outline: two\n\
+                           \x20   Given <x>\n\
+                           \x20   Examples:\n\
+                           \x20     | x |\n\
+                           \x20     | 1 |\n\
+                           \x20     | 2 |\n\
+                           \x20 Rule: R\n\
+                           \x20   Scenario: three\n\
+                           \x20     Given c\n";
+
+    fn select(lines: &[usize]) -> Vec<(String, usize)> {
+        let feature =
+            gherkin::Feature::parse(FEATURE, gherkin::GherkinEnv::default())
+                .unwrap();
+        let lines = lines.iter().copied().collect();
+        let selection = Selection::new(&feature, &lines);
+        let feature = selection.retain(feature.expand_examples().unwrap());
+
+        feature
+            .scenarios
+            .iter()
+            .chain(feature.rules.iter().flat_map(|r| &r.scenarios))
+            .map(|s| (s.name.clone(), s.position.line))
+            .collect()
+    }
+
+    #[test]
+    fn splits_trailing_lines() {
+        let (paths, selected) = split_all([
+            "tests/features/readme/eating.feature:3:14",
+            "tests/features/readme",
+        ]);
+
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("tests/features/readme/eating.feature"),
+                PathBuf::from("tests/features/readme"),
+            ],
+        );
+        assert_eq!(
+            selected.into_values().collect::<Vec<_>>(),
+            [BTreeSet::from([3, 14])],
+        );
+        assert_eq!(
+            split(Path::new("C:\\features\\a.feature")),
+            (PathBuf::from("C:\\features\\a.feature"), BTreeSet::new()),
+        );
+    }
+
+    #[test]
+    fn selects_scenarios_by_lines() {
+        assert_eq!(select(&[2]), [("one".into(), 2)]);
+        assert_eq!(select(&[3, 9]), [("one".into(), 2), ("two".into(), 9)]);
+        assert_eq!(select(&[4]), [("two".into(), 8), ("two".into(), 9)]);
+        assert_eq!(select(&[5, 6]).len(), 2);
+        assert_eq!(select(&[10]), [("three".into(), 11)]);
+        assert_eq!(select(&[1]).len(), 4);
+        assert_eq!(select(&[42]), []);
+    }
+}
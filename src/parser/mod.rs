@@ -13,14 +13,31 @@
 //! [Gherkin]: https://cucumber.io/docs/gherkin/reference
 
 pub mod basic;
+mod chain;
+pub mod discard;
+mod embedded;
+mod ext;
+mod external_examples;
+mod ignore_file;
+mod inputs;
+mod location;
+mod order;
+#[cfg(feature = "remote")]
+mod remote;
+mod template;
 
-use std::sync::Arc;
+use std::{io, sync::Arc};
 
 use derive_more::with_trait::{Display, Error as StdError};
 use futures::Stream;
 
+#[cfg(feature = "remote")]
 #[doc(inline)]
-pub use self::basic::Basic;
+pub use self::remote::{CommandFetcher, Fetcher, Remote};
+#[doc(inline)]
+pub use self::{
+    basic::Basic, chain::Chain, embedded::Embedded, ext::Ext, inputs::Inputs,
+};
 use crate::feature::ExpandExamplesError;
 
 /// Source of parsed [`Feature`]s.
@@ -69,6 +86,29 @@ pub enum Error {
     /// [`Examples`]: gherkin::Examples
     #[display("Failed to expand examples: {_0}")]
     ExampleExpansion(Arc<ExpandExamplesError>),
+
+    /// Failed to fetch remote `.feature` files (via `parser::Remote`).
+    ///
+    /// If `cached`, the previously fetched ones are parsed instead.
+    #[display(
+        "Failed to fetch `{location}`{}: {source}",
+        if *cached {
+            ", using the previously cached `.feature` files instead"
+        } else {
+            ""
+        },
+    )]
+    Fetching {
+        /// Location the `.feature` files were fetched from.
+        location: String,
+
+        /// Indicator whether the previously cached `.feature` files are
+        /// parsed instead.
+        cached: bool,
+
+        /// Error of fetching.
+        source: Arc<io::Error>,
+    },
 }
 
 impl From<gherkin::ParseFileError> for Error {
@@ -0,0 +1,194 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ordering of `.feature` files discovered by a [`Basic`] [`Parser`].
+//!
+//! [`Basic`]: super::Basic
+//! [`Parser`]: super::Parser
+
+use std::{
+    cmp::{self, Reverse},
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
+
+use itertools::{Either, Itertools as _};
+
+use super::inputs::{self, Discovered};
+
+/// Comparator of `.feature` file paths for an [`Order::Custom`].
+pub type Comparator = Arc<dyn Fn(&Path, &Path) -> cmp::Ordering + Send + Sync>;
+
+/// Order to execute `.feature` files discovered by a [`Basic`] [`Parser`] in.
+///
+/// Files failed to be discovered are always reported first.
+///
+/// [`Basic`]: super::Basic
+/// [`Parser`]: super::Parser
+#[derive(Clone, Default)]
+pub enum Order {
+    /// Order of inputs, with files sorted by their paths within a single
+    /// input.
+    #[default]
+    Discovered,
+
+    /// Files sorted by their paths, regardless of inputs.
+    Path,
+
+    /// Most recently modified files first, ties broken by paths.
+    Modified,
+
+    /// Files in the order they are listed (one path per line) in the provided
+    /// file, while the unlisted ones are executed afterwards in the
+    /// [`Order::Discovered`].
+    ///
+    /// Empty lines and `#` comments of the list file are skipped.
+    List(PathBuf),
+
+    /// Files sorted with the provided [`Comparator`].
+    Custom(Comparator),
+}
+
+impl Order {
+    /// Creates a new [`Order::Custom`] out of the provided comparator of
+    /// `.feature` file paths.
+    #[must_use]
+    pub fn custom(
+        cmp: impl Fn(&Path, &Path) -> cmp::Ordering + Send + Sync + 'static,
+    ) -> Self {
+        Self::Custom(Arc::new(cmp))
+    }
+
+    /// Reorders the provided `files` according to this [`Order`].
+    pub(super) fn apply(&self, files: Vec<Discovered>) -> Vec<Discovered> {
+        if matches!(self, Self::Discovered) {
+            return files;
+        }
+
+        let (mut errors, mut paths): (Vec<_>, Vec<_>) =
+            files.into_iter().partition_map(|f| match f {
+                Ok(p) => Either::Right(p),
+                Err(e) => Either::Left(Err(e)),
+            });
+        match self {
+            Self::Discovered => {}
+            Self::Path => paths.sort(),
+            Self::Modified => paths.sort_by_cached_key(|p| {
+                let modified = fs::metadata(p).and_then(|m| m.modified());
+                (Reverse(modified.ok()), p.clone())
+            }),
+            Self::List(file) => match listed(file) {
+                Ok(listed) => paths.sort_by_key(|p| {
+                    listed.get(p).copied().unwrap_or(usize::MAX)
+                }),
+                Err(e) => errors.push(Err(e)),
+            },
+            Self::Custom(cmp) => paths.sort_by(|a, b| cmp(a, b)),
+        }
+        errors.extend(paths.into_iter().map(Ok));
+        errors
+    }
+}
+
+impl fmt::Debug for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Discovered => f.write_str("Discovered"),
+            Self::Path => f.write_str("Path"),
+            Self::Modified => f.write_str("Modified"),
+            Self::List(file) => f.debug_tuple("List").field(file).finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl FromStr for Order {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "discovered" => Ok(Self::Discovered),
+            "path" => Ok(Self::Path),
+            "mtime" => Ok(Self::Modified),
+            _ => s
+                .strip_prefix("list:")
+                .filter(|f| !f.is_empty())
+                .map(|f| Self::List(f.into()))
+                .ok_or(
+                    "possible options: discovered, path, mtime, list:<file>",
+                ),
+        }
+    }
+}
+
+/// Reads positions of the `.feature` files listed in the provided `file`.
+///
+/// Listed files, which don't exist, are omitted.
+fn listed(
+    file: &Path,
+) -> Result<HashMap<PathBuf, usize>, gherkin::ParseFileError> {
+    let list = fs::read_to_string(file).map_err(|source| {
+        gherkin::ParseFileError::Reading { path: file.to_path_buf(), source }
+    })?;
+    let mut listed = HashMap::new();
+    let paths = list
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| inputs::canonicalize(Path::new(l)).ok());
+    for path in paths {
+        let n = listed.len();
+        _ = listed.entry(path).or_insert(n);
+    }
+    Ok(listed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use super::*;
+
+    fn discovered(order: &Order) -> Vec<PathBuf> {
+        let files = inputs::discover_all(["tests/features/wait"]);
+        order.apply(files).into_iter().map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn parses_from_str() {
+        assert!(matches!("path".parse(), Ok(Order::Path)));
+        assert!(matches!("mtime".parse(), Ok(Order::Modified)));
+        assert!(matches!(
+            "list:order.txt".parse(),
+            Ok(Order::List(f)) if f == Path::new("order.txt"),
+        ));
+        assert!("list:".parse::<Order>().is_err());
+        assert!("random".parse::<Order>().is_err());
+    }
+
+    #[test]
+    fn orders_by_list_and_comparator() {
+        let all = discovered(&Order::Discovered);
+        let last = all.last().unwrap().clone();
+
+        let mut list = tempfile::NamedTempFile::new().unwrap();
+        writeln!(list, "# first\n{}\n\nmissing.feature", last.display())
+            .unwrap();
+        let listed = discovered(&Order::List(list.path().into()));
+        assert_eq!(listed.first(), Some(&last));
+        assert_eq!(listed[1..], all[..all.len() - 1]);
+
+        let reversed = discovered(&Order::custom(|a, b| b.cmp(a)));
+        assert_eq!(reversed, all.iter().rev().cloned().collect::<Vec<_>>());
+    }
+}
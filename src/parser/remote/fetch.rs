@@ -0,0 +1,175 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Fetcher`]s of [`Remote`] `.feature` files.
+//!
+//! [`Remote`]: super::Remote
+
+use std::{fmt, fs, io, path::Path, process::Command};
+
+/// Fetcher of [`Remote`] `.feature` files into a local cache.
+///
+/// Allows to use a native HTTP or Git client instead of the default
+/// [`CommandFetcher`].
+///
+/// [`Remote`]: super::Remote
+pub trait Fetcher: fmt::Debug {
+    /// Downloads a single `.feature` file by the provided `url` into the
+    /// `target` file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be downloaded.
+    fn fetch_http(&self, url: &str, target: &Path) -> io::Result<()>;
+
+    /// Checks out the provided `reference` (branch, tag or commit) of the Git
+    /// `repository` into the `target` directory, which may already contain
+    /// its previous checkout.
+    ///
+    /// # Errors
+    ///
+    /// If the `reference` cannot be checked out.
+    fn fetch_git(
+        &self,
+        repository: &str,
+        reference: &str,
+        target: &Path,
+    ) -> io::Result<()>;
+}
+
+/// Default [`Fetcher`] running `curl` and `git` executables, which should be
+/// available in `PATH`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CommandFetcher;
+
+impl Fetcher for CommandFetcher {
+    fn fetch_http(&self, url: &str, target: &Path) -> io::Result<()> {
+        let url = not_option(url)?;
+        if let Some(dir) = target.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let partial = target.with_extension("part");
+        run(Command::new("curl")
+            .args(["-fsSL", "-o"])
+            .arg(&partial)
+            .args(["--url", url]))?;
+        fs::rename(partial, target)
+    }
+
+    fn fetch_git(
+        &self,
+        repository: &str,
+        reference: &str,
+        target: &Path,
+    ) -> io::Result<()> {
+        let (repository, reference) =
+            (not_option(repository)?, not_option(reference)?);
+        if !target.join(".git").exists() {
+            fs::create_dir_all(target)?;
+            run(Command::new("git")
+                .arg("-C")
+                .arg(target)
+                .args(["init", "-q"]))?;
+        }
+        run(Command::new("git").arg("-C").arg(target).args([
+            "fetch", "-q", "--depth", "1", "--", repository, reference,
+        ]))?;
+        run(Command::new("git").arg("-C").arg(target).args([
+            "checkout",
+            "-q",
+            "--force",
+            "--detach",
+            "FETCH_HEAD",
+        ]))
+    }
+}
+
+/// Ensures the provided `value` cannot be interpreted as a command-line
+/// option of `curl` or `git`.
+fn not_option(value: &str) -> io::Result<&str> {
+    if value.starts_with('-') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("`{value}` must not start with `-`"),
+        ));
+    }
+    Ok(value)
+}
+
+/// Runs the provided [`Command`], failing if it doesn't succeed.
+fn run(cmd: &mut Command) -> io::Result<()> {
+    let output = cmd.output()?;
+    if output.status.success() {
+        return Ok(());
+    }
+    Err(io::Error::other(format!(
+        "`{}` failed: {}",
+        cmd.get_program().display(),
+        String::from_utf8_lossy(&output.stderr).trim(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::Remote, *};
+
+    const FEATURE: &str = "Feature: Remote\n  Scenario: S\n    Given a step\n";
+
+    fn git(dir: &Path, args: &[&str]) {
+        run(Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@test"])
+            .args(args))
+        .unwrap();
+    }
+
+    #[test]
+    fn checks_out_git_reference() {
+        let repo = tempfile::tempdir().unwrap();
+        let cache = tempfile::tempdir().unwrap();
+        git(repo.path(), &["init", "-q"]);
+        fs::create_dir(repo.path().join("specs")).unwrap();
+        fs::write(repo.path().join("specs/a.feature"), FEATURE).unwrap();
+        git(repo.path(), &["add", "."]);
+        git(repo.path(), &["commit", "-qm", "init"]);
+        git(repo.path(), &["tag", "v1"]);
+
+        let (path, stale) =
+            Remote::git(repo.path().display().to_string(), "v1")
+                .path("specs")
+                .cache_dir(cache.path())
+                .fetch()
+                .unwrap();
+
+        assert!(stale.is_none(), "{stale:?}");
+
+        assert!(path.starts_with(cache.path()));
+        assert_eq!(
+            fs::read_to_string(path.join("a.feature")).unwrap(),
+            FEATURE
+        );
+    }
+
+    #[test]
+    fn rejects_option_like_sources() {
+        let cache = tempfile::tempdir().unwrap();
+
+        for remote in [
+            Remote::http("-K/etc/passwd"),
+            Remote::git("--upload-pack=touch /tmp/pwned", "main"),
+            Remote::git("https://example.com/specs.git", "--help"),
+        ] {
+            let err = remote.cache_dir(cache.path()).fetch().unwrap_err();
+
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{err}");
+        }
+    }
+}
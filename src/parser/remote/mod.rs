@@ -0,0 +1,295 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Remote `.feature` files sources for a [`Basic`] [`Parser`].
+//!
+//! [`Basic`]: super::Basic
+//! [`Parser`]: super::Parser
+
+mod fetch;
+
+use std::{env, fmt, io, path::PathBuf, sync::Arc};
+
+pub use self::fetch::{CommandFetcher, Fetcher};
+
+/// Remote source of `.feature` files for a [`Basic`] [`Parser`], fetched
+/// either over HTTP or from a Git repository.
+///
+/// Allows a central specification repository to be executed by multiple
+/// implementation repositories.
+///
+/// Fetched files are cached in a local directory, so whenever fetching fails
+/// (being offline, for example) the previously cached ones are used, while
+/// the failure is still reported as a [`parser::Error::Fetching`].
+///
+/// By default, fetching relies on `curl` and `git` executables being
+/// available in `PATH` (see [`CommandFetcher`]), which may be replaced with a
+/// custom [`Fetcher`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use cucumber::{World, parser};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .run(
+///         parser::Remote::git("https://github.com/org/specs.git", "v1.2.0")
+///             .path("features/checkout"),
+///     )
+///     .await;
+/// # }
+/// ```
+///
+/// [`Basic`]: super::Basic
+/// [`Parser`]: super::Parser
+/// [`parser::Error::Fetching`]: super::Error::Fetching
+#[derive(Clone, Debug)]
+pub struct Remote {
+    /// Location to fetch `.feature` files from.
+    location: Location,
+
+    /// Directory to cache fetched `.feature` files in.
+    cache_dir: PathBuf,
+
+    /// [`Fetcher`] of `.feature` files.
+    fetcher: Arc<dyn Fetcher>,
+}
+
+/// Location of [`Remote`] `.feature` files.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Location {
+    /// URL of a single `.feature` file.
+    Http(String),
+
+    /// Git repository.
+    Git {
+        /// URL (or local path) of the repository.
+        repository: String,
+
+        /// Branch, tag or commit to check out.
+        reference: String,
+
+        /// Path inside the repository to look for `.feature` files in.
+        path: Option<PathBuf>,
+    },
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(url) => write!(f, "{url}"),
+            Self::Git { repository, reference, .. } => {
+                write!(f, "{repository}@{reference}")
+            }
+        }
+    }
+}
+
+impl Remote {
+    /// Creates a new [`Remote`] source of a single `.feature` file fetched by
+    /// the provided HTTP `url`.
+    #[must_use]
+    pub fn http(url: impl Into<String>) -> Self {
+        Self::new(Location::Http(url.into()))
+    }
+
+    /// Creates a new [`Remote`] source of `.feature` files in the provided
+    /// Git `repository` checked out at the provided `reference` (branch, tag
+    /// or commit).
+    #[must_use]
+    pub fn git(
+        repository: impl Into<String>,
+        reference: impl Into<String>,
+    ) -> Self {
+        Self::new(Location::Git {
+            repository: repository.into(),
+            reference: reference.into(),
+            path: None,
+        })
+    }
+
+    /// Creates a new [`Remote`] source with the default cache directory.
+    fn new(location: Location) -> Self {
+        Self {
+            location,
+            cache_dir: env::temp_dir().join("cucumber-remote"),
+            fetcher: Arc::new(CommandFetcher),
+        }
+    }
+
+    /// Sets the path inside a Git repository to look for `.feature` files in
+    /// (either a directory or a single file).
+    ///
+    /// Has no effect for an HTTP source.
+    #[must_use]
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        if let Location::Git { path: p, .. } = &mut self.location {
+            *p = Some(path.into());
+        }
+        self
+    }
+
+    /// Sets the directory to cache fetched `.feature` files in.
+    ///
+    /// Default is `cucumber-remote` in the [`env::temp_dir()`].
+    #[must_use]
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = dir.into();
+        self
+    }
+
+    /// Sets the [`Fetcher`] of `.feature` files.
+    ///
+    /// Default is [`CommandFetcher`].
+    #[must_use]
+    pub fn fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Arc::new(fetcher);
+        self
+    }
+
+    /// Returns the location this [`Remote`] source fetches `.feature` files
+    /// from.
+    pub(super) fn location(&self) -> String {
+        self.location.to_string()
+    }
+
+    /// Fetches `.feature` files of this [`Remote`] source into its cache
+    /// directory, returning the local path to look for them in.
+    ///
+    /// Falls back to the previously cached files if fetching fails, returning
+    /// the error of fetching along with them.
+    pub(super) fn fetch(&self) -> io::Result<(PathBuf, Option<io::Error>)> {
+        let target = self.cache_dir.join(cache_key(&self.location));
+        let fetched = match &self.location {
+            Location::Http(url) => self.fetcher.fetch_http(url, &target),
+            Location::Git { repository, reference, .. } => {
+                self.fetcher.fetch_git(repository, reference, &target)
+            }
+        };
+        let stale = match fetched {
+            Ok(()) => None,
+            Err(e) if target.exists() => Some(e),
+            Err(e) => return Err(e),
+        };
+
+        let path = match &self.location {
+            Location::Git { path: Some(path), .. } => target.join(path),
+            Location::Http(_) | Location::Git { path: None, .. } => target,
+        };
+        Ok((path, stale))
+    }
+}
+
+/// Returns a name of the cache entry for the provided [`Location`], being a
+/// hash of everything it's fetched from.
+///
+/// The path inside a Git repository isn't a part of it, as the whole
+/// repository is checked out anyway.
+fn cache_key(location: &Location) -> String {
+    match location {
+        Location::Http(url) => format!("http-{:016x}.feature", fnv1a(&[url])),
+        Location::Git { repository, reference, .. } => {
+            format!("git-{:016x}", fnv1a(&[repository, reference]))
+        }
+    }
+}
+
+/// Calculates a [FNV-1a] hash of the provided `parts`, which, unlike the
+/// [`std::hash`] ones, is stable across Rust versions, so the cache survives
+/// toolchain upgrades.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler–Noll–Vo_hash_function
+fn fnv1a(parts: &[&str]) -> u64 {
+    parts
+        .iter()
+        // Separator makes `["ab", "c"]` and `["a", "bc"]` hashed differently.
+        .flat_map(|p| p.bytes().chain([0]))
+        .fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Native;
+
+    impl Fetcher for Native {
+        fn fetch_http(&self, url: &str, target: &Path) -> io::Result<()> {
+            fs::write(target, format!("Feature: {url}\n"))
+        }
+
+        fn fetch_git(&self, _: &str, _: &str, _: &Path) -> io::Result<()> {
+            Err(io::ErrorKind::Unsupported.into())
+        }
+    }
+
+    #[test]
+    fn uses_custom_fetcher() {
+        let cache = tempfile::tempdir().unwrap();
+        let remote = Remote::http("https://example.com/a.feature")
+            .cache_dir(cache.path())
+            .fetcher(Native);
+
+        let (path, stale) = remote.fetch().unwrap();
+        assert!(stale.is_none(), "{stale:?}");
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            "Feature: https://example.com/a.feature\n",
+        );
+
+        let err = Remote::git("https://example.com/a.git", "main")
+            .cache_dir(cache.path())
+            .fetcher(Native)
+            .fetch()
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported, "{err}");
+    }
+
+    #[test]
+    fn falls_back_to_cache() {
+        let cache = tempfile::tempdir().unwrap();
+        let remote = Remote::http("http://127.0.0.1:9/a.feature")
+            .cache_dir(cache.path());
+
+        assert!(remote.fetch().is_err(), "nothing is cached yet");
+
+        let cached = cache.path().join(cache_key(&remote.location));
+        fs::write(&cached, "Feature: Remote\n").unwrap();
+
+        let (path, stale) = remote.fetch().unwrap();
+        assert_eq!(path, cached);
+        assert!(stale.is_some(), "fetching failure is returned");
+    }
+
+    #[test]
+    fn distinguishes_cache_keys() {
+        let keys = [
+            Remote::http("https://example.com/a/b.feature"),
+            Remote::http("https://example.com/a_b.feature"),
+            Remote::git("https://example.com/a.git", "b@c"),
+            Remote::git("https://example.com/a.git@b", "c"),
+        ]
+        .map(|r| cache_key(&r.location));
+
+        for (i, key) in keys.iter().enumerate() {
+            assert!(!keys[i + 1..].contains(key), "{key} is duplicated");
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `{{placeholder}}` templating of `.feature` files.
+
+use std::{
+    borrow::Cow, collections::BTreeMap, fs, io, path::PathBuf, str::FromStr,
+};
+
+use derive_more::with_trait::{Display, Error};
+use gherkin::GherkinEnv;
+
+use super::embedded;
+
+/// Variables to replace `{{placeholders}}` in `.feature` files with.
+pub(super) type Vars = BTreeMap<String, String>;
+
+/// Replaces all the `{{placeholders}}` in the provided `source` with the
+/// provided `vars`.
+///
+/// # Errors
+///
+/// If the `source` contains a placeholder without a variable, or an
+/// unterminated one.
+pub(super) fn render(source: &str, vars: &Vars) -> Result<String, String> {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some((before, after)) = rest.split_once("{{") {
+        let (name, after) = after
+            .split_once("}}")
+            .ok_or_else(|| "unterminated `{{` placeholder".to_owned())?;
+        let value = vars.get(name.trim()).ok_or_else(|| {
+            format!("unknown template placeholder `{}`", name.trim())
+        })?;
+        out.push_str(before);
+        out.push_str(value);
+        rest = after;
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Parses the `.feature` file by the provided `path`, replacing its
+/// `{{placeholders}}` with the provided `vars` first, if any.
+pub(super) fn parse_path(
+    path: PathBuf,
+    env: GherkinEnv,
+    vars: &Vars,
+) -> Result<gherkin::Feature, gherkin::ParseFileError> {
+    if vars.is_empty() {
+        return gherkin::Feature::parse_path(path, env);
+    }
+    match fs::read_to_string(&path) {
+        Ok(source) => parse_str(path, &source, env, vars),
+        Err(e) => Err(gherkin::ParseFileError::Reading { path, source: e }),
+    }
+}
+
+/// Parses the provided in-memory `source` with the virtual file `name`,
+/// replacing its `{{placeholders}}` with the provided `vars` first, if any.
+pub(super) fn parse_str(
+    name: PathBuf,
+    source: &str,
+    env: GherkinEnv,
+    vars: &Vars,
+) -> Result<gherkin::Feature, gherkin::ParseFileError> {
+    let source = if vars.is_empty() {
+        Cow::Borrowed(source)
+    } else {
+        match render(source, vars) {
+            Ok(s) => Cow::Owned(s),
+            Err(e) => {
+                return Err(gherkin::ParseFileError::Reading {
+                    path: name,
+                    source: io::Error::new(io::ErrorKind::InvalidData, e),
+                });
+            }
+        }
+    };
+    embedded::parse(name, &source, env)
+}
+
+/// `KEY=VALUE` template variable to replace `{{KEY}}` placeholders with.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateVar {
+    /// Name of the placeholder.
+    pub key: String,
+
+    /// Value to replace the placeholder with.
+    pub value: String,
+}
+
+impl FromStr for TemplateVar {
+    type Err = InvalidTemplateVarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .filter(|(k, _)| !k.trim().is_empty())
+            .ok_or_else(|| InvalidTemplateVarError(s.to_owned()))?;
+        Ok(Self { key: key.trim().to_owned(), value: value.to_owned() })
+    }
+}
+
+/// Error of a [`TemplateVar`] not being in a `KEY=VALUE` form.
+#[derive(Clone, Debug, Display, Error)]
+#[display("Expected `KEY=VALUE` template variable, found `{_0}`")]
+pub struct InvalidTemplateVarError(#[error(not(source))] pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_placeholders() {
+        let vars = Vars::from([("env".into(), "staging".into())]);
+
+        assert_eq!(
+            render("Given {{env}} at {{ env }}", &vars).unwrap(),
+            "Given staging at staging",
+        );
+        assert_eq!(
+            render("Given {{user}}", &vars).unwrap_err(),
+            "unknown template placeholder `user`",
+        );
+        assert!(render("Given {{env", &vars).is_err());
+    }
+
+    #[test]
+    fn parses_cli_vars() {
+        assert_eq!(
+            "env=a=b".parse::<TemplateVar>().unwrap(),
+            TemplateVar { key: "env".into(), value: "a=b".into() },
+        );
+        assert!("=x".parse::<TemplateVar>().is_err());
+        assert!("x".parse::<TemplateVar>().is_err());
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CLI options of selecting a configuration profile.
+
+/// CLI options of selecting a configuration profile.
+#[derive(Clone, Debug, Default, clap::Args)]
+#[group(skip)]
+pub struct Cli {
+    /// Name of the `[profile.<name>]` table of the `cucumber.toml` config file
+    /// to take the default CLI options from (`default` one is used, if
+    /// present, when omitted).
+    ///
+    /// Options provided explicitly take precedence over the profile ones.
+    #[arg(long, value_name = "name", global = true)]
+    pub profile: Option<String>,
+}
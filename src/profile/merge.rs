@@ -0,0 +1,81 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Merging of a selected [`Profile`] into CLI arguments.
+//!
+//! [`Profile`]: super::Profile
+
+use std::ffi::OsString;
+
+use super::{DEFAULT_PROFILE, LoadError, Profiles};
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to merging only"
+)]
+impl Profiles {
+    /// Inserts the CLI arguments of the [`Profile`] selected via the
+    /// `--profile` option (or the [`DEFAULT_PROFILE`], if any) right after the
+    /// binary name of the provided command line `args`.
+    ///
+    /// As later CLI arguments override earlier ones, the explicitly provided
+    /// ones take precedence over the [`Profile`] ones.
+    ///
+    /// # Errors
+    ///
+    /// If the selected [`Profile`] isn't defined.
+    ///
+    /// [`Profile`]: super::Profile
+    pub fn expand<I, T>(&self, args: I) -> Result<Vec<OsString>, LoadError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        let mut args = args.into_iter().map(Into::into);
+        let bin = args.next();
+        let rest = args.collect::<Vec<_>>();
+
+        let profile = match selected(&rest) {
+            Some(name) => {
+                Some(self.get(&name).ok_or(LoadError::UnknownProfile(name))?)
+            }
+            None => self.get(DEFAULT_PROFILE),
+        };
+
+        Ok(bin
+            .into_iter()
+            .chain(
+                profile.into_iter().flat_map(|p| p.args.iter().map(Into::into)),
+            )
+            .chain(rest)
+            .collect())
+    }
+}
+
+/// Returns the name of the [`Profile`] selected via the last `--profile` CLI
+/// option, if any.
+///
+/// [`Profile`]: super::Profile
+fn selected(args: &[OsString]) -> Option<String> {
+    let mut args = args.iter().map(|a| a.to_string_lossy());
+    let mut name = None;
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        } else if arg == "--profile" {
+            name = args.next().map(Into::into);
+        } else if let Some(n) = arg.strip_prefix("--profile=") {
+            name = Some(n.to_owned());
+        }
+    }
+    name
+}
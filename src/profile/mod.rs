@@ -0,0 +1,190 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Named configuration [`Profiles`] bundling default CLI options of a run.
+//!
+//! [`Profiles`] are defined as `[profile.<name>]` tables of a [`CONFIG_FILE`]
+//! (or the one pointed by the [`CONFIG_ENV`] environment variable), each key
+//! of which is a long name of a CLI option (including the ones of the used
+//! [`Parser`], [`Runner`] and [`Writer`]):
+//!
+//! ```toml
+//! [profile.smoke]
+//! tags = "@smoke"
+//! concurrency = 4
+//!
+//! [profile.nightly]
+//! retry = 2
+//! fail-fast = true
+//! exit-code = ["undefined=3", "timeout=4"]
+//! ```
+//!
+//! A [`Profile`] is selected via the `--profile <name>` CLI option, while the
+//! `default` one (if any) is used when it's omitted.
+//!
+//! [`Parser`]: crate::Parser
+//! [`Runner`]: crate::Runner
+//! [`Writer`]: crate::Writer
+mod cli;
+mod merge;
+mod parse;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use derive_more::with_trait::{Display, Error};
+use toml::de;
+
+#[doc(inline)]
+pub use self::cli::Cli;
+
+/// Default path of the config file defining [`Profiles`].
+pub const CONFIG_FILE: &str = "cucumber.toml";
+
+/// Environment variable overriding the path of the [`CONFIG_FILE`].
+pub const CONFIG_ENV: &str = "CUCUMBER_CONFIG";
+
+/// Name of the [`Profile`] used when no `--profile` CLI option is provided.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// All the [`Profile`]s defined in a config file, by their names.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::profile::Profiles;
+/// #
+/// let profiles = r#"
+///     [profile.smoke]
+///     tags = "@smoke"
+///     concurrency = 4
+/// "#
+/// .parse::<Profiles>()
+/// .unwrap();
+///
+/// let args = profiles
+///     .expand(["cucumber", "--profile", "smoke", "--concurrency=1"])
+///     .unwrap();
+///
+/// assert_eq!(
+///     args,
+///     [
+///         "cucumber",
+///         "--concurrency=4",
+///         "--tags=@smoke",
+///         "--profile",
+///         "smoke",
+///         "--concurrency=1",
+///     ],
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Profiles(BTreeMap<String, Profile>);
+
+impl Profiles {
+    /// Loads [`Profiles`] from the config file at the provided `path`.
+    ///
+    /// Missing config file is considered as having no [`Profile`]s.
+    ///
+    /// # Errors
+    ///
+    /// If the config file cannot be read or is invalid.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(source) => Err(LoadError::Io { path: path.to_owned(), source }),
+        }
+    }
+
+    /// Loads [`Profiles`] from the config file pointed by the [`CONFIG_ENV`]
+    /// environment variable, or from the [`CONFIG_FILE`] in the current
+    /// directory, if it's not set.
+    ///
+    /// # Errors
+    ///
+    /// If the config file cannot be read or is invalid.
+    pub fn discover() -> Result<Self, LoadError> {
+        Self::load(
+            env::var_os(CONFIG_ENV)
+                .map_or_else(|| CONFIG_FILE.into(), PathBuf::from),
+        )
+    }
+
+    /// Returns the [`Profile`] with the provided `name`, if any.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Profile> {
+        self.0.get(name)
+    }
+}
+
+/// Named set of CLI options, defined as a `[profile.<name>]` table of a
+/// config file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Profile {
+    /// CLI arguments of this [`Profile`].
+    args: Vec<String>,
+}
+
+impl Profile {
+    /// Returns CLI arguments of this [`Profile`], in the order of its options
+    /// names.
+    #[must_use]
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+}
+
+/// Error of loading [`Profiles`] or selecting a [`Profile`].
+#[derive(Debug, Display, Error)]
+pub enum LoadError {
+    /// Failed to read the config file.
+    #[display("Failed to read `{}` config file: {source}", path.display())]
+    Io {
+        /// Path to the config file.
+        path: PathBuf,
+
+        /// Error of reading the config file.
+        source: io::Error,
+    },
+
+    /// Config file isn't a valid TOML.
+    #[display("Invalid config file: {_0}")]
+    Toml(de::Error),
+
+    /// `[profile]` or `[profile.<name>]` isn't a TOML table.
+    #[display("`{_0}` of config file is expected to be a table")]
+    NotTable(#[error(not(source))] String),
+
+    /// Option of a [`Profile`] cannot be converted into a CLI argument.
+    #[display(
+        "Option `{key}` of `{profile}` profile is expected to be a string, \
+         a number, a boolean or an array of strings and numbers"
+    )]
+    InvalidValue {
+        /// Name of the [`Profile`].
+        profile: String,
+
+        /// Name of the option.
+        key: String,
+    },
+
+    /// Selected [`Profile`] isn't defined in the config file.
+    #[display("Unknown profile `{_0}`")]
+    UnknownProfile(#[error(not(source))] String),
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing of [`Profiles`] out of a config file.
+
+use std::str::FromStr;
+
+use super::{LoadError, Profile, Profiles};
+
+impl FromStr for Profiles {
+    type Err = LoadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = s.parse::<toml::Table>().map_err(LoadError::Toml)?;
+        let Some(profiles) = config.remove("profile") else {
+            return Ok(Self::default());
+        };
+        let toml::Value::Table(profiles) = profiles else {
+            return Err(LoadError::NotTable("profile".into()));
+        };
+
+        profiles
+            .into_iter()
+            .map(|(name, options)| {
+                let toml::Value::Table(options) = options else {
+                    return Err(LoadError::NotTable(format!("profile.{name}")));
+                };
+                let profile = Profile::from_options(&name, options)?;
+                Ok((name, profile))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to parsing only"
+)]
+impl Profile {
+    /// Converts the `options` of the [`Profile`] with the provided `name` into
+    /// CLI arguments.
+    ///
+    /// `true` booleans become flags (while `false` ones are omitted), and
+    /// arrays repeat the option for each of their values.
+    fn from_options(
+        name: &str,
+        options: toml::Table,
+    ) -> Result<Self, LoadError> {
+        let mut args = Vec::new();
+        for (key, value) in options {
+            let values = match value {
+                toml::Value::Boolean(flag) => {
+                    if flag {
+                        args.push(format!("--{key}"));
+                    }
+                    continue;
+                }
+                toml::Value::Array(values) => values,
+                scalar @ (toml::Value::String(_)
+                | toml::Value::Integer(_)
+                | toml::Value::Float(_)
+                | toml::Value::Datetime(_)
+                | toml::Value::Table(_)) => vec![scalar],
+            };
+            for v in values {
+                let arg = match v {
+                    toml::Value::String(s) => s,
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::Float(n) => n.to_string(),
+                    toml::Value::Boolean(_)
+                    | toml::Value::Datetime(_)
+                    | toml::Value::Array(_)
+                    | toml::Value::Table(_) => {
+                        return Err(LoadError::InvalidValue {
+                            profile: name.to_owned(),
+                            key,
+                        });
+                    }
+                };
+                args.push(format!("--{key}={arg}"));
+            }
+        }
+        Ok(Self { args })
+    }
+}
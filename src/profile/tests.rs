@@ -0,0 +1,80 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests of [`Profiles`] parsing and merging.
+
+use super::*;
+
+const CONFIG: &str = r#"
+    [profile.default]
+    tags = "not @slow"
+
+    [profile.nightly]
+    retry = 2
+    fail-fast = true
+    strict = false
+    exit-code = ["undefined=3", "timeout=4"]
+"#;
+
+#[test]
+fn converts_options_into_args() {
+    let profiles = CONFIG.parse::<Profiles>().unwrap();
+
+    assert_eq!(
+        profiles.get("nightly").unwrap().args(),
+        [
+            "--exit-code=undefined=3",
+            "--exit-code=timeout=4",
+            "--fail-fast",
+            "--retry=2",
+        ],
+    );
+    assert!(profiles.get("smoke").is_none());
+}
+
+#[test]
+fn selects_profile() {
+    let profiles = CONFIG.parse::<Profiles>().unwrap();
+
+    assert_eq!(
+        profiles.expand(["cucumber", "-v"]).unwrap(),
+        ["cucumber", "--tags=not @slow", "-v"],
+    );
+    assert_eq!(
+        profiles.expand(["cucumber", "--profile=nightly"]).unwrap().len(),
+        6,
+    );
+    assert_eq!(
+        profiles.expand(["cucumber", "--", "--profile=nightly"]).unwrap(),
+        ["cucumber", "--tags=not @slow", "--", "--profile=nightly"],
+    );
+    assert!(matches!(
+        profiles.expand(["cucumber", "--profile", "smoke"]),
+        Err(LoadError::UnknownProfile(name)) if name == "smoke",
+    ));
+    assert_eq!(Profiles::default().expand(["cucumber"]).unwrap(), ["cucumber"],);
+}
+
+#[test]
+fn rejects_invalid_config() {
+    assert!(matches!(
+        "profile = 1".parse::<Profiles>(),
+        Err(LoadError::NotTable(_)),
+    ));
+    assert!(matches!(
+        "[profile.smoke]\ntags = { smoke = true }".parse::<Profiles>(),
+        Err(LoadError::InvalidValue { profile, key })
+            if profile == "smoke" && key == "tags",
+    ));
+    assert!(matches!(
+        Profiles::load("tests/features/missing.toml"),
+        Ok(p) if p == Profiles::default(),
+    ));
+}
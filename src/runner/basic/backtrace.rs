@@ -0,0 +1,47 @@
+//! Capturing of [`Backtrace`]s of panicking [`crate::step::Step`]s.
+//!
+//! The panic hook installed for the run [records](record()) the [`Backtrace`]
+//! of the panicking thread, which is [taken](take()) right after the panic is
+//! caught by the [`catch_unwind()`], being polled on the same thread.
+//!
+//! [`catch_unwind()`]: futures::FutureExt::catch_unwind
+
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    cell::RefCell,
+    sync::Arc,
+};
+
+thread_local! {
+    /// Rendered [`Backtrace`] of the last panic on the current thread.
+    static LAST: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+}
+
+/// Records the [`Backtrace`] of the current panic, if capturing is enabled via
+/// `RUST_BACKTRACE` environment variable, or `force`d.
+pub(super) fn record(force: bool) {
+    let backtrace =
+        if force { Backtrace::force_capture() } else { Backtrace::capture() };
+    let rendered = (backtrace.status() == BacktraceStatus::Captured)
+        .then(|| backtrace.to_string().into());
+    LAST.with(|last| *last.borrow_mut() = rendered);
+}
+
+/// Takes the [`Backtrace`] [recorded](record()) for the last panic on the
+/// current thread (if any).
+pub(super) fn take() -> Option<Arc<str>> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{record, take};
+
+    #[test]
+    fn records_forced_backtrace_once() {
+        record(true);
+
+        assert!(take().is_some_and(|bt| !bt.is_empty()));
+        assert!(take().is_none());
+    }
+}
@@ -1,6 +1,6 @@
 //! Basic runner struct and its core implementation methods.
 
-use std::{mem, sync::Arc, time::Duration};
+use std::{any::Any, mem, path::PathBuf, sync::Arc, time::Duration};
 
 #[cfg(feature = "tracing")]
 use crossbeam_utils::atomic::AtomicCell;
@@ -10,12 +10,17 @@ use gherkin::tagexpr::TagOperation;
 use regex::Regex;
 
 use super::cli_and_types::{
-    AfterHookFn, BeforeHookFn, Cli, RetryOptions, RetryOptionsFn, ScenarioType,
-    WhichScenarioFn,
+    AfterHookFn, AfterPanic, BackgroundSnapshotFn, BeforeHookFn, Cli,
+    RetryOptions, RetryOptionsFn, ScenarioType, WhichScenarioFn,
 };
+use super::{sla::Sla, stop::StopSignal};
 #[cfg(feature = "tracing")]
 use crate::tracing::Collector as TracingCollector;
-use crate::{Step, event, step};
+use crate::{
+    Step, event, fixture,
+    runtime::{self, Runtime},
+    step,
+};
 
 /// Default [`crate::runner::Runner`] implementation which follows [_order guarantees_][1] from
 /// the [`crate::runner::Runner`] trait docs.
@@ -94,6 +99,75 @@ pub struct Basic<
     /// Indicates whether execution should be stopped after the first failure.
     pub(super) fail_fast: bool,
 
+    /// Function snapshotting a `World` after running [`Background`]
+    /// [`crate::step::Step`]s of `@cached-background` [`gherkin::Feature`]s
+    /// and [`gherkin::Rule`]s.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`crate::step::Step`]: gherkin::Step
+    #[debug(ignore)]
+    pub(super) background_snapshot: Option<BackgroundSnapshotFn<World>>,
+
+    /// Optional path to the [`RunHistory`] file used for dispatching the
+    /// historically slowest [`gherkin::Scenario`]s first.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    /// [`RunHistory`]: super::RunHistory
+    pub(super) duration_history: Option<PathBuf>,
+
+    /// [`SharedState`] created once per run and provided to every
+    /// [`crate::step::Step`] via its [`Context`].
+    ///
+    /// [`Context`]: step::Context
+    /// [`SharedState`]: step::SharedState
+    #[debug(ignore)]
+    pub(super) shared_state: Option<step::SharedState>,
+
+    /// [`Fixtures`] set up for the [`gherkin::Scenario`]s requesting them via
+    /// `@fixture:<name>` tags.
+    ///
+    /// [`Fixtures`]: fixture::Fixtures
+    pub(super) fixtures: Option<fixture::Fixtures>,
+
+    /// Optional interval of [`event::Step::Heartbeat`]s emitted for
+    /// long-running [`crate::step::Step`]s.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub(super) heartbeat: Option<Duration>,
+
+    /// Optional limit of a single [`crate::step::Step`] execution, after
+    /// which it fails with [`event::StepError::Timeout`].
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub(super) step_timeout: Option<Duration>,
+
+    /// [`Runtime`] measuring step timeouts, heartbeats and retry delays.
+    pub(super) runtime: Arc<dyn Runtime>,
+
+    /// Optional interval of sampling the [`event::ResourceUsage`] during
+    /// [`gherkin::Scenario`]s.
+    pub(super) resource_sampling: Option<Duration>,
+
+    /// Optional [`StopSignal`] requesting a graceful stop of the run.
+    pub(super) stop: Option<StopSignal>,
+
+    /// Optional duration [`Sla`] of [`gherkin::Scenario`]s.
+    pub(super) sla: Option<Sla>,
+
+    /// Indicator whether [`Backtrace`]s of panicking [`crate::step::Step`]s
+    /// should be captured regardless of the `RUST_BACKTRACE` environment
+    /// variable.
+    ///
+    /// [`Backtrace`]: std::backtrace::Backtrace
+    pub(super) force_backtraces: bool,
+
+    /// [`AfterPanic`] behavior regarding the remaining
+    /// [`crate::step::Step`]s of a [`gherkin::Scenario`] after one of them
+    /// fails.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub(super) after_panic: AfterPanic,
+
     #[cfg(feature = "tracing")]
     /// [`TracingCollector`] for [`crate::event::Scenario::Log`]s forwarding.
     #[debug(ignore)]
@@ -131,6 +205,18 @@ impl<World, F: Clone, B: Clone, A: Clone> Clone for Basic<World, F, B, A> {
             before_hook: self.before_hook.clone(),
             after_hook: self.after_hook.clone(),
             fail_fast: self.fail_fast,
+            duration_history: self.duration_history.clone(),
+            shared_state: self.shared_state.clone(),
+            fixtures: self.fixtures.clone(),
+            heartbeat: self.heartbeat,
+            step_timeout: self.step_timeout,
+            runtime: Arc::clone(&self.runtime),
+            resource_sampling: self.resource_sampling,
+            stop: self.stop.clone(),
+            sla: self.sla.clone(),
+            force_backtraces: self.force_backtraces,
+            after_panic: self.after_panic,
+            background_snapshot: self.background_snapshot.clone(),
             #[cfg(feature = "tracing")]
             logs_collector: Arc::clone(&self.logs_collector),
             #[cfg(feature = "observability")]
@@ -155,6 +241,18 @@ impl<World: crate::World, F: Clone, B: Clone, A: Clone> Clone
             before_hook: self.before_hook.clone(),
             after_hook: self.after_hook.clone(),
             fail_fast: self.fail_fast,
+            duration_history: self.duration_history.clone(),
+            shared_state: self.shared_state.clone(),
+            fixtures: self.fixtures.clone(),
+            heartbeat: self.heartbeat,
+            step_timeout: self.step_timeout,
+            runtime: Arc::clone(&self.runtime),
+            resource_sampling: self.resource_sampling,
+            stop: self.stop.clone(),
+            sla: self.sla.clone(),
+            force_backtraces: self.force_backtraces,
+            after_panic: self.after_panic,
+            background_snapshot: self.background_snapshot.clone(),
             #[cfg(feature = "tracing")]
             logs_collector: Arc::clone(&self.logs_collector),
             observers: Arc::clone(&self.observers),
@@ -186,6 +284,18 @@ impl<World> Default for Basic<World> {
             before_hook: None,
             after_hook: None,
             fail_fast: false,
+            duration_history: None,
+            shared_state: None,
+            fixtures: None,
+            heartbeat: None,
+            step_timeout: None,
+            runtime: Arc::new(runtime::Agnostic),
+            resource_sampling: None,
+            stop: None,
+            sla: None,
+            force_backtraces: false,
+            after_panic: AfterPanic::Skip,
+            background_snapshot: None,
             #[cfg(feature = "tracing")]
             logs_collector: Arc::new(AtomicCell::new(Box::new(None))),
             #[cfg(feature = "observability")]
@@ -220,6 +330,18 @@ impl<World: crate::World> Default for Basic<World> {
             before_hook: None,
             after_hook: None,
             fail_fast: false,
+            duration_history: None,
+            shared_state: None,
+            fixtures: None,
+            heartbeat: None,
+            step_timeout: None,
+            runtime: Arc::new(runtime::Agnostic),
+            resource_sampling: None,
+            stop: None,
+            sla: None,
+            force_backtraces: false,
+            after_panic: AfterPanic::Skip,
+            background_snapshot: None,
             #[cfg(feature = "tracing")]
             logs_collector: Arc::new(AtomicCell::new(Box::new(None))),
             observers: Arc::new(std::sync::Mutex::new(
@@ -317,6 +439,18 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook,
             after_hook,
             fail_fast,
+            duration_history,
+            shared_state,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
+            background_snapshot,
             #[cfg(feature = "tracing")]
             logs_collector,
             #[cfg(feature = "observability")]
@@ -334,6 +468,18 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook,
             after_hook,
             fail_fast,
+            duration_history,
+            shared_state,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
+            background_snapshot,
             #[cfg(feature = "tracing")]
             logs_collector,
             #[cfg(feature = "observability")]
@@ -359,6 +505,172 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
         self
     }
 
+    /// Enables caching of [`Background`] [`crate::step::Step`]s for
+    /// [`gherkin::Feature`]s and [`gherkin::Rule`]s tagged with
+    /// `@cached-background`.
+    ///
+    /// [`Background`] is executed only once for such [`gherkin::Feature`] or
+    /// [`gherkin::Rule`], and the resulting `World` is snapshotted with the
+    /// provided `func`, which is also used to restore it for every other
+    /// [`gherkin::Scenario`] instead of re-running the [`Background`].
+    ///
+    /// __NOTE__: Restored `World` replaces the one prepared by the
+    ///           [`before`] hook.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`before`]: Self::before()
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub fn cached_background(
+        mut self,
+        func: impl Fn(&World) -> World + 'static,
+    ) -> Self {
+        self.background_snapshot = Some(Arc::new(func));
+        self
+    }
+
+    /// Makes concurrent [`gherkin::Scenario`]s to be dispatched in the order
+    /// of their historical duration (longest first), using the [`RunHistory`]
+    /// stored at the provided `path`.
+    ///
+    /// The [`RunHistory`] is updated with durations of the current run once
+    /// it's finished.
+    ///
+    /// [`RunHistory`]: super::RunHistory
+    #[must_use]
+    pub fn duration_history(mut self, path: impl Into<PathBuf>) -> Self {
+        self.duration_history = Some(path.into());
+        self
+    }
+
+    /// If `interval` is [`Some`], then every [`crate::step::Step`] running
+    /// longer than it emits an [`event::Step::Heartbeat`], repeated with the
+    /// same `interval` until the [`crate::step::Step`] finishes.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub fn heartbeat(mut self, interval: impl Into<Option<Duration>>) -> Self {
+        self.heartbeat = interval.into();
+        self
+    }
+
+    /// If `timeout` is [`Some`], then every [`crate::step::Step`] running
+    /// longer than it is interrupted and fails with
+    /// [`event::StepError::Timeout`].
+    ///
+    /// A [`gherkin::Scenario`] may override it with a `@timeout(<duration>)`
+    /// tag, like `@timeout(500ms)` or `@timeout(30)` (in seconds).
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub fn step_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.step_timeout = timeout.into();
+        self
+    }
+
+    /// Sets the [`Runtime`] measuring step timeouts, heartbeats and retry
+    /// delays.
+    ///
+    /// [`runtime::Agnostic`] by default, working with any async runtime.
+    #[must_use]
+    pub fn runtime(mut self, runtime: impl Runtime) -> Self {
+        self.runtime = Arc::new(runtime);
+        self
+    }
+
+    /// If `interval` is [`Some`], then the process resources (memory, CPU and
+    /// threads) are sampled every `interval` while running each
+    /// [`gherkin::Scenario`], and summarized in the [`event::ResourceUsage`]
+    /// of its [`event::Scenario::Finished`] event.
+    ///
+    /// Resources are sampled for the whole process, so run
+    /// [`gherkin::Scenario`]s with [`max_concurrent_scenarios()`] of `1` to
+    /// attribute the usage precisely. Sampling is supported on Linux only.
+    ///
+    /// [`max_concurrent_scenarios()`]: Self::max_concurrent_scenarios
+    #[must_use]
+    pub fn resource_sampling(
+        mut self,
+        interval: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.resource_sampling = interval.into();
+        self
+    }
+
+    /// Sets the [`StopSignal`] to stop the run gracefully once
+    /// [requested][`StopSignal::request()`].
+    ///
+    /// No new [`gherkin::Scenario`]s are started after the request, while the
+    /// running ones are finished normally, same as with [`fail_fast()`].
+    ///
+    /// [`fail_fast()`]: Self::fail_fast
+    #[must_use]
+    pub fn stop_signal(mut self, signal: StopSignal) -> Self {
+        self.stop = Some(signal);
+        self
+    }
+
+    /// Enforces the provided duration [`Sla`] of [`gherkin::Scenario`]s,
+    /// emitting an [`event::Scenario::SlaViolated`] for every one running
+    /// longer than the budget of its tags.
+    #[must_use]
+    pub fn sla(mut self, sla: Sla) -> Self {
+        self.sla = Some(sla);
+        self
+    }
+
+    /// Makes [`Backtrace`]s of panicking [`crate::step::Step`]s to be always
+    /// captured into their [`event::Step::Failed`] events, regardless of the
+    /// `RUST_BACKTRACE` environment variable.
+    ///
+    /// [`Backtrace`]: std::backtrace::Backtrace
+    #[must_use]
+    pub const fn force_backtraces(mut self, force: bool) -> Self {
+        self.force_backtraces = force;
+        self
+    }
+
+    /// Sets the [`AfterPanic`] behavior regarding the remaining
+    /// [`crate::step::Step`]s of a [`gherkin::Scenario`] after one of them
+    /// fails.
+    ///
+    /// [`AfterPanic::Skip`] by default.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[must_use]
+    pub const fn after_panic(mut self, behavior: AfterPanic) -> Self {
+        self.after_panic = behavior;
+        self
+    }
+
+    /// Sets the `state` created once per run and shared between all the
+    /// [`crate::step::Step`]s, accessible via [`Context::shared()`].
+    ///
+    /// Intended for read-only configuration, clients and caches, being too
+    /// expensive to build for every [`gherkin::Scenario`] in its `World`.
+    ///
+    /// [`Context::shared()`]: step::Context::shared
+    #[must_use]
+    pub fn shared_state<T: Any + Send + Sync>(mut self, state: T) -> Self {
+        self.shared_state = Some(Arc::new(state));
+        self
+    }
+
+    /// Sets the [`Fixtures`] to be set up for the [`gherkin::Scenario`]s
+    /// requesting them via `@fixture:<name>` tags, accessible via
+    /// [`Context::fixture()`].
+    ///
+    /// [`Context::fixture()`]: step::Context::fixture
+    /// [`Fixtures`]: fixture::Fixtures
+    #[must_use]
+    pub fn fixtures(mut self, fixtures: fixture::Fixtures) -> Self {
+        self.fixtures = Some(fixtures);
+        self
+    }
+
     /// Sets a hook, executed on each [`gherkin::Scenario`] before running all its
     /// [`crate::step::Step`]s, including [`Background`] ones.
     ///
@@ -385,6 +697,18 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             retry_options,
             after_hook,
             fail_fast,
+            duration_history,
+            shared_state,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
+            background_snapshot,
             #[cfg(feature = "tracing")]
             logs_collector,
             #[cfg(feature = "observability")]
@@ -402,6 +726,18 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook: Some(func),
             after_hook,
             fail_fast,
+            duration_history,
+            shared_state,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
+            background_snapshot,
             #[cfg(feature = "tracing")]
             logs_collector,
             #[cfg(feature = "observability")]
@@ -441,6 +777,18 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             retry_options,
             before_hook,
             fail_fast,
+            duration_history,
+            shared_state,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
+            background_snapshot,
             #[cfg(feature = "tracing")]
             logs_collector,
             #[cfg(feature = "observability")]
@@ -458,6 +806,18 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook,
             after_hook: Some(func),
             fail_fast,
+            duration_history,
+            shared_state,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
+            background_snapshot,
             #[cfg(feature = "tracing")]
             logs_collector,
             #[cfg(feature = "observability")]
@@ -534,10 +894,47 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
         World: crate::World,
     {
         if let Ok(mut registry) = self.observers.lock() {
-            registry.register(observer);
+            _ = registry.register(observer);
+        }
+        self
+    }
+
+    /// Registers an observer with the provided `priority`, so it's notified
+    /// before the observers of lower priorities.
+    ///
+    /// See [`ObserverRegistry::register_with_priority()`] for details.
+    ///
+    /// [`ObserverRegistry::register_with_priority()`]: crate::observer::ObserverRegistry::register_with_priority
+    #[cfg(feature = "observability")]
+    #[must_use]
+    pub fn register_observer_with_priority(
+        self,
+        observer: Box<dyn crate::observer::TestObserver<World>>,
+        priority: i32,
+    ) -> Self
+    where
+        World: crate::World,
+    {
+        if let Ok(mut registry) = self.observers.lock() {
+            _ = registry.register_with_priority(observer, priority);
         }
         self
     }
+
+    /// Returns the [`ObserverRegistry`] shared with this runner, allowing to
+    /// register and unregister observers while the run progresses.
+    ///
+    /// __NOTE__: The registry is locked while notifying observers, so it
+    ///           mustn't be accessed from inside an observer.
+    ///
+    /// [`ObserverRegistry`]: crate::observer::ObserverRegistry
+    #[cfg(feature = "observability")]
+    #[must_use]
+    pub fn observer_registry(
+        &self,
+    ) -> Arc<std::sync::Mutex<crate::observer::ObserverRegistry<World>>> {
+        Arc::clone(&self.observers)
+    }
 }
 
 #[cfg(test)]
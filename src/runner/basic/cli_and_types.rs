@@ -1,16 +1,16 @@
 //! CLI options and type definitions for Basic runner.
 
-use std::{
-    sync::Arc,
-    time::{Duration, Instant},
-};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use futures::future::LocalBoxFuture;
 use gherkin::tagexpr::TagOperation;
 
 use crate::{
     event::{self, Retries},
+    scenario::Ext as _,
+    step::ChangedFiles,
     tag::Ext as _,
+    time::Instant,
 };
 
 /// CLI options of a [`Basic`] [`crate::runner::Runner`].
@@ -54,6 +54,124 @@ pub struct Cli {
     /// Tag expression to filter retried scenarios.
     #[arg(long, value_name = "tagexpr", global = true)]
     pub retry_tag_filter: Option<TagOperation>,
+
+    /// Run only scenarios affected by the changed files listed in the
+    /// provided file (either `git diff --name-only` or `git diff` output).
+    #[arg(
+        long,
+        value_name = "path",
+        value_parser = |p: &str| ChangedFiles::from_file(p),
+        global = true,
+    )]
+    pub changed_files: Option<ChangedFiles>,
+
+    /// Path to the run-history file of scenarios durations. If specified,
+    /// historically slowest scenarios are started first, and the file is
+    /// updated with durations of the current run.
+    #[arg(long, value_name = "path", global = true)]
+    pub duration_history: Option<PathBuf>,
+
+    /// Re-run the selected scenarios until any of them fails, reporting the
+    /// failed iteration and its scenarios order. Runs at most the provided
+    /// number of iterations, or unlimited if omitted or `0`.
+    #[arg(
+        long,
+        value_name = "max-iterations",
+        num_args = 0..=1,
+        default_missing_value = "0",
+        global = true
+    )]
+    pub until_failure: Option<usize>,
+
+    /// Seed to shuffle the scenarios of the first `--until-failure` iteration
+    /// with, as reported for the failed one. Random, if omitted.
+    #[arg(long, value_name = "seed", global = true)]
+    pub until_failure_seed: Option<u64>,
+
+    /// Report every step running longer than the provided duration as still
+    /// running, repeating the report with the same interval until the step
+    /// finishes.
+    ///
+    /// Duration is represented in a human-readable format like `30s`.
+    #[arg(
+        long,
+        value_name = "duration",
+        value_parser = humantime::parse_duration,
+        global = true,
+    )]
+    pub heartbeat: Option<Duration>,
+
+    /// Fail every step running longer than the provided duration as timed
+    /// out. Scenarios may override it with a `@timeout(<duration>)` tag.
+    ///
+    /// Duration is represented in a human-readable format like `30s`.
+    #[arg(
+        long,
+        value_name = "duration",
+        value_parser = humantime::parse_duration,
+        global = true,
+    )]
+    pub step_timeout: Option<Duration>,
+
+    /// Sample memory, CPU and threads usage of the process with the provided
+    /// interval while running each scenario.
+    ///
+    /// Duration is represented in a human-readable format like `100ms`.
+    #[arg(
+        long,
+        value_name = "duration",
+        value_parser = humantime::parse_duration,
+        global = true,
+    )]
+    pub sample_resources: Option<Duration>,
+
+    /// Capture backtraces of panicking steps regardless of the
+    /// `RUST_BACKTRACE` environment variable.
+    #[arg(long, global = true)]
+    pub backtrace: bool,
+
+    /// What to do with the remaining steps of a scenario after one of its
+    /// steps fails.
+    #[arg(long, value_name = "skip|continue|not-run", global = true)]
+    pub after_panic: Option<AfterPanic>,
+}
+
+/// Behavior of [`Basic`] regarding the remaining [`crate::step::Step`]s of a
+/// [`gherkin::Scenario`] after one of its [`crate::step::Step`]s fails
+/// (panics).
+///
+/// Whatever the behavior is, the [`gherkin::Scenario`] itself is considered
+/// failed.
+///
+/// [`Basic`]: super::Basic
+/// [`crate::step::Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, clap::ValueEnum)]
+pub enum AfterPanic {
+    /// Skip the remaining [`crate::step::Step`]s, emitting
+    /// [`event::Step::Skipped`] for them.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    #[default]
+    Skip,
+
+    /// Rebuild the [`World`] and continue with the next
+    /// [`crate::step::Step`].
+    ///
+    /// Rebuilding is reported as a [`HookType::Before`] [`HookScope::World`]
+    /// hook, and if it fails, the remaining [`crate::step::Step`]s are
+    /// skipped.
+    ///
+    /// [`HookScope::World`]: event::HookScope::World
+    /// [`HookType::Before`]: event::HookType::Before
+    /// [`World`]: crate::World
+    /// [`crate::step::Step`]: gherkin::Step
+    Continue,
+
+    /// Don't run the remaining [`crate::step::Step`]s, emitting
+    /// [`event::Step::NotRun`] for them.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    NotRun,
 }
 
 /// Type determining whether [`gherkin::Scenario`]s should run concurrently or
@@ -98,8 +216,13 @@ impl RetryOptions {
     /// Parses [`RetryOptions`] from [`Feature`]'s, [`Rule`]'s, [`gherkin::Scenario`]'s
     /// tags and [`Cli`] options.
     ///
+    /// Tags of the [`Examples`] an expanded [`Scenario Outline`] originates
+    /// from take priority over its own ones.
+    ///
+    /// [`Examples`]: gherkin::Examples
     /// [`Feature`]: gherkin::Feature
     /// [`Rule`]: gherkin::Rule
+    /// [`Scenario Outline`]: https://cucumber.io/docs/gherkin/reference#scenario-outline
     /// [`gherkin::Scenario`]: gherkin::Scenario
     #[must_use]
     pub fn parse_from_tags(
@@ -170,7 +293,8 @@ impl RetryOptions {
         };
 
         apply_cli(
-            parse_tags(&scenario.tags)
+            parse_tags(scenario.examples_tags())
+                .or_else(|| parse_tags(&scenario.tags))
                 .or_else(|| rule.and_then(|r| parse_tags(&r.tags)))
                 .or_else(|| parse_tags(&feature.tags)),
         )
@@ -282,6 +406,19 @@ pub type AfterHookFn<World> = for<'a> fn(
     Option<&'a mut World>,
 ) -> LocalBoxFuture<'a, ()>;
 
+/// Alias for [`Arc`]ed [`Fn`] producing a snapshot of a `World`.
+///
+/// The snapshot is taken right after running [`Background`]
+/// [`crate::step::Step`]s, and restored for every [`gherkin::Scenario`] of a
+/// `@cached-background` [`Feature`] or [`Rule`].
+///
+/// [`Background`]: gherkin::Background
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`gherkin::Scenario`]: gherkin::Scenario
+/// [`crate::step::Step`]: gherkin::Step
+pub type BackgroundSnapshotFn<World> = Arc<dyn Fn(&World) -> World>;
+
 #[cfg(test)]
 mod tests {
     use std::thread;
@@ -306,6 +443,15 @@ mod tests {
             retry: Some(3),
             retry_after: Some(Duration::from_secs(2)),
             retry_tag_filter: None, // TagOperation parsing would be complex for test
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
 
         let cloned = cli.clone();
@@ -453,6 +599,31 @@ mod tests {
         assert_eq!(opts.retries.left, 3); // Scenario tag wins
     }
 
+    #[test]
+    fn test_retry_options_parse_from_tags_examples_priority() {
+        use crate::feature::Ext as _;
+
+        let feature = gherkin::Feature::parse(
+            "Feature: F\n\
+             \x20 @retry(1)\n  Scenario Outline: S\n    Given <x>\n\
+             \x20   @retry(4)\n    Examples:\n      | x |\n      | 1 |\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+        .expand_examples()
+        .unwrap();
+
+        let opts = RetryOptions::parse_from_tags(
+            &feature,
+            None,
+            &feature.scenarios[0],
+            &Cli::default(),
+        )
+        .unwrap();
+
+        assert_eq!(opts.retries.left, 4); // `Examples` tag wins
+    }
+
     #[test]
     fn test_retry_options_parse_from_cli() {
         let feature = create_test_feature(vec![]);
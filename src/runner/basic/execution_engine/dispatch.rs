@@ -0,0 +1,197 @@
+//! Helpers of dispatching [`gherkin::Scenario`]s for [`execute()`].
+//!
+//! [`execute()`]: super::execute
+
+use std::ops::ControlFlow;
+
+use futures::{
+    FutureExt as _, StreamExt as _,
+    future::{self, LocalBoxFuture},
+    stream,
+};
+
+use super::super::{
+    executor::Executor,
+    scenario_storage::{Features, FinishedRulesAndFeatures, Runnable},
+    stop::StopSignal,
+    tracker::Tracker,
+};
+#[cfg(feature = "tracing")]
+use crate::tracing::{Collector as TracingCollector, SpanCloseWaiter};
+use crate::{World, event, future::FutureExt as _, runtime::Runtime};
+
+/// Starts running the provided `runnable` [`gherkin::Scenario`]s, pushing them
+/// into the `running` ones.
+pub(super) fn start_scenarios<'e, W, Before, After>(
+    executor: &'e Executor<W, Before, After>,
+    runnable: Vec<Runnable>,
+    running: &stream::FuturesUnordered<LocalBoxFuture<'e, ()>>,
+    tracker: &mut Tracker,
+    #[cfg(feature = "tracing")] waiter: Option<&'e SpanCloseWaiter>,
+) where
+    W: World,
+    Before: for<'a> Fn(
+        &'a gherkin::Feature,
+        Option<&'a gherkin::Rule>,
+        &'a gherkin::Scenario,
+        &'a mut W,
+    ) -> LocalBoxFuture<'a, ()>,
+    After: for<'a> Fn(
+        &'a gherkin::Feature,
+        Option<&'a gherkin::Rule>,
+        &'a gherkin::Scenario,
+        &'a event::ScenarioFinished,
+        Option<&'a mut W>,
+    ) -> LocalBoxFuture<'a, ()>,
+{
+    for (id, f, r, s, ty, retry_options) in runnable {
+        tracker.scenario_started(id, &f, &s);
+        running.push(
+            executor
+                .run_scenario(
+                    id,
+                    f,
+                    r,
+                    s,
+                    ty,
+                    retry_options,
+                    #[cfg(feature = "tracing")]
+                    waiter,
+                )
+                .then_yield()
+                .boxed_local(),
+        );
+    }
+}
+
+/// Forwards the logs emitted by the running [`gherkin::Scenario`]s into the
+/// provided `send` function, never resolving (so may be typed as any `Out`).
+#[cfg(feature = "tracing")]
+#[expect(clippy::infinite_loop, reason = "never resolves intentionally")]
+pub(super) async fn forward_logs<W, Out>(
+    mut logs_collector: Option<&mut TracingCollector>,
+    send: impl Fn(Vec<event::Cucumber<W>>),
+) -> Out {
+    loop {
+        while let Some(logs) = logs_collector
+            .as_deref_mut()
+            .and_then(TracingCollector::emitted_logs)
+        {
+            send(logs);
+        }
+        future::ready(()).then_yield().await;
+    }
+}
+
+/// Handles all the [`gherkin::Scenario`]s finished so far, emitting the
+/// finishing events of their [`Rule`]s and [`Feature`]s, if they were the last
+/// ones.
+///
+/// Returns `true` if any of them has failed without being retried.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+pub(super) async fn finish_scenarios<W, Before, After>(
+    executor: &Executor<W, Before, After>,
+    storage: &mut FinishedRulesAndFeatures,
+    features: &Features,
+    tracker: &mut Tracker,
+    #[cfg(feature = "tracing")] mut logs_collector: Option<
+        &mut TracingCollector,
+    >,
+) -> bool
+where
+    W: World,
+    Before: for<'a> Fn(
+        &'a gherkin::Feature,
+        Option<&'a gherkin::Rule>,
+        &'a gherkin::Scenario,
+        &'a mut W,
+    ) -> LocalBoxFuture<'a, ()>,
+    After: for<'a> Fn(
+        &'a gherkin::Feature,
+        Option<&'a gherkin::Rule>,
+        &'a gherkin::Scenario,
+        &'a event::ScenarioFinished,
+        Option<&'a mut W>,
+    ) -> LocalBoxFuture<'a, ()>,
+{
+    let mut any_failed = false;
+    while let Some(Some((id, feat, rule, scenario_failed, retried))) =
+        storage.finished_receiver_mut().next().now_or_never()
+    {
+        let failed = scenario_failed && !retried;
+        any_failed |= failed;
+
+        features.scenario_finished(id).await;
+        tracker.scenario_finished(id, &feat, failed);
+        if let Some(rule) = rule {
+            if let Some(f) =
+                storage.rule_scenario_finished::<W>(feat.clone(), rule, retried)
+            {
+                executor.send_event(f);
+            }
+        }
+        if let Some(f) =
+            storage.feature_scenario_finished(feat.clone(), retried)
+        {
+            executor.finish_feature_fixtures(&feat).await;
+            executor.finish_feature_background(&feat);
+            executor.send_event(f);
+        }
+        #[cfg(feature = "tracing")]
+        if let Some(coll) = logs_collector.as_mut() {
+            coll.finish_scenario(id);
+        }
+    }
+    any_failed
+}
+
+/// Returns the next [`gherkin::Scenario`]s to run, waiting for them if there
+/// are no `running` ones, and starting the next iteration of the
+/// run-until-failure mode, once the current one is finished.
+///
+/// Returns [`ControlFlow::Break`] once the whole run is finished.
+pub(super) async fn next_scenarios(
+    features: &Features,
+    tracker: &mut Tracker,
+    started_scenarios: &mut ControlFlow<(), Option<usize>>,
+    stop: Option<&StopSignal>,
+    running: bool,
+    runtime: &dyn Runtime,
+) -> ControlFlow<(), Vec<Runnable>> {
+    loop {
+        let stopped = stop.is_some_and(StopSignal::is_requested);
+        if stopped {
+            *started_scenarios = ControlFlow::Break(());
+        }
+
+        let (runnable, sleep) = features
+            .get(started_scenarios.continue_value().unwrap_or(Some(0)))
+            .await;
+        if running || !runnable.is_empty() {
+            return ControlFlow::Continue(runnable);
+        }
+
+        if features.is_finished(started_scenarios.is_break()).await {
+            let seed = if stopped { None } else { tracker.next_iteration() };
+            let Some(seed) = seed else {
+                return ControlFlow::Break(());
+            };
+            features.reinsert_recorded(seed).await;
+            continue;
+        }
+
+        // To avoid busy-polling of `Features::get()`, in case there are no
+        // scenarios that are running or scheduled for execution, we sleep for
+        // the minimal deadline of all retried scenarios.
+        if let Some(dur) = sleep {
+            runtime.sleep(dur).await;
+        } else {
+            // Give the `Feature`s insertion a chance to progress, as
+            // `Scenario`s may be held back until it's finished (see
+            // `Features::get()`).
+            future::ready(()).then_yield().await;
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Execution orchestration and feature insertion logic.
+
+mod dispatch;
+#[cfg(test)]
+mod tests;
+
+use std::{ops::ControlFlow, panic, sync::Arc, time::Duration};
+
+use futures::{
+    Stream, StreamExt as _,
+    channel::mpsc,
+    future::{self, LocalBoxFuture},
+    pin_mut, stream,
+};
+
+#[cfg(feature = "tracing")]
+use self::dispatch::forward_logs;
+use self::dispatch::{finish_scenarios, next_scenarios, start_scenarios};
+use super::{
+    backtrace,
+    cli_and_types::{
+        AfterPanic, BackgroundSnapshotFn, Cli, RetryOptionsFn, ScenarioType,
+    },
+    executor::{BackgroundCache, Executor},
+    history::RunHistory,
+    scenario_storage::{Features, FinishedRulesAndFeatures},
+    sla::Sla,
+    stop::StopSignal,
+    tracker::Tracker,
+    until_failure::UntilFailure,
+};
+#[cfg(feature = "tracing")]
+use crate::tracing::Collector as TracingCollector;
+use crate::{
+    Event, World, event,
+    feature::Ext as _,
+    fixture,
+    future::{FutureExt as _, select_with_biased_first},
+    parser,
+    runtime::Runtime,
+    step,
+};
+
+/// Stores [`Feature`]s for later use by [`execute()`].
+///
+/// [`Feature`]: gherkin::Feature
+pub(super) async fn insert_features<W, S, F>(
+    into: Features,
+    features_stream: S,
+    which_scenario: F,
+    retries: RetryOptionsFn,
+    sender: mpsc::UnboundedSender<parser::Result<Event<event::Cucumber<W>>>>,
+    cli: Cli,
+    fail_fast: bool,
+) where
+    S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
+    F: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> ScenarioType
+        + 'static,
+{
+    let mut features = 0;
+    let mut rules = 0;
+    let mut scenarios = 0;
+    let mut steps = 0;
+    let mut parser_errors = 0;
+
+    pin_mut!(features_stream);
+    while let Some(feat) = features_stream.next().await {
+        match feat {
+            Ok(f) => {
+                features += 1;
+                rules += f.rules.len();
+                scenarios += f.count_scenarios();
+                steps += f.count_steps();
+
+                into.insert(f, &which_scenario, &retries, &cli).await;
+            }
+            Err(e) => {
+                parser_errors += 1;
+
+                // If the receiver end is dropped, then no one listens for the
+                // events, so we can just stop from here.
+                if sender.unbounded_send(Err(e)).is_err() || fail_fast {
+                    break;
+                }
+            }
+        }
+
+        // Yield, so the already inserted `Feature`s start executing while the
+        // remaining ones are still being parsed.
+        future::ready(()).then_yield().await;
+    }
+
+    drop(sender.unbounded_send(Ok(Event::new(
+        event::Cucumber::ParsingFinished {
+            features,
+            rules,
+            scenarios,
+            steps,
+            parser_errors,
+        },
+    ))));
+
+    into.finish();
+}
+
+/// Retrieves [`Feature`]s and executes them.
+///
+/// # Events
+///
+/// - [`gherkin::Scenario`] events are emitted by [`Executor`].
+/// - If [`gherkin::Scenario`] was first or last for particular [`Rule`] or [`Feature`],
+///   emits starting or finishing events for them.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`gherkin::Scenario`]: gherkin::Scenario
+#[expect(clippy::too_many_arguments, reason = "needs refactoring")]
+pub(super) async fn execute<W, Before, After>(
+    features: Features,
+    max_concurrent_scenarios: Option<usize>,
+    collection: step::Collection<W>,
+    event_sender: mpsc::UnboundedSender<
+        parser::Result<Event<event::Cucumber<W>>>,
+    >,
+    before_hook: Option<Before>,
+    after_hook: Option<After>,
+    fail_fast: bool,
+    background_snapshot: Option<BackgroundSnapshotFn<W>>,
+    history: Option<Arc<RunHistory>>,
+    until_failure: Option<UntilFailure>,
+    fixtures: Option<fixture::Fixtures>,
+    heartbeat: Option<Duration>,
+    step_timeout: Option<Duration>,
+    runtime: Arc<dyn Runtime>,
+    resource_sampling: Option<Duration>,
+    stop: Option<StopSignal>,
+    sla: Option<Sla>,
+    force_backtraces: bool,
+    after_panic: AfterPanic,
+    #[cfg(feature = "tracing")] mut logs_collector: Option<TracingCollector>,
+    #[cfg(feature = "observability")] observers: std::sync::Arc<
+        std::sync::Mutex<crate::observer::ObserverRegistry<W>>,
+    >,
+) where
+    W: World,
+    Before: 'static
+        + for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>,
+    After: 'static
+        + for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>,
+{
+    // Those panic hook shenanigans are done to avoid console messages like
+    // "thread 'main' panicked at ..."
+    //
+    // 1. We obtain the current panic hook and replace it with an empty one.
+    // 2. We run tests, which can panic. In that case we pass all panic info
+    //    down the line to the Writer, which will print it at a right time.
+    // 3. We restore original panic hook, because suppressing all panics doesn't
+    //    sound like a very good idea.
+    //
+    // The empty hook still records the `Backtrace` of the panic, so it can be
+    // attached to the failed step.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |_| backtrace::record(force_backtraces)));
+
+    let (finished_sender, finished_receiver) = mpsc::unbounded();
+    let mut storage = FinishedRulesAndFeatures::new(finished_receiver);
+    let executor = Executor::new(
+        collection,
+        before_hook,
+        after_hook,
+        event_sender,
+        finished_sender,
+        features.clone(),
+        #[cfg(feature = "observability")]
+        observers,
+    )
+    .with_background_cache(background_snapshot.map(BackgroundCache::new))
+    .with_fixtures(fixtures.map(fixture::Runtime::new))
+    .with_heartbeat(heartbeat)
+    .with_step_timeout(step_timeout)
+    .with_runtime(Arc::clone(&runtime))
+    .with_after_panic(after_panic)
+    .with_resource_sampling(resource_sampling)
+    .with_sla(sla);
+
+    executor.send_event(event::Cucumber::Started);
+
+    #[cfg(feature = "tracing")]
+    let waiter = logs_collector
+        .as_ref()
+        .map(TracingCollector::scenario_span_event_waiter);
+
+    let mut started_scenarios = ControlFlow::Continue(max_concurrent_scenarios);
+    let mut run_scenarios = stream::FuturesUnordered::new();
+    let mut tracker = Tracker::new(history, until_failure);
+    loop {
+        let next = next_scenarios(
+            &features,
+            &mut tracker,
+            &mut started_scenarios,
+            stop.as_ref(),
+            !run_scenarios.is_empty(),
+            &*runtime,
+        );
+        let ControlFlow::Continue(runnable) = next.await else {
+            break;
+        };
+
+        let started = storage.start_scenarios(&runnable);
+        executor.send_all_events(started);
+
+        {
+            #[cfg(feature = "tracing")]
+            if let Some(coll) = logs_collector.as_mut() {
+                coll.start_scenarios(&runnable);
+            }
+            #[cfg(feature = "tracing")]
+            let forward_logs = forward_logs(logs_collector.as_mut(), |logs| {
+                executor.send_all_events(logs);
+            });
+            #[cfg(feature = "tracing")]
+            pin_mut!(forward_logs);
+            #[cfg(not(feature = "tracing"))]
+            let forward_logs = future::pending();
+
+            if let ControlFlow::Continue(Some(sc)) = &mut started_scenarios {
+                *sc -= runnable.len();
+            }
+            start_scenarios(
+                &executor,
+                runnable,
+                &run_scenarios,
+                &mut tracker,
+                #[cfg(feature = "tracing")]
+                waiter.as_ref(),
+            );
+
+            let (finished_scenario, _) =
+                select_with_biased_first(forward_logs, run_scenarios.next())
+                    .await
+                    .factor_first();
+            if finished_scenario.is_some() {
+                if let ControlFlow::Continue(Some(sc)) = &mut started_scenarios
+                {
+                    *sc += 1;
+                }
+            }
+        }
+
+        let failed = finish_scenarios(
+            &executor,
+            &mut storage,
+            &features,
+            &mut tracker,
+            #[cfg(feature = "tracing")]
+            logs_collector.as_mut(),
+        );
+        if failed.await && fail_fast {
+            started_scenarios = ControlFlow::Break(());
+        }
+    }
+
+    // This is done in case of `fail_fast: true` or a requested stop, when not
+    // all `Scenario`s might be executed.
+    executor.send_all_events(storage.finish_all_rules_and_features());
+    executor.finish_fixtures().await;
+
+    for report in tracker.finish() {
+        executor.send_event(event::Cucumber::Report(report));
+    }
+    executor.send_event(event::Cucumber::Finished);
+
+    panic::set_hook(hook);
+}
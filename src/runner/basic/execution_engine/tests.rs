@@ -0,0 +1,345 @@
+//! Tests of the execution orchestration and feature insertion logic.
+
+use std::sync::{Arc, Mutex};
+
+use futures::stream;
+
+use super::*;
+use crate::{
+    runner::basic::RetryOptions, runtime::Agnostic,
+    test_utils::common::TestWorld,
+};
+
+// Using common TestWorld from test_utils
+
+#[tokio::test]
+async fn test_insert_features_empty_stream() {
+    let features = Features::default();
+    let (sender, mut receiver) = mpsc::unbounded();
+    let cli = Cli::default();
+
+    let which_scenario =
+        |_: &gherkin::Feature,
+         _: Option<&gherkin::Rule>,
+         _: &gherkin::Scenario| { ScenarioType::Concurrent };
+
+    let retry_fn = Arc::new(
+        |_: &gherkin::Feature,
+         _: Option<&gherkin::Rule>,
+         _: &gherkin::Scenario,
+         _: &Cli|
+         -> Option<RetryOptions> { None },
+    );
+
+    insert_features(
+        features.clone(),
+        stream::empty(),
+        which_scenario,
+        retry_fn,
+        sender,
+        cli,
+        false,
+    )
+    .await;
+
+    // Should receive ParsingFinished event
+    let event: event::Event<event::Cucumber<TestWorld>> =
+        receiver.next().await.unwrap().unwrap();
+    match event.value {
+        event::Cucumber::ParsingFinished {
+            features,
+            rules,
+            scenarios,
+            steps,
+            parser_errors,
+        } => {
+            assert_eq!(features, 0);
+            assert_eq!(rules, 0);
+            assert_eq!(scenarios, 0);
+            assert_eq!(steps, 0);
+            assert_eq!(parser_errors, 0);
+        }
+        _ => panic!("Expected ParsingFinished event"),
+    }
+
+    // No more events
+    assert!(receiver.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_insert_features_with_error() {
+    let features = Features::default();
+    let (sender, mut receiver) = mpsc::unbounded();
+    let cli = Cli::default();
+
+    let which_scenario =
+        |_: &gherkin::Feature,
+         _: Option<&gherkin::Rule>,
+         _: &gherkin::Scenario| { ScenarioType::Concurrent };
+
+    let retry_fn = Arc::new(
+        |_: &gherkin::Feature,
+         _: Option<&gherkin::Rule>,
+         _: &gherkin::Scenario,
+         _: &Cli|
+         -> Option<RetryOptions> { None },
+    );
+
+    let error_stream = stream::once(async {
+        Err(parser::Error::Parsing(std::sync::Arc::new(
+            gherkin::ParseFileError::Reading {
+                path: std::path::PathBuf::from("test.feature"),
+                source: std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "Test file not found",
+                ),
+            },
+        )))
+    });
+
+    insert_features(
+        features.clone(),
+        error_stream,
+        which_scenario,
+        retry_fn,
+        sender,
+        cli,
+        false,
+    )
+    .await;
+
+    // Should receive error first
+    let error_event = receiver.next().await.unwrap();
+    assert!(error_event.is_err());
+
+    // Then ParsingFinished event
+    let event: Event<event::Cucumber<TestWorld>> =
+        receiver.next().await.unwrap().unwrap();
+    match event.value {
+        event::Cucumber::ParsingFinished { parser_errors, .. } => {
+            assert_eq!(parser_errors, 1);
+        }
+        _ => panic!("Expected ParsingFinished event"),
+    }
+}
+
+#[tokio::test]
+async fn test_insert_features_yields_between_features() {
+    let features = Features::default();
+    let (sender, mut receiver) =
+        mpsc::unbounded::<parser::Result<Event<event::Cucumber<TestWorld>>>>();
+
+    let feature = gherkin::Feature::parse(
+        "Feature: F\n  Scenario: S\n    Given a step\n",
+        gherkin::GherkinEnv::default(),
+    )
+    .unwrap();
+    let error =
+        parser::Error::Parsing(Arc::new(gherkin::ParseFileError::Reading {
+            path: "late.feature".into(),
+            source: std::io::Error::other("late"),
+        }));
+
+    let insert = insert_features(
+        features.clone(),
+        stream::iter([Ok(feature), Err(error)]),
+        |_: &gherkin::Feature,
+         _: Option<&gherkin::Rule>,
+         _: &gherkin::Scenario| ScenarioType::Concurrent,
+        Arc::new(|_: &_, _: Option<&_>, _: &_, _: &_| None),
+        sender,
+        Cli::default(),
+        false,
+    );
+    pin_mut!(insert);
+
+    // The first `Feature` is available before the second one is parsed.
+    assert!(futures::poll!(insert.as_mut()).is_pending());
+    assert!(!features.is_finished(false).await);
+    assert!(receiver.try_next().is_err(), "no events before yielding");
+
+    insert.await;
+
+    assert!(receiver.next().await.unwrap().is_err());
+    assert!(matches!(
+        receiver.next().await.unwrap().unwrap().value,
+        event::Cucumber::ParsingFinished { features: 1, parser_errors: 1, .. },
+    ));
+}
+
+#[tokio::test]
+async fn test_execute_with_empty_features() {
+    let features = Features::default();
+    features.finish(); // Mark as finished
+
+    let (sender, mut receiver) = mpsc::unbounded();
+    let collection = step::Collection::<TestWorld>::new();
+
+    execute(
+        features,
+        Some(1),
+        collection,
+        sender,
+        None::<
+            for<'a> fn(
+                &'a gherkin::Feature,
+                Option<&'a gherkin::Rule>,
+                &'a gherkin::Scenario,
+                &'a mut TestWorld,
+            ) -> LocalBoxFuture<'a, ()>,
+        >,
+        None::<
+            for<'a> fn(
+                &'a gherkin::Feature,
+                Option<&'a gherkin::Rule>,
+                &'a gherkin::Scenario,
+                &'a event::ScenarioFinished,
+                Option<&'a mut TestWorld>,
+            ) -> LocalBoxFuture<'a, ()>,
+        >,
+        false,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Arc::new(Agnostic),
+        None,
+        None,
+        None,
+        false,
+        AfterPanic::Skip,
+        #[cfg(feature = "tracing")]
+        None,
+        #[cfg(feature = "observability")]
+        std::sync::Arc::new(std::sync::Mutex::new(
+            crate::observer::ObserverRegistry::new(),
+        )),
+    )
+    .await;
+
+    // Should receive Started event
+    let started = receiver.next().await.unwrap().unwrap();
+    match started.value {
+        event::Cucumber::Started => {}
+        _ => panic!("Expected Started event"),
+    }
+
+    // Should receive Finished event
+    let finished = receiver.next().await.unwrap().unwrap();
+    match finished.value {
+        event::Cucumber::Finished => {}
+        _ => panic!("Expected Finished event"),
+    }
+
+    // No more events
+    assert!(receiver.next().await.is_none());
+}
+
+#[test]
+fn test_scenario_type_determination() {
+    let which_scenario =
+        |_: &gherkin::Feature,
+         _: Option<&gherkin::Rule>,
+         scenario: &gherkin::Scenario| {
+            if scenario.tags.contains(&"@serial".to_string()) {
+                ScenarioType::Serial
+            } else {
+                ScenarioType::Concurrent
+            }
+        };
+
+    let feature = gherkin::Feature {
+        tags: vec![],
+        keyword: "Feature".to_string(),
+        name: "Test".to_string(),
+        span: gherkin::Span { start: 0, end: 0 },
+        position: gherkin::LineCol { line: 1, col: 1 },
+        path: None,
+        description: None,
+        background: None,
+        scenarios: vec![],
+        rules: vec![],
+    };
+
+    let concurrent_scenario = gherkin::Scenario {
+        tags: vec![],
+        keyword: "Scenario".to_string(),
+        name: "Concurrent".to_string(),
+        span: gherkin::Span { start: 0, end: 0 },
+        position: gherkin::LineCol { line: 1, col: 1 },
+        description: None,
+        steps: vec![],
+        examples: vec![],
+    };
+
+    let serial_scenario = gherkin::Scenario {
+        tags: vec!["@serial".to_string()],
+        keyword: "Scenario".to_string(),
+        name: "Serial".to_string(),
+        span: gherkin::Span { start: 0, end: 0 },
+        position: gherkin::LineCol { line: 1, col: 1 },
+        description: None,
+        steps: vec![],
+        examples: vec![],
+    };
+
+    assert_eq!(
+        which_scenario(&feature, None, &concurrent_scenario),
+        ScenarioType::Concurrent
+    );
+    assert_eq!(
+        which_scenario(&feature, None, &serial_scenario),
+        ScenarioType::Serial
+    );
+}
+
+#[tokio::test]
+async fn test_thread_safe_execution_engine() {
+    // Test thread-safe execution using Mutex for concurrent access
+    let execution_count = Arc::new(Mutex::new(0));
+    let execution_count_clone = Arc::clone(&execution_count);
+
+    // Simulate concurrent execution scenario
+    let handles = (0..5)
+        .map(|_| {
+            let count = Arc::clone(&execution_count);
+            tokio::spawn(async move {
+                // Simulate execution engine work
+                let mut counter = count.lock().unwrap();
+                *counter += 1;
+                drop(counter);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Wait for all tasks to complete
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    // Verify thread-safe execution
+    let final_count = *execution_count_clone.lock().unwrap();
+    assert_eq!(final_count, 5);
+}
+
+#[test]
+fn test_execution_engine_state_sharing() {
+    // Test Mutex-protected state sharing in execution engine context
+    let shared_state = Arc::new(Mutex::new(Vec::<String>::new()));
+    let shared_clone = Arc::clone(&shared_state);
+
+    // Simulate adding execution results
+    {
+        let mut state = shared_state.lock().unwrap();
+        state.push("execution_1".to_string());
+        state.push("execution_2".to_string());
+    }
+
+    // Verify state consistency
+    let final_state = shared_clone.lock().unwrap();
+    assert_eq!(final_state.len(), 2);
+    assert_eq!(final_state[0], "execution_1");
+    assert_eq!(final_state[1], "execution_2");
+}
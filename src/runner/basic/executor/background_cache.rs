@@ -0,0 +1,291 @@
+//! Caching of [`Background`] execution results for the Basic executor.
+//!
+//! [`Background`]: gherkin::Background
+
+use std::{cell::RefCell, collections::HashMap, convert::Infallible};
+
+use futures::channel::oneshot;
+
+use super::super::cli_and_types::BackgroundSnapshotFn;
+use crate::{
+    World,
+    event::{self, source::Source},
+};
+
+/// Tag enabling [`Background`] caching on a [`gherkin::Feature`] or a
+/// [`gherkin::Rule`].
+///
+/// [`Background`]: gherkin::Background
+pub(crate) const CACHED_BACKGROUND_TAG: &str = "cached-background";
+
+/// Key of a cached [`World`] snapshot.
+type SnapshotKey = (Source<gherkin::Feature>, Option<Source<gherkin::Rule>>);
+
+/// Snapshotted [`World`] along with the results of its [`Background`]
+/// [`gherkin::Step`]s.
+///
+/// [`Background`]: gherkin::Background
+type Snapshot<W> = (W, Vec<event::Step<W>>);
+
+/// State of a [`Snapshot`] in a [`BackgroundCache`].
+enum Slot<W> {
+    /// [`Background`] is being executed by a [`Flight`], notifying the
+    /// waiting [`gherkin::Scenario`]s once it's done by dropping the senders.
+    ///
+    /// [`Background`]: gherkin::Background
+    Running(Vec<oneshot::Sender<Infallible>>),
+
+    /// [`Snapshot`] is ready to be restored.
+    Ready(Snapshot<W>),
+}
+
+/// Cache of [`World`]s snapshotted right after executing [`Background`]
+/// [`gherkin::Step`]s.
+///
+/// Only a single [`gherkin::Scenario`] executes a [`Background`] at a time,
+/// while the concurrent ones wait for its [`Snapshot`].
+///
+/// [`Background`]: gherkin::Background
+pub(crate) struct BackgroundCache<W> {
+    /// Function producing a [`World`] snapshot.
+    snapshot: BackgroundSnapshotFn<W>,
+
+    /// Snapshotted [`World`]s.
+    worlds: RefCell<HashMap<SnapshotKey, Slot<W>>>,
+}
+
+/// Result of [`BackgroundCache::acquire()`].
+pub(crate) enum Acquired<'c, W> {
+    /// [`World`] restored from a [`Snapshot`] along with the results of its
+    /// [`Background`] [`gherkin::Step`]s.
+    ///
+    /// [`Background`]: gherkin::Background
+    Restored(Snapshot<W>),
+
+    /// [`Flight`] to execute the [`Background`] and store its [`Snapshot`]
+    /// with.
+    ///
+    /// [`Background`]: gherkin::Background
+    Flight(Flight<'c, W>),
+}
+
+/// Exclusive execution of a [`Background`], storing its [`Snapshot`] into a
+/// [`BackgroundCache`].
+///
+/// Once dropped without storing, lets another [`gherkin::Scenario`] execute
+/// the [`Background`] instead.
+///
+/// [`Background`]: gherkin::Background
+pub(crate) struct Flight<'c, W> {
+    /// [`BackgroundCache`] to store the [`Snapshot`] into.
+    cache: &'c BackgroundCache<W>,
+
+    /// Key of the [`Snapshot`] to store.
+    key: SnapshotKey,
+}
+
+impl<W: World> BackgroundCache<W> {
+    /// Creates a new empty [`BackgroundCache`].
+    pub(crate) fn new(snapshot: BackgroundSnapshotFn<W>) -> Self {
+        Self { snapshot, worlds: RefCell::new(HashMap::new()) }
+    }
+
+    /// Indicates whether [`Background`] caching is enabled for the provided
+    /// [`gherkin::Feature`] and [`gherkin::Rule`].
+    ///
+    /// [`Background`]: gherkin::Background
+    pub(crate) fn is_enabled(
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+    ) -> bool {
+        let has_background = feature.background.is_some()
+            || rule.is_some_and(|r| r.background.is_some());
+
+        has_background
+            && feature
+                .tags
+                .iter()
+                .chain(rule.iter().flat_map(|r| &r.tags))
+                .any(|t| t == CACHED_BACKGROUND_TAG)
+    }
+
+    /// Restores a [`World`] snapshotted for the provided [`gherkin::Feature`]
+    /// and [`gherkin::Rule`] along with the results of its [`Background`]
+    /// [`gherkin::Step`]s, or returns a [`Flight`] to execute them, if there
+    /// is no [`Snapshot`] yet.
+    ///
+    /// Waits for the [`Flight`] of a concurrent [`gherkin::Scenario`] to
+    /// finish, if there is one.
+    ///
+    /// [`Background`]: gherkin::Background
+    pub(crate) async fn acquire(
+        &self,
+        feature: &Source<gherkin::Feature>,
+        rule: Option<&Source<gherkin::Rule>>,
+    ) -> Acquired<'_, W> {
+        let key = (feature.clone(), rule.cloned());
+        loop {
+            let done = match self.worlds.borrow_mut().get_mut(&key) {
+                Some(Slot::Ready((w, steps))) => {
+                    return Acquired::Restored((
+                        (self.snapshot)(w),
+                        steps.clone(),
+                    ));
+                }
+                Some(Slot::Running(waiters)) => {
+                    let (notify, done) = oneshot::channel();
+                    waiters.push(notify);
+                    done
+                }
+                None => break,
+            };
+            _ = done.await;
+        }
+
+        drop(
+            self.worlds.borrow_mut().insert(key.clone(), Slot::Running(vec![])),
+        );
+        Acquired::Flight(Flight { cache: self, key })
+    }
+
+    /// Removes all the [`Snapshot`]s of the provided finished
+    /// [`gherkin::Feature`] (including the ones of its [`gherkin::Rule`]s).
+    pub(crate) fn clear_feature(&self, feature: &Source<gherkin::Feature>) {
+        self.worlds.borrow_mut().retain(|(f, _), _| f != feature);
+    }
+}
+
+impl<W> Flight<'_, W> {
+    /// Stores a snapshot of the provided [`World`] along with the results of
+    /// its [`Background`] [`gherkin::Step`]s, notifying the waiting
+    /// [`gherkin::Scenario`]s.
+    ///
+    /// [`Background`]: gherkin::Background
+    pub(crate) fn store(self, world: &W, steps: Vec<event::Step<W>>) {
+        let snapshot = ((self.cache.snapshot)(world), steps);
+        drop(
+            self.cache
+                .worlds
+                .borrow_mut()
+                .insert(self.key.clone(), Slot::Ready(snapshot)),
+        );
+    }
+}
+
+impl<W> Drop for Flight<'_, W> {
+    fn drop(&mut self) {
+        let mut worlds = self.cache.worlds.borrow_mut();
+        if matches!(worlds.get(&self.key), Some(Slot::Running(_))) {
+            // Dropping the `Slot::Running` notifies the waiters.
+            drop(worlds.remove(&self.key));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::{pin_mut, poll};
+
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Counter(usize);
+
+    impl World for Counter {
+        type Error = std::convert::Infallible;
+
+        async fn new() -> Result<Self, Self::Error> {
+            Ok(Self::default())
+        }
+    }
+
+    fn feature(tags: Vec<String>, background: bool) -> gherkin::Feature {
+        let mut src = String::from("Feature: F\n");
+        if background {
+            src.push_str("  Background:\n    Given a step\n");
+        }
+        src.push_str("  Scenario: S\n    Given a step\n");
+        let mut feature =
+            gherkin::Feature::parse(src, gherkin::GherkinEnv::default())
+                .unwrap();
+        feature.tags = tags;
+        feature
+    }
+
+    #[test]
+    fn enabled_only_with_tag_and_background() {
+        let tag = vec![CACHED_BACKGROUND_TAG.to_owned()];
+
+        assert!(BackgroundCache::<Counter>::is_enabled(
+            &feature(tag.clone(), true),
+            None,
+        ));
+        assert!(!BackgroundCache::<Counter>::is_enabled(
+            &feature(tag, false),
+            None,
+        ));
+        assert!(!BackgroundCache::<Counter>::is_enabled(
+            &feature(vec![], true),
+            None,
+        ));
+    }
+
+    #[tokio::test]
+    async fn restores_stored_snapshot() {
+        let cache = BackgroundCache::new(Arc::new(Counter::clone));
+        let feature = Source::new(feature(vec![], true));
+
+        let Acquired::Flight(flight) = cache.acquire(&feature, None).await
+        else {
+            panic!("expected no snapshot");
+        };
+        let waiter = cache.acquire(&feature, None);
+        pin_mut!(waiter);
+        assert!(poll!(waiter.as_mut()).is_pending(), "single flight");
+
+        flight.store(&Counter(1), vec![event::Step::Skipped(None)]);
+
+        let Acquired::Restored((world, steps)) = waiter.await else {
+            panic!("expected stored snapshot");
+        };
+        assert_eq!(world, Counter(1));
+        assert_eq!(steps.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn hands_over_abandoned_flight() {
+        let cache = BackgroundCache::new(Arc::new(Counter::clone));
+        let feature = Source::new(feature(vec![], true));
+
+        let flight = cache.acquire(&feature, None).await;
+        let waiter = cache.acquire(&feature, None);
+        pin_mut!(waiter);
+        assert!(poll!(waiter.as_mut()).is_pending(), "single flight");
+
+        drop(flight);
+
+        assert!(matches!(waiter.await, Acquired::Flight(_)));
+    }
+
+    #[tokio::test]
+    async fn clears_snapshots_of_finished_feature() {
+        let cache = BackgroundCache::new(Arc::new(Counter::clone));
+        let feature = Source::new(feature(vec![], true));
+        if let Acquired::Flight(flight) = cache.acquire(&feature, None).await {
+            flight.store(&Counter(1), vec![]);
+        }
+        assert!(matches!(
+            cache.acquire(&feature, None).await,
+            Acquired::Restored(_),
+        ));
+
+        cache.clear_feature(&feature);
+
+        assert!(matches!(
+            cache.acquire(&feature, None).await,
+            Acquired::Flight(_),
+        ));
+    }
+}
@@ -1,26 +1,36 @@
 //! Core Executor struct and main scenario execution logic.
 
+use std::{cell::RefCell, collections::HashMap, sync::Arc, time::Duration};
+
 use futures::{channel::mpsc, future::LocalBoxFuture};
 
 use super::{
     super::{
-        cli_and_types::{RetryOptions, ScenarioType},
+        cli_and_types::{AfterPanic, RetryOptions, ScenarioType},
         scenario_storage::{Features, FinishedFeaturesSender},
+        sla::Sla,
         supporting_structures::{
             AfterHookEventsMeta, ExecutionFailure, IsFailed, IsRetried,
             ScenarioId, coerce_into_info,
         },
     },
+    background_cache::BackgroundCache,
     events::EventSender,
+    fixtures,
     hooks::HookExecutor,
-    steps::StepExecutor,
+    resources::Sampler,
+    sla::SlaClock,
+    steps::{CachedBackground, ScenarioRun, StepExecutor},
+    teardown,
 };
 #[cfg(feature = "tracing")]
 use crate::tracing::SpanCloseWaiter;
 use crate::{
     Event, World,
-    event::{self, HookType, Retries, source::Source},
-    parser, step,
+    event::{self, HookScope, HookType, Info, Retries, source::Source},
+    fixture, parser,
+    runtime::{Agnostic, Runtime},
+    step,
 };
 
 /// Runs [`gherkin::Scenario`]s and notifies about their state of completion.
@@ -59,6 +69,50 @@ pub(crate) struct Executor<W, Before, After> {
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
     storage: Features,
+
+    /// Cache of `@cached-background` [`Background`]s execution results.
+    ///
+    /// [`Background`]: gherkin::Background
+    background_cache: Option<BackgroundCache<W>>,
+
+    /// [`fixture::Runtime`] setting up [`Fixture`]s of [`gherkin::Scenario`]s.
+    ///
+    /// [`Fixture`]: fixture::Fixture
+    fixtures: Option<fixture::Runtime>,
+
+    /// Interval of [`event::Step::Heartbeat`]s emitted for long-running
+    /// [`crate::step::Step`]s, if any.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    heartbeat: Option<Duration>,
+
+    /// Limit of a single [`crate::step::Step`] execution, if any.
+    ///
+    /// Overridden by the `@timeout(<duration>)` tag of a
+    /// [`gherkin::Scenario`].
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    step_timeout: Option<Duration>,
+
+    /// [`Runtime`] measuring step timeouts and heartbeats.
+    runtime: Arc<dyn Runtime>,
+
+    /// [`AfterPanic`] behavior regarding the remaining
+    /// [`crate::step::Step`]s of a [`gherkin::Scenario`] after one of them
+    /// fails.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    after_panic: AfterPanic,
+
+    /// Interval of sampling the [`event::ResourceUsage`] during
+    /// [`gherkin::Scenario`]s, if any.
+    resource_sampling: Option<Duration>,
+
+    /// [`Sampler`]s of the currently running [`gherkin::Scenario`]s.
+    samplers: RefCell<HashMap<ScenarioId, Sampler>>,
+
+    /// [`SlaClock`] checking durations of [`gherkin::Scenario`]s, if any.
+    sla: Option<SlaClock>,
 }
 
 /// Runs [`gherkin::Scenario`]s and notifies about their state of completion (with observability).
@@ -98,6 +152,50 @@ pub(crate) struct Executor<W: World, Before, After> {
     /// [`gherkin::Scenario`]: gherkin::Scenario
     storage: Features,
 
+    /// Cache of `@cached-background` [`Background`]s execution results.
+    ///
+    /// [`Background`]: gherkin::Background
+    background_cache: Option<BackgroundCache<W>>,
+
+    /// [`fixture::Runtime`] setting up [`Fixture`]s of [`gherkin::Scenario`]s.
+    ///
+    /// [`Fixture`]: fixture::Fixture
+    fixtures: Option<fixture::Runtime>,
+
+    /// Interval of [`event::Step::Heartbeat`]s emitted for long-running
+    /// [`crate::step::Step`]s, if any.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    heartbeat: Option<Duration>,
+
+    /// Limit of a single [`crate::step::Step`] execution, if any.
+    ///
+    /// Overridden by the `@timeout(<duration>)` tag of a
+    /// [`gherkin::Scenario`].
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    step_timeout: Option<Duration>,
+
+    /// [`Runtime`] measuring step timeouts and heartbeats.
+    runtime: Arc<dyn Runtime>,
+
+    /// [`AfterPanic`] behavior regarding the remaining
+    /// [`crate::step::Step`]s of a [`gherkin::Scenario`] after one of them
+    /// fails.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    after_panic: AfterPanic,
+
+    /// Interval of sampling the [`event::ResourceUsage`] during
+    /// [`gherkin::Scenario`]s, if any.
+    resource_sampling: Option<Duration>,
+
+    /// [`Sampler`]s of the currently running [`gherkin::Scenario`]s.
+    samplers: RefCell<HashMap<ScenarioId, Sampler>>,
+
+    /// [`SlaClock`] checking durations of [`gherkin::Scenario`]s, if any.
+    sla: Option<SlaClock>,
+
     /// Observer registry for external monitoring
     observers:
         std::sync::Arc<std::sync::Mutex<crate::observer::ObserverRegistry<W>>>,
@@ -146,11 +244,139 @@ where
             ),
             finished_sender,
             storage,
+            background_cache: None,
+            fixtures: None,
+            heartbeat: None,
+            step_timeout: None,
+            runtime: Arc::new(Agnostic),
+            after_panic: AfterPanic::Skip,
+            resource_sampling: None,
+            samplers: RefCell::default(),
+            sla: None,
             #[cfg(feature = "observability")]
             observers,
         }
     }
 
+    /// Sets the [`BackgroundCache`] of this [`Executor`].
+    pub(crate) fn with_background_cache(
+        mut self,
+        cache: Option<BackgroundCache<W>>,
+    ) -> Self {
+        self.background_cache = cache;
+        self
+    }
+
+    /// Sets the [`fixture::Runtime`] of this [`Executor`].
+    pub(crate) fn with_fixtures(
+        mut self,
+        fixtures: Option<fixture::Runtime>,
+    ) -> Self {
+        self.fixtures = fixtures;
+        self
+    }
+
+    /// Sets the interval of [`event::Step::Heartbeat`]s of this [`Executor`].
+    pub(crate) fn with_heartbeat(
+        mut self,
+        heartbeat: Option<Duration>,
+    ) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// Sets the limit of a single [`crate::step::Step`] execution of this
+    /// [`Executor`].
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub(crate) const fn with_step_timeout(
+        mut self,
+        timeout: Option<Duration>,
+    ) -> Self {
+        self.step_timeout = timeout;
+        self
+    }
+
+    /// Sets the [`Runtime`] measuring step timeouts and heartbeats of this
+    /// [`Executor`].
+    pub(crate) fn with_runtime(mut self, runtime: Arc<dyn Runtime>) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// Sets the [`AfterPanic`] behavior of this [`Executor`].
+    pub(crate) const fn with_after_panic(
+        mut self,
+        after_panic: AfterPanic,
+    ) -> Self {
+        self.after_panic = after_panic;
+        self
+    }
+
+    /// Sets the interval of sampling the [`event::ResourceUsage`] of this
+    /// [`Executor`].
+    pub(crate) fn with_resource_sampling(
+        mut self,
+        interval: Option<Duration>,
+    ) -> Self {
+        self.resource_sampling = interval;
+        self
+    }
+
+    /// Sets the [`Sla`] of [`gherkin::Scenario`]s durations of this
+    /// [`Executor`].
+    pub(crate) fn with_sla(mut self, sla: Option<Sla>) -> Self {
+        self.sla = sla.map(SlaClock::new);
+        self
+    }
+
+    /// Creates an [`event::Scenario::Finished`] event of the
+    /// [`gherkin::Scenario`] with the provided [`ScenarioId`], finishing its
+    /// [`Sampler`], if any.
+    fn scenario_finished_event(&self, id: ScenarioId) -> event::Scenario<W> {
+        if let Some(clock) = &self.sla {
+            clock.discard(id);
+        }
+        let sampler = self.samplers.borrow_mut().remove(&id);
+        event::Scenario::Finished(sampler.and_then(Sampler::finish))
+    }
+
+    /// Tears down the [`Scope::Feature`] [`Fixture`]s of the provided finished
+    /// `feature`.
+    ///
+    /// [`Fixture`]: fixture::Fixture
+    /// [`Scope::Feature`]: fixture::Scope::Feature
+    pub(crate) async fn finish_feature_fixtures(
+        &self,
+        feature: &gherkin::Feature,
+    ) {
+        if let Some(fixtures) = &self.fixtures {
+            fixtures.finish_feature(feature).await;
+        }
+    }
+
+    /// Clears the [`Background`] snapshots of the provided finished
+    /// `feature`.
+    ///
+    /// [`Background`]: gherkin::Background
+    pub(crate) fn finish_feature_background(
+        &self,
+        feature: &Source<gherkin::Feature>,
+    ) {
+        if let Some(cache) = &self.background_cache {
+            cache.clear_feature(feature);
+        }
+    }
+
+    /// Tears down all the remaining [`Fixture`]s once the run finishes.
+    ///
+    /// [`Fixture`]: fixture::Fixture
+    pub(crate) async fn finish_fixtures(&self) {
+        if let Some(fixtures) = &self.fixtures {
+            fixtures.finish().await;
+        }
+    }
+
     /// Register an observer for monitoring test execution
     #[cfg(feature = "observability")]
     #[allow(dead_code)] // Used only when observability feature is enabled
@@ -190,72 +416,58 @@ where
         }
         let retries = retry_options.map(|opts| opts.retries);
 
+        if let Some(clock) = &self.sla {
+            clock.start(id);
+        }
+        if let Some(interval) = self.resource_sampling {
+            drop(
+                self.samplers.borrow_mut().insert(id, Sampler::start(interval)),
+            );
+        }
+
+        let fixtures = match fixtures::setup(
+            self.fixtures.as_ref(),
+            &feature,
+            rule.as_deref(),
+            &scenario,
+        )
+        .await
+        {
+            Ok(setup) => setup,
+            Err(error_info) => {
+                self.fail_to_start(
+                    HookScope::Fixtures,
+                    error_info,
+                    id,
+                    feature,
+                    rule,
+                    scenario,
+                    scenario_ty,
+                    retry_options,
+                )
+                .await;
+                return;
+            }
+        };
+
         // Create world instance for this scenario
         let mut world = match W::new().await {
             Ok(world) => world,
-            Err(_err) => {
-                // Emit world creation error as a before hook failure using Before variant
-                let error_info = coerce_into_info("Failed to create World");
-                let meta = event::Metadata::new(());
-                let started_event = event::Cucumber::scenario(
-                    feature.clone(),
-                    rule.clone(),
-                    scenario.clone(),
-                    event::RetryableScenario {
-                        event: event::Scenario::Hook(
-                            HookType::Before,
-                            event::Hook::Failed(None, error_info.clone()),
-                        ),
-                        retries,
-                    },
-                );
-                
-                // Use send_event_with_meta for precise timing of critical failure events
-                self.event_sender.send_event_with_meta(started_event, &meta);
-                
-                // Handle the failure using the Before variant for world creation failures
-                self.handle_execution_failure(
-                    ExecutionFailure::Before,
-                    id,
-                    feature.clone(),
-                    rule.clone(),
-                    scenario.clone(),
-                    retries,
-                );
-
-                let finished_event = event::Cucumber::scenario(
-                    feature.clone(),
-                    rule.clone(),
-                    scenario.clone(),
-                    event::RetryableScenario {
-                        event: event::Scenario::Finished,
-                        retries,
-                    },
-                );
-                self.event_sender.send_event(finished_event);
-
-                // Check if scenario will be retried
-                let next_try = retry_options.and_then(RetryOptions::next_try);
-
-                if let Some(next_try) = next_try {
-                    self.storage
-                        .insert_retried_scenario(
-                            feature.clone(),
-                            rule.clone(),
-                            scenario,
-                            scenario_ty,
-                            Some(next_try),
-                        )
-                        .await;
-                }
-
-                self.scenario_finished(
+            Err(err) => {
+                drop(fixtures.teardown().await);
+                let error_info =
+                    coerce_into_info(format!("Failed to create World: {err:#}"));
+                self.fail_to_start(
+                    HookScope::World,
+                    error_info,
                     id,
                     feature,
                     rule,
-                    true, // World creation failure is a failure
-                    next_try.is_some(),
-                );
+                    scenario,
+                    scenario_ty,
+                    retry_options,
+                )
+                .await;
                 return;
             }
         };
@@ -268,6 +480,7 @@ where
             event::RetryableScenario {
                 event: event::Scenario::Started,
                 retries,
+                id: Some(id),
             },
         );
         self.event_sender.send_event(started_event);
@@ -286,6 +499,7 @@ where
                 rule.clone(),
                 scenario.clone(),
                 &mut world,
+                &fixtures.instances,
                 retries,
                 #[cfg(feature = "tracing")]
                 waiter,
@@ -311,6 +525,7 @@ where
             scenario_ty,
             execution_result,
             world,
+            fixtures,
             retry_options,
             #[cfg(feature = "tracing")]
             waiter,
@@ -322,7 +537,81 @@ where
         self.event_sender.clear_scenario_context();
     }
 
+    /// Fails the [`gherkin::Scenario`] unable to start with the provided
+    /// `error_info`, reporting it as a failed [`HookType::Before`] of the
+    /// provided [`HookScope`].
+    #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
+    async fn fail_to_start(
+        &self,
+        scope: HookScope,
+        error_info: Info,
+        id: ScenarioId,
+        feature: Source<gherkin::Feature>,
+        rule: Option<Source<gherkin::Rule>>,
+        scenario: Source<gherkin::Scenario>,
+        scenario_ty: ScenarioType,
+        retry_options: Option<RetryOptions>,
+    ) {
+        let retries = retry_options.map(|opts| opts.retries);
+        let scenario_event = |ev: event::Scenario<W>| {
+            event::Cucumber::scenario(
+                feature.clone(),
+                rule.clone(),
+                scenario.clone(),
+                event::RetryableScenario { event: ev, retries, id: Some(id) },
+            )
+        };
+        self.event_sender.send_event(scenario_event(event::Scenario::Started));
+        self.event_sender.send_event(scenario_event(
+            event::Scenario::hook_started(HookType::Before, scope),
+        ));
+
+        // Use send_event_with_meta for precise timing of critical failure events
+        let meta = event::Metadata::new(());
+        let failed_event = scenario_event(event::Scenario::hook_failed(
+            HookType::Before,
+            scope,
+            None,
+            error_info,
+        ));
+        self.event_sender.send_event_with_meta(failed_event, &meta);
+        
+        // Handle the failure using the Before variant for failures to start
+        self.handle_execution_failure(
+            ExecutionFailure::Before,
+            id,
+            feature.clone(),
+            rule.clone(),
+            scenario.clone(),
+            retries,
+        );
+
+        // Check if scenario will be retried
+        let next_try = retry_options.and_then(RetryOptions::next_try);
+
+        if let Some(next_try) = next_try {
+            self.storage
+                .insert_retried_scenario(
+                    feature.clone(),
+                    rule.clone(),
+                    scenario,
+                    scenario_ty,
+                    Some(next_try),
+                )
+                .await;
+        }
+
+        self.scenario_finished(
+            id,
+            feature,
+            rule,
+            true, // Failure to start is a failure
+            next_try.is_some(),
+        );
+    }
+
     /// Executes all steps of a scenario including hooks.
+    #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
     async fn execute_scenario_steps(
         &self,
         id: ScenarioId,
@@ -330,16 +619,40 @@ where
         rule: Option<Source<gherkin::Rule>>,
         scenario: Source<gherkin::Scenario>,
         world: &mut W,
+        fixtures: &fixture::Instances,
         retries: Option<Retries>,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) -> Result<AfterHookEventsMeta, ExecutionFailure<W>> {
+        let run = ScenarioRun {
+            collection: &self.collection,
+            id,
+            feature,
+            rule,
+            scenario,
+            fixtures,
+            retries,
+            background_cache: self.background_cache.as_ref(),
+            heartbeat: self.heartbeat,
+            step_timeout: self.step_timeout,
+            runtime: &*self.runtime,
+            after_panic: self.after_panic,
+            send_event: |event| self.event_sender.send_event(event),
+            #[cfg(feature = "tracing")]
+            waiter,
+        };
+
+        // Restore the cached `Background` before the `Before` hook, so the
+        // hook's changes to the `World` are not overwritten by the snapshot.
+        let background = CachedBackground::acquire(&run, world).await;
+
         // Run before hook
         HookExecutor::run_before_hook(
             self.before_hook.as_ref(),
             id,
-            feature.clone(),
-            rule.clone(),
-            scenario.clone(),
+            run.feature.clone(),
+            run.rule.clone(),
+            run.scenario.clone(),
+            retries,
             world,
             |event| self.event_sender.send_event(event),
             #[cfg(feature = "tracing")]
@@ -347,20 +660,8 @@ where
         )
         .await?;
 
-        // Execute steps
-        let step_results = StepExecutor::run_steps(
-            &self.collection,
-            id,
-            feature.clone(),
-            rule.clone(),
-            scenario.clone(),
-            world,
-            retries,
-            |event| self.event_sender.send_event(event),
-            #[cfg(feature = "tracing")]
-            waiter,
-        )
-        .await;
+        let step_results =
+            StepExecutor::run_steps(&run, world, background).await;
 
         Ok(step_results)
     }
@@ -375,6 +676,7 @@ where
         scenario_ty: ScenarioType,
         step_results: Result<AfterHookEventsMeta, ExecutionFailure<W>>,
         mut world: W,
+        fixtures: fixture::Setup,
         retry_options: Option<RetryOptions>,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) {
@@ -394,6 +696,15 @@ where
             Err(failure) => {
                 let _finished = failure.get_scenario_finished_event();
                 let failed = true; // ExecutionFailure always indicates failure
+                self.teardown(
+                    id,
+                    &feature,
+                    rule.as_ref(),
+                    &scenario,
+                    &mut world,
+                    fixtures,
+                )
+                .await;
                 // Handle execution failure
                 self.handle_execution_failure(
                     failure,
@@ -441,6 +752,7 @@ where
             feature.clone(),
             rule.clone(),
             scenario.clone(),
+            retries,
             Some(&mut world),
             &scenario_finished,
             |event| self.event_sender.send_event(event),
@@ -449,18 +761,48 @@ where
         )
         .await;
 
+        self.teardown(
+            id,
+            &feature,
+            rule.as_ref(),
+            &scenario,
+            &mut world,
+            fixtures,
+        )
+        .await;
+
         // After hook meta contains timing information that can be used for future events
         let _started_time = after_hook_meta.started;
         let _finished_time = after_hook_meta.finished;
 
+        // Duration SLA is checked before finishing, so its violation is
+        // reported within the `Scenario`.
+        let violation = self.sla.as_ref().and_then(|clock| {
+            clock.finish(id, &feature, rule.as_deref(), &scenario)
+        });
+        let sla_failed = violation.as_ref().is_some_and(|v| v.fails_scenario);
+        if let Some(violation) = violation {
+            self.event_sender.send_event(event::Cucumber::scenario(
+                feature.clone(),
+                rule.clone(),
+                scenario.clone(),
+                event::RetryableScenario {
+                    event: event::Scenario::SlaViolated(violation),
+                    retries,
+                    id: Some(id),
+                },
+            ));
+        }
+
         // Send finished event
         let finished_event = event::Cucumber::scenario(
             feature.clone(),
             rule.clone(),
             scenario.clone(),
             event::RetryableScenario {
-                event: event::Scenario::Finished,
+                event: self.scenario_finished_event(id),
                 retries,
+                id: Some(id),
             },
         );
         self.event_sender.send_event(finished_event);
@@ -488,11 +830,45 @@ where
             id,
             feature,
             rule,
-            is_failed,
+            is_failed || sla_failed,
             next_try.is_some(),
         );
     }
 
+    /// Runs [`World::teardown()`] of the finished [`gherkin::Scenario`], and
+    /// tears down its [`Scope::Scenario`] [`Fixture`]s afterwards.
+    ///
+    /// [`Fixture`]: fixture::Fixture
+    /// [`Scope::Scenario`]: fixture::Scope::Scenario
+    async fn teardown(
+        &self,
+        id: ScenarioId,
+        feature: &Source<gherkin::Feature>,
+        rule: Option<&Source<gherkin::Rule>>,
+        scenario: &Source<gherkin::Scenario>,
+        world: &mut W,
+        fixtures: fixture::Setup,
+    ) {
+        teardown::run(
+            id,
+            feature.clone(),
+            rule.cloned(),
+            scenario.clone(),
+            world,
+            |event| self.event_sender.send_event(event),
+        )
+        .await;
+        fixtures::teardown(
+            id,
+            feature,
+            rule,
+            scenario,
+            fixtures,
+            |event| self.event_sender.send_event(event),
+        )
+        .await;
+    }
+
     /// Handles execution failures during scenario execution.
     ///
     /// Note: The actual failure events are already emitted by the respective
@@ -585,8 +961,9 @@ where
             rule,
             scenario,
             event::RetryableScenario {
-                event: event::Scenario::Finished,
+                event: self.scenario_finished_event(id),
                 retries,
+                id: Some(id),
             },
         );
         self.event_sender.send_event(failure_event);
@@ -611,12 +988,18 @@ where
                         let feature_name = feature_src.name.clone();
 
                         // Extract scenario, rule, and retry information from the event
-                        let (scenario_name, rule_name, retry_info, tags) =
-                            match feature_event {
+                        let (
+                            scenario_id,
+                            scenario_name,
+                            rule_name,
+                            retry_info,
+                            tags,
+                        ) = match feature_event {
                                 event::Feature::Scenario(
                                     scenario_src,
                                     retryable,
                                 ) => (
+                                    retryable.id,
                                     scenario_src.name.clone(),
                                     None,
                                     retryable.retries,
@@ -632,6 +1015,7 @@ where
                                             scenario_src,
                                             retryable,
                                         ) => (
+                                            retryable.id,
                                             scenario_src.name.clone(),
                                             Some(rule_src.name.clone()),
                                             retryable.retries,
@@ -642,6 +1026,7 @@ where
                                                 .collect(),
                                         ),
                                         _ => (
+                                            None,
                                             String::new(),
                                             Some(rule_src.name.clone()),
                                             None,
@@ -649,17 +1034,23 @@ where
                                         ),
                                     }
                                 }
-                                _ => (String::new(), None, None, Vec::new()),
+                                _ => (
+                                    None,
+                                    String::new(),
+                                    None,
+                                    None,
+                                    Vec::new(),
+                                ),
                             };
 
                         crate::observer::ObservationContext {
-                            scenario_id: None, // ScenarioId is not available at this level
+                            scenario_id: scenario_id.map(|id| id.0),
                             feature_name,
                             rule_name,
                             scenario_name,
                             retry_info,
                             tags,
-                            timestamp: std::time::Instant::now(),
+                            timestamp: crate::time::Instant::now(),
                         }
                     }
                     _ => {
@@ -671,7 +1062,7 @@ where
                             scenario_name: String::new(),
                             retry_info: None,
                             tags: Vec::new(),
-                            timestamp: std::time::Instant::now(),
+                            timestamp: crate::time::Instant::now(),
                         }
                     }
                 };
@@ -880,7 +1271,7 @@ mod tests {
                 event::Feature::Scenario(
                     _,
                     event::RetryableScenario {
-                        event: event::Scenario::Finished,
+                        event: event::Scenario::Finished(_),
                         ..
                     },
                 ),
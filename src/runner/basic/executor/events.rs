@@ -144,7 +144,7 @@ impl<W: World> EventSender<W> {
                     scenario_name: ctx.scenario.name.clone(),
                     retry_info: ctx.retries.clone(),
                     tags: ctx.scenario.tags.clone(),
-                    timestamp: std::time::Instant::now(),
+                    timestamp: crate::time::Instant::now(),
                 };
 
                 // Extract additional context from the event itself for more accurate reporting
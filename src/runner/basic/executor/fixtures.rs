@@ -0,0 +1,63 @@
+//! [`Fixture`]s setup and teardown logic for the Basic executor.
+//!
+//! [`Fixture`]: crate::fixture::Fixture
+
+use super::{super::supporting_structures::coerce_into_info, teardown};
+use crate::{
+    event::{self, Info, source::Source},
+    fixture,
+    runner::basic::ScenarioId,
+};
+
+/// Sets up the [`Fixture`]s requested by the provided [`gherkin::Scenario`].
+///
+/// # Errors
+///
+/// With the [`Info`] to be reported as a failed `Before` hook of the
+/// [`event::HookScope::Fixtures`], if the [`Fixture`]s fail to set up.
+///
+/// [`Fixture`]: crate::fixture::Fixture
+pub(super) async fn setup(
+    runtime: Option<&fixture::Runtime>,
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> Result<fixture::Setup, Info> {
+    let Some(runtime) = runtime else {
+        return Ok(fixture::Setup::default());
+    };
+    runtime
+        .setup(feature, rule, scenario)
+        .await
+        .map_err(|e| coerce_into_info(format!("Fixtures: {e}")))
+}
+
+/// Tears down the [`Scope::Scenario`] [`Fixture`]s of the provided
+/// [`fixture::Setup`].
+///
+/// A panic of the teardown is reported as a failed `After` hook of the
+/// [`event::HookScope::Fixtures`], so the [`gherkin::Scenario`] fails.
+///
+/// [`Fixture`]: crate::fixture::Fixture
+/// [`Scope::Scenario`]: fixture::Scope::Scenario
+pub(super) async fn teardown<W>(
+    id: ScenarioId,
+    feature: &Source<gherkin::Feature>,
+    rule: Option<&Source<gherkin::Rule>>,
+    scenario: &Source<gherkin::Scenario>,
+    setup: fixture::Setup,
+    send_event: impl Fn(event::Cucumber<W>),
+) {
+    if let Err(panic) = setup.teardown().await {
+        let info = coerce_into_info(panic);
+        teardown::report_failure(
+            id,
+            event::HookScope::Fixtures,
+            feature,
+            rule,
+            scenario,
+            info,
+            send_event,
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Heartbeats of long-running steps.
+
+use std::time::Duration;
+
+use futures::{
+    future::{self, Either},
+    pin_mut,
+};
+
+use crate::{runtime::Runtime, time::Instant};
+
+/// Awaits the provided `fut`, calling the `beat` function with the elapsed
+/// time every `interval` until it completes.
+///
+/// The `interval` is measured by the provided [`Runtime`], stopping as soon as
+/// the `fut` completes.
+pub(super) async fn beating<F: Future>(
+    fut: F,
+    interval: Duration,
+    runtime: &dyn Runtime,
+    beat: impl Fn(Duration),
+) -> F::Output {
+    let started = Instant::now();
+
+    pin_mut!(fut);
+    loop {
+        match future::select(fut.as_mut(), runtime.sleep(interval)).await {
+            Either::Left((out, _)) => break out,
+            Either::Right(((), _)) => beat(started.elapsed()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, time::Duration};
+
+    use super::beating;
+    use crate::runtime::Agnostic;
+
+    #[tokio::test]
+    async fn beats_until_completed() {
+        let beats = RefCell::new(Vec::new());
+
+        beating(
+            tokio::time::sleep(Duration::from_millis(130)),
+            Duration::from_millis(50),
+            &Agnostic,
+            |elapsed| beats.borrow_mut().push(elapsed),
+        )
+        .await;
+
+        let beats = beats.into_inner();
+        assert_eq!(beats.len(), 2, "{beats:?}");
+        assert!(beats[0] >= Duration::from_millis(50), "{beats:?}");
+        assert!(beats[1] > beats[0], "{beats:?}");
+    }
+
+    #[tokio::test]
+    async fn doesnt_beat_fast_futures() {
+        let beats = RefCell::new(0);
+
+        beating(async {}, Duration::from_millis(50), &Agnostic, |_| {
+            *beats.borrow_mut() += 1;
+        })
+        .await;
+
+        assert_eq!(beats.into_inner(), 0);
+    }
+}
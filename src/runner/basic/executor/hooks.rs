@@ -9,7 +9,7 @@ use super::super::supporting_structures::{
 };
 use crate::{
     Event, World,
-    event::{self, HookType, source::Source},
+    event::{self, HookScope, HookType, Retries, source::Source},
 };
 
 /// Hook execution functionality for the Executor.
@@ -17,12 +17,14 @@ pub(super) struct HookExecutor;
 
 impl HookExecutor {
     /// Runs a before hook if present.
+    #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
     pub(super) async fn run_before_hook<W, Before>(
         hook: Option<&Before>,
         id: ScenarioId,
         feature: Source<gherkin::Feature>,
         rule: Option<Source<gherkin::Rule>>,
         scenario: Source<gherkin::Scenario>,
+        retries: Option<Retries>,
         world: &mut W,
         send_event: impl Fn(event::Cucumber<W>),
         #[cfg(feature = "tracing")] waiter: Option<
@@ -49,9 +51,11 @@ impl HookExecutor {
                 event::RetryableScenario {
                     event: event::Scenario::Hook(
                         HookType::Before,
+                        HookScope::Function,
                         event::Hook::Started,
                     ),
-                    retries: None,
+                    retries,
+                    id: Some(id),
                 },
             );
             
@@ -111,9 +115,11 @@ impl HookExecutor {
                         event::RetryableScenario {
                             event: event::Scenario::Hook(
                                 HookType::Before,
+                                HookScope::Function,
                                 event::Hook::Failed(None, info),
                             ),
-                            retries: None,
+                            retries,
+                            id: Some(id),
                         },
                     );
                     
@@ -137,8 +143,13 @@ impl HookExecutor {
                 rule,
                 scenario,
                 event::RetryableScenario {
-                    event: event::Scenario::Hook(HookType::Before, hook_event),
-                    retries: None,
+                    event: event::Scenario::Hook(
+                        HookType::Before,
+                        HookScope::Function,
+                        hook_event,
+                    ),
+                    retries,
+                    id: Some(id),
                 },
             ));
             send_event(event.value);
@@ -148,12 +159,14 @@ impl HookExecutor {
     }
 
     /// Runs an after hook if present.
+    #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
     pub(super) async fn run_after_hook<W, After>(
         hook: Option<&After>,
         id: ScenarioId,
         feature: Source<gherkin::Feature>,
         rule: Option<Source<gherkin::Rule>>,
         scenario: Source<gherkin::Scenario>,
+        retries: Option<Retries>,
         world: Option<&mut W>,
         scenario_finished: &event::ScenarioFinished,
         send_event: impl Fn(event::Cucumber<W>),
@@ -183,9 +196,11 @@ impl HookExecutor {
                 event::RetryableScenario {
                     event: event::Scenario::Hook(
                         HookType::After,
+                        HookScope::Function,
                         event::Hook::Started,
                     ),
-                    retries: None,
+                    retries,
+                    id: Some(id),
                 },
             ));
             send_event(event.value);
@@ -238,8 +253,13 @@ impl HookExecutor {
                 rule,
                 scenario,
                 event::RetryableScenario {
-                    event: event::Scenario::Hook(HookType::After, hook_event),
-                    retries: None,
+                    event: event::Scenario::Hook(
+                        HookType::After,
+                        HookScope::Function,
+                        hook_event,
+                    ),
+                    retries,
+                    id: Some(id),
                 },
             ));
             send_event(event.value);
@@ -284,6 +304,7 @@ mod tests {
             feature,
             None,
             scenario,
+            None,
             &mut world,
             move |event| events_clone.lock().unwrap().push(event),
             #[cfg(feature = "tracing")]
@@ -318,6 +339,7 @@ mod tests {
             feature,
             None,
             scenario,
+            None,
             &mut world,
             move |event| events_clone.lock().unwrap().push(event),
             #[cfg(feature = "tracing")]
@@ -354,6 +376,7 @@ mod tests {
             feature,
             None,
             scenario,
+            None,
             Some(&mut world),
             &scenario_finished,
             move |event| events_clone.lock().unwrap().push(event),
@@ -391,6 +414,7 @@ mod tests {
             feature,
             None,
             scenario,
+            None,
             Some(&mut world),
             &scenario_finished,
             move |event| events_clone.lock().unwrap().push(event),
@@ -4,14 +4,30 @@
 //! - `core`: Main Executor struct and orchestration logic
 //! - `hooks`: Before/after hook execution logic
 //! - `steps`: Step execution logic
+//! - `fixtures`: `Fixture`s setup and teardown logic
+//! - `teardown`: `World` teardown execution logic
+//! - `timeout`: Timeouts of long-running steps
+//! - `background_cache`: Caching of `Background` execution results
+//! - `heartbeat`: Heartbeats of long-running steps
+//! - `publishing`: Forwarding of custom events published from steps
+//! - `resources`: Sampling of resources usage during scenarios
+//! - `sla`: Enforcement of scenarios duration SLAs
 //! - `events`: Event sending functionality
 
+mod background_cache;
 mod core;
 mod events;
+mod fixtures;
+mod heartbeat;
 mod hooks;
+mod publishing;
+mod resources;
+mod sla;
 mod steps;
+mod teardown;
+mod timeout;
 
-pub(super) use core::Executor;
+pub(super) use self::{background_cache::BackgroundCache, core::Executor};
 
 #[cfg(test)]
 mod integration_tests {
@@ -59,6 +75,7 @@ mod integration_tests {
             event::RetryableScenario {
                 event: event::Scenario::<TestWorld>::Started,
                 retries: None,
+                id: None,
             },
         );
 
@@ -82,6 +99,7 @@ mod integration_tests {
                 event::RetryableScenario {
                     event: event::Scenario::<TestWorld>::Started,
                     retries: None,
+                    id: None,
                 },
             ),
             event::Cucumber::<TestWorld>::scenario(
@@ -89,8 +107,9 @@ mod integration_tests {
                 None::<event::source::Source<gherkin::Rule>>,
                 scenario,
                 event::RetryableScenario {
-                    event: event::Scenario::<TestWorld>::Finished,
+                    event: event::Scenario::<TestWorld>::Finished(None),
                     retries: None,
+                    id: None,
                 },
             ),
         ];
@@ -0,0 +1,90 @@
+//! Forwarding of [`Custom`] events published from step functions.
+
+use futures::{
+    StreamExt as _,
+    channel::mpsc,
+    future::{self, Either},
+    pin_mut,
+};
+
+use crate::event::Custom;
+
+/// Awaits the provided `fut`, calling the `forward` function with every
+/// [`Custom`] event `published` meanwhile.
+///
+/// The [`Custom`] events still pending once the `fut` completes are forwarded
+/// too, while the ones published later are discarded.
+pub(super) async fn forwarding<F: Future>(
+    fut: F,
+    mut published: mpsc::UnboundedReceiver<Custom>,
+    forward: impl Fn(Custom),
+) -> F::Output {
+    pin_mut!(fut);
+    let out = loop {
+        match future::select(fut.as_mut(), published.next()).await {
+            Either::Left((out, _)) => break out,
+            Either::Right((Some(ev), _)) => forward(ev),
+            Either::Right((None, _)) => break fut.await,
+        }
+    };
+    forward_pending(published, forward);
+    out
+}
+
+/// Calls the `forward` function with every [`Custom`] event already
+/// `published`, discarding the ones published later.
+pub(super) fn forward_pending(
+    mut published: mpsc::UnboundedReceiver<Custom>,
+    forward: impl Fn(Custom),
+) {
+    published.close();
+    while let Ok(ev) = published.try_recv() {
+        forward(ev);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, time::Duration};
+
+    use super::forwarding;
+    use crate::{event::Custom, step::publish::Publisher};
+
+    #[tokio::test]
+    async fn forwards_published_events_in_order() {
+        let (publisher, published) = Publisher::channel();
+        let forwarded = RefCell::new(Vec::new());
+        let ctx = crate::step::Context::new(
+            gherkin::Step {
+                keyword: "When".into(),
+                ty: gherkin::StepType::When,
+                value: "the order is placed".into(),
+                docstring: None,
+                table: None,
+                span: gherkin::Span { start: 0, end: 0 },
+                position: gherkin::LineCol { line: 1, col: 1 },
+            },
+            vec![],
+        )
+        .with_publisher(publisher);
+
+        let out = forwarding(
+            async {
+                ctx.publish("order placed");
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                ctx.publish("order shipped");
+                42
+            },
+            published,
+            |ev| forwarded.borrow_mut().push(ev),
+        )
+        .await;
+        ctx.publish("too late");
+
+        assert_eq!(out, 42);
+        assert_eq!(
+            forwarded.into_inner(),
+            [Custom::new("order placed"), Custom::new("order shipped")],
+        );
+    }
+}
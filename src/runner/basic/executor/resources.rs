@@ -0,0 +1,164 @@
+//! Sampling of the process resources usage during [`gherkin::Scenario`]s.
+
+use std::{
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::event::{ResourceUsage, Sampled};
+
+/// Sampler of the process resources, running in a separate thread until
+/// [finished][`Sampler::finish()`].
+#[derive(Debug)]
+pub(super) struct Sampler {
+    /// Sender stopping the sampling thread once dropped.
+    stop: mpsc::Sender<()>,
+
+    /// Handle of the sampling thread, returning the taken [`Sample`]s.
+    ///
+    /// [`None`] if sampling isn't supported on the current platform, so no
+    /// thread is spawned (as threads may be unsupported there as well, like
+    /// on `wasm32-unknown-unknown` target).
+    thread: Option<thread::JoinHandle<Vec<Sample>>>,
+}
+
+impl Sampler {
+    /// Starts sampling the process resources every `interval`.
+    ///
+    /// The first [`Sample`] is taken right away, and the last one once
+    /// [finished][`Sampler::finish()`].
+    pub(super) fn start(interval: Duration) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = Sample::SUPPORTED.then(|| {
+            thread::spawn(move || {
+                let mut samples = Vec::new();
+                samples.extend(Sample::take());
+                while stopped.recv_timeout(interval)
+                    == Err(mpsc::RecvTimeoutError::Timeout)
+                {
+                    samples.extend(Sample::take());
+                }
+                samples.extend(Sample::take());
+                samples
+            })
+        });
+        Self { stop, thread }
+    }
+
+    /// Stops sampling and summarizes the taken [`Sample`]s.
+    ///
+    /// Returns [`None`] if sampling isn't supported on the current platform.
+    pub(super) fn finish(self) -> Option<ResourceUsage> {
+        let Self { stop, thread } = self;
+        drop(stop);
+        let samples = thread?.join().ok()?;
+
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "sampled values fit into `f64` mantissa"
+        )]
+        let (memory, threads) = (
+            Sampled::of(samples.iter().map(|s| s.memory as f64))?,
+            Sampled::of(samples.iter().map(|s| s.threads as f64))?,
+        );
+        let cpu = Sampled::of(samples.windows(2).filter_map(|w| {
+            let wall = w[1].at.duration_since(w[0].at).as_secs_f64();
+            let cpu = w[1].cpu.saturating_sub(w[0].cpu).as_secs_f64();
+            (wall > 0.0).then(|| cpu / wall * 100.0)
+        }))?;
+
+        Some(ResourceUsage { samples: samples.len(), memory, cpu, threads })
+    }
+}
+
+/// Single sample of the process resources.
+#[derive(Clone, Copy, Debug)]
+struct Sample {
+    /// [`Instant`] this [`Sample`] was taken at.
+    at: Instant,
+
+    /// Resident set size of the process, in bytes.
+    memory: u64,
+
+    /// Total CPU time consumed by the process.
+    cpu: Duration,
+
+    /// Number of the process threads.
+    threads: u64,
+}
+
+impl Sample {
+    /// Indicator whether sampling the process resources is supported on the
+    /// current platform.
+    const SUPPORTED: bool = cfg!(target_os = "linux");
+
+    /// Takes a [`Sample`] of the current process resources from `/proc`.
+    #[cfg(target_os = "linux")]
+    fn take() -> Option<Self> {
+        use std::fs;
+
+        /// Number of clock ticks per second used in `/proc/self/stat`.
+        ///
+        /// It's always `100` on the supported Linux architectures.
+        const USER_HZ: u64 = 100;
+
+        let at = Instant::now();
+        let status = fs::read_to_string("/proc/self/status").ok()?;
+        let field = |name: &str| {
+            status
+                .lines()
+                .find_map(|l| l.strip_prefix(name))?
+                .split_whitespace()
+                .next()?
+                .parse::<u64>()
+                .ok()
+        };
+        let memory = field("VmRSS:")? * 1024;
+        let threads = field("Threads:")?;
+
+        let stat = fs::read_to_string("/proc/self/stat").ok()?;
+        // `utime` and `stime` are the 14th and 15th fields, counting from the
+        // 3rd one after the parenthesized executable name.
+        let mut fields = stat.rsplit_once(')')?.1.split_whitespace().skip(11);
+        let utime = fields.next()?.parse::<u64>().ok()?;
+        let stime = fields.next()?.parse::<u64>().ok()?;
+        let cpu = Duration::from_millis((utime + stime) * 1000 / USER_HZ);
+
+        Some(Self { at, memory, cpu, threads })
+    }
+
+    /// Sampling the process resources isn't supported on this platform.
+    #[cfg(not(target_os = "linux"))]
+    fn take() -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Sampler;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn samples_until_finished() {
+        let sampler = Sampler::start(Duration::from_millis(10));
+        std::thread::sleep(Duration::from_millis(50));
+
+        let usage = sampler.finish().expect("sampling is supported");
+        assert!(usage.samples >= 3, "{usage:?}");
+        assert!(usage.memory.min > 0.0, "{usage:?}");
+        assert!(usage.memory.min <= usage.memory.max, "{usage:?}");
+        assert!(usage.threads.min >= 1.0, "{usage:?}");
+        assert!(usage.cpu.min >= 0.0, "{usage:?}");
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn unsupported_elsewhere() {
+        assert!(Sampler::start(Duration::from_millis(10)).finish().is_none());
+    }
+}
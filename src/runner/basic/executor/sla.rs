@@ -0,0 +1,52 @@
+//! Enforcement of [`gherkin::Scenario`]s duration SLAs.
+
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{
+    event::SlaViolation,
+    runner::basic::{ScenarioId, Sla},
+    time::Instant,
+};
+
+/// Clock measuring durations of the running [`gherkin::Scenario`]s to check
+/// them against the [`Sla`].
+#[derive(Debug)]
+pub(super) struct SlaClock {
+    /// [`Sla`] to check the durations against.
+    sla: Sla,
+
+    /// Start times of the currently running [`gherkin::Scenario`]s.
+    started: RefCell<HashMap<ScenarioId, Instant>>,
+}
+
+impl SlaClock {
+    /// Creates a new [`SlaClock`] checking against the provided [`Sla`].
+    pub(super) fn new(sla: Sla) -> Self {
+        Self { sla, started: RefCell::default() }
+    }
+
+    /// Starts measuring the [`gherkin::Scenario`] with the provided
+    /// [`ScenarioId`].
+    pub(super) fn start(&self, id: ScenarioId) {
+        _ = self.started.borrow_mut().insert(id, Instant::now());
+    }
+
+    /// Stops measuring the [`gherkin::Scenario`] with the provided
+    /// [`ScenarioId`], returning its [`SlaViolation`], if any.
+    pub(super) fn finish(
+        &self,
+        id: ScenarioId,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> Option<SlaViolation> {
+        let started = self.started.borrow_mut().remove(&id)?;
+        self.sla.check(feature, rule, scenario, started.elapsed())
+    }
+
+    /// Stops measuring the [`gherkin::Scenario`] with the provided
+    /// [`ScenarioId`] without checking it.
+    pub(super) fn discard(&self, id: ScenarioId) {
+        _ = self.started.borrow_mut().remove(&id);
+    }
+}
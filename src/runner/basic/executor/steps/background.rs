@@ -0,0 +1,76 @@
+//! `@cached-background` state of a [`gherkin::Scenario`] run.
+
+use std::vec;
+
+use super::{
+    super::background_cache::{Acquired, BackgroundCache, Flight},
+    ScenarioRun,
+};
+use crate::{World, event};
+
+/// `@cached-background` state of a [`ScenarioRun`].
+pub(in super::super) struct CachedBackground<'c, W> {
+    /// Results of the `Background` [`gherkin::Step`]s restored from a
+    /// snapshot, if any.
+    restored: Option<vec::IntoIter<event::Step<W>>>,
+
+    /// [`Flight`] of the snapshot to be stored, along with the results of the
+    /// `Background` [`gherkin::Step`]s collected so far.
+    caching: Option<(Flight<'c, W>, Vec<event::Step<W>>)>,
+}
+
+impl<'c, W: World> CachedBackground<'c, W> {
+    /// Acquires the `@cached-background` snapshot of the provided
+    /// [`ScenarioRun`], if it's enabled.
+    ///
+    /// A snapshotted `World` is restored into the provided `world` right away,
+    /// so this should be called before running any `Before` hooks on it,
+    /// otherwise their changes would be lost.
+    pub(in super::super) async fn acquire<S>(
+        run: &ScenarioRun<'c, W, S>,
+        world: &mut W,
+    ) -> Self {
+        let cache = run.background_cache.filter(|_| {
+            BackgroundCache::<W>::is_enabled(&run.feature, run.rule.as_deref())
+        });
+        let acquired = match cache {
+            Some(c) => Some(c.acquire(&run.feature, run.rule.as_ref()).await),
+            None => None,
+        };
+        match acquired {
+            Some(Acquired::Restored((restored, results))) => {
+                *world = restored;
+                Self { restored: Some(results.into_iter()), caching: None }
+            }
+            Some(Acquired::Flight(flight)) => {
+                Self { restored: None, caching: Some((flight, Vec::new())) }
+            }
+            None => Self { restored: None, caching: None },
+        }
+    }
+
+    /// Returns the next restored result of a `Background` [`gherkin::Step`],
+    /// if any.
+    pub(super) fn next_restored(&mut self) -> Option<event::Step<W>> {
+        self.restored.as_mut().and_then(Iterator::next)
+    }
+
+    /// Collects the provided `result` of a `Background` [`gherkin::Step`] to
+    /// be snapshotted.
+    pub(super) fn collect(&mut self, result: &event::Step<W>) {
+        if let Some((_, results)) = &mut self.caching {
+            results.push(result.clone());
+        }
+    }
+
+    /// Finishes collecting the `Background` results, snapshotting them along
+    /// with the provided `world` if they're `clean`.
+    ///
+    /// Otherwise, the [`Flight`] is dropped, letting another
+    /// [`gherkin::Scenario`] execute the `Background`.
+    pub(super) fn finish(&mut self, world: &W, clean: bool) {
+        if let Some((flight, results)) = self.caching.take().filter(|_| clean) {
+            flight.store(world, results);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Step execution logic for the Basic executor.
+
+mod background;
+mod outcome;
+mod run;
+#[cfg(test)]
+mod tests;
+
+use std::time::Duration;
+
+pub(super) use self::background::CachedBackground;
+use self::outcome::Outcome;
+use super::{
+    super::{
+        cli_and_types::AfterPanic,
+        supporting_structures::{
+            AfterHookEventsMeta, ScenarioId, coerce_into_info,
+        },
+    },
+    background_cache::BackgroundCache,
+};
+#[cfg(feature = "tracing")]
+use crate::tracing::SpanCloseWaiter;
+use crate::{
+    World,
+    event::{self, HookScope, HookType, source::Source},
+    fixture,
+    runtime::Runtime,
+    step,
+};
+
+/// [`gherkin::Scenario`] being run along with the state shared by all its
+/// [`gherkin::Step`]s.
+pub(super) struct ScenarioRun<'a, W, S> {
+    /// [`step::Collection`] to match the [`gherkin::Step`]s against.
+    pub(super) collection: &'a step::Collection<W>,
+
+    /// ID of the [`gherkin::Scenario`] run.
+    pub(super) id: ScenarioId,
+
+    /// [`gherkin::Feature`] of the [`gherkin::Scenario`].
+    pub(super) feature: Source<gherkin::Feature>,
+
+    /// [`gherkin::Rule`] of the [`gherkin::Scenario`], if any.
+    pub(super) rule: Option<Source<gherkin::Rule>>,
+
+    /// [`gherkin::Scenario`] being run.
+    pub(super) scenario: Source<gherkin::Scenario>,
+
+    /// [`fixture::Instances`] available to the [`gherkin::Step`]s.
+    pub(super) fixtures: &'a fixture::Instances,
+
+    /// Retries of the [`gherkin::Scenario`], if it's retried.
+    pub(super) retries: Option<event::Retries>,
+
+    /// [`BackgroundCache`] to restore and store `Background` results with.
+    pub(super) background_cache: Option<&'a BackgroundCache<W>>,
+
+    /// Interval of heartbeats of long-running [`gherkin::Step`]s.
+    pub(super) heartbeat: Option<Duration>,
+
+    /// Default timeout of the [`gherkin::Step`]s.
+    pub(super) step_timeout: Option<Duration>,
+
+    /// [`Runtime`] to run the timers on.
+    pub(super) runtime: &'a dyn Runtime,
+
+    /// Behavior after a failed [`gherkin::Step`].
+    pub(super) after_panic: AfterPanic,
+
+    /// Sender of the happened [`event::Cucumber`]s.
+    pub(super) send_event: S,
+
+    /// Waiter of the [`gherkin::Step`]s' spans being closed.
+    #[cfg(feature = "tracing")]
+    pub(super) waiter: Option<&'a SpanCloseWaiter>,
+}
+
+impl<W, S> ScenarioRun<'_, W, S>
+where
+    W: World,
+    S: Fn(event::Cucumber<W>),
+{
+    /// Emits the provided [`event::Scenario`] of the [`gherkin::Scenario`].
+    fn emit(&self, event: event::Scenario<W>) {
+        (self.send_event)(event::Cucumber::scenario(
+            self.feature.clone(),
+            self.rule.clone(),
+            self.scenario.clone(),
+            event::RetryableScenario {
+                event,
+                retries: self.retries,
+                id: Some(self.id),
+            },
+        ));
+    }
+
+    /// Emits the provided [`event::Step`] of the `step`, being a `Background`
+    /// one or not.
+    fn emit_step(
+        &self,
+        step: &Source<gherkin::Step>,
+        is_background: bool,
+        event: event::Step<W>,
+    ) {
+        let step = step.clone();
+        self.emit(if is_background {
+            event::Scenario::Background(step, event)
+        } else {
+            event::Scenario::Step(step, event)
+        });
+    }
+
+    /// Returns all the [`gherkin::Step`]s to run, along with the indicator
+    /// whether each of them is a `Background` one: [`gherkin::Feature`]'s
+    /// `Background` first, then [`gherkin::Rule`]'s, and then the
+    /// [`gherkin::Scenario`]'s own ones.
+    fn steps(&self) -> Vec<(Source<gherkin::Step>, bool)> {
+        let background = self
+            .feature
+            .background
+            .iter()
+            .chain(self.rule.iter().filter_map(|r| r.background.as_ref()))
+            .flat_map(|b| &b.steps)
+            .map(|s| (s, true));
+        let own = self.scenario.steps.iter().map(|s| (s, false));
+
+        background
+            .chain(own)
+            .map(|(s, is_background)| (Source::new(s.clone()), is_background))
+            .collect()
+    }
+
+    /// Rebuilds the `world` after a failed step, reporting it as a
+    /// [`HookType::Before`] [`HookScope::World`] hook.
+    ///
+    /// Returns `false` if a new [`World`] fails to be created.
+    async fn rebuild_world(&self, world: &mut W) -> bool {
+        self.emit(event::Scenario::hook_started(
+            HookType::Before,
+            HookScope::World,
+        ));
+        match W::new().await {
+            Ok(new) => {
+                *world = new;
+                self.emit(event::Scenario::hook_passed(
+                    HookType::Before,
+                    HookScope::World,
+                ));
+                true
+            }
+            Err(err) => {
+                let info = coerce_into_info(format!(
+                    "Failed to create World: {err:#}",
+                ));
+                self.emit(event::Scenario::hook_failed(
+                    HookType::Before,
+                    HookScope::World,
+                    None,
+                    info,
+                ));
+                false
+            }
+        }
+    }
+}
+
+/// Step execution functionality for the Executor.
+pub(super) struct StepExecutor;
+
+impl StepExecutor {
+    /// Runs all steps for a scenario.
+    ///
+    /// The provided [`CachedBackground`] should be acquired before running
+    /// `Before` hooks on the `world`.
+    pub(super) async fn run_steps<'c, W, S>(
+        run: &ScenarioRun<'c, W, S>,
+        world: &mut W,
+        mut background: CachedBackground<'c, W>,
+    ) -> AfterHookEventsMeta
+    where
+        W: World,
+        S: Fn(event::Cucumber<W>),
+    {
+        let mut outcome = Outcome::default();
+
+        for (step, is_background) in run.steps() {
+            if !is_background {
+                background.finish(world, outcome.is_clean());
+            }
+
+            if let Some(result) = (is_background && outcome.is_clean())
+                .then(|| background.next_restored())
+                .flatten()
+            {
+                run.emit_step(&step, true, event::Step::Started);
+                run.emit_step(&step, true, result);
+                continue;
+            }
+
+            if !outcome.is_running() {
+                // Skip remaining steps if one has already failed or skipped
+                // itself.
+                let ev = outcome.skip(run.after_panic);
+                run.emit_step(&step, is_background, ev);
+                continue;
+            }
+
+            let result = run::run_step(run, &step, is_background, world).await;
+
+            if is_background {
+                background.collect(&result);
+            }
+
+            outcome.record::<W>(result, &step, is_background, run.id);
+
+            if outcome.step_failed && run.after_panic == AfterPanic::Continue {
+                outcome.step_failed = !run.rebuild_world(world).await;
+            }
+        }
+
+        // `Scenario` may have no own steps, so the `Background` is snapshotted
+        // after all of them.
+        background.finish(world, outcome.is_clean());
+
+        AfterHookEventsMeta {
+            started: event::Metadata::new(()),
+            finished: event::Metadata::new(()),
+            scenario_finished: outcome.finish(),
+        }
+    }
+}
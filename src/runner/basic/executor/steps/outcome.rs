@@ -0,0 +1,251 @@
+//! Outcome of the [`gherkin::Step`]s of a [`gherkin::Scenario`].
+
+use super::super::super::{
+    cli_and_types::AfterPanic,
+    supporting_structures::{ExecutionFailure, ScenarioId},
+};
+use crate::{
+    event::{self, source::Source},
+    step,
+};
+
+/// Failure of a [`gherkin::Step`] failing the whole [`gherkin::Scenario`].
+type StepFailure =
+    (Option<regex::CaptureLocations>, Option<step::Location>, event::StepError);
+
+/// Outcome of the [`gherkin::Step`]s of a [`gherkin::Scenario`] run so far.
+#[derive(Default)]
+pub(super) struct Outcome {
+    /// Number of the skipped [`gherkin::Step`]s.
+    skipped_steps: usize,
+
+    /// Indicator whether the rest [`gherkin::Step`]s shouldn't be run, as
+    /// one has failed.
+    pub(super) step_failed: bool,
+
+    /// Reason of a [`gherkin::Step`] skipping itself, skipping the rest ones
+    /// as well.
+    skipped_with: Option<step::Skip>,
+
+    /// Last failure of a [`gherkin::Step`].
+    last_failure: Option<StepFailure>,
+}
+
+impl Outcome {
+    /// Indicates whether no [`gherkin::Step`] has failed or skipped itself.
+    pub(super) const fn is_clean(&self) -> bool {
+        self.last_failure.is_none() && self.skipped_with.is_none()
+    }
+
+    /// Indicates whether the next [`gherkin::Step`] should be run.
+    pub(super) const fn is_running(&self) -> bool {
+        !self.step_failed && self.skipped_with.is_none()
+    }
+
+    /// Skips the next [`gherkin::Step`], returning the [`event::Step`] to be
+    /// reported for it.
+    pub(super) fn skip<W>(
+        &mut self,
+        after_panic: AfterPanic,
+    ) -> event::Step<W> {
+        self.skipped_steps += 1;
+        if self.skipped_with.is_none() && after_panic == AfterPanic::NotRun {
+            event::Step::NotRun
+        } else {
+            event::Step::Skipped(self.skipped_with.clone())
+        }
+    }
+
+    /// Records the `result` of the provided [`gherkin::Step`].
+    #[cfg_attr(
+        not(feature = "tracing"),
+        expect(unused_variables, reason = "only traced")
+    )]
+    pub(super) fn record<W>(
+        &mut self,
+        result: event::Step<W>,
+        step: &Source<gherkin::Step>,
+        is_background: bool,
+        id: ScenarioId,
+    ) {
+        // Create execution failure for potential recovery handling
+        let _failure = create_execution_failure_from_step_result(
+            &result,
+            step.clone(),
+            is_background,
+        );
+        match result {
+            event::Step::Started
+            | event::Step::Heartbeat { .. }
+            | event::Step::Published(_)
+            | event::Step::NotRun
+            | event::Step::Passed { .. } => {}
+            event::Step::Skipped(reason) => {
+                self.skipped_steps += 1;
+                self.skipped_with = reason;
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    scenario_id = ?id,
+                    step_text = %step.value,
+                    "Step was skipped in scenario execution"
+                );
+            }
+            event::Step::Failed { captures, location, error, .. } => {
+                self.step_failed = true;
+                self.last_failure = Some((captures, location, error));
+
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    scenario_id = ?id,
+                    step_text = %step.value,
+                    is_background = is_background,
+                    "Step failed during scenario execution"
+                );
+            }
+        }
+    }
+
+    /// Determines the [`gherkin::Scenario`] outcome based on canonical
+    /// Cucumber behavior:
+    /// 1. If any step failed -> `StepFailed`
+    /// 2. If any step was skipped (but none failed) -> `StepSkipped`
+    /// 3. If all steps passed -> `StepPassed`
+    pub(super) fn finish(self) -> event::ScenarioFinished {
+        if let Some((captures, location, error)) = self.last_failure {
+            event::ScenarioFinished::StepFailed(captures, location, error)
+        } else if self.skipped_steps > 0 {
+            event::ScenarioFinished::StepSkipped
+        } else {
+            event::ScenarioFinished::StepPassed
+        }
+    }
+}
+
+/// Creates an [`ExecutionFailure::StepSkipped`] from a skipped step scenario.
+const fn create_step_skipped_failure<W>(
+    world: Option<W>,
+) -> ExecutionFailure<W> {
+    ExecutionFailure::StepSkipped(world)
+}
+
+/// Creates an [`ExecutionFailure::StepPanicked`] from a failed step.
+fn create_step_panicked_failure<W>(
+    world: Option<W>,
+    step: Source<gherkin::Step>,
+    captures: Option<regex::CaptureLocations>,
+    loc: Option<step::Location>,
+    err: event::StepError,
+    is_background: bool,
+) -> ExecutionFailure<W> {
+    ExecutionFailure::StepPanicked {
+        world,
+        step,
+        captures,
+        loc,
+        err,
+        meta: event::Metadata::new(()),
+        is_background,
+    }
+}
+
+/// Creates an [`ExecutionFailure`] based on the step execution result.
+fn create_execution_failure_from_step_result<W>(
+    step_result: &event::Step<W>,
+    step: Source<gherkin::Step>,
+    is_background: bool,
+) -> Option<ExecutionFailure<W>> {
+    match step_result {
+        event::Step::Failed { captures, location, error, .. } => {
+            Some(create_step_panicked_failure(
+                None, // World is not available here
+                step,
+                captures.clone(),
+                *location,
+                error.clone(),
+                is_background,
+            ))
+        }
+        event::Step::Skipped(_) => Some(create_step_skipped_failure(None)),
+        event::Step::Started
+        | event::Step::Heartbeat { .. }
+        | event::Step::Published(_)
+        | event::Step::NotRun
+        | event::Step::Passed { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::super::supporting_structures::AfterHookEventsMeta, *,
+    };
+    use crate::test_utils::common::TestWorld;
+
+    #[test]
+    fn test_after_hook_events_meta_creation() {
+        let meta = AfterHookEventsMeta {
+            started: event::Metadata::new(()),
+            finished: event::Metadata::new(()),
+            scenario_finished: event::ScenarioFinished::StepPassed,
+        };
+
+        // Just verify it can be created
+        assert!(matches!(meta.started, _));
+        assert!(matches!(meta.finished, _));
+    }
+
+    #[test]
+    fn test_after_hook_events_meta_default_values() {
+        let meta = AfterHookEventsMeta {
+            started: event::Metadata::new(()),
+            finished: event::Metadata::new(()),
+            scenario_finished: event::ScenarioFinished::StepPassed,
+        };
+
+        // Verify both fields exist
+        assert!(matches!(meta.started, _));
+        assert!(matches!(meta.finished, _));
+    }
+
+    #[test]
+    fn test_create_execution_failure_from_step_result() {
+        let step = Source::new(gherkin::Step {
+            ty: gherkin::StepType::Given,
+            keyword: "Given".into(),
+            value: "I have a test step".into(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 3, col: 1 },
+        });
+        let failed = event::Step::<TestWorld>::Failed {
+            captures: None,
+            location: Some(step::Location::new("test.rs", 1, 1)),
+            matched: None,
+            world: None,
+            error: event::StepError::NotFound,
+            owner: None,
+            timing: event::StepTiming::default(),
+            backtrace: None,
+        };
+        let skipped = event::Step::<TestWorld>::Skipped(None);
+        let passed = event::Step::<TestWorld>::Passed {
+            captures: regex::Regex::new("").unwrap().capture_locations(),
+            location: Some(step::Location::new("test.rs", 1, 1)),
+            timing: event::StepTiming::default(),
+        };
+
+        let failure = |ev| {
+            create_execution_failure_from_step_result(ev, step.clone(), false)
+        };
+        assert!(matches!(
+            failure(&failed),
+            Some(ExecutionFailure::StepPanicked { .. }),
+        ));
+        assert!(matches!(
+            failure(&skipped),
+            Some(ExecutionFailure::StepSkipped(None)),
+        ));
+        assert!(failure(&passed).is_none());
+    }
+}
@@ -0,0 +1,221 @@
+//! Running of a single [`gherkin::Step`].
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    time::Duration,
+};
+
+use futures::{FutureExt as _, channel::mpsc};
+
+use super::{
+    super::{
+        super::{backtrace, supporting_structures::into_step_error},
+        heartbeat, publishing, timeout,
+    },
+    ScenarioRun,
+};
+use crate::{
+    World,
+    event::{self, source::Source},
+    runtime::{Ext as _, Runtime},
+    step::{self, publish::Publisher},
+    tag::Tags,
+    time::Instant,
+};
+
+/// Result of executing a [`step::Function`]: either its outcome, or the
+/// exceeded timeout.
+type Execution = Result<Result<(), Box<dyn Any + Send>>, Duration>;
+
+/// Runs the provided [`gherkin::Step`], being a `Background` one or not,
+/// emitting its events and returning its final [`event::Step`].
+pub(super) async fn run_step<W, S>(
+    run: &ScenarioRun<'_, W, S>,
+    step: &Source<gherkin::Step>,
+    is_background: bool,
+    world: &mut W,
+) -> event::Step<W>
+where
+    W: World,
+    S: Fn(event::Cucumber<W>),
+{
+    run.emit_step(step, is_background, event::Step::Started);
+
+    #[cfg(feature = "tracing")]
+    let span = run.id.step_span(is_background);
+    #[cfg(feature = "tracing")]
+    let guard = span.enter();
+
+    let tags = Tags::of(&run.feature, run.rule.as_deref(), &run.scenario);
+    let matching_started = Instant::now();
+    let found = match run.collection.find_tagged(step, &tags) {
+        Ok(Some(found)) => Ok(found),
+        Ok(None) => Err(event::StepError::NotFound),
+        Err(e) => Err(event::StepError::AmbiguousMatch(e)),
+    };
+    let mut timing = event::StepTiming {
+        matching: matching_started.elapsed(),
+        ..event::StepTiming::default()
+    };
+    let (step_fn, captures, location, ctx) = match found {
+        Ok(found) => found,
+        Err(error) => {
+            let ev = event::Step::Failed {
+                captures: None,
+                location: None,
+                matched: None,
+                world: None,
+                error,
+                owner: None,
+                timing,
+                backtrace: None,
+            };
+            run.emit_step(step, is_background, ev.clone());
+            return ev;
+        }
+    };
+
+    let matched = event::StepMatch::of(&ctx).map(Arc::new);
+    let (ctx, publications) = with_scenario(run, ctx);
+    let checks = ctx.checks.clone();
+    let owner = ctx.owner.clone();
+    let execution_started = Instant::now();
+    let result = execute_step(
+        *step_fn,
+        world,
+        ctx,
+        publications,
+        timeout::of(&tags, run.step_timeout),
+        run,
+        |ev| run.emit_step(step, is_background, ev),
+    )
+    .await;
+    timing.execution = execution_started.elapsed();
+
+    #[cfg(feature = "tracing")]
+    {
+        drop(guard);
+        if let Some(waiter) = run.waiter {
+            if let Some(span_id) = span.id() {
+                waiter.wait_for_span_close(span_id).await;
+            }
+        }
+    }
+
+    let failed = |error, backtrace| event::Step::Failed {
+        captures: Some(captures.clone()),
+        location,
+        matched: matched.clone(),
+        world: None,
+        error,
+        owner: owner.clone(),
+        timing,
+        backtrace,
+    };
+    let ev = match result.map(|r| r.map(|()| checks.take())) {
+        Ok(Ok(None)) => {
+            event::Step::Passed { captures: captures.clone(), location, timing }
+        }
+        Ok(Ok(Some(failures))) => {
+            failed(event::StepError::Checks(failures), None)
+        }
+        Err(limit) => failed(event::StepError::Timeout(limit), None),
+        Ok(Err(err)) => {
+            // Taken regardless of the error, so isn't attached to any later
+            // one.
+            let backtrace = backtrace::take();
+            match err.downcast::<step::Skip>() {
+                Ok(skip) => event::Step::Skipped(Some(*skip)),
+                Err(err) => {
+                    let error = into_step_error(err);
+                    let backtrace = backtrace.filter(|_| {
+                        matches!(error, event::StepError::Panic(_))
+                    });
+                    failed(error, backtrace)
+                }
+            }
+        }
+    };
+
+    run.emit_step(step, is_background, ev.clone());
+    ev
+}
+
+/// Provides the [`step::Context`] with the [`gherkin::Scenario`] metadata and
+/// [`fixture::Instances`] of the `run`, along with a [`Publisher`] of the
+/// [`Custom`] events, which are returned as a receiver.
+///
+/// [`Custom`]: event::Custom
+/// [`fixture::Instances`]: crate::fixture::Instances
+fn with_scenario<W, S>(
+    run: &ScenarioRun<'_, W, S>,
+    ctx: step::Context,
+) -> (step::Context, mpsc::UnboundedReceiver<event::Custom>) {
+    let (publisher, publications) = Publisher::channel();
+    let ctx = ctx
+        .with_scenario(step::ScenarioMetadata {
+            id: Some(run.id),
+            ..step::ScenarioMetadata::of(
+                &run.feature,
+                run.rule.as_deref(),
+                &run.scenario,
+            )
+        })
+        .with_fixtures(run.fixtures.clone())
+        .with_publisher(publisher);
+    (ctx, publications)
+}
+
+/// Executes the provided [`step::Function`], forwarding the [`Custom`]
+/// events it publishes, limiting it with the provided `timeout` and
+/// reporting its heartbeats (if any) with the [`Runtime`] of the `run`.
+///
+/// [`step::Function::Sync`] is called inline instead, as can be neither
+/// interrupted nor observed while running.
+///
+/// [`Custom`]: event::Custom
+async fn execute_step<W, S>(
+    step_fn: step::Function<W>,
+    world: &mut W,
+    ctx: step::Context,
+    publications: mpsc::UnboundedReceiver<event::Custom>,
+    timeout: Option<Duration>,
+    run: &ScenarioRun<'_, W, S>,
+    send_step_event: impl Fn(event::Step<W>),
+) -> Execution {
+    let forward = |ev| send_step_event(event::Step::Published(ev));
+    let step_fn = match step_fn {
+        step::Function::Async(step_fn) => step_fn,
+        step::Function::Sync(step_fn) => {
+            let out = panic::catch_unwind(AssertUnwindSafe(|| {
+                step_fn(world, ctx);
+            }));
+            publishing::forward_pending(publications, forward);
+            return Ok(out);
+        }
+    };
+
+    let runtime: &dyn Runtime = run.runtime;
+    let execution = publishing::forwarding(
+        AssertUnwindSafe(step_fn(world, ctx)).catch_unwind(),
+        publications,
+        forward,
+    );
+    let execution = async {
+        match timeout {
+            Some(limit) => runtime.timeout(execution, limit).await,
+            None => Ok(execution.await),
+        }
+    };
+    match run.heartbeat {
+        Some(interval) => {
+            heartbeat::beating(execution, interval, runtime, |elapsed| {
+                send_step_event(event::Step::Heartbeat { elapsed });
+            })
+            .await
+        }
+        None => execution.await,
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use super::{run, *};
+use crate::{runtime::Agnostic, test_utils::common::TestWorld};
+
+/// Events emitted by a [`ScenarioRun`].
+type Events = Arc<Mutex<Vec<event::Cucumber<TestWorld>>>>;
+
+/// Parses a [`gherkin::Feature`] with a single [`gherkin::Scenario`] from the
+/// provided Gherkin `src`.
+fn parse(src: &str) -> (Source<gherkin::Feature>, Source<gherkin::Scenario>) {
+    let mut feature =
+        gherkin::Feature::parse(src, gherkin::GherkinEnv::default()).unwrap();
+    let scenario = feature.scenarios.remove(0);
+    (Source::new(feature), Source::new(scenario))
+}
+
+/// Creates a [`ScenarioRun`] of the [`gherkin::Scenario`] with a single
+/// `Given I have a test step`, collecting its events into the returned
+/// [`Events`].
+fn scenario_run<'a>(
+    collection: &'a step::Collection<TestWorld>,
+    fixtures: &'a fixture::Instances,
+) -> (ScenarioRun<'a, TestWorld, impl Fn(event::Cucumber<TestWorld>)>, Events) {
+    let (feature, scenario) =
+        parse("Feature: F\n  Scenario: S\n    Given I have a test step\n");
+    let events = Events::default();
+    let sink = Arc::clone(&events);
+    let run = ScenarioRun {
+        collection,
+        id: ScenarioId::new(),
+        feature,
+        rule: None,
+        scenario,
+        fixtures,
+        retries: None,
+        background_cache: None,
+        heartbeat: None,
+        step_timeout: None,
+        runtime: &Agnostic,
+        after_panic: AfterPanic::Skip,
+        send_event: move |ev| sink.lock().unwrap().push(ev),
+        #[cfg(feature = "tracing")]
+        waiter: None,
+    };
+    (run, events)
+}
+
+/// Returns the [`event::Scenario`]s of the collected [`Events`].
+fn scenario_events(events: &Events) -> Vec<event::Scenario<TestWorld>> {
+    events
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|ev| match ev {
+            event::Cucumber::Feature(
+                _,
+                event::Feature::Scenario(
+                    _,
+                    event::RetryableScenario { event, .. },
+                ),
+            ) => Some(event.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Runs the first [`gherkin::Step`] of the provided [`ScenarioRun`].
+async fn run_first_step<S: Fn(event::Cucumber<TestWorld>)>(
+    run: &ScenarioRun<'_, TestWorld, S>,
+) -> event::Step<TestWorld> {
+    let step = Source::new(run.scenario.steps[0].clone());
+    run::run_step(run, &step, false, &mut TestWorld).await
+}
+
+#[tokio::test]
+async fn test_run_steps_empty_scenario() {
+    let (collection, fixtures) = (step::Collection::new(), Default::default());
+    let (mut run, events) = scenario_run(&collection, &fixtures);
+    run.scenario = parse("Feature: F\n  Scenario: S\n").1;
+
+    let background = CachedBackground::acquire(&run, &mut TestWorld).await;
+    let meta = StepExecutor::run_steps(&run, &mut TestWorld, background).await;
+
+    assert!(matches!(
+        meta.scenario_finished,
+        event::ScenarioFinished::StepPassed,
+    ));
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_run_steps_with_background_steps() {
+    let collection = step::Collection::new().given(
+        None,
+        "^I have a (background|test) step$".parse().unwrap(),
+        |_, _| Box::pin(async {}),
+    );
+    let fixtures = fixture::Instances::default();
+    let (mut run, events) = scenario_run(&collection, &fixtures);
+    (run.feature, run.scenario) = parse(
+        "Feature: F\n  \
+           Background:\n    Given I have a background step\n  \
+           Scenario: S\n    Given I have a test step\n",
+    );
+
+    let background = CachedBackground::acquire(&run, &mut TestWorld).await;
+    let meta = StepExecutor::run_steps(&run, &mut TestWorld, background).await;
+
+    assert!(matches!(
+        meta.scenario_finished,
+        event::ScenarioFinished::StepPassed,
+    ));
+    let kinds = scenario_events(&events)
+        .into_iter()
+        .map(|ev| match ev {
+            event::Scenario::Background(s, event::Step::Passed { .. }) => {
+                format!("background: {}", s.value)
+            }
+            event::Scenario::Step(s, event::Step::Passed { .. }) => {
+                format!("step: {}", s.value)
+            }
+            _ => String::new(),
+        })
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        ["background: I have a background step", "step: I have a test step"],
+    );
+}
+
+#[tokio::test]
+async fn skips_rest_steps_with_reason() {
+    let collection = step::Collection::new().given(
+        None,
+        "^I have a test step$".parse().unwrap(),
+        |_, ctx| Box::pin(async move { ctx.skip("no GPU available") }),
+    );
+    let fixtures = fixture::Instances::default();
+    let (mut run, events) = scenario_run(&collection, &fixtures);
+    let mut scenario = (*run.scenario).clone();
+    let mut next = scenario.steps[0].clone();
+    next.value = "I have another test step".into();
+    scenario.steps.push(next);
+    run.scenario = Source::new(scenario);
+
+    let background = CachedBackground::acquire(&run, &mut TestWorld).await;
+    let meta = StepExecutor::run_steps(&run, &mut TestWorld, background).await;
+
+    assert!(matches!(
+        meta.scenario_finished,
+        event::ScenarioFinished::StepSkipped,
+    ));
+    let reasons = scenario_events(&events)
+        .into_iter()
+        .filter_map(|ev| match ev {
+            event::Scenario::Step(_, event::Step::Skipped(reason)) => {
+                Some(reason)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let reason = Some(step::Skip("no GPU available".into()));
+    assert_eq!(reasons, [reason.clone(), reason]);
+}
+
+#[tokio::test]
+async fn fails_step_with_all_failed_checks() {
+    let collection = step::Collection::new().given(
+        None,
+        "^I have a test step$".parse().unwrap(),
+        |_, ctx| {
+            Box::pin(async move {
+                _ = ctx.check(1 + 1 == 3, "math is broken");
+                _ = ctx.check(true, "truth holds");
+                _ = ctx.check(false, "falsehood holds");
+            })
+        },
+    );
+    let fixtures = fixture::Instances::default();
+    let (run, _) = scenario_run(&collection, &fixtures);
+
+    let ev = run_first_step(&run).await;
+
+    let event::Step::Failed { error: event::StepError::Checks(e), .. } = ev
+    else {
+        panic!("expected failed checks, got: {ev:?}");
+    };
+    assert_eq!(e.0, ["math is broken", "falsehood holds"]);
+}
+
+#[tokio::test]
+async fn runs_sync_steps_inline() {
+    let collection = step::Collection::new().given_sync(
+        None,
+        "^I have a test step$".parse().unwrap(),
+        |_, ctx| {
+            ctx.publish("card charged");
+            std::thread::sleep(Duration::from_millis(20));
+            panic!("receipt lost");
+        },
+    );
+    let fixtures = fixture::Instances::default();
+    let (mut run, events) = scenario_run(&collection, &fixtures);
+    run.heartbeat = Some(Duration::from_millis(1));
+    run.step_timeout = Some(Duration::from_millis(1));
+
+    let ev = run_first_step(&run).await;
+
+    let event::Step::Failed { error: event::StepError::Panic(_), .. } = ev
+    else {
+        panic!("expected panicked step, got: {ev:?}");
+    };
+    let published = scenario_events(&events)
+        .into_iter()
+        .filter_map(|ev| match ev {
+            event::Scenario::Step(_, ev) => Some(ev),
+            _ => None,
+        })
+        .filter(|ev| !matches!(ev, event::Step::Failed { .. }))
+        .collect::<Vec<_>>();
+    assert!(
+        matches!(
+            published.as_slice(),
+            [event::Step::Started, event::Step::Published(ev)]
+                if *ev == event::Custom::new("card charged"),
+        ),
+        "{published:?}",
+    );
+}
+
+#[tokio::test]
+async fn reports_owner_of_failed_step() {
+    let collection = step::Collection::new()
+        .given(None, "^I have a test step$".parse().unwrap(), |_, _| {
+            Box::pin(async { panic!("payment declined") })
+        })
+        .owned_by(step::Ownership::new().team("payments"));
+    let fixtures = fixture::Instances::default();
+    let (run, _) = scenario_run(&collection, &fixtures);
+
+    let ev = run_first_step(&run).await;
+
+    let event::Step::Failed { owner: Some(owner), .. } = ev else {
+        panic!("expected owned failed step, got: {ev:?}");
+    };
+    assert_eq!(owner.team.as_deref(), Some("payments"));
+}
+
+#[tokio::test]
+async fn reports_match_of_failed_step() {
+    let collection = step::Collection::new().given(
+        None,
+        "^I have a (?<kind>\\w+) (step)$".parse().unwrap(),
+        |_, _| Box::pin(async { panic!("login rejected") }),
+    );
+    let fixtures = fixture::Instances::default();
+    let (run, _) = scenario_run(&collection, &fixtures);
+
+    let ev = run_first_step(&run).await;
+
+    let event::Step::Failed { matched: Some(matched), .. } = ev else {
+        panic!("expected matched failed step, got: {ev:?}");
+    };
+    assert_eq!(matched.pattern, "^I have a (?<kind>\\w+) (step)$");
+    assert_eq!(
+        matched.captures,
+        [(Some("kind".into()), "test".into()), (None, "step".into())],
+    );
+}
+
+#[test]
+fn test_step_executor_emit_skipped_event() {
+    let (collection, fixtures) = (step::Collection::new(), Default::default());
+    let (run, events) = scenario_run(&collection, &fixtures);
+    let step = Source::new(run.scenario.steps[0].clone());
+
+    run.emit_step(&step, true, event::Step::Skipped(None));
+    run.emit_step(&step, false, event::Step::Skipped(None));
+
+    assert!(matches!(
+        scenario_events(&events).as_slice(),
+        [
+            event::Scenario::Background(_, event::Step::Skipped(None)),
+            event::Scenario::Step(_, event::Step::Skipped(None)),
+        ],
+    ));
+}
@@ -0,0 +1,74 @@
+//! `World` teardown execution logic for the Basic executor.
+
+use std::panic::AssertUnwindSafe;
+
+use futures::FutureExt as _;
+
+use super::super::supporting_structures::coerce_into_info;
+use crate::{
+    World,
+    event::{self, HookScope, HookType, Info, source::Source},
+    runner::basic::ScenarioId,
+};
+
+/// Runs [`World::teardown()`] of the provided `world`.
+///
+/// Failure of the teardown (either an error or a panic) is reported as a
+/// failed [`HookType::After`] of the [`HookScope::World`], so the
+/// [`gherkin::Scenario`] fails.
+pub(super) async fn run<W: World>(
+    id: ScenarioId,
+    feature: Source<gherkin::Feature>,
+    rule: Option<Source<gherkin::Rule>>,
+    scenario: Source<gherkin::Scenario>,
+    world: &mut W,
+    send_event: impl Fn(event::Cucumber<W>),
+) {
+    let info = match AssertUnwindSafe(world.teardown()).catch_unwind().await {
+        Ok(Ok(())) => return,
+        Ok(Err(e)) => coerce_into_info(format!("World teardown: {e}")),
+        Err(panic) => coerce_into_info(panic),
+    };
+
+    #[cfg(feature = "tracing")]
+    tracing::error!(
+        scenario_name = %scenario.name,
+        feature_name = %feature.name,
+        "World teardown failed"
+    );
+
+    report_failure(
+        id,
+        HookScope::World,
+        &feature,
+        rule.as_ref(),
+        &scenario,
+        info,
+        send_event,
+    );
+}
+
+/// Reports the failed teardown with the provided `info` as a failed
+/// [`HookType::After`] of the provided [`HookScope`].
+pub(super) fn report_failure<W>(
+    id: ScenarioId,
+    scope: HookScope,
+    feature: &Source<gherkin::Feature>,
+    rule: Option<&Source<gherkin::Rule>>,
+    scenario: &Source<gherkin::Scenario>,
+    info: Info,
+    send_event: impl Fn(event::Cucumber<W>),
+) {
+    for hook in [event::Hook::Started, event::Hook::Failed(None, info)] {
+        send_event(event::Cucumber::scenario(
+            feature.clone(),
+            rule.cloned(),
+            scenario.clone(),
+            event::RetryableScenario {
+                event: event::Scenario::Hook(HookType::After, scope, hook),
+                retries: None,
+                id: Some(id),
+            },
+        ));
+    }
+}
@@ -0,0 +1,50 @@
+//! Timeouts of long-running steps.
+
+use std::time::Duration;
+
+use crate::tag::Tags;
+
+/// Name of the tag overriding the [`Executor`]'s step timeout for a
+/// [`gherkin::Scenario`].
+///
+/// [`Executor`]: super::Executor
+const TAG: &str = "timeout";
+
+/// Resolves the limit of a single step execution in a [`gherkin::Scenario`]
+/// with the provided [`Tags`], falling back to the `default` one.
+///
+/// The `@timeout(<duration>)` tag accepts either a [`humantime`] duration
+/// (like `@timeout(500ms)`) or a number of seconds (like `@timeout(30)`),
+/// while an invalid value is ignored.
+pub(super) fn of(tags: &Tags, default: Option<Duration>) -> Option<Duration> {
+    tags.value(TAG)
+        .and_then(|v| {
+            v.parse()
+                .map(Duration::from_secs)
+                .ok()
+                .or_else(|| humantime::parse_duration(v).ok())
+        })
+        .or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::of;
+    use crate::tag::Tags;
+
+    #[test]
+    fn tag_overrides_default() {
+        let default = Some(Duration::from_secs(1));
+
+        for (tag, expected) in [
+            ("timeout(250ms)", Some(Duration::from_millis(250))),
+            ("timeout:3", Some(Duration::from_secs(3))),
+            ("timeout(soon)", default),
+            ("serial", default),
+        ] {
+            assert_eq!(of(&Tags::from_iter([tag]), default), expected, "{tag}");
+        }
+    }
+}
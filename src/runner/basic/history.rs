@@ -0,0 +1,187 @@
+//! Run-history store of [`gherkin::Scenario`]s durations.
+
+use std::{
+    cmp,
+    collections::HashMap,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Store of [`gherkin::Scenario`]s durations from the previous runs.
+///
+/// Used by a [`Basic`] runner to dispatch the historically slowest
+/// [`gherkin::Scenario`]s first, so the concurrency slots drain evenly near
+/// the end of the run.
+///
+/// Stored as a plain text file, where each line contains a
+/// [`gherkin::Scenario`] key (`.feature` file path and line) and its last
+/// duration in milliseconds, separated by a tab.
+///
+/// [`Basic`]: super::Basic
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RunHistory {
+    /// Path to the file this [`RunHistory`] is stored in.
+    path: PathBuf,
+
+    /// [`gherkin::Scenario`]s durations by their keys.
+    durations: HashMap<String, Duration>,
+}
+
+impl RunHistory {
+    /// Creates a new empty [`RunHistory`] to be stored at the provided `path`.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), durations: HashMap::new() }
+    }
+
+    /// Loads a [`RunHistory`] from the file at the provided `path`.
+    ///
+    /// Returns an empty [`RunHistory`] if the file doesn't exist yet, and
+    /// ignores malformed lines.
+    ///
+    /// # Errors
+    ///
+    /// If the file exists, but cannot be read.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let mut history = Self::new(path);
+        let contents = match fs::read_to_string(&history.path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Ok(history);
+            }
+            Err(e) => return Err(e),
+        };
+
+        history.durations = contents
+            .lines()
+            .filter_map(|l| {
+                let (key, millis) = l.rsplit_once('\t')?;
+                let millis = millis.trim().parse().ok()?;
+                Some((key.to_owned(), Duration::from_millis(millis)))
+            })
+            .collect();
+        Ok(history)
+    }
+
+    /// Stores this [`RunHistory`] into its file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let mut entries = self.durations.iter().collect::<Vec<_>>();
+        entries.sort();
+
+        let mut out = String::new();
+        for (key, dur) in entries {
+            _ = writeln!(out, "{key}\t{}", dur.as_millis());
+        }
+        fs::write(&self.path, out)
+    }
+
+    /// Returns path to the file this [`RunHistory`] is stored in.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the last recorded duration of the provided
+    /// [`gherkin::Scenario`], if any.
+    #[must_use]
+    pub fn duration(
+        &self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+    ) -> Option<Duration> {
+        self.durations.get(&Self::key(feature, scenario)).copied()
+    }
+
+    /// Records the provided `duration` of the [`gherkin::Scenario`].
+    pub fn record(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        duration: Duration,
+    ) {
+        _ = self.durations.insert(Self::key(feature, scenario), duration);
+    }
+
+    /// Sorts the provided `items` so the historically slowest
+    /// [`gherkin::Scenario`]s go first, and the ones without any history go
+    /// last, preserving the relative order otherwise.
+    pub(crate) fn sort_longest_first<T>(
+        &self,
+        items: &mut [T],
+        scenario: impl Fn(&T) -> (&gherkin::Feature, &gherkin::Scenario),
+    ) {
+        items.sort_by_cached_key(|item| {
+            let (f, s) = scenario(item);
+            cmp::Reverse(self.duration(f, s))
+        });
+    }
+
+    /// Builds a key identifying the provided [`gherkin::Scenario`] between
+    /// runs.
+    fn key(feature: &gherkin::Feature, scenario: &gherkin::Scenario) -> String {
+        let file = feature
+            .path
+            .as_deref()
+            .map_or_else(|| feature.name.clone(), |p| p.display().to_string());
+        format!("{file}:{}", scenario.position.line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feature() -> gherkin::Feature {
+        gherkin::Feature::parse(
+            "Feature: F\n\
+             \x20 Scenario: fast\n    Given a step\n\
+             \x20 Scenario: slow\n    Given a step\n\
+             \x20 Scenario: new\n    Given a step\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sorts_longest_first() {
+        let feature = feature();
+        let mut history = RunHistory::new("unused");
+        history.record(&feature, &feature.scenarios[0], Duration::from_secs(1));
+        history.record(&feature, &feature.scenarios[1], Duration::from_secs(5));
+
+        let mut scenarios = feature.scenarios.iter().collect::<Vec<_>>();
+        scenarios.reverse();
+        history.sort_longest_first(&mut scenarios, |s| (&feature, *s));
+
+        let names =
+            scenarios.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["slow", "fast", "new"]);
+    }
+
+    #[test]
+    fn saves_and_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.tsv");
+        let feature = feature();
+
+        assert_eq!(RunHistory::load(&path).unwrap(), RunHistory::new(&path));
+
+        let mut history = RunHistory::new(&path);
+        history.record(&feature, &feature.scenarios[1], Duration::from_secs(2));
+        history.save().unwrap();
+
+        let loaded = RunHistory::load(&path).unwrap();
+        assert_eq!(loaded, history);
+        assert_eq!(
+            loaded.duration(&feature, &feature.scenarios[1]),
+            Some(Duration::from_secs(2)),
+        );
+        assert_eq!(loaded.duration(&feature, &feature.scenarios[0]), None);
+    }
+}
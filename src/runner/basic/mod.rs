@@ -3,20 +3,31 @@
 //! This module provides the default [`crate::runner::Runner`] implementation that executes
 //! scenarios with configurable concurrency, retry logic, and hooks.
 
+mod backtrace;
 mod basic_struct;
 mod cli_and_types;
 mod execution_engine;
 mod executor;
+mod history;
 mod runner_impl;
 mod scenario_storage;
+mod sla;
+mod stop;
 mod supporting_structures;
+mod tracker;
+mod until_failure;
+mod warmup;
 
 // Re-export public APIs for backward compatibility
 pub use basic_struct::Basic;
 pub use cli_and_types::{
-    AfterHookFn, BeforeHookFn, Cli, RetryOptions, RetryOptionsFn,
-    RetryOptionsWithDeadline, ScenarioType, WhichScenarioFn,
+    AfterHookFn, AfterPanic, BackgroundSnapshotFn, BeforeHookFn, Cli,
+    RetryOptions, RetryOptionsFn, RetryOptionsWithDeadline, ScenarioType,
+    WhichScenarioFn,
 };
+pub use history::RunHistory;
+pub use sla::Sla;
+pub use stop::StopSignal;
 pub use supporting_structures::ScenarioId;
 
 #[cfg(test)]
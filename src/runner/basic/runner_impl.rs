@@ -1,5 +1,7 @@
 //! Runner trait implementation for the Basic runner.
 
+use std::sync::Arc;
+
 use futures::{
     FutureExt as _, Stream, StreamExt as _,
     channel::mpsc,
@@ -11,9 +13,11 @@ use super::{
     basic_struct::Basic,
     cli_and_types::Cli,
     execution_engine::{execute, insert_features},
+    history::RunHistory,
     scenario_storage::Features,
+    until_failure::UntilFailure,
 };
-use crate::{Event, Runner, World, event, parser};
+use crate::{Event, Runner, World, event, parser, step};
 
 impl<W, Which, Before, After> Runner<W> for Basic<W, Which, Before, After>
 where
@@ -47,6 +51,10 @@ where
         parser::Result<Event<event::Cucumber<W>>>,
     >;
 
+    fn step_collection(&self) -> Option<&step::Collection<W>> {
+        Some(&self.steps)
+    }
+
     fn run<S>(self, features: S, mut cli: Cli) -> Self::EventStream
     where
         S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
@@ -64,8 +72,21 @@ where
             before_hook,
             after_hook,
             fail_fast,
+            background_snapshot,
+            duration_history,
+            shared_state,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
             ..
         } = self;
+        let steps = steps.with_shared(shared_state);
 
         cli.retry = cli.retry.or(retries);
         cli.retry_after = cli.retry_after.or(retry_after);
@@ -73,7 +94,33 @@ where
         let fail_fast = cli.fail_fast || fail_fast;
         let concurrency = cli.concurrency.or(max_concurrent_scenarios);
 
-        let buffer = Features::default();
+        // Test impact analysis needs the whole `Collection` to map
+        // `gherkin::Step`s onto changed files, so is done before storing.
+        let impact = cli.changed_files.take().map(|c| (c, steps.clone()));
+        let features = features.map(move |feature| match &impact {
+            Some((changed, collection)) => {
+                feature.map(|f| changed.retain_affected(collection, f))
+            }
+            None => feature,
+        });
+
+        // Missing or unreadable history just means no reordering this run.
+        let history =
+            cli.duration_history.take().or(duration_history).map(|p| {
+                Arc::new(
+                    RunHistory::load(&p).unwrap_or_else(|_| RunHistory::new(p)),
+                )
+            });
+
+        let until_failure = UntilFailure::from_cli(&cli);
+        let heartbeat = cli.heartbeat.or(heartbeat);
+        let step_timeout = cli.step_timeout.or(step_timeout);
+        let resource_sampling = cli.sample_resources.or(resource_sampling);
+        let force_backtraces = cli.backtrace || force_backtraces;
+        let after_panic = cli.after_panic.unwrap_or(after_panic);
+        let buffer = Features::default()
+            .with_history(history.clone())
+            .with_recording(until_failure.is_some());
         let (sender, receiver) = mpsc::unbounded();
 
         let insert = insert_features(
@@ -93,6 +140,18 @@ where
             before_hook,
             after_hook,
             fail_fast,
+            background_snapshot,
+            history,
+            until_failure,
+            fixtures,
+            heartbeat,
+            step_timeout,
+            runtime,
+            resource_sampling,
+            stop,
+            sla,
+            force_backtraces,
+            after_panic,
             #[cfg(feature = "tracing")]
             logs_collector,
             #[cfg(feature = "observability")]
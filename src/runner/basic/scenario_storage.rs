@@ -8,7 +8,7 @@ use std::{
         Arc,
         atomic::{AtomicBool, Ordering},
     },
-    time::{Duration, Instant},
+    time::Duration,
 };
 
 use futures::{channel::mpsc, lock::Mutex};
@@ -19,11 +19,15 @@ use super::{
         Cli, RetryOptions, RetryOptionsFn, RetryOptionsWithDeadline,
         ScenarioType,
     },
+    history::RunHistory,
     supporting_structures::{IsFailed, IsRetried, ScenarioId},
+    until_failure::Recorded,
+    warmup::Warmups,
 };
 use crate::{
     event::{self, source::Source},
     feature::Ext as _,
+    time::Instant,
 };
 
 /// [`gherkin::Scenario`]s storage.
@@ -41,7 +45,7 @@ type Scenarios = HashMap<
 >;
 
 /// Alias of a [`Features::insert_scenarios()`] argument.
-type InsertedScenarios = HashMap<
+pub(super) type InsertedScenarios = HashMap<
     ScenarioType,
     Vec<(
         ScenarioId,
@@ -52,6 +56,16 @@ type InsertedScenarios = HashMap<
     )>,
 >;
 
+/// [`gherkin::Scenario`] ready to run, returned by [`Features::get()`].
+pub(super) type Runnable = (
+    ScenarioId,
+    Source<gherkin::Feature>,
+    Option<Source<gherkin::Rule>>,
+    Source<gherkin::Scenario>,
+    ScenarioType,
+    Option<RetryOptions>,
+);
+
 /// Storage sorted by [`ScenarioType`] [`Feature`]'s [`gherkin::Scenario`]s.
 ///
 /// [`Feature`]: gherkin::Feature
@@ -65,9 +79,60 @@ pub(super) struct Features {
     ///
     /// [`Feature`]: gherkin::Feature
     finished: Arc<AtomicBool>,
+
+    /// [`RunHistory`] to dispatch the historically slowest
+    /// [`ScenarioType::Concurrent`] [`gherkin::Scenario`]s first, if any.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    history: Option<Arc<RunHistory>>,
+
+    /// Inserted [`gherkin::Scenario`]s recorded for re-running them in the
+    /// next iteration of the run-until-failure mode, if it's enabled.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    recorded: Option<Recorded>,
+
+    /// Warm-up [`gherkin::Scenario`]s to execute before all the other ones.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    warmups: Warmups,
 }
 
 impl Features {
+    /// Sets the [`RunHistory`] to order [`ScenarioType::Concurrent`]
+    /// [`gherkin::Scenario`]s by.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    pub(super) fn with_history(
+        mut self,
+        history: Option<Arc<RunHistory>>,
+    ) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Enables recording of the inserted [`gherkin::Scenario`]s for
+    /// [`Features::reinsert_recorded()`].
+    ///
+    /// Recorded [`gherkin::Scenario`]s aren't stored right away, but only once
+    /// re-inserted for every iteration of the run-until-failure mode.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    pub(super) fn with_recording(mut self, enabled: bool) -> Self {
+        self.recorded = enabled.then(Recorded::default);
+        self
+    }
+
+    /// Re-inserts all the recorded [`gherkin::Scenario`]s as new ones,
+    /// shuffled with the provided `seed`, if recording is enabled.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    pub(super) async fn reinsert_recorded(&self, seed: u64) {
+        if let Some(recorded) = &self.recorded {
+            self.insert_scenarios(recorded.fresh(seed).await).await;
+        }
+    }
+
     /// Splits [`Feature`] into [`gherkin::Scenario`]s, sorts by [`ScenarioType`] and
     /// stores them.
     ///
@@ -89,7 +154,7 @@ impl Features {
     {
         let feature = Source::new(feature);
 
-        let local = feature
+        let mut local = feature
             .scenarios
             .iter()
             .map(|s| (None, s))
@@ -114,7 +179,12 @@ impl Features {
                 which_scenario(f, r.as_ref().map(AsRef::as_ref), s)
             });
 
-        self.insert_scenarios(local).await;
+        self.warmups.extract(&mut local).await;
+        if let Some(recorded) = &self.recorded {
+            recorded.record(local).await;
+        } else {
+            self.insert_scenarios(local).await;
+        }
     }
 
     /// Inserts the provided retried [`gherkin::Scenario`] into this [`Features`]
@@ -210,6 +280,24 @@ impl Features {
                 storage.entry(which).or_default().extend(values);
             }
         }
+
+        if let Some(history) = &self.history {
+            if let Some(concurrent) = storage.get_mut(&ScenarioType::Concurrent)
+            {
+                // Retried `Scenario`s keep going first, as they've been
+                // already scheduled.
+                let is_initial = |ret: &Option<RetryOptionsWithDeadline>| {
+                    ret.as_ref().is_none_or(|r| r.retries.current == 0)
+                };
+                concurrent.sort_by_key(|(.., ret)| is_initial(ret));
+                let initial =
+                    concurrent.partition_point(|(.., ret)| !is_initial(ret));
+                history.sort_longest_first(
+                    &mut concurrent[initial..],
+                    |(_, f, _, s, _)| (&**f, &**s),
+                );
+            }
+        }
     }
 
     /// Returns [`gherkin::Scenario`]s which are ready to run and the minimal deadline of
@@ -219,17 +307,7 @@ impl Features {
     pub(super) async fn get(
         &self,
         max_concurrent_scenarios: Option<usize>,
-    ) -> (
-        Vec<(
-            ScenarioId,
-            Source<gherkin::Feature>,
-            Option<Source<gherkin::Rule>>,
-            Source<gherkin::Scenario>,
-            ScenarioType,
-            Option<RetryOptions>,
-        )>,
-        Option<Duration>,
-    ) {
+    ) -> (Vec<Runnable>, Option<Duration>) {
         use RetryOptionsWithDeadline as WithDeadline;
         use ScenarioType::{Concurrent, Serial};
 
@@ -237,6 +315,18 @@ impl Features {
             return (Vec::new(), None);
         }
 
+        // Warm-up `Scenario`s run one by one before any other ones, so the
+        // latter are held until all the `Feature`s are parsed (as any of them
+        // may contain warm-ups) and all the warm-ups are done.
+        if let Some((id, f, r, s, ret)) = self.warmups.next().await {
+            return (vec![(id, f, r, s, Serial, ret)], None);
+        }
+        if !self.finished.load(Ordering::SeqCst)
+            || !self.warmups.is_done().await
+        {
+            return (Vec::new(), None);
+        }
+
         let mut min_dur = None;
         let mut drain =
             |storage: &mut Vec<(_, _, _, _, Option<WithDeadline>)>,
@@ -294,6 +384,14 @@ impl Features {
         self.finished.store(true, Ordering::SeqCst);
     }
 
+    /// Marks the [`gherkin::Scenario`] with the provided [`ScenarioId`] as
+    /// finished, releasing the next warm-up one, if any.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    pub(super) async fn scenario_finished(&self, id: ScenarioId) {
+        self.warmups.finished(id).await;
+    }
+
     /// Indicates whether there are more [`Feature`]s to execute.
     ///
     /// `fail_fast` argument indicates whether not yet executed scenarios should
@@ -303,7 +401,8 @@ impl Features {
     pub(super) async fn is_finished(&self, fail_fast: bool) -> bool {
         self.finished.load(Ordering::SeqCst)
             && (fail_fast
-                || self.scenarios.lock().await.values().all(Vec::is_empty))
+                || (self.scenarios.lock().await.values().all(Vec::is_empty)
+                    && self.warmups.is_done().await))
     }
 }
 
@@ -603,6 +702,7 @@ mod tests {
                 None,
             )
             .await;
+        features.finish();
 
         let (scenarios, _) = features.get(Some(5)).await;
         assert_eq!(scenarios.len(), 1);
@@ -668,6 +768,7 @@ mod tests {
         let cli = Cli::default();
 
         features.insert(feature, &which_scenario, &retry_fn, &cli).await;
+        features.finish();
 
         // Should get serial scenario first
         let (scenarios, _) = features.get(Some(5)).await;
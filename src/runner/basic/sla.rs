@@ -0,0 +1,150 @@
+//! Duration SLAs of [`gherkin::Scenario`]s configured per tag.
+
+use std::time::Duration;
+
+use crate::event::SlaViolation;
+
+/// Duration SLAs of [`gherkin::Scenario`]s, configured per tag.
+///
+/// A [`gherkin::Scenario`] (or its [`gherkin::Rule`] or [`gherkin::Feature`])
+/// tagged with a configured tag must finish within its budget, otherwise an
+/// [`event::Scenario::SlaViolated`] is emitted for it. If multiple configured
+/// tags apply, the strictest budget is enforced.
+///
+/// By default, a violation fails the [`gherkin::Scenario`] (without retrying
+/// it), while with [`Sla::fail_run()`] it only fails the whole run.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// #
+/// # use cucumber::runner::basic::Sla;
+/// #
+/// let sla = Sla::new()
+///     .tag("fast", Duration::from_secs(2))
+///     .tag("@slow", Duration::from_secs(60));
+/// ```
+///
+/// [`event::Scenario::SlaViolated`]: crate::event::Scenario::SlaViolated
+#[derive(Clone, Debug)]
+pub struct Sla {
+    /// Duration budgets by the tags (without the leading `@`).
+    budgets: Vec<(String, Duration)>,
+
+    /// Indicator whether a violation fails the [`gherkin::Scenario`], rather
+    /// than only the whole run.
+    fails_scenario: bool,
+}
+
+impl Default for Sla {
+    fn default() -> Self {
+        Self { budgets: Vec::new(), fails_scenario: true }
+    }
+}
+
+impl Sla {
+    /// Creates a new [`Sla`] without any budgets.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the [`gherkin::Scenario`]s tagged with the provided `tag` to
+    /// finish within the provided `budget`.
+    ///
+    /// The leading `@` of the `tag` is optional.
+    #[must_use]
+    pub fn tag(mut self, tag: impl AsRef<str>, budget: Duration) -> Self {
+        let tag = tag.as_ref();
+        let tag = tag.strip_prefix('@').unwrap_or(tag);
+        self.budgets.push((tag.to_owned(), budget));
+        self
+    }
+
+    /// Makes the violations to fail only the whole run, keeping the
+    /// [`gherkin::Scenario`]s passed.
+    #[must_use]
+    pub const fn fail_run(mut self) -> Self {
+        self.fails_scenario = false;
+        self
+    }
+
+    /// Checks the provided `elapsed` duration of the [`gherkin::Scenario`]
+    /// against the strictest budget of its tags, returning the
+    /// [`SlaViolation`], if any.
+    pub(crate) fn check(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        elapsed: Duration,
+    ) -> Option<SlaViolation> {
+        let tags = scenario
+            .tags
+            .iter()
+            .chain(rule.iter().flat_map(|r| &r.tags))
+            .chain(&feature.tags)
+            .collect::<Vec<_>>();
+        let (tag, budget) = self
+            .budgets
+            .iter()
+            .filter(|(tag, _)| tags.contains(&tag))
+            .min_by_key(|(_, budget)| *budget)?;
+
+        (elapsed > *budget).then(|| SlaViolation {
+            tag: tag.clone(),
+            budget: *budget,
+            elapsed,
+            fails_scenario: self.fails_scenario,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Sla;
+
+    /// Parses a [`gherkin::Feature`] with a single tagged scenario.
+    fn feature() -> gherkin::Feature {
+        gherkin::Feature::parse(
+            "@api\n\
+             Feature: SLA\n  \
+               @fast\n  \
+               Scenario: tagged\n    \
+                 Given a step\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn enforces_strictest_budget() {
+        let feature = feature();
+        let scenario = &feature.scenarios[0];
+        let sla = Sla::new()
+            .tag("@api", Duration::from_secs(10))
+            .tag("fast", Duration::from_secs(2))
+            .tag("slow", Duration::from_millis(1));
+
+        assert_eq!(
+            sla.check(&feature, None, scenario, Duration::from_secs(1)),
+            None,
+        );
+        let violation = sla
+            .check(&feature, None, scenario, Duration::from_secs(3))
+            .expect("`@fast` budget is violated");
+        assert_eq!(violation.tag, "fast");
+        assert_eq!(violation.budget, Duration::from_secs(2));
+        assert!(violation.fails_scenario);
+
+        let violation = sla
+            .fail_run()
+            .check(&feature, None, scenario, Duration::from_secs(11))
+            .expect("`@fast` budget is violated");
+        assert_eq!(violation.tag, "fast");
+        assert!(!violation.fails_scenario);
+    }
+}
@@ -0,0 +1,60 @@
+//! Graceful stop of a [`Basic`] runner requested from outside of it.
+//!
+//! [`Basic`]: super::Basic
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+/// Signal requesting a [`Basic`] runner to stop gracefully.
+///
+/// Once [requested][`StopSignal::request()`], no new [`gherkin::Scenario`]s are
+/// started, while the already running ones are finished normally, same as with
+/// the [`Basic::fail_fast()`] option.
+///
+/// Cloned [`StopSignal`]s share the same state, so any of them may be passed
+/// to the [`Basic::stop_signal()`] and requested from elsewhere (another
+/// thread or a [`writer::Server`], for example).
+///
+/// [`Basic`]: super::Basic
+/// [`Basic::fail_fast()`]: super::Basic::fail_fast
+/// [`Basic::stop_signal()`]: super::Basic::stop_signal
+/// [`writer::Server`]: crate::writer::Server
+#[derive(Clone, Debug, Default)]
+pub struct StopSignal(Arc<AtomicBool>);
+
+impl StopSignal {
+    /// Creates a new not yet requested [`StopSignal`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the graceful stop.
+    pub fn request(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Indicates whether the graceful stop has been requested.
+    #[must_use]
+    pub fn is_requested(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StopSignal;
+
+    #[test]
+    fn shared_between_clones() {
+        let signal = StopSignal::new();
+        let cloned = signal.clone();
+        assert!(!signal.is_requested());
+
+        cloned.request();
+        assert!(signal.is_requested());
+        assert!(cloned.is_requested());
+    }
+}
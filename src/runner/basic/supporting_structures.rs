@@ -22,6 +22,11 @@ use crate::{
 ///
 /// [`gherkin::Scenario`]: gherkin::Scenario
 #[derive(Clone, Copy, Debug, Display, Eq, FromStr, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
 pub struct ScenarioId(pub u64);
 
 impl ScenarioId {
@@ -211,6 +216,28 @@ pub(super) fn coerce_into_info<T: Any + Send + 'static>(val: T) -> Info {
     Arc::new(val)
 }
 
+/// Converts the provided panic payload of a [`Step`] function into an
+/// [`event::StepError`], recognizing [`step::ArgError`] and [`step::Failure`]
+/// ones.
+///
+/// [`Step`]: crate::Step
+pub(super) fn into_step_error(
+    payload: Box<dyn Any + Send + 'static>,
+) -> event::StepError {
+    let payload = match payload.downcast::<step::ArgError>() {
+        Ok(e) => return event::StepError::ArgMismatch(e),
+        Err(payload) => payload,
+    };
+    let payload = match payload.downcast::<step::Pending>() {
+        Ok(p) => return event::StepError::Pending(*p),
+        Err(payload) => payload,
+    };
+    match payload.downcast::<step::Failure>() {
+        Ok(f) => event::StepError::Returned(*f),
+        Err(payload) => event::StepError::Panic(coerce_into_info(payload)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,141 @@
+//! Tracking of the executed [`gherkin::Scenario`]s across the whole run.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use super::{
+    history::RunHistory, supporting_structures::ScenarioId,
+    until_failure::UntilFailure,
+};
+use crate::{
+    event::{self, source::Source},
+    time::Instant,
+};
+
+/// Tracker of the executed [`gherkin::Scenario`]s, feeding the [`RunHistory`]
+/// and the run-until-failure mode, if any of them is used.
+pub(super) struct Tracker {
+    /// [`RunHistory`] to record durations of the finished
+    /// [`gherkin::Scenario`]s into, if any.
+    history: Option<Arc<RunHistory>>,
+
+    /// Start times of the running [`gherkin::Scenario`]s, collected only when
+    /// the [`RunHistory`] is used.
+    started_at: HashMap<ScenarioId, (Source<gherkin::Scenario>, Instant)>,
+
+    /// Durations of the finished [`gherkin::Scenario`]s, collected only when
+    /// the [`RunHistory`] is used.
+    durations:
+        Vec<(Source<gherkin::Feature>, Source<gherkin::Scenario>, Duration)>,
+
+    /// State of the run-until-failure mode, if it's enabled.
+    until_failure: Option<UntilFailure>,
+}
+
+impl Tracker {
+    /// Creates a new [`Tracker`] with the provided [`RunHistory`] and
+    /// [`UntilFailure`] state.
+    pub(super) fn new(
+        history: Option<Arc<RunHistory>>,
+        until_failure: Option<UntilFailure>,
+    ) -> Self {
+        Self {
+            history,
+            started_at: HashMap::new(),
+            durations: Vec::new(),
+            until_failure,
+        }
+    }
+
+    /// Marks the [`gherkin::Scenario`] with the provided [`ScenarioId`] as
+    /// started.
+    pub(super) fn scenario_started(
+        &mut self,
+        id: ScenarioId,
+        feature: &gherkin::Feature,
+        scenario: &Source<gherkin::Scenario>,
+    ) {
+        if self.history.is_some() {
+            drop(
+                self.started_at.insert(id, (scenario.clone(), Instant::now())),
+            );
+        }
+        if let Some(state) = self.until_failure.as_mut() {
+            state.scenario_started(feature, scenario);
+        }
+    }
+
+    /// Marks the [`gherkin::Scenario`] with the provided [`ScenarioId`] as
+    /// finished, and whether it has `failed` without being retried.
+    pub(super) fn scenario_finished(
+        &mut self,
+        id: ScenarioId,
+        feature: &Source<gherkin::Feature>,
+        failed: bool,
+    ) {
+        if let Some((scenario, at)) = self.started_at.remove(&id) {
+            self.durations.push((feature.clone(), scenario, at.elapsed()));
+        }
+        if failed {
+            if let Some(state) = self.until_failure.as_mut() {
+                state.scenario_failed();
+            }
+        }
+    }
+
+    /// Advances to the next iteration of the run-until-failure mode, if it's
+    /// enabled and should go on.
+    ///
+    /// Returns the seed to shuffle the [`gherkin::Scenario`]s of the next
+    /// iteration with.
+    pub(super) fn next_iteration(&mut self) -> Option<u64> {
+        self.until_failure.as_mut().and_then(UntilFailure::next_iteration)
+    }
+
+    /// Finishes tracking, storing the [`RunHistory`], if any.
+    ///
+    /// Returns [`event::Report`]s about the whole run, including a failure to
+    /// store the [`RunHistory`], as it shouldn't fail the whole run.
+    pub(super) fn finish(self) -> Vec<event::Report> {
+        let mut reports = Vec::new();
+        reports
+            .extend(self.until_failure.as_ref().and_then(UntilFailure::report));
+
+        if let Some(history) = self.history {
+            let mut history = Arc::unwrap_or_clone(history);
+            for (feature, scenario, duration) in self.durations {
+                history.record(&feature, &scenario, duration);
+            }
+            if let Err(e) = history.save() {
+                reports.push(event::Report::HistoryNotSaved {
+                    path: history.path().to_owned(),
+                    error: e.to_string(),
+                });
+            }
+        }
+
+        reports
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unsaved_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").join("history.tsv");
+        let tracker =
+            Tracker::new(Some(Arc::new(RunHistory::new(&path))), None);
+
+        let reports = tracker.finish();
+
+        assert!(
+            matches!(
+                reports.as_slice(),
+                [event::Report::HistoryNotSaved { path: p, .. }] if *p == path,
+            ),
+            "{reports:?}",
+        );
+    }
+}
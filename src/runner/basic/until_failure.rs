@@ -0,0 +1,256 @@
+//! Run-until-failure mode of the Basic runner.
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher as _, RandomState},
+    sync::Arc,
+};
+
+use futures::lock::Mutex;
+
+use super::{
+    cli_and_types::Cli, scenario_storage::InsertedScenarios,
+    supporting_structures::ScenarioId,
+};
+use crate::event::{self, source::Source};
+
+/// Recorded [`gherkin::Scenario`]s inserted into a [`Features`] storage,
+/// allowing to re-insert them for the next iteration.
+///
+/// [`Features`]: super::scenario_storage::Features
+#[derive(Clone, Default)]
+pub(super) struct Recorded(Arc<Mutex<Vec<InsertedScenarios>>>);
+
+impl Recorded {
+    /// Records the provided inserted [`gherkin::Scenario`]s.
+    pub(super) async fn record(&self, scenarios: InsertedScenarios) {
+        self.0.lock().await.push(scenarios);
+    }
+
+    /// Returns all the recorded [`gherkin::Scenario`]s wrapped into fresh
+    /// [`Source`]s and [`ScenarioId`]s, so they're treated as new ones by
+    /// [`Writer`]s, and shuffled with the provided `seed`.
+    ///
+    /// [`Writer`]: crate::Writer
+    pub(super) async fn fresh(&self, seed: u64) -> InsertedScenarios {
+        let mut features = HashMap::new();
+        let mut rules = HashMap::new();
+        let mut fresh = InsertedScenarios::new();
+
+        for batch in self.0.lock().await.iter() {
+            #[expect(
+                clippy::iter_over_hash_type,
+                reason = "order doesn't matter"
+            )]
+            for (which, values) in batch {
+                for (_, f, r, s, ret) in values {
+                    let f = features
+                        .entry(f.clone())
+                        .or_insert_with(|| Source::new((**f).clone()))
+                        .clone();
+                    let r = r.as_ref().map(|r| {
+                        rules
+                            .entry(r.clone())
+                            .or_insert_with(|| Source::new((**r).clone()))
+                            .clone()
+                    });
+                    let s = Source::new((**s).clone());
+                    fresh.entry(*which).or_default().push((
+                        ScenarioId::new(),
+                        f,
+                        r,
+                        s,
+                        *ret,
+                    ));
+                }
+            }
+        }
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for values in fresh.values_mut() {
+            shuffle(values, seed);
+        }
+        fresh
+    }
+}
+
+/// State of the run-until-failure mode.
+#[derive(Debug)]
+pub(super) struct UntilFailure {
+    /// Maximum number of iterations, or [`None`] if unlimited.
+    max_iterations: Option<usize>,
+
+    /// Number of the current iteration, starting from `1`, or `0` if none
+    /// has been started yet.
+    iteration: usize,
+
+    /// Seed the [`gherkin::Scenario`]s of the current iteration are shuffled
+    /// with.
+    seed: u64,
+
+    /// Indicates whether any [`gherkin::Scenario`] has failed in the current
+    /// iteration.
+    failed: bool,
+
+    /// Keys of [`gherkin::Scenario`]s in the order they've been started in
+    /// the current iteration.
+    started: Vec<String>,
+}
+
+impl UntilFailure {
+    /// Creates a new [`UntilFailure`] state with the provided maximum number
+    /// of iterations, where `0` means unlimited.
+    ///
+    /// The first iteration is shuffled with the provided `seed`, or a random
+    /// one, if [`None`].
+    pub(super) fn new(max_iterations: usize, seed: Option<u64>) -> Self {
+        Self {
+            max_iterations: (max_iterations > 0).then_some(max_iterations),
+            iteration: 0,
+            seed: seed.unwrap_or_else(|| RandomState::new().hash_one(())),
+            failed: false,
+            started: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`UntilFailure`] state out of the provided [`Cli`]
+    /// options, if the run-until-failure mode is enabled.
+    pub(super) fn from_cli(cli: &Cli) -> Option<Self> {
+        cli.until_failure.map(|max| Self::new(max, cli.until_failure_seed))
+    }
+
+    /// Marks the provided [`gherkin::Scenario`] as started in the current
+    /// iteration.
+    pub(super) fn scenario_started(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+    ) {
+        let file = feature
+            .path
+            .as_deref()
+            .map_or_else(|| feature.name.clone(), |p| p.display().to_string());
+        self.started.push(format!("{file}:{}", scenario.position.line));
+    }
+
+    /// Marks the current iteration as failed.
+    pub(super) const fn scenario_failed(&mut self) {
+        self.failed = true;
+    }
+
+    /// Advances to the next iteration, unless the current one has failed or
+    /// the maximum number of iterations is reached.
+    ///
+    /// Returns the seed to shuffle the [`gherkin::Scenario`]s of the next
+    /// iteration with, or [`None`] if no more iterations should be run.
+    pub(super) fn next_iteration(&mut self) -> Option<u64> {
+        if self.failed
+            || self.max_iterations.is_some_and(|m| self.iteration >= m)
+        {
+            return None;
+        }
+        if self.iteration > 0 {
+            let mut state = self.seed;
+            self.seed = splitmix64(&mut state);
+        }
+        self.iteration += 1;
+        self.started.clear();
+        Some(self.seed)
+    }
+
+    /// Returns an [`event::Report`] about the failed iteration, its seed and
+    /// the order its [`gherkin::Scenario`]s have been started in, if any has
+    /// failed.
+    pub(super) fn report(&self) -> Option<event::Report> {
+        self.failed.then(|| {
+            event::Report::IterationFailed(event::IterationFailed {
+                iteration: self.iteration,
+                seed: self.seed,
+                scenarios: self.started.clone(),
+            })
+        })
+    }
+}
+
+/// Shuffles the provided `items` pseudo-randomly, but deterministically for
+/// the same `seed`.
+fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed;
+    for i in (1..items.len()).rev() {
+        let bound = u64::try_from(i + 1).unwrap_or(u64::MAX);
+        let j = usize::try_from(splitmix64(&mut state) % bound)
+            .unwrap_or_else(|_| unreachable!("`j <= i`"));
+        items.swap(i, j);
+    }
+}
+
+/// Advances the provided [SplitMix64] `state`, returning the next
+/// pseudo-random number.
+///
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+const fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feature() -> gherkin::Feature {
+        gherkin::Feature::parse(
+            "Feature: F\n  Scenario: S\n    Given a step\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn stops_on_failure() {
+        let feature = feature();
+        let mut state = UntilFailure::new(0, Some(42));
+
+        assert_eq!(state.next_iteration(), Some(42));
+        let seed = state.next_iteration().unwrap();
+        assert_ne!(seed, 42, "seed should change between iterations");
+        state.scenario_started(&feature, &feature.scenarios[0]);
+        state.scenario_failed();
+
+        assert_eq!(state.next_iteration(), None);
+        assert_eq!(
+            state.report(),
+            Some(event::Report::IterationFailed(event::IterationFailed {
+                iteration: 2,
+                seed,
+                scenarios: vec!["F:2".into()],
+            })),
+        );
+    }
+
+    #[test]
+    fn stops_on_max_iterations() {
+        let mut state = UntilFailure::new(2, None);
+
+        assert!(state.next_iteration().is_some());
+        assert!(state.next_iteration().is_some());
+        assert_eq!(state.next_iteration(), None);
+        assert_eq!(state.report(), None);
+    }
+
+    #[test]
+    fn shuffles_deterministically() {
+        let shuffled = |seed| {
+            let mut items = (0..10).collect::<Vec<_>>();
+            shuffle(&mut items, seed);
+            items
+        };
+
+        assert_eq!(shuffled(7), shuffled(7));
+        assert_ne!(shuffled(7), shuffled(8));
+        let mut sorted = shuffled(7);
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+}
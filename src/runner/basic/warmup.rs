@@ -0,0 +1,162 @@
+//! Warm-up [`gherkin::Scenario`]s executed before all the other ones.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use futures::lock::Mutex;
+
+use super::{
+    cli_and_types::{RetryOptions, ScenarioType},
+    scenario_storage::InsertedScenarios,
+    supporting_structures::ScenarioId,
+};
+use crate::{event::source::Source, scenario};
+
+/// Stored warm-up [`gherkin::Scenario`].
+type Warmup = (
+    ScenarioId,
+    Source<gherkin::Feature>,
+    Option<Source<gherkin::Rule>>,
+    Source<gherkin::Scenario>,
+    Option<RetryOptions>,
+);
+
+/// Queue of warm-up [`gherkin::Scenario`]s, executed one by one.
+#[derive(Clone, Default)]
+pub(super) struct Warmups(Arc<Mutex<State>>);
+
+/// [`Warmups`] queue state.
+#[derive(Default)]
+struct State {
+    /// Warm-up [`gherkin::Scenario`]s waiting for execution.
+    pending: VecDeque<Warmup>,
+
+    /// Currently running warm-up [`gherkin::Scenario`], if any.
+    running: Option<ScenarioId>,
+}
+
+impl Warmups {
+    /// Moves all the warm-up [`gherkin::Scenario`]s out of the provided
+    /// `scenarios` into this queue, preserving their order.
+    pub(super) async fn extract(&self, scenarios: &mut InsertedScenarios) {
+        let mut pending = Vec::new();
+        for ty in [ScenarioType::Serial, ScenarioType::Concurrent] {
+            if let Some(values) = scenarios.get_mut(&ty) {
+                pending.extend(values.extract_if(.., |(_, f, r, s, _)| {
+                    scenario::is_warmup(f, r.as_deref(), s)
+                }));
+            }
+        }
+        scenarios.retain(|_, values| !values.is_empty());
+        self.0.lock().await.pending.extend(pending);
+    }
+
+    /// Returns the next warm-up [`gherkin::Scenario`] to run, unless there is
+    /// one running already.
+    pub(super) async fn next(&self) -> Option<Warmup> {
+        let mut state = self.0.lock().await;
+        if state.running.is_some() {
+            return None;
+        }
+        let next = state.pending.pop_front()?;
+        state.running = Some(next.0);
+        drop(state);
+        Some(next)
+    }
+
+    /// Marks the [`gherkin::Scenario`] with the provided [`ScenarioId`] as
+    /// finished, if it's the running warm-up one.
+    pub(super) async fn finished(&self, id: ScenarioId) {
+        let mut state = self.0.lock().await;
+        if state.running == Some(id) {
+            state.running = None;
+        }
+    }
+
+    /// Indicates whether all the warm-up [`gherkin::Scenario`]s have been
+    /// executed.
+    pub(super) async fn is_done(&self) -> bool {
+        let state = self.0.lock().await;
+        state.pending.is_empty() && state.running.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{
+            cli_and_types::{Cli, RetryOptionsFn},
+            scenario_storage::Features,
+        },
+        *,
+    };
+
+    fn inserted() -> InsertedScenarios {
+        let feature = gherkin::Feature::parse(
+            "Feature: F\n\
+             \x20 @warmup\n  Scenario: login\n    Given a step\n\
+             \x20 Scenario: regular\n    Given a step\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap();
+        let feature = Source::new(feature);
+        let values = feature
+            .scenarios
+            .iter()
+            .map(|s| {
+                let s = Source::new(s.clone());
+                (ScenarioId::new(), feature.clone(), None, s, None)
+            })
+            .collect();
+        [(ScenarioType::Concurrent, values)].into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn runs_warmups_one_by_one() {
+        let warmups = Warmups::default();
+        let mut scenarios = inserted();
+
+        warmups.extract(&mut scenarios).await;
+
+        assert_eq!(scenarios[&ScenarioType::Concurrent].len(), 1);
+        assert!(!warmups.is_done().await);
+
+        let (id, .., s, _) = warmups.next().await.unwrap();
+        assert_eq!(s.name, "login");
+        assert!(warmups.next().await.is_none());
+        assert!(!warmups.is_done().await);
+
+        warmups.finished(id).await;
+        assert!(warmups.is_done().await);
+    }
+
+    #[tokio::test]
+    async fn holds_scenarios_until_warmups_of_all_features_are_done() {
+        let features = Features::default();
+        let insert = async |input: &str| {
+            let feature =
+                gherkin::Feature::parse(input, gherkin::GherkinEnv::default())
+                    .unwrap();
+            let which = |_: &_, _: Option<&_>, _: &_| ScenarioType::Concurrent;
+            let retry: RetryOptionsFn =
+                Arc::new(|_: &_, _: Option<&_>, _: &_, _: &_| None);
+            features.insert(feature, &which, &retry, &Cli::default()).await;
+        };
+
+        insert("Feature: A\n  Scenario: regular\n    Given a step\n").await;
+        assert!(features.get(None).await.0.is_empty(), "parsing unfinished");
+
+        insert("Feature: B\n  @warmup\n  Scenario: login\n    Given a step\n")
+            .await;
+        features.finish();
+
+        let warmup = features.get(None).await.0;
+        assert_eq!(warmup.len(), 1);
+        assert_eq!(warmup[0].3.name, "login");
+        assert!(features.get(None).await.0.is_empty(), "warm-up is running");
+
+        features.scenario_finished(warmup[0].0).await;
+        let regular = features.get(None).await.0;
+        assert_eq!(regular.len(), 1);
+        assert_eq!(regular[0].3.name, "regular");
+    }
+}
@@ -18,7 +18,7 @@ use futures::Stream;
 
 #[doc(inline)]
 pub use self::basic::{Basic, ScenarioType};
-use crate::{Event, event, parser};
+use crate::{Event, event, parser, step};
 #[cfg(doc)]
 use crate::{Step, event::Source};
 
@@ -89,4 +89,13 @@ pub trait Runner<World> {
     fn run<S>(self, features: S, cli: Self::Cli) -> Self::EventStream
     where
         S: Stream<Item = parser::Result<gherkin::Feature>> + 'static;
+
+    /// Returns the [`step::Collection`] this [`crate::runner::Runner`] matches
+    /// [`gherkin::Step`]s against, if it has one.
+    ///
+    /// Used for validating [`gherkin::Step`]s coverage without executing them
+    /// (see the `--validate` CLI option). Returns [`None`] by default.
+    fn step_collection(&self) -> Option<&step::Collection<World>> {
+        None
+    }
 }
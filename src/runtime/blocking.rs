@@ -0,0 +1,229 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Single-threaded [`block_on()`] executor along with its timers, spawning
+//! no threads.
+
+use std::{
+    cell::RefCell,
+    pin::{Pin, pin},
+    sync::Arc,
+    task::{self, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::Duration,
+};
+
+use crate::time::Instant;
+
+thread_local! {
+    /// [`Timers`] of the [`block_on()`] running on the current thread, if any.
+    static TIMERS: RefCell<Option<Timers>> = const { RefCell::new(None) };
+}
+
+/// Runs the provided [`Future`] to completion on the current thread, parking
+/// it until either the [`Future`] is woken, or the nearest deadline of a
+/// [`Blocking`] timer passes.
+///
+/// [`Blocking`]: super::Blocking
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    let _timers = Installed::new();
+    let waker = Waker::from(Arc::new(Unparker(thread::current())));
+    let mut cx = task::Context::from_waker(&waker);
+    let mut fut = pin!(fut);
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+        let nearest = TIMERS
+            .with_borrow_mut(|timers| timers.as_mut()?.fire(Instant::now()));
+        match nearest {
+            Some(deadline) => thread::park_timeout(
+                deadline.saturating_duration_since(Instant::now()),
+            ),
+            None => thread::park(),
+        }
+    }
+}
+
+/// [`Future`] resolving once the provided [`Duration`] elapses, measured by
+/// the [`block_on()`] it's polled in.
+#[derive(Debug)]
+pub(super) struct Sleep {
+    /// [`Instant`] this [`Sleep`] resolves at.
+    deadline: Instant,
+
+    /// ID of this [`Sleep`] in the [`Timers`], once scheduled.
+    id: Option<u64>,
+}
+
+impl Sleep {
+    /// Creates a new [`Sleep`] resolving once the provided [`Duration`]
+    /// elapses.
+    pub(super) fn new(dur: Duration) -> Self {
+        Self { deadline: Instant::now() + dur, id: None }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> Poll<Self::Output> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        let (deadline, id) = (self.deadline, self.id);
+        self.id = Some(TIMERS.with_borrow_mut(|timers| {
+            timers
+                .as_mut()
+                .unwrap_or_else(|| {
+                    panic!(
+                        "`runtime::Blocking` timers should be polled inside \
+                         `runtime::block_on()`",
+                    )
+                })
+                .schedule(id, deadline, cx.waker())
+        }));
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            // Ignored, as the thread may be terminating already.
+            _ = TIMERS.try_with(|timers| {
+                if let Some(t) = timers.borrow_mut().as_mut() {
+                    t.cancel(id);
+                }
+            });
+        }
+    }
+}
+
+/// [`Waker`] unparking the [`Thread`] a [`block_on()`] runs on.
+#[derive(Debug)]
+struct Unparker(Thread);
+
+impl Wake for Unparker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Pending [`Sleep`]s of a [`block_on()`].
+#[derive(Debug, Default)]
+struct Timers {
+    /// ID of the next scheduled [`Sleep`].
+    next_id: u64,
+
+    /// IDs, deadlines and [`Waker`]s of the pending [`Sleep`]s.
+    pending: Vec<(u64, Instant, Waker)>,
+}
+
+impl Timers {
+    /// Schedules waking the provided [`Waker`] at the provided `deadline`,
+    /// returning the ID to reschedule or [`Timers::cancel()`] it with.
+    fn schedule(
+        &mut self,
+        id: Option<u64>,
+        deadline: Instant,
+        waker: &Waker,
+    ) -> u64 {
+        if let Some(id) = id {
+            if let Some((.., w)) =
+                self.pending.iter_mut().find(|(i, ..)| *i == id)
+            {
+                w.clone_from(waker);
+                return id;
+            }
+        }
+        let new_id = self.next_id;
+        self.next_id += 1;
+        self.pending.push((new_id, deadline, waker.clone()));
+        new_id
+    }
+
+    /// Cancels the [`Sleep`] with the provided ID.
+    fn cancel(&mut self, id: u64) {
+        self.pending.retain(|(i, ..)| *i != id);
+    }
+
+    /// Wakes the [`Sleep`]s whose deadlines have passed by the provided `now`,
+    /// returning the nearest deadline of the remaining ones, if any.
+    fn fire(&mut self, now: Instant) -> Option<Instant> {
+        self.pending.retain(|(_, deadline, waker)| {
+            let passed = *deadline <= now;
+            if passed {
+                waker.wake_by_ref();
+            }
+            !passed
+        });
+        self.pending.iter().map(|(_, deadline, _)| *deadline).min()
+    }
+}
+
+/// Guard of [`Timers`] installed for the current thread, restoring the
+/// previous ones (of an outer [`block_on()`]) once dropped.
+struct Installed(Option<Timers>);
+
+impl Installed {
+    /// Installs new [`Timers`] for the current thread.
+    fn new() -> Self {
+        Self(TIMERS.replace(Some(Timers::default())))
+    }
+}
+
+impl Drop for Installed {
+    fn drop(&mut self) {
+        TIMERS.set(self.0.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::future::{self, Either};
+
+    use super::{Sleep, block_on};
+    use crate::time::Instant;
+
+    #[test]
+    fn sleeps_without_threads() {
+        let started = Instant::now();
+
+        block_on(Sleep::new(Duration::from_millis(50)));
+
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn wakes_nearest_timer_first() {
+        let out = block_on(future::select(
+            Sleep::new(Duration::from_secs(5)),
+            Sleep::new(Duration::from_millis(20)),
+        ));
+
+        assert!(matches!(out, Either::Right(_)));
+    }
+
+    #[test]
+    #[should_panic(expected = "polled inside `runtime::block_on()`")]
+    fn panics_outside_block_on() {
+        futures::executor::block_on(Sleep::new(Duration::from_millis(10)));
+    }
+}
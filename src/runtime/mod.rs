@@ -0,0 +1,249 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Async [`Runtime`]s measuring time of a [`runner::Basic`] run.
+//!
+//! [`runner::Basic`] drives all the [`gherkin::Scenario`]s concurrently on
+//! the task it's polled on, so isn't bound to any async runtime and never
+//! spawns. Only the timers of step timeouts, heartbeats and retry delays are
+//! provided by a [`Runtime`], which is [`Agnostic`] by default, and may be
+//! switched to the one of the async runtime a suite is run on via
+//! [`Cucumber::runtime()`].
+//!
+//! With `blocking` feature, a whole suite may be run on the current thread
+//! without any async runtime at all, via `runtime::block_on()` executor and
+//! `runtime::Blocking` timers (see `Cucumber::run_blocking()`).
+//!
+//! [`Cucumber::runtime()`]: crate::Cucumber::runtime
+//! [`runner::Basic`]: crate::runner::Basic
+
+#[cfg(all(
+    feature = "blocking",
+    not(all(target_family = "wasm", target_os = "unknown"))
+))]
+mod blocking;
+
+use std::{fmt::Debug, time::Duration};
+
+use futures::{
+    FutureExt as _,
+    future::{self, Either, LocalBoxFuture},
+    pin_mut,
+};
+use sealed::sealed;
+
+#[cfg(all(
+    feature = "blocking",
+    not(all(target_family = "wasm", target_os = "unknown"))
+))]
+pub use self::blocking::block_on;
+use crate::time;
+
+/// Async runtime providing timers to a [`runner::Basic`].
+///
+/// [`runner::Basic`]: crate::runner::Basic
+pub trait Runtime: Debug + Send + Sync + 'static {
+    /// Returns a [`Future`] resolving once the provided [`Duration`] elapses.
+    fn sleep(&self, dur: Duration) -> LocalBoxFuture<'static, ()>;
+}
+
+/// Extension of a [`Runtime`] with timeouts.
+#[sealed]
+pub trait Ext: Runtime {
+    /// Awaits the provided `fut`, giving up on it once the `limit` elapses.
+    ///
+    /// # Errors
+    ///
+    /// With the `limit` itself, if the `fut` hasn't completed in time.
+    fn timeout<F: Future>(
+        &self,
+        fut: F,
+        limit: Duration,
+    ) -> impl Future<Output = Result<F::Output, Duration>>;
+}
+
+#[sealed]
+impl<R: Runtime + ?Sized> Ext for R {
+    async fn timeout<F: Future>(
+        &self,
+        fut: F,
+        limit: Duration,
+    ) -> Result<F::Output, Duration> {
+        pin_mut!(fut);
+        match future::select(fut, self.sleep(limit)).await {
+            Either::Left((out, _)) => Ok(out),
+            Either::Right(((), _)) => Err(limit),
+        }
+    }
+}
+
+/// [`Runtime`] working with any async runtime.
+///
+/// Measures time in a single timer thread shared by all the timers (so no
+/// thread is spawned per timer), cancelling them as soon as the measured
+/// [`Future`] completes, or via JS timers on `wasm32-unknown-unknown` target.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Agnostic;
+
+impl Runtime for Agnostic {
+    fn sleep(&self, dur: Duration) -> LocalBoxFuture<'static, ()> {
+        time::sleep(dur).boxed_local()
+    }
+}
+
+/// [`Runtime`] measuring time via [`tokio`] timers.
+///
+/// Requires the [`Cucumber`] to be run on a [`tokio`] runtime with the time
+/// driver enabled.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{World, runtime};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .runtime(runtime::Tokio)
+///     .run("tests/features/readme")
+///     .await;
+/// # }
+/// ```
+///
+/// [`Cucumber`]: crate::Cucumber
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tokio;
+
+#[cfg(feature = "tokio")]
+impl Runtime for Tokio {
+    fn sleep(&self, dur: Duration) -> LocalBoxFuture<'static, ()> {
+        use tokio::time;
+
+        time::sleep(dur).boxed_local()
+    }
+}
+
+/// [`Runtime`] measuring time via [`async_std`] timers.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{World, runtime};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # fn main() {
+/// async_std::task::block_on(
+///     MyWorld::cucumber()
+///         .runtime(runtime::AsyncStd)
+///         .run("tests/features/readme"),
+/// );
+/// # }
+/// ```
+#[cfg(feature = "async-std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsyncStd;
+
+#[cfg(feature = "async-std")]
+impl Runtime for AsyncStd {
+    fn sleep(&self, dur: Duration) -> LocalBoxFuture<'static, ()> {
+        use async_std::task;
+
+        task::sleep(dur).boxed_local()
+    }
+}
+
+/// [`Runtime`] measuring time via [`smol`] timers.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{World, runtime};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # fn main() {
+/// smol::block_on(
+///     MyWorld::cucumber()
+///         .runtime(runtime::Smol)
+///         .run("tests/features/readme"),
+/// );
+/// # }
+/// ```
+#[cfg(feature = "smol")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Smol;
+
+#[cfg(feature = "smol")]
+impl Runtime for Smol {
+    fn sleep(&self, dur: Duration) -> LocalBoxFuture<'static, ()> {
+        smol::Timer::after(dur).map(drop).boxed_local()
+    }
+}
+
+/// [`Runtime`] measuring time on the thread of the [`block_on()`] executor,
+/// without spawning any threads.
+///
+/// Used by [`Cucumber::run_blocking()`] for running a whole suite on a single
+/// thread without any async runtime.
+///
+/// # Panics
+///
+/// If its timers are polled outside the [`block_on()`].
+///
+/// [`Cucumber::run_blocking()`]: crate::Cucumber::run_blocking
+#[cfg(all(
+    feature = "blocking",
+    not(all(target_family = "wasm", target_os = "unknown"))
+))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Blocking;
+
+#[cfg(all(
+    feature = "blocking",
+    not(all(target_family = "wasm", target_os = "unknown"))
+))]
+impl Runtime for Blocking {
+    fn sleep(&self, dur: Duration) -> LocalBoxFuture<'static, ()> {
+        blocking::Sleep::new(dur).boxed_local()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Agnostic, Ext as _};
+
+    #[tokio::test]
+    async fn gives_up_on_slow_futures() {
+        let out = Agnostic
+            .timeout(
+                tokio::time::sleep(Duration::from_secs(5)),
+                Duration::from_millis(50),
+            )
+            .await;
+
+        assert_eq!(out, Err(Duration::from_millis(50)));
+    }
+
+    #[tokio::test]
+    async fn awaits_fast_futures() {
+        let out = Agnostic.timeout(async { 42 }, Duration::from_secs(5)).await;
+
+        assert_eq!(out, Ok(42));
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`gherkin::Scenario`] extension.
+
+use std::fmt;
+
+use itertools::Itertools as _;
+use sealed::sealed;
+
+/// Tag marking [`gherkin::Scenario`]s as warm-up ones.
+///
+/// Warm-up [`gherkin::Scenario`]s are executed serially as soon as they're
+/// parsed, holding back all the other ones until they're done, and are
+/// excluded from the [`Summarize`]d stats by default, unless they fail.
+///
+/// [`Summarize`]: crate::writer::Summarize
+pub const WARMUP_TAG: &str = "warmup";
+
+/// Indicates whether the provided [`gherkin::Scenario`] is a warm-up one,
+/// being tagged with [`WARMUP_TAG`] either directly, or via its
+/// [`gherkin::Feature`] or [`gherkin::Rule`].
+#[must_use]
+pub fn is_warmup(
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> bool {
+    scenario
+        .tags
+        .iter()
+        .chain(rule.iter().flat_map(|r| &r.tags))
+        .chain(&feature.tags)
+        .any(|t| t == WARMUP_TAG)
+}
+
+/// Helper methods to operate on [`gherkin::Scenario`]s.
+#[sealed]
+pub trait Ext {
+    /// Returns the [`ExampleRow`] this [`gherkin::Scenario`] has been expanded
+    /// from, if it's an expanded [`Scenario Outline`][1].
+    ///
+    /// Allows selecting individual [`Examples`][2] rows in a
+    /// [`Cucumber::filter_run()`] filter.
+    ///
+    /// [`Cucumber::filter_run()`]: crate::Cucumber::filter_run
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    #[must_use]
+    fn example_row(&self) -> Option<ExampleRow<'_>>;
+
+    /// Returns tags of the [`Examples`][2] this [`gherkin::Scenario`] has
+    /// been expanded from, if it's an expanded [`Scenario Outline`][1], or
+    /// nothing otherwise.
+    ///
+    /// These tags are also merged into [`gherkin::Scenario::tags`], while
+    /// being more specific than the [`Scenario Outline`][1] own ones.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    #[must_use]
+    fn examples_tags(&self) -> &[String];
+}
+
+#[sealed]
+impl Ext for gherkin::Scenario {
+    fn example_row(&self) -> Option<ExampleRow<'_>> {
+        // Expanded `Scenario Outline` keeps only the `Examples` it has been
+        // expanded from, with the header and the single executed row.
+        let [examples] = self.examples.as_slice() else {
+            return None;
+        };
+        let table = examples.table.as_ref()?;
+        let [header, values] = table.rows.as_slice() else {
+            return None;
+        };
+
+        // See `feature::expand_scenario()` for the position calculation.
+        let index =
+            self.position.line.checked_sub(examples.position.line + 2)?;
+
+        Some(ExampleRow {
+            index,
+            line: table.position.line + 1 + index,
+            values: header
+                .iter()
+                .map(String::as_str)
+                .zip(values.iter().map(String::as_str))
+                .collect(),
+        })
+    }
+
+    fn examples_tags(&self) -> &[String] {
+        match self.examples.as_slice() {
+            [examples] if self.example_row().is_some() => &examples.tags,
+            _ => &[],
+        }
+    }
+}
+
+/// Row of [`Examples`][1] an expanded [`Scenario Outline`][2] is executed with.
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#examples
+/// [2]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExampleRow<'s> {
+    /// Zero-based index of this row inside its [`Examples`][1] table.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#examples
+    pub index: usize,
+
+    /// Line of this row in the `.feature` file.
+    ///
+    /// Counted from the header of its [`Examples`][1] table, so is shifted by
+    /// the comments inside the table (if any), as the [`gherkin`] parser
+    /// doesn't preserve positions of the table rows.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#examples
+    pub line: usize,
+
+    /// Pairs of the [`Examples`][1] table header and the row value.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#examples
+    pub values: Vec<(&'s str, &'s str)>,
+}
+
+impl ExampleRow<'_> {
+    /// Returns value of the provided `column` in this row, if any.
+    #[must_use]
+    pub fn get(&self, column: &str) -> Option<&str> {
+        self.values.iter().find_map(|(k, v)| (*k == column).then_some(*v))
+    }
+}
+
+impl fmt::Display for ExampleRow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.values.iter().map(|(k, v)| format!("{k}={v}")).join(", "),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::Ext as _;
+
+    const FEATURE: &str = "\
+Feature: Hungry
+  Scenario Outline: eating
+    Given there are <start> cucumbers
+
+    Examples:
+      | start |
+      | 12    |
+      | 20    |
+";
+
+    fn expanded() -> gherkin::Feature {
+        gherkin::Feature::parse(FEATURE, gherkin::GherkinEnv::default())
+            .unwrap()
+            .expand_examples()
+            .unwrap()
+    }
+
+    #[test]
+    fn returns_expanded_row() {
+        let feature = expanded();
+
+        let first = feature.scenarios[0].example_row().unwrap();
+        assert_eq!(first.index, 0);
+        assert_eq!(first.line, 7);
+        assert_eq!(first.get("start"), Some("12"));
+        assert_eq!(first.to_string(), "start=12");
+
+        let second = feature.scenarios[1].example_row().unwrap();
+        assert_eq!(second.index, 1);
+        assert_eq!(second.line, 8);
+        assert_eq!(second.get("start"), Some("20"));
+        assert_eq!(second.get("unknown"), None);
+    }
+
+    #[test]
+    fn returns_line_of_row_after_description() {
+        let feature = gherkin::Feature::parse(
+            FEATURE.replace(
+                "    Examples:\n",
+                "    Examples:\n      Amounts of cucumbers.\n\n",
+            ),
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+        .expand_examples()
+        .unwrap();
+
+        let lines = feature
+            .scenarios
+            .iter()
+            .map(|s| s.example_row().map(|r| (r.index, r.line)))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [Some((0, 9)), Some((1, 10))]);
+    }
+
+    #[test]
+    fn returns_examples_tags() {
+        let feature = gherkin::Feature::parse(
+            FEATURE.replace("    Examples:", "    @slow\n    Examples:"),
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap();
+        assert!(feature.scenarios[0].examples_tags().is_empty());
+
+        let feature = feature.expand_examples().unwrap();
+
+        assert_eq!(feature.scenarios[0].examples_tags(), ["slow"]);
+        assert_eq!(feature.scenarios[1].tags, ["slow"]);
+    }
+
+    #[test]
+    fn detects_inherited_warmup_tag() {
+        let mut feature = expanded();
+        assert!(!is_warmup(&feature, None, &feature.scenarios[0]));
+
+        feature.tags.push(WARMUP_TAG.into());
+        assert!(is_warmup(&feature, None, &feature.scenarios[0]));
+    }
+
+    #[test]
+    fn returns_none_for_regular_scenario() {
+        let feature = gherkin::Feature::parse(
+            "Feature: F\n  Scenario: S\n    Given a step\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap();
+
+        assert_eq!(feature.scenarios[0].example_row(), None);
+    }
+}
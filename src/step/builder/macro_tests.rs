@@ -0,0 +1,157 @@
+//! Tests of the [`step_builder!`] macro.
+//!
+//! [`step_builder!`]: crate::step_builder
+
+use futures::{executor::block_on, future::LocalBoxFuture};
+use regex::Regex;
+
+use crate::{
+    step::{Collection, Context, Function, Ownership, StepBuilder},
+    step_builder,
+};
+
+#[derive(Default)]
+struct TestWorld;
+
+fn test_step(_world: &mut TestWorld, _ctx: Context) -> LocalBoxFuture<'_, ()> {
+    Box::pin(async {})
+}
+
+#[test]
+fn macro_step_builder_pattern() {
+    step_builder!(
+        MonitoringSteps,
+        "Health & Monitoring",
+        TestWorld,
+        |collection| {
+            collection
+                .given(
+                    None,
+                    Regex::new(r"service is healthy").unwrap(),
+                    test_step,
+                )
+                .when(
+                    None,
+                    Regex::new(r"checking health endpoint").unwrap(),
+                    test_step,
+                )
+                .then(
+                    None,
+                    Regex::new(r"should return healthy status").unwrap(),
+                    test_step,
+                )
+        }
+    );
+
+    let monitoring_steps = MonitoringSteps::register_steps(Collection::new());
+    assert_eq!(monitoring_steps.given_len(), 1);
+    assert_eq!(monitoring_steps.when_len(), 1);
+    assert_eq!(monitoring_steps.then_len(), 1);
+
+    assert_eq!(MonitoringSteps::domain_name(), "Health & Monitoring");
+}
+
+#[test]
+fn macro_step_builder_records_owner() {
+    step_builder!(
+        PaymentSteps,
+        "Payments",
+        TestWorld,
+        |collection| {
+            collection.when(None, Regex::new(r"^paying$").unwrap(), test_step)
+        },
+        owner = Ownership::new().team("payments"),
+    );
+
+    let steps = PaymentSteps::register_steps(Collection::new().given(
+        None,
+        Regex::new(r"^a user$").unwrap(),
+        test_step,
+    ));
+    let owner = |ty, value: &str| {
+        let step = gherkin::Step {
+            keyword: String::new(),
+            ty,
+            value: value.into(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        };
+        let (.., ctx) = steps.find(&step).unwrap().unwrap();
+        ctx.owner().and_then(|o| o.team.clone())
+    };
+    assert_eq!(
+        owner(gherkin::StepType::When, "paying").as_deref(),
+        Some("payments"),
+    );
+    assert_eq!(owner(gherkin::StepType::Given, "a user"), None);
+    assert_eq!(
+        PaymentSteps::ownership(),
+        Some(Ownership::new().team("payments")),
+    );
+}
+
+#[test]
+fn macro_step_builder_declarative_syntax() {
+    #[derive(Default)]
+    struct Wallet {
+        cards: usize,
+    }
+
+    fn pay(_world: &mut Wallet, _ctx: Context) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    step_builder! {
+        /// Steps of the wallet domain.
+        #[derive(Clone, Copy, Debug)]
+        struct WalletSteps for Wallet {
+            domain = "Wallet";
+            owner = Ownership::new().team("wallet");
+
+            given "^a card$" => async |world, _ctx| {
+                world.cards += 1;
+            },
+            given "^(\\d+) cards$" => async |world, ctx| {
+                world.cards += ctx.matches[1].1.parse::<usize>().unwrap();
+            },
+            when "^paying$" => pay,
+            then "^nothing$" => async |_, _| {}
+        }
+    }
+
+    let steps = WalletSteps::register_steps(Collection::new());
+    assert_eq!(
+        (steps.given_len(), steps.when_len(), steps.then_len()),
+        (2, 1, 1),
+    );
+    assert_eq!(WalletSteps::domain_name(), "Wallet");
+    assert_eq!(
+        WalletSteps::ownership().and_then(|o| o.team),
+        Some("wallet".into()),
+    );
+    assert_eq!(format!("{WalletSteps:?}"), "WalletSteps");
+
+    let mut world = Wallet::default();
+    for value in ["a card", "2 cards"] {
+        let step = gherkin::Step {
+            keyword: String::new(),
+            ty: gherkin::StepType::Given,
+            value: value.into(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        };
+        let (&Function::Async(step_fn), _, loc, ctx) =
+            steps.find(&step).unwrap().unwrap()
+        else {
+            panic!("expected async step");
+        };
+        assert_eq!(loc.map(|l| l.path), Some(file!()));
+        assert_eq!(ctx.owner().and_then(|o| o.team.as_deref()), Some("wallet"));
+        block_on(step_fn(&mut world, ctx));
+    }
+    assert_eq!(world.cards, 3);
+}
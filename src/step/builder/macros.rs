@@ -0,0 +1,280 @@
+//! [`step_builder!`] macro implementing [`StepBuilder`]s.
+//!
+//! [`StepBuilder`]: super::StepBuilder
+//! [`step_builder!`]: crate::step_builder
+
+/// Macro for implementing step builders with consistent patterns.
+///
+/// This macro reduces boilerplate when creating domain-specific step builders
+/// and ensures consistent implementation patterns across teams.
+///
+/// # Example
+///
+/// ```rust
+/// use cucumber::{
+///     step::{Collection, StepBuilder},
+///     step_builder,
+/// };
+/// use futures::future::LocalBoxFuture;
+/// use regex::Regex;
+///
+/// #[derive(Default)]
+/// struct TestWorld;
+///
+/// fn test_step(
+///     _world: &mut TestWorld,
+///     _ctx: cucumber::step::Context,
+/// ) -> LocalBoxFuture<'_, ()> {
+///     Box::pin(async {})
+/// }
+///
+/// step_builder!(
+///     CryptoSteps,
+///     "Cryptographic Operations",
+///     TestWorld,
+///     |collection| {
+///         collection
+///             .when(None, Regex::new(r"creating a key").unwrap(), test_step)
+///             .then(
+///                 None,
+///                 Regex::new(r"key should be created").unwrap(),
+///                 test_step,
+///             )
+///     }
+/// );
+///
+/// // Use the generated step builder
+/// let crypto_steps = CryptoSteps::register_steps(Collection::new());
+/// ```
+///
+/// An [`Ownership`] may be provided after the body to be recorded on all the
+/// generated step definitions, so their failures report whom to contact:
+///
+/// ```rust
+/// # use cucumber::{
+/// #     step::{Collection, Ownership, StepBuilder},
+/// #     step_builder,
+/// # };
+/// # use futures::future::LocalBoxFuture;
+/// # use regex::Regex;
+/// #
+/// # #[derive(Default)]
+/// # struct TestWorld;
+/// #
+/// # fn test_step(
+/// #     _world: &mut TestWorld,
+/// #     _ctx: cucumber::step::Context,
+/// # ) -> LocalBoxFuture<'_, ()> {
+/// #     Box::pin(async {})
+/// # }
+/// #
+/// step_builder!(
+///     PaymentSteps,
+///     "Payments",
+///     TestWorld,
+///     |collection| {
+///         collection.when(None, Regex::new(r"paying").unwrap(), test_step)
+///     },
+///     owner = Ownership::new()
+///         .team("payments")
+///         .contact("#payments-oncall")
+///         .version("1.2.0")
+///         .required_tag("api"),
+/// );
+///
+/// assert_eq!(
+///     PaymentSteps::ownership().unwrap().to_string(),
+///     "payments (#payments-oncall), v1.2.0, requires @api",
+/// );
+/// ```
+///
+/// # Declarative syntax
+///
+/// Alternatively, the step definitions may be listed declaratively, along
+/// with doc comments and attributes of the generated type. Each definition
+/// names its step type (`given`, `when` or `then`) and [`Regex`] pattern,
+/// followed either by a [`Step`] function, or by an inline `async` closure,
+/// whose future is boxed automatically. The definitions are located at the
+/// macro invocation.
+///
+/// ```rust
+/// # use cucumber::{
+/// #     step::{Collection, Context, Ownership, StepBuilder},
+/// #     step_builder,
+/// # };
+/// # use futures::future::LocalBoxFuture;
+/// #
+/// # #[derive(Default)]
+/// # struct TestWorld {
+/// #     cards: usize,
+/// # }
+/// #
+/// # fn test_step(
+/// #     _world: &mut TestWorld,
+/// #     _ctx: Context,
+/// # ) -> LocalBoxFuture<'_, ()> {
+/// #     Box::pin(async {})
+/// # }
+/// #
+/// step_builder! {
+///     /// Steps of the payments domain.
+///     #[derive(Clone, Copy, Debug)]
+///     pub struct PaymentSteps for TestWorld {
+///         domain = "Payments";
+///         owner = Ownership::new().team("payments");
+///
+///         given "^a card$" => async |world, _ctx| {
+///             world.cards += 1;
+///         },
+///         when "^paying$" => test_step,
+///         then "^the card is charged$" => async |_, _| {},
+///     }
+/// }
+///
+/// let steps = PaymentSteps::register_steps(Collection::new());
+/// assert_eq!(steps.total_len(), 3);
+/// assert_eq!(PaymentSteps::domain_name(), "Payments");
+/// ```
+///
+/// [`Ownership`]: crate::step::Ownership
+/// [`Regex`]: regex::Regex
+/// [`Step`]: crate::step::Step
+#[macro_export]
+macro_rules! step_builder {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident for $world:ty {
+            domain = $domain:expr;
+            owner = $owner:expr;
+            $($steps:tt)*
+        }
+    ) => {
+        $crate::step_builder!(
+            @builder $(#[$attr])* $vis $name, $world, $domain, [$owner];
+            $($steps)*
+        );
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident for $world:ty {
+            domain = $domain:expr;
+            $($steps:tt)*
+        }
+    ) => {
+        $crate::step_builder!(
+            @builder $(#[$attr])* $vis $name, $world, $domain, [];
+            $($steps)*
+        );
+    };
+    (
+        @builder $(#[$attr:meta])* $vis:vis $name:ident, $world:ty,
+        $domain:expr, [$($owner:expr)?]; $($steps:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl $crate::step::StepBuilder<$world> for $name {
+            fn register_steps(
+                collection: $crate::step::Collection<$world>,
+            ) -> $crate::step::Collection<$world> {
+                let steps = $crate::step_builder!(
+                    @steps $crate::step::Collection::<$world>::new();
+                    $($steps)*
+                );
+                $(let steps = steps.owned_by($owner);)?
+                collection.merge(steps.in_domain($domain))
+            }
+
+            fn domain_name() -> &'static str {
+                $domain
+            }
+
+            $(
+                fn ownership() -> Option<$crate::step::Ownership> {
+                    Some($owner)
+                }
+            )?
+        }
+    };
+    (@steps $collection:expr; $(,)?) => {
+        $collection
+    };
+    (
+        @steps $collection:expr;
+        $ty:ident $re:literal => async | $w:pat_param, $ctx:pat_param |
+            $body:block
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::step_builder!(
+            @steps $crate::step_builder!(
+                @step $collection; $ty $re,
+                |$w, $ctx| ::std::boxed::Box::pin(async move $body)
+            );
+            $($($rest)*)?
+        )
+    };
+    (
+        @steps $collection:expr;
+        $ty:ident $re:literal => $step:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::step_builder!(
+            @steps $crate::step_builder!(@step $collection; $ty $re, $step);
+            $($($rest)*)?
+        )
+    };
+    (@step $collection:expr; $ty:ident $re:literal, $step:expr) => {
+        $collection.$ty(
+            Some($crate::step::Location::new(file!(), line!(), column!())),
+            $re.parse().unwrap_or_else(|e| {
+                panic!(
+                    "invalid `{}` step pattern {:?}: {e}",
+                    stringify!($ty),
+                    $re,
+                )
+            }),
+            $step,
+        )
+    };
+    (
+        $name:ident, $domain:expr, $world:ty, | $collection:ident | $body:expr
+    ) => {
+        pub struct $name;
+
+        impl StepBuilder<$world> for $name {
+            fn register_steps(
+                collection: Collection<$world>,
+            ) -> Collection<$world> {
+                let $collection = Collection::new();
+                collection.merge(($body).in_domain($domain))
+            }
+
+            fn domain_name() -> &'static str {
+                $domain
+            }
+        }
+    };
+    (
+        $name:ident, $domain:expr, $world:ty, | $collection:ident | $body:expr,
+        owner = $owner:expr $(,)?
+    ) => {
+        pub struct $name;
+
+        impl StepBuilder<$world> for $name {
+            fn register_steps(
+                collection: Collection<$world>,
+            ) -> Collection<$world> {
+                let $collection = Collection::new();
+                collection
+                    .merge(($body).owned_by($owner).in_domain($domain))
+            }
+
+            fn domain_name() -> &'static str {
+                $domain
+            }
+
+            fn ownership() -> Option<$crate::step::Ownership> {
+                Some($owner)
+            }
+        }
+    };
+}
@@ -4,7 +4,11 @@
 //! enterprise-scale BDD testing by allowing different teams to own different
 //! domain-specific step definitions.
 
-use super::Collection;
+#[cfg(test)]
+mod macro_tests;
+mod macros;
+
+use super::{Collection, Ownership};
 
 /// Trait for modular step definition builders.
 ///
@@ -81,6 +85,19 @@ pub trait StepBuilder<World> {
     /// This is used for documentation and debugging purposes to identify
     /// which team or domain owns these step definitions.
     fn domain_name() -> &'static str;
+
+    /// Returns the [`Ownership`] of this step builder's definitions (if any).
+    ///
+    /// This is informational only: to have it reported along with failed
+    /// steps, the [`register_steps()`] implementation should record it via
+    /// [`Collection::owned_by()`] (as the [`step_builder!`] macro does).
+    ///
+    /// [`register_steps()`]: StepBuilder::register_steps
+    /// [`step_builder!`]: crate::step_builder
+    #[must_use]
+    fn ownership() -> Option<Ownership> {
+        None
+    }
 }
 
 /// Composes multiple step builders into a single collection.
@@ -122,70 +139,6 @@ pub fn compose_step_builders<World>(
     builders.into_iter().fold(Collection::new(), |acc, builder| builder(acc))
 }
 
-/// Macro for implementing step builders with consistent patterns.
-///
-/// This macro reduces boilerplate when creating domain-specific step builders
-/// and ensures consistent implementation patterns across teams.
-///
-/// # Example
-///
-/// ```rust
-/// use cucumber::{
-///     step::{Collection, StepBuilder},
-///     step_builder,
-/// };
-/// use futures::future::LocalBoxFuture;
-/// use regex::Regex;
-///
-/// #[derive(Default)]
-/// struct TestWorld;
-///
-/// fn test_step(
-///     _world: &mut TestWorld,
-///     _ctx: cucumber::step::Context,
-/// ) -> LocalBoxFuture<'_, ()> {
-///     Box::pin(async {})
-/// }
-///
-/// step_builder!(
-///     CryptoSteps,
-///     "Cryptographic Operations",
-///     TestWorld,
-///     |collection| {
-///         collection
-///             .when(None, Regex::new(r"creating a key").unwrap(), test_step)
-///             .then(
-///                 None,
-///                 Regex::new(r"key should be created").unwrap(),
-///                 test_step,
-///             )
-///     }
-/// );
-///
-/// // Use the generated step builder
-/// let crypto_steps = CryptoSteps::register_steps(Collection::new());
-/// ```
-#[macro_export]
-macro_rules! step_builder {
-    (
-        $name:ident, $domain:expr, $world:ty, | $collection:ident | $body:expr
-    ) => {
-        pub struct $name;
-
-        impl StepBuilder<$world> for $name {
-            fn register_steps(
-                $collection: Collection<$world>,
-            ) -> Collection<$world> {
-                $body
-            }
-
-            fn domain_name() -> &'static str {
-                $domain
-            }
-        }
-    };
-}
-
 #[cfg(test)]
 mod tests {
     use futures::future::LocalBoxFuture;
@@ -277,41 +230,6 @@ mod tests {
         assert_eq!(composed.then_len(), 1); // crypto then
     }
 
-    #[test]
-    fn macro_step_builder_pattern() {
-        step_builder!(
-            MonitoringSteps,
-            "Health & Monitoring",
-            TestWorld,
-            |collection| {
-                collection
-                    .given(
-                        None,
-                        Regex::new(r"service is healthy").unwrap(),
-                        test_step,
-                    )
-                    .when(
-                        None,
-                        Regex::new(r"checking health endpoint").unwrap(),
-                        test_step,
-                    )
-                    .then(
-                        None,
-                        Regex::new(r"should return healthy status").unwrap(),
-                        test_step,
-                    )
-            }
-        );
-
-        let monitoring_steps =
-            MonitoringSteps::register_steps(Collection::new());
-        assert_eq!(monitoring_steps.given_len(), 1);
-        assert_eq!(monitoring_steps.when_len(), 1);
-        assert_eq!(monitoring_steps.then_len(), 1);
-
-        assert_eq!(MonitoringSteps::domain_name(), "Health & Monitoring");
-    }
-
     #[test]
     fn enterprise_pattern_integration() {
         // Demonstrate the full enterprise pattern
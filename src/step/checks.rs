@@ -0,0 +1,104 @@
+//! Soft assertions of a step function.
+//!
+//! This module provides [`Context::check()`] recording a failed verification
+//! without stopping the step function, so all of them are reported at once
+//! via [`CheckFailures`].
+
+use std::{
+    fmt, mem,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use derive_more::with_trait::Error;
+use itertools::Itertools as _;
+
+use super::Context;
+
+/// Failed soft assertions of a [`crate::step::Step`] function, recorded via
+/// [`Context::check()`].
+///
+/// Once the [`crate::step::Step`] function finishes, they fail it all at once
+/// with an [`event::StepError::Checks`].
+///
+/// [`event::StepError::Checks`]: crate::event::StepError::Checks
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct CheckFailures(#[error(not(source))] pub Vec<String>);
+
+impl fmt::Display for CheckFailures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.iter().map(|msg| format!("- {msg}")).join("\n"))
+    }
+}
+
+/// Collector of [`CheckFailures`] shared between a [`Context`] and the
+/// executor of its [`crate::step::Step`] function.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Checks(Arc<Mutex<Vec<String>>>);
+
+impl Checks {
+    /// Takes all the recorded [`CheckFailures`], if any.
+    pub(crate) fn take(&self) -> Option<CheckFailures> {
+        let failures = mem::take(
+            &mut *self.0.lock().unwrap_or_else(PoisonError::into_inner),
+        );
+        (!failures.is_empty()).then_some(CheckFailures(failures))
+    }
+}
+
+impl Context {
+    /// Soft-asserts the provided `condition`, recording the provided `message`
+    /// if it doesn't hold, and returning the `condition` itself.
+    ///
+    /// Unlike [`assert!`], the step function isn't stopped, but rather fails
+    /// with all the recorded [`CheckFailures`] once finished.
+    pub fn check(&self, condition: bool, message: impl fmt::Display) -> bool {
+        if !condition {
+            self.checks
+                .0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(message.to_string());
+        }
+        condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_failed_checks() {
+        let ctx = Context::new(
+            gherkin::Step {
+                keyword: "Then".into(),
+                ty: gherkin::StepType::Then,
+                value: "the cart is valid".into(),
+                docstring: None,
+                table: None,
+                span: gherkin::Span { start: 0, end: 0 },
+                position: gherkin::LineCol { line: 1, col: 1 },
+            },
+            vec![],
+        );
+        let checks = ctx.checks.clone();
+
+        assert!(ctx.check(true, "total is positive"));
+        assert!(checks.take().is_none());
+
+        assert!(!ctx.check(false, "total is 42, got 41"));
+        assert!(!ctx.clone().check(false, "currency is EUR, got USD"));
+        let failures = checks.take().unwrap();
+        assert_eq!(
+            failures.to_string(),
+            "- total is 42, got 41\n\
+             - currency is EUR, got USD",
+        );
+        assert!(checks.take().is_none());
+    }
+}
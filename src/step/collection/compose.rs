@@ -0,0 +1,174 @@
+//! Composition of multiple [`Collection`]s into a single one.
+
+use super::Collection;
+use crate::step::StepRegistrar;
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to composition only"
+)]
+impl<World> Collection<World> {
+    /// Merges another [`Collection`] into this one, enabling modular composition.
+    ///
+    /// This method allows combining step definitions from multiple collections,
+    /// which is essential for enterprise-scale BDD testing where different teams
+    /// own different domain-specific step definitions.
+    ///
+    /// A definition of the same pattern in `other` overwrites the one in this
+    /// [`Collection`] silently. Use [`Collection::try_merge()`] to reject such
+    /// duplicates instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::step::Collection;
+    /// # use regex::Regex;
+    /// # use futures::future::LocalBoxFuture;
+    /// #
+    /// # #[derive(Default)]
+    /// # struct TestWorld;
+    /// #
+    /// # fn test_step(_world: &mut TestWorld, _ctx: cucumber::step::Context) -> LocalBoxFuture<'_, ()> {
+    /// #     Box::pin(async {})
+    /// # }
+    ///
+    /// // Create domain-specific collections
+    /// let auth_steps = Collection::new()
+    ///     .given(None, Regex::new(r"user is logged in").unwrap(), test_step);
+    ///     
+    /// let crypto_steps = Collection::new()
+    ///     .when(None, Regex::new(r"creating a key").unwrap(), test_step);
+    ///
+    /// // Merge collections for comprehensive testing
+    /// let all_steps = auth_steps.merge(crypto_steps);
+    /// ```
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        self.given.extend(other.given);
+        self.when.extend(other.when);
+        self.then.extend(other.then);
+        drop(self.index.take());
+        self
+    }
+
+    /// Composes multiple [`Collection`]s (or any other [`StepRegistrar`]s,
+    /// including [`Box`]ed ones) into a single collection.
+    ///
+    /// This is a convenience method for merging many collections at once,
+    /// particularly useful when building enterprise-scale BDD architectures
+    /// with multiple domain-specific step builders.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::step::Collection;
+    /// # use regex::Regex;
+    /// # use futures::future::LocalBoxFuture;
+    /// #
+    /// # #[derive(Default)]
+    /// # struct TestWorld;
+    /// #
+    /// # fn test_step(_world: &mut TestWorld, _ctx: cucumber::step::Context) -> LocalBoxFuture<'_, ()> {
+    /// #     Box::pin(async {})
+    /// # }
+    ///
+    /// let collections = vec![
+    ///     Collection::new().given(None, Regex::new(r"auth").unwrap(), test_step),
+    ///     Collection::new().when(None, Regex::new(r"crypto").unwrap(), test_step),
+    ///     Collection::new().then(None, Regex::new(r"audit").unwrap(), test_step),
+    /// ];
+    ///
+    /// let enterprise_steps = Collection::compose(collections);
+    /// ```
+    #[must_use]
+    pub fn compose<R>(registrars: impl IntoIterator<Item = R>) -> Self
+    where
+        R: StepRegistrar<World>,
+    {
+        registrars
+            .into_iter()
+            .fold(Self::new(), |acc, registrar| registrar.register(acc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::test_utils::common::{TestWorld, test_step};
+
+    fn re(pattern: &str) -> Regex {
+        Regex::new(pattern).unwrap()
+    }
+
+    fn lens(steps: &Collection<TestWorld>) -> (usize, usize, usize) {
+        (steps.given_len(), steps.when_len(), steps.then_len())
+    }
+
+    #[test]
+    fn collection_merge_functionality() {
+        let auth_steps = Collection::new()
+            .given(None, re("user is logged in"), test_step)
+            .when(None, re("user performs auth action"), test_step);
+        let crypto_steps = Collection::new()
+            .given(None, re("crypto service is available"), test_step)
+            .then(None, re("key should be created"), test_step);
+
+        assert_eq!(lens(&auth_steps.merge(crypto_steps)), (2, 1, 1));
+    }
+
+    #[test]
+    fn collection_compose_multiple() {
+        let composed = Collection::compose(vec![
+            Collection::new().given(None, re("auth step"), test_step),
+            Collection::new().when(None, re("crypto step"), test_step),
+            Collection::new().then(None, re("audit step"), test_step),
+        ]);
+
+        assert_eq!(lens(&composed), (1, 1, 1));
+    }
+
+    #[test]
+    fn collection_compose_empty_vec() {
+        let composed = Collection::compose(Vec::<Collection<TestWorld>>::new());
+
+        assert_eq!(lens(&composed), (0, 0, 0));
+    }
+
+    #[test]
+    fn collection_merge_maintains_step_uniqueness() {
+        let first = Collection::new()
+            .given(None, re("first step"), test_step)
+            .given(None, re("duplicate step"), test_step);
+        let second = Collection::new()
+            .given(None, re("second step"), test_step)
+            .given(None, re("duplicate step"), test_step);
+
+        // Duplicate regex overwrites.
+        assert_eq!(first.merge(second).given_len(), 3);
+    }
+
+    #[test]
+    fn composes_domain_specific_collections() {
+        let infrastructure = Collection::new()
+            .given(None, re("the vault service is running"), test_step)
+            .when(None, re("checking the health endpoint"), test_step);
+        let users = Collection::new()
+            .given(None, re(r"(\w+) is an admin user"), test_step)
+            .when(None, re(r"(\w+) logs in with credentials"), test_step);
+        let keys = Collection::new()
+            .when(None, re(r#"(\w+) creates a key "([^"]+)""#), test_step)
+            .then(
+                None,
+                re("the key should be created successfully"),
+                test_step,
+            );
+
+        let composed = Collection::compose(vec![infrastructure, users, keys]);
+
+        assert_eq!(lens(&composed), (2, 3, 1));
+    }
+}
@@ -0,0 +1,246 @@
+//! Report of conflicting definitions between composed [`Collection`]s.
+
+use std::{fmt, sync::Arc};
+
+use derive_more::with_trait::Error;
+use gherkin::StepType;
+use itertools::Itertools as _;
+
+use super::{
+    Collection,
+    strict::{STEP_TYPES, step_type_index},
+};
+use crate::step::{Ownership, location::Location, regex::HashableRegex};
+
+/// Kind of a [`Conflict`] between composed [`Collection`]s.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConflictKind {
+    /// The same [`regex::Regex`] pattern is defined by multiple
+    /// [`Collection`]s.
+    Duplicate,
+
+    /// A literal [`regex::Regex`] pattern of one [`Collection`] is matched by
+    /// a pattern of the same priority of another one, so a
+    /// [`gherkin::Step`] of this text is ambiguous.
+    Overlap,
+}
+
+/// [`crate::step::Step`] definition involved into a [`Conflict`].
+#[derive(Clone, Debug)]
+pub struct Contribution {
+    /// Index of the composed [`Collection`] this definition comes from.
+    pub collection: usize,
+
+    /// [`Ownership`] of this definition (if recorded), identifying the
+    /// builder it comes from.
+    pub owner: Option<Arc<Ownership>>,
+
+    /// [`regex::Regex`] pattern of this definition.
+    pub pattern: HashableRegex,
+
+    /// [`Location`] of the [`crate::step::Step`] function (if known).
+    pub location: Option<Location>,
+}
+
+impl fmt::Display for Contribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} --> ", self.pattern)?;
+        match &self.location {
+            Some(loc) => write!(f, "{loc}")?,
+            None => write!(f, "<unknown location>")?,
+        }
+        write!(f, " (collection #{}", self.collection)?;
+        if let Some(owner) = &self.owner {
+            write!(f, ", {owner}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Conflict between [`crate::step::Step`] definitions of composed
+/// [`Collection`]s.
+#[derive(Clone, Debug)]
+pub struct Conflict {
+    /// [`StepType`] of the conflicting definitions.
+    pub ty: StepType,
+
+    /// Kind of this [`Conflict`].
+    pub kind: ConflictKind,
+
+    /// Conflicting definitions.
+    pub definitions: Vec<Contribution>,
+}
+
+/// Report of [`Conflict`]s found by [`Collection::compose_checked()`].
+#[derive(Clone, Debug, Error)]
+pub struct ComposeReport {
+    /// All the found [`Conflict`]s.
+    pub conflicts: Vec<Conflict>,
+}
+
+impl fmt::Display for ComposeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Conflicting step definitions:")?;
+        for c in &self.conflicts {
+            let kind = match c.kind {
+                ConflictKind::Duplicate => "duplicate",
+                ConflictKind::Overlap => "overlapping",
+            };
+            let ty = STEP_TYPES[step_type_index(c.ty)].1;
+            write!(f, "\n{kind} {ty} steps:")?;
+            for def in &c.definitions {
+                write!(f, "\n  {def}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to conflicts reporting only"
+)]
+impl<World> Collection<World> {
+    /// Composes multiple [`Collection`]s into a single one, like
+    /// [`compose()`] does, but failing with a [`ComposeReport`] if any two of
+    /// them define the same [`regex::Regex`] pattern, or a literal pattern of
+    /// one of them is matched by a pattern of another one.
+    ///
+    /// # Errors
+    ///
+    /// With all the [`Conflict`]s between the [`Collection`]s, along with the
+    /// [`Ownership`] of the conflicting definitions (if recorded).
+    ///
+    /// [`compose()`]: Collection::compose
+    pub fn compose_checked(
+        collections: Vec<Self>,
+    ) -> Result<Self, ComposeReport> {
+        let defs = collections
+            .iter()
+            .enumerate()
+            .flat_map(|(n, c)| {
+                c.by_type().into_iter().flat_map(move |(ty, defs)| {
+                    defs.iter().map(move |((re, loc), def)| {
+                        let contribution = Contribution {
+                            collection: n,
+                            owner: def.owner.clone(),
+                            pattern: re.clone(),
+                            location: *loc,
+                        };
+                        (step_type_index(ty), def.priority, contribution)
+                    })
+                })
+            })
+            .sorted_by(|(ty_a, _, a), (ty_b, _, b)| {
+                (ty_a, &a.pattern, a.collection, a.location).cmp(&(
+                    ty_b,
+                    &b.pattern,
+                    b.collection,
+                    b.location,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let mut conflicts = defs
+            .iter()
+            .chunk_by(|(ty, _, c)| (*ty, &c.pattern))
+            .into_iter()
+            .filter_map(|((ty, _), group)| {
+                let group = group.map(|(.., c)| c.clone()).collect::<Vec<_>>();
+                let mut domains = group.iter().map(|c| c.collection);
+                (!domains.all_equal()).then(|| Conflict {
+                    ty: STEP_TYPES[ty].0,
+                    kind: ConflictKind::Duplicate,
+                    definitions: group,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for ((ty_a, prio_a, a), (ty_b, prio_b, b)) in
+            defs.iter().tuple_combinations()
+        {
+            let overlaps = |re: &HashableRegex, lit: &HashableRegex| {
+                literal(lit.as_str()).is_some_and(|text| re.is_match(text))
+            };
+            if ty_a == ty_b
+                && prio_a == prio_b
+                && a.collection != b.collection
+                && a.pattern != b.pattern
+                && (overlaps(&a.pattern, &b.pattern)
+                    || overlaps(&b.pattern, &a.pattern))
+            {
+                conflicts.push(Conflict {
+                    ty: STEP_TYPES[*ty_a].0,
+                    kind: ConflictKind::Overlap,
+                    definitions: vec![a.clone(), b.clone()],
+                });
+            }
+        }
+
+        if conflicts.is_empty() {
+            Ok(Self::compose(collections))
+        } else {
+            Err(ComposeReport { conflicts })
+        }
+    }
+}
+
+/// Returns the text matched by the provided [`regex::Regex`] `pattern`, if
+/// it's a literal one (containing no meta characters besides anchors).
+fn literal(pattern: &str) -> Option<&str> {
+    let text = pattern.strip_prefix('^').unwrap_or(pattern);
+    let text = text.strip_suffix('$').unwrap_or(text);
+    (regex::escape(text) == text).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::test_utils::common::test_step;
+
+    #[test]
+    fn reports_duplicates_and_overlaps() {
+        let loc = |line| Some(Location::new("src/steps.rs", line, 1));
+        let re = |s| Regex::new(s).unwrap();
+        let auth = Collection::new()
+            .given(loc(1), re("^a user$"), test_step)
+            .when(loc(2), re(r"^(\w+) logs in$"), test_step)
+            .owned_by(Ownership::new().team("auth"));
+        let billing = Collection::new()
+            .given(loc(3), re("^a user$"), test_step)
+            .when(loc(4), re("^admin logs in$"), test_step)
+            .then(loc(5), re("^admin is billed$"), test_step);
+
+        let report = Collection::compose_checked(vec![auth.clone(), billing])
+            .map(drop)
+            .unwrap_err();
+        assert_eq!(
+            report.to_string(),
+            "Conflicting step definitions:\n\
+             duplicate Given steps:\n  \
+             ^a user$ --> src/steps.rs:1:1 (collection #0, auth)\n  \
+             ^a user$ --> src/steps.rs:3:1 (collection #1)\n\
+             overlapping When steps:\n  \
+             ^(\\w+) logs in$ --> src/steps.rs:2:1 (collection #0, auth)\n  \
+             ^admin logs in$ --> src/steps.rs:4:1 (collection #1)",
+        );
+
+        let composed = Collection::compose_checked(vec![
+            auth,
+            Collection::new().then(loc(5), re("^admin is billed$"), test_step),
+        ]);
+        assert_eq!(composed.unwrap().total_len(), 3);
+    }
+
+    #[test]
+    fn detects_literal_patterns_only() {
+        assert_eq!(literal("^a user$"), Some("a user"));
+        assert_eq!(literal("logging in"), Some("logging in"));
+        assert_eq!(literal(r"^(\w+) logs in$"), None);
+        assert_eq!(literal(r"^costs \$5$"), None);
+    }
+}
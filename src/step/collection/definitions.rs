@@ -0,0 +1,153 @@
+//! Read-only introspection of [`Collection`]s.
+
+use gherkin::{StepType, tagexpr::TagOperation};
+use itertools::Itertools as _;
+use regex::Regex;
+
+use super::{Collection, Definition, Key};
+use crate::step::{Ownership, location::Location};
+
+/// Read-only view of a [`crate::step::Step`] definition registered in a
+/// [`Collection`], returned by [`Collection::definitions()`].
+#[derive(Clone, Copy, Debug)]
+pub struct DefinitionInfo<'c> {
+    /// [`StepType`] the definition is registered for.
+    pub ty: StepType,
+
+    /// [`regex::Regex`] pattern of the definition.
+    pub pattern: &'c str,
+
+    /// Compiled [`regex::Regex`] of the `pattern`.
+    pub regex: &'c Regex,
+
+    /// [`Location`] of the [`crate::step::Step`] function (if known).
+    pub location: Option<Location>,
+
+    /// Priority resolving an ambiguity in favor of the definition.
+    pub priority: i32,
+
+    /// Primary pattern of the [`crate::step::Step`] function, in case the
+    /// definition is registered for its alias.
+    pub alias_of: Option<&'c str>,
+
+    /// Tag expression a [`gherkin::Scenario`] should match for the definition
+    /// to be considered.
+    pub tags: Option<&'c TagOperation>,
+
+    /// Indicator whether the [`gherkin::Step`] text is normalized before
+    /// matching.
+    pub normalized: bool,
+
+    /// [`Ownership`] of the definition (if recorded).
+    pub owner: Option<&'c Ownership>,
+
+    /// Name of the [`StepBuilder`] domain the definition is registered by (if
+    /// recorded).
+    ///
+    /// [`StepBuilder`]: crate::step::StepBuilder
+    pub domain: Option<&'c str>,
+
+    /// Doc comment of the [`crate::step::Step`] function (if any).
+    pub doc: Option<&'static str>,
+}
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to introspection only"
+)]
+impl<World> Collection<World> {
+    /// Iterates over all the [`crate::step::Step`] definitions registered in
+    /// this [`Collection`].
+    ///
+    /// Definitions are ordered by their [`StepType`] (Given, When, Then), and
+    /// then by their patterns and [`Location`]s, so the order is stable
+    /// between runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::step::{Collection, Context};
+    /// # use futures::future::LocalBoxFuture;
+    /// # use regex::Regex;
+    /// #
+    /// # struct TestWorld;
+    /// #
+    /// # fn test_step(
+    /// #     _world: &mut TestWorld,
+    /// #     _ctx: Context,
+    /// # ) -> LocalBoxFuture<'_, ()> {
+    /// #     Box::pin(async {})
+    /// # }
+    /// #
+    /// let steps = Collection::new()
+    ///     .then(None, Regex::new("^it's done$").unwrap(), test_step)
+    ///     .given(None, Regex::new(r"^(\d+) users$").unwrap(), test_step);
+    ///
+    /// let patterns = steps
+    ///     .definitions()
+    ///     .map(|def| format!("{:?} {}", def.ty, def.pattern))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(patterns, [r"Given ^(\d+) users$", "Then ^it's done$"]);
+    /// ```
+    pub fn definitions(&self) -> impl Iterator<Item = DefinitionInfo<'_>> {
+        self.by_type().into_iter().flat_map(|(ty, defs)| {
+            defs.iter()
+                .sorted_by(|(a, _), (b, _)| a.cmp(b))
+                .map(move |(key, def)| DefinitionInfo::new(ty, key, def))
+        })
+    }
+}
+
+impl<'c> DefinitionInfo<'c> {
+    /// Creates a new [`DefinitionInfo`] of the provided [`Definition`].
+    pub(super) fn new<World>(
+        ty: StepType,
+        (re, loc): &'c Key,
+        def: &'c Definition<World>,
+    ) -> Self {
+        Self {
+            ty,
+            pattern: re.as_str(),
+            regex: re,
+            location: *loc,
+            priority: def.priority,
+            alias_of: def.alias_of.as_deref().map(Regex::as_str),
+            tags: def.tags.as_deref(),
+            normalized: def.normalized.is_some(),
+            owner: def.owner.as_deref(),
+            domain: def.domain.as_deref(),
+            doc: def.doc,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{step::Collection, test_utils::common::test_step};
+
+    #[test]
+    fn lists_definitions_in_stable_order() {
+        let loc = Location { path: "steps.rs", line: 3, column: 1 };
+        let steps = Collection::new()
+            .when(None, Regex::new("^b$").unwrap(), test_step)
+            .when(Some(loc), Regex::new("^a$").unwrap(), test_step)
+            .given(None, Regex::new("^c$").unwrap(), test_step)
+            .owned_by(Ownership::new().team("core"));
+
+        let defs = steps.definitions().collect::<Vec<_>>();
+        assert_eq!(
+            defs.iter().map(|d| (d.ty, d.pattern)).collect::<Vec<_>>(),
+            [
+                (StepType::Given, "^c$"),
+                (StepType::When, "^a$"),
+                (StepType::When, "^b$"),
+            ],
+        );
+        assert_eq!(defs[1].location, Some(loc));
+        assert_eq!(defs[2].location, None);
+        assert!(defs.iter().all(|d| d.owner.is_some() && d.alias_of.is_none()));
+    }
+}
@@ -0,0 +1,129 @@
+//! Erasure of the `World` type of a [`Collection`].
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+
+use gherkin::tagexpr::TagOperation;
+
+use super::{Collection, Definition, Function, Key, Step};
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to `World` type erasure only"
+)]
+impl<World: 'static> Collection<World> {
+    /// Erases the `World` type of this [`Collection`], replacing all its
+    /// [`crate::step::Step`] functions with the provided `dispatch` one, and
+    /// scoping them to the [`gherkin::Scenario`]s matching the provided
+    /// `scope` tag expression.
+    ///
+    /// The original [`Function`] is provided to the `dispatch` function via
+    /// its [`Context`].
+    ///
+    /// [`Context`]: crate::step::Context
+    pub(crate) fn erase<Other>(
+        self,
+        scope: &TagOperation,
+        dispatch: Step<Other>,
+    ) -> Collection<Other> {
+        let erase = |definitions: HashMap<Key, Definition<World>>| {
+            definitions
+                .into_iter()
+                .map(|(key, def)| {
+                    let tags = def.tags.map_or_else(
+                        || scope.clone(),
+                        |t| {
+                            TagOperation::And(
+                                Box::new(scope.clone()),
+                                Box::new((*t).clone()),
+                            )
+                        },
+                    );
+                    let def = Definition {
+                        step: Function::Async(dispatch),
+                        priority: def.priority,
+                        alias_of: def.alias_of,
+                        tags: Some(Arc::new(tags)),
+                        normalized: def.normalized,
+                        owner: def.owner,
+                        domain: def.domain,
+                        doc: def.doc,
+                        erased: Some(Arc::new(def.step)),
+                    };
+                    (key, def)
+                })
+                .collect()
+        };
+        Collection {
+            given: erase(self.given),
+            when: erase(self.when),
+            then: erase(self.then),
+            index: OnceLock::new(),
+            shared: self.shared,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::LocalBoxFuture;
+    use gherkin::StepType;
+    use regex::Regex;
+
+    use super::*;
+    use crate::{
+        step::Context,
+        tag::Tags,
+        test_utils::common::{TestWorld, step, test_step},
+    };
+
+    /// `World` the [`TestWorld`] steps are erased into.
+    struct Erased;
+
+    fn dispatch(_: &mut Erased, _: Context) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    #[test]
+    fn scopes_and_dispatches_erased_steps() {
+        let steps = Collection::<TestWorld>::new()
+            .given(None, Regex::new("^a cat$").unwrap(), test_step)
+            .given_tagged(
+                None,
+                Regex::new("^a dog$").unwrap(),
+                "@dog".parse().unwrap(),
+                test_step,
+            )
+            .erase(&"@pets".parse().unwrap(), dispatch);
+        let find = |value, tags: &[&str]| {
+            steps
+                .find_tagged(
+                    &step(StepType::Given, value),
+                    &Tags::from_iter(tags.iter().copied()),
+                )
+                .unwrap()
+                .map(|(f, .., ctx)| (*f, ctx))
+        };
+
+        assert!(find("a cat", &[]).is_none(), "out of the scope");
+        assert!(find("a dog", &["pets"]).is_none(), "own tags are kept");
+
+        let (f, mut ctx) = find("a dog", &["pets", "dog"]).unwrap();
+        let Function::Async(f) = f else { panic!("not dispatched") };
+        let expected: Step<Erased> = dispatch;
+        assert!(std::ptr::fn_addr_eq(f, expected));
+
+        let Some(Function::Async(original)) =
+            ctx.take_erased::<Function<TestWorld>>()
+        else {
+            panic!("no original function");
+        };
+        let expected: Step<TestWorld> = test_step;
+        assert!(std::ptr::fn_addr_eq(original, expected));
+        assert!(ctx.take_erased::<Function<TestWorld>>().is_none());
+    }
+}
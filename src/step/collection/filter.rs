@@ -0,0 +1,119 @@
+//! Filtering of [`Collection`]s into sub-collections.
+
+use gherkin::StepType;
+
+use super::{Collection, DefinitionInfo};
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to filtering only"
+)]
+impl<World> Collection<World> {
+    /// Retains only the [`crate::step::Step`] definitions of this
+    /// [`Collection`] satisfying the provided `predicate`.
+    ///
+    /// The `predicate` may inspect the [`StepType`], pattern,
+    /// [`Location`] and [`Ownership`] of every definition via its
+    /// [`DefinitionInfo`].
+    ///
+    /// [`Location`]: crate::step::Location
+    /// [`Ownership`]: crate::step::Ownership
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&DefinitionInfo<'_>) -> bool,
+    {
+        for (ty, defs) in [
+            (StepType::Given, &mut self.given),
+            (StepType::When, &mut self.when),
+            (StepType::Then, &mut self.then),
+        ] {
+            defs.retain(|key, def| {
+                predicate(&DefinitionInfo::new(ty, key, def))
+            });
+        }
+        drop(self.index.take());
+    }
+
+    /// Returns a sub-collection of this [`Collection`] with only the
+    /// [`crate::step::Step`] definitions satisfying the provided `predicate`
+    /// (see [`Collection::retain()`] for details).
+    ///
+    /// Useful for a focused run loading only the [`crate::step::Step`]s of a
+    /// single domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::step::{Collection, Context, Location};
+    /// # use futures::future::LocalBoxFuture;
+    /// # use regex::Regex;
+    /// #
+    /// # struct TestWorld;
+    /// #
+    /// # fn test_step(
+    /// #     _world: &mut TestWorld,
+    /// #     _ctx: Context,
+    /// # ) -> LocalBoxFuture<'_, ()> {
+    /// #     Box::pin(async {})
+    /// # }
+    /// #
+    /// let billing = Location::new("tests/steps/billing.rs", 1, 1);
+    /// let steps = Collection::new()
+    ///     .given(None, Regex::new("^a user$").unwrap(), test_step)
+    ///     .when(Some(billing), Regex::new("^paying$").unwrap(), test_step);
+    ///
+    /// let billing_only = steps.filter(|def| {
+    ///     def.location.is_some_and(|l| l.path.contains("/billing"))
+    /// });
+    /// assert_eq!(billing_only.total_len(), 1);
+    /// ```
+    #[must_use]
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: FnMut(&DefinitionInfo<'_>) -> bool,
+    {
+        self.retain(predicate);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::test_utils::common::{step, test_step};
+
+    #[test]
+    fn filters_by_type_and_pattern() {
+        let steps = Collection::new()
+            .given(None, Regex::new("^a user$").unwrap(), test_step)
+            .given(None, Regex::new("^an invoice$").unwrap(), test_step)
+            .then(
+                None,
+                Regex::new("^the invoice is paid$").unwrap(),
+                test_step,
+            );
+
+        let given = steps.clone().filter(|d| d.ty == StepType::Given);
+        assert_eq!((given.given_len(), given.then_len()), (2, 0));
+
+        let mut invoices = steps;
+        assert!(
+            invoices.find(&step(StepType::Given, "a user")).unwrap().is_some()
+        );
+        invoices.retain(|d| d.pattern.contains("invoice"));
+        assert_eq!(invoices.total_len(), 2);
+        assert!(
+            invoices.find(&step(StepType::Given, "a user")).unwrap().is_none()
+        );
+        assert!(
+            invoices
+                .find(&step(StepType::Given, "an invoice"))
+                .unwrap()
+                .is_some(),
+        );
+    }
+}
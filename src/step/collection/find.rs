@@ -0,0 +1,255 @@
+//! Matching of [`gherkin::Step`]s against a [`Collection`].
+
+use std::iter;
+
+use gherkin::StepType;
+use itertools::Itertools as _;
+use regex::{CaptureLocations, CaptureNames, Match};
+
+use super::{Collection, Index, WithContext, normalize::normalize};
+use crate::{
+    step::{
+        CaptureName,
+        context::Context,
+        error::{AmbiguousMatchError, MatchDetails},
+    },
+    tag::Tags,
+};
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to lookup only"
+)]
+impl<World> Collection<World> {
+    /// Returns a [`crate::step::Step`] function matching the given [`gherkin::Step`], if
+    /// any.
+    ///
+    /// If the given [`gherkin::Step`] matches multiple [`regex::Regex`]es, the
+    /// [`crate::step::Step`] with the highest priority is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the given [`gherkin::Step`] matches multiple [`regex::Regex`]es with
+    /// the same highest priority.
+    pub fn find(
+        &self,
+        step: &gherkin::Step,
+    ) -> Result<Option<WithContext<'_, World>>, AmbiguousMatchError> {
+        self.find_tagged(step, &Tags::default())
+    }
+
+    /// Returns a [`crate::step::Step`] function matching the given
+    /// [`gherkin::Step`] of a [`gherkin::Scenario`] with the given `tags`, if
+    /// any.
+    ///
+    /// Works like [`Collection::find()`], but also considers tag-scoped
+    /// [`crate::step::Step`]s whose tag expressions match the `tags`. The
+    /// returned [`Context`] carries the `tags` as well.
+    ///
+    /// # Errors
+    ///
+    /// If the given [`gherkin::Step`] matches multiple [`regex::Regex`]es with
+    /// the same highest priority.
+    pub fn find_tagged(
+        &self,
+        step: &gherkin::Step,
+        tags: &Tags,
+    ) -> Result<Option<WithContext<'_, World>>, AmbiguousMatchError> {
+        let collection = match step.ty {
+            StepType::Given => &self.given,
+            StepType::When => &self.when,
+            StepType::Then => &self.then,
+        };
+
+        let normalized = normalize(&step.value);
+        let index = self.index.get_or_init(|| Index::new(self));
+        let mut captures = index
+            .candidates(step.ty, &step.value, &normalized)
+            .filter_map(|key| collection.get_key_value(key))
+            .filter(|(_, def)| {
+                def.tags.as_ref().is_none_or(|t| tags.matches(t))
+            })
+            .filter_map(|((re, loc), def)| {
+                let (matcher, text) = def.normalized.as_ref().map_or_else(
+                    || (re.inner(), &step.value),
+                    |n| (n, &normalized),
+                );
+                let mut captures = matcher.capture_locations();
+                let names = matcher.capture_names();
+                matcher
+                    .captures_read(&mut captures, text)
+                    .map(|m| (re, loc, m, captures, names, def, text))
+            })
+            .max_set_by_key(|(.., def, _)| def.priority);
+
+        let (re, loc, whole_match, captures, names, def, text) =
+            match captures.len() {
+                0 => return Ok(None),
+                // Instead of `.unwrap()` to avoid documenting `# Panics`.
+                1 => captures.pop().unwrap_or_else(|| unreachable!()),
+                _ => {
+                    let (possible_matches, details) = captures
+                        .into_iter()
+                        .sorted_by(|(a, a_loc, ..), (b, b_loc, ..)| {
+                            (a, a_loc).cmp(&(b, b_loc))
+                        })
+                        .map(|(re, loc, m, captures, names, def, text)| {
+                            let details = MatchDetails {
+                                captures: matched_groups(
+                                    names, &m, &captures, text,
+                                )
+                                .into_iter()
+                                .skip(1)
+                                .collect(),
+                                domain: def.domain.clone(),
+                            };
+                            ((re.clone(), *loc), details)
+                        })
+                        .unzip();
+                    return Err(AmbiguousMatchError::new(possible_matches)
+                        .with_details(details));
+                }
+            };
+
+        let matches = matched_groups(names, &whole_match, &captures, text);
+
+        // Returned capture locations should point to the source text, so are
+        // left empty if it doesn't match without normalization.
+        let captures = match &def.normalized {
+            Some(n) if *text != step.value => {
+                let mut locs = n.capture_locations();
+                n.captures_read(&mut locs, &step.value)
+                    .map_or_else(|| n.capture_locations(), |_| locs)
+            }
+            _ => captures,
+        };
+
+        Ok(Some((
+            &def.step,
+            captures,
+            *loc,
+            Context::new(step.clone(), matches)
+                .with_pattern(re.clone(), def.alias_of.clone())
+                .with_owner(def.owner.clone())
+                .with_tags(tags.clone())
+                .with_shared(self.shared.clone())
+                .with_erased(def.erased.clone()),
+        )))
+    }
+}
+
+/// Collects the whole `matched` text and the values of all the `captures`
+/// groups in the `text`, along with their `names`.
+fn matched_groups(
+    names: CaptureNames<'_>,
+    matched: &Match<'_>,
+    captures: &CaptureLocations,
+    text: &str,
+) -> Vec<(CaptureName, String)> {
+    #[expect( // intentional
+        clippy::string_slice,
+        reason = "all indices are obtained from the source string"
+    )]
+    names
+        .map(|opt| opt.map(str::to_owned))
+        .zip(iter::once(matched.as_str().to_owned()).chain(
+            (1..captures.len()).map(|group_id| {
+                captures
+                    .get(group_id)
+                    .map_or("", |(s, e)| &text[s..e])
+                    .to_owned()
+            }),
+        ))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::test_utils::common::{step, test_step};
+
+    fn given(value: &str) -> gherkin::Step {
+        step(StepType::Given, value)
+    }
+
+    #[test]
+    fn collection_find_functionality() {
+        let regex = Regex::new(r"I have (\d+) cucumbers").unwrap();
+        let collection = Collection::new().given(None, regex, test_step);
+
+        let result = collection.find(&given("I have 5 cucumbers")).unwrap();
+        assert!(result.is_some());
+
+        let (_, _, _, context) = result.unwrap();
+        assert_eq!(context.matches.len(), 2);
+        assert_eq!(context.matches[1].1, "5");
+    }
+
+    #[test]
+    fn resolves_ambiguity_by_priority() {
+        let collection = Collection::new()
+            .given(None, Regex::new(r"^a (\w+) user$").unwrap(), test_step)
+            .given_with_priority(
+                None,
+                Regex::new(r"^a (admin) user$").unwrap(),
+                10,
+                test_step,
+            )
+            .given_with_priority(
+                None,
+                Regex::new(r"^a (\w+) (\w+)$").unwrap(),
+                -1,
+                test_step,
+            );
+
+        let (.., ctx) =
+            collection.find(&given("a admin user")).unwrap().unwrap();
+        assert_eq!(ctx.matches[0].1, "a admin user");
+        assert!(collection.find(&given("a guest user")).unwrap().is_some());
+
+        let collection = collection.given_with_priority(
+            None,
+            Regex::new(r"^a admin (user)$").unwrap(),
+            10,
+            test_step,
+        );
+        let err = collection.find(&given("a admin user")).unwrap_err();
+        assert_eq!(err.match_count(), 2);
+    }
+
+    #[test]
+    fn reports_details_of_ambiguous_matches() {
+        let collection = Collection::new()
+            .given(
+                None,
+                Regex::new(r"^a (?<role>\w+) user$").unwrap(),
+                test_step,
+            )
+            .in_domain("Accounts")
+            .given(None, Regex::new(r"^a (\w+) (\w+)$").unwrap(), test_step);
+
+        let err = collection.find(&given("a admin user")).unwrap_err();
+        let patterns = err.patterns().collect::<Vec<_>>();
+        assert_eq!(patterns, [r"^a (?<role>\w+) user$", r"^a (\w+) (\w+)$"]);
+        assert_eq!(
+            err.details(),
+            [
+                MatchDetails {
+                    captures: vec![(Some("role".into()), "admin".into())],
+                    domain: Some("Accounts".into()),
+                },
+                MatchDetails {
+                    captures: vec![
+                        (None, "admin".into()),
+                        (None, "user".into()),
+                    ],
+                    domain: None,
+                },
+            ],
+        );
+    }
+}
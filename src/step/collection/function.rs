@@ -0,0 +1,143 @@
+//! [`gherkin::Step`] functions stored in a [`Collection`].
+//!
+//! [`Collection`]: super::Collection
+
+use std::fmt;
+
+use futures::future::LocalBoxFuture;
+use gherkin::StepType;
+use regex::Regex;
+
+use super::{Collection, Options};
+use crate::step::{Context, location::Location};
+
+/// Alias for a [`gherkin::Step`] function that returns a [`LocalBoxFuture`].
+pub type Step<World> =
+    for<'a> fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>;
+
+/// Alias for a synchronous [`gherkin::Step`] function, called inline without
+/// allocating a [`Future`].
+pub type SyncFunction<World> = fn(&mut World, Context);
+
+/// [`gherkin::Step`] function stored in a [`Collection`].
+///
+/// [`Collection`]: super::Collection
+pub enum Function<World> {
+    /// Asynchronous [`Step`] function, returning a [`LocalBoxFuture`].
+    Async(Step<World>),
+
+    /// [`SyncFunction`], called inline.
+    ///
+    /// As it blocks the whole run until it returns, it's neither limited with
+    /// a step timeout, nor reports heartbeats.
+    Sync(SyncFunction<World>),
+}
+
+// Implemented manually to omit redundant `World: Clone` trait bound, imposed by
+// `#[derive(Clone)]`.
+impl<World> Clone for Function<World> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<World> Copy for Function<World> {}
+
+// Implemented manually to print the function as a pointer.
+impl<World> fmt::Debug for Function<World> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Async(step) => write!(f, "Async({step:p})"),
+            Self::Sync(step) => write!(f, "Sync({step:p})"),
+        }
+    }
+}
+
+impl<World> From<Step<World>> for Function<World> {
+    fn from(step: Step<World>) -> Self {
+        Self::Async(step)
+    }
+}
+
+impl<World> From<SyncFunction<World>> for Function<World> {
+    fn from(step: SyncFunction<World>) -> Self {
+        Self::Sync(step)
+    }
+}
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to synchronous functions only"
+)]
+impl<World> Collection<World> {
+    /// Adds a [Given] [`SyncFunction`] matching the given `regex`.
+    ///
+    /// Unlike a [`Step`], it's called inline, without allocating a
+    /// [`Future`] on every run.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given_sync(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: SyncFunction<World>,
+    ) -> Self {
+        self.define(StepType::Given, loc, [regex], Options::default(), step)
+    }
+
+    /// Adds a [When] [`SyncFunction`] matching the given `regex`.
+    ///
+    /// Unlike a [`Step`], it's called inline, without allocating a
+    /// [`Future`] on every run.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when_sync(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: SyncFunction<World>,
+    ) -> Self {
+        self.define(StepType::When, loc, [regex], Options::default(), step)
+    }
+
+    /// Adds a [Then] [`SyncFunction`] matching the given `regex`.
+    ///
+    /// Unlike a [`Step`], it's called inline, without allocating a
+    /// [`Future`] on every run.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then_sync(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: SyncFunction<World>,
+    ) -> Self {
+        self.define(StepType::Then, loc, [regex], Options::default(), step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::common::{TestWorld, step, test_step};
+
+    #[test]
+    fn registers_sync_steps() {
+        fn sync_step(_: &mut TestWorld, _: Context) {}
+
+        let collection = Collection::new()
+            .given_sync(None, Regex::new("^a user$").unwrap(), sync_step)
+            .given(None, Regex::new("^an admin$").unwrap(), test_step);
+
+        let given = |value| step(StepType::Given, value);
+        let (found, ..) = collection.find(&given("a user")).unwrap().unwrap();
+        assert!(matches!(found, Function::Sync(_)), "{found:?}");
+        let (found, ..) = collection.find(&given("an admin")).unwrap().unwrap();
+        assert!(matches!(found, Function::Async(_)), "{found:?}");
+    }
+}
@@ -0,0 +1,191 @@
+//! Pre-filtering of [`Collection`]'s patterns with [`RegexSet`]s.
+
+use std::collections::HashMap;
+
+use gherkin::StepType;
+use itertools::{Either, Itertools as _};
+use regex::{RegexSet, RegexSetBuilder};
+
+use super::{Collection, Definition, Key};
+
+/// [`RegexSet`]s of all the patterns of a [`Collection`] by [`StepType`]s,
+/// allowing to run a [`gherkin::Step`] text through all of them at once, and
+/// capture only the matching ones afterwards.
+#[derive(Clone, Debug)]
+pub(super) struct Index {
+    /// [`Candidates`] of [Given] [`crate::step::Step`]s.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    given: Candidates,
+
+    /// [`Candidates`] of [When] [`crate::step::Step`]s.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    when: Candidates,
+
+    /// [`Candidates`] of [Then] [`crate::step::Step`]s.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    then: Candidates,
+}
+
+impl Index {
+    /// Builds a new [`Index`] of the provided [`Collection`].
+    pub(super) fn new<World>(collection: &Collection<World>) -> Self {
+        Self {
+            given: Candidates::new(&collection.given),
+            when: Candidates::new(&collection.when),
+            then: Candidates::new(&collection.then),
+        }
+    }
+
+    /// Returns [`Key`]s of the [`Definition`]s of the provided [`StepType`]
+    /// possibly matching the provided `text` (or its `normalized` variant for
+    /// the normalized [`Definition`]s).
+    pub(super) fn candidates<'me>(
+        &'me self,
+        ty: StepType,
+        text: &str,
+        normalized: &str,
+    ) -> impl Iterator<Item = &'me Key> {
+        let candidates = match ty {
+            StepType::Given => &self.given,
+            StepType::When => &self.when,
+            StepType::Then => &self.then,
+        };
+        candidates.matches(text, normalized)
+    }
+}
+
+/// [`Group`]s of [`Definition`]s of a single [`StepType`].
+#[derive(Clone, Debug)]
+struct Candidates {
+    /// [`Group`]s of [`Definition`]s matching the source text.
+    plain: Vec<Group>,
+
+    /// [`Group`]s of [`Definition`]s matching the normalized text.
+    normalized: Vec<Group>,
+}
+
+impl Candidates {
+    /// Builds new [`Candidates`] of the provided `definitions`.
+    fn new<World>(definitions: &HashMap<Key, Definition<World>>) -> Self {
+        let (normalized, plain): (Vec<_>, Vec<_>) =
+            definitions.iter().partition_map(|(key, def)| {
+                if def.normalized.is_some() {
+                    Either::Left(key.clone())
+                } else {
+                    Either::Right(key.clone())
+                }
+            });
+        Self {
+            plain: Group::chunked(plain, false),
+            normalized: Group::chunked(normalized, true),
+        }
+    }
+
+    /// Returns the [`Key`]s whose patterns match the provided `text` (or the
+    /// `normalized` one).
+    fn matches<'me>(
+        &'me self,
+        text: &str,
+        normalized: &str,
+    ) -> impl Iterator<Item = &'me Key> {
+        self.plain.iter().flat_map(move |g| g.matches(text)).chain(
+            self.normalized.iter().flat_map(move |g| g.matches(normalized)),
+        )
+    }
+}
+
+/// [`Key`]s of [`Definition`]s along with the [`RegexSet`] of their patterns.
+#[derive(Clone, Debug)]
+struct Group {
+    /// [`Key`]s of [`Definition`]s in the order of the [`RegexSet`] patterns.
+    keys: Vec<Key>,
+
+    /// [`RegexSet`] of the [`Key`]s' patterns.
+    ///
+    /// [`None`] if it exceeds the [`regex`] size limits, so all the [`Key`]s
+    /// are considered as candidates.
+    set: Option<RegexSet>,
+}
+
+impl Group {
+    /// Maximum number of patterns in a single [`Group`].
+    ///
+    /// Keeps the [`RegexSet`] within the default [`regex`] size limits even
+    /// for the patterns with large Unicode classes (like `\w`).
+    const MAX_SIZE: usize = 32;
+
+    /// Splits the provided [`Key`]s into [`Group`]s of at most
+    /// [`Group::MAX_SIZE`].
+    fn chunked(keys: Vec<Key>, case_insensitive: bool) -> Vec<Self> {
+        keys.into_iter()
+            .chunks(Self::MAX_SIZE)
+            .into_iter()
+            .map(|keys| Self::new(keys.collect(), case_insensitive))
+            .collect()
+    }
+
+    /// Builds a new [`Group`] of the provided [`Key`]s.
+    fn new(keys: Vec<Key>, case_insensitive: bool) -> Self {
+        let set = RegexSetBuilder::new(keys.iter().map(|(re, _)| re.as_str()))
+            .case_insensitive(case_insensitive)
+            .build()
+            .ok();
+        Self { keys, set }
+    }
+
+    /// Returns the [`Key`]s whose patterns match the provided `text`.
+    fn matches<'me>(&'me self, text: &str) -> impl Iterator<Item = &'me Key> {
+        self.set.as_ref().map_or_else(
+            || Either::Right(self.keys.iter()),
+            |set| {
+                Either::Left(
+                    set.matches(text)
+                        .into_iter()
+                        .filter_map(|i| self.keys.get(i)),
+                )
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::test_utils::common::{step, test_step};
+
+    fn when(value: &str) -> gherkin::Step {
+        step(StepType::When, value)
+    }
+
+    #[test]
+    fn prefilters_candidates_and_rebuilds_on_changes() {
+        let steps = (0..500).fold(Collection::new(), |steps, i| {
+            let re = Regex::new(&format!(r"^I press button {i} ([a-z]+)$"));
+            steps.when(None, re.unwrap(), test_step)
+        });
+
+        let index = Index::new(&steps);
+        let candidates = index
+            .candidates(StepType::When, "I press button 42 twice", "")
+            .collect::<Vec<_>>();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].0.as_str(), r"^I press button 42 ([a-z]+)$");
+
+        let (.., ctx) =
+            steps.find(&when("I press button 42 twice")).unwrap().unwrap();
+        assert_eq!(ctx.matches[1].1, "twice");
+        assert!(steps.find(&when("I release button 42")).unwrap().is_none());
+
+        let steps = steps.when_normalized(
+            None,
+            Regex::new(r"^I release button (\d+)$").unwrap(),
+            test_step,
+        );
+        assert!(steps.find(&when("I  RELEASE button 42")).unwrap().is_some(),);
+    }
+}
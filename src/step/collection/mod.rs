@@ -0,0 +1,279 @@
+//! Step collection management and matching functionality.
+//!
+//! This module provides the [`Collection`] struct for storing and matching
+//! step definitions with their corresponding regex patterns.
+
+mod compose;
+mod conflicts;
+mod definitions;
+mod erase;
+mod filter;
+mod find;
+mod function;
+mod index;
+mod namespace;
+mod normalize;
+mod owner;
+mod register;
+mod scope;
+mod strict;
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, OnceLock},
+};
+
+use derive_more::with_trait::Debug;
+use gherkin::tagexpr::TagOperation;
+use regex::Regex;
+
+use self::index::Index;
+pub use self::{
+    conflicts::{ComposeReport, Conflict, ConflictKind, Contribution},
+    definitions::DefinitionInfo,
+    function::{Function, Step, SyncFunction},
+    strict::DuplicateStepsError,
+};
+use super::{
+    Ownership,
+    context::{Context, Erased, SharedState},
+    location::Location,
+    regex::HashableRegex,
+};
+
+/// Alias for a [`Function`] with [`regex::CaptureLocations`], [`Location`] and
+/// [`Context`] returned by [`Collection::find()`].
+pub type WithContext<'me, World> =
+    (&'me Function<World>, regex::CaptureLocations, Option<Location>, Context);
+
+/// Key of a [`Definition`] inside a [`Collection`].
+type Key = (HashableRegex, Option<Location>);
+
+/// Collection of [`crate::step::Step`]s.
+///
+/// Every [`crate::step::Step`] has to match with exactly 1 [`regex::Regex`],
+/// unless one of the matching [`crate::step::Step`]s has a higher priority
+/// than all the others.
+#[derive(Debug)]
+pub struct Collection<World> {
+    /// Collection of [Given] [`crate::step::Step`]s.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    given: HashMap<Key, Definition<World>>,
+
+    /// Collection of [When] [`crate::step::Step`]s.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    when: HashMap<Key, Definition<World>>,
+
+    /// Collection of [Then] [`crate::step::Step`]s.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    then: HashMap<Key, Definition<World>>,
+
+    /// [`Index`] of all the patterns, built lazily on the first matching.
+    #[debug(skip)]
+    index: OnceLock<Index>,
+
+    /// [`SharedState`] of the run, provided to the [`Context`] of every
+    /// matched [`crate::step::Step`].
+    #[debug(skip)]
+    shared: Option<SharedState>,
+}
+
+/// [`crate::step::Step`] function stored in a [`Collection`] along with its
+/// matching options.
+struct Definition<World> {
+    /// [`crate::step::Step`] function itself.
+    step: Function<World>,
+
+    /// Priority resolving an ambiguity in favor of this [`Definition`].
+    priority: i32,
+
+    /// Primary pattern of the [`crate::step::Step`] function, in case this
+    /// [`Definition`] is registered for its alias.
+    alias_of: Option<HashableRegex>,
+
+    /// Tag expression a [`gherkin::Scenario`] should match for this
+    /// [`Definition`] to be considered.
+    tags: Option<Arc<TagOperation>>,
+
+    /// Case-insensitive variant of the pattern, matched against the
+    /// [`gherkin::Step`] text with collapsed whitespaces, in case this
+    /// [`Definition`] is normalized.
+    normalized: Option<Regex>,
+
+    /// [`Ownership`] of this [`Definition`], if recorded.
+    owner: Option<Arc<Ownership>>,
+
+    /// Name of the [`StepBuilder`] domain this [`Definition`] is registered
+    /// by, if recorded.
+    ///
+    /// [`StepBuilder`]: crate::step::StepBuilder
+    domain: Option<Arc<str>>,
+
+    /// Doc comment of the [`crate::step::Step`] function, if any.
+    doc: Option<&'static str>,
+
+    /// Original function of the [`crate::step::Step`], in case its `World`
+    /// type is erased.
+    erased: Option<Erased>,
+}
+
+/// Options of a [`crate::step::Step`] function registered in a [`Collection`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Options {
+    /// Priority resolving an ambiguity in favor of the
+    /// [`crate::step::Step`] function.
+    pub(crate) priority: i32,
+
+    /// Tag expression a [`gherkin::Scenario`] should match for the
+    /// [`crate::step::Step`] function to be considered.
+    pub(crate) tags: Option<TagOperation>,
+
+    /// Indicator whether the [`gherkin::Step`] text should be normalized
+    /// before matching.
+    pub(crate) normalize: bool,
+
+    /// Doc comment of the [`crate::step::Step`] function.
+    pub(crate) doc: Option<&'static str>,
+}
+
+// Implemented manually to omit redundant `World: Clone` trait bound, imposed by
+// `#[derive(Clone)]`.
+impl<World> Clone for Definition<World> {
+    fn clone(&self) -> Self {
+        Self {
+            step: self.step,
+            priority: self.priority,
+            alias_of: self.alias_of.clone(),
+            tags: self.tags.clone(),
+            normalized: self.normalized.clone(),
+            owner: self.owner.clone(),
+            domain: self.domain.clone(),
+            doc: self.doc,
+            erased: self.erased.clone(),
+        }
+    }
+}
+
+// Implemented manually to print the `step` function as a pointer.
+impl<World> fmt::Debug for Definition<World> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Definition")
+            .field("step", &self.step)
+            .field("priority", &self.priority)
+            .field("alias_of", &self.alias_of)
+            .field("tags", &self.tags)
+            .field("normalized", &self.normalized)
+            .field("owner", &self.owner)
+            .field("domain", &self.domain)
+            .field("doc", &self.doc)
+            .field("erased", &self.erased.is_some())
+            .finish()
+    }
+}
+
+// Implemented manually to omit redundant `World: Clone` trait bound, imposed by
+// `#[derive(Clone)]`.
+impl<World> Clone for Collection<World> {
+    fn clone(&self) -> Self {
+        Self {
+            given: self.given.clone(),
+            when: self.when.clone(),
+            then: self.then.clone(),
+            index: self.index.clone(),
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+// Implemented manually to omit redundant `World: Default` trait bound, imposed
+// by `#[derive(Default)]`.
+impl<World> Default for Collection<World> {
+    fn default() -> Self {
+        Self {
+            given: HashMap::new(),
+            when: HashMap::new(),
+            then: HashMap::new(),
+            index: OnceLock::new(),
+            shared: None,
+        }
+    }
+}
+
+impl<World> Collection<World> {
+    /// Creates a new empty [`Collection`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`SharedState`] provided to the [`Context`] of every matched
+    /// [`crate::step::Step`].
+    #[must_use]
+    pub(crate) fn with_shared(mut self, shared: Option<SharedState>) -> Self {
+        self.shared = shared;
+        self
+    }
+
+    /// Returns the number of Given step definitions in this collection.
+    ///
+    /// This is useful for testing and validation of modular step builders.
+    #[must_use]
+    pub fn given_len(&self) -> usize {
+        self.given.len()
+    }
+
+    /// Returns the number of When step definitions in this collection.
+    ///
+    /// This is useful for testing and validation of modular step builders.
+    #[must_use]
+    pub fn when_len(&self) -> usize {
+        self.when.len()
+    }
+
+    /// Returns the number of Then step definitions in this collection.
+    ///
+    /// This is useful for testing and validation of modular step builders.
+    #[must_use]
+    pub fn then_len(&self) -> usize {
+        self.then.len()
+    }
+
+    /// Returns the total number of step definitions in this collection.
+    ///
+    /// This is useful for testing and validation of modular step builders.
+    #[must_use]
+    pub fn total_len(&self) -> usize {
+        self.given.len() + self.when.len() + self.then.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::common::{TestWorld, test_step};
+
+    #[test]
+    fn collection_creation_and_step_addition() {
+        let collection: Collection<TestWorld> = Collection::new();
+        assert!(collection.given.is_empty());
+
+        let regex = Regex::new(r"I have (\d+) cucumbers").unwrap();
+        let collection = collection.given(None, regex, test_step);
+        assert_eq!(collection.given.len(), 1);
+    }
+
+    #[test]
+    fn collection_clone_and_default() {
+        let regex = Regex::new(r"test").unwrap();
+        let collection = Collection::new().given(None, regex, test_step);
+        let cloned = collection.clone();
+        assert_eq!(cloned.given.len(), 1);
+
+        let default_collection: Collection<TestWorld> = Collection::default();
+        assert!(default_collection.given.is_empty());
+    }
+}
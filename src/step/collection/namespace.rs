@@ -0,0 +1,134 @@
+//! Namespacing of [`Collection`]s' patterns with a required prefix.
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use regex::Regex;
+
+use super::{Collection, Definition, Key, normalize::case_insensitive};
+use crate::step::regex::HashableRegex;
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to namespacing only"
+)]
+impl<World> Collection<World> {
+    /// Merges another [`Collection`] into this one, constraining all its
+    /// patterns to the required `namespace` prefix (see
+    /// [`Collection::namespaced()`] for details).
+    ///
+    /// This way the same wording may be used by different domains without
+    /// colliding with each other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::step::Collection;
+    /// # use futures::future::LocalBoxFuture;
+    /// # use regex::Regex;
+    /// #
+    /// # struct World;
+    /// #
+    /// # fn step(_: &mut World, _: cucumber::step::Context) -> LocalBoxFuture<'_, ()> {
+    /// #     Box::pin(async {})
+    /// # }
+    /// #
+    /// let crypto = Collection::new()
+    ///     .given(None, Regex::new("^the service is running$").unwrap(), step);
+    /// let vault = Collection::new()
+    ///     .given(None, Regex::new("^the service is running$").unwrap(), step);
+    ///
+    /// // Matches `Given crypto: the service is running` and
+    /// // `Given vault: the service is running` steps.
+    /// let steps = Collection::new()
+    ///     .merge_namespaced("crypto", crypto)
+    ///     .merge_namespaced("vault", vault);
+    /// assert_eq!(steps.given_len(), 2);
+    /// ```
+    #[must_use]
+    pub fn merge_namespaced(self, namespace: &str, other: Self) -> Self {
+        self.merge(other.namespaced(namespace))
+    }
+
+    /// Constrains all the patterns of this [`Collection`] to the required
+    /// `namespace` prefix, followed by a colon.
+    ///
+    /// For example, a `^the service is running$` pattern in a `crypto`
+    /// namespace matches `crypto: the service is running` step only. Capture
+    /// groups of the patterns remain intact.
+    #[must_use]
+    pub fn namespaced(self, namespace: &str) -> Self {
+        let prefix = format!("^{}:\\s*", regex::escape(namespace));
+        Self {
+            given: prefixed(&prefix, self.given),
+            when: prefixed(&prefix, self.when),
+            then: prefixed(&prefix, self.then),
+            index: OnceLock::new(),
+            shared: self.shared,
+        }
+    }
+}
+
+/// Prefixes all the patterns of the provided `definitions` with the provided
+/// `prefix`.
+fn prefixed<World>(
+    prefix: &str,
+    definitions: HashMap<Key, Definition<World>>,
+) -> HashMap<Key, Definition<World>> {
+    let prefix_re = |re: &HashableRegex| -> HashableRegex {
+        let pattern = re.as_str().strip_prefix('^').unwrap_or(re.as_str());
+        Regex::new(&format!("{prefix}(?:{pattern})"))
+            .unwrap_or_else(|e| {
+                unreachable!("prefixing a valid `Regex` keeps it valid: {e}")
+            })
+            .into()
+    };
+
+    definitions
+        .into_iter()
+        .map(|((re, loc), mut def)| {
+            let re = prefix_re(&re);
+            def.alias_of = def.alias_of.as_ref().map(prefix_re);
+            if def.normalized.is_some() {
+                def.normalized = Some(case_insensitive(re.inner()));
+            }
+            ((re, loc), def)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use gherkin::StepType;
+
+    use super::*;
+    use crate::{
+        step::Collection,
+        test_utils::common::{step, test_step},
+    };
+
+    fn when(value: &str) -> gherkin::Step {
+        step(StepType::When, value)
+    }
+
+    #[test]
+    fn constrains_patterns_to_namespace() {
+        let steps = Collection::new().merge_namespaced(
+            "key.ops",
+            Collection::new().when(
+                None,
+                Regex::new(r"^(\w+) creates a key$").unwrap(),
+                test_step,
+            ),
+        );
+
+        let (.., ctx) =
+            steps.find(&when("key.ops: alice creates a key")).unwrap().unwrap();
+        assert_eq!(ctx.matches[1].1, "alice");
+        assert!(steps.find(&when("alice creates a key")).unwrap().is_none());
+        assert!(
+            steps.find(&when("keyXops: bob creates a key")).unwrap().is_none()
+        );
+    }
+}
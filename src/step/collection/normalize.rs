@@ -0,0 +1,152 @@
+//! Normalized matching of [`gherkin::Step`]s, ignoring case and extra
+//! whitespaces of their text.
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use gherkin::StepType;
+use itertools::Itertools as _;
+use regex::{Regex, RegexBuilder};
+
+use super::{Collection, Definition, Key, Options, Step};
+use crate::step::location::Location;
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to normalized matching only"
+)]
+impl<World> Collection<World> {
+    /// Adds a [Given] [`crate::step::Step`] matching the given `regex` against
+    /// the normalized [`gherkin::Step`] text (see [`Collection::normalized()`]
+    /// for details).
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given_normalized(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: Step<World>,
+    ) -> Self {
+        let options = Options { normalize: true, ..Options::default() };
+        self.define(StepType::Given, loc, [regex], options, step)
+    }
+
+    /// Adds a [When] [`crate::step::Step`] matching the given `regex` against
+    /// the normalized [`gherkin::Step`] text (see [`Collection::normalized()`]
+    /// for details).
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when_normalized(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: Step<World>,
+    ) -> Self {
+        let options = Options { normalize: true, ..Options::default() };
+        self.define(StepType::When, loc, [regex], options, step)
+    }
+
+    /// Adds a [Then] [`crate::step::Step`] matching the given `regex` against
+    /// the normalized [`gherkin::Step`] text (see [`Collection::normalized()`]
+    /// for details).
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then_normalized(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: Step<World>,
+    ) -> Self {
+        let options = Options { normalize: true, ..Options::default() };
+        self.define(StepType::Then, loc, [regex], options, step)
+    }
+
+    /// Makes all the [`crate::step::Step`]s of this [`Collection`] match the
+    /// normalized [`gherkin::Step`] text.
+    ///
+    /// Normalization trims the text and collapses all its whitespace runs into
+    /// a single space, while the patterns are matched case-insensitively. This
+    /// way `Given  the User` matches a `^the user$` pattern. Values captured
+    /// by the patterns are taken from the normalized text.
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        Self {
+            given: normalized(self.given),
+            when: normalized(self.when),
+            then: normalized(self.then),
+            index: OnceLock::new(),
+            shared: self.shared,
+        }
+    }
+}
+
+/// Makes all the provided `definitions` normalized.
+fn normalized<World>(
+    definitions: HashMap<Key, Definition<World>>,
+) -> HashMap<Key, Definition<World>> {
+    definitions
+        .into_iter()
+        .map(|((re, loc), mut def)| {
+            def.normalized = Some(case_insensitive(re.inner()));
+            ((re, loc), def)
+        })
+        .collect()
+}
+
+/// Returns a case-insensitive variant of the provided [`Regex`].
+pub(super) fn case_insensitive(regex: &Regex) -> Regex {
+    RegexBuilder::new(regex.as_str())
+        .case_insensitive(true)
+        .build()
+        .unwrap_or_else(|e| {
+            unreachable!("making a valid `Regex` case-insensitive: {e}")
+        })
+}
+
+/// Normalizes the provided [`gherkin::Step`] `text` by trimming it and
+/// collapsing all its whitespace runs into a single space.
+pub(super) fn normalize(text: &str) -> String {
+    text.split_whitespace().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use gherkin::StepType;
+
+    use super::*;
+    use crate::{
+        step::Collection,
+        test_utils::common::{step, test_step},
+    };
+
+    fn given(value: &str) -> gherkin::Step {
+        step(StepType::Given, value)
+    }
+
+    #[test]
+    fn matches_normalized_text() {
+        let re = || Regex::new(r"^the user (\w+) exists$").unwrap();
+        let strict = Collection::new().given(None, re(), test_step);
+        let lenient = Collection::new().given_normalized(None, re(), test_step);
+
+        let text = " The  user\tALICE exists ";
+        assert!(strict.find(&given(text)).unwrap().is_none());
+        assert!(
+            strict.clone().normalized().find(&given(text)).unwrap().is_some()
+        );
+
+        let (_, captures, _, ctx) =
+            lenient.find(&given(text)).unwrap().unwrap();
+        assert_eq!(ctx.matches[1].1, "ALICE");
+        assert_eq!(captures.get(1), None);
+
+        let (_, captures, _, ctx) =
+            lenient.find(&given("THE USER bob exists")).unwrap().unwrap();
+        assert_eq!(ctx.matches[1].1, "bob");
+        assert_eq!(captures.get(1), Some((9, 12)));
+    }
+}
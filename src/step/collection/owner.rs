@@ -0,0 +1,111 @@
+//! Recording [`Ownership`] and domains of [`crate::step::Step`] definitions.
+
+use std::sync::Arc;
+
+use super::Collection;
+use crate::step::Ownership;
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to ownership only"
+)]
+impl<World> Collection<World> {
+    /// Records the provided [`Ownership`] on all the [`crate::step::Step`]
+    /// definitions of this [`Collection`] not owned by anyone yet.
+    ///
+    /// The [`Ownership`] is provided to the matched [`crate::step::Step`]
+    /// functions via [`Context::owner()`], and reported along with their
+    /// [`event::Step::Failed`]s.
+    ///
+    /// [`Context::owner()`]: crate::step::Context::owner
+    /// [`event::Step::Failed`]: crate::event::Step::Failed
+    #[must_use]
+    pub fn owned_by(mut self, ownership: Ownership) -> Self {
+        let ownership = Arc::new(ownership);
+        for def in self
+            .given
+            .values_mut()
+            .chain(self.when.values_mut())
+            .chain(self.then.values_mut())
+        {
+            _ = def.owner.get_or_insert_with(|| Arc::clone(&ownership));
+        }
+        self
+    }
+
+    /// Records the provided `domain` name on all the [`crate::step::Step`]
+    /// definitions of this [`Collection`] not belonging to any domain yet.
+    ///
+    /// The domain is reported along with the possible matches of an
+    /// [`AmbiguousMatchError`], so it's clear which [`StepBuilder`]s are
+    /// conflicting. It's recorded automatically for the [`StepBuilder`]s
+    /// declared via [`step_builder!`] macro, or discovered via
+    /// [`Collection::discovered()`].
+    ///
+    /// [`AmbiguousMatchError`]: crate::step::AmbiguousMatchError
+    /// [`StepBuilder`]: crate::step::StepBuilder
+    /// [`step_builder!`]: crate::step_builder
+    #[must_use]
+    pub fn in_domain(mut self, domain: impl Into<Arc<str>>) -> Self {
+        let domain = domain.into();
+        for def in self
+            .given
+            .values_mut()
+            .chain(self.when.values_mut())
+            .chain(self.then.values_mut())
+        {
+            _ = def.domain.get_or_insert_with(|| Arc::clone(&domain));
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gherkin::StepType;
+    use regex::Regex;
+
+    use super::*;
+    use crate::test_utils::common::{step, test_step};
+
+    #[test]
+    fn keeps_the_innermost_owner() {
+        let payments = Collection::new()
+            .given(None, Regex::new("^a card$").unwrap(), test_step)
+            .owned_by(Ownership::new().team("payments"));
+        let steps = Collection::new()
+            .given(None, Regex::new("^a user$").unwrap(), test_step)
+            .merge(payments)
+            .owned_by(Ownership::new().team("platform"));
+
+        let owner = |value| {
+            let (.., ctx) =
+                steps.find(&step(StepType::Given, value)).unwrap().unwrap();
+            ctx.owner().and_then(|o| o.team.clone())
+        };
+        assert_eq!(owner("a card").as_deref(), Some("payments"));
+        assert_eq!(owner("a user").as_deref(), Some("platform"));
+    }
+
+    #[test]
+    fn keeps_the_innermost_domain() {
+        let payments = Collection::new()
+            .given(None, Regex::new("^a card$").unwrap(), test_step)
+            .in_domain("Payments");
+        let steps = Collection::new()
+            .given(None, Regex::new("^a user$").unwrap(), test_step)
+            .merge(payments)
+            .in_domain("Platform");
+
+        let domains = steps
+            .definitions()
+            .map(|d| (d.pattern, d.domain))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            domains,
+            [("^a card$", Some("Payments")), ("^a user$", Some("Platform"))],
+        );
+    }
+}
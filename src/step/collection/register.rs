@@ -0,0 +1,252 @@
+//! Registration of [`crate::step::Step`] functions in a [`Collection`].
+
+use std::sync::Arc;
+
+use gherkin::StepType;
+use regex::Regex;
+
+use super::{
+    Collection, Definition, Function, Options, Step,
+    normalize::case_insensitive,
+};
+use crate::step::{location::Location, regex::HashableRegex};
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to registration only"
+)]
+impl<World> Collection<World> {
+    /// Adds a [Given] [`crate::step::Step`] matching the given `regex`.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: Step<World>,
+    ) -> Self {
+        self.given_with_priority(loc, regex, 0, step)
+    }
+
+    /// Adds a [When] [`crate::step::Step`] matching the given `regex`.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: Step<World>,
+    ) -> Self {
+        self.when_with_priority(loc, regex, 0, step)
+    }
+
+    /// Adds a [Then] [`crate::step::Step`] matching the given `regex`.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: Step<World>,
+    ) -> Self {
+        self.then_with_priority(loc, regex, 0, step)
+    }
+
+    /// Adds a [Given] [`crate::step::Step`] matching the given `regex` with
+    /// the given `priority`.
+    ///
+    /// When a [`gherkin::Step`] matches multiple [`crate::step::Step`]s, the
+    /// one with the highest `priority` is chosen (the default one is `0`).
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given_with_priority(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        priority: i32,
+        step: Step<World>,
+    ) -> Self {
+        self.define(
+            StepType::Given,
+            loc,
+            [regex],
+            Options { priority, ..Options::default() },
+            step,
+        )
+    }
+
+    /// Adds a [When] [`crate::step::Step`] matching the given `regex` with
+    /// the given `priority`.
+    ///
+    /// When a [`gherkin::Step`] matches multiple [`crate::step::Step`]s, the
+    /// one with the highest `priority` is chosen (the default one is `0`).
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when_with_priority(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        priority: i32,
+        step: Step<World>,
+    ) -> Self {
+        self.define(
+            StepType::When,
+            loc,
+            [regex],
+            Options { priority, ..Options::default() },
+            step,
+        )
+    }
+
+    /// Adds a [Then] [`crate::step::Step`] matching the given `regex` with
+    /// the given `priority`.
+    ///
+    /// When a [`gherkin::Step`] matches multiple [`crate::step::Step`]s, the
+    /// one with the highest `priority` is chosen (the default one is `0`).
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then_with_priority(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        priority: i32,
+        step: Step<World>,
+    ) -> Self {
+        self.define(
+            StepType::Then,
+            loc,
+            [regex],
+            Options { priority, ..Options::default() },
+            step,
+        )
+    }
+
+    /// Adds a [Given] [`crate::step::Step`] matching any of the given `regexes`.
+    ///
+    /// All the `regexes` but the first one are recorded as its aliases, while
+    /// [`Context::pattern()`] of the [`crate::step::Step`] function tells which
+    /// one has matched.
+    ///
+    /// [`Context::pattern()`]: crate::step::Context::pattern
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given_aliased(
+        self,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        step: Step<World>,
+    ) -> Self {
+        self.define(StepType::Given, loc, regexes, Options::default(), step)
+    }
+
+    /// Adds a [When] [`crate::step::Step`] matching any of the given `regexes`.
+    ///
+    /// All the `regexes` but the first one are recorded as its aliases, while
+    /// [`Context::pattern()`] of the [`crate::step::Step`] function tells which
+    /// one has matched.
+    ///
+    /// [`Context::pattern()`]: crate::step::Context::pattern
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when_aliased(
+        self,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        step: Step<World>,
+    ) -> Self {
+        self.define(StepType::When, loc, regexes, Options::default(), step)
+    }
+
+    /// Adds a [Then] [`crate::step::Step`] matching any of the given `regexes`.
+    ///
+    /// All the `regexes` but the first one are recorded as its aliases, while
+    /// [`Context::pattern()`] of the [`crate::step::Step`] function tells which
+    /// one has matched.
+    ///
+    /// [`Context::pattern()`]: crate::step::Context::pattern
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then_aliased(
+        self,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        step: Step<World>,
+    ) -> Self {
+        self.define(StepType::Then, loc, regexes, Options::default(), step)
+    }
+
+    /// Adds the provided [`crate::step::Step`] function of the provided
+    /// [`StepType`] with the provided [`Options`] for each of the `regexes`,
+    /// recording all but the first one as its aliases.
+    pub(crate) fn define(
+        mut self,
+        ty: StepType,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        options: Options,
+        step: impl Into<Function<World>>,
+    ) -> Self {
+        let step = step.into();
+        let definitions = match ty {
+            StepType::Given => &mut self.given,
+            StepType::When => &mut self.when,
+            StepType::Then => &mut self.then,
+        };
+        let Options { priority, tags, normalize, doc } = options;
+        let tags = tags.map(Arc::new);
+        let mut primary = None::<HashableRegex>;
+        for regex in regexes {
+            let def = Definition {
+                step,
+                priority,
+                alias_of: primary.clone(),
+                tags: tags.clone(),
+                normalized: normalize.then(|| case_insensitive(&regex)),
+                owner: None,
+                domain: None,
+                doc,
+                erased: None,
+            };
+            let regex = HashableRegex::from(regex);
+            _ = primary.get_or_insert_with(|| regex.clone());
+            drop(definitions.insert((regex, loc), def));
+        }
+        drop(self.index.take());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        step::Context,
+        test_utils::common::{TestWorld, step, test_step},
+    };
+
+    #[test]
+    fn registers_aliases() {
+        let re = |s| Regex::new(s).unwrap();
+        let collection = Collection::new().when_aliased(
+            None,
+            [re("^I log in$"), re("^I sign in$"), re("^I authenticate$")],
+            test_step,
+        );
+        assert_eq!(collection.when_len(), 3);
+
+        let (.., ctx) = collection
+            .find(&step(StepType::When, "I sign in"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(ctx.pattern(), Some("^I sign in$"));
+        assert_eq!(ctx.alias_of(), Some("^I log in$"));
+    }
+}
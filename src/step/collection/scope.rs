@@ -0,0 +1,128 @@
+//! Scoping of [`crate::step::Step`] functions to tagged
+//! [`gherkin::Scenario`]s.
+
+use gherkin::{StepType, tagexpr::TagOperation};
+use regex::Regex;
+
+use super::{Collection, Options, Step};
+use crate::step::location::Location;
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to tag scoping only"
+)]
+impl<World> Collection<World> {
+    /// Adds a [Given] [`crate::step::Step`] matching the given `regex` only in
+    /// [`gherkin::Scenario`]s matching the given `tags` expression (including
+    /// the inherited tags).
+    ///
+    /// Different tag-scoped variants of the same `regex` should be registered
+    /// with different `loc`ations, otherwise they overwrite each other.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given_tagged(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        tags: TagOperation,
+        step: Step<World>,
+    ) -> Self {
+        self.define(
+            StepType::Given,
+            loc,
+            [regex],
+            Options { tags: Some(tags), ..Options::default() },
+            step,
+        )
+    }
+
+    /// Adds a [When] [`crate::step::Step`] matching the given `regex` only in
+    /// [`gherkin::Scenario`]s matching the given `tags` expression (including
+    /// the inherited tags).
+    ///
+    /// Different tag-scoped variants of the same `regex` should be registered
+    /// with different `loc`ations, otherwise they overwrite each other.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when_tagged(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        tags: TagOperation,
+        step: Step<World>,
+    ) -> Self {
+        self.define(
+            StepType::When,
+            loc,
+            [regex],
+            Options { tags: Some(tags), ..Options::default() },
+            step,
+        )
+    }
+
+    /// Adds a [Then] [`crate::step::Step`] matching the given `regex` only in
+    /// [`gherkin::Scenario`]s matching the given `tags` expression (including
+    /// the inherited tags).
+    ///
+    /// Different tag-scoped variants of the same `regex` should be registered
+    /// with different `loc`ations, otherwise they overwrite each other.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then_tagged(
+        self,
+        loc: Option<Location>,
+        regex: Regex,
+        tags: TagOperation,
+        step: Step<World>,
+    ) -> Self {
+        self.define(
+            StepType::Then,
+            loc,
+            [regex],
+            Options { tags: Some(tags), ..Options::default() },
+            step,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::LocalBoxFuture;
+
+    use super::*;
+    use crate::{
+        step::{Context, Function},
+        tag::Tags,
+        test_utils::common::{TestWorld, step, test_step},
+    };
+
+    fn live(_: &mut TestWorld, _: Context) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    #[test]
+    fn matches_only_in_tagged_scenarios() {
+        let re = || Regex::new("^the service is running$").unwrap();
+        let loc = |line| Some(Location::new("src/steps.rs", line, 1));
+        let steps = Collection::new()
+            .given_tagged(loc(1), re(), "@mock".parse().unwrap(), test_step)
+            .given_tagged(loc(2), re(), "not @mock".parse().unwrap(), live);
+        let step = step(StepType::Given, "the service is running");
+
+        let cases: [(_, Step<TestWorld>); 2] =
+            [(["mock"], test_step), (["live"], live)];
+        for (tags, expected) in cases {
+            let (f, ..) = steps
+                .find_tagged(&step, &Tags::from_iter(tags))
+                .unwrap()
+                .unwrap();
+            let Function::Async(f) = f else { panic!("{tags:?}") };
+            assert!(std::ptr::fn_addr_eq(*f, expected), "{tags:?}");
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Strict composition of [`Collection`]s, rejecting duplicated patterns.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use derive_more::with_trait::Error;
+use gherkin::StepType;
+use itertools::Itertools as _;
+
+use super::{Collection, Definition, Key};
+use crate::step::{location::Location, regex::HashableRegex};
+
+/// Error of strictly merging [`Collection`]s defining the same
+/// [`regex::Regex`] patterns.
+#[derive(Clone, Debug, Error)]
+pub struct DuplicateStepsError {
+    /// Duplicated [`regex::Regex`] patterns along with their [`StepType`]s and
+    /// [`Location`]s of all their definitions.
+    pub duplicates: Vec<(StepType, HashableRegex, Vec<Option<Location>>)>,
+}
+
+impl fmt::Display for DuplicateStepsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Duplicate step definitions:")?;
+        for (ty, re, locs) in &self.duplicates {
+            write!(f, "\n{} {re}", STEP_TYPES[step_type_index(*ty)].1)?;
+            for loc in locs {
+                match loc {
+                    Some(loc) => write!(f, "\n  --> {loc}")?,
+                    None => write!(f, "\n  --> <unknown location>")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to strict merging only"
+)]
+impl<World> Collection<World> {
+    /// Merges another [`Collection`] into this one, like [`merge()`] does, but
+    /// failing if both of them define the same [`regex::Regex`] pattern for
+    /// the same [`StepType`], instead of silently overwriting or duplicating
+    /// the definition.
+    ///
+    /// # Errors
+    ///
+    /// With all the duplicated [`regex::Regex`] patterns and their
+    /// [`Location`]s.
+    ///
+    /// [`merge()`]: Collection::merge
+    pub fn try_merge(self, other: Self) -> Result<Self, DuplicateStepsError> {
+        Self::try_compose(vec![self, other])
+    }
+
+    /// Composes multiple [`Collection`]s into a single one, like
+    /// [`compose()`] does, but failing if any two of them define the same
+    /// [`regex::Regex`] pattern for the same [`StepType`].
+    ///
+    /// # Errors
+    ///
+    /// With all the duplicated [`regex::Regex`] patterns and their
+    /// [`Location`]s.
+    ///
+    /// [`compose()`]: Collection::compose
+    pub fn try_compose(
+        collections: Vec<Self>,
+    ) -> Result<Self, DuplicateStepsError> {
+        let mut seen = BTreeMap::<_, Vec<_>>::new();
+        for c in &collections {
+            for (ty, defs) in c.by_type() {
+                for (re, loc) in defs.keys().sorted() {
+                    seen.entry((step_type_index(ty), re.clone()))
+                        .or_default()
+                        .push(*loc);
+                }
+            }
+        }
+
+        let duplicates = seen
+            .into_iter()
+            .filter(|(_, locs)| locs.len() > 1)
+            .map(|((ty, re), locs)| (STEP_TYPES[ty].0, re, locs))
+            .collect::<Vec<_>>();
+        if duplicates.is_empty() {
+            Ok(Self::compose(collections))
+        } else {
+            Err(DuplicateStepsError { duplicates })
+        }
+    }
+
+    /// Returns all the [`Definition`]s of this [`Collection`] by their
+    /// [`StepType`]s.
+    pub(super) const fn by_type(
+        &self,
+    ) -> [(StepType, &HashMap<Key, Definition<World>>); 3] {
+        [
+            (StepType::Given, &self.given),
+            (StepType::When, &self.when),
+            (StepType::Then, &self.then),
+        ]
+    }
+}
+
+/// All the [`StepType`]s in their natural order, along with their names.
+pub(super) const STEP_TYPES: [(StepType, &str); 3] = [
+    (StepType::Given, "Given"),
+    (StepType::When, "When"),
+    (StepType::Then, "Then"),
+];
+
+/// Returns the index of the provided [`StepType`] in [`STEP_TYPES`].
+pub(super) const fn step_type_index(ty: StepType) -> usize {
+    match ty {
+        StepType::Given => 0,
+        StepType::When => 1,
+        StepType::Then => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::test_utils::common::test_step;
+
+    #[test]
+    fn rejects_duplicate_patterns() {
+        let loc = |line| Some(Location::new("src/steps.rs", line, 1));
+        let re = |s| Regex::new(s).unwrap();
+        let auth = Collection::new()
+            .given(loc(1), re("^a user$"), test_step)
+            .when(loc(2), re("^logging in$"), test_step);
+        let billing = Collection::new()
+            .given(loc(3), re("^a user$"), test_step)
+            .then(loc(4), re("^logging in$"), test_step);
+        let audit = Collection::new().given(None, re("^a user$"), test_step);
+
+        let merged = auth.clone().try_merge(billing.clone().given(
+            None,
+            re("^an admin$"),
+            test_step,
+        ));
+        let err = merged.map(drop).unwrap_err();
+        assert_eq!(err.duplicates.len(), 1);
+        assert_eq!(err.duplicates[0].2, [loc(1), loc(3)]);
+
+        let err = Collection::try_compose(vec![auth.clone(), billing, audit])
+            .map(drop)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Duplicate step definitions:\n\
+             Given ^a user$\n  \
+             --> src/steps.rs:1:1\n  \
+             --> src/steps.rs:3:1\n  \
+             --> <unknown location>",
+        );
+
+        let ok = auth.try_merge(Collection::new().when(
+            None,
+            re("^logging out$"),
+            test_step,
+        ));
+        assert_eq!(ok.unwrap().total_len(), 3);
+    }
+}
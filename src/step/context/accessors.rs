@@ -0,0 +1,262 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Typed accessors of [`Context`] payloads: capture groups, doc strings and
+//! data tables.
+
+use std::{any, fmt::Display, str::FromStr};
+
+use itertools::Itertools as _;
+
+#[cfg(any(
+    feature = "docstrings-json",
+    feature = "docstrings-toml",
+    feature = "docstrings-yaml",
+    feature = "tables-serde",
+))]
+use serde::de::DeserializeOwned;
+
+#[cfg(any(
+    feature = "docstrings-json",
+    feature = "docstrings-toml",
+    feature = "docstrings-yaml",
+))]
+use super::super::DocStringError;
+#[cfg(feature = "tables-serde")]
+use super::super::{TableError, table_serde};
+use super::{
+    super::{ArgError, DocString},
+    CaptureName, Context,
+};
+
+/// Formats the provided capture group values as `name = "value"` (or
+/// `$index = "value"` for unnamed groups) separated with commas.
+pub(crate) fn format_capture_values(
+    captures: &[(CaptureName, String)],
+) -> String {
+    captures
+        .iter()
+        .enumerate()
+        .map(|(i, (name, val))| {
+            name.as_ref().map_or_else(
+                || format!("${} = {val:?}", i + 1),
+                |name| format!("{name} = {val:?}"),
+            )
+        })
+        .join(", ")
+}
+
+impl Context {
+    /// Parses the value of a capture group by index (0 is the whole match)
+    /// into a typed argument.
+    ///
+    /// This way a hand-written [`crate::step::Step`] function doesn't need to
+    /// index [`Context::matches`] and unwrap parsing of the value, while the
+    /// returned [`ArgError`] names the argument, its type and the value.
+    ///
+    /// # Errors
+    ///
+    /// If there is no such capture group, or its value cannot be parsed.
+    #[doc(alias = "param")]
+    pub fn arg<T>(&self, index: usize) -> Result<T, ArgError>
+    where
+        T: FromStr<Err: Display>,
+    {
+        parse_arg(&index.to_string(), self.get_capture(index))
+    }
+
+    /// Parses the value of a named capture group into a typed argument.
+    ///
+    /// # Errors
+    ///
+    /// If there is no such capture group, or its value cannot be parsed.
+    #[doc(alias = "named_param")]
+    pub fn named_arg<T>(&self, name: &str) -> Result<T, ArgError>
+    where
+        T: FromStr<Err: Display>,
+    {
+        parse_arg(name, self.get_named_capture(name))
+    }
+
+    /// Returns the [`DocString`] of the step, if any.
+    #[must_use]
+    pub fn docstring(&self) -> Option<DocString> {
+        self.step.docstring.as_deref().map(DocString::parse)
+    }
+
+    /// Deserializes the [`DocString`] of the step according to its media
+    /// type (JSON, if not specified).
+    ///
+    /// See [`DocString::deserialize()`] for the supported media types.
+    ///
+    /// # Errors
+    ///
+    /// If the step has no [`DocString`], its media type isn't supported, or
+    /// its content cannot be deserialized.
+    #[cfg(any(
+        feature = "docstrings-json",
+        feature = "docstrings-toml",
+        feature = "docstrings-yaml",
+    ))]
+    pub fn docstring_as<T: DeserializeOwned>(
+        &self,
+    ) -> Result<T, DocStringError> {
+        self.docstring().ok_or(DocStringError::Missing)?.deserialize()
+    }
+
+    /// Deserializes the data table of the step into records, using its first
+    /// row as field names.
+    ///
+    /// # Errors
+    ///
+    /// If the step has no data table, or any of its rows cannot be
+    /// deserialized (reporting the offending row and field).
+    #[cfg(feature = "tables-serde")]
+    pub fn table_as<T: DeserializeOwned>(&self) -> Result<Vec<T>, TableError> {
+        table_serde::rows(self.step.table.as_ref().ok_or(TableError::Missing)?)
+    }
+
+    /// Deserializes the vertical data table of the step into a single record,
+    /// using its first column as field names, and its second one as values.
+    ///
+    /// # Errors
+    ///
+    /// If the step has no data table, or it cannot be deserialized (reporting
+    /// the offending row and field).
+    #[cfg(feature = "tables-serde")]
+    pub fn vertical_table_as<T: DeserializeOwned>(
+        &self,
+    ) -> Result<T, TableError> {
+        let table = self.step.table.as_ref().ok_or(TableError::Missing)?;
+        table_serde::vertical(table)
+    }
+
+    /// Returns the value of a named capture group, if it exists.
+    #[must_use]
+    pub fn get_named_capture(&self, name: &str) -> Option<&str> {
+        self.matches
+            .iter()
+            .find(|(capture_name, _)| {
+                capture_name.as_ref().map_or(false, |n| n == name)
+            })
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of a capture group by index (0 is the whole match).
+    #[must_use]
+    pub fn get_capture(&self, index: usize) -> Option<&str> {
+        self.matches.get(index).map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the number of capture groups (including the whole match).
+    #[must_use]
+    pub fn capture_count(&self) -> usize {
+        self.matches.len()
+    }
+}
+
+/// Parses the provided captured `value` of the `name`d argument.
+fn parse_arg<T>(name: &str, value: Option<&str>) -> Result<T, ArgError>
+where
+    T: FromStr<Err: Display>,
+{
+    let error = |val: &str, reason: String| ArgError {
+        name: name.to_owned(),
+        ty: any::type_name::<T>().to_owned(),
+        value: val.to_owned(),
+        reason,
+    };
+    let val = value.ok_or_else(|| error("", "no such capture group".into()))?;
+    val.parse().map_err(|e: T::Err| error(val, e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_step() -> gherkin::Step {
+        gherkin::Step {
+            keyword: "Given".to_string(),
+            ty: gherkin::StepType::Given,
+            value: "I have 5 cucumbers".to_string(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        }
+    }
+
+    #[test]
+    fn context_parses_typed_args() {
+        let matches = vec![
+            (None, "I have 5 cucumbers".to_string()),
+            (Some("count".to_string()), "5".to_string()),
+            (Some("item".to_string()), "cucumbers".to_string()),
+        ];
+        let context = Context::new(create_test_step(), matches);
+
+        assert_eq!(context.arg::<u32>(1), Ok(5));
+        assert_eq!(context.named_arg::<String>("item").unwrap(), "cucumbers");
+
+        let err = context.named_arg::<u32>("item").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot parse `cucumbers` as `u32` for `item` argument: \
+             invalid digit found in string",
+        );
+        assert_eq!(
+            context.arg::<u32>(3).unwrap_err().reason,
+            "no such capture group"
+        );
+    }
+
+    #[test]
+    fn context_get_named_capture_returns_correct_value() {
+        let step = create_test_step();
+        let matches = vec![
+            (None, "I have 5 cucumbers".to_string()),
+            (Some("count".to_string()), "5".to_string()),
+            (Some("item".to_string()), "cucumbers".to_string()),
+        ];
+
+        let context = Context::new(step, matches);
+        assert_eq!(context.get_named_capture("count"), Some("5"));
+        assert_eq!(context.get_named_capture("item"), Some("cucumbers"));
+        assert_eq!(context.get_named_capture("nonexistent"), None);
+    }
+
+    #[test]
+    fn context_get_capture_returns_correct_value_by_index() {
+        let step = create_test_step();
+        let matches = vec![
+            (None, "whole match".to_string()),
+            (Some("group1".to_string()), "value1".to_string()),
+            (Some("group2".to_string()), "value2".to_string()),
+        ];
+
+        let context = Context::new(step, matches);
+        assert_eq!(context.get_capture(0), Some("whole match"));
+        assert_eq!(context.get_capture(1), Some("value1"));
+        assert_eq!(context.get_capture(2), Some("value2"));
+        assert_eq!(context.get_capture(3), None);
+    }
+
+    #[test]
+    fn context_capture_count_returns_correct_count() {
+        let step = create_test_step();
+        let matches = vec![
+            (None, "whole match".to_string()),
+            (Some("group1".to_string()), "value1".to_string()),
+        ];
+
+        let context = Context::new(step, matches);
+        assert_eq!(context.capture_count(), 2);
+    }
+}
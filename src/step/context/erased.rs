@@ -0,0 +1,42 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Original [`crate::step::Step`] functions dispatched to a `World` of an
+//! erased type via a [`Context`].
+
+use std::{any::Any, sync::Arc};
+
+use super::Context;
+
+/// Type-erased original [`crate::step::Step`] function, whose `World` type
+/// differs from the one of the function it's matched to.
+pub(crate) type Erased = Arc<dyn Any + Send + Sync>;
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to `World` type erasure only"
+)]
+impl Context {
+    /// Sets the [`Erased`] original function of the matched
+    /// [`crate::step::Step`] function.
+    #[must_use]
+    pub(crate) fn with_erased(mut self, erased: Option<Erased>) -> Self {
+        self.erased = erased;
+        self
+    }
+
+    /// Takes the [`Erased`] original function of the matched
+    /// [`crate::step::Step`] function, if it's of type `F`.
+    pub(crate) fn take_erased<F: Any + Copy + Send + Sync>(&mut self) -> Option<F> {
+        self.erased.take()?.downcast().ok().map(|f: Arc<F>| *f)
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Fixture`]s of a [`gherkin::Scenario`] accessible via a [`Context`].
+//!
+//! [`Fixture`]: crate::fixture::Fixture
+
+use std::{any::Any, sync::Arc};
+
+use super::Context;
+use crate::fixture::Instances;
+
+impl Context {
+    /// Sets the [`Instances`] of the [`Fixture`]s set up for the
+    /// [`gherkin::Scenario`] the step belongs to.
+    ///
+    /// [`Fixture`]: crate::fixture::Fixture
+    #[must_use]
+    pub fn with_fixtures(mut self, fixtures: Instances) -> Self {
+        self.fixtures = fixtures;
+        self
+    }
+
+    /// Returns the value of the first [`Fixture`] of type `T` set up for the
+    /// [`gherkin::Scenario`] the step belongs to, if any.
+    ///
+    /// [`Fixture`]: crate::fixture::Fixture
+    #[must_use]
+    pub fn fixture<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.fixtures.get()
+    }
+
+    /// Returns the [`Instances`] of all the [`Fixture`]s set up for the
+    /// [`gherkin::Scenario`] the step belongs to.
+    ///
+    /// [`Fixture`]: crate::fixture::Fixture
+    #[must_use]
+    pub const fn fixtures(&self) -> &Instances {
+        &self.fixtures
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Metadata of the [`gherkin::Scenario`] a step is executed in.
+
+use std::path::PathBuf;
+
+use crate::{runner::basic::ScenarioId, scenario::Ext as _};
+
+/// Metadata of the [`gherkin::Scenario`] a [`crate::step::Step`] function is
+/// executed in, allowing to log meaningful identifiers without threading them
+/// through the [`World`] manually.
+///
+/// [`World`]: crate::World
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScenarioMetadata {
+    /// [`ScenarioId`] of the [`gherkin::Scenario`] attempt, correlating it
+    /// with the emitted [`event`]s and the output of [`Writer`]s (if provided
+    /// by the [`Runner`]).
+    ///
+    /// [`event`]: crate::event
+    /// [`Runner`]: crate::Runner
+    /// [`Writer`]: crate::Writer
+    pub id: Option<ScenarioId>,
+
+    /// Name of the [`gherkin::Feature`].
+    pub feature: String,
+
+    /// Path to the `.feature` file of the [`gherkin::Feature`], if any.
+    pub path: Option<PathBuf>,
+
+    /// Name of the [`gherkin::Rule`], if the [`gherkin::Scenario`] belongs to
+    /// any.
+    pub rule: Option<String>,
+
+    /// Name of the [`gherkin::Scenario`], with the [`Examples`][1] values
+    /// substituted.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#examples
+    pub scenario: String,
+
+    /// Line of the [`gherkin::Scenario`] (or of its [`Examples`][1] row) in
+    /// the `.feature` file.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#examples
+    pub line: usize,
+
+    /// Pairs of the [`Examples`][1] table header and the row value an expanded
+    /// [`Scenario Outline`][2] is executed with, or nothing otherwise.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#examples
+    /// [2]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    pub example: Vec<(String, String)>,
+}
+
+impl ScenarioMetadata {
+    /// Collects [`ScenarioMetadata`] of the provided [`gherkin::Scenario`].
+    #[must_use]
+    pub fn of(
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> Self {
+        Self {
+            id: None,
+            feature: feature.name.clone(),
+            path: feature.path.clone(),
+            rule: rule.map(|r| r.name.clone()),
+            scenario: scenario.name.clone(),
+            line: scenario.position.line,
+            example: scenario
+                .example_row()
+                .map(|row| {
+                    row.values
+                        .into_iter()
+                        .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns value of the provided [`Examples`][1] `column` the
+    /// [`gherkin::Scenario`] is executed with, if any.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#examples
+    #[must_use]
+    pub fn example_value(&self, column: &str) -> Option<&str> {
+        self.example
+            .iter()
+            .find_map(|(k, v)| (k == column).then_some(v.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::Ext as _;
+
+    #[test]
+    fn collects_scenario_metadata() {
+        let feature = gherkin::Feature::parse(
+            "Feature: Checkout\n\
+             \x20 Rule: Payments\n\
+             \x20   Scenario Outline: pay with <method>\n\
+             \x20     Given a <method> payment\n\
+             \x20     Examples:\n\
+             \x20       | method |\n\
+             \x20       | card   |\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+        .expand_examples()
+        .unwrap();
+        let rule = &feature.rules[0];
+
+        let meta =
+            ScenarioMetadata::of(&feature, Some(rule), &rule.scenarios[0]);
+        assert_eq!(meta.feature, "Checkout");
+        assert_eq!(meta.rule.as_deref(), Some("Payments"));
+        assert_eq!(meta.scenario, "pay with card");
+        assert_eq!(meta.line, 7);
+        assert_eq!(meta.example_value("method"), Some("card"));
+        assert_eq!(meta.example_value("amount"), None);
+    }
+}
@@ -0,0 +1,288 @@
+//! Step execution context and related types.
+//!
+//! This module provides the [`Context`] struct that contains information
+//! about the step being executed, including the step itself and any regex
+//! capture groups from the step matching process.
+
+mod accessors;
+mod erased;
+mod fixture;
+mod metadata;
+mod shared;
+
+use std::{borrow::Borrow, sync::Arc};
+
+pub(crate) use self::{accessors::format_capture_values, erased::Erased};
+pub use self::{metadata::ScenarioMetadata, shared::SharedState};
+use super::{
+    Ownership, checks::Checks, publish::Publisher, regex::HashableRegex,
+};
+use crate::{cli, fixture::Instances, runner::basic::ScenarioId, tag::Tags};
+
+/// Name of a capturing group inside a [`regex`].
+pub type CaptureName = Option<String>;
+
+/// Context for a [`crate::step::Step`] function execution.
+#[derive(Clone, Debug)]
+pub struct Context {
+    /// [`crate::step::Step`] matched to a [`crate::step::Step`] function.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub step: gherkin::Step,
+
+    /// [`regex::Regex`] matches of a [`Step::value`].
+    ///
+    /// [`Step::value`]: gherkin::Step::value
+    pub matches: Vec<(CaptureName, String)>,
+
+    /// Structured [`Tags`] of the [`gherkin::Scenario`] the
+    /// [`crate::step::Step`] belongs to, including the inherited ones.
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub tags: Tags,
+
+    /// [`regex::Regex`] pattern the [`crate::step::Step`] has matched (if
+    /// known).
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub pattern: Option<HashableRegex>,
+
+    /// Primary [`regex::Regex`] pattern of the [`crate::step::Step`] function,
+    /// in case the matched [`Context::pattern`] is its alias.
+    pub alias_of: Option<HashableRegex>,
+
+    /// [`ScenarioMetadata`] of the [`gherkin::Scenario`] the
+    /// [`crate::step::Step`] belongs to (if known).
+    ///
+    /// [`crate::step::Step`]: gherkin::Step
+    pub scenario: Option<ScenarioMetadata>,
+
+    /// [`Ownership`] of the matched [`crate::step::Step`] function (if
+    /// recorded).
+    pub owner: Option<Arc<Ownership>>,
+
+    /// Failed soft assertions recorded via [`Context::check()`].
+    pub(crate) checks: Checks,
+
+    /// [`Publisher`] of [`crate::event::Custom`] events published via
+    /// [`Context::publish()`].
+    pub(crate) publisher: Publisher,
+
+    /// [`SharedState`] of the run (if provided).
+    pub(crate) shared: Option<SharedState>,
+
+    /// Custom CLI options of the run (if any).
+    pub(crate) cli: Option<cli::custom::Erased>,
+
+    /// [`Instances`] of the [`Fixture`]s set up for the
+    /// [`gherkin::Scenario`] the [`crate::step::Step`] belongs to.
+    ///
+    /// [`Fixture`]: crate::fixture::Fixture
+    pub(crate) fixtures: Instances,
+
+    /// [`Erased`] original function of the matched [`crate::step::Step`]
+    /// function, in case it dispatches it to a `World` of an erased type.
+    pub(crate) erased: Option<Erased>,
+}
+
+impl Context {
+    /// Creates a new [`Context`] with the given step and matches.
+    #[must_use]
+    pub fn new(
+        step: gherkin::Step,
+        matches: Vec<(CaptureName, String)>,
+    ) -> Self {
+        Self {
+            step,
+            matches,
+            tags: Tags::default(),
+            pattern: None,
+            alias_of: None,
+            scenario: None,
+            owner: None,
+            checks: Checks::default(),
+            publisher: Publisher::default(),
+            shared: None,
+            cli: cli::custom::current(),
+            fixtures: Instances::default(),
+            erased: None,
+        }
+    }
+
+    /// Sets the structured [`Tags`] of the [`gherkin::Scenario`] the step
+    /// belongs to.
+    #[must_use]
+    pub fn with_tags(mut self, tags: Tags) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Sets the [`ScenarioMetadata`] of the [`gherkin::Scenario`] the step
+    /// belongs to.
+    #[must_use]
+    pub fn with_scenario(mut self, scenario: ScenarioMetadata) -> Self {
+        self.scenario = Some(scenario);
+        self
+    }
+
+    /// Sets the [`Ownership`] of the matched step function.
+    #[must_use]
+    pub fn with_owner(mut self, owner: Option<Arc<Ownership>>) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the matched [`regex::Regex`] `pattern` and the primary one it's an
+    /// alias of (if any).
+    #[must_use]
+    pub fn with_pattern(
+        mut self,
+        pattern: HashableRegex,
+        alias_of: Option<HashableRegex>,
+    ) -> Self {
+        self.pattern = Some(pattern);
+        self.alias_of = alias_of;
+        self
+    }
+
+    /// Returns the [`regex::Regex`] pattern the step has matched (if known).
+    #[must_use]
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_ref().map(HashableRegex::as_str)
+    }
+
+    /// Returns the primary [`regex::Regex`] pattern of the step function, in
+    /// case the matched [`Context::pattern()`] is its alias.
+    #[must_use]
+    pub fn alias_of(&self) -> Option<&str> {
+        self.alias_of.as_ref().map(HashableRegex::as_str)
+    }
+
+    /// Returns a reference to the step.
+    #[must_use]
+    pub fn step(&self) -> &gherkin::Step {
+        &self.step
+    }
+
+    /// Returns a reference to the regex matches.
+    #[must_use]
+    pub fn matches(&self) -> &[(CaptureName, String)] {
+        &self.matches
+    }
+
+    /// Returns a reference to the structured [`Tags`] of the
+    /// [`gherkin::Scenario`] the step belongs to.
+    #[must_use]
+    pub const fn tags(&self) -> &Tags {
+        &self.tags
+    }
+
+    /// Returns the [`ScenarioMetadata`] of the [`gherkin::Scenario`] the step
+    /// belongs to (if known).
+    #[must_use]
+    pub const fn scenario(&self) -> Option<&ScenarioMetadata> {
+        self.scenario.as_ref()
+    }
+
+    /// Returns the [`ScenarioId`] of the [`gherkin::Scenario`] the step
+    /// belongs to (if known), to correlate the logs of concurrently running
+    /// [`gherkin::Scenario`]s.
+    #[must_use]
+    pub fn scenario_id(&self) -> Option<ScenarioId> {
+        self.scenario.as_ref()?.id
+    }
+
+    /// Returns the [`Ownership`] of the matched step function (if recorded).
+    #[must_use]
+    pub fn owner(&self) -> Option<&Ownership> {
+        self.owner.as_deref()
+    }
+}
+
+// Allows `#[step]` arguments of the step attribute macros to be either a
+// `gherkin::Step` or a whole `Context`.
+impl Borrow<gherkin::Step> for Context {
+    fn borrow(&self) -> &gherkin::Step {
+        &self.step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gherkin::{Step as GherkinStep, StepType};
+
+    use super::*;
+
+    fn create_test_step() -> GherkinStep {
+        GherkinStep {
+            keyword: "Given".to_string(),
+            ty: StepType::Given,
+            value: "I have 5 cucumbers".to_string(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        }
+    }
+
+    #[test]
+    fn context_new_creates_context_with_step_and_matches() {
+        let step = create_test_step();
+        let matches = vec![
+            (None, "I have 5 cucumbers".to_string()),
+            (Some("count".to_string()), "5".to_string()),
+        ];
+
+        let context = Context::new(step.clone(), matches.clone());
+        assert_eq!(context.step.value, step.value);
+        assert_eq!(context.matches, matches);
+    }
+
+    #[test]
+    fn context_step_returns_step_reference() {
+        let step = create_test_step();
+        let context = Context::new(step.clone(), vec![]);
+
+        assert_eq!(context.step().value, step.value);
+        assert_eq!(context.step().ty, step.ty);
+    }
+
+    #[test]
+    fn context_matches_returns_matches_reference() {
+        let step = create_test_step();
+        let matches = vec![
+            (None, "whole match".to_string()),
+            (Some("group1".to_string()), "value1".to_string()),
+        ];
+
+        let context = Context::new(step, matches.clone());
+        assert_eq!(context.matches(), &matches);
+    }
+
+    #[test]
+    fn context_clone_works() {
+        let step = create_test_step();
+        let matches = vec![
+            (None, "test".to_string()),
+            (Some("group".to_string()), "value".to_string()),
+        ];
+
+        let context = Context::new(step.clone(), matches.clone());
+        let cloned = context.clone();
+
+        assert_eq!(cloned.step.value, step.value);
+        assert_eq!(cloned.matches, matches);
+    }
+
+    #[test]
+    fn context_debug_format_works() {
+        let step = create_test_step();
+        let matches = vec![(None, "test".to_string())];
+
+        let context = Context::new(step, matches);
+        let debug_output = format!("{:?}", context);
+        assert!(debug_output.contains("Context"));
+        assert!(debug_output.contains("step"));
+        assert!(debug_output.contains("matches"));
+    }
+}
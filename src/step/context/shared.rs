@@ -0,0 +1,88 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Run-scoped state and custom CLI options shared between all the
+//! [`crate::step::Step`]s via a [`Context`].
+
+use std::{any::Any, sync::Arc};
+
+use super::Context;
+
+/// Type-erased state created once per run and shared between all the
+/// [`crate::step::Step`]s, unlike a `World` created per
+/// [`gherkin::Scenario`].
+pub type SharedState = Arc<dyn Any + Send + Sync>;
+
+impl Context {
+    /// Sets the [`SharedState`] of the run.
+    #[must_use]
+    pub fn with_shared(mut self, shared: Option<SharedState>) -> Self {
+        self.shared = shared;
+        self
+    }
+
+    /// Returns the [`SharedState`] of the run, if it's of type `T`.
+    ///
+    /// Returns [`None`] if no state has been provided via
+    /// [`Cucumber::shared_state()`], or it's of another type.
+    ///
+    /// [`Cucumber::shared_state()`]: crate::Cucumber::shared_state
+    #[must_use]
+    pub fn shared<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.shared.clone()?.downcast().ok()
+    }
+
+    /// Returns the [`Opts::custom`] CLI options of the run, if they're of type
+    /// `T`.
+    ///
+    /// Returns [`None`] if the [`Context`] has been created outside a run, or
+    /// the [`Opts::custom`] CLI options are of another type.
+    ///
+    /// See [`cli::custom()`] for accessing them outside a
+    /// [`crate::step::Step`] (like in a [`World::new()`]).
+    ///
+    /// [`cli::custom()`]: crate::cli::custom
+    /// [`Opts::custom`]: crate::cli::Opts::custom
+    /// [`World::new()`]: crate::World::new
+    #[must_use]
+    pub fn cli<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.cli.clone()?.downcast().ok()
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use std::sync::Arc;
+
+    use super::{Context, SharedState};
+
+    fn context() -> Context {
+        let step = gherkin::Step {
+            keyword: "Given".into(),
+            ty: gherkin::StepType::Given,
+            value: "a config".into(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        };
+        Context::new(step, vec![])
+    }
+
+    #[test]
+    fn downcasts_shared_state() {
+        let state: SharedState = Arc::new(String::from("config"));
+        let ctx = context().with_shared(Some(Arc::clone(&state)));
+
+        assert_eq!(ctx.shared::<String>().as_deref().unwrap(), "config");
+        assert!(ctx.shared::<u32>().is_none(), "wrong type");
+        assert!(context().shared::<String>().is_none(), "no state");
+    }
+}
@@ -0,0 +1,199 @@
+//! Automatic discovery of [`StepBuilder`]s across linked crates.
+//!
+//! This module provides the [`discover_step_builder!`] macro submitting a
+//! [`StepBuilder`] into a global [`inventory`], so its definitions are
+//! collected by [`Collection::discovered()`] (and so [`World::collection()`])
+//! without calling its [`StepBuilder::register_steps()`] manually.
+//!
+//! [`discover_step_builder!`]: crate::discover_step_builder
+//! [`World::collection()`]: crate::World::collection
+
+use std::{any::Any, fmt, mem};
+
+use super::{Collection, StepBuilder};
+
+/// [`StepBuilder`] submitted into a global [`inventory`] via the
+/// [`discover_step_builder!`] macro.
+///
+/// [`discover_step_builder!`]: crate::discover_step_builder
+#[derive(Clone, Copy)]
+pub struct Discovered {
+    /// Returns the [`StepBuilder::domain_name()`].
+    domain_name: fn() -> &'static str,
+
+    /// Registers the [`StepBuilder`] into the provided [`Collection`], if it's
+    /// one of the [`StepBuilder`]'s `World`.
+    register: fn(&mut dyn Any),
+}
+
+impl Discovered {
+    /// Creates a new [`Discovered`] [`StepBuilder`] of the provided `World`.
+    #[must_use]
+    pub const fn new<World, B>() -> Self
+    where
+        World: 'static,
+        B: StepBuilder<World>,
+    {
+        Self { domain_name: B::domain_name, register: register::<World, B> }
+    }
+
+    /// Returns the human-readable name of this [`StepBuilder`]'s domain.
+    #[must_use]
+    pub fn domain_name(&self) -> &'static str {
+        (self.domain_name)()
+    }
+}
+
+// Implemented manually to print the `register` function as a pointer.
+impl fmt::Debug for Discovered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Discovered")
+            .field("domain_name", &self.domain_name())
+            .field("register", &format_args!("{:p}", self.register))
+            .finish()
+    }
+}
+
+inventory::collect!(Discovered);
+
+/// Registers the [`StepBuilder`] into the provided `collection` (recording
+/// its [domain](StepBuilder::domain_name()) on its definitions), if it's a
+/// [`Collection`] of the provided `World`.
+fn register<World: 'static, B: StepBuilder<World>>(collection: &mut dyn Any) {
+    if let Some(c) = collection.downcast_mut::<Collection<World>>() {
+        let steps = B::register_steps(Collection::new());
+        *c = mem::take(c).merge(steps.in_domain(B::domain_name()));
+    }
+}
+
+impl<World: 'static> Collection<World> {
+    /// Returns a [`Collection`] of all the [`StepBuilder`]s of this `World`
+    /// submitted via the [`discover_step_builder!`] macro in any linked crate.
+    ///
+    /// [`discover_step_builder!`]: crate::discover_step_builder
+    #[must_use]
+    pub fn discovered() -> Self {
+        let mut collection = Self::new();
+        for builder in inventory::iter::<Discovered> {
+            (builder.register)(&mut collection);
+        }
+        collection
+    }
+}
+
+/// Submits a [`StepBuilder`] of the provided `World` to be discovered by the
+/// [`Collection::discovered()`] (and so [`World::collection()`]).
+///
+/// The crate invoking this macro must be linked into the test binary, so
+/// referring to any of its items is required.
+///
+/// # Example
+///
+/// ```rust
+/// use cucumber::{
+///     World,
+///     discover_step_builder,
+///     step::{Collection, Context, StepBuilder},
+/// };
+/// use futures::future::LocalBoxFuture;
+/// use regex::Regex;
+///
+/// #[derive(Debug, Default, World)]
+/// struct TestWorld;
+///
+/// fn test_step(
+///     _world: &mut TestWorld,
+///     _ctx: Context,
+/// ) -> LocalBoxFuture<'_, ()> {
+///     Box::pin(async {})
+/// }
+///
+/// struct AuditSteps;
+///
+/// impl StepBuilder<TestWorld> for AuditSteps {
+///     fn register_steps(
+///         collection: Collection<TestWorld>,
+///     ) -> Collection<TestWorld> {
+///         collection.then(
+///             None,
+///             Regex::new("^it's audited$").unwrap(),
+///             test_step,
+///         )
+///     }
+///
+///     fn domain_name() -> &'static str {
+///         "Audit"
+///     }
+/// }
+///
+/// discover_step_builder!(TestWorld, AuditSteps);
+///
+/// assert_eq!(TestWorld::collection().then_len(), 1);
+/// ```
+///
+/// [`World::collection()`]: crate::World::collection
+#[macro_export]
+macro_rules! discover_step_builder {
+    ($world:ty, $builder:ty $(,)?) => {
+        $crate::codegen::submit! {
+            $crate::step::discovery::Discovered::new::<$world, $builder>()
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::LocalBoxFuture;
+    use regex::Regex;
+
+    use super::*;
+    use crate::step::Context;
+
+    struct TestWorld;
+
+    struct OtherWorld;
+
+    fn test_step(
+        _world: &mut TestWorld,
+        _ctx: Context,
+    ) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    struct HealthSteps;
+
+    impl StepBuilder<TestWorld> for HealthSteps {
+        fn register_steps(
+            collection: Collection<TestWorld>,
+        ) -> Collection<TestWorld> {
+            collection.given(
+                None,
+                Regex::new("^the service is up$").unwrap(),
+                test_step,
+            )
+        }
+
+        fn domain_name() -> &'static str {
+            "Health"
+        }
+    }
+
+    crate::discover_step_builder!(TestWorld, HealthSteps);
+
+    #[test]
+    fn discovers_builders_of_the_world_only() {
+        assert_eq!(Collection::<TestWorld>::discovered().given_len(), 1);
+        assert_eq!(Collection::<OtherWorld>::discovered().given_len(), 0);
+        assert!(
+            Collection::<TestWorld>::discovered()
+                .definitions()
+                .all(|d| d.domain == Some("Health")),
+        );
+
+        assert!(
+            inventory::iter::<Discovered>
+                .into_iter()
+                .any(|d| d.domain_name() == "Health"),
+        );
+    }
+}
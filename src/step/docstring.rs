@@ -0,0 +1,256 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [Doc strings][1] of [`gherkin::Step`]s with their media types.
+//!
+//! [1]: https://cucumber.io/docs/gherkin/reference#doc-strings
+
+#[cfg(any(
+    feature = "docstrings-json",
+    feature = "docstrings-toml",
+    feature = "docstrings-yaml",
+))]
+use std::fmt;
+
+use derive_more::with_trait::{Display, Error};
+#[cfg(any(
+    feature = "docstrings-json",
+    feature = "docstrings-toml",
+    feature = "docstrings-yaml",
+))]
+use serde::de::DeserializeOwned;
+
+/// [Doc string][1] of a [`gherkin::Step`], split into its media type and
+/// content.
+///
+/// ```gherkin
+/// Given a payload:
+///   """json
+///   {"id": 42}
+///   """
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::step::DocString;
+/// #
+/// let doc = DocString::parse("json\n  {\"id\": 42}\n  ");
+///
+/// assert_eq!(doc.media_type(), Some("json"));
+/// assert_eq!(doc.content(), "{\"id\": 42}");
+/// ```
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#doc-strings
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocString {
+    /// Media type of this [`DocString`] (like `json`), if specified.
+    media_type: Option<String>,
+
+    /// Content of this [`DocString`].
+    content: String,
+}
+
+impl DocString {
+    /// Parses a raw [`gherkin::Step::docstring`], which starts with the rest
+    /// of the line opening it (holding its media type, if any).
+    #[must_use]
+    pub fn parse(raw: &str) -> Self {
+        let (first, rest) = raw.split_once('\n').unwrap_or((raw, ""));
+        let first = first.trim();
+        let media_type = (!first.is_empty()).then(|| first.to_owned());
+
+        let lines = rest.trim_end().lines().collect::<Vec<_>>();
+        let indent = lines
+            .iter()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.len() - l.trim_start().len())
+            .min()
+            .unwrap_or_default();
+        let content = lines
+            .iter()
+            .map(|l| l.get(indent..).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Self { media_type, content }
+    }
+
+    /// Returns the media type of this [`DocString`] (like `json`), if
+    /// specified.
+    #[must_use]
+    pub fn media_type(&self) -> Option<&str> {
+        self.media_type.as_deref()
+    }
+
+    /// Returns the content of this [`DocString`].
+    #[must_use]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Deserializes the content of this [`DocString`] according to its media
+    /// type: JSON (`docstrings-json` feature), YAML (`docstrings-yaml`
+    /// feature) or TOML (`docstrings-toml` feature).
+    ///
+    /// JSON is assumed if no media type is specified.
+    ///
+    /// # Errors
+    ///
+    /// If the media type isn't supported, or the content cannot be
+    /// deserialized.
+    #[cfg(any(
+        feature = "docstrings-json",
+        feature = "docstrings-toml",
+        feature = "docstrings-yaml",
+    ))]
+    pub fn deserialize<T: DeserializeOwned>(
+        &self,
+    ) -> Result<T, DocStringError> {
+        let content = self.content.as_str();
+        match self.media_type() {
+            #[cfg(feature = "docstrings-json")]
+            None | Some("json" | "application/json") => {
+                serde_json::from_str(content).map_err(|e| invalid("json", &e))
+            }
+            #[cfg(feature = "docstrings-yaml")]
+            Some("yaml" | "yml" | "application/yaml") => {
+                serde_yaml::from_str(content).map_err(|e| invalid("yaml", &e))
+            }
+            #[cfg(feature = "docstrings-toml")]
+            Some("toml" | "application/toml") => {
+                toml::from_str(content).map_err(|e| invalid("toml", &e))
+            }
+            other => Err(DocStringError::Unsupported(
+                other.unwrap_or_default().into(),
+            )),
+        }
+    }
+}
+
+/// Creates a [`DocStringError::Invalid`] of the provided `media_type` out of
+/// the provided deserialization error.
+#[cfg(any(
+    feature = "docstrings-json",
+    feature = "docstrings-toml",
+    feature = "docstrings-yaml",
+))]
+fn invalid(media_type: &str, e: &impl fmt::Display) -> DocStringError {
+    DocStringError::Invalid {
+        media_type: media_type.into(),
+        message: e.to_string(),
+    }
+}
+
+/// Error of deserializing a [`DocString`] of a [`gherkin::Step`].
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum DocStringError {
+    /// [`gherkin::Step`] has no [`DocString`].
+    #[display("step has no doc string")]
+    Missing,
+
+    /// Media type of the [`DocString`] isn't supported.
+    #[display("unsupported doc string media type `{_0}`")]
+    Unsupported(#[error(not(source))] String),
+
+    /// Content of the [`DocString`] cannot be deserialized.
+    #[display("invalid `{media_type}` doc string: {message}")]
+    Invalid {
+        /// Media type the content was deserialized as.
+        media_type: String,
+
+        /// Description of the failure.
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_media_type_and_content() {
+        let doc = DocString::parse("\n    line 1\n      line 2\n    ");
+        assert_eq!(doc.media_type(), None);
+        assert_eq!(doc.content(), "line 1\n  line 2");
+
+        let doc = DocString::parse(" yaml \n  a: 1\n  b:\n    - 2\n");
+        assert_eq!(doc.media_type(), Some("yaml"));
+        assert_eq!(doc.content(), "a: 1\nb:\n  - 2");
+    }
+
+    #[cfg(feature = "docstrings-json")]
+    #[test]
+    fn deserializes_json() {
+        let doc = DocString::parse("json\n  {\"id\": 42}\n");
+        assert_eq!(doc.deserialize::<serde_json::Value>().unwrap()["id"], 42);
+
+        let doc = DocString::parse("\n{\"id\": 42}\n");
+        assert_eq!(doc.deserialize::<serde_json::Value>().unwrap()["id"], 42);
+
+        let err = DocString::parse("\n{\"id\": }\n")
+            .deserialize::<serde_json::Value>()
+            .unwrap_err();
+        assert!(
+            err.to_string().starts_with("invalid `json` doc string: "),
+            "{err}",
+        );
+    }
+
+    #[cfg(feature = "docstrings-yaml")]
+    #[test]
+    fn deserializes_yaml() {
+        let doc = DocString::parse("yaml\n  id: 42\n  items:\n    - a\n");
+        let value = doc.deserialize::<serde_yaml::Value>().unwrap();
+        assert_eq!(value["id"], 42);
+        assert_eq!(value["items"][0], "a");
+
+        let doc = DocString::parse("yml\nid: 42\n");
+        assert_eq!(doc.deserialize::<serde_yaml::Value>().unwrap()["id"], 42);
+
+        let err = DocString::parse("yaml\nid: [42\n")
+            .deserialize::<serde_yaml::Value>()
+            .unwrap_err();
+        assert!(
+            err.to_string().starts_with("invalid `yaml` doc string: "),
+            "{err}",
+        );
+    }
+
+    #[cfg(feature = "docstrings-toml")]
+    #[test]
+    fn deserializes_toml() {
+        let doc = DocString::parse("toml\n  id = 42\n  items = [\"a\"]\n");
+        let value = doc.deserialize::<toml::Table>().unwrap();
+        assert_eq!(value["id"].as_integer(), Some(42));
+        assert_eq!(value["items"][0].as_str(), Some("a"));
+
+        let err = DocString::parse("toml\nid = \n")
+            .deserialize::<toml::Table>()
+            .unwrap_err();
+        assert!(
+            err.to_string().starts_with("invalid `toml` doc string: "),
+            "{err}",
+        );
+    }
+
+    #[cfg(any(
+        feature = "docstrings-json",
+        feature = "docstrings-toml",
+        feature = "docstrings-yaml",
+    ))]
+    #[test]
+    fn rejects_unsupported_media_type() {
+        let err = DocString::parse("xml\n<id>42</id>\n")
+            .deserialize::<String>()
+            .unwrap_err();
+        assert_eq!(err, DocStringError::Unsupported("xml".into()));
+    }
+}
@@ -0,0 +1,199 @@
+//! Error types for step matching and execution.
+//!
+//! This module provides error types that can occur during step matching,
+//! such as when a step matches multiple regex patterns, or when its capture
+//! group cannot be parsed into a typed argument of a step function.
+
+#[cfg(test)]
+mod tests;
+
+use std::{fmt, sync::Arc};
+
+use derive_more::with_trait::{Display, Error};
+use itertools::Itertools as _;
+
+use super::{
+    context::{CaptureName, format_capture_values},
+    location::Location,
+    regex::HashableRegex,
+};
+
+/// Error of a [`gherkin::Step`] matching multiple [`crate::step::Step`] [`regex::Regex`]es inside a
+/// [`Collection`].
+///
+/// [`Collection`]: super::Collection
+#[derive(Clone, Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AmbiguousMatchError {
+    /// Possible [`regex::Regex`]es the [`gherkin::Step`] matches.
+    pub possible_matches: Vec<(HashableRegex, Option<Location>)>,
+
+    /// [`MatchDetails`] of the [`AmbiguousMatchError::possible_matches`], in
+    /// the same order (empty, if unknown).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub details: Vec<MatchDetails>,
+}
+
+/// Details of a possible match of an [`AmbiguousMatchError`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MatchDetails {
+    /// Values of the capture groups (the whole match excluded), along with
+    /// their names (if any).
+    pub captures: Vec<(CaptureName, String)>,
+
+    /// Name of the [`StepBuilder`] domain the matching definition is
+    /// registered by (if recorded).
+    ///
+    /// [`StepBuilder`]: super::StepBuilder
+    pub domain: Option<Arc<str>>,
+}
+
+impl AmbiguousMatchError {
+    /// Creates a new [`AmbiguousMatchError`] with the given possible matches.
+    #[must_use]
+    pub fn new(
+        possible_matches: Vec<(HashableRegex, Option<Location>)>,
+    ) -> Self {
+        Self { possible_matches, details: Vec::new() }
+    }
+
+    /// Sets the [`MatchDetails`] of the possible matches, in the same order.
+    #[must_use]
+    pub fn with_details(mut self, details: Vec<MatchDetails>) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Returns a reference to the possible matches.
+    #[must_use]
+    pub fn possible_matches(&self) -> &[(HashableRegex, Option<Location>)] {
+        &self.possible_matches
+    }
+
+    /// Returns a reference to the [`MatchDetails`] of the possible matches.
+    #[must_use]
+    pub fn details(&self) -> &[MatchDetails] {
+        &self.details
+    }
+
+    /// Returns the number of possible matches.
+    #[must_use]
+    pub fn match_count(&self) -> usize {
+        self.possible_matches.len()
+    }
+
+    /// Returns an iterator over the regex patterns that matched.
+    pub fn patterns(&self) -> impl Iterator<Item = &str> + '_ {
+        self.possible_matches.iter().map(|(regex, _)| regex.as_str())
+    }
+
+    /// Returns an iterator over the locations of the matching steps.
+    pub fn locations(&self) -> impl Iterator<Item = Option<&Location>> + '_ {
+        self.possible_matches.iter().map(|(_, loc)| loc.as_ref())
+    }
+
+    /// Returns a sorted copy of the possible matches.
+    #[must_use]
+    pub fn sorted_matches(&self) -> Vec<(HashableRegex, Option<Location>)> {
+        self.possible_matches.iter().cloned().sorted().collect()
+    }
+
+    /// Returns a hint on resolving this [`AmbiguousMatchError`] (if there are
+    /// any possible matches).
+    ///
+    /// Suggests anchoring the first pattern missing `^` or `$` anchors, or
+    /// tightening the least specific one (having the fewest literal
+    /// characters) otherwise.
+    #[must_use]
+    pub fn hint(&self) -> Option<String> {
+        if let Some(p) =
+            self.patterns().find(|p| !p.starts_with('^') || !p.ends_with('$'))
+        {
+            return Some(format!(
+                "tighten pattern `{p}` or add an anchor (`^`/`$`) to it, so \
+                 it doesn't match a part of other steps",
+            ));
+        }
+        self.patterns().min_by_key(|p| literal_len(p)).map(|p| {
+            format!(
+                "tighten pattern `{p}` (the least specific one), or give one \
+                 of the matching step functions a higher priority",
+            )
+        })
+    }
+}
+
+/// Counts the literal characters of the provided [`regex::Regex`] `pattern`,
+/// so the less of them it has, the more steps it may match.
+fn literal_len(pattern: &str) -> usize {
+    let mut chars = pattern.chars();
+    let mut len = 0;
+    while let Some(c) = chars.next() {
+        len += match c {
+            '\\' => {
+                chars.next().map_or(0, |e| usize::from(!e.is_alphanumeric()))
+            }
+            '.' | '^' | '$' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{'
+            | '}' | '|' => 0,
+            _ => 1,
+        };
+    }
+    len
+}
+
+impl fmt::Display for AmbiguousMatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Possible matches:")?;
+        for (i, (reg, loc_opt)) in self.possible_matches.iter().enumerate() {
+            write!(f, "\n{reg}")?;
+            if let Some(loc) = loc_opt {
+                write!(f, " --> {loc}")?;
+            }
+            let details = self.details.get(i);
+            if let Some(domain) = details.and_then(|d| d.domain.as_ref()) {
+                write!(f, " (domain: {domain})")?;
+            }
+            if let Some(d) = details.filter(|d| !d.captures.is_empty()) {
+                write!(
+                    f,
+                    "\n    captures: {}",
+                    format_capture_values(&d.captures),
+                )?;
+            }
+        }
+        if let Some(hint) = self.hint() {
+            write!(f, "\nHint: {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error of a capture group not matching a typed argument of a
+/// [`crate::step::Step`] function.
+///
+/// A [`crate::step::Step`] function panicking with this error (as the code
+/// generated by [`given`], [`when`] and [`then`] macros does) fails with an
+/// [`event::StepError::ArgMismatch`] instead of a regular panic.
+///
+/// [`event::StepError::ArgMismatch`]: crate::event::StepError::ArgMismatch
+/// [`given`]: crate::given
+/// [`then`]: crate::then
+/// [`when`]: crate::when
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+#[display("cannot parse `{value}` as `{ty}` for `{name}` argument: {reason}")]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ArgError {
+    /// Name of the argument (or index of its capture group).
+    pub name: String,
+
+    /// Type of the argument.
+    pub ty: String,
+
+    /// Captured value failed to be parsed.
+    pub value: String,
+
+    /// Reason of the failure.
+    #[error(not(source))]
+    pub reason: String,
+}
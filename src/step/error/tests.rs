@@ -0,0 +1,229 @@
+//! Tests of step matching errors.
+
+use regex::Regex;
+
+use super::*;
+
+fn create_test_matches() -> Vec<(HashableRegex, Option<Location>)> {
+    vec![
+        (
+            HashableRegex::from(Regex::new(r"I have (\d+) cucumbers").unwrap()),
+            Some(Location::new("src/steps.rs", 10, 5)),
+        ),
+        (
+            HashableRegex::from(Regex::new(r"I have \d+ cucumbers").unwrap()),
+            Some(Location::new("src/more_steps.rs", 20, 10)),
+        ),
+        (
+            HashableRegex::from(Regex::new(r"I have .+ cucumbers").unwrap()),
+            None,
+        ),
+    ]
+}
+
+#[test]
+fn ambiguous_match_error_new_creates_error() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches.clone());
+    assert_eq!(error.possible_matches.len(), matches.len());
+    assert_eq!(error.possible_matches, matches);
+}
+
+#[test]
+fn ambiguous_match_error_possible_matches_returns_reference() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches.clone());
+    assert_eq!(error.possible_matches(), &matches);
+}
+
+#[test]
+fn ambiguous_match_error_match_count_returns_correct_count() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches);
+    assert_eq!(error.match_count(), 3);
+}
+
+#[test]
+fn ambiguous_match_error_patterns_returns_regex_patterns() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches);
+
+    let patterns: Vec<&str> = error.patterns().collect();
+    assert_eq!(patterns.len(), 3);
+    assert!(patterns.contains(&r"I have (\d+) cucumbers"));
+    assert!(patterns.contains(&r"I have \d+ cucumbers"));
+    assert!(patterns.contains(&r"I have .+ cucumbers"));
+}
+
+#[test]
+fn ambiguous_match_error_locations_returns_locations() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches);
+
+    let locations: Vec<Option<&Location>> = error.locations().collect();
+    assert_eq!(locations.len(), 3);
+
+    // First two should have locations, third should be None
+    assert!(locations[0].is_some());
+    assert!(locations[1].is_some());
+    assert!(locations[2].is_none());
+
+    if let Some(loc) = locations[0] {
+        assert_eq!(loc.path(), "src/steps.rs");
+        assert_eq!(loc.line(), 10);
+    }
+
+    if let Some(loc) = locations[1] {
+        assert_eq!(loc.path(), "src/more_steps.rs");
+        assert_eq!(loc.line(), 20);
+    }
+}
+
+#[test]
+fn ambiguous_match_error_sorted_matches_returns_sorted_copy() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches);
+
+    let sorted = error.sorted_matches();
+    assert_eq!(sorted.len(), 3);
+
+    // Should be sorted by regex pattern (alphabetically)
+    let patterns: Vec<&str> = sorted.iter().map(|(r, _)| r.as_str()).collect();
+    assert!(patterns[0] < patterns[1]);
+    assert!(patterns[1] < patterns[2]);
+}
+
+#[test]
+fn ambiguous_match_error_display_works() {
+    let matches = vec![
+        (
+            HashableRegex::from(Regex::new(r"pattern1").unwrap()),
+            Some(Location::new("src/test.rs", 10, 5)),
+        ),
+        (HashableRegex::from(Regex::new(r"pattern2").unwrap()), None),
+    ];
+
+    let error = AmbiguousMatchError::new(matches);
+    let display_output = format!("{}", error);
+
+    assert!(display_output.contains("Possible matches:"));
+    assert!(display_output.contains("pattern1"));
+    assert!(display_output.contains("pattern2"));
+    assert!(display_output.contains("src/test.rs:10:5"));
+}
+
+#[test]
+fn ambiguous_match_error_display_without_location() {
+    let matches =
+        vec![(HashableRegex::from(Regex::new(r"pattern").unwrap()), None)];
+
+    let error = AmbiguousMatchError::new(matches);
+    let display_output = format!("{}", error);
+
+    assert!(display_output.contains("Possible matches:"));
+    assert!(display_output.contains("pattern"));
+    // Should not contain " --> " when no location
+    assert!(!display_output.contains(" --> "));
+}
+
+#[test]
+fn ambiguous_match_error_display_with_details() {
+    let error = AmbiguousMatchError::new(vec![
+        (
+            HashableRegex::from(Regex::new(r"^I have (\d+) items$").unwrap()),
+            Some(Location::new("src/cart.rs", 10, 1)),
+        ),
+        (
+            HashableRegex::from(
+                Regex::new(r"^I have (?<n>\d+) (\w+)$").unwrap(),
+            ),
+            None,
+        ),
+    ])
+    .with_details(vec![
+        MatchDetails {
+            captures: vec![(None, "5".into())],
+            domain: Some("Cart".into()),
+        },
+        MatchDetails {
+            captures: vec![
+                (Some("n".into()), "5".into()),
+                (None, "items".into()),
+            ],
+            domain: None,
+        },
+    ]);
+
+    assert_eq!(
+        error.to_string(),
+        "Possible matches:\n\
+         ^I have (\\d+) items$ --> src/cart.rs:10:1 (domain: Cart)\n    \
+         captures: $1 = \"5\"\n\
+         ^I have (?<n>\\d+) (\\w+)$\n    \
+         captures: n = \"5\", $2 = \"items\"\n\
+         Hint: tighten pattern `^I have (?<n>\\d+) (\\w+)$` (the least \
+         specific one), or give one of the matching step functions a \
+         higher priority",
+    );
+}
+
+#[test]
+fn ambiguous_match_error_hints_anchoring() {
+    let error = AmbiguousMatchError::new(vec![
+        (HashableRegex::from(Regex::new(r"^a user$").unwrap()), None),
+        (HashableRegex::from(Regex::new(r"a user").unwrap()), None),
+    ]);
+
+    assert_eq!(
+        error.hint().as_deref(),
+        Some(
+            "tighten pattern `a user` or add an anchor (`^`/`$`) to it, \
+             so it doesn't match a part of other steps",
+        ),
+    );
+    assert!(AmbiguousMatchError::new(vec![]).hint().is_none());
+}
+
+#[test]
+fn literal_len_ignores_meta_characters() {
+    assert_eq!(literal_len(r"^a (\d+) user$"), 7);
+    assert_eq!(literal_len(r"^a \(.+\) user$"), 9);
+}
+
+#[test]
+fn ambiguous_match_error_clone_works() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches.clone());
+    let cloned = error.clone();
+
+    assert_eq!(cloned.possible_matches, matches);
+    assert_eq!(cloned.match_count(), error.match_count());
+}
+
+#[test]
+fn ambiguous_match_error_debug_works() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches);
+    let debug_output = format!("{:?}", error);
+
+    assert!(debug_output.contains("AmbiguousMatchError"));
+    assert!(debug_output.contains("possible_matches"));
+}
+
+#[test]
+fn ambiguous_match_error_empty_matches() {
+    let error = AmbiguousMatchError::new(vec![]);
+    assert_eq!(error.match_count(), 0);
+    assert!(error.patterns().collect::<Vec<_>>().is_empty());
+    assert!(error.locations().collect::<Vec<_>>().is_empty());
+    assert!(error.sorted_matches().is_empty());
+}
+
+#[test]
+fn ambiguous_match_error_is_error_trait() {
+    let matches = create_test_matches();
+    let error = AmbiguousMatchError::new(matches);
+
+    // Should implement std::error::Error
+    let _: &dyn std::error::Error = &error;
+}
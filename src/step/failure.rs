@@ -0,0 +1,98 @@
+//! Failure of a step function returning an error.
+//!
+//! This module provides the [`Failure`] type that an [`Err`] returned from a
+//! step function is turned into, so fallible code can use `?` operator
+//! instead of unwrapping everything.
+
+use std::{fmt, panic};
+
+use derive_more::with_trait::{Display, Error};
+
+/// Error returned from a [`crate::step::Step`] function.
+///
+/// [`given`], [`when`] and [`then`] macros raise it for an [`Err`] returned
+/// from a step function, failing the step with an
+/// [`event::StepError::Returned`] rather than a regular panic. Step functions
+/// registered in a [`Collection`] directly may use [`Failure::check()`].
+///
+/// ```rust
+/// # use std::num::ParseIntError;
+/// #
+/// # use cucumber::{step, World};
+/// # use futures::FutureExt as _;
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld(u32);
+/// #
+/// fn cucumbers(w: &mut MyWorld, count: &str) -> Result<(), ParseIntError> {
+///     w.0 = count.parse()?;
+///     Ok(())
+/// }
+///
+/// let steps = step::Collection::<MyWorld>::new().given(
+///     None,
+///     "^I have (\\S+) cucumbers$".parse().unwrap(),
+///     |w, ctx| {
+///         async move {
+///             step::Failure::check(cucumbers(w, &ctx.matches[1].1));
+///         }
+///         .boxed_local()
+///     },
+/// );
+/// ```
+///
+/// [`Collection`]: crate::step::Collection
+/// [`event::StepError::Returned`]: crate::event::StepError::Returned
+/// [`given`]: crate::given
+/// [`then`]: crate::then
+/// [`when`]: crate::when
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+#[display("{_0}")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct Failure(#[error(not(source))] pub String);
+
+impl Failure {
+    /// Fails the current [`crate::step::Step`] with the provided `error`.
+    ///
+    /// # Panics
+    ///
+    /// Always, with a [`Failure`] payload.
+    pub fn raise(error: impl fmt::Display) -> ! {
+        panic::panic_any(Self(error.to_string()))
+    }
+
+    /// Unwraps the provided [`Result`] of a [`crate::step::Step`] function,
+    /// failing the [`crate::step::Step`] on an [`Err`].
+    ///
+    /// # Panics
+    ///
+    /// If the provided `result` is an [`Err`], with a [`Failure`] payload.
+    pub fn check<T, E: fmt::Display>(result: Result<T, E>) -> T {
+        result.unwrap_or_else(|e| Self::raise(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::*;
+
+    #[test]
+    fn raises_displayed_error() {
+        let payload = panic::catch_unwind(AssertUnwindSafe(|| {
+            Failure::check("five".parse::<u32>())
+        }))
+        .unwrap_err();
+
+        assert_eq!(
+            payload.downcast_ref::<Failure>().map(ToString::to_string),
+            Some("invalid digit found in string".into()),
+        );
+        assert_eq!(Failure::check("5".parse::<u32>()), 5);
+    }
+}
@@ -0,0 +1,232 @@
+//! Test impact analysis based on changed [`crate::step::Step`] definitions.
+//!
+//! This module provides the [`ChangedFiles`] set, which maps a list of changed
+//! source files (or a `git diff` output) onto [`Location`]s of the registered
+//! [`crate::step::Step`]s, allowing to run only the [`gherkin::Scenario`]s
+//! actually affected by the changes.
+
+mod path;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::HashSet,
+    fs, io, mem,
+    path::{Path, PathBuf},
+};
+
+use self::path::{diff_path, normalize, package_root, repository_root};
+use super::{collection::Collection, location::Location};
+use crate::tag::Tags;
+
+/// Set of changed files used to determine which [`gherkin::Scenario`]s are
+/// affected by a change.
+///
+/// A [`gherkin::Scenario`] is considered affected if:
+/// - its `.feature` file has been changed;
+/// - any of its [`gherkin::Step`]s (including [`Background`] ones) matches a
+///   [`crate::step::Step`] defined in a changed file;
+/// - any of its [`gherkin::Step`]s cannot be mapped onto a file at all (it
+///   doesn't match any [`crate::step::Step`], matches ambiguously, or matches
+///   a [`crate::step::Step`] without a [`Location`]), so the analysis stays
+///   conservative and never hides a potential failure.
+///
+/// [`Background`]: gherkin::Background
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChangedFiles {
+    /// Paths of the changed files, as they were provided.
+    raw: Vec<PathBuf>,
+
+    /// Normalized paths of the changed files, relative to the [`root`].
+    ///
+    /// [`root`]: ChangedFiles::root
+    paths: HashSet<PathBuf>,
+
+    /// Root of the repository the changed files are relative to.
+    root: PathBuf,
+
+    /// Root of the package being run, relative to the [`root`].
+    ///
+    /// Relative paths of [`Location`]s (as [`file!()`] produces them) are
+    /// resolved against it.
+    ///
+    /// [`root`]: ChangedFiles::root
+    package: PathBuf,
+}
+
+impl ChangedFiles {
+    /// Creates a new [`ChangedFiles`] set out of the provided `paths`,
+    /// relative to the root of the repository the current directory belongs
+    /// to.
+    #[must_use]
+    pub fn new<P: Into<PathBuf>>(paths: impl IntoIterator<Item = P>) -> Self {
+        let root = repository_root();
+        let package = normalize(&package_root(), &root);
+        let raw = paths.into_iter().map(Into::into).collect();
+        Self { raw, paths: HashSet::new(), root, package }.normalized()
+    }
+
+    /// Treats the changed files as relative to the provided `root` directory,
+    /// instead of the detected repository root.
+    #[must_use]
+    pub fn with_root(self, root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        let package = normalize(&self.root.join(&self.package), &root);
+        Self { root, package, ..self }.normalized()
+    }
+
+    /// Resolves relative [`Location`]s against the provided `package`
+    /// directory, instead of the detected `CARGO_MANIFEST_DIR`.
+    #[must_use]
+    pub fn with_package_root(self, package: impl AsRef<Path>) -> Self {
+        let package = normalize(&self.root.join(package), &self.root);
+        Self { package, ..self }
+    }
+
+    /// Normalizes the [`raw`] paths against the current [`root`].
+    ///
+    /// [`raw`]: ChangedFiles::raw
+    /// [`root`]: ChangedFiles::root
+    fn normalized(mut self) -> Self {
+        self.paths =
+            self.raw.iter().map(|p| normalize(p, &self.root)).collect();
+        self
+    }
+
+    /// Parses [`ChangedFiles`] either from a newline-separated list of paths
+    /// (as `git diff --name-only` outputs), or from a unified `git diff`
+    /// output.
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        let is_diff = input
+            .lines()
+            .any(|l| l.starts_with("diff --git ") || l.starts_with("+++ "));
+        if !is_diff {
+            return Self::new(
+                input.lines().map(str::trim).filter(|l| !l.is_empty()),
+            );
+        }
+
+        Self::new(input.lines().flat_map(|l| {
+            let mut found = Vec::with_capacity(2);
+            if let Some(rest) = l.strip_prefix("diff --git ") {
+                if let Some((a, b)) = rest.split_once(" b/") {
+                    found.extend(diff_path(a));
+                    found.extend(diff_path(b));
+                }
+            } else if let Some(p) =
+                l.strip_prefix("+++ ").or_else(|| l.strip_prefix("--- "))
+            {
+                found.extend(diff_path(p));
+            }
+            found
+        }))
+    }
+
+    /// Reads [`ChangedFiles`] from the file at the provided `path`.
+    ///
+    /// See [`ChangedFiles::parse()`] for the supported formats.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        fs::read_to_string(path).map(|s| Self::parse(&s))
+    }
+
+    /// Returns paths of the changed files.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.paths.iter().map(PathBuf::as_path)
+    }
+
+    /// Indicates whether this [`ChangedFiles`] set is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Indicates whether the provided `path` is one of the changed files.
+    ///
+    /// Paths are compared after being normalized, so absolute paths inside
+    /// the repository match repository relative ones. Relative paths are
+    /// resolved against the package root, but are also matched as repository
+    /// relative ones, so the analysis stays conservative whichever of them
+    /// [`file!()`] is relative to.
+    #[must_use]
+    pub fn contains(&self, path: &Path) -> bool {
+        let in_package = path.is_relative().then(|| {
+            normalize(&self.root.join(&self.package).join(path), &self.root)
+        });
+        in_package
+            .into_iter()
+            .chain([normalize(path, &self.root)])
+            .any(|p| self.paths.contains(&p))
+    }
+
+    /// Indicates whether the [`crate::step::Step`] defined at the provided
+    /// [`Location`] is affected by the changes.
+    #[must_use]
+    pub fn is_location_affected(&self, loc: Option<Location>) -> bool {
+        loc.is_none_or(|l| self.contains(Path::new(l.path)))
+    }
+
+    /// Indicates whether the provided [`gherkin::Scenario`] is affected by the
+    /// changes, using the provided [`Collection`] for mapping its
+    /// [`gherkin::Step`]s onto [`crate::step::Step`] definitions.
+    #[must_use]
+    pub fn is_scenario_affected<W>(
+        &self,
+        collection: &Collection<W>,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> bool {
+        if feature.path.as_deref().is_some_and(|p| self.contains(p)) {
+            return true;
+        }
+
+        let tags = Tags::of(feature, rule, scenario);
+        feature
+            .background
+            .iter()
+            .chain(rule.and_then(|r| r.background.as_ref()))
+            .flat_map(|bg| &bg.steps)
+            .chain(&scenario.steps)
+            .any(|step| match collection.find_tagged(step, &tags) {
+                Ok(Some((_, _, loc, _))) => self.is_location_affected(loc),
+                Ok(None) | Err(_) => true,
+            })
+    }
+
+    /// Retains in the provided [`gherkin::Feature`] only the
+    /// [`gherkin::Scenario`]s affected by the changes.
+    ///
+    /// See [`ChangedFiles::is_scenario_affected()`] for details.
+    #[must_use]
+    pub fn retain_affected<W>(
+        &self,
+        collection: &Collection<W>,
+        mut feature: gherkin::Feature,
+    ) -> gherkin::Feature {
+        let scenarios = mem::take(&mut feature.scenarios);
+        feature.scenarios = scenarios
+            .into_iter()
+            .filter(|s| {
+                self.is_scenario_affected(collection, &feature, None, s)
+            })
+            .collect();
+
+        let mut rules = mem::take(&mut feature.rules);
+        for r in &mut rules {
+            r.scenarios = mem::take(&mut r.scenarios)
+                .into_iter()
+                .filter(|s| {
+                    self.is_scenario_affected(collection, &feature, Some(r), s)
+                })
+                .collect();
+        }
+        feature.rules = rules;
+
+        feature
+    }
+}
@@ -0,0 +1,95 @@
+//! Normalization of the [`ChangedFiles`] paths.
+//!
+//! [`ChangedFiles`]: super::ChangedFiles
+
+use std::{
+    env,
+    path::{Component, Path, PathBuf},
+};
+
+/// Detects the root of the repository the current directory belongs to, as
+/// the closest ancestor containing a `.git` entry.
+///
+/// Falls back to the current directory, if no such ancestor exists.
+pub(super) fn repository_root() -> PathBuf {
+    let cwd = env::current_dir().unwrap_or_default();
+    cwd.ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .map_or_else(|| cwd.clone(), Path::to_path_buf)
+}
+
+/// Detects the root of the package being run, as the `CARGO_MANIFEST_DIR`
+/// set by Cargo for the test and run processes.
+///
+/// Falls back to the current directory, if Cargo hasn't set it.
+pub(super) fn package_root() -> PathBuf {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map_or_else(|| env::current_dir().unwrap_or_default(), PathBuf::from)
+}
+
+/// Normalizes the provided `path` lexically (without touching the file
+/// system), resolving `.` and `..` components, and making it relative to the
+/// provided `root` if it lies inside of it.
+pub(super) fn normalize(path: &Path, root: &Path) -> PathBuf {
+    let path = lexical(path);
+    path.strip_prefix(lexical(root))
+        .map_or_else(|_| path.clone(), Path::to_path_buf)
+}
+
+/// Resolves `.` and `..` components of the provided `path` lexically.
+fn lexical(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    out.components().next_back(),
+                    Some(Component::Normal(_)),
+                ) =>
+            {
+                _ = out.pop();
+            }
+            Component::Prefix(_)
+            | Component::RootDir
+            | Component::ParentDir
+            | Component::Normal(_) => out.push(component),
+        }
+    }
+    out
+}
+
+/// Extracts a repository relative path from a `git diff` header entry,
+/// omitting `/dev/null` of added or removed files.
+pub(super) fn diff_path(entry: &str) -> Option<&str> {
+    let entry = entry.trim();
+    (entry != "/dev/null").then(|| {
+        entry
+            .strip_prefix("a/")
+            .or_else(|| entry.strip_prefix("b/"))
+            .unwrap_or(entry)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_lexically_against_root() {
+        let root = Path::new("/repo/./crate/..");
+
+        for (path, expected) in [
+            ("./src/../tests/steps/cat.rs", "tests/steps/cat.rs"),
+            ("/repo/tests/steps/cat.rs", "tests/steps/cat.rs"),
+            ("/elsewhere/tests/steps/cat.rs", "/elsewhere/tests/steps/cat.rs"),
+            ("../steps/cat.rs", "../steps/cat.rs"),
+        ] {
+            assert_eq!(
+                normalize(Path::new(path), root),
+                Path::new(expected),
+                "{path}",
+            );
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use regex::Regex;
+
+use super::*;
+
+fn step(value: &str) -> gherkin::Step {
+    gherkin::Step {
+        keyword: "Given ".to_owned(),
+        ty: gherkin::StepType::Given,
+        value: value.to_owned(),
+        docstring: None,
+        table: None,
+        span: gherkin::Span { start: 0, end: 0 },
+        position: gherkin::LineCol { line: 1, col: 1 },
+    }
+}
+
+fn scenario(steps: Vec<gherkin::Step>) -> gherkin::Scenario {
+    gherkin::Scenario {
+        keyword: "Scenario".to_owned(),
+        name: "test".to_owned(),
+        tags: vec![],
+        description: None,
+        steps,
+        examples: vec![],
+        span: gherkin::Span { start: 0, end: 0 },
+        position: gherkin::LineCol { line: 1, col: 1 },
+    }
+}
+
+fn feature(scenarios: Vec<gherkin::Scenario>) -> gherkin::Feature {
+    gherkin::Feature {
+        keyword: "Feature".to_owned(),
+        name: "test".to_owned(),
+        tags: vec![],
+        description: None,
+        background: None,
+        scenarios,
+        rules: vec![],
+        span: gherkin::Span { start: 0, end: 0 },
+        position: gherkin::LineCol { line: 1, col: 1 },
+        path: Some(PathBuf::from("tests/features/test.feature")),
+    }
+}
+
+fn collection() -> Collection<()> {
+    Collection::new()
+        .given(
+            Some(Location::new("tests/steps/cat.rs", 1, 1)),
+            Regex::new("^cat$").unwrap(),
+            |_, _| Box::pin(async {}),
+        )
+        .given(
+            Some(Location::new("tests/steps/dog.rs", 1, 1)),
+            Regex::new("^dog$").unwrap(),
+            |_, _| Box::pin(async {}),
+        )
+}
+
+#[test]
+fn parses_name_only_list() {
+    let changed = ChangedFiles::parse("src/a.rs\n\n  tests/b.rs \n");
+
+    assert!(changed.contains(Path::new("src/a.rs")));
+    assert!(changed.contains(Path::new("tests/b.rs")));
+    assert_eq!(changed.paths().count(), 2);
+}
+
+#[test]
+fn parses_unified_diff() {
+    let changed = ChangedFiles::parse(
+        "diff --git a/src/old.rs b/src/new.rs\n\
+         --- a/src/old.rs\n\
+         +++ b/src/new.rs\n\
+         @@ -1 +1 @@\n\
+         -fn a() {}\n\
+         +fn b() {}\n\
+         diff --git a/src/gone.rs b/src/gone.rs\n\
+         --- a/src/gone.rs\n\
+         +++ /dev/null\n",
+    );
+
+    assert!(changed.contains(Path::new("src/old.rs")));
+    assert!(changed.contains(Path::new("src/new.rs")));
+    assert!(changed.contains(Path::new("src/gone.rs")));
+    assert!(!changed.contains(Path::new("/dev/null")));
+}
+
+#[test]
+fn matches_normalized_paths() {
+    let changed = ChangedFiles::new(["tests/steps/cat.rs"]).with_root("/repo");
+
+    assert!(changed.contains(Path::new("/repo/tests/steps/cat.rs")));
+    assert!(changed.contains(Path::new("./tests/steps/../steps/cat.rs")));
+    assert!(!changed.contains(Path::new("/other/tests/steps/cat.rs")));
+    assert!(!changed.contains(Path::new("steps/cat.rs")));
+    assert!(!changed.contains(Path::new("tests/steps/dog.rs")));
+}
+
+#[test]
+fn renormalizes_original_paths_against_new_root() {
+    let root = path::repository_root();
+    let parent = root.parent().unwrap().to_path_buf();
+    let name = root.file_name().unwrap();
+
+    let changed = ChangedFiles::new([root.join("src/a.rs")]).with_root(&parent);
+
+    assert!(changed.contains(&Path::new(name).join("src/a.rs")));
+    assert!(changed.contains(&root.join("src/a.rs")));
+    assert!(!changed.contains(Path::new("/src/a.rs")));
+}
+
+#[test]
+fn resolves_relative_locations_against_package_root() {
+    let changed = ChangedFiles::new(["crate/tests/steps/cat.rs"])
+        .with_root("/repo")
+        .with_package_root("crate");
+
+    assert!(changed.contains(Path::new("tests/steps/cat.rs")));
+    assert!(changed.contains(Path::new("crate/tests/steps/cat.rs")));
+    assert!(changed.contains(Path::new("/repo/crate/tests/steps/cat.rs")));
+    assert!(!changed.contains(Path::new("tests/steps/dog.rs")));
+}
+
+#[test]
+fn selects_scenarios_using_changed_steps() {
+    let changed = ChangedFiles::new(["tests/steps/cat.rs"]);
+    let coll = collection();
+    let feat =
+        feature(vec![scenario(vec![step("cat")]), scenario(vec![step("dog")])]);
+
+    let feat = changed.retain_affected(&coll, feat);
+
+    assert_eq!(feat.scenarios.len(), 1);
+    assert_eq!(feat.scenarios[0].steps[0].value, "cat");
+}
+
+#[test]
+fn keeps_unmatched_steps_conservatively() {
+    let changed = ChangedFiles::new(["tests/steps/cat.rs"]);
+    let coll = collection();
+    let feat = feature(vec![scenario(vec![step("dog"), step("bird")])]);
+
+    assert!(changed.is_scenario_affected(
+        &coll,
+        &feat,
+        None,
+        &feat.scenarios[0],
+    ));
+}
+
+#[test]
+fn changed_feature_file_selects_all_its_scenarios() {
+    let changed = ChangedFiles::new(["tests/features/test.feature"]);
+    let coll = collection();
+    let feat = feature(vec![scenario(vec![step("dog")])]);
+
+    assert_eq!(changed.retain_affected(&coll, feat).scenarios.len(), 1);
+}
@@ -11,6 +11,7 @@ use derive_more::with_trait::{Debug, Display};
 /// Location of a [`crate::step::Step`] [`fn`] automatically filled by a proc macro.
 #[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[display("{path}:{line}:{column}")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Location {
     /// Path to the file where [`crate::step::Step`] [`fn`] is located.
     pub path: &'static str,
@@ -17,32 +17,71 @@
 //! backward compatibility through re-exports. Each module follows the Single
 //! Responsibility Principle:
 //!
+//! - [`checks`]: Soft assertions of step functions
 //! - [`collection`]: Step collection management and matching
 //! - [`context`]: Step execution context and capture handling
+//! - [`docstring`]: Doc strings of steps with their media types
 //! - [`error`]: Error types for step matching failures
+//! - [`failure`]: Failures of step functions returning errors
 //! - [`location`]: File location tracking for step definitions
+//! - [`pending`]: Marking of steps as pending from within their functions
 //! - [`regex`]: Hashable regex wrapper utilities
+//! - [`skip`]: Skipping of steps from within their functions
 //! - [`builder`]: Modular step builder traits for enterprise-scale BDD
+//! - `discovery`: Automatic discovery of step builders across crates
+//! - [`ownership`]: Ownership metadata of step definitions
+//! - [`publish`]: Custom events published from step functions
+//! - [`registration`]: Instance-based step builders carrying configuration
+//! - [`impact`]: Test impact analysis based on changed step definitions
+//! - `table_serde`: Deserialization of data tables via [`serde`]
 //!
 //! [`crate::step::Step`]: gherkin::Step
 
 pub mod builder;
+pub mod checks;
 pub mod collection;
 pub mod context;
+#[cfg(feature = "macros")]
+pub mod discovery;
+pub mod docstring;
 pub mod error;
+pub mod failure;
+pub mod impact;
 pub mod location;
+pub mod ownership;
+pub mod pending;
+pub mod publish;
 pub mod regex;
+pub mod registration;
+pub mod skip;
 pub mod table;
+#[cfg(feature = "tables-serde")]
+pub mod table_serde;
 
 // Re-export all public items for easy access
 pub use builder::{StepBuilder, compose_step_builders};
-pub use collection::{Collection, WithContext};
-pub use context::{CaptureName, Context};
-pub use error::AmbiguousMatchError;
+pub use checks::CheckFailures;
+pub use collection::{
+    Collection, ComposeReport, Conflict, ConflictKind, Contribution,
+    DefinitionInfo, DuplicateStepsError, Function, SyncFunction, WithContext,
+};
+pub use context::{CaptureName, Context, ScenarioMetadata, SharedState};
+#[cfg(feature = "macros")]
+pub use discovery::Discovered;
+pub use docstring::{DocString, DocStringError};
+pub use error::{AmbiguousMatchError, ArgError, MatchDetails};
+pub use failure::Failure;
+pub use impact::ChangedFiles;
+pub use ownership::Ownership;
+pub use pending::Pending;
+pub use registration::StepRegistrar;
 // Type aliases that depend on other modules
 use futures::future::LocalBoxFuture;
 pub use location::Location;
 pub use regex::HashableRegex;
+pub use skip::Skip;
+#[cfg(feature = "tables-serde")]
+pub use table_serde::TableError;
 
 /// Alias for a [`gherkin::Step`] function that returns a [`LocalBoxFuture`].
 pub type Step<World> =
@@ -0,0 +1,115 @@
+//! Ownership metadata of step definitions.
+//!
+//! This module provides the [`Ownership`] type describing which team owns
+//! [`crate::step::Step`] definitions of a [`StepBuilder`], so a failing step
+//! report says whom to contact.
+//!
+//! [`StepBuilder`]: super::StepBuilder
+
+use std::fmt;
+
+use itertools::Itertools as _;
+
+/// Ownership metadata of [`crate::step::Step`] definitions, recorded into a
+/// [`Collection`] per definition via [`Collection::owned_by()`] and reported
+/// along with their [`event::Step::Failed`]s.
+///
+/// [`Collection`]: super::Collection
+/// [`Collection::owned_by()`]: super::Collection::owned_by
+/// [`event::Step::Failed`]: crate::event::Step::Failed
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Ownership {
+    /// Team owning the [`crate::step::Step`] definitions.
+    pub team: Option<String>,
+
+    /// Contact of the owning team (like an e-mail or a chat channel).
+    pub contact: Option<String>,
+
+    /// Version of the [`crate::step::Step`] definitions.
+    pub version: Option<String>,
+
+    /// Tags the [`gherkin::Scenario`]s using the [`crate::step::Step`]
+    /// definitions are required to have.
+    pub required_tags: Vec<String>,
+}
+
+impl Ownership {
+    /// Creates a new empty [`Ownership`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `team` owning the [`crate::step::Step`] definitions.
+    #[must_use]
+    pub fn team(mut self, team: impl Into<String>) -> Self {
+        self.team = Some(team.into());
+        self
+    }
+
+    /// Sets the `contact` of the owning team.
+    #[must_use]
+    pub fn contact(mut self, contact: impl Into<String>) -> Self {
+        self.contact = Some(contact.into());
+        self
+    }
+
+    /// Sets the `version` of the [`crate::step::Step`] definitions.
+    #[must_use]
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Adds the `tag` the [`gherkin::Scenario`]s using the
+    /// [`crate::step::Step`] definitions are required to have.
+    #[must_use]
+    pub fn required_tag(mut self, tag: impl Into<String>) -> Self {
+        self.required_tags.push(tag.into());
+        self
+    }
+}
+
+impl fmt::Display for Ownership {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let owner = match (&self.team, &self.contact) {
+            (Some(team), Some(contact)) => Some(format!("{team} ({contact})")),
+            (team, contact) => team.clone().or_else(|| contact.clone()),
+        };
+        let version = self.version.as_ref().map(|v| format!("v{v}"));
+        let tags = (!self.required_tags.is_empty()).then(|| {
+            format!(
+                "requires {}",
+                self.required_tags
+                    .iter()
+                    .map(|t| format!("@{}", t.trim_start_matches('@')))
+                    .join(" "),
+            )
+        });
+        write!(f, "{}", [owner, version, tags].into_iter().flatten().join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_present_fields_only() {
+        let owner = Ownership::new()
+            .team("payments")
+            .contact("#payments-oncall")
+            .version("1.2.0")
+            .required_tag("api")
+            .required_tag("db");
+        assert_eq!(
+            owner.to_string(),
+            "payments (#payments-oncall), v1.2.0, requires @api @db",
+        );
+
+        assert_eq!(Ownership::new().team("auth").to_string(), "auth");
+        assert_eq!(Ownership::new().version("2").to_string(), "v2");
+        assert_eq!(Ownership::new().to_string(), "");
+    }
+}
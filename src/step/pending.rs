@@ -0,0 +1,88 @@
+//! Marking of a step as pending from within its function.
+//!
+//! This module provides the [`Pending`] type a step function may raise to mark
+//! itself as not implemented yet, rather than failing.
+
+use std::{fmt, panic};
+
+use derive_more::with_trait::{Display, Error};
+
+use super::Context;
+
+/// Reason of a [`crate::step::Step`] function being not implemented yet.
+///
+/// Raised via [`Pending::raise()`] or [`Context::pending()`], it's reported as
+/// an [`event::Step::Failed`] with a [`StepError::Pending`], which isn't
+/// considered a failure of the run unless [`Cucumber::strict()`] (or the
+/// `--strict` CLI option) is used.
+///
+/// ```rust
+/// # use cucumber::{then, step, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// #[then("the cat purrs")]
+/// fn purrs(_: &mut MyWorld) {
+///     step::Pending::raise("purring isn't measurable yet");
+/// }
+/// ```
+///
+/// [`Cucumber::strict()`]: crate::Cucumber::strict
+/// [`event::Step::Failed`]: crate::event::Step::Failed
+/// [`StepError::Pending`]: crate::event::StepError::Pending
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+#[display("{_0}")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct Pending(#[error(not(source))] pub String);
+
+impl Pending {
+    /// Marks the current [`crate::step::Step`] as pending with the provided
+    /// `reason`.
+    ///
+    /// Unwinds the step function without invoking the panic hook, so nothing
+    /// is printed.
+    ///
+    /// # Panics
+    ///
+    /// Always, with a [`Pending`] payload.
+    pub fn raise(reason: impl fmt::Display) -> ! {
+        panic::resume_unwind(Box::new(Self(reason.to_string())))
+    }
+}
+
+impl Context {
+    /// Marks the step as pending with the provided `reason`.
+    ///
+    /// # Panics
+    ///
+    /// Always, with a [`Pending`] payload (see [`Pending::raise()`] for
+    /// details).
+    pub fn pending(&self, reason: impl fmt::Display) -> ! {
+        Pending::raise(reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::*;
+
+    #[test]
+    fn raises_reason() {
+        let payload = panic::catch_unwind(AssertUnwindSafe(|| {
+            Pending::raise("not implemented yet")
+        }))
+        .unwrap_err();
+
+        assert_eq!(
+            payload.downcast_ref::<Pending>(),
+            Some(&Pending("not implemented yet".into())),
+        );
+    }
+}
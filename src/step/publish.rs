@@ -0,0 +1,88 @@
+//! [`Custom`] events published from a step function.
+//!
+//! This module provides [`Context::publish()`] emitting a [`Custom`] event
+//! through the same event pipeline as the built-in ones, so it reaches all the
+//! [`Writer`]s and observers.
+//!
+//! [`Writer`]: crate::Writer
+
+use futures::channel::mpsc;
+
+use super::Context;
+use crate::event::Custom;
+
+/// Publisher of [`Custom`] events shared between a [`Context`] and the
+/// executor of its [`crate::step::Step`] function.
+///
+/// [`crate::step::Step`]: gherkin::Step
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Publisher(Option<mpsc::UnboundedSender<Custom>>);
+
+impl Publisher {
+    /// Creates a new [`Publisher`] along with the receiver of the [`Custom`]
+    /// events published via it.
+    pub(crate) fn channel() -> (Self, mpsc::UnboundedReceiver<Custom>) {
+        let (sender, receiver) = mpsc::unbounded();
+        (Self(Some(sender)), receiver)
+    }
+}
+
+impl Context {
+    /// Publishes the provided [`Custom`] `event` as an
+    /// [`event::Step::Published`] of the step being executed.
+    ///
+    /// Events published after the step function finishes (or from a
+    /// [`Context`] not created by a runner) are discarded.
+    ///
+    /// [`event::Step::Published`]: crate::event::Step::Published
+    pub fn publish(&self, event: impl Into<Custom>) {
+        if let Some(sender) = &self.publisher.0 {
+            drop(sender.unbounded_send(event.into()));
+        }
+    }
+
+    /// Sets the [`Publisher`] of the [`Custom`] events published via
+    /// [`Context::publish()`].
+    #[must_use]
+    pub(crate) fn with_publisher(mut self, publisher: Publisher) -> Self {
+        self.publisher = publisher;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt as _;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn publishes_until_receiver_dropped() {
+        let ctx = Context::new(
+            gherkin::Step {
+                keyword: "When".into(),
+                ty: gherkin::StepType::When,
+                value: "the order is placed".into(),
+                docstring: None,
+                table: None,
+                span: gherkin::Span { start: 0, end: 0 },
+                position: gherkin::LineCol { line: 1, col: 1 },
+            },
+            vec![],
+        );
+        ctx.publish("ignored without publisher");
+
+        let (publisher, mut published) = Publisher::channel();
+        let ctx = ctx.with_publisher(publisher);
+        ctx.publish(Custom::new("order placed").field("id", 123));
+        ctx.clone().publish("cart emptied");
+        drop(ctx);
+
+        assert_eq!(
+            published.next().await.map(|ev| ev.to_string()).as_deref(),
+            Some("order placed id=123"),
+        );
+        assert_eq!(published.next().await, Some(Custom::new("cart emptied")),);
+        assert_eq!(published.next().await, None);
+    }
+}
@@ -0,0 +1,161 @@
+//! Instance-based step builders carrying their own configuration.
+//!
+//! This module provides the [`StepRegistrar`] trait, an object-safe
+//! counterpart of the [`StepBuilder`] one, whose implementors are values
+//! rather than types, so they may be constructed with arbitrary state and
+//! composed as [`Box`]ed trait objects via [`Collection::compose()`].
+//!
+//! [`StepBuilder`]: super::StepBuilder
+
+use std::any;
+
+use super::Collection;
+
+/// Instance-based builder of [`crate::step::Step`] definitions.
+///
+/// Unlike the [`StepBuilder`], its methods take `&self`, so a builder may
+/// carry configuration (like a namespace or tags to scope its steps with)
+/// provided at construction.
+///
+/// As [`crate::step::Step`] functions are plain [`fn`] pointers, such
+/// configuration affects how the steps are registered, while values required
+/// by the steps at runtime (like base URLs or credentials) should still be
+/// kept in the [`World`].
+///
+/// # Example
+///
+/// ```rust
+/// use cucumber::step::{Collection, Context, StepRegistrar};
+/// use futures::future::LocalBoxFuture;
+/// use regex::Regex;
+///
+/// #[derive(Default)]
+/// struct TestWorld;
+///
+/// fn service_is_up(
+///     _world: &mut TestWorld,
+///     _ctx: Context,
+/// ) -> LocalBoxFuture<'_, ()> {
+///     Box::pin(async {})
+/// }
+///
+/// struct ServiceSteps {
+///     service: &'static str,
+/// }
+///
+/// impl StepRegistrar<TestWorld> for ServiceSteps {
+///     fn register(
+///         &self,
+///         collection: Collection<TestWorld>,
+///     ) -> Collection<TestWorld> {
+///         let steps = Collection::new().given(
+///             None,
+///             Regex::new("^the service is up$").unwrap(),
+///             service_is_up,
+///         );
+///         collection.merge_namespaced(self.service, steps)
+///     }
+///
+///     fn domain_name(&self) -> &str {
+///         self.service
+///     }
+/// }
+///
+/// // Matches `Given vault: the service is up` and
+/// // `Given crypto: the service is up` steps.
+/// let steps = Collection::compose(vec![
+///     Box::new(ServiceSteps { service: "vault" })
+///         as Box<dyn StepRegistrar<_>>,
+///     Box::new(ServiceSteps { service: "crypto" }),
+/// ]);
+/// assert_eq!(steps.given_len(), 2);
+/// ```
+///
+/// [`StepBuilder`]: super::StepBuilder
+/// [`World`]: crate::World
+pub trait StepRegistrar<World> {
+    /// Registers all the step definitions of this builder into the provided
+    /// [`Collection`].
+    #[must_use]
+    fn register(&self, collection: Collection<World>) -> Collection<World>;
+
+    /// Returns the human-readable name of this builder's domain.
+    ///
+    /// Returns the name of the implementing type by default.
+    fn domain_name(&self) -> &str {
+        any::type_name::<Self>()
+    }
+}
+
+impl<World> StepRegistrar<World> for Collection<World> {
+    fn register(&self, collection: Self) -> Self {
+        collection.merge(self.clone())
+    }
+}
+
+impl<World, R> StepRegistrar<World> for Box<R>
+where
+    R: StepRegistrar<World> + ?Sized,
+{
+    fn register(&self, collection: Collection<World>) -> Collection<World> {
+        (**self).register(collection)
+    }
+
+    fn domain_name(&self) -> &str {
+        (**self).domain_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::LocalBoxFuture;
+    use gherkin::tagexpr::TagOperation;
+    use regex::Regex;
+
+    use super::*;
+    use crate::step::Context;
+
+    struct TestWorld;
+
+    fn test_step(
+        _world: &mut TestWorld,
+        _ctx: Context,
+    ) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    struct ScopedSteps {
+        tags: &'static str,
+    }
+
+    impl StepRegistrar<TestWorld> for ScopedSteps {
+        fn register(
+            &self,
+            collection: Collection<TestWorld>,
+        ) -> Collection<TestWorld> {
+            collection.when_tagged(
+                None,
+                Regex::new("^the request is sent$").unwrap(),
+                self.tags.parse::<TagOperation>().unwrap(),
+                test_step,
+            )
+        }
+    }
+
+    #[test]
+    fn composes_boxed_instances() {
+        let registrars: Vec<Box<dyn StepRegistrar<TestWorld>>> = vec![
+            Box::new(ScopedSteps { tags: "@api" }),
+            Box::new(Collection::new().given(
+                None,
+                Regex::new("^the service is up$").unwrap(),
+                test_step,
+            )),
+        ];
+        assert!(registrars[0].domain_name().ends_with("ScopedSteps"));
+
+        let steps = Collection::compose(registrars);
+        assert_eq!(steps.given_len(), 1);
+        assert_eq!(steps.when_len(), 1);
+    }
+}
@@ -0,0 +1,89 @@
+//! Skipping of a step from within its function.
+//!
+//! This module provides the [`Skip`] type a step function may raise to mark
+//! itself (and the rest of its [`gherkin::Scenario`]) as skipped at runtime,
+//! rather than failing.
+
+use std::{fmt, panic};
+
+use derive_more::with_trait::{Display, Error};
+
+use super::Context;
+
+/// Reason of a [`crate::step::Step`] function skipping itself at runtime.
+///
+/// Raised via [`Skip::raise()`] or [`Context::skip()`], it's reported as an
+/// [`event::Step::Skipped`] with the reason, and the rest [`gherkin::Step`]s
+/// of the [`gherkin::Scenario`] are skipped as well.
+///
+/// ```rust
+/// # use cucumber::{given, step, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld {
+/// #     gpus: usize,
+/// # }
+/// #
+/// #[given("a GPU")]
+/// fn gpu(w: &mut MyWorld) {
+///     if w.gpus == 0 {
+///         step::Skip::raise("no GPU available");
+///     }
+/// }
+/// ```
+///
+/// [`event::Step::Skipped`]: crate::event::Step::Skipped
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+#[display("{_0}")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct Skip(#[error(not(source))] pub String);
+
+impl Skip {
+    /// Skips the current [`crate::step::Step`] with the provided `reason`.
+    ///
+    /// Unwinds the step function without invoking the panic hook, so nothing
+    /// is printed.
+    ///
+    /// # Panics
+    ///
+    /// Always, with a [`Skip`] payload.
+    pub fn raise(reason: impl fmt::Display) -> ! {
+        panic::resume_unwind(Box::new(Self(reason.to_string())))
+    }
+}
+
+impl Context {
+    /// Skips the step with the provided `reason`, along with the rest steps of
+    /// its [`gherkin::Scenario`].
+    ///
+    /// # Panics
+    ///
+    /// Always, with a [`Skip`] payload (see [`Skip::raise()`] for details).
+    pub fn skip(&self, reason: impl fmt::Display) -> ! {
+        Skip::raise(reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::*;
+
+    #[test]
+    fn raises_reason() {
+        let payload = panic::catch_unwind(AssertUnwindSafe(|| {
+            Skip::raise("no GPU available")
+        }))
+        .unwrap_err();
+
+        assert_eq!(
+            payload.downcast_ref::<Skip>(),
+            Some(&Skip("no GPU available".into())),
+        );
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Deserializer`]s of [`gherkin::Table`] records and their cell values.
+
+use std::{cell::Cell, fmt, str::FromStr, vec};
+
+use derive_more::with_trait::{Display, Error};
+use serde::de::{
+    self, DeserializeSeed, Deserializer, MapAccess, Visitor,
+    value::StrDeserializer,
+};
+
+/// Description of a deserialization failure.
+#[derive(Debug, Display, Error)]
+pub(super) struct Message(#[error(not(source))] pub(super) String);
+
+impl de::Error for Message {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Field of a record with its value.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Entry<'t> {
+    /// Name of the field.
+    pub(super) key: &'t str,
+
+    /// Value of the field.
+    pub(super) value: &'t str,
+
+    /// Row of the value in a [`gherkin::Table`].
+    pub(super) row: usize,
+}
+
+/// Row and field being deserialized currently.
+pub(super) type Current<'t> = Cell<(usize, Option<&'t str>)>;
+
+/// [`Deserializer`] of a record out of [`Entry`]s.
+pub(super) struct Record<'t, 'c> {
+    /// [`Entry`]s of the record.
+    pub(super) entries: Vec<Entry<'t>>,
+
+    /// Row of the record itself.
+    pub(super) row: usize,
+
+    /// Tracking of the [`Entry`] being deserialized currently.
+    pub(super) current: &'c Current<'t>,
+}
+
+impl<'de> Deserializer<'de> for Record<'_, '_> {
+    type Error = Message;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(Entries {
+            iter: self.entries.into_iter(),
+            value: "",
+            row: self.row,
+            current: self.current,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// [`MapAccess`] over [`Entry`]s of a [`Record`].
+struct Entries<'t, 'c> {
+    /// Remaining [`Entry`]s.
+    iter: vec::IntoIter<Entry<'t>>,
+
+    /// Value of the current [`Entry`].
+    value: &'t str,
+
+    /// Row of the [`Record`] itself.
+    row: usize,
+
+    /// Tracking of the [`Entry`] being deserialized currently.
+    current: &'c Current<'t>,
+}
+
+impl<'de> MapAccess<'de> for Entries<'_, '_> {
+    type Error = Message;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some(entry) = self.iter.next() else {
+            self.current.set((self.row, None));
+            return Ok(None);
+        };
+        self.current.set((entry.row, Some(entry.key)));
+        self.value = entry.value;
+        seed.deserialize(StrDeserializer::new(entry.key)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(Value(self.value))
+    }
+}
+
+/// [`Deserializer`] of a single cell value, parsing it into the requested
+/// type.
+struct Value<'t>(&'t str);
+
+impl Value<'_> {
+    /// Parses this [`Value`] via [`FromStr`].
+    fn parse<T: FromStr<Err: fmt::Display>>(&self) -> Result<T, Message> {
+        self.0.parse().map_err(|e| Message(format!("`{}`: {e}", self.0)))
+    }
+}
+
+/// Implements [`Deserializer`] methods parsing a [`Value`] via [`FromStr`].
+macro_rules! parse_value {
+    ($($method:ident => $visit:ident),* $(,)?) => {$(
+        fn $method<V: Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            visitor.$visit(self.parse()?)
+        }
+    )*};
+}
+
+impl<'de> Deserializer<'de> for Value<'_> {
+    type Error = Message;
+
+    parse_value! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(StrDeserializer::new(self.0))
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map
+        struct identifier ignored_any
+    }
+}
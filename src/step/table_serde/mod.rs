@@ -0,0 +1,111 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`serde`] deserialization of [`gherkin::Table`]s of [`gherkin::Step`]s.
+
+mod de;
+#[cfg(test)]
+mod tests;
+
+use std::cell::Cell;
+
+use derive_more::with_trait::{Display, Error};
+use serde::de::DeserializeOwned;
+
+use self::de::{Entry, Message, Record};
+
+/// Error of deserializing a [`gherkin::Table`] of a [`gherkin::Step`].
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum TableError {
+    /// [`gherkin::Step`] has no [`gherkin::Table`].
+    #[display("step has no data table")]
+    Missing,
+
+    /// Row of a [`gherkin::Table`] cannot be deserialized.
+    #[display(
+        "row {row} at line {line}{}: {message}",
+        field.as_ref().map(|f| format!(", field `{f}`")).unwrap_or_default(),
+    )]
+    Row {
+        /// Number of the row in the [`gherkin::Table`], starting from `1`.
+        row: usize,
+
+        /// Line of the row in the `.feature` file.
+        line: usize,
+
+        /// Field (header of a column, or a key of a vertical table) failed to
+        /// be deserialized, if any.
+        field: Option<String>,
+
+        /// Description of the failure.
+        message: String,
+    },
+}
+
+/// Deserializes rows of the provided [`gherkin::Table`] into records, using
+/// its first row as field names.
+pub(super) fn rows<T: DeserializeOwned>(
+    table: &gherkin::Table,
+) -> Result<Vec<T>, TableError> {
+    let Some((header, rows)) = table.rows.split_first() else {
+        return Ok(Vec::new());
+    };
+    rows.iter()
+        .zip(2..)
+        .map(|(cells, row)| {
+            let entries = header
+                .iter()
+                .zip(cells)
+                .map(|(key, value)| Entry { key, value, row })
+                .collect();
+            record(table, row, entries)
+        })
+        .collect()
+}
+
+/// Deserializes the provided vertical [`gherkin::Table`] into a single
+/// record, using its first column as field names, and its second one as
+/// values.
+pub(super) fn vertical<T: DeserializeOwned>(
+    table: &gherkin::Table,
+) -> Result<T, TableError> {
+    let entries = table
+        .rows
+        .iter()
+        .zip(1..)
+        .filter_map(|(cells, row)| {
+            let key = cells.first()?;
+            let value = cells.get(1).map_or("", String::as_str);
+            Some(Entry { key, value, row })
+        })
+        .collect();
+    record(table, 1, entries)
+}
+
+/// Deserializes a single record out of the provided `entries`, reporting
+/// errors at the provided `row` when they don't relate to any entry.
+fn record<T: DeserializeOwned>(
+    table: &gherkin::Table,
+    row: usize,
+    entries: Vec<Entry<'_>>,
+) -> Result<T, TableError> {
+    let current = Cell::new((row, None));
+    T::deserialize(Record { entries, row, current: &current }).map_err(
+        |Message(message)| {
+            let (at, field) = current.get();
+            TableError::Row {
+                row: at,
+                line: table.position.line + at - 1,
+                field: field.map(str::to_owned),
+                message,
+            }
+        },
+    )
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use serde::Deserialize;
+
+use super::*;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    name: String,
+    age: u8,
+    role: Role,
+    email: Option<String>,
+}
+
+fn table(rows: &[&[&str]]) -> gherkin::Table {
+    gherkin::Table {
+        rows: rows
+            .iter()
+            .map(|r| r.iter().map(|&c| c.to_owned()).collect())
+            .collect(),
+        span: gherkin::Span { start: 0, end: 0 },
+        position: gherkin::LineCol { line: 10, col: 7 },
+    }
+}
+
+#[test]
+fn deserializes_rows() {
+    let users = rows::<User>(&table(&[
+        &["name", "age", "role", "email"],
+        &["Alice", "30", "admin", "alice@example.com"],
+        &["Bob", "25", "guest", ""],
+    ]));
+
+    assert_eq!(
+        users.unwrap(),
+        [
+            User {
+                name: "Alice".into(),
+                age: 30,
+                role: Role::Admin,
+                email: Some("alice@example.com".into()),
+            },
+            User {
+                name: "Bob".into(),
+                age: 25,
+                role: Role::Guest,
+                email: None,
+            },
+        ],
+    );
+}
+
+#[test]
+fn reports_offending_row_and_field() {
+    let err = rows::<User>(&table(&[
+        &["name", "age", "role", "email"],
+        &["Alice", "30", "admin", ""],
+        &["Bob", "old", "guest", ""],
+    ]))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "row 3 at line 12, field `age`: \
+         `old`: invalid digit found in string",
+    );
+
+    let err =
+        vertical::<User>(&table(&[&["name", "Alice"], &["role", "admin"]]))
+            .unwrap_err();
+    assert_eq!(err.to_string(), "row 1 at line 10: missing field `age`");
+}
+
+#[test]
+fn deserializes_vertical_table() {
+    let user = vertical::<User>(&table(&[
+        &["name", "Alice"],
+        &["age", "30"],
+        &["role", "admin"],
+        &["email", ""],
+    ]));
+
+    assert_eq!(
+        user.unwrap(),
+        User { name: "Alice".into(), age: 30, role: Role::Admin, email: None },
+    );
+}
@@ -0,0 +1,37 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CLI options of printing the JSON index of step definitions.
+
+use std::path::PathBuf;
+
+/// CLI options of printing the JSON index of step definitions.
+#[derive(Clone, Debug, Default, clap::Args)]
+#[group(skip)]
+pub struct Cli {
+    /// Print the JSON index of all the step definitions (their patterns,
+    /// parameters, source locations and domains) for editor tooling, instead
+    /// of executing the steps.
+    #[arg(long, global = true)]
+    pub steps_index: bool,
+
+    /// Write the JSON index of all the step definitions, along with the
+    /// locations of scenarios and steps of the executed `.feature` files
+    /// (mapped to the matched step definitions), into the file at the end of
+    /// the run (`target/cucumber/steps-index.json`, if no path is provided).
+    #[arg(
+        long,
+        value_name = "path",
+        num_args = 0..=1,
+        default_missing_value = super::INDEX_FILE,
+        global = true
+    )]
+    pub steps_index_file: Option<PathBuf>,
+}
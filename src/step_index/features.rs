@@ -0,0 +1,132 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Indexing of [`gherkin::Feature`]s along with the [`step::Step`]
+//! definitions they use.
+
+use std::collections::{HashMap, HashSet};
+
+use super::{Feature, Index, Scenario, Usage};
+use crate::{step, validate::iter_steps, writer::basic::trim_path};
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to indexing features only"
+)]
+impl Index {
+    /// Builds an [`Index`] of all the [`step::Step`] definitions of the
+    /// provided [`step::Collection`], along with the [`Feature`]s of the
+    /// provided [`gherkin::Feature`]s, mapping their [`gherkin::Step`]s to the
+    /// matched definitions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{feature::Ext as _, step, step_index::Index};
+    /// # use regex::Regex;
+    /// #
+    /// # struct World;
+    /// #
+    /// let steps = step::Collection::<World>::new().given(
+    ///     None,
+    ///     Regex::new(r"^(\d+) cats?$").unwrap(),
+    ///     |_, _| Box::pin(async {}),
+    /// );
+    /// let feature = gherkin::Feature::parse(
+    ///     "Feature: Cats\n  Scenario: Feeding\n    Given 2 cats\n",
+    ///     gherkin::GherkinEnv::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let index = Index::with_features(&steps, &[feature]);
+    ///
+    /// assert_eq!(index.features[0].scenarios[0].line, 2);
+    /// assert_eq!(index.features[0].steps[0].line, 3);
+    /// assert_eq!(index.features[0].steps[0].definitions, [0]);
+    /// ```
+    #[must_use]
+    pub fn with_features<World>(
+        steps: &step::Collection<World>,
+        features: &[gherkin::Feature],
+    ) -> Self {
+        let mut index = Self::of(steps);
+        let positions = steps
+            .definitions()
+            .enumerate()
+            .map(|(i, def)| ((def.pattern, def.location), i))
+            .collect::<HashMap<_, _>>();
+        let position = |re: &step::HashableRegex,
+                        loc: Option<step::Location>| {
+            positions.get(&(re.as_str(), loc)).copied()
+        };
+
+        index.features = features
+            .iter()
+            .map(|feature| {
+                let mut scenarios = feature
+                    .scenarios
+                    .iter()
+                    .map(|sc| (None, sc))
+                    .chain(feature.rules.iter().flat_map(|r| {
+                        r.scenarios.iter().map(move |sc| (Some(r), sc))
+                    }))
+                    .map(|(rule, sc)| Scenario {
+                        name: sc.name.clone(),
+                        rule: rule.map(|r| r.name.clone()),
+                        line: sc.position.line,
+                        column: sc.position.col,
+                    })
+                    .collect::<Vec<_>>();
+                scenarios.sort_by_key(|sc| (sc.line, sc.column));
+                scenarios.dedup_by_key(|sc| (sc.line, sc.column));
+
+                let mut seen = HashSet::new();
+                let steps = iter_steps(feature)
+                    .filter(|(_, st)| {
+                        seen.insert((st.position, &st.keyword, &st.value))
+                    })
+                    .map(|(tags, st)| Usage {
+                        keyword: st.keyword.trim().to_owned(),
+                        text: st.value.clone(),
+                        line: st.position.line,
+                        column: st.position.col,
+                        definitions: match steps.find_tagged(st, &tags) {
+                            Ok(Some((_, _, loc, ctx))) => ctx
+                                .pattern
+                                .and_then(|re| position(&re, loc))
+                                .into_iter()
+                                .collect(),
+                            Ok(None) => vec![],
+                            Err(e) => e
+                                .possible_matches
+                                .iter()
+                                .filter_map(|(re, loc)| position(re, *loc))
+                                .collect(),
+                        },
+                    })
+                    .collect();
+
+                Feature {
+                    path: feature
+                        .path
+                        .as_ref()
+                        .and_then(|p| p.to_str())
+                        .map(|p| trim_path(p).to_owned()),
+                    name: feature.name.clone(),
+                    scenarios,
+                    steps,
+                }
+            })
+            .collect();
+        index
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! JSON index of [`step::Step`] definitions for editor tooling (like
+//! autocompletion and go-to-definition in Gherkin plugins).
+//!
+//! Printed via the `--steps-index` CLI option, or built programmatically via
+//! [`Index::of()`].
+//!
+//! Along with the [`Feature`]s of a run, mapping their [`gherkin::Step`]s to
+//! the matched [`Entry`]s (for go-to-definition from `.feature` files), is
+//! written into a file via the `--steps-index-file` CLI option (the
+//! [`INDEX_FILE`], if no path is provided), or built programmatically via
+//! [`Index::with_features()`].
+
+mod cli;
+mod features;
+#[cfg(test)]
+mod tests;
+
+use std::{fmt, fs, io, path::Path};
+
+use serde::Serialize;
+
+#[doc(inline)]
+pub use self::cli::Cli;
+use crate::{step, writer::basic::trim_path};
+
+/// Default path of the file an [`Index`] is written into via the
+/// `--steps-index-file` CLI option, well-known for editor tooling.
+pub const INDEX_FILE: &str = "target/cucumber/steps-index.json";
+
+/// Source location of an [`Entry`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct Source {
+    /// Path to the file of the [`step::Step`] function.
+    pub path: &'static str,
+
+    /// Line of the [`step::Step`] function in its file.
+    pub line: u32,
+
+    /// Column of the [`step::Step`] function in its file.
+    pub column: u32,
+}
+
+/// Single [`step::Step`] definition of an [`Index`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Entry {
+    /// Keyword of the [`gherkin::StepType`] of this [`Entry`] (like `Given`).
+    pub keyword: &'static str,
+
+    /// [`regex::Regex`] pattern of this [`Entry`].
+    pub pattern: String,
+
+    /// Names of the capture groups of the `pattern` (or their positions, for
+    /// the unnamed ones).
+    pub parameters: Vec<String>,
+
+    /// [`Source`] of the [`step::Step`] function, if known.
+    pub source: Option<Source>,
+
+    /// Team owning this [`Entry`], if recorded in its [`step::Ownership`].
+    pub domain: Option<String>,
+
+    /// Doc comment of the [`step::Step`] function, if any.
+    pub doc: Option<&'static str>,
+}
+
+/// [`gherkin::Scenario`] of a [`Feature`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Scenario {
+    /// Name of the [`gherkin::Scenario`].
+    pub name: String,
+
+    /// Name of the [`gherkin::Rule`] of the [`gherkin::Scenario`], if any.
+    pub rule: Option<String>,
+
+    /// Line of the [`gherkin::Scenario`] in its `.feature` file.
+    pub line: usize,
+
+    /// Column of the [`gherkin::Scenario`] in its `.feature` file.
+    pub column: usize,
+}
+
+/// [`gherkin::Step`] of a [`Feature`], along with the [`Entry`]s it matches.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Usage {
+    /// Keyword of the [`gherkin::Step`], as written in its `.feature` file
+    /// (like `And`).
+    pub keyword: String,
+
+    /// Text of the [`gherkin::Step`], without its keyword.
+    pub text: String,
+
+    /// Line of the [`gherkin::Step`] in its `.feature` file.
+    pub line: usize,
+
+    /// Column of the [`gherkin::Step`] in its `.feature` file.
+    pub column: usize,
+
+    /// Indices of the [`Index::steps`] matched by the [`gherkin::Step`] (none,
+    /// if it's undefined, or multiple, if it's ambiguous).
+    pub definitions: Vec<usize>,
+}
+
+/// `.feature` file of an [`Index`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Feature {
+    /// Path to the `.feature` file, if known.
+    pub path: Option<String>,
+
+    /// Name of the [`gherkin::Feature`].
+    pub name: String,
+
+    /// All the [`Scenario`]s of the [`gherkin::Feature`] (including the ones
+    /// of its [`gherkin::Rule`]s), in the order of their position.
+    pub scenarios: Vec<Scenario>,
+
+    /// All the [`Usage`]s of [`gherkin::Step`]s of the [`gherkin::Feature`]
+    /// (including [`gherkin::Background`] ones). Steps of
+    /// [Scenario Outline][1]s are present for each distinct text of their
+    /// expanded examples.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    pub steps: Vec<Usage>,
+}
+
+/// JSON index of all the [`step::Step`] definitions of a
+/// [`step::Collection`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct Index {
+    /// All the [`Entry`]s of this [`Index`], in the
+    /// [`step::Collection::definitions()`] order.
+    pub steps: Vec<Entry>,
+
+    /// [`Feature`]s using the [`Entry`]s of this [`Index`], if built via
+    /// [`Index::with_features()`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<Feature>,
+}
+
+impl Index {
+    /// Builds an [`Index`] of all the [`step::Step`] definitions of the
+    /// provided [`step::Collection`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{step, step_index::Index};
+    /// # use regex::Regex;
+    /// #
+    /// # struct World;
+    /// #
+    /// let steps = step::Collection::<World>::new().given(
+    ///     None,
+    ///     Regex::new(r"^(?P<count>\d+) cats?$").unwrap(),
+    ///     |_, _| Box::pin(async {}),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Index::of(&steps).to_string(),
+    ///     r#"{"steps":[{"keyword":"Given","pattern":"^(?P<count>\\d+) cats?$","parameters":["count"],"source":null,"domain":null,"doc":null}]}"#,
+    /// );
+    /// ```
+    #[must_use]
+    pub fn of<World>(steps: &step::Collection<World>) -> Self {
+        let steps = steps
+            .definitions()
+            .map(|def| Entry {
+                keyword: match def.ty {
+                    gherkin::StepType::Given => "Given",
+                    gherkin::StepType::When => "When",
+                    gherkin::StepType::Then => "Then",
+                },
+                pattern: def.pattern.to_owned(),
+                parameters: def
+                    .regex
+                    .capture_names()
+                    .enumerate()
+                    .skip(1)
+                    .map(|(n, name)| {
+                        name.map_or_else(|| n.to_string(), Into::into)
+                    })
+                    .collect(),
+                source: def.location.map(|l| Source {
+                    path: trim_path(l.path),
+                    line: l.line,
+                    column: l.column,
+                }),
+                domain: def.owner.and_then(|o| o.team.clone()),
+                doc: def.doc,
+            })
+            .collect();
+        Self { steps, features: vec![] }
+    }
+
+    /// Writes this [`Index`] as JSON into the file at the provided `path`,
+    /// creating its parent directories, if required.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let json =
+            serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json)
+    }
+}
+
+impl fmt::Display for Index {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[expect(
+            clippy::map_err_ignore,
+            reason = "`fmt::Error` cannot carry `serde_json::Error`"
+        )]
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        write!(f, "{json}")
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests of building and saving [`Index`]es.
+
+use regex::Regex;
+
+use super::*;
+
+struct World;
+
+#[test]
+fn indexes_parameters_sources_and_domains() {
+    let steps = step::Collection::<World>::new()
+        .when(
+            Some(step::Location::new("tests/steps/pay.rs", 7, 1)),
+            Regex::new(r#"^(\w+) pays "(?P<amount>[^"]+)"$"#).unwrap(),
+            |_, _| Box::pin(async {}),
+        )
+        .owned_by(step::Ownership::new().team("billing"));
+
+    assert_eq!(
+        Index::of(&steps).steps,
+        [Entry {
+            keyword: "When",
+            pattern: r#"^(\w+) pays "(?P<amount>[^"]+)"$"#.into(),
+            parameters: vec!["1".into(), "amount".into()],
+            source: Some(Source {
+                path: "tests/steps/pay.rs",
+                line: 7,
+                column: 1,
+            }),
+            domain: Some("billing".into()),
+            doc: None,
+        }],
+    );
+}
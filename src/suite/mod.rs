@@ -0,0 +1,172 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Running [`Feature`]s against several `World` types, routing their
+//! [`gherkin::Scenario`]s via tags.
+//!
+//! [`Feature`]: gherkin::Feature
+
+mod routed;
+mod summary;
+mod world;
+
+use std::fmt;
+
+use gherkin::tagexpr::TagOperation;
+
+pub use self::{routed::Routed, summary::Summary, world::AnyWorld};
+use crate::{Parser, World, parser, runner, step, writer, writer::Ext as _};
+
+/// [`crate::Cucumber`] executor of a [`Suite`], running all its `World`s
+/// erased into an [`AnyWorld`].
+pub type Cucumber<I> = crate::Cucumber<
+    AnyWorld,
+    Routed<parser::Basic>,
+    I,
+    runner::Basic<AnyWorld>,
+    writer::Summarize<writer::Normalize<AnyWorld, writer::Basic>>,
+>;
+
+/// Suite of several `World` types, each running the [`gherkin::Scenario`]s
+/// routed to it via a tag expression.
+///
+/// Every [`gherkin::Scenario`] is run by the first registered `World` whose
+/// tag expression matches the tags of its [`Feature`], [`Rule`] and itself.
+/// [`gherkin::Scenario`]s matched by no tag expression aren't run at all.
+///
+/// All the `World`s are run in a single [`Cucumber`] executor, erasing their
+/// types into an [`AnyWorld`], so mixed suites don't need a single `World`
+/// containing the state of all of them.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{Suite, World};
+/// #
+/// #[derive(Debug, Default, World)]
+/// struct ApiWorld;
+///
+/// #[derive(Debug, Default, World)]
+/// struct BrowserWorld;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let summary = Suite::new()
+///     .world("@api", ApiWorld::collection())
+///     .world("@browser", BrowserWorld::collection())
+///     .run("tests/features/readme")
+///     .await;
+/// # assert!(!summary.execution_has_failed());
+/// # }
+/// ```
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+pub struct Suite {
+    /// Tag expressions routing [`gherkin::Scenario`]s to the `World`s.
+    routes: Vec<TagOperation>,
+
+    /// [`step::Collection`]s of all the `World`s, erased into an [`AnyWorld`].
+    steps: step::Collection<AnyWorld>,
+}
+
+impl fmt::Debug for Suite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Suite")
+            .field("routes", &self.routes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Suite {
+    fn default() -> Self {
+        Self { routes: Vec::new(), steps: step::Collection::new() }
+    }
+}
+
+impl Suite {
+    /// Creates a new empty [`Suite`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a `World` with the provided [`step::Collection`] to run the
+    /// [`gherkin::Scenario`]s matching the `tags` expression (like `@api` or
+    /// `@browser and not @slow`), and not matched by any previously registered
+    /// `World`.
+    ///
+    /// # Panics
+    ///
+    /// If the `tags` expression is invalid.
+    #[must_use]
+    pub fn world<W: World + fmt::Debug>(
+        mut self,
+        tags: &str,
+        steps: step::Collection<W>,
+    ) -> Self {
+        let op = tags
+            .parse::<TagOperation>()
+            .unwrap_or_else(|e| panic!("invalid `World` tags `{tags}`: {e}"));
+        let scope = self.routes.iter().fold(op.clone(), |scope, previous| {
+            TagOperation::And(
+                Box::new(scope),
+                Box::new(TagOperation::Not(Box::new(previous.clone()))),
+            )
+        });
+        self.routes.push(op);
+        self.steps =
+            self.steps.merge(steps.erase(&scope, world::dispatch::<W>));
+        self
+    }
+
+    /// Returns the [`Cucumber`] executor running all the registered `World`s,
+    /// allowing to configure it further.
+    #[must_use]
+    pub fn cucumber<I>(self) -> Cucumber<I>
+    where
+        parser::Basic: Parser<I>,
+    {
+        let Self { routes, steps } = self;
+        let parser = Routed { parser: parser::Basic::new(), routes };
+        crate::Cucumber::custom(
+            parser,
+            runner::Basic::default(),
+            writer::Basic::stdout().summarized(),
+        )
+        .steps(steps)
+    }
+
+    /// Runs all the registered `World`s, returning the [`Summary`] of their
+    /// execution.
+    pub async fn run<I>(self, input: I) -> Summary
+    where
+        parser::Basic: Parser<I>,
+    {
+        Summary::of(&self.cucumber().run(input).await)
+    }
+
+    /// Runs all the registered `World`s, exiting the process on failures via
+    /// [`crate::Cucumber::run_and_exit()`].
+    ///
+    /// # Panics
+    ///
+    /// If encountered errors while parsing [`Feature`]s, or at least one
+    /// [`Step`] or hook has failed.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    pub async fn run_and_exit<I>(self, input: I)
+    where
+        parser::Basic: Parser<I>,
+    {
+        self.cucumber().run_and_exit(input).await;
+    }
+}
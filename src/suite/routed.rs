@@ -0,0 +1,160 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Parser`] wrapper leaving only [`gherkin::Scenario`]s routed to any
+//! `World` of a [`Suite`].
+//!
+//! [`Suite`]: super::Suite
+
+use std::mem;
+
+use futures::{StreamExt as _, future, stream::LocalBoxStream};
+use gherkin::tagexpr::TagOperation;
+
+use crate::{Parser, parser, tag::Ext as _};
+
+/// Indicates whether the [`gherkin::Scenario`] is routed to any `World`, so
+/// any of the `routes` tag expressions matches it.
+fn is_routed(
+    routes: &[TagOperation],
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> bool {
+    // The order `Feature` -> `Rule` -> `Scenario` matters here.
+    let tags = feature
+        .tags
+        .iter()
+        .chain(rule.iter().flat_map(|r| &r.tags))
+        .chain(&scenario.tags);
+    routes.iter().any(|op| op.eval(tags.clone()))
+}
+
+/// [`Parser`] leaving only [`gherkin::Scenario`]s routed to any `World` of a
+/// [`Suite`], and omitting [`gherkin::Feature`]s left without them.
+///
+/// [`Suite`]: super::Suite
+#[derive(Debug)]
+pub struct Routed<P> {
+    /// Wrapped [`Parser`].
+    pub(super) parser: P,
+
+    /// Tag expressions routing [`gherkin::Scenario`]s to the `World`s.
+    pub(super) routes: Vec<TagOperation>,
+}
+
+impl<I, P: Parser<I>> Parser<I> for Routed<P> {
+    type Cli = P::Cli;
+
+    type Output = LocalBoxStream<'static, parser::Result<gherkin::Feature>>;
+
+    fn parse(self, input: I, cli: Self::Cli) -> Self::Output {
+        let Self { parser, routes } = self;
+        parser
+            .parse(input, cli)
+            .filter_map(move |feature| {
+                future::ready(match feature {
+                    Ok(feat) => retain(feat, &routes).map(Ok),
+                    Err(e) => Some(Err(e)),
+                })
+            })
+            .boxed_local()
+    }
+}
+
+/// Retains only [`gherkin::Scenario`]s of the `feature` routed to any `World`,
+/// returning [`None`] if none of them is left.
+fn retain(
+    mut feature: gherkin::Feature,
+    routes: &[TagOperation],
+) -> Option<gherkin::Feature> {
+    let scenarios = mem::take(&mut feature.scenarios)
+        .into_iter()
+        .filter(|sc| is_routed(routes, &feature, None, sc))
+        .collect();
+    feature.scenarios = scenarios;
+
+    let rules = mem::take(&mut feature.rules)
+        .into_iter()
+        .filter_map(|mut rule| {
+            let kept = mem::take(&mut rule.scenarios)
+                .into_iter()
+                .filter(|sc| is_routed(routes, &feature, Some(&rule), sc))
+                .collect();
+            rule.scenarios = kept;
+            (!rule.scenarios.is_empty()).then_some(rule)
+        })
+        .collect();
+    feature.rules = rules;
+
+    (!feature.scenarios.is_empty() || !feature.rules.is_empty())
+        .then_some(feature)
+}
+
+#[cfg(test)]
+mod spec {
+    use gherkin::GherkinEnv;
+
+    use super::retain;
+
+    const FEATURE: &str = "\
+Feature: Mixed
+  @api
+  Scenario: request
+    Given a request
+
+  @browser
+  Scenario: click
+    Given a click
+
+  Scenario: unrouted
+    Given a step
+
+  Rule: routed
+    @api @browser
+    Scenario: both
+      Given a request
+
+  Rule: unrouted
+    Scenario: none
+      Given a step
+";
+
+    fn routes(tags: &[&str]) -> Vec<gherkin::tagexpr::TagOperation> {
+        tags.iter().map(|t| t.parse().unwrap()).collect()
+    }
+
+    fn names(feature: &gherkin::Feature) -> Vec<&str> {
+        feature
+            .scenarios
+            .iter()
+            .chain(feature.rules.iter().flat_map(|r| &r.scenarios))
+            .map(|sc| sc.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn retains_routed_scenarios() {
+        let feature =
+            gherkin::Feature::parse(FEATURE, GherkinEnv::default()).unwrap();
+
+        let routed = retain(feature, &routes(&["@api", "@browser"])).unwrap();
+        assert_eq!(names(&routed), ["request", "click", "both"]);
+        assert_eq!(routed.rules.len(), 1, "empty `Rule` should be omitted");
+    }
+
+    #[test]
+    fn omits_feature_without_routed_scenarios() {
+        let feature =
+            gherkin::Feature::parse(FEATURE, GherkinEnv::default()).unwrap();
+
+        assert!(retain(feature, &routes(&["@ui"])).is_none());
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Summary`] of a [`Suite`] execution.
+//!
+//! [`Suite`]: super::Suite
+
+use std::fmt;
+
+use crate::writer;
+
+/// Summary of a [`Suite`] execution over all of its `World`s.
+///
+/// [`Suite`]: super::Suite
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    /// Number of [`Passed`] [`Step`]s.
+    ///
+    /// [`Passed`]: crate::event::Step::Passed
+    /// [`Step`]: gherkin::Step
+    pub passed_steps: usize,
+
+    /// Number of [`Skipped`] [`Step`]s.
+    ///
+    /// [`Skipped`]: crate::event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    pub skipped_steps: usize,
+
+    /// Number of [`Failed`] [`Step`]s.
+    ///
+    /// [`Failed`]: crate::event::Step::Failed
+    /// [`Step`]: gherkin::Step
+    pub failed_steps: usize,
+
+    /// Number of timed out [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub timed_out_steps: usize,
+
+    /// Number of [`Step`]s not matching any function.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub undefined_steps: usize,
+
+    /// Number of pending [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub pending_steps: usize,
+
+    /// Number of retried [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub retried_steps: usize,
+
+    /// Number of parsing errors.
+    pub parsing_errors: usize,
+
+    /// Number of failed [`gherkin::Scenario`] hooks.
+    pub hook_errors: usize,
+
+    /// Number of [`gherkin::Scenario`] duration SLA violations.
+    pub sla_violations: usize,
+}
+
+impl Summary {
+    /// Collects a [`Summary`] from the provided [`writer::Stats`].
+    pub(super) fn of<W>(writer: &impl writer::Stats<W>) -> Self {
+        Self {
+            passed_steps: writer.passed_steps(),
+            skipped_steps: writer.skipped_steps(),
+            failed_steps: writer.failed_steps(),
+            timed_out_steps: writer.timed_out_steps(),
+            undefined_steps: writer.undefined_steps(),
+            pending_steps: writer.pending_steps(),
+            retried_steps: writer.retried_steps(),
+            parsing_errors: writer.parsing_errors(),
+            hook_errors: writer.hook_errors(),
+            sla_violations: writer.sla_violations(),
+        }
+    }
+
+    /// Indicates whether there were failures/errors during execution.
+    #[must_use]
+    pub const fn execution_has_failed(&self) -> bool {
+        self.failed_steps > 0
+            || self.timed_out_steps > 0
+            || self.parsing_errors > 0
+            || self.hook_errors > 0
+            || self.sla_violations > 0
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |n: usize| if n > 1 { "s" } else { "" };
+        let msg = [
+            (self.failed_steps, "step", " failed"),
+            (self.timed_out_steps, "step", " timed out"),
+            (self.parsing_errors, "parsing error", ""),
+            (self.hook_errors, "hook error", ""),
+            (self.sla_violations, "SLA violation", ""),
+        ]
+        .into_iter()
+        .filter(|(n, ..)| *n > 0)
+        .map(|(n, what, suffix)| format!("{n} {what}{}{suffix}", plural(n)))
+        .collect::<Vec<_>>();
+        write!(f, "{}", msg.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::Summary;
+
+    #[test]
+    fn describes_failures() {
+        let summary = Summary {
+            passed_steps: 4,
+            failed_steps: 2,
+            parsing_errors: 1,
+            hook_errors: 2,
+            ..Summary::default()
+        };
+
+        assert!(summary.execution_has_failed());
+        assert_eq!(
+            summary.to_string(),
+            "2 steps failed, 1 parsing error, 2 hook errors",
+        );
+        assert!(!Summary::default().execution_has_failed());
+
+        let undefined = Summary {
+            undefined_steps: 2,
+            pending_steps: 1,
+            ..Summary::default()
+        };
+        assert!(!undefined.execution_has_failed());
+
+        let timed_out = Summary { timed_out_steps: 2, ..Summary::default() };
+        assert!(timed_out.execution_has_failed());
+        assert_eq!(timed_out.to_string(), "2 steps timed out");
+
+        let sla = Summary { sla_violations: 1, ..Summary::default() };
+        assert!(sla.execution_has_failed());
+        assert_eq!(sla.to_string(), "1 SLA violation");
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`AnyWorld`] erasing the `World` types of a [`Suite`].
+//!
+//! [`Suite`]: super::Suite
+
+use std::{any::Any, fmt};
+
+use futures::future::LocalBoxFuture;
+
+use crate::{
+    World,
+    step::{self, Context},
+};
+
+/// `World` of a [`Suite`], holding a `World` of the type the running
+/// [`gherkin::Scenario`] is routed to.
+///
+/// The routed `World` is created on the first [`crate::step::Step`] of the
+/// [`gherkin::Scenario`], so failing to create it fails that
+/// [`crate::step::Step`].
+///
+/// [`Suite`]: super::Suite
+#[derive(Default)]
+pub struct AnyWorld(Option<Box<dyn Erased>>);
+
+impl AnyWorld {
+    /// Returns the routed `World`, if it's of type `W` and has been created
+    /// already.
+    #[must_use]
+    pub fn get<W: World>(&self) -> Option<&W> {
+        self.0.as_ref()?.as_any().downcast_ref()
+    }
+
+    /// Returns the routed `World` mutably, if it's of type `W` and has been
+    /// created already.
+    #[must_use]
+    pub fn get_mut<W: World>(&mut self) -> Option<&mut W> {
+        self.0.as_mut()?.as_any_mut().downcast_mut()
+    }
+}
+
+// Implemented manually to output the routed `World` only.
+impl fmt::Debug for AnyWorld {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(world) => world.fmt(f),
+            None => f.write_str("AnyWorld"),
+        }
+    }
+}
+
+impl World for AnyWorld {
+    type Error = String;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self::default())
+    }
+
+    async fn teardown(&mut self) -> Result<(), Self::Error> {
+        match &mut self.0 {
+            Some(world) => world.teardown().await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// Routed `World` of an erased type.
+trait Erased: fmt::Debug {
+    /// Returns this `World` as [`Any`].
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns this `World` as mutable [`Any`].
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Tears down this `World` via its [`World::teardown()`].
+    fn teardown(&mut self) -> LocalBoxFuture<'_, Result<(), String>>;
+}
+
+impl<W: World + fmt::Debug> Erased for W {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn teardown(&mut self) -> LocalBoxFuture<'_, Result<(), String>> {
+        Box::pin(async {
+            World::teardown(self).await.map_err(|e| e.to_string())
+        })
+    }
+}
+
+/// Dispatches the [`crate::step::Step`] function of the `W` type erased into
+/// the provided [`Context`] to the routed `world`, creating it if required.
+///
+/// # Panics
+///
+/// If the routed `World` cannot be created, or it's not of type `W`.
+pub(super) fn dispatch<W: World + fmt::Debug>(
+    world: &mut AnyWorld,
+    mut ctx: Context,
+) -> LocalBoxFuture<'_, ()> {
+    Box::pin(async move {
+        let Some(step) = ctx.take_erased::<step::Function<W>>() else {
+            panic!("`Step` function of another `World` type");
+        };
+        if world.0.is_none() {
+            let routed = W::new().await.unwrap_or_else(|e| {
+                panic!("failed to initialize `World`: {e}")
+            });
+            world.0 = Some(Box::new(routed));
+        }
+        let Some(world) = world.get_mut::<W>() else {
+            panic!("`Scenario` is routed to another `World` type");
+        };
+        match step {
+            step::Function::Async(f) => f(world, ctx).await,
+            step::Function::Sync(f) => f(world, ctx),
+        }
+    })
+}
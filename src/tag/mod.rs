@@ -8,11 +8,19 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Extension of a [`TagOperation`].
+//! [`gherkin`] tags utilities.
+//!
+//! Contains an extension of a [`TagOperation`] and [`Tags`] structured into
+//! `@key:value` or `@key(value)` pairs.
+
+mod structured;
 
 use gherkin::tagexpr::TagOperation;
 use sealed::sealed;
 
+#[doc(inline)]
+pub use self::structured::{Tag, Tags};
+
 /// Extension of a [`TagOperation`] allowing to evaluate it.
 #[sealed]
 pub trait Ext {
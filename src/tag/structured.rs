@@ -0,0 +1,221 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Structured `@key:value` and `@key(value)` tags.
+
+use std::{fmt, slice, str::FromStr};
+
+use gherkin::tagexpr::TagOperation;
+
+use crate::scenario::Ext as _;
+
+/// [`gherkin`] tag structured into a name and an optional value.
+///
+/// Both `@key:value` and `@key(value)` forms are recognized, while any other
+/// tag (like `@serial` or `@retry(3).after(5s)`) has no value and is named
+/// after its whole text.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Tag {
+    /// Name of this [`Tag`] (its whole text, if it has no value).
+    pub name: String,
+
+    /// Value of this [`Tag`], if any.
+    pub value: Option<String>,
+}
+
+impl Tag {
+    /// Parses the provided `tag` (without the leading `@`) into a [`Tag`].
+    #[must_use]
+    pub fn parse(tag: &str) -> Self {
+        let tag = tag.strip_prefix('@').unwrap_or(tag);
+        let (name, value) = if let Some((name, value)) = tag
+            .split_once('(')
+            .and_then(|(n, rest)| Some((n, rest.strip_suffix(')')?)))
+            .filter(|(_, v)| !v.contains([')', '(']))
+        {
+            (name, Some(value))
+        } else if let Some((name, value)) = tag.split_once(':') {
+            (name, Some(value))
+        } else {
+            (tag, None)
+        };
+        Self { name: name.to_owned(), value: value.map(str::to_owned) }
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(v) => write!(f, "@{}:{v}", self.name),
+            None => write!(f, "@{}", self.name),
+        }
+    }
+}
+
+/// Structured [`Tag`]s of a [`gherkin::Scenario`], including the ones
+/// inherited from its [`gherkin::Rule`] and [`gherkin::Feature`].
+///
+/// Lookups prefer the most specific [`Tag`], so a [`gherkin::Scenario`] tag
+/// overrides the same named [`gherkin::Rule`] or [`gherkin::Feature`] one.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::tag::Tags;
+/// #
+/// let tags = Tags::from_iter(["owner:payments", "timeout(30)", "serial"]);
+///
+/// assert_eq!(tags.value("owner"), Some("payments"));
+/// assert_eq!(tags.parse_value::<u64>("timeout"), Some(Ok(30)));
+/// assert!(tags.contains("serial"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Tags(Vec<Tag>);
+
+impl Tags {
+    /// Collects [`Tags`] of the provided [`gherkin::Scenario`], including the
+    /// ones inherited from its [`gherkin::Rule`] and [`gherkin::Feature`].
+    ///
+    /// Tags of the [`gherkin::Examples`] an expanded [`Scenario Outline`][1]
+    /// originates from are the most specific ones.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub fn of(
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> Self {
+        scenario
+            .examples_tags()
+            .iter()
+            .chain(&scenario.tags)
+            .chain(rule.iter().flat_map(|r| &r.tags))
+            .chain(&feature.tags)
+            .collect()
+    }
+
+    /// Returns the most specific [`Tag`] with the provided `name`, if any.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Tag> {
+        self.0.iter().find(|t| t.name == name)
+    }
+
+    /// Returns the value of the most specific [`Tag`] with the provided
+    /// `name`, if it has any.
+    #[must_use]
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.get(name)?.value.as_deref()
+    }
+
+    /// Parses the value of the most specific [`Tag`] with the provided
+    /// `name`, if it has any.
+    #[must_use]
+    pub fn parse_value<T: FromStr>(
+        &self,
+        name: &str,
+    ) -> Option<Result<T, T::Err>> {
+        self.value(name).map(str::parse)
+    }
+
+    /// Indicates whether a [`Tag`] with the provided `name` is present.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Evaluates the provided tag expression against these [`Tags`].
+    ///
+    /// Tags of the expression are compared structurally, so `@env:live` and
+    /// `@env(live)` are equal.
+    #[must_use]
+    pub fn matches(&self, expr: &TagOperation) -> bool {
+        match expr {
+            TagOperation::And(l, r) => self.matches(l) && self.matches(r),
+            TagOperation::Or(l, r) => self.matches(l) || self.matches(r),
+            TagOperation::Not(t) => !self.matches(t),
+            TagOperation::Tag(t) => self.0.contains(&Tag::parse(t)),
+        }
+    }
+
+    /// Returns an [`Iterator`] over all these [`Tags`], from the most specific
+    /// ones.
+    pub fn iter(&self) -> slice::Iter<'_, Tag> {
+        self.0.iter()
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for Tags {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self(iter.into_iter().map(|t| Tag::parse(t.as_ref())).collect())
+    }
+}
+
+impl<'t> IntoIterator for &'t Tags {
+    type Item = &'t Tag;
+    type IntoIter = slice::Iter<'t, Tag>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tag_forms() {
+        for (tag, name, value) in [
+            ("owner:payments", "owner", Some("payments")),
+            ("@case(JIRA-12)", "case", Some("JIRA-12")),
+            ("url:http://host", "url", Some("http://host")),
+            ("serial", "serial", None),
+            ("retry(3).after(5s)", "retry(3).after(5s)", None),
+        ] {
+            let parsed = Tag::parse(tag);
+
+            assert_eq!(parsed.name, name, "name of `{tag}`");
+            assert_eq!(parsed.value.as_deref(), value, "value of `{tag}`");
+        }
+    }
+
+    #[test]
+    fn prefers_most_specific_tag() {
+        let feature = gherkin::Feature::parse(
+            "@owner:core @timeout:10\n\
+             Feature: F\n\
+             \x20 @owner:payments\n  Scenario: S\n    Given a step\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap();
+
+        let tags = Tags::of(&feature, None, &feature.scenarios[0]);
+
+        assert_eq!(tags.value("owner"), Some("payments"));
+        assert_eq!(tags.parse_value::<u8>("timeout"), Some(Ok(10)));
+        assert_eq!(tags.value("unknown"), None);
+    }
+
+    #[test]
+    fn matches_tag_expressions() {
+        let tags = Tags::from_iter(["@live", "slow"]);
+
+        for (expr, expected) in [
+            ("@live", true),
+            ("@mock or @slow", true),
+            ("@live and not @slow", false),
+            ("not (@mock or @flaky)", true),
+        ] {
+            let expr = expr.parse().unwrap();
+            assert_eq!(tags.matches(&expr), expected, "{expr:?}");
+        }
+    }
+}
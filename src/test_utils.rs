@@ -7,6 +7,10 @@
 pub mod common {
     use std::future::Future;
 
+    use futures::future::LocalBoxFuture;
+
+    use crate::step::Context;
+
     /// Empty CLI implementation for tests that don't need CLI arguments.
     #[derive(Debug, Default, Clone)]
     pub struct EmptyCli;
@@ -45,4 +49,30 @@ pub mod common {
             Ok(Self)
         }
     }
+
+    /// No-op [`Step`] function of the [`TestWorld`].
+    ///
+    /// [`Step`]: crate::step::Step
+    pub fn test_step(_: &mut TestWorld, _: Context) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    /// Builds a [`gherkin::Step`] of the provided [`gherkin::StepType`] with
+    /// the provided `value` text.
+    pub fn step(ty: gherkin::StepType, value: &str) -> gherkin::Step {
+        let keyword = match ty {
+            gherkin::StepType::Given => "Given",
+            gherkin::StepType::When => "When",
+            gherkin::StepType::Then => "Then",
+        };
+        gherkin::Step {
+            keyword: keyword.into(),
+            ty,
+            value: value.into(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        }
+    }
 }
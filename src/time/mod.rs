@@ -0,0 +1,146 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Time measurement and timers, working on any platform and async runtime.
+//!
+//! On `wasm32-unknown-unknown` target (having neither a clock in [`std::time`]
+//! nor threads) the browser APIs are used instead.
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+mod timer;
+
+use std::{pin::Pin, task, time::Duration};
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use futures::FutureExt as _;
+use futures::{Stream, StreamExt as _};
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+use gloo_timers::future::IntervalStream;
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+pub(crate) use std::time::Instant;
+#[cfg(all(
+    feature = "timestamps",
+    not(all(target_family = "wasm", target_os = "unknown"))
+))]
+pub(crate) use std::time::SystemTime;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+pub(crate) use web_time::Instant;
+#[cfg(all(
+    feature = "timestamps",
+    target_family = "wasm",
+    target_os = "unknown"
+))]
+pub(crate) use web_time::SystemTime;
+
+/// Creates a new [`Ticks`] [`Stream`], yielding every `interval`.
+pub(crate) fn ticks(interval: Duration) -> Ticks {
+    Ticks::new(interval)
+}
+
+/// Sleeps for the provided [`Duration`].
+pub(crate) async fn sleep(dur: Duration) {
+    _ = ticks(dur).next().await;
+}
+
+/// [`Stream`] yielding every `interval`, being stopped once dropped.
+///
+/// The `interval` is measured by a single timer thread shared by all the
+/// [`Ticks`] to stay runtime-agnostic, so no thread is spawned per timer.
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+#[derive(Debug)]
+pub(crate) struct Ticks {
+    /// Interval between the ticks.
+    interval: Duration,
+
+    /// [`timer::Sleep`] until the next tick.
+    next: timer::Sleep,
+}
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+impl Ticks {
+    /// Schedules the first tick after the provided `interval`.
+    fn new(interval: Duration) -> Self {
+        let next = timer::Sleep::until(Instant::now() + interval);
+        Self { interval, next }
+    }
+
+    /// Polls the next tick, scheduling the following one once it happens.
+    fn poll_tick(&mut self, cx: &mut task::Context<'_>) -> task::Poll<()> {
+        task::ready!(self.next.poll_unpin(cx));
+        let deadline = self.next.deadline() + self.interval;
+        self.next = timer::Sleep::until(deadline);
+        task::Poll::Ready(())
+    }
+}
+
+/// [`Stream`] yielding every `interval`, being stopped once dropped.
+///
+/// The `interval` is measured via a JS `setInterval()` timer.
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+#[derive(Debug)]
+pub(crate) struct Ticks {
+    /// Stream of the JS `setInterval()` timer ticks.
+    ticks: IntervalStream,
+}
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+impl Ticks {
+    /// Schedules a JS `setInterval()` timer with the provided `interval`.
+    fn new(interval: Duration) -> Self {
+        let millis = u32::try_from(interval.as_millis()).unwrap_or(u32::MAX);
+        Self { ticks: IntervalStream::new(millis) }
+    }
+
+    /// Polls the next tick of the JS `setInterval()` timer.
+    fn poll_tick(&mut self, cx: &mut task::Context<'_>) -> task::Poll<()> {
+        self.ticks.poll_next_unpin(cx).map(drop)
+    }
+}
+
+impl Stream for Ticks {
+    type Item = ();
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Option<Self::Item>> {
+        self.poll_tick(cx).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt as _;
+
+    use super::{Instant, sleep, ticks};
+
+    #[tokio::test]
+    async fn ticks_every_interval() {
+        let started = Instant::now();
+
+        let ticked = ticks(Duration::from_millis(20)).take(3).count().await;
+
+        assert_eq!(ticked, 3);
+        assert!(started.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[tokio::test]
+    async fn sleeps() {
+        let started = Instant::now();
+
+        sleep(Duration::from_millis(50)).await;
+
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+}
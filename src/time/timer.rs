@@ -0,0 +1,198 @@
+//! Single timer thread shared by all the [`Sleep`]s.
+
+use std::{
+    collections::{BTreeMap, btree_map},
+    pin::Pin,
+    sync::{
+        Arc, Condvar, Mutex, MutexGuard, Once, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+    task, thread,
+};
+
+use futures::task::AtomicWaker;
+
+use super::Instant;
+
+/// [`Timer`] driving all the [`Sleep`]s.
+static TIMER: Timer = Timer {
+    state: Mutex::new(State { deadlines: BTreeMap::new(), next_id: 0 }),
+    changed: Condvar::new(),
+};
+
+/// Key of a scheduled [`Sleep`], ordering it by its deadline.
+type Key = (Instant, u64);
+
+/// Timer measuring deadlines of all the [`Sleep`]s in a single thread,
+/// spawned lazily on the first [`Sleep`].
+#[derive(Debug)]
+struct Timer {
+    /// [`State`] of this [`Timer`].
+    state: Mutex<State>,
+
+    /// [`Condvar`] notifying the timer thread about an earlier deadline.
+    changed: Condvar,
+}
+
+/// State of a [`Timer`].
+#[derive(Debug)]
+struct State {
+    /// Scheduled deadlines, ordered by their [`Instant`]s.
+    deadlines: BTreeMap<Key, Arc<Entry>>,
+
+    /// ID to disambiguate the next scheduled deadline with.
+    next_id: u64,
+}
+
+/// Deadline of a [`Sleep`], shared with the [`Timer`].
+#[derive(Debug, Default)]
+struct Entry {
+    /// Indicator whether the deadline has been reached.
+    fired: AtomicBool,
+
+    /// [`AtomicWaker`] of the task awaiting the [`Sleep`].
+    waker: AtomicWaker,
+}
+
+impl Timer {
+    /// Locks the [`State`] of this [`Timer`].
+    ///
+    /// Never panics while holding the lock, so its poisoning is ignored.
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Schedules a new [`Entry`] firing at the provided `deadline`.
+    fn schedule(&'static self, deadline: Instant) -> (Key, Arc<Entry>) {
+        static SPAWN: Once = Once::new();
+        SPAWN.call_once(|| drop(thread::spawn(move || self.run())));
+
+        let entry = Arc::new(Entry::default());
+        let mut state = self.state();
+        let key = (deadline, state.next_id);
+        state.next_id += 1;
+        let is_earliest =
+            state.deadlines.first_key_value().is_none_or(|(k, _)| key < *k);
+        drop(state.deadlines.insert(key, Arc::clone(&entry)));
+        drop(state);
+        if is_earliest {
+            self.changed.notify_one();
+        }
+        (key, entry)
+    }
+
+    /// Cancels the [`Entry`] scheduled by the provided [`Key`], if it hasn't
+    /// fired yet.
+    fn cancel(&self, key: &Key) {
+        drop(self.state().deadlines.remove(key));
+    }
+
+    /// Fires the reached deadlines, sleeping until the next one in between.
+    fn run(&self) -> ! {
+        let mut state = self.state();
+        loop {
+            let now = Instant::now();
+            while let Some(entry) = state
+                .deadlines
+                .first_entry()
+                .filter(|e| e.key().0 <= now)
+                .map(btree_map::OccupiedEntry::remove)
+            {
+                entry.fired.store(true, Ordering::Release);
+                entry.waker.wake();
+            }
+
+            state = match state.deadlines.first_key_value() {
+                Some(((deadline, _), _)) => {
+                    let timeout = deadline.saturating_duration_since(now);
+                    self.changed
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => self
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+}
+
+/// [`Future`] resolving once its deadline is reached, cancelled once dropped.
+#[derive(Debug)]
+pub(super) struct Sleep {
+    /// [`Key`] of the deadline scheduled in the [`Timer`].
+    key: Key,
+
+    /// [`Entry`] of the deadline shared with the [`Timer`].
+    entry: Arc<Entry>,
+}
+
+impl Sleep {
+    /// Schedules a new [`Sleep`] until the provided `deadline`.
+    pub(super) fn until(deadline: Instant) -> Self {
+        let (key, entry) = TIMER.schedule(deadline);
+        Self { key, entry }
+    }
+
+    /// Returns the deadline of this [`Sleep`].
+    pub(super) const fn deadline(&self) -> Instant {
+        self.key.0
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Self::Output> {
+        self.entry.waker.register(cx.waker());
+        if self.entry.fired.load(Ordering::Acquire) {
+            task::Poll::Ready(())
+        } else {
+            task::Poll::Pending
+        }
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if !self.entry.fired.load(Ordering::Acquire) {
+            TIMER.cancel(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fires_in_deadlines_order() {
+        let started = Instant::now();
+        let at = |ms| Sleep::until(started + Duration::from_millis(ms));
+        let (late, early) = (at(60), at(20));
+
+        early.await;
+        assert!(started.elapsed() >= Duration::from_millis(20));
+        assert!(!late.entry.fired.load(Ordering::Acquire));
+
+        late.await;
+        assert!(started.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[test]
+    fn cancels_once_dropped() {
+        let sleep = Sleep::until(Instant::now() + Duration::from_secs(60));
+        let key = sleep.key;
+
+        drop(sleep);
+
+        assert!(!TIMER.state().deadlines.contains_key(&key));
+    }
+}
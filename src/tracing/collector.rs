@@ -110,12 +110,12 @@ impl Collector {
         self.notify_about_closing_spans();
 
         self.logs_receiver.try_next().ok().flatten().map(|(id, msg)| {
-            id.and_then(|k| self.scenarios.get(&k))
+            id.and_then(|k| self.scenarios.get_key_value(&k))
                 .map_or_else(
-                    || Either::Left(self.scenarios.values()),
+                    || Either::Left(self.scenarios.iter()),
                     |p| Either::Right(std::iter::once(p)),
                 )
-                .map(|(f, r, s, opt)| {
+                .map(|(id, (f, r, s, opt))| {
                     event::Cucumber::scenario(
                         f.clone(),
                         r.clone(),
@@ -123,6 +123,7 @@ impl Collector {
                         event::RetryableScenario {
                             event: event::Scenario::Log(msg.clone()),
                             retries: opt.map(|o| o.retries),
+                            id: Some(*id),
                         },
                     )
                 })
@@ -156,7 +156,7 @@ mod tests {
     }
 
     impl crate::codegen::StepConstructor<TestWorld> for EmptyGiven {
-        fn inner(&self) -> (crate::step::Location, crate::codegen::LazyRegex, crate::step::Step<TestWorld>) {
+        fn inner(&self) -> (crate::step::Location, crate::codegen::LazyRegex, crate::step::Function<TestWorld>) {
             unreachable!("Test-only type")
         }
     }
@@ -172,7 +172,7 @@ mod tests {
     }
 
     impl crate::codegen::StepConstructor<TestWorld> for EmptyWhen {
-        fn inner(&self) -> (crate::step::Location, crate::codegen::LazyRegex, crate::step::Step<TestWorld>) {
+        fn inner(&self) -> (crate::step::Location, crate::codegen::LazyRegex, crate::step::Function<TestWorld>) {
             unreachable!("Test-only type")
         }
     }
@@ -188,7 +188,7 @@ mod tests {
     }
 
     impl crate::codegen::StepConstructor<TestWorld> for EmptyThen {
-        fn inner(&self) -> (crate::step::Location, crate::codegen::LazyRegex, crate::step::Step<TestWorld>) {
+        fn inner(&self) -> (crate::step::Location, crate::codegen::LazyRegex, crate::step::Function<TestWorld>) {
             unreachable!("Test-only type")
         }
     }
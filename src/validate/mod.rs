@@ -0,0 +1,277 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Validation of [`gherkin::Step`]s being covered by a [`step::Collection`],
+//! without executing them.
+//!
+//! Run via the `--validate` CLI option, or programmatically via
+//! [`validate()`].
+
+mod cli;
+
+use std::{collections::HashSet, fmt, path::PathBuf, slice};
+
+#[doc(inline)]
+pub use self::cli::Cli;
+use crate::{
+    event, parser,
+    step::{self, HashableRegex, Location},
+    tag::Tags,
+    writer::basic::trim_path,
+};
+
+/// Kind of an [`Issue`] found by [`validate()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IssueKind {
+    /// `.feature` file failed to be parsed.
+    Parsing,
+
+    /// [`gherkin::Step`] matches no [`step::Step`] definition.
+    Undefined,
+
+    /// [`gherkin::Step`] matches multiple [`step::Step`] definitions.
+    Ambiguous(Vec<(HashableRegex, Option<Location>)>),
+}
+
+/// Single problem found by [`validate()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Issue {
+    /// Kind of this [`Issue`].
+    pub kind: IssueKind,
+
+    /// Path to the `.feature` file of this [`Issue`], if known.
+    pub path: Option<PathBuf>,
+
+    /// Position of this [`Issue`] in the `.feature` file, if known.
+    pub position: Option<gherkin::LineCol>,
+
+    /// Text of the [`gherkin::Step`] (with its keyword), or a parsing error
+    /// message.
+    pub text: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self
+            .path
+            .as_ref()
+            .map(|p| trim_path(&p.display().to_string()).to_owned())
+            .unwrap_or_default();
+        match self.position {
+            Some(pos) => write!(f, "{path}:{}:{}: ", pos.line, pos.col)?,
+            None => write!(f, "{path}: ")?,
+        }
+        match &self.kind {
+            IssueKind::Parsing => write!(f, "{}", self.text),
+            IssueKind::Undefined => write!(f, "undefined step `{}`", self.text),
+            IssueKind::Ambiguous(matches) => {
+                write!(f, "ambiguous step `{}`, matching:", self.text)?;
+                for (re, loc) in matches {
+                    write!(f, "\n    {re}")?;
+                    if let Some(loc) = loc {
+                        write!(f, " --> {loc}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// All the [`Issue`]s found by [`validate()`].
+///
+/// [`fmt::Display`]s as one `path:line:col: problem` line per [`Issue`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report(Vec<Issue>);
+
+impl Report {
+    /// Returns all the [`Issue`]s of this [`Report`].
+    #[must_use]
+    pub fn issues(&self) -> &[Issue] {
+        &self.0
+    }
+
+    /// Returns an [`Iterator`] over all the [`Issue`]s of this [`Report`].
+    pub fn iter(&self) -> slice::Iter<'_, Issue> {
+        self.0.iter()
+    }
+
+    /// Indicates whether this [`Report`] contains no [`Issue`]s.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.0 {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'r> IntoIterator for &'r Report {
+    type Item = &'r Issue;
+    type IntoIter = slice::Iter<'r, Issue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Resolves every [`gherkin::Step`] of the provided parsed
+/// [`gherkin::Feature`]s against the provided [`step::Collection`], reporting
+/// undefined and ambiguous ones, along with [`parser::Error`]s.
+///
+/// Steps of expanded [`Scenario Outline`][1]s are reported once per distinct
+/// text.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{step, validate};
+/// # use regex::Regex;
+/// #
+/// # struct World;
+/// #
+/// let steps = step::Collection::<World>::new().given(
+///     None,
+///     Regex::new("^a cat$").unwrap(),
+///     |_, _| Box::pin(async {}),
+/// );
+/// let feature = gherkin::Feature::parse(
+///     "Feature: F\n  Scenario: S\n    Given a cat\n    And a dog\n",
+///     gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap();
+///
+/// let report = validate::validate(&steps, [Ok(feature)]);
+///
+/// assert_eq!(report.to_string(), ":4:5: undefined step `And a dog`\n");
+/// ```
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+pub fn validate<World>(
+    steps: &step::Collection<World>,
+    features: impl IntoIterator<Item = parser::Result<gherkin::Feature>>,
+) -> Report {
+    let mut issues = vec![];
+    for feature in features {
+        let feature = match feature {
+            Ok(f) => f,
+            Err(e) => {
+                let e = event::ParsingFailed::from(&e);
+                issues.push(Issue {
+                    kind: IssueKind::Parsing,
+                    path: e.path,
+                    position: e.position,
+                    text: e.message,
+                });
+                continue;
+            }
+        };
+
+        let mut seen = HashSet::new();
+        for (tags, st) in iter_steps(&feature) {
+            let kind = match steps.find_tagged(st, &tags) {
+                Ok(Some(_)) => continue,
+                Ok(None) => IssueKind::Undefined,
+                Err(e) => IssueKind::Ambiguous(e.possible_matches),
+            };
+            if !seen.insert((st.position, &st.keyword, &st.value)) {
+                continue;
+            }
+            issues.push(Issue {
+                kind,
+                path: feature.path.clone(),
+                position: Some(st.position),
+                text: format!("{} {}", st.keyword.trim(), st.value),
+            });
+        }
+    }
+    Report(issues)
+}
+
+/// Returns an [`Iterator`] over all the [`gherkin::Step`]s of the provided
+/// [`gherkin::Feature`] to be run for each of its [`gherkin::Scenario`]s
+/// (including [`gherkin::Background`] ones), along with the [`Tags`] of the
+/// [`gherkin::Scenario`].
+pub(crate) fn iter_steps(
+    feature: &gherkin::Feature,
+) -> impl Iterator<Item = (Tags, &gherkin::Step)> {
+    feature
+        .scenarios
+        .iter()
+        .map(|s| (None, s))
+        .chain(
+            feature
+                .rules
+                .iter()
+                .flat_map(|r| r.scenarios.iter().map(move |s| (Some(r), s))),
+        )
+        .flat_map(move |(rule, scenario)| {
+            let tags = Tags::of(feature, rule, scenario);
+            feature
+                .background
+                .iter()
+                .chain(rule.and_then(|r| r.background.as_ref()))
+                .flat_map(|bg| &bg.steps)
+                .chain(&scenario.steps)
+                .map(move |st| (tags.clone(), st))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::feature::Ext as _;
+
+    #[test]
+    fn reports_ambiguous_steps_once_with_definitions() {
+        let noop: step::Step<()> = |_, _| Box::pin(async {});
+        let steps = step::Collection::new()
+            .when(
+                Some(Location::new("steps.rs", 3, 1)),
+                Regex::new("^I feed the cat$").unwrap(),
+                noop,
+            )
+            .when(None, Regex::new("^I feed the (.+)$").unwrap(), noop)
+            .then(None, Regex::new("^it's (full|hungry)$").unwrap(), noop);
+        let feature = gherkin::Feature::parse(
+            "Feature: F\n\
+             \x20 Scenario Outline: S\n\
+             \x20   When I feed the cat\n\
+             \x20   Then it's <state>\n\
+             \x20   Examples:\n\
+             \x20     | state |\n\
+             \x20     | full  |\n\
+             \x20     | empty |\n\
+             \x20     | empty |\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+        .expand_examples()
+        .unwrap();
+
+        let report = validate(&steps, [Ok(feature)]);
+
+        assert_eq!(
+            report.to_string(),
+            ":3:5: ambiguous step `When I feed the cat`, matching:\n\
+             \x20   ^I feed the (.+)$\n\
+             \x20   ^I feed the cat$ --> steps.rs:3:1\n\
+             :4:5: undefined step `Then it's empty`\n",
+        );
+    }
+}
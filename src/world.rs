@@ -14,13 +14,19 @@
 //! state for Cucumber test runs, along with its associated methods and functionality.
 
 #[cfg(feature = "macros")]
-use std::{fmt::Debug, path::Path};
-use std::{fmt::{Display, Formatter, Result as FmtResult}, future::Future, error::Error, result::Result};
+use std::fmt::Debug;
+use std::{fmt::{Display, Formatter, Result as FmtResult}, future::{self, Future}, error::Error, result::Result};
+
+#[cfg(feature = "macros")]
+use gherkin::StepType;
 
 #[cfg(feature = "macros")]
 use crate::{
-    codegen::{StepConstructor as _, WorldInventory},
+    Parser,
+    codegen::{self, WorldInventory},
     cucumber::DefaultCucumber,
+    parser,
+    runner::basic::{AfterHookFn, BeforeHookFn},
     step::Collection,
 };
 
@@ -43,42 +49,72 @@ pub trait World: Sized + 'static {
     /// Creates a new [`crate::World`] instance.
     fn new() -> impl Future<Output = Result<Self, Self::Error>>;
 
+    /// Tears down this [`crate::World`] instance once its [scenario][0] is
+    /// finished, after the [`Cucumber::after()`] hook has run.
+    ///
+    /// Failing teardown is reported as a failed [`Cucumber::after()`] hook.
+    /// Does nothing by default.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference#descriptions
+    /// [`Cucumber::after()`]: crate::Cucumber::after
+    fn teardown(&mut self) -> impl Future<Output = Result<(), Self::Error>> {
+        future::ready(Ok(()))
+    }
+
     #[cfg(feature = "macros")]
     /// Returns runner for tests with auto-wired steps marked by [`crate::given`],
-    /// [`crate::when`] and [`crate::then`] attributes.
+    /// [`crate::when`] and [`crate::then`] attributes, along with the
+    /// [`StepBuilder`]s submitted via [`crate::discover_step_builder!`] macro.
+    ///
+    /// # Panics
+    ///
+    /// If a `tags` expression of an attribute is invalid.
+    ///
+    /// [`StepBuilder`]: crate::step::StepBuilder
     #[must_use]
     fn collection() -> Collection<Self>
     where
         Self: Debug + WorldInventory,
     {
-        let mut out = Collection::new();
+        let mut out = Collection::discovered();
 
         for given in inventory::iter::<Self::Given> {
-            let (loc, regex, fun) = given.inner();
-            out = out.given(Some(loc), regex(), fun);
+            out = codegen::register(out, StepType::Given, given);
         }
 
         for when in inventory::iter::<Self::When> {
-            let (loc, regex, fun) = when.inner();
-            out = out.when(Some(loc), regex(), fun);
+            out = codegen::register(out, StepType::When, when);
         }
 
         for then in inventory::iter::<Self::Then> {
-            let (loc, regex, fun) = then.inner();
-            out = out.then(Some(loc), regex(), fun);
+            out = codegen::register(out, StepType::Then, then);
         }
 
         out
     }
 
     #[cfg(feature = "macros")]
-    /// Returns default [`crate::Cucumber`] with all the auto-wired [`crate::step::Step`]s.
+    /// Returns default [`crate::Cucumber`] with all the auto-wired [`crate::step::Step`]s,
+    /// and the [`crate::before`] and [`crate::after`] hooks.
     #[must_use]
-    fn cucumber<I: AsRef<Path>>() -> DefaultCucumber<Self, I>
+    fn cucumber<I>() -> DefaultCucumber<Self, I>
     where
         Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
     {
-        crate::Cucumber::new().steps(Self::collection())
+        let cucumber = crate::Cucumber::new().steps(Self::collection());
+        let cucumber = if codegen::Hook::any_before::<Self>() {
+            let before: BeforeHookFn<Self> = codegen::run_before_hooks::<Self>;
+            cucumber.before(before)
+        } else {
+            cucumber
+        };
+        if codegen::Hook::any_after::<Self>() {
+            let after: AfterHookFn<Self> = codegen::run_after_hooks::<Self>;
+            cucumber.after(after)
+        } else {
+            cucumber
+        }
     }
 
     #[cfg(feature = "macros")]
@@ -93,13 +129,38 @@ pub trait World: Sized + 'static {
     /// [`crate::step::Step`] panicked.
     ///
     /// [`Feature`]: gherkin::Feature
-    fn run<I: AsRef<Path>>(input: I) -> impl Future<Output = ()>
+    fn run<I>(input: I) -> impl Future<Output = ()>
     where
         Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
     {
         Self::cucumber().run_and_exit(input)
     }
 
+    #[cfg(all(
+        feature = "blocking",
+        feature = "macros",
+        not(all(target_family = "wasm", target_os = "unknown"))
+    ))]
+    /// Runs [`crate::Cucumber`] on the current thread, blocking it until
+    /// finished, without any async runtime.
+    ///
+    /// See [`crate::Cucumber::run_blocking()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If encountered errors while parsing [`Feature`]s or at least one
+    /// [`crate::step::Step`] panicked.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn run_blocking<I>(input: I)
+    where
+        Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
+    {
+        Self::cucumber().run_and_exit_blocking(input);
+    }
+
     #[cfg(feature = "macros")]
     /// Runs [`crate::Cucumber`] with [`gherkin::Scenario`]s filter.
     ///
@@ -117,7 +178,7 @@ pub trait World: Sized + 'static {
     fn filter_run<I, F>(input: I, filter: F) -> impl Future<Output = ()>
     where
         Self: Debug + WorldInventory,
-        I: AsRef<Path>,
+        parser::Basic: Parser<I>,
         F: Fn(
                 &gherkin::Feature,
                 Option<&gherkin::Rule>,
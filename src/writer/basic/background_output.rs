@@ -6,6 +6,7 @@ use regex::CaptureLocations;
 
 use super::{
     basic_struct::Basic,
+    failed_output::FailedStep,
     formatting::{
         format_captures, format_str_with_indent, format_table, trim_path,
     },
@@ -40,23 +41,48 @@ impl<Out: io::Write> Basic<Out> {
             Step::Started => {
                 self.bg_step_started(bg)?;
             }
+            Step::Heartbeat { elapsed } => {
+                self.step_heartbeat(bg, *elapsed)?;
+            }
+            Step::Published(ev) => {
+                self.step_published(ev)?;
+            }
             Step::Passed { captures, .. } => {
                 self.bg_step_passed(sc, bg, &captures, retries)?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Skipped => {
-                self.bg_step_skipped(feat, bg)?;
+            Step::Skipped(reason) => {
+                self.bg_step_skipped(feat, bg, reason.as_ref())?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Failed { captures, location, world, error } => {
-                self.bg_step_failed(
-                    feat,
-                    bg,
-                    captures.as_ref(),
-                    *location,
-                    retries,
-                    world.as_ref(),
-                    error,
+            Step::NotRun => {
+                self.bg_step_not_run(feat, bg)?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::Failed {
+                captures,
+                location,
+                matched,
+                world,
+                error,
+                owner,
+                backtrace,
+                ..
+            } => {
+                self.failed_step(
+                    "✘> ",
+                    &FailedStep {
+                        feature: feat,
+                        step: bg,
+                        captures: captures.as_ref(),
+                        location: *location,
+                        matched: matched.as_deref(),
+                        owner: owner.as_deref(),
+                        retries,
+                        world: world.as_ref(),
+                        error,
+                        backtrace: backtrace.as_deref(),
+                    },
                 )?;
                 self.indent = self.indent.saturating_sub(4);
             }
@@ -102,6 +128,7 @@ impl<Out: io::Write> Basic<Out> {
                     .unwrap_or_default(),
                 indent = " ".repeat(self.indent.saturating_sub(2)),
             );
+            let out = self.with_scenario_prefix(&out);
             self.lines_to_clear += self.styles.lines_count(&out);
             self.output.write_line(&out)?;
         }
@@ -161,7 +188,7 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or_default(),
         );
 
-        self.output.write_line(style(format!(
+        self.write_scenario_line(style(format!(
             "{step_keyword}{step_value}{doc_str}{step_table}",
         )))
     }
@@ -175,11 +202,12 @@ impl<Out: io::Write> Basic<Out> {
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
+        reason: Option<&step::Skip>,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
-        self.output.write_line(self.styles.skipped(format!(
+        self.write_scenario_line(self.styles.skipped(format!(
             "{indent}?> {}{}{}{}\n\
-             {indent}   Background step failed: {}:{}:{}",
+             {indent}   Background step failed: {}:{}:{}{}",
             step.keyword,
             step.value,
             step.docstring
@@ -201,97 +229,34 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or(&feat.name),
             step.position.line,
             step.position.col,
+            reason.map(|r| format!(" ({r})")).unwrap_or_default(),
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
 
-    /// Outputs the [failed] [`Background`] [`crate::step::Step`].
+    /// Outputs the [not run] [`Background`] [`crate::step::Step`].
     ///
-    /// [failed]: event::Step::Failed
+    /// [not run]: event::Step::NotRun
     /// [`Background`]: gherkin::Background
     /// [`crate::step::Step`]: gherkin::Step
-    pub(super) fn bg_step_failed<W: Debug>(
+    pub(super) fn bg_step_not_run(
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
-        captures: Option<&CaptureLocations>,
-        loc: Option<step::Location>,
-        retries: Option<Retries>,
-        world: Option<&W>,
-        err: &event::StepError,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
-
-        let style = |s| {
-            if retries
-                .filter(|r| {
-                    r.left > 0 && !matches!(err, event::StepError::NotFound)
-                })
-                .is_some()
-            {
-                self.styles.bright().retry(s)
-            } else {
-                self.styles.err(s)
-            }
-        };
-
-        let indent = " ".repeat(self.indent.saturating_sub(3));
-        let step_keyword = style(format!("{indent}✘> {}", step.keyword));
-        let step_value = captures.map_or_else(
-            || style(step.value.clone()),
-            |capts| {
-                format_captures(
-                    &step.value,
-                    capts,
-                    |v| style(v.to_owned()),
-                    |v| style(self.styles.bold(v).to_string()),
-                )
-                .into()
-            },
-        );
-
-        let diagnostics = style(format!(
-            "{}{}\n\
-             {indent}   Step failed:\n\
-             {indent}   Defined: {}:{}:{}{}{}{}",
-            step.docstring
-                .as_ref()
-                .and_then(|doc| self.verbosity.shows_docstring().then(|| {
-                    format_str_with_indent(
-                        doc,
-                        self.indent.saturating_sub(3) + 3,
-                    )
-                }))
-                .unwrap_or_default(),
-            step.table
-                .as_ref()
-                .map(|t| format_table(t, self.indent))
-                .unwrap_or_default(),
+        self.write_scenario_line(self.styles.skipped(format!(
+            "{indent}-> {}{}\n\
+             {indent}   Background step not run: {}:{}:{}",
+            step.keyword,
+            step.value,
             feat.path
                 .as_ref()
                 .and_then(|p| p.to_str().map(trim_path))
                 .unwrap_or(&feat.name),
             step.position.line,
             step.position.col,
-            loc.map(|l| format!(
-                "\n{indent}   Matched: {}:{}:{}",
-                l.path, l.line, l.column,
-            ))
-            .unwrap_or_default(),
-            format_str_with_indent(
-                err.to_string(),
-                self.indent.saturating_sub(3) + 3,
-            ),
-            world
-                .map(|w| format_str_with_indent(
-                    format!("{w:#?}"),
-                    self.indent.saturating_sub(3) + 3,
-                ))
-                .filter(|_| self.verbosity.shows_world())
-                .unwrap_or_default(),
-        ));
-
-        self.output
-            .write_line(format!("{step_keyword}{step_value}{diagnostics}"))
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        )))
     }
 }
@@ -1,13 +1,16 @@
 //! Core Basic writer struct and constructors.
 
-use std::{fmt::Display, io};
+use std::{borrow::Cow, fmt::Display, io};
 
 use derive_more::with_trait::{Deref, DerefMut};
 
 use super::cli::{Cli, Coloring};
-use crate::writer::{
-    self, Ext as _, Verbosity,
-    out::{Styles, WriteStrExt as _},
+use crate::{
+    event::Suite,
+    writer::{
+        self, Ext as _, Verbosity,
+        out::{Styles, WriteStrExt as _},
+    },
 };
 
 /// Default [`crate::Writer`] implementation outputting to an [`io::Write`] implementor
@@ -41,6 +44,12 @@ pub struct Basic<Out: io::Write = io::Stdout> {
     /// Number of lines to clear.
     pub(super) lines_to_clear: usize,
 
+    /// Number of lines of the last outputted [`Heartbeat`] status line, to be
+    /// overwritten by the next one.
+    ///
+    /// [`Heartbeat`]: crate::event::Step::Heartbeat
+    pub(super) heartbeat_lines: usize,
+
     /// Buffer to be re-output after [`clear_last_lines_if_term_present()`][0].
     ///
     /// [0]: Self::clear_last_lines_if_term_present
@@ -48,6 +57,39 @@ pub struct Basic<Out: io::Write = io::Stdout> {
 
     /// [`Verbosity`] of this [`crate::Writer`].
     pub(super) verbosity: Verbosity,
+
+    /// Indicator whether only the summary of the run is outputted.
+    pub(super) quiet: bool,
+
+    /// Indicator whether the captured output of passed
+    /// [`gherkin::Scenario`]s is outputted too.
+    pub(super) show_output: bool,
+
+    /// Captured output ([`Scenario::Log`]s and [published] events) of the
+    /// currently outputted [`gherkin::Scenario`], not outputted unless it
+    /// fails.
+    ///
+    /// [`None`] if the output isn't captured.
+    ///
+    /// [published]: crate::event::Step::Published
+    /// [`Scenario::Log`]: crate::event::Scenario::Log
+    pub(super) captured: Option<String>,
+
+    /// [`Suite`] of the last outputted [`Feature`], if features are outputted
+    /// as a tree of suites.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub(super) suite: Option<Suite>,
+
+    /// Indicator whether the output lines of [`gherkin::Scenario`]s are
+    /// prefixed with their [`ScenarioId`]s.
+    ///
+    /// [`ScenarioId`]: crate::runner::basic::ScenarioId
+    pub(super) scenario_ids: bool,
+
+    /// Prefix of the output lines of the currently outputted
+    /// [`gherkin::Scenario`].
+    pub(super) scenario_prefix: String,
 }
 
 impl Basic {
@@ -100,10 +142,21 @@ impl<Out: io::Write> Basic<Out> {
             styles: Styles::new(),
             indent: 0,
             lines_to_clear: 0,
+            heartbeat_lines: 0,
             re_output_after_clear: String::new(),
             verbosity: verbosity.into(),
+            quiet: false,
+            show_output: false,
+            captured: None,
+            suite: None,
+            scenario_ids: false,
+            scenario_prefix: String::new(),
         };
-        basic.apply_cli(Cli { verbose: u8::from(basic.verbosity) + 1, color });
+        basic.apply_cli(Cli {
+            verbose: u8::from(basic.verbosity) + 1,
+            color,
+            ..Cli::default()
+        });
         basic
     }
 
@@ -115,7 +168,50 @@ impl<Out: io::Write> Basic<Out> {
             2 => self.verbosity = Verbosity::ShowWorld,
             _ => self.verbosity = Verbosity::ShowWorldAndDocString,
         }
+        self.quiet |= cli.quiet;
+        self.show_output |= cli.show_output;
         self.styles.apply_coloring(cli.color);
+        if cli.suites && self.suite.is_none() {
+            self.suite = Some(Suite::default());
+        }
+        self.scenario_ids |= cli.scenario_ids;
+    }
+
+    /// Prefixes each line of the provided `text` with the
+    /// [`Basic::scenario_prefix`], if any.
+    pub(super) fn with_scenario_prefix<'t>(
+        &self,
+        text: &'t str,
+    ) -> Cow<'t, str> {
+        if self.scenario_prefix.is_empty() {
+            return text.into();
+        }
+        let mut prefixed = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            prefixed.push_str(&self.scenario_prefix);
+            prefixed.push_str(line);
+        }
+        prefixed.into()
+    }
+
+    /// Writes the provided `line` of the currently outputted
+    /// [`gherkin::Scenario`], prefixed with the [`Basic::scenario_prefix`].
+    pub(super) fn write_scenario_line(
+        &mut self,
+        line: impl AsRef<str>,
+    ) -> io::Result<()> {
+        let line = line.as_ref();
+        let prefixed = self.with_scenario_prefix(line);
+        self.output.write_line(prefixed)
+    }
+
+    /// Outputs the [`Basic::captured`] output of the currently outputted
+    /// [`gherkin::Scenario`] once it has failed, so its further output isn't
+    /// captured anymore.
+    pub(super) fn flush_captured(&mut self) -> io::Result<()> {
+        self.captured
+            .take()
+            .map_or(Ok(()), |captured| self.output.write_str(captured))
     }
 
     /// Clears last `n` lines if [`Coloring`] is enabled.
@@ -128,6 +224,7 @@ impl<Out: io::Write> Basic<Out> {
             self.re_output_after_clear.clear();
             self.lines_to_clear = 0;
         }
+        self.heartbeat_lines = 0;
         Ok(())
     }
 
@@ -6,20 +6,81 @@ use smart_default::SmartDefault;
 
 use crate::cli::Colored;
 
+/// ID of the `--show-output` CLI option, shared between [`Cli`] and the
+/// [`libtest::Cli`] (when both are used, like in [`Libtest::or_basic()`]).
+///
+/// [`libtest::Cli`]: crate::writer::libtest::Cli
+/// [`Libtest::or_basic()`]: crate::writer::Libtest::or_basic
+pub(crate) const SHOW_OUTPUT: &str = "show_output";
+
+/// Adds the `--show-output` CLI option with the provided `help` to the
+/// provided [`clap::Command`], unless it has been added already.
+pub(crate) fn augment_show_output(
+    cmd: clap::Command,
+    help: &'static str,
+) -> clap::Command {
+    if cmd.get_arguments().any(|arg| arg.get_id() == SHOW_OUTPUT) {
+        return cmd;
+    }
+    cmd.arg(
+        clap::Arg::new(SHOW_OUTPUT)
+            .long("show-output")
+            .action(clap::ArgAction::SetTrue)
+            .global(true)
+            .help(help),
+    )
+}
+
 /// CLI options of a [`Basic`] [`crate::Writer`].
 ///
 /// [`Basic`]: super::Basic
 /// [`crate::Writer`]: crate::Writer
-#[derive(Clone, Copy, Debug, SmartDefault, clap::Args)]
-#[group(skip)]
+#[derive(Clone, Copy, Debug, SmartDefault)]
+#[expect(clippy::struct_excessive_bools, reason = "CLI flags")]
 pub struct Cli {
+    /// Outputs only the summary of the run, omitting features, scenarios and
+    /// steps.
+    pub quiet: bool,
+
     /// Verbosity of an output.
     ///
     /// `-v` is default verbosity, `-vv` additionally outputs world on failed
     /// steps, `-vvv` additionally outputs step's doc string (if present).
-    #[arg(short, action = clap::ArgAction::Count, global = true)]
     pub verbose: u8,
 
+    /// Outputs the captured output (logs and published events) of passed
+    /// scenarios too, not only of the failed ones.
+    pub show_output: bool,
+
+    /// Coloring policy for a console output.
+    #[default(Coloring::Auto)]
+    pub color: Coloring,
+
+    /// Outputs features nested into the directories of their files, as a tree
+    /// of suites.
+    pub suites: bool,
+
+    /// Prefixes the output lines of scenarios with their IDs, correlating
+    /// them with the logs of concurrently running scenarios.
+    pub scenario_ids: bool,
+}
+
+/// [`Cli`] options, except the `--show-output` one.
+#[derive(clap::Args)]
+#[group(skip)]
+struct Options {
+    /// Outputs only the summary of the run, omitting features, scenarios and
+    /// steps.
+    #[arg(short, long, conflicts_with = "verbose", global = true)]
+    quiet: bool,
+
+    /// Verbosity of an output.
+    ///
+    /// `-v` is default verbosity, `-vv` additionally outputs world on failed
+    /// steps, `-vvv` additionally outputs step's doc string (if present).
+    #[arg(short, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
     /// Coloring policy for a console output.
     #[arg(
         long,
@@ -27,8 +88,58 @@ pub struct Cli {
         default_value = "auto",
         global = true
     )]
-    #[default(Coloring::Auto)]
-    pub color: Coloring,
+    color: Coloring,
+
+    /// Outputs features nested into the directories of their files, as a tree
+    /// of suites.
+    #[arg(long, global = true)]
+    suites: bool,
+
+    /// Prefixes the output lines of scenarios with their IDs, correlating
+    /// them with the logs of concurrently running scenarios.
+    #[arg(long, global = true)]
+    scenario_ids: bool,
+}
+
+impl clap::FromArgMatches for Cli {
+    fn from_arg_matches(
+        matches: &clap::ArgMatches,
+    ) -> clap::error::Result<Self> {
+        let Options { quiet, verbose, color, suites, scenario_ids } =
+            Options::from_arg_matches(matches)?;
+        Ok(Self {
+            quiet,
+            verbose,
+            // Not removed from the `matches`, as may be shared with the
+            // `libtest::Cli`.
+            show_output: matches.get_flag(SHOW_OUTPUT),
+            color,
+            suites,
+            scenario_ids,
+        })
+    }
+
+    fn update_from_arg_matches(
+        &mut self,
+        matches: &clap::ArgMatches,
+    ) -> clap::error::Result<()> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl clap::Args for Cli {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        augment_show_output(
+            Options::augment_args(cmd),
+            "Outputs the captured output (logs and published events) of \
+             passed scenarios too, not only of the failed ones",
+        )
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
 }
 
 impl Colored for Cli {
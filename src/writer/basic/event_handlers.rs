@@ -25,17 +25,22 @@ where
         self.apply_cli(*cli);
 
         match event.map(Event::into_inner) {
-            Err(err) => self.parsing_failed(&err),
+            Err(err) => self.parsing_failed(event::ParsingFailed::from(&err)),
             Ok(
                 Cucumber::Started
                 | Cucumber::ParsingFinished { .. }
                 | Cucumber::Finished,
             ) => Ok(()),
+            Ok(Cucumber::Report(r)) => self.report(&r),
+            Ok(Cucumber::Feature(..)) if self.quiet => Ok(()),
             Ok(Cucumber::Feature(f, ev)) => match ev {
                 Feature::Started => self.feature_started(&f),
                 Feature::Scenario(sc, ev) => self.scenario(&f, &sc, &ev),
                 Feature::Rule(r, ev) => self.rule(&f, &r, ev),
-                Feature::Finished => Ok(()),
+                Feature::Finished => {
+                    self.indent = 0;
+                    Ok(())
+                }
             },
         }
         .unwrap_or_else(|e| {
@@ -0,0 +1,172 @@
+//! Failed step output handling for Basic writer.
+
+use std::{fmt::Debug, io};
+
+use regex::CaptureLocations;
+
+use super::{
+    basic_struct::Basic,
+    formatting::{
+        format_captures, format_matched, format_str_with_indent, format_table,
+        trim_path,
+    },
+};
+use crate::{
+    event::{self, Retries},
+    step,
+};
+
+/// [Failed] [`gherkin::Step`] to be outputted, along with its diagnostics.
+///
+/// [Failed]: event::Step::Failed
+pub(super) struct FailedStep<'e, W> {
+    /// [`gherkin::Feature`] of the failed [`gherkin::Step`].
+    pub(super) feature: &'e gherkin::Feature,
+
+    /// Failed [`gherkin::Step`] itself.
+    pub(super) step: &'e gherkin::Step,
+
+    /// [`CaptureLocations`] of the matched [`gherkin::Step`], if any.
+    pub(super) captures: Option<&'e CaptureLocations>,
+
+    /// [`step::Location`] of the matched [`crate::step::Step`] function, if
+    /// any.
+    pub(super) location: Option<step::Location>,
+
+    /// Details of the matched [`crate::step::Step`] function, if any.
+    pub(super) matched: Option<&'e event::StepMatch>,
+
+    /// [`step::Ownership`] of the matched [`crate::step::Step`] function, if
+    /// any.
+    pub(super) owner: Option<&'e step::Ownership>,
+
+    /// [`Retries`] of the [`gherkin::Scenario`], if any.
+    pub(super) retries: Option<Retries>,
+
+    /// `World` at the moment of the failure, if any.
+    pub(super) world: Option<&'e W>,
+
+    /// [`event::StepError`] the [`gherkin::Step`] failed with.
+    pub(super) error: &'e event::StepError,
+
+    /// Captured backtrace of the failure, if any.
+    pub(super) backtrace: Option<&'e str>,
+}
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to failed steps output only"
+)]
+impl<Out: io::Write> Basic<Out> {
+    /// Outputs the [failed] [`crate::step::Step`], prefixed with the provided
+    /// `marker`.
+    ///
+    /// [failed]: event::Step::Failed
+    /// [`crate::step::Step`]: gherkin::Step
+    pub(super) fn failed_step<W: Debug>(
+        &mut self,
+        marker: &str,
+        failed: &FailedStep<'_, W>,
+    ) -> io::Result<()> {
+        let FailedStep {
+            feature: feat,
+            step,
+            captures,
+            location: loc,
+            matched,
+            owner,
+            retries,
+            world,
+            error: err,
+            backtrace,
+        } = *failed;
+
+        self.clear_last_lines_if_term_present()?;
+        self.flush_captured()?;
+
+        let style = |s| {
+            if retries.is_some_and(|r| {
+                r.left > 0 && !matches!(err, event::StepError::NotFound)
+            }) {
+                self.styles.bright().retry(s)
+            } else {
+                self.styles.err(s)
+            }
+        };
+
+        let indent = " ".repeat(self.indent.saturating_sub(3));
+
+        let step_keyword = style(format!("{indent}{marker}{}", step.keyword));
+        let step_value = captures.map_or_else(
+            || style(step.value.clone()),
+            |capts| {
+                format_captures(
+                    &step.value,
+                    capts,
+                    |v| style(v.to_owned()),
+                    |v| style(self.styles.bold(v).to_string()),
+                )
+                .into()
+            },
+        );
+
+        let diagnostics = style(format!(
+            "{}{}\n\
+             {indent}   Step {}:\n\
+             {indent}   Defined: {}:{}:{}{}{}{}{}",
+            step.docstring
+                .as_ref()
+                .and_then(|doc| self.verbosity.shows_docstring().then(|| {
+                    format_str_with_indent(
+                        doc,
+                        self.indent.saturating_sub(3) + 3,
+                    )
+                }))
+                .unwrap_or_default(),
+            step.table
+                .as_ref()
+                .map(|t| format_table(t, self.indent))
+                .unwrap_or_default(),
+            if err.is_timeout() {
+                "timed out"
+            } else if err.is_pending() {
+                "pending"
+            } else {
+                "failed"
+            },
+            feat.path
+                .as_ref()
+                .and_then(|p| p.to_str().map(trim_path))
+                .unwrap_or(&feat.name),
+            step.position.line,
+            step.position.col,
+            format_matched(loc, matched, owner, &indent),
+            format_str_with_indent(
+                err.to_string(),
+                self.indent.saturating_sub(3) + 3,
+            ),
+            backtrace
+                .map(|bt| format!(
+                    "\n{indent}   Backtrace:{}",
+                    format_str_with_indent(
+                        bt,
+                        self.indent.saturating_sub(3) + 3,
+                    ),
+                ))
+                .unwrap_or_default(),
+            world
+                .map(|w| format_str_with_indent(
+                    format!("{w:#?}"),
+                    self.indent.saturating_sub(3) + 3,
+                ))
+                .filter(|_| self.verbosity.shows_world())
+                .unwrap_or_default(),
+        ));
+
+        self.write_scenario_line(format!(
+            "{step_keyword}{step_value}{diagnostics}"
+        ))
+    }
+}
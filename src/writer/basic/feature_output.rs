@@ -3,7 +3,7 @@
 use std::{fmt::Debug, io};
 
 use super::basic_struct::Basic;
-use crate::{event, writer::out::WriteStrExt as _};
+use crate::{event, feature::Ext as _, writer::out::WriteStrExt as _};
 
 impl<Out: io::Write> Basic<Out> {
     /// Outputs the [started] [`Feature`].
@@ -14,10 +14,36 @@ impl<Out: io::Write> Basic<Out> {
         &mut self,
         feature: &gherkin::Feature,
     ) -> io::Result<()> {
-        let out = format!("{}: {}", feature.keyword, feature.name);
+        if self.suite.is_some() {
+            self.suite_started(feature)?;
+        }
+        let out = format!(
+            "{indent}{}: {}",
+            feature.keyword,
+            feature.name,
+            indent = " ".repeat(self.indent),
+        );
         self.output.write_line(self.styles.ok(out))
     }
 
+    /// Outputs the directories of the [started] [`Feature`]'s [`Suite`], which
+    /// differ from the previous one, and indents the [`Feature`] under them.
+    ///
+    /// [started]: event::Feature::Started
+    /// [`Feature`]: gherkin::Feature
+    /// [`Suite`]: event::Suite
+    fn suite_started(&mut self, feature: &gherkin::Feature) -> io::Result<()> {
+        let suite = feature.suite();
+        let prev = self.suite.replace(suite.clone()).unwrap_or_default();
+        let depth = suite.common_depth(&prev);
+        for (n, dir) in suite.dirs().iter().enumerate().skip(depth) {
+            let out = format!("{}{dir}/", " ".repeat(n * 2));
+            self.output.write_line(self.styles.ok(out))?;
+        }
+        self.indent = suite.dirs().len() * 2;
+        Ok(())
+    }
+
     /// Outputs the [`Rule`]'s [started]/[scenario]/[finished] event.
     ///
     /// [finished]: event::Rule::Finished
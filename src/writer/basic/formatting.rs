@@ -1,35 +1,90 @@
 //! Formatting utilities for Basic writer output.
 
-use std::{borrow::Cow, cmp, env, fmt::Write, sync::LazyLock};
+use std::{
+    any::{self, Any},
+    borrow::Cow,
+    cmp, env,
+    error::Error as StdError,
+    fmt::Write,
+    sync::{Arc, LazyLock},
+};
 
 use itertools::Itertools as _;
 use regex::CaptureLocations;
 
-use crate::event::Info;
+use crate::{
+    event::{self, Info},
+    step,
+};
 
 /// Coerces error information into a readable string.
+///
+/// Besides [`String`] and [`str`] payloads of regular panics, recognizes
+/// [`Box`]`<dyn `[`Error`]`>` ones (rendered with their whole [`source()`]
+/// chain), [`Cow`]`<str>` and primitive values (passed to the
+/// [`panic_any()`]). Any other payload is rendered with its [`TypeId`], as its
+/// type name cannot be resolved at runtime.
+///
+/// [`Error`]: StdError
+/// [`panic_any()`]: std::panic::panic_any
+/// [`source()`]: StdError::source
+/// [`TypeId`]: std::any::TypeId
 pub fn coerce_error(err: &Info) -> Cow<'static, str> {
-    use std::any::Any;
+    // `catch_unwind()` payloads may be stored as a `Box<dyn Any>`.
+    let payload: &dyn Any = (**err)
+        .downcast_ref::<Box<dyn Any + Send>>()
+        .map_or(&**err, |boxed| &**boxed);
 
-    // First try direct downcast
-    if let Some(s) = (**err).downcast_ref::<String>() {
+    if let Some(s) = payload.downcast_ref::<String>() {
         return s.clone().into();
     }
-    if let Some(&s) = (**err).downcast_ref::<&str>() {
+    if let Some(&s) = payload.downcast_ref::<&str>() {
         return s.to_owned().into();
     }
+    if let Some(s) = payload.downcast_ref::<Cow<'static, str>>() {
+        return s.clone();
+    }
+    if let Some(s) = payload.downcast_ref::<Box<str>>() {
+        return s.to_string().into();
+    }
+    if let Some(e) = payload.downcast_ref::<Box<dyn StdError + Send + Sync>>() {
+        return error_chain(&**e).into();
+    }
+    if let Some(e) = payload.downcast_ref::<Box<dyn StdError + Send>>() {
+        return error_chain(&**e).into();
+    }
+    if let Some(e) = payload.downcast_ref::<Arc<dyn StdError + Send + Sync>>() {
+        return error_chain(&**e).into();
+    }
 
-    // Handle Box<dyn Any> from catch_unwind
-    if let Some(boxed) = (**err).downcast_ref::<Box<dyn Any + Send>>() {
-        if let Some(s) = boxed.downcast_ref::<String>() {
-            return s.clone().into();
-        }
-        if let Some(&s) = boxed.downcast_ref::<&str>() {
-            return s.to_owned().into();
-        }
+    /// Renders the `$ty`ped primitive payload along with its type name.
+    macro_rules! primitive {
+        ($($ty:ty),* $(,)?) => {$(
+            if let Some(v) = payload.downcast_ref::<$ty>() {
+                return format!("{v} ({})", any::type_name::<$ty>()).into();
+            }
+        )*};
+    }
+    primitive!(
+        bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+        usize, f32, f64,
+    );
+    if payload.is::<()>() {
+        return "()".into();
     }
 
-    "(Could not resolve panic payload)".into()
+    format!("(Could not resolve panic payload of {:?})", payload.type_id())
+        .into()
+}
+
+/// Renders the provided [`Error`] along with its whole [`source()`] chain.
+///
+/// [`Error`]: StdError
+/// [`source()`]: StdError::source
+fn error_chain(err: &(dyn StdError + 'static)) -> String {
+    itertools::iterate(Some(err), |e| e.and_then(StdError::source))
+        .while_some()
+        .join(": ")
 }
 
 /// Formats the given [`str`] by adding `indent`s to each line to prettify the
@@ -125,6 +180,49 @@ where
     formatted
 }
 
+/// Formats the [`step::Location`] of the matched [`crate::step::Step`]
+/// function along with its [`event::StepMatch`] and [`step::Ownership`] (if
+/// any).
+pub(super) fn format_matched(
+    loc: Option<step::Location>,
+    matched: Option<&event::StepMatch>,
+    owner: Option<&step::Ownership>,
+    indent: &str,
+) -> String {
+    let mut out = String::new();
+    match (matched, loc) {
+        (Some(m), Some(l)) => {
+            _ = write!(
+                out,
+                "\n{indent}   Matched: `{}` defined at {}:{}",
+                m.pattern, l.path, l.line,
+            );
+        }
+        (Some(m), None) => {
+            _ = write!(out, "\n{indent}   Matched: `{}`", m.pattern);
+        }
+        (None, Some(l)) => {
+            _ = write!(
+                out,
+                "\n{indent}   Matched: {}:{}:{}",
+                l.path, l.line, l.column,
+            );
+        }
+        (None, None) => {}
+    }
+    if let Some(m) = matched.filter(|m| !m.captures.is_empty()) {
+        _ = write!(
+            out,
+            "\n{indent}   Captures: {}",
+            step::context::format_capture_values(&m.captures),
+        );
+    }
+    if let Some(owner) = owner.filter(|o| **o != step::Ownership::default()) {
+        _ = write!(out, "\n{indent}   Owner: {owner}");
+    }
+    out
+}
+
 /// Trims start of the path if it matches the current project directory.
 pub fn trim_path(path: &str) -> &str {
     /// Path of the current project directory.
@@ -0,0 +1,46 @@
+//! Heartbeat output handling for Basic writer.
+
+use std::{io, time::Duration};
+
+use super::basic_struct::Basic;
+use crate::writer::out::WriteStrExt as _;
+
+impl<Out: io::Write> Basic<Out> {
+    /// Outputs the [heartbeat] of a long-running [`crate::step::Step`] as a
+    /// status line.
+    ///
+    /// If [`Coloring`] is enabled, the status line overwrites the previous one
+    /// and gets cleared by the later [passed]/[skipped]/[failed] events.
+    ///
+    /// [`Coloring`]: super::Coloring
+    /// [failed]: crate::event::Step::Failed
+    /// [heartbeat]: crate::event::Step::Heartbeat
+    /// [passed]: crate::event::Step::Passed
+    /// [skipped]: crate::event::Step::Skipped
+    /// [`crate::step::Step`]: gherkin::Step
+    pub(super) fn step_heartbeat(
+        &mut self,
+        step: &gherkin::Step,
+        elapsed: Duration,
+    ) -> io::Result<()> {
+        if self.styles.is_present && self.heartbeat_lines > 0 {
+            self.output.clear_last_lines(self.heartbeat_lines)?;
+            self.lines_to_clear =
+                self.lines_to_clear.saturating_sub(self.heartbeat_lines);
+        }
+
+        let out = format!(
+            "{indent}…  still running {}{} (elapsed {})",
+            step.keyword,
+            step.value,
+            humantime::format_duration(Duration::from_secs(elapsed.as_secs())),
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        );
+        let out = self.with_scenario_prefix(&out);
+        if self.styles.is_present {
+            self.heartbeat_lines = self.styles.lines_count(&out);
+            self.lines_to_clear += self.heartbeat_lines;
+        }
+        self.output.write_line(self.styles.skipped(out))
+    }
+}
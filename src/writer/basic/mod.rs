@@ -7,12 +7,19 @@ mod background_output;
 mod basic_struct;
 mod cli;
 mod event_handlers;
+mod failed_output;
 mod feature_output;
 mod formatting;
+mod heartbeat_output;
 mod output_formatter;
+mod published_output;
+mod report_output;
 mod scenario_output;
+mod sla_output;
 mod step_output;
 
 pub use basic_struct::Basic;
 pub use cli::{Cli, Coloring};
+#[cfg(feature = "libtest")]
+pub(crate) use cli::{SHOW_OUTPUT, augment_show_output};
 pub use formatting::{coerce_error, trim_path};
@@ -0,0 +1,29 @@
+//! Published custom events output handling for Basic writer.
+
+use std::io;
+
+use super::basic_struct::Basic;
+use crate::event;
+
+impl<Out: io::Write> Basic<Out> {
+    /// Outputs the [`event::Custom`] event [published] by a running
+    /// [`crate::step::Step`].
+    ///
+    /// Similarly to [`event::Scenario::Log`]s, it's re-outputted after
+    /// clearing the [started] [`crate::step::Step`], so stays above its
+    /// [passed]/[skipped]/[failed] status.
+    ///
+    /// [failed]: event::Step::Failed
+    /// [passed]: event::Step::Passed
+    /// [published]: event::Step::Published
+    /// [skipped]: event::Step::Skipped
+    /// [started]: event::Step::Started
+    /// [`crate::step::Step`]: gherkin::Step
+    pub(super) fn step_published(
+        &mut self,
+        ev: &event::Custom,
+    ) -> io::Result<()> {
+        let indent = " ".repeat(self.indent.saturating_sub(3));
+        self.emit_log(format!("{indent}»  {ev}\n"))
+    }
+}
@@ -0,0 +1,29 @@
+//! Run reports output handling for Basic writer.
+
+use std::io;
+
+use super::basic_struct::Basic;
+use crate::{event, writer::out::WriteStrExt as _};
+
+// TODO: Try remove on next Rust version update.
+#[expect(clippy::allow_attributes, reason = "`#[expect]` doesn't work here")]
+#[allow( // intentional
+    clippy::multiple_inherent_impl,
+    reason = "related to run reports output only"
+)]
+impl<Out: io::Write> Basic<Out> {
+    /// Outputs the [`event::Report`] about the whole run.
+    pub(super) fn report(&mut self, report: &event::Report) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        let out = match report {
+            event::Report::IterationFailed(_) => {
+                self.styles.err(report.to_string())
+            }
+            event::Report::HistoryNotSaved { .. } => {
+                self.styles.skipped(report.to_string())
+            }
+        };
+        self.output.write_line(out)
+    }
+}
@@ -26,26 +26,33 @@ impl<Out: io::Write> Basic<Out> {
     ) -> io::Result<()> {
         use event::{Hook, Scenario};
 
+        self.scenario_prefix = ev
+            .id
+            .filter(|_| self.scenario_ids)
+            .map(|id| format!("[#{id}] "))
+            .unwrap_or_default();
+
         let retries = ev.retries;
         match &ev.event {
             Scenario::Started => {
+                self.captured = (!self.show_output).then(String::new);
                 self.scenario_started(scenario, retries)?;
             }
-            Scenario::Hook(_, Hook::Started) => {
+            Scenario::Hook(_, _, Hook::Started) => {
                 self.indent += 4;
             }
-            Scenario::Hook(which, Hook::Failed(world, info)) => {
+            Scenario::Hook(which, scope, Hook::Failed(world, info)) => {
                 self.hook_failed(
                     feat,
                     scenario,
-                    *which,
+                    (*which, *scope),
                     retries,
                     world.as_ref(),
                     info,
                 )?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Scenario::Hook(_, Hook::Passed) => {
+            Scenario::Hook(_, _, Hook::Passed) => {
                 self.indent = self.indent.saturating_sub(4);
             }
             Scenario::Background(bg, ev) => {
@@ -54,22 +61,36 @@ impl<Out: io::Write> Basic<Out> {
             Scenario::Step(st, ev) => {
                 self.step(feat, scenario, st, ev, retries)?;
             }
-            Scenario::Finished => {
+            Scenario::Finished(_) => {
+                self.captured = None;
                 self.indent = self.indent.saturating_sub(2);
             }
             Scenario::Log(msg) => self.emit_log(msg)?,
+            Scenario::SlaViolated(violation) => self.sla_violated(violation)?,
         }
         Ok(())
     }
 
     /// Outputs the [`crate::event::Scenario::Log`].
+    ///
+    /// Unless [`Cli::show_output`] is specified, it's captured until the
+    /// [`gherkin::Scenario`] fails.
+    ///
+    /// [`Cli::show_output`]: super::Cli::show_output
     pub(super) fn emit_log(&mut self, msg: impl AsRef<str>) -> io::Result<()> {
-        self.lines_to_clear += self.styles.lines_count(msg.as_ref());
-        self.re_output_after_clear.push_str(msg.as_ref());
+        let msg = self.with_scenario_prefix(msg.as_ref());
+        if let Some(captured) = &mut self.captured {
+            captured.push_str(&msg);
+            return Ok(());
+        }
+        self.lines_to_clear += self.styles.lines_count(&msg);
+        self.heartbeat_lines = 0;
+        self.re_output_after_clear.push_str(&msg);
         self.output.write_str(msg)
     }
 
-    /// Outputs the [failed] [`gherkin::Scenario`]'s hook.
+    /// Outputs the [failed] [`gherkin::Scenario`]'s hook, naming what exactly
+    /// has failed according to its [`event::HookScope`].
     ///
     /// [failed]: event::Hook::Failed
     /// [`gherkin::Scenario`]: gherkin::Scenario
@@ -77,12 +98,13 @@ impl<Out: io::Write> Basic<Out> {
         &mut self,
         feat: &gherkin::Feature,
         sc: &gherkin::Scenario,
-        which: event::HookType,
+        (which, scope): (event::HookType, event::HookScope),
         retries: Option<Retries>,
         world: Option<&W>,
         info: &Info,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
+        self.flush_captured()?;
 
         let style = |s| {
             if retries.filter(|r| r.left > 0).is_some() {
@@ -92,8 +114,13 @@ impl<Out: io::Write> Basic<Out> {
             }
         };
 
-        self.output.write_line(style(format!(
-            "{indent}✘  Scenario's {which} hook failed {}:{}:{}\n\
+        let what = match (scope, which) {
+            (event::HookScope::Function, _) => format!("{which} hook"),
+            (_, event::HookType::Before) => format!("{scope} setup"),
+            (_, event::HookType::After) => format!("{scope} teardown"),
+        };
+        self.write_scenario_line(style(format!(
+            "{indent}✘  Scenario's {what} failed {}:{}:{}\n\
              {indent}   Captured output: {}{}",
             feat.path
                 .as_ref()
@@ -136,7 +163,7 @@ impl<Out: io::Write> Basic<Out> {
                 retries.current,
                 retries.left + retries.current,
             );
-            self.output.write_line(self.styles.retry(out))
+            self.write_scenario_line(self.styles.retry(out))
         } else {
             let out = format!(
                 "{}{}: {}",
@@ -144,7 +171,7 @@ impl<Out: io::Write> Basic<Out> {
                 scenario.keyword,
                 scenario.name,
             );
-            self.output.write_line(self.styles.ok(out))
+            self.write_scenario_line(self.styles.ok(out))
         }
     }
 }
@@ -0,0 +1,29 @@
+//! SLA violations output handling for Basic writer.
+
+use std::io;
+
+use super::basic_struct::Basic;
+use crate::event;
+
+impl<Out: io::Write> Basic<Out> {
+    /// Outputs the [`event::SlaViolation`] of a [`gherkin::Scenario`].
+    ///
+    /// It's styled as an error only if it fails the [`gherkin::Scenario`],
+    /// and as a warning otherwise.
+    pub(super) fn sla_violated(
+        &mut self,
+        violation: &event::SlaViolation,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        let out = format!(
+            "{}⏱  {violation}",
+            " ".repeat(self.indent.saturating_sub(3)),
+        );
+        self.write_scenario_line(if violation.fails_scenario {
+            self.styles.err(out)
+        } else {
+            self.styles.skipped(out)
+        })
+    }
+}
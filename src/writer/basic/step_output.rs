@@ -6,6 +6,7 @@ use regex::CaptureLocations;
 
 use super::{
     basic_struct::Basic,
+    failed_output::FailedStep,
     formatting::{
         format_captures, format_str_with_indent, format_table, trim_path,
     },
@@ -38,23 +39,48 @@ impl<Out: io::Write> Basic<Out> {
             Step::Started => {
                 self.step_started(step)?;
             }
+            Step::Heartbeat { elapsed } => {
+                self.step_heartbeat(step, *elapsed)?;
+            }
+            Step::Published(ev) => {
+                self.step_published(ev)?;
+            }
             Step::Passed { captures, .. } => {
                 self.step_passed(sc, step, &captures, retries)?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Skipped => {
-                self.step_skipped(feat, step)?;
+            Step::Skipped(reason) => {
+                self.step_skipped(feat, step, reason.as_ref())?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Failed { captures, location, world, error } => {
-                self.step_failed(
-                    feat,
-                    step,
-                    captures.as_ref(),
-                    *location,
-                    retries,
-                    world.as_ref(),
-                    error,
+            Step::NotRun => {
+                self.step_not_run(feat, step)?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::Failed {
+                captures,
+                location,
+                matched,
+                world,
+                error,
+                owner,
+                backtrace,
+                ..
+            } => {
+                self.failed_step(
+                    "✘  ",
+                    &FailedStep {
+                        feature: feat,
+                        step,
+                        captures: captures.as_ref(),
+                        location: *location,
+                        matched: matched.as_deref(),
+                        owner: owner.as_deref(),
+                        retries,
+                        world: world.as_ref(),
+                        error,
+                        backtrace: backtrace.as_deref(),
+                    },
                 )?;
                 self.indent = self.indent.saturating_sub(4);
             }
@@ -99,6 +125,7 @@ impl<Out: io::Write> Basic<Out> {
                     .unwrap_or_default(),
                 indent = " ".repeat(self.indent),
             );
+            let out = self.with_scenario_prefix(&out);
             self.lines_to_clear += self.styles.lines_count(&out);
             self.output.write_line(&out)?;
         }
@@ -155,7 +182,7 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or_default(),
         );
 
-        self.output.write_line(style(format!(
+        self.write_scenario_line(style(format!(
             "{indent}{step_keyword}{step_value}{doc_str}{step_table}",
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
@@ -169,11 +196,12 @@ impl<Out: io::Write> Basic<Out> {
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
+        reason: Option<&step::Skip>,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
-        self.output.write_line(self.styles.skipped(format!(
+        self.write_scenario_line(self.styles.skipped(format!(
             "{indent}?  {}{}{}{}\n\
-             {indent}   Step skipped: {}:{}:{}",
+             {indent}   Step skipped: {}:{}:{}{}",
             step.keyword,
             step.value,
             step.docstring
@@ -195,97 +223,33 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or(&feat.name),
             step.position.line,
             step.position.col,
+            reason.map(|r| format!(" ({r})")).unwrap_or_default(),
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
 
-    /// Outputs the [failed] [`crate::step::Step`].
+    /// Outputs the [not run] [`crate::step::Step`].
     ///
-    /// [failed]: event::Step::Failed
+    /// [not run]: event::Step::NotRun
     /// [`crate::step::Step`]: gherkin::Step
-    pub(super) fn step_failed<W: Debug>(
+    pub(super) fn step_not_run(
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
-        captures: Option<&CaptureLocations>,
-        loc: Option<step::Location>,
-        retries: Option<Retries>,
-        world: Option<&W>,
-        err: &event::StepError,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
-
-        let style = |s| {
-            if retries
-                .filter(|r| {
-                    r.left > 0 && !matches!(err, event::StepError::NotFound)
-                })
-                .is_some()
-            {
-                self.styles.bright().retry(s)
-            } else {
-                self.styles.err(s)
-            }
-        };
-
-        let indent = " ".repeat(self.indent.saturating_sub(3));
-
-        let step_keyword = style(format!("{indent}✘  {}", step.keyword));
-        let step_value = captures.map_or_else(
-            || style(step.value.clone()),
-            |capts| {
-                format_captures(
-                    &step.value,
-                    capts,
-                    |v| style(v.to_owned()),
-                    |v| style(self.styles.bold(v).to_string()),
-                )
-                .into()
-            },
-        );
-
-        let diagnostics = style(format!(
-            "{}{}\n\
-             {indent}   Step failed:\n\
-             {indent}   Defined: {}:{}:{}{}{}{}",
-            step.docstring
-                .as_ref()
-                .and_then(|doc| self.verbosity.shows_docstring().then(|| {
-                    format_str_with_indent(
-                        doc,
-                        self.indent.saturating_sub(3) + 3,
-                    )
-                }))
-                .unwrap_or_default(),
-            step.table
-                .as_ref()
-                .map(|t| format_table(t, self.indent))
-                .unwrap_or_default(),
+        self.write_scenario_line(self.styles.skipped(format!(
+            "{indent}-  {}{}\n\
+             {indent}   Step not run: {}:{}:{}",
+            step.keyword,
+            step.value,
             feat.path
                 .as_ref()
                 .and_then(|p| p.to_str().map(trim_path))
                 .unwrap_or(&feat.name),
             step.position.line,
             step.position.col,
-            loc.map(|l| format!(
-                "\n{indent}   Matched: {}:{}:{}",
-                l.path, l.line, l.column,
-            ))
-            .unwrap_or_default(),
-            format_str_with_indent(
-                err.to_string(),
-                self.indent.saturating_sub(3) + 3,
-            ),
-            world
-                .map(|w| format_str_with_indent(
-                    format!("{w:#?}"),
-                    self.indent.saturating_sub(3) + 3,
-                ))
-                .filter(|_| self.verbosity.shows_world())
-                .unwrap_or_default(),
-        ));
-
-        self.output
-            .write_line(format!("{step_keyword}{step_value}{diagnostics}"))
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        )))
     }
 }
@@ -80,9 +80,13 @@ impl WriterStats {
 
         match event {
             event::Step::Passed { .. } => self.record_passed_step(),
-            event::Step::Skipped => self.record_skipped_step(),
+            event::Step::Skipped(_) | event::Step::NotRun => {
+                self.record_skipped_step();
+            }
             event::Step::Failed { .. } => self.record_failed_step(),
-            event::Step::Started => {} // No stats change
+            event::Step::Started
+            | event::Step::Heartbeat { .. }
+            | event::Step::Published(_) => {} // No stats change
         }
     }
 
@@ -231,8 +235,11 @@ mod tests {
 
         // Test passed step - create a simple CaptureLocations
         let captures = regex::Regex::new(r"test").unwrap().capture_locations();
-        let passed_event: event::Step<i32> =
-            event::Step::Passed { captures, location: None };
+        let passed_event: event::Step<i32> = event::Step::Passed {
+            captures,
+            location: None,
+            timing: event::StepTiming::default(),
+        };
         stats.update_from_step_event(&passed_event, Some(&retries));
         assert_eq!(stats.passed_steps, 1);
         assert_eq!(stats.retried_steps, 1); // Should record retry
@@ -241,14 +248,18 @@ mod tests {
         let failed_event: event::Step<i32> = event::Step::Failed {
             captures: None,
             location: None,
+            matched: None,
             world: None,
             error: crate::event::StepError::NotFound,
+            owner: None,
+            timing: event::StepTiming::default(),
+            backtrace: None,
         };
         stats.update_from_step_event(&failed_event, None);
         assert_eq!(stats.failed_steps, 1);
 
         // Test skipped step - it's a unit variant
-        let skipped_event: event::Step<i32> = event::Step::Skipped;
+        let skipped_event: event::Step<i32> = event::Step::Skipped(None);
         stats.update_from_step_event(&skipped_event, None);
         assert_eq!(stats.skipped_steps, 1);
 
@@ -0,0 +1,275 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`crate::Writer`]-wrapper for outputting events in a deterministic order.
+
+use std::{collections::HashMap, mem};
+
+use derive_more::with_trait::Deref;
+
+use crate::{
+    Event, Writer,
+    event::{self, Source},
+    parser, writer,
+};
+
+/// Position of a [`gherkin`] item in its `.feature` file, as `(line, col)`.
+type Position = (usize, usize);
+
+/// Key of an [`event::Feature`] in the deterministic order.
+type OrderKey = (u8, Position, u8, Position, usize);
+
+/// [`crate::Writer`]-wrapper for outputting events in a deterministic order,
+/// not depending on the concurrency of their execution.
+///
+/// Buffers all the [`Feature`] events until [`event::Cucumber::Finished`], and
+/// then passes them to the wrapped [`crate::Writer`] in the following order:
+/// 1. [`Feature`]s, ordered by their path and position.
+/// 2. [`Rule`]s and [`gherkin::Scenario`]s of every [`Feature`], ordered by
+///    their position in the `.feature` file.
+/// 3. [`gherkin::Scenario`]s of every [`Rule`], ordered by their position in
+///    the `.feature` file.
+/// 4. All the events of every [`gherkin::Scenario`] (hooks, [`Step`]s,
+///    logs, etc.) uninterruptedly, in the order they've happened. Events of
+///    its retries follow the ones of the previous attempt.
+/// 5. [`event::Report`]s about the whole run, in the order they've happened.
+///
+/// [`event::Cucumber::Started`], [`event::Cucumber::ParsingFinished`] and
+/// parsing errors are passed to the wrapped [`crate::Writer`] immediately.
+///
+/// Intended for golden-file tests of [`crate::Writer`]s output and other
+/// consumers requiring reproducible output. As nothing is output until the
+/// whole run finishes, prefer [`Normalize`] for an interactive output.
+///
+/// > ⚠️ __WARNING__: Only the order of events is deterministic. Their contents
+/// >                 may still vary between runs (like timings, or a number
+/// >                 of [`Step::Heartbeat`]s).
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Normalize`]: writer::Normalize
+/// [`Rule`]: gherkin::Rule
+/// [`Step`]: gherkin::Step
+/// [`Step::Heartbeat`]: event::Step::Heartbeat
+#[derive(Debug, Deref)]
+pub struct Deterministic<World, Writer> {
+    /// Original [`crate::Writer`] to pass the ordered events into.
+    #[deref]
+    writer: Writer,
+
+    /// Buffered events of every [`gherkin::Feature`], in the order they've
+    /// started.
+    features: Vec<(Source<gherkin::Feature>, Vec<FeatureEvent<World>>)>,
+
+    /// Order in which [`gherkin::Scenario`]s have been seen, used to keep
+    /// events of [`gherkin::Scenario`]s sharing the same position apart.
+    scenarios: HashMap<Source<gherkin::Scenario>, usize>,
+
+    /// Buffered [`event::Report`]s, in the order they've happened.
+    reports: Vec<Event<event::Report>>,
+
+    /// Indicator whether [`event::Cucumber::Finished`] has been passed to the
+    /// wrapped [`crate::Writer`] already.
+    finished: bool,
+}
+
+/// Buffered [`event::Feature`] along with its [`OrderKey`].
+type FeatureEvent<W> = (OrderKey, Event<event::Feature<W>>);
+
+impl<W, Wr> Deterministic<W, Wr> {
+    /// Creates a new [`Deterministic`] wrapper, which will reorder [`event`]s
+    /// and feed them to the given [`crate::Writer`].
+    #[must_use]
+    pub fn new(writer: Wr) -> Self {
+        Self {
+            writer,
+            features: Vec::new(),
+            scenarios: HashMap::new(),
+            reports: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Returns the original [`crate::Writer`], wrapped by this
+    /// [`Deterministic`] one.
+    #[must_use]
+    pub const fn inner_writer(&self) -> &Wr {
+        &self.writer
+    }
+
+    /// Returns the [`OrderKey`] of the provided [`event::Feature`].
+    fn order_key(&mut self, ev: &event::Feature<W>) -> OrderKey {
+        use event::{Feature, Rule};
+
+        let pos = |p: gherkin::LineCol| (p.line, p.col);
+        let mut seen = |sc: &Source<gherkin::Scenario>| {
+            let next = self.scenarios.len();
+            *self.scenarios.entry(sc.clone()).or_insert(next)
+        };
+
+        match ev {
+            Feature::Started => (0, (0, 0), 0, (0, 0), 0),
+            Feature::Scenario(sc, _) => {
+                (1, pos(sc.position), 1, pos(sc.position), seen(sc))
+            }
+            Feature::Rule(r, Rule::Started) => {
+                (1, pos(r.position), 0, (0, 0), 0)
+            }
+            Feature::Rule(r, Rule::Scenario(sc, _)) => {
+                (1, pos(r.position), 1, pos(sc.position), seen(sc))
+            }
+            Feature::Rule(r, Rule::Finished) => {
+                (1, pos(r.position), 2, (0, 0), 0)
+            }
+            Feature::Finished => (2, (0, 0), 0, (0, 0), 0),
+        }
+    }
+}
+
+impl<World, Wr: Writer<World>> Writer<World> for Deterministic<World, Wr> {
+    type Cli = Wr::Cli;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<World>>>,
+        cli: &Self::Cli,
+    ) {
+        use event::Cucumber;
+
+        // Once `Cucumber::Finished` is emitted, we just pass events through,
+        // the same way `writer::Normalize` does.
+        if self.finished {
+            self.writer.handle_event(event, cli).await;
+            return;
+        }
+
+        match event.map(Event::split) {
+            res @ (Err(_)
+            | Ok((
+                Cucumber::Started | Cucumber::ParsingFinished { .. },
+                _,
+            ))) => {
+                self.writer
+                    .handle_event(res.map(|(ev, meta)| meta.insert(ev)), cli)
+                    .await;
+            }
+            Ok((Cucumber::Feature(f, ev), meta)) => {
+                let key = self.order_key(&ev);
+                let ev = meta.wrap(ev);
+                if let Some((_, events)) =
+                    self.features.iter_mut().find(|(feat, _)| *feat == f)
+                {
+                    events.push((key, ev));
+                } else {
+                    self.features.push((f, vec![(key, ev)]));
+                }
+            }
+            Ok((Cucumber::Report(r), meta)) => {
+                self.reports.push(meta.wrap(r));
+            }
+            Ok((Cucumber::Finished, meta)) => {
+                let mut features = mem::take(&mut self.features);
+                features.sort_by(|(a, _), (b, _)| {
+                    (&a.path, a.position.line, a.position.col).cmp(&(
+                        &b.path,
+                        b.position.line,
+                        b.position.col,
+                    ))
+                });
+                for (f, mut events) in features {
+                    // Sorting is stable, so events of the same item preserve
+                    // their happened-before order.
+                    events.sort_by_key(|(key, _)| *key);
+                    for (_, ev) in events {
+                        let ev = ev.map(|ev| Cucumber::Feature(f.clone(), ev));
+                        self.writer.handle_event(Ok(ev), cli).await;
+                    }
+                }
+                for ev in mem::take(&mut self.reports) {
+                    let ev = ev.map(Cucumber::Report);
+                    self.writer.handle_event(Ok(ev), cli).await;
+                }
+                self.scenarios.clear();
+                self.finished = true;
+                self.writer
+                    .handle_event(Ok(meta.wrap(Cucumber::Finished)), cli)
+                    .await;
+            }
+        }
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<W, Wr, Val> writer::Arbitrary<W, Val> for Deterministic<W, Wr>
+where
+    Wr: writer::Arbitrary<W, Val>,
+{
+    async fn write(&mut self, val: Val) {
+        self.writer.write(val).await;
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<W, Wr> writer::Stats<W> for Deterministic<W, Wr>
+where
+    Wr: writer::Stats<W>,
+    Self: Writer<W>,
+{
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn failed_steps(&self) -> usize {
+        self.writer.failed_steps()
+    }
+
+    fn retried_steps(&self) -> usize {
+        self.writer.retried_steps()
+    }
+
+    fn timed_out_steps(&self) -> usize {
+        self.writer.timed_out_steps()
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.writer.undefined_steps()
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.writer.parsing_errors()
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.writer.hook_errors()
+    }
+
+    fn sla_violations(&self) -> usize {
+        self.writer.sla_violations()
+    }
+
+    fn execution_has_failed(&self) -> bool {
+        self.writer.execution_has_failed()
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<W, Wr: writer::NonTransforming> writer::NonTransforming
+    for Deterministic<W, Wr>
+{
+}
+
+impl<W, Wr> writer::Normalized for Deterministic<W, Wr> {}
@@ -70,6 +70,18 @@ where
         self.0.retried_steps()
     }
 
+    fn timed_out_steps(&self) -> usize {
+        self.0.timed_out_steps()
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.0.undefined_steps()
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.0.pending_steps()
+    }
+
     fn parsing_errors(&self) -> usize {
         self.0.parsing_errors()
     }
@@ -78,6 +90,10 @@ where
         self.0.hook_errors()
     }
 
+    fn sla_violations(&self) -> usize {
+        self.0.sla_violations()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.0.execution_has_failed()
     }
@@ -157,6 +173,19 @@ where
         0
     }
 
+    /// Always returns `0`.
+    fn timed_out_steps(&self) -> usize {
+        0
+    }
+
+    fn undefined_steps(&self) -> usize {
+        0
+    }
+
+    fn pending_steps(&self) -> usize {
+        0
+    }
+
     /// Always returns `0`.
     fn parsing_errors(&self) -> usize {
         0
@@ -166,6 +195,10 @@ where
     fn hook_errors(&self) -> usize {
         0
     }
+
+    fn sla_violations(&self) -> usize {
+        0
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
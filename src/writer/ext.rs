@@ -17,7 +17,7 @@
 use sealed::sealed;
 
 use super::{
-    AssertNormalized, FailOnSkipped, Normalize, Repeat, Summarize, Tee, Writer,
+    AssertNormalized, Deterministic, FailOnSkipped, Normalize, Repeat, Summarize, Tee, Writer,
     discard,
 };
 use crate::{Event, event, parser};
@@ -50,6 +50,13 @@ pub trait Ext: Sized {
     #[must_use]
     fn normalized<W>(self) -> Normalize<W, Self>;
 
+    /// Wraps this [`crate::Writer`] into a [`Deterministic`] version, outputting
+    /// events in the same order regardless of their execution concurrency.
+    ///
+    /// See [`Deterministic`] for more information.
+    #[must_use]
+    fn deterministic<W>(self) -> Deterministic<W, Self>;
+
     /// Wraps this [`crate::Writer`] to print a summary at the end of an output.
     ///
     /// See [`Summarize`] for more information.
@@ -166,6 +173,10 @@ impl<T> Ext for T {
         Normalize::new(self)
     }
 
+    fn deterministic<W>(self) -> Deterministic<W, Self> {
+        Deterministic::new(self)
+    }
+
     fn summarized(self) -> Summarize<Self> {
         Summarize::from(self)
     }
@@ -14,6 +14,8 @@
 //! [`Skipped`]: event::Step::Skipped
 //! [`crate::step::Step`]: gherkin::Step
 
+use std::cell::Cell;
+
 use derive_more::with_trait::Deref;
 
 use crate::{
@@ -24,7 +26,11 @@ use crate::{
 
 /// [`crate::Writer`]-wrapper for transforming [`Skipped`] [`crate::step::Step`]s into [`Failed`].
 ///
+/// [`crate::step::Step`]s skipped intentionally with a [`step::Skip`] reason
+/// (and the ones following them) are never transformed.
+///
 /// [`Failed`]: event::Step::Failed
+/// [`step::Skip`]: crate::step::Skip
 /// [`Skipped`]: event::Step::Skipped
 /// [`crate::step::Step`]: gherkin::Step
 #[derive(Clone, Copy, Debug, Deref)]
@@ -39,6 +45,19 @@ pub struct FailOnSkipped<W, F = SkipFn> {
     /// [`Failed`]: event::Step::Failed
     /// [`Skipped`]: event::Step::Skipped
     should_fail: F,
+
+    /// Number of [`Skipped`] [`crate::step::Step`]s transformed into
+    /// [`Failed`] ones.
+    ///
+    /// These are reported with a [`StepError::NotFound`], so are counted as
+    /// [`undefined_steps()`] by the original [`crate::Writer`].
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`StepError::NotFound`]: event::StepError::NotFound
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [`undefined_steps()`]: writer::Stats::undefined_steps
+    transformed: usize,
 }
 
 /// Alias for a [`fn`] used to determine whether [`Skipped`] test should be
@@ -71,28 +90,45 @@ where
             StepError::NotFound,
         };
 
+        let transformed = Cell::new(0);
         let map_failed = |f: &Source<_>, r: &Option<_>, sc: &Source<_>| {
             if (self.should_fail)(f, r.as_deref(), sc) {
+                transformed.set(transformed.get() + 1);
                 Step::Failed {
                     captures: None,
                     location: None,
+                    matched: None,
                     world: None,
                     error: NotFound,
+                    owner: None,
+                    timing: event::StepTiming::default(),
+                    backtrace: None,
                 }
             } else {
-                Step::Skipped
+                Step::Skipped(None)
             }
         };
-        let map_failed_bg =
-            |f: Source<_>, r: Option<_>, sc: Source<_>, st: _, ret| {
-                let ev = map_failed(&f, &r, &sc);
-                let ev = Scenario::Background(st, ev).with_retries(ret);
-                Cucumber::scenario(f, r, sc, ev)
-            };
-        let map_failed_step =
-            |f: Source<_>, r: Option<_>, sc: Source<_>, st: _, ret| {
-                let ev = map_failed(&f, &r, &sc);
-                let ev = Scenario::Step(st, ev).with_retries(ret);
+        let map_scenario =
+            |f: Source<_>,
+             r: Option<_>,
+             sc: Source<_>,
+             ev: RetryableScenario<W>| {
+                let scenario = match ev.event {
+                    Scenario::Background(st, Step::Skipped(None)) => {
+                        Scenario::Background(st, map_failed(&f, &r, &sc))
+                    }
+                    Scenario::Step(st, Step::Skipped(None)) => {
+                        Scenario::Step(st, map_failed(&f, &r, &sc))
+                    }
+                    other @ (Scenario::Started
+                    | Scenario::Hook(..)
+                    | Scenario::Background(..)
+                    | Scenario::Step(..)
+                    | Scenario::Log(_)
+                    | Scenario::SlaViolated(_)
+                    | Scenario::Finished(_)) => other,
+                };
+                let ev = RetryableScenario { event: scenario, ..ev };
                 Cucumber::scenario(f, r, sc, ev)
             };
 
@@ -100,58 +136,20 @@ where
             outer.map(|ev| match ev {
                 Cucumber::Feature(
                     f,
-                    Feature::Rule(
-                        r,
-                        Rule::Scenario(
-                            sc,
-                            RetryableScenario {
-                                event: Scenario::Background(st, Step::Skipped),
-                                retries,
-                            },
-                        ),
-                    ),
-                ) => map_failed_bg(f, Some(r), sc, st, retries),
-                Cucumber::Feature(
-                    f,
-                    Feature::Scenario(
-                        sc,
-                        RetryableScenario {
-                            event: Scenario::Background(st, Step::Skipped),
-                            retries,
-                        },
-                    ),
-                ) => map_failed_bg(f, None, sc, st, retries),
-                Cucumber::Feature(
-                    f,
-                    Feature::Rule(
-                        r,
-                        Rule::Scenario(
-                            sc,
-                            RetryableScenario {
-                                event: Scenario::Step(st, Step::Skipped),
-                                retries,
-                            },
-                        ),
-                    ),
-                ) => map_failed_step(f, Some(r), sc, st, retries),
-                Cucumber::Feature(
-                    f,
-                    Feature::Scenario(
-                        sc,
-                        RetryableScenario {
-                            event: Scenario::Step(st, Step::Skipped),
-                            retries,
-                        },
-                        ..,
-                    ),
-                ) => map_failed_step(f, None, sc, st, retries),
+                    Feature::Rule(r, Rule::Scenario(sc, ev)),
+                ) => map_scenario(f, Some(r), sc, *ev),
+                Cucumber::Feature(f, Feature::Scenario(sc, ev)) => {
+                    map_scenario(f, None, sc, ev)
+                }
                 Cucumber::Started
                 | Cucumber::Feature(..)
                 | Cucumber::ParsingFinished { .. }
+                | Cucumber::Report(_)
                 | Cucumber::Finished => ev,
             })
         });
 
+        self.transformed += transformed.get();
         self.writer.handle_event(event, cli).await;
     }
 }
@@ -183,13 +181,28 @@ where
     }
 
     fn failed_steps(&self) -> usize {
+        // The original `Writer` may not count undefined `Step`s separately,
+        // having the transformed ones already as failed.
         self.writer.failed_steps()
+            + self.transformed.min(self.writer.undefined_steps())
     }
 
     fn retried_steps(&self) -> usize {
         self.writer.retried_steps()
     }
 
+    fn timed_out_steps(&self) -> usize {
+        self.writer.timed_out_steps()
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.writer.undefined_steps().saturating_sub(self.transformed)
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
     fn parsing_errors(&self) -> usize {
         self.writer.parsing_errors()
     }
@@ -198,8 +211,12 @@ where
         self.writer.hook_errors()
     }
 
+    fn sla_violations(&self) -> usize {
+        self.writer.sla_violations()
+    }
+
     fn execution_has_failed(&self) -> bool {
-        self.writer.execution_has_failed()
+        self.writer.execution_has_failed() || self.transformed > 0
     }
 }
 
@@ -217,6 +234,7 @@ impl<Writer> From<Writer> for FailOnSkipped<Writer> {
                     .chain(&feat.tags)
                     .any(|t| t == "allow.skipped")
             },
+            transformed: 0,
         }
     }
 }
@@ -247,7 +265,7 @@ impl<Writer> FailOnSkipped<Writer> {
             &gherkin::Scenario,
         ) -> bool,
     {
-        FailOnSkipped { writer, should_fail: predicate }
+        FailOnSkipped { writer, should_fail: predicate, transformed: 0 }
     }
 
     /// Returns the original [`crate::Writer`], wrapped by this [`FailOnSkipped`] one.
@@ -0,0 +1,297 @@
+//! [`Case`] tracking [`proto::TestCase`] executions of a single scenario.
+
+use std::time::SystemTime;
+
+use super::{
+    Ids, LOG_MEDIA_TYPE, PUBLISHED_MEDIA_TYPE, envelope, pickle::Kind,
+    timestamp,
+};
+use crate::{
+    event,
+    writer::{
+        basic::coerce_error,
+        grpc::proto::{
+            self, Attachment, AttachmentContentEncoding, Envelope,
+            TestCaseFinished, TestCaseStarted, TestStepFinished,
+            TestStepResult, TestStepResultStatus, TestStepStarted,
+        },
+    },
+};
+
+/// Executions of a single [`proto::TestCase`].
+#[derive(Debug)]
+pub(super) struct Case {
+    /// ID of the [`proto::TestCase`].
+    id: String,
+
+    /// IDs of the [`proto::TestStep`]s along with what they run, in their
+    /// execution order.
+    steps: Vec<(String, Kind)>,
+
+    /// Current execution attempt, if any.
+    attempt: Option<Attempt>,
+}
+
+/// Execution attempt of a [`Case`].
+#[derive(Debug)]
+struct Attempt {
+    /// ID of the [`TestCaseStarted`].
+    id: String,
+
+    /// Index of the first not finished [`proto::TestStep`].
+    next: usize,
+
+    /// Index of the running [`proto::TestStep`] and its start time, if any.
+    running: Option<(usize, SystemTime)>,
+
+    /// Indicator whether any [`proto::TestStep`] has failed.
+    failed: bool,
+}
+
+impl Case {
+    /// Creates a new [`Case`] of the [`proto::TestCase`] with the provided
+    /// `id`, running the provided `steps`.
+    pub(super) const fn new(id: String, steps: Vec<(String, Kind)>) -> Self {
+        Self { id, steps, attempt: None }
+    }
+
+    /// Handles the provided [`event::RetryableScenario`] happened `at` the
+    /// provided time, pushing the resulting [`Envelope`]s into the `out`.
+    ///
+    /// Returns whether the current attempt has failed so far.
+    pub(super) fn handle<W>(
+        &mut self,
+        ids: &mut Ids,
+        ev: event::RetryableScenario<W>,
+        at: SystemTime,
+        out: &mut Vec<Envelope>,
+    ) -> bool {
+        use event::{Hook, Scenario, Step};
+
+        if matches!(ev.event, Scenario::Started) {
+            let attempt = Attempt {
+                id: ids.next(),
+                next: 0,
+                running: None,
+                failed: false,
+            };
+            out.push(envelope(TestCaseStarted {
+                id: attempt.id.clone(),
+                test_case_id: self.id.clone(),
+                attempt: ev.retries.map_or(0, |r| {
+                    u32::try_from(r.current).unwrap_or(u32::MAX)
+                }),
+                timestamp: Some(timestamp(at)),
+            }));
+            self.attempt = Some(attempt);
+            return false;
+        }
+        let Some(mut attempt) = self.attempt.take() else { return false };
+
+        let mut step = |kind, step_ev: Step<W>| {
+            let index = self.index(kind);
+            let (status, message) = match step_ev {
+                Step::Started => {
+                    self.start(&mut attempt, index, at, out);
+                    return;
+                }
+                Step::Heartbeat { .. } => return,
+                Step::Published(custom) => {
+                    self.attach(
+                        &attempt,
+                        custom.to_string(),
+                        PUBLISHED_MEDIA_TYPE,
+                        out,
+                    );
+                    return;
+                }
+                Step::Skipped(reason) => (
+                    TestStepResultStatus::Skipped,
+                    reason.map(|r| r.to_string()),
+                ),
+                Step::NotRun => (TestStepResultStatus::Skipped, None),
+                Step::Passed { .. } => (TestStepResultStatus::Passed, None),
+                Step::Failed { error, .. } => {
+                    (status(&error), Some(error.to_string()))
+                }
+            };
+            self.finish(&mut attempt, index, status, message, at, out);
+        };
+        match ev.event {
+            Scenario::Started => {}
+            Scenario::Background(s, ev) => {
+                step(Kind::Step(true, s.position), ev);
+            }
+            Scenario::Step(s, ev) => step(Kind::Step(false, s.position), ev),
+            Scenario::Hook(ty, _, hook) => {
+                let index = self.index(ty.into());
+                match hook {
+                    Hook::Started => self.start(&mut attempt, index, at, out),
+                    Hook::Passed => self.finish(
+                        &mut attempt,
+                        index,
+                        TestStepResultStatus::Passed,
+                        None,
+                        at,
+                        out,
+                    ),
+                    Hook::Failed(_, info) => self.finish(
+                        &mut attempt,
+                        index,
+                        TestStepResultStatus::Failed,
+                        Some(coerce_error(&info).into_owned()),
+                        at,
+                        out,
+                    ),
+                }
+            }
+            Scenario::Log(msg) => {
+                self.attach(&attempt, msg, LOG_MEDIA_TYPE, out);
+            }
+            Scenario::SlaViolated(violation) => {
+                attempt.failed |= violation.fails_scenario;
+                self.attach(
+                    &attempt,
+                    violation.to_string(),
+                    LOG_MEDIA_TYPE,
+                    out,
+                );
+            }
+            Scenario::Finished(_) => {
+                self.flush(&mut attempt, self.steps.len(), at, out);
+                out.push(envelope(TestCaseFinished {
+                    test_case_started_id: attempt.id,
+                    timestamp: Some(timestamp(at)),
+                    will_be_retried: attempt.failed
+                        && ev.retries.is_some_and(|r| r.left > 0),
+                }));
+                return attempt.failed;
+            }
+        }
+        let failed = attempt.failed;
+        self.attempt = Some(attempt);
+        failed
+    }
+
+    /// Returns the index of the [`proto::TestStep`] running the provided
+    /// [`Kind`].
+    fn index(&self, kind: Kind) -> usize {
+        self.steps.iter().position(|(_, k)| *k == kind).unwrap_or_else(|| {
+            unreachable!("`{kind:?}` is not compiled into `Case` {}", self.id)
+        })
+    }
+
+    /// Starts the [`proto::TestStep`] with the provided `index`, reporting the
+    /// not run ones before it.
+    fn start(
+        &self,
+        attempt: &mut Attempt,
+        index: usize,
+        at: SystemTime,
+        out: &mut Vec<Envelope>,
+    ) {
+        self.flush(attempt, index, at, out);
+        out.push(envelope(TestStepStarted {
+            test_case_started_id: attempt.id.clone(),
+            test_step_id: self.steps[index].0.clone(),
+            timestamp: Some(timestamp(at)),
+        }));
+        attempt.running = Some((index, at));
+    }
+
+    /// Finishes the [`proto::TestStep`] with the provided `index` with the
+    /// provided [`TestStepResultStatus`], starting it first, if it's not.
+    fn finish(
+        &self,
+        attempt: &mut Attempt,
+        index: usize,
+        status: TestStepResultStatus,
+        message: Option<String>,
+        at: SystemTime,
+        out: &mut Vec<Envelope>,
+    ) {
+        let started = match attempt.running.take() {
+            Some((i, started)) if i == index => started,
+            _ => {
+                self.start(attempt, index, at, out);
+                at
+            }
+        };
+        attempt.running = None;
+        attempt.next = index + 1;
+        attempt.failed |= !matches!(
+            status,
+            TestStepResultStatus::Passed | TestStepResultStatus::Skipped,
+        );
+        out.push(envelope(TestStepFinished {
+            test_case_started_id: attempt.id.clone(),
+            test_step_id: self.steps[index].0.clone(),
+            test_step_result: Some(TestStepResult {
+                status: status.into(),
+                duration: Some(duration(started, at)),
+                message,
+            }),
+            timestamp: Some(timestamp(at)),
+        }));
+    }
+
+    /// Reports the not run [`proto::TestStep`]s before the provided `index`,
+    /// as the passed hooks (there may be none of them) and the skipped steps.
+    fn flush(
+        &self,
+        attempt: &mut Attempt,
+        index: usize,
+        at: SystemTime,
+        out: &mut Vec<Envelope>,
+    ) {
+        for i in attempt.next..index {
+            let status = match self.steps[i].1 {
+                Kind::Before | Kind::After => TestStepResultStatus::Passed,
+                Kind::Step(..) => TestStepResultStatus::Skipped,
+            };
+            self.finish(attempt, i, status, None, at, out);
+        }
+    }
+
+    /// Attaches the provided `body` to the running [`proto::TestStep`], if
+    /// any.
+    fn attach(
+        &self,
+        attempt: &Attempt,
+        body: String,
+        media_type: &str,
+        out: &mut Vec<Envelope>,
+    ) {
+        out.push(envelope(Attachment {
+            body,
+            content_encoding: AttachmentContentEncoding::Identity.into(),
+            media_type: media_type.to_owned(),
+            test_case_started_id: Some(attempt.id.clone()),
+            test_step_id: attempt.running.map(|(i, _)| self.steps[i].0.clone()),
+        }));
+    }
+}
+
+/// Returns the [`TestStepResultStatus`] of the provided [`event::StepError`].
+const fn status(error: &event::StepError) -> TestStepResultStatus {
+    match error {
+        event::StepError::NotFound => TestStepResultStatus::Undefined,
+        event::StepError::AmbiguousMatch(_) => TestStepResultStatus::Ambiguous,
+        event::StepError::Pending(_) => TestStepResultStatus::Pending,
+        event::StepError::Panic(..)
+        | event::StepError::ArgMismatch(_)
+        | event::StepError::Returned(_)
+        | event::StepError::Checks(_)
+        | event::StepError::Timeout(_) => TestStepResultStatus::Failed,
+    }
+}
+
+/// Converts the time elapsed between the provided ones into a
+/// [`proto::Duration`].
+fn duration(from: SystemTime, to: SystemTime) -> proto::Duration {
+    let elapsed = to.duration_since(from).unwrap_or_default();
+    proto::Duration {
+        seconds: i64::try_from(elapsed.as_secs()).unwrap_or(i64::MAX),
+        nanos: i32::try_from(elapsed.subsec_nanos()).unwrap_or_default(),
+    }
+}
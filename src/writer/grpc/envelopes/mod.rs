@@ -0,0 +1,211 @@
+//! Conversion of [`event::Cucumber`]s into Cucumber Messages [`Envelope`]s.
+
+mod case;
+mod pickle;
+
+use std::{collections::HashMap, env, time::SystemTime};
+
+use self::case::Case;
+use super::proto::{
+    self, Envelope, Location, Meta, ParseError, Product, SourceReference,
+    TestRunFinished, TestRunStarted, envelope,
+};
+use crate::{Event, event, event::Source, parser};
+
+/// Media type of the [`event::Scenario::Log`] attachments.
+const LOG_MEDIA_TYPE: &str = "text/x.cucumber.log+plain";
+
+/// Media type of the [`event::Step::Published`] attachments.
+const PUBLISHED_MEDIA_TYPE: &str = "text/plain";
+
+/// Key identifying a scenario by its sources.
+type ScenarioKey = (
+    Source<gherkin::Feature>,
+    Option<Source<gherkin::Rule>>,
+    Source<gherkin::Scenario>,
+);
+
+/// Converter of [`event::Cucumber`]s into [`Envelope`]s, keeping track of the
+/// running scenarios.
+#[derive(Debug)]
+pub(super) struct Envelopes {
+    /// Generator of the IDs.
+    ids: Ids,
+
+    /// IDs of the `Before` and `After` [`proto::Hook`]s.
+    hooks: [String; 2],
+
+    /// [`Case`]s of the scenarios.
+    cases: HashMap<ScenarioKey, Case>,
+
+    /// Indicator whether nothing has failed so far.
+    success: bool,
+}
+
+impl Envelopes {
+    /// Creates a new [`Envelopes`] converter.
+    pub(super) fn new() -> Self {
+        let mut ids = Ids::default();
+        let hooks = [ids.next(), ids.next()];
+        Self { ids, hooks, cases: HashMap::new(), success: true }
+    }
+
+    /// Converts the provided event into [`Envelope`]s.
+    pub(super) fn convert<W>(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+    ) -> Vec<Envelope> {
+        let (ev, meta) = match ev {
+            Ok(ev) => ev.split(),
+            Err(e) => {
+                self.success = false;
+                return vec![envelope(parse_error(&e))];
+            }
+        };
+        let at = meta.at;
+
+        match ev {
+            event::Cucumber::Started => {
+                let mut out = vec![envelope(run_meta())];
+                out.extend(self.hooks.iter().map(|id| {
+                    envelope(proto::Hook {
+                        id: id.clone(),
+                        name: None,
+                        source_reference: Some(SourceReference::default()),
+                    })
+                }));
+                out.push(envelope(TestRunStarted {
+                    timestamp: Some(timestamp(at)),
+                }));
+                out
+            }
+            event::Cucumber::Feature(f, event::Feature::Scenario(sc, ev)) => {
+                self.scenario((f, None, sc), ev, at)
+            }
+            event::Cucumber::Feature(
+                f,
+                event::Feature::Rule(r, event::Rule::Scenario(sc, ev)),
+            ) => self.scenario((f, Some(r), sc), *ev, at),
+            event::Cucumber::Finished => {
+                vec![envelope(TestRunFinished {
+                    success: self.success,
+                    timestamp: Some(timestamp(at)),
+                })]
+            }
+            event::Cucumber::ParsingFinished { .. }
+            | event::Cucumber::Report(_)
+            | event::Cucumber::Feature(
+                _,
+                event::Feature::Started
+                | event::Feature::Finished
+                | event::Feature::Rule(
+                    _,
+                    event::Rule::Started | event::Rule::Finished,
+                ),
+            ) => vec![],
+        }
+    }
+
+    /// Converts the provided [`event::RetryableScenario`] into [`Envelope`]s,
+    /// compiling the scenario into a [`proto::Pickle`] once it's started for
+    /// the first time.
+    fn scenario<W>(
+        &mut self,
+        key: ScenarioKey,
+        ev: event::RetryableScenario<W>,
+        at: SystemTime,
+    ) -> Vec<Envelope> {
+        let mut out = vec![];
+        let finished = matches!(ev.event, event::Scenario::Finished(_));
+        // Violated SLA fails the run, even if it doesn't fail the scenario.
+        if matches!(ev.event, event::Scenario::SlaViolated(_)) {
+            self.success = false;
+        }
+        let case = self.cases.entry(key).or_insert_with_key(|(f, r, sc)| {
+            let (steps, pickle, test_case) = pickle::compile(
+                &mut self.ids,
+                &self.hooks,
+                f,
+                r.as_deref(),
+                sc,
+            );
+            let case = Case::new(test_case.id.clone(), steps);
+            out.extend([envelope(pickle), envelope(test_case)]);
+            case
+        });
+        let failed = case.handle(&mut self.ids, ev, at, &mut out);
+        if finished && failed {
+            self.success = false;
+        }
+        out
+    }
+}
+
+/// Generator of the IDs, unique within a run.
+#[derive(Debug, Default)]
+pub(super) struct Ids(u64);
+
+impl Ids {
+    /// Returns the next ID.
+    pub(super) fn next(&mut self) -> String {
+        self.0 += 1;
+        self.0.to_string()
+    }
+}
+
+/// Wraps the provided message into an [`Envelope`].
+pub(super) fn envelope(message: impl Into<envelope::Message>) -> Envelope {
+    Envelope { message: Some(message.into()) }
+}
+
+/// Converts the provided [`SystemTime`] into a [`proto::Timestamp`].
+pub(super) fn timestamp(at: SystemTime) -> proto::Timestamp {
+    let since_epoch = at.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+    proto::Timestamp {
+        seconds: i64::try_from(since_epoch.as_secs()).unwrap_or(i64::MAX),
+        nanos: i32::try_from(since_epoch.subsec_nanos()).unwrap_or_default(),
+    }
+}
+
+/// Creates the [`Meta`] of the run.
+fn run_meta() -> Meta {
+    let product = |name: &str, version: Option<&str>| Product {
+        name: name.to_owned(),
+        version: version.map(ToOwned::to_owned),
+    };
+    Meta {
+        protocol_version: proto::PROTOCOL_VERSION.to_owned(),
+        implementation: Some(product(
+            "cucumber-rs",
+            Some(env!("CARGO_PKG_VERSION")),
+        )),
+        runtime: Some(product("rust", None)),
+        os: Some(product(env::consts::OS, None)),
+        cpu: Some(product(env::consts::ARCH, None)),
+    }
+}
+
+/// Converts the provided [`parser::Error`] into a [`ParseError`].
+fn parse_error(e: &parser::Error) -> ParseError {
+    let (path, location) = match e {
+        parser::Error::Parsing(e) => match &**e {
+            gherkin::ParseFileError::Reading { path, .. }
+            | gherkin::ParseFileError::Parsing { path, .. } => (Some(path), None),
+        },
+        parser::Error::ExampleExpansion(e) => (
+            e.path.as_ref(),
+            Some(Location {
+                line: u32::try_from(e.pos.line).unwrap_or(u32::MAX),
+                column: u32::try_from(e.pos.col).ok(),
+            }),
+        ),
+        parser::Error::Fetching { .. } => (None, None),
+    };
+    ParseError {
+        source: Some(SourceReference {
+            uri: path.map(|p| p.display().to_string()),
+            location,
+        }),
+        message: e.to_string(),
+    }
+}
@@ -0,0 +1,145 @@
+//! Compilation of scenarios into [`Pickle`]s and [`proto::TestCase`]s.
+
+use super::Ids;
+use crate::{
+    event,
+    writer::grpc::proto::{self, Pickle, PickleStepType},
+};
+
+/// What a [`proto::TestStep`] runs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Kind {
+    /// `Before` [`proto::Hook`].
+    Before,
+
+    /// `After` [`proto::Hook`].
+    After,
+
+    /// [`gherkin::Step`] at the provided position, being a `Background` one
+    /// or not.
+    Step(bool, gherkin::LineCol),
+}
+
+impl From<event::HookType> for Kind {
+    fn from(ty: event::HookType) -> Self {
+        match ty {
+            event::HookType::Before => Self::Before,
+            event::HookType::After => Self::After,
+        }
+    }
+}
+
+/// Compiles the provided scenario into a [`Pickle`] and a
+/// [`proto::TestCase`] running it between the provided `hooks`.
+///
+/// Returns the IDs of the [`proto::TestStep`]s along with what they run, in
+/// their execution order.
+pub(super) fn compile(
+    ids: &mut Ids,
+    hooks: &[String; 2],
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> (Vec<(String, Kind)>, Pickle, proto::TestCase) {
+    let background = feature
+        .background
+        .iter()
+        .chain(rule.and_then(|r| r.background.as_ref()))
+        .flat_map(|b| b.steps.iter().map(|s| (true, s)));
+    let steps = background
+        .chain(scenario.steps.iter().map(|s| (false, s)))
+        .map(|(bg, s)| (Kind::Step(bg, s.position), pickle_step(ids, s)))
+        .collect::<Vec<_>>();
+
+    let pickle = Pickle {
+        id: ids.next(),
+        uri: feature
+            .path
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default(),
+        name: scenario.name.clone(),
+        language: String::new(),
+        steps: steps.iter().map(|(_, s)| s.clone()).collect(),
+        tags: feature
+            .tags
+            .iter()
+            .chain(rule.iter().flat_map(|r| &r.tags))
+            .chain(&scenario.tags)
+            .map(|t| proto::PickleTag {
+                name: format!("@{t}"),
+                ast_node_id: String::new(),
+            })
+            .collect(),
+        ast_node_ids: vec![],
+    };
+
+    let [before, after] = hooks;
+    let hook = |counter: &mut Ids, kind, hook_id: &String| {
+        let step = proto::TestStep {
+            id: counter.next(),
+            pickle_step_id: None,
+            hook_id: Some(hook_id.clone()),
+        };
+        ((step.id.clone(), kind), step)
+    };
+    let mut test_steps = vec![hook(ids, Kind::Before, before)];
+    test_steps.extend(steps.into_iter().map(|(kind, s)| {
+        let step = proto::TestStep {
+            id: ids.next(),
+            pickle_step_id: Some(s.id),
+            hook_id: None,
+        };
+        ((step.id.clone(), kind), step)
+    }));
+    test_steps.push(hook(ids, Kind::After, after));
+    let (kinds, test_steps) = test_steps.into_iter().unzip();
+
+    let test_case = proto::TestCase {
+        id: ids.next(),
+        pickle_id: pickle.id.clone(),
+        test_steps,
+    };
+    (kinds, pickle, test_case)
+}
+
+/// Compiles the provided [`gherkin::Step`] into a [`proto::PickleStep`].
+fn pickle_step(ids: &mut Ids, step: &gherkin::Step) -> proto::PickleStep {
+    let ty = match step.ty {
+        gherkin::StepType::Given => PickleStepType::Context,
+        gherkin::StepType::When => PickleStepType::Action,
+        gherkin::StepType::Then => PickleStepType::Outcome,
+    };
+    let argument =
+        (step.docstring.is_some() || step.table.is_some()).then(|| {
+            proto::PickleStepArgument {
+                doc_string: step.docstring.as_ref().map(|content| {
+                    proto::PickleDocString {
+                        media_type: None,
+                        content: content.clone(),
+                    }
+                }),
+                data_table: step.table.as_ref().map(|t| proto::PickleTable {
+                    rows: t
+                        .rows
+                        .iter()
+                        .map(|row| proto::PickleTableRow {
+                            cells: row
+                                .iter()
+                                .map(|value| proto::PickleTableCell {
+                                    value: value.clone(),
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                }),
+            }
+        });
+    proto::PickleStep {
+        id: ids.next(),
+        text: step.value.clone(),
+        r#type: ty.into(),
+        argument,
+        ast_node_ids: vec![],
+    }
+}
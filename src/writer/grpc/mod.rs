@@ -0,0 +1,202 @@
+//! Streaming [Cucumber Messages][1] envelopes to subscribers via [gRPC].
+//!
+//! [`Grpc`] [`Writer`] converts the [`event::Cucumber`]s into the
+//! [`proto::Envelope`]s and serves them with the `Envelopes` service (see the
+//! `messages.proto` schema shipped along with this module) on a TCP port. Each
+//! `Subscribe` call streams all the envelopes of the run: the ones emitted
+//! before the subscription first, and then the live ones, until the run
+//! finishes.
+//!
+//! Only the subset of the protocol, which may be produced from the
+//! [`event::Cucumber`]s, is emitted: `Meta`, `ParseError`, `Pickle`, `Hook`,
+//! `TestCase`, `TestRun*`, `TestCase*`, `TestStep*` and `Attachment` messages.
+//! Each [`proto::TestCase`] runs the `Before` and `After` [`proto::Hook`]s
+//! around its steps, which are reported as passed, if there are none.
+//!
+//! [1]: https://github.com/cucumber/messages
+//! [gRPC]: https://grpc.io
+
+mod envelopes;
+pub mod proto;
+mod service;
+
+use std::{
+    io,
+    net::{SocketAddr, TcpListener, ToSocketAddrs},
+    sync::{Arc, Mutex, mpsc},
+    thread,
+    time::Duration,
+};
+
+use derive_more::with_trait::Debug;
+use tokio::{net, runtime, sync::oneshot};
+use tonic::transport::{self, server::TcpIncoming};
+
+use self::{envelopes::Envelopes, service::Hub};
+use crate::{
+    Event, World, Writer, cli, event, parser,
+    writer::{self, Ext as _},
+};
+
+/// Default time a dropped [`Grpc`] waits for its subscribers to receive the
+/// rest of the [`proto::Envelope`]s.
+const DEFAULT_LINGER: Duration = Duration::from_secs(5);
+
+/// [`Writer`] streaming [Cucumber Messages][1] envelopes to subscribers via
+/// [gRPC].
+///
+/// See the [module-level documentation](self) for details.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use cucumber::{World, WriterExt as _, writer};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let grpc = writer::Grpc::bind("0.0.0.0:50051").unwrap();
+/// MyWorld::cucumber()
+///     .with_writer(
+///         writer::Basic::stdout()
+///             .summarized()
+///             .tee::<MyWorld, _>(grpc.for_tee())
+///             .normalized(),
+///     )
+///     .run("tests/features")
+///     .await;
+/// # }
+/// ```
+///
+/// [1]: https://github.com/cucumber/messages
+/// [gRPC]: https://grpc.io
+#[derive(Debug)]
+pub struct Grpc {
+    /// Converter of the [`event::Cucumber`]s into [`proto::Envelope`]s.
+    envelopes: Envelopes,
+
+    /// [`proto::Envelope`]s shared with the gRPC service.
+    #[debug(ignore)]
+    hub: Arc<Mutex<Hub>>,
+
+    /// Address of the TCP port being listened on.
+    local_addr: SocketAddr,
+
+    /// Sender stopping the gRPC server.
+    #[debug(ignore)]
+    stop: Option<oneshot::Sender<()>>,
+
+    /// Receiver disconnected once the gRPC server stops.
+    #[debug(ignore)]
+    stopped: mpsc::Receiver<()>,
+
+    /// Time to wait for the subscribers to receive the rest of the
+    /// [`proto::Envelope`]s, once this [`Grpc`] is dropped.
+    linger: Duration,
+}
+
+impl Grpc {
+    /// Creates a new [`Grpc`] serving the `Envelopes` service on the provided
+    /// TCP `addr` in a separate thread, so the run itself doesn't require
+    /// [`tokio`] runtime.
+    ///
+    /// # Errors
+    ///
+    /// If the `addr` cannot be bound, or the [`tokio`] runtime cannot be
+    /// created.
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+        let rt = runtime::Builder::new_current_thread().enable_all().build()?;
+
+        let hub = Hub::new();
+        let router = transport::Server::builder()
+            .add_service(service::Envelopes(Arc::clone(&hub)));
+        let (stop, stop_requested) = oneshot::channel();
+        let (stopped_tx, stopped) = mpsc::channel();
+        drop(thread::spawn(move || {
+            let served = rt.block_on(async {
+                let listener = net::TcpListener::from_std(listener)?;
+                router
+                    .serve_with_incoming_shutdown(
+                        TcpIncoming::from(listener),
+                        async {
+                            _ = stop_requested.await;
+                        },
+                    )
+                    .await
+                    .map_err(io::Error::other)
+            });
+            if let Err(e) = served {
+                #[expect(clippy::print_stderr, reason = "no other way to report")]
+                {
+                    eprintln!("Warning: Failed to serve gRPC: {e}");
+                }
+            }
+            drop(stopped_tx);
+        }));
+
+        Ok(Self {
+            envelopes: Envelopes::new(),
+            hub,
+            local_addr,
+            stop: Some(stop),
+            stopped,
+            linger: DEFAULT_LINGER,
+        })
+    }
+
+    /// Sets the time this [`Grpc`] waits for its subscribers to receive the
+    /// rest of the [`proto::Envelope`]s, once dropped (`5 seconds` by
+    /// default).
+    #[must_use]
+    pub const fn linger(mut self, timeout: Duration) -> Self {
+        self.linger = timeout;
+        self
+    }
+
+    /// Wraps this [`Grpc`] to be suitable for feeding into [`tee()`].
+    ///
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(self) -> writer::discard::Arbitrary<writer::discard::Stats<Self>> {
+        self.discard_stats_writes().discard_arbitrary_writes()
+    }
+
+    /// Returns the address of the TCP port this [`Grpc`] listens on.
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl<W: World> Writer<W> for Grpc {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        let envelopes = self.envelopes.convert(event);
+        service::lock(&self.hub).publish(envelopes);
+    }
+}
+
+impl writer::NonTransforming for Grpc {}
+
+impl Drop for Grpc {
+    fn drop(&mut self) {
+        service::lock(&self.hub).finish();
+        if let Some(stop) = self.stop.take() {
+            // Ignored, as the server may have failed already.
+            _ = stop.send(());
+        }
+        // Gracefully stopped server waits for the subscribers to receive the
+        // rest of the streams, but a stuck one shouldn't hang the process.
+        _ = self.stopped.recv_timeout(self.linger);
+    }
+}
@@ -0,0 +1,147 @@
+//! Messages of [`TestCase`]s execution.
+//!
+//! [`TestCase`]: super::TestCase
+
+use super::{Duration, Timestamp};
+
+/// Start of a [`TestCase`] execution attempt.
+///
+/// [`TestCase`]: super::TestCase
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct TestCaseStarted {
+    /// ID of this [`TestCaseStarted`].
+    #[prost(string, tag = "1")]
+    pub id: String,
+
+    /// ID of the started [`TestCase`].
+    ///
+    /// [`TestCase`]: super::TestCase
+    #[prost(string, tag = "2")]
+    pub test_case_id: String,
+
+    /// Number of the attempt, starting from `0` and increasing on retries.
+    #[prost(uint32, tag = "3")]
+    pub attempt: u32,
+
+    /// [`Timestamp`] the attempt started at.
+    #[prost(message, optional, tag = "4")]
+    pub timestamp: Option<Timestamp>,
+}
+
+/// Start of a [`TestStep`] execution.
+///
+/// [`TestStep`]: super::TestStep
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct TestStepStarted {
+    /// ID of the [`TestCaseStarted`] the [`TestStep`] belongs to.
+    ///
+    /// [`TestStep`]: super::TestStep
+    #[prost(string, tag = "1")]
+    pub test_case_started_id: String,
+
+    /// ID of the started [`TestStep`].
+    ///
+    /// [`TestStep`]: super::TestStep
+    #[prost(string, tag = "2")]
+    pub test_step_id: String,
+
+    /// [`Timestamp`] the [`TestStep`] started at.
+    ///
+    /// [`TestStep`]: super::TestStep
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: Option<Timestamp>,
+}
+
+/// Status of a [`TestStepResult`].
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum TestStepResultStatus {
+    /// Unknown status.
+    Unknown = 0,
+
+    /// Step passed.
+    Passed = 1,
+
+    /// Step has been skipped.
+    Skipped = 2,
+
+    /// Step is not implemented yet.
+    Pending = 3,
+
+    /// Step doesn't match any step definition.
+    Undefined = 4,
+
+    /// Step matches more than one step definition.
+    Ambiguous = 5,
+
+    /// Step failed.
+    Failed = 6,
+}
+
+/// Result of a [`TestStep`] execution.
+///
+/// [`TestStep`]: super::TestStep
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct TestStepResult {
+    /// [`TestStepResultStatus`] of the execution.
+    #[prost(enumeration = "TestStepResultStatus", tag = "1")]
+    pub status: i32,
+
+    /// [`Duration`] of the execution.
+    #[prost(message, optional, tag = "2")]
+    pub duration: Option<Duration>,
+
+    /// Description of the failure or skip reason, if any.
+    #[prost(string, optional, tag = "3")]
+    pub message: Option<String>,
+}
+
+/// End of a [`TestStep`] execution.
+///
+/// [`TestStep`]: super::TestStep
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct TestStepFinished {
+    /// ID of the [`TestCaseStarted`] the [`TestStep`] belongs to.
+    ///
+    /// [`TestStep`]: super::TestStep
+    #[prost(string, tag = "1")]
+    pub test_case_started_id: String,
+
+    /// ID of the finished [`TestStep`].
+    ///
+    /// [`TestStep`]: super::TestStep
+    #[prost(string, tag = "2")]
+    pub test_step_id: String,
+
+    /// [`TestStepResult`] of the execution.
+    #[prost(message, optional, tag = "3")]
+    pub test_step_result: Option<TestStepResult>,
+
+    /// [`Timestamp`] the [`TestStep`] finished at.
+    ///
+    /// [`TestStep`]: super::TestStep
+    #[prost(message, optional, tag = "4")]
+    pub timestamp: Option<Timestamp>,
+}
+
+/// End of a [`TestCase`] execution attempt.
+///
+/// [`TestCase`]: super::TestCase
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct TestCaseFinished {
+    /// ID of the finished [`TestCaseStarted`].
+    #[prost(string, tag = "1")]
+    pub test_case_started_id: String,
+
+    /// [`Timestamp`] the attempt finished at.
+    #[prost(message, optional, tag = "2")]
+    pub timestamp: Option<Timestamp>,
+
+    /// Indicator whether the [`TestCase`] is going to be retried.
+    ///
+    /// [`TestCase`]: super::TestCase
+    #[prost(bool, tag = "3")]
+    pub will_be_retried: bool,
+}
@@ -0,0 +1,268 @@
+//! [Protobuf] messages of the Cucumber Messages envelopes streamed by a
+//! [`Grpc`] [`Writer`].
+//!
+//! Mirrors the `messages.proto` schema shipped along with this module, which
+//! may be used for generating clients in other languages.
+//!
+//! [`Grpc`]: super::Grpc
+//! [`Writer`]: crate::Writer
+//! [Protobuf]: https://protobuf.dev
+
+mod execution;
+mod pickle;
+
+pub use self::{
+    execution::{
+        TestCaseFinished, TestCaseStarted, TestStepFinished, TestStepResult,
+        TestStepResultStatus, TestStepStarted,
+    },
+    pickle::{
+        Hook, Pickle, PickleDocString, PickleStep, PickleStepArgument,
+        PickleStepType, PickleTable, PickleTableCell, PickleTableRow,
+        PickleTag, TestCase, TestStep,
+    },
+};
+
+/// Version of the Cucumber Messages protocol the envelopes are modelled after.
+pub const PROTOCOL_VERSION: &str = "27.0.0";
+
+/// Request of the `Subscribe` method of the `Envelopes` service.
+#[derive(Clone, Copy, Eq, PartialEq, prost::Message)]
+#[expect(
+    clippy::empty_structs_with_brackets,
+    reason = "`prost::Message` derive doesn't support unit structs"
+)]
+pub struct SubscribeRequest {}
+
+/// Single message of the Cucumber Messages protocol.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct Envelope {
+    /// Wrapped message.
+    #[prost(
+        oneof = "envelope::Message",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12"
+    )]
+    pub message: Option<envelope::Message>,
+}
+
+/// Nested types of an [`Envelope`].
+pub mod envelope {
+    use derive_more::with_trait::From;
+
+    use super::{
+        Attachment, Hook, Meta, ParseError, Pickle, TestCase,
+        TestCaseFinished, TestCaseStarted, TestRunFinished, TestRunStarted,
+        TestStepFinished, TestStepStarted,
+    };
+
+    /// Message wrapped into an [`Envelope`].
+    ///
+    /// [`Envelope`]: super::Envelope
+    #[derive(Clone, Eq, From, PartialEq, prost::Oneof)]
+    pub enum Message {
+        /// [`Meta`] information about the run.
+        #[prost(message, tag = "1")]
+        Meta(Meta),
+
+        /// [`ParseError`] of a `.feature` file.
+        #[prost(message, tag = "2")]
+        ParseError(ParseError),
+
+        /// [`Pickle`] compiled from a scenario.
+        #[prost(message, tag = "3")]
+        Pickle(Pickle),
+
+        /// [`Hook`] definition.
+        #[prost(message, tag = "4")]
+        Hook(Hook),
+
+        /// [`TestCase`] executing a [`Pickle`].
+        #[prost(message, tag = "5")]
+        TestCase(TestCase),
+
+        /// [`TestRunStarted`] event.
+        #[prost(message, tag = "6")]
+        TestRunStarted(TestRunStarted),
+
+        /// [`TestCaseStarted`] event.
+        #[prost(message, tag = "7")]
+        TestCaseStarted(TestCaseStarted),
+
+        /// [`TestStepStarted`] event.
+        #[prost(message, tag = "8")]
+        TestStepStarted(TestStepStarted),
+
+        /// [`TestStepFinished`] event.
+        #[prost(message, tag = "9")]
+        TestStepFinished(TestStepFinished),
+
+        /// [`TestCaseFinished`] event.
+        #[prost(message, tag = "10")]
+        TestCaseFinished(TestCaseFinished),
+
+        /// [`TestRunFinished`] event.
+        #[prost(message, tag = "11")]
+        TestRunFinished(TestRunFinished),
+
+        /// [`Attachment`] of a test step.
+        #[prost(message, tag = "12")]
+        Attachment(Attachment),
+    }
+}
+
+/// Point in time since the Unix epoch.
+#[derive(Clone, Copy, Eq, PartialEq, prost::Message)]
+pub struct Timestamp {
+    /// Whole seconds.
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+
+    /// Remaining nanoseconds.
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
+
+/// Span of time.
+#[derive(Clone, Copy, Eq, PartialEq, prost::Message)]
+pub struct Duration {
+    /// Whole seconds.
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+
+    /// Remaining nanoseconds.
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
+
+/// Name and version of a software or hardware product.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct Product {
+    /// Name of this [`Product`].
+    #[prost(string, tag = "1")]
+    pub name: String,
+
+    /// Version of this [`Product`], if known.
+    #[prost(string, optional, tag = "2")]
+    pub version: Option<String>,
+}
+
+/// Information about the run, being the first [`Envelope`] of a stream.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct Meta {
+    /// [`PROTOCOL_VERSION`] of the Cucumber Messages.
+    #[prost(string, tag = "1")]
+    pub protocol_version: String,
+
+    /// Implementation producing the [`Envelope`]s.
+    #[prost(message, optional, tag = "2")]
+    pub implementation: Option<Product>,
+
+    /// Runtime of the implementation.
+    #[prost(message, optional, tag = "3")]
+    pub runtime: Option<Product>,
+
+    /// Operating system of the run.
+    #[prost(message, optional, tag = "4")]
+    pub os: Option<Product>,
+
+    /// CPU architecture of the run.
+    #[prost(message, optional, tag = "5")]
+    pub cpu: Option<Product>,
+}
+
+/// Location in a source file.
+#[derive(Clone, Copy, Eq, PartialEq, prost::Message)]
+pub struct Location {
+    /// Line number, starting from `1`.
+    #[prost(uint32, tag = "1")]
+    pub line: u32,
+
+    /// Column number, starting from `1`, if known.
+    #[prost(uint32, optional, tag = "2")]
+    pub column: Option<u32>,
+}
+
+/// Reference to a [`Location`] in a source file.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct SourceReference {
+    /// URI of the source file, if known.
+    #[prost(string, optional, tag = "1")]
+    pub uri: Option<String>,
+
+    /// [`Location`] in the source file, if known.
+    #[prost(message, optional, tag = "2")]
+    pub location: Option<Location>,
+}
+
+/// Error of parsing a `.feature` file.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct ParseError {
+    /// [`SourceReference`] of the failed `.feature` file.
+    #[prost(message, optional, tag = "1")]
+    pub source: Option<SourceReference>,
+
+    /// Description of the error.
+    #[prost(string, tag = "2")]
+    pub message: String,
+}
+
+/// Start of the run.
+#[derive(Clone, Copy, Eq, PartialEq, prost::Message)]
+pub struct TestRunStarted {
+    /// [`Timestamp`] the run started at.
+    #[prost(message, optional, tag = "1")]
+    pub timestamp: Option<Timestamp>,
+}
+
+/// End of the run, being the last [`Envelope`] of a stream.
+#[derive(Clone, Copy, Eq, PartialEq, prost::Message)]
+pub struct TestRunFinished {
+    /// Indicator whether all the [`TestCase`]s have passed.
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+
+    /// [`Timestamp`] the run finished at.
+    #[prost(message, optional, tag = "2")]
+    pub timestamp: Option<Timestamp>,
+}
+
+/// Encoding of an [`Attachment::body`].
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum AttachmentContentEncoding {
+    /// Body is the content itself.
+    Identity = 0,
+
+    /// Body is the [Base64]-encoded content.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    Base64 = 1,
+}
+
+/// Content attached to a [`TestStep`], like a log message.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct Attachment {
+    /// Attached content.
+    #[prost(string, tag = "1")]
+    pub body: String,
+
+    /// [`AttachmentContentEncoding`] of the `body`.
+    #[prost(enumeration = "AttachmentContentEncoding", tag = "2")]
+    pub content_encoding: i32,
+
+    /// [Media type][1] of the attached content.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Media_type
+    #[prost(string, tag = "3")]
+    pub media_type: String,
+
+    /// ID of the [`TestCaseStarted`] the content is attached during.
+    #[prost(string, optional, tag = "4")]
+    pub test_case_started_id: Option<String>,
+
+    /// ID of the [`TestStep`] the content is attached to, if any.
+    #[prost(string, optional, tag = "5")]
+    pub test_step_id: Option<String>,
+}
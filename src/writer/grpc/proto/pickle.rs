@@ -0,0 +1,189 @@
+//! [`Pickle`]s and [`TestCase`]s messages.
+
+use super::SourceReference;
+
+/// Doc string argument of a [`PickleStep`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct PickleDocString {
+    /// [Media type][1] of the `content`, if specified.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Media_type
+    #[prost(string, optional, tag = "1")]
+    pub media_type: Option<String>,
+
+    /// Content of the doc string.
+    #[prost(string, tag = "2")]
+    pub content: String,
+}
+
+/// Cell of a [`PickleTableRow`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct PickleTableCell {
+    /// Value of this [`PickleTableCell`].
+    #[prost(string, tag = "1")]
+    pub value: String,
+}
+
+/// Row of a [`PickleTable`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct PickleTableRow {
+    /// [`PickleTableCell`]s of this [`PickleTableRow`].
+    #[prost(message, repeated, tag = "1")]
+    pub cells: Vec<PickleTableCell>,
+}
+
+/// Data table argument of a [`PickleStep`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct PickleTable {
+    /// [`PickleTableRow`]s of this [`PickleTable`].
+    #[prost(message, repeated, tag = "1")]
+    pub rows: Vec<PickleTableRow>,
+}
+
+/// Argument of a [`PickleStep`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct PickleStepArgument {
+    /// [`PickleDocString`] argument, if any.
+    #[prost(message, optional, tag = "1")]
+    pub doc_string: Option<PickleDocString>,
+
+    /// [`PickleTable`] argument, if any.
+    #[prost(message, optional, tag = "2")]
+    pub data_table: Option<PickleTable>,
+}
+
+/// Type of a [`PickleStep`], defined by its keyword.
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum PickleStepType {
+    /// Unknown type.
+    Unknown = 0,
+
+    /// `Given` step.
+    Context = 1,
+
+    /// `When` step.
+    Action = 2,
+
+    /// `Then` step.
+    Outcome = 3,
+}
+
+/// Step of a [`Pickle`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct PickleStep {
+    /// ID of this [`PickleStep`].
+    #[prost(string, tag = "1")]
+    pub id: String,
+
+    /// Text of this [`PickleStep`], without its keyword.
+    #[prost(string, tag = "2")]
+    pub text: String,
+
+    /// [`PickleStepType`] of this [`PickleStep`].
+    #[prost(enumeration = "PickleStepType", tag = "3")]
+    pub r#type: i32,
+
+    /// [`PickleStepArgument`] of this [`PickleStep`], if any.
+    #[prost(message, optional, tag = "4")]
+    pub argument: Option<PickleStepArgument>,
+
+    /// IDs of the Gherkin AST nodes this [`PickleStep`] is compiled from.
+    #[prost(string, repeated, tag = "5")]
+    pub ast_node_ids: Vec<String>,
+}
+
+/// Tag of a [`Pickle`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct PickleTag {
+    /// Name of this [`PickleTag`], including its leading `@`.
+    #[prost(string, tag = "1")]
+    pub name: String,
+
+    /// ID of the Gherkin AST node this [`PickleTag`] is compiled from.
+    #[prost(string, tag = "2")]
+    pub ast_node_id: String,
+}
+
+/// Scenario compiled into a flat list of steps, with the background ones
+/// included and the outline placeholders substituted.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct Pickle {
+    /// ID of this [`Pickle`].
+    #[prost(string, tag = "1")]
+    pub id: String,
+
+    /// URI of the `.feature` file this [`Pickle`] is compiled from.
+    #[prost(string, tag = "2")]
+    pub uri: String,
+
+    /// Name of the scenario.
+    #[prost(string, tag = "3")]
+    pub name: String,
+
+    /// Language of the `.feature` file.
+    #[prost(string, tag = "4")]
+    pub language: String,
+
+    /// [`PickleStep`]s of this [`Pickle`].
+    #[prost(message, repeated, tag = "5")]
+    pub steps: Vec<PickleStep>,
+
+    /// [`PickleTag`]s of this [`Pickle`], including the inherited ones.
+    #[prost(message, repeated, tag = "6")]
+    pub tags: Vec<PickleTag>,
+
+    /// IDs of the Gherkin AST nodes this [`Pickle`] is compiled from.
+    #[prost(string, repeated, tag = "7")]
+    pub ast_node_ids: Vec<String>,
+}
+
+/// Definition of a hook run before or after each scenario.
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct Hook {
+    /// ID of this [`Hook`].
+    #[prost(string, tag = "1")]
+    pub id: String,
+
+    /// Name of this [`Hook`], if any.
+    #[prost(string, optional, tag = "2")]
+    pub name: Option<String>,
+
+    /// [`SourceReference`] of this [`Hook`] definition.
+    #[prost(message, optional, tag = "3")]
+    pub source_reference: Option<SourceReference>,
+}
+
+/// Step of a [`TestCase`], running either a [`PickleStep`] or a [`Hook`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct TestStep {
+    /// ID of this [`TestStep`].
+    #[prost(string, tag = "1")]
+    pub id: String,
+
+    /// ID of the [`PickleStep`] this [`TestStep`] runs, if any.
+    #[prost(string, optional, tag = "2")]
+    pub pickle_step_id: Option<String>,
+
+    /// ID of the [`Hook`] this [`TestStep`] runs, if any.
+    #[prost(string, optional, tag = "3")]
+    pub hook_id: Option<String>,
+}
+
+/// Plan of executing a [`Pickle`].
+#[derive(Clone, Eq, PartialEq, prost::Message)]
+pub struct TestCase {
+    /// ID of this [`TestCase`].
+    #[prost(string, tag = "1")]
+    pub id: String,
+
+    /// ID of the executed [`Pickle`].
+    #[prost(string, tag = "2")]
+    pub pickle_id: String,
+
+    /// [`TestStep`]s of this [`TestCase`] in their execution order.
+    #[prost(message, repeated, tag = "3")]
+    pub test_steps: Vec<TestStep>,
+}
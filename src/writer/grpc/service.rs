@@ -0,0 +1,148 @@
+//! `Envelopes` gRPC service streaming the published [`Envelope`]s to its
+//! subscribers.
+
+use std::{
+    convert::Infallible,
+    future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task,
+};
+
+use futures::{FutureExt as _, StreamExt as _, stream};
+use tokio::sync::broadcast;
+use tonic::{
+    Request, Response, Status,
+    body::Body,
+    codegen::{self, BoxFuture, BoxStream, Service, http},
+    server::{Grpc, NamedService, ServerStreamingService},
+};
+use tonic_prost::ProstCodec;
+
+use super::proto::{Envelope, SubscribeRequest, envelope::Message};
+
+/// Maximum number of live [`Envelope`]s a subscriber may lag behind, before
+/// its stream is terminated with an error, so a stuck subscriber neither
+/// blocks the run, nor makes it buffer endlessly.
+const LAG_CAPACITY: usize = 4096;
+
+/// [`Envelope`]s published so far, along with the live ones' channel.
+#[derive(Debug)]
+pub(super) struct Hub {
+    /// All the [`Envelope`]s published so far.
+    history: Vec<Envelope>,
+
+    /// Sender of the live [`Envelope`]s, until the run finishes.
+    live: Option<broadcast::Sender<Envelope>>,
+}
+
+impl Hub {
+    /// Creates a new empty [`Hub`].
+    pub(super) fn new() -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self {
+            history: vec![],
+            live: Some(broadcast::channel(LAG_CAPACITY).0),
+        }))
+    }
+
+    /// Publishes the provided [`Envelope`]s to all the subscribers, finishing
+    /// their streams once the run finishes.
+    pub(super) fn publish(&mut self, envelopes: Vec<Envelope>) {
+        for env in envelopes {
+            let finished =
+                matches!(env.message, Some(Message::TestRunFinished(_)));
+            if let Some(live) = &self.live {
+                // Ignored, as there may be no subscribers yet.
+                drop(live.send(env.clone()));
+            }
+            self.history.push(env);
+            if finished {
+                self.finish();
+            }
+        }
+    }
+
+    /// Finishes the streams of all the subscribers, once they receive the
+    /// already published [`Envelope`]s.
+    pub(super) fn finish(&mut self) {
+        self.live = None;
+    }
+
+    /// Subscribes to all the [`Envelope`]s: the published ones first, and
+    /// then the live ones, if the run isn't finished yet.
+    fn subscribe(&self) -> BoxStream<Envelope> {
+        let history = stream::iter(self.history.clone()).map(Ok);
+        let Some(live) = &self.live else { return history.boxed() };
+
+        let live = stream::unfold(Some(live.subscribe()), async |rx| {
+            let mut rx = rx?;
+            match rx.recv().await {
+                Ok(env) => Some((Ok(env), Some(rx))),
+                Err(broadcast::error::RecvError::Closed) => None,
+                Err(broadcast::error::RecvError::Lagged(n)) => Some((
+                    Err(Status::resource_exhausted(format!(
+                        "subscriber lagged behind by {n} envelopes",
+                    ))),
+                    None,
+                )),
+            }
+        });
+        history.chain(live).boxed()
+    }
+}
+
+/// Locks the provided [`Hub`], ignoring the poisoning, as it always remains
+/// consistent.
+pub(super) fn lock(hub: &Mutex<Hub>) -> MutexGuard<'_, Hub> {
+    hub.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// `Envelopes` gRPC service of the [`Hub`].
+#[derive(Clone, Debug)]
+pub(super) struct Envelopes(pub(super) Arc<Mutex<Hub>>);
+
+impl NamedService for Envelopes {
+    const NAME: &'static str = "cucumber.messages.Envelopes";
+}
+
+impl<B> Service<http::Request<B>> for Envelopes
+where
+    B: codegen::Body + Send + 'static,
+    B::Error: Into<codegen::StdError> + Send + 'static,
+{
+    type Response = http::Response<Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(
+        &mut self,
+        _: &mut task::Context<'_>,
+    ) -> task::Poll<Result<(), Self::Error>> {
+        task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        if req.uri().path() != "/cucumber.messages.Envelopes/Subscribe" {
+            let resp = Status::unimplemented("unknown method").into_http();
+            return future::ready(Ok(resp)).boxed();
+        }
+        let subscribe = Subscribe(Arc::clone(&self.0));
+        async move {
+            let mut grpc = Grpc::new(ProstCodec::default());
+            Ok(grpc.server_streaming(subscribe, req).await)
+        }
+        .boxed()
+    }
+}
+
+/// `Subscribe` method of the [`Envelopes`] service.
+struct Subscribe(Arc<Mutex<Hub>>);
+
+impl ServerStreamingService<SubscribeRequest> for Subscribe {
+    type Response = Envelope;
+    type ResponseStream = BoxStream<Envelope>;
+    type Future = future::Ready<Result<Response<Self::ResponseStream>, Status>>;
+
+    fn call(&mut self, _: Request<SubscribeRequest>) -> Self::Future {
+        future::ready(Ok(Response::new(lock(&self.0).subscribe())))
+    }
+}
@@ -10,10 +10,13 @@
 
 //! Feature structure and utilities for Cucumber JSON format.
 
+use std::{fmt::Display, path::Path};
+
 use serde::Serialize;
 
 use crate::{
     feature::ExpandExamplesError,
+    parser,
     writer::{
         basic::trim_path,
         json::{
@@ -67,44 +70,12 @@ impl Feature {
 
     /// Creates a new [`Feature`] from the given [`ExpandExamplesError`].
     pub fn example_expansion_err(err: &ExpandExamplesError) -> Self {
-        Self {
-            uri: err
-                .path
-                .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .map(str::to_owned),
-            keyword: String::new(),
-            name: String::new(),
-            tags: vec![],
-            elements: vec![Element {
-                after: vec![],
-                before: vec![],
-                keyword: String::new(),
-                r#type: "scenario",
-                id: format!(
-                    "failed-to-expand-examples{}",
-                    err.path
-                        .as_ref()
-                        .and_then(|p| p.to_str().map(trim_path))
-                        .unwrap_or_default(),
-                ),
-                line: 0,
-                name: String::new(),
-                tags: vec![],
-                steps: vec![Step {
-                    keyword: String::new(),
-                    line: err.pos.line,
-                    name: "scenario".into(),
-                    hidden: false,
-                    result: RunResult {
-                        status: Status::Failed,
-                        duration: 0,
-                        error_message: Some(err.to_string()),
-                    },
-                    embeddings: vec![],
-                }],
-            }],
-        }
+        Self::failed(
+            "failed-to-expand-examples",
+            err.path.as_deref(),
+            err.pos.line,
+            err,
+        )
     }
 
     /// Creates a new [`Feature`] from the given [`gherkin::ParseFileError`].
@@ -112,13 +83,34 @@ impl Feature {
         let path = match err {
             gherkin::ParseFileError::Reading { path, .. }
             | gherkin::ParseFileError::Parsing { path, .. } => path,
-        }
-        .to_str()
-        .map(trim_path)
-        .map(str::to_owned);
+        };
+        Self::failed("failed-to-parse", Some(path), 0, err)
+    }
+
+    /// Creates a new [`Feature`] from the given [`parser::Error::Fetching`].
+    pub fn fetching_err(err: &parser::Error) -> Self {
+        let location = if let parser::Error::Fetching { location, .. } = err {
+            Some(Path::new(location))
+        } else {
+            None
+        };
+        Self::failed("failed-to-fetch", location, 0, err)
+    }
+
+    /// Creates a new [`Feature`] of the `.feature` file at the provided
+    /// `path` failed to be parsed, with a single failed [`Step`] at the
+    /// provided `line`, identified by the provided `kind` of the failure.
+    fn failed(
+        kind: &str,
+        path: Option<&Path>,
+        line: usize,
+        err: &impl Display,
+    ) -> Self {
+        let uri =
+            path.and_then(|p| p.to_str().map(trim_path)).map(str::to_owned);
 
         Self {
-            uri: path.clone(),
+            uri: uri.clone(),
             keyword: String::new(),
             name: String::new(),
             tags: vec![],
@@ -127,16 +119,13 @@ impl Feature {
                 before: vec![],
                 keyword: String::new(),
                 r#type: "scenario",
-                id: format!(
-                    "failed-to-parse{}",
-                    path.as_deref().unwrap_or_default(),
-                ),
+                id: format!("{kind}{}", uri.as_deref().unwrap_or_default()),
                 line: 0,
                 name: String::new(),
                 tags: vec![],
                 steps: vec![Step {
                     keyword: String::new(),
-                    line: 0,
+                    line,
                     name: "scenario".into(),
                     hidden: false,
                     result: RunResult {
@@ -10,7 +10,7 @@
 
 //! Event handling utilities for JSON writer.
 
-use std::{mem, time::SystemTime};
+use std::{mem, time::Duration};
 
 use crate::{
     event::{self, Scenario},
@@ -33,10 +33,11 @@ pub struct EventHandler {
     /// [1]: https://github.com/cucumber/cucumber-json-schema
     pub features: Vec<Feature>,
 
-    /// [`SystemTime`] when the current [`Hook`]/[`crate::step::Step`] has started.
+    /// [`Event::offset`] of the current [`Hook`]/[`crate::step::Step`] start.
     ///
+    /// [`Event::offset`]: crate::Event::offset
     /// [`Hook`]: event::Hook
-    pub started: Option<SystemTime>,
+    pub started: Option<Duration>,
 
     /// [`crate::event::Scenario::Log`]s of the current [`Hook`]/[`crate::step::Step`].
     ///
@@ -69,7 +70,7 @@ impl EventHandler {
     ) {
         match ev {
             Scenario::Started => {}
-            Scenario::Hook(ty, ev) => {
+            Scenario::Hook(ty, _, ev) => {
                 self.handle_hook_event(feature, rule, scenario, ty, ev, meta);
             }
             Scenario::Background(st, ev) => {
@@ -89,7 +90,23 @@ impl EventHandler {
             Scenario::Log(msg) => {
                 self.logs.push(msg);
             }
-            Scenario::Finished => {
+            Scenario::SlaViolated(violation) => {
+                // Reported as an `after` hook, so it's distinguishable from the
+                // assertion failures of the steps.
+                let (status, error_message) = if violation.fails_scenario {
+                    (Status::Failed, Some(violation.to_string()))
+                } else {
+                    (Status::Passed, None)
+                };
+                let res = HookResult {
+                    result: RunResult { status, duration: 0, error_message },
+                    embeddings: vec![Embedding::from_log(violation.to_string())],
+                };
+                self.mut_or_insert_element(feature, rule, scenario, "scenario")
+                    .after
+                    .push(res);
+            }
+            Scenario::Finished(_) => {
                 self.logs.clear();
             }
         }
@@ -117,22 +134,12 @@ impl EventHandler {
                     return 0;
                 }
             };
-            meta.at
-                .duration_since(started)
-                .unwrap_or_else(|e| {
-                    eprintln!(
-                        "Warning: Failed to compute duration between {:?} and \
-                         {started:?}: {e}",
-                        meta.at,
-                    );
-                    std::time::Duration::ZERO
-                })
-                .as_nanos()
+            meta.offset.saturating_sub(started).as_nanos()
         };
 
         let res = match event {
             Hook::Started => {
-                self.started = Some(meta.at);
+                self.started = Some(meta.offset);
                 return;
             }
             Hook::Passed => HookResult {
@@ -190,25 +197,20 @@ impl EventHandler {
                     return 0;
                 }
             };
-            meta.at
-                .duration_since(started)
-                .unwrap_or_else(|e| {
-                    eprintln!(
-                        "Warning: failed to compute duration between {:?} and \
-                         {started:?}: {e}",
-                        meta.at,
-                    );
-                    std::time::Duration::ZERO
-                })
-                .as_nanos()
+            meta.offset.saturating_sub(started).as_nanos()
         };
 
         let result = match event {
             event::Step::Started => {
-                self.started = Some(meta.at);
+                self.started = Some(meta.offset);
                 _ = self.mut_or_insert_element(feature, rule, scenario, ty);
                 return;
             }
+            event::Step::Heartbeat { .. } => return,
+            event::Step::Published(ev) => {
+                self.logs.push(format!("{ev}\n"));
+                return;
+            }
             event::Step::Passed { .. } => {
                 self.stats.record_passed_step();
                 RunResult {
@@ -217,33 +219,54 @@ impl EventHandler {
                     error_message: None,
                 }
             }
-            event::Step::Failed { location, error, .. } => {
+            event::Step::Failed { location, error, owner, backtrace, .. } => {
                 self.stats.record_failed_step();
                 let status = match &error {
                     event::StepError::NotFound => Status::Undefined,
                     event::StepError::AmbiguousMatch(..) => Status::Ambiguous,
-                    event::StepError::Panic(..) => Status::Failed,
+                    event::StepError::Pending(..) => Status::Pending,
+                    event::StepError::Panic(..)
+                    | event::StepError::ArgMismatch(..)
+                    | event::StepError::Returned(..)
+                    | event::StepError::Checks(..)
+                    | event::StepError::Timeout(..) => Status::Failed,
                 };
                 RunResult {
                     status,
                     duration: duration(),
                     error_message: Some(format!(
-                        "{}{error}",
+                        "{}{}{error}{}",
                         location
                             .map(|l| format!(
                                 "Matched: {}:{}:{}\n",
                                 l.path, l.line, l.column,
                             ))
                             .unwrap_or_default(),
+                        owner
+                            .as_ref()
+                            .map(|o| format!("Owner: {o}\n"))
+                            .unwrap_or_default(),
+                        backtrace
+                            .as_ref()
+                            .map(|bt| format!("\nBacktrace:\n{bt}"))
+                            .unwrap_or_default(),
                     )),
                 }
             }
-            event::Step::Skipped => {
+            event::Step::Skipped(reason) => {
                 self.stats.record_skipped_step();
                 RunResult {
                     status: Status::Skipped,
                     duration: duration(),
-                    error_message: None,
+                    error_message: reason.as_ref().map(ToString::to_string),
+                }
+            }
+            event::Step::NotRun => {
+                self.stats.record_skipped_step();
+                RunResult {
+                    status: Status::Skipped,
+                    duration: duration(),
+                    error_message: Some("Not run".into()),
                 }
             }
         };
@@ -421,7 +444,7 @@ mod tests {
             &feature,
             None,
             &scenario,
-            Scenario::Finished::<TestWorld>,
+            Scenario::Finished::<TestWorld>(None),
             meta,
         );
 
@@ -446,7 +469,7 @@ mod tests {
         );
 
         assert!(handler.started.is_some());
-        assert_eq!(handler.started.unwrap(), meta.at);
+        assert_eq!(handler.started.unwrap(), meta.offset);
     }
 
     #[test]
@@ -454,10 +477,13 @@ mod tests {
         let mut handler = EventHandler::new();
         let feature = create_test_feature();
         let scenario = create_test_scenario();
-        let start_time = SystemTime::now();
-        let end_time = start_time + std::time::Duration::from_millis(100);
+        let end = Metadata {
+            at: SystemTime::now(),
+            offset: Duration::from_millis(150),
+            value: (),
+        };
 
-        handler.started = Some(start_time);
+        handler.started = Some(Duration::from_millis(50));
         handler.logs.push("Hook log".to_string());
 
         handler.handle_hook_event(
@@ -466,7 +492,7 @@ mod tests {
             &scenario,
             HookType::Before,
             Hook::Passed::<TestWorld>,
-            Metadata { at: end_time, value: () },
+            end,
         );
 
         assert_eq!(handler.features.len(), 1);
@@ -475,7 +501,7 @@ mod tests {
 
         let hook_result = &handler.features[0].elements[0].before[0];
         assert_eq!(hook_result.result.status, Status::Passed);
-        assert!(hook_result.result.duration > 0);
+        assert_eq!(hook_result.result.duration, 100_000_000);
         assert!(hook_result.result.error_message.is_none());
         assert_eq!(hook_result.embeddings.len(), 1);
     }
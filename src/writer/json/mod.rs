@@ -44,7 +44,7 @@ mod integration_tests {
     use crate::{
         Event, World, Writer, cli,
         event::{
-            Cucumber, Feature as FeatureEvent, Hook, HookType, Metadata,
+            Cucumber, Feature as FeatureEvent, HookScope, HookType, Metadata,
             Scenario, Step as StepEvent,
         },
         parser::Result as ParserResult,
@@ -117,6 +117,7 @@ mod integration_tests {
                     crate::event::RetryableScenario {
                         event: Scenario::Started::<TestWorld>,
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
@@ -132,6 +133,7 @@ mod integration_tests {
                     crate::event::RetryableScenario {
                         event: Scenario::Step::<TestWorld>(crate::event::Source::new(step.clone()), StepEvent::Started),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
@@ -147,6 +149,7 @@ mod integration_tests {
                     crate::event::RetryableScenario {
                         event: Scenario::<TestWorld>::Log("Step execution log".to_string()),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
@@ -165,9 +168,11 @@ mod integration_tests {
                             StepEvent::Passed {
                                 captures: regex::Regex::new(r"").unwrap().capture_locations(),
                                 location: None,
+                                timing: crate::event::StepTiming::default(),
                             },
                         ),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
@@ -181,8 +186,9 @@ mod integration_tests {
                 FeatureEvent::Scenario(
                     crate::event::Source::new(scenario.clone()),
                     crate::event::RetryableScenario {
-                        event: Scenario::<TestWorld>::Hook(HookType::Before, Hook::Started),
+                        event: Scenario::<TestWorld>::hook_started(HookType::Before, HookScope::Function),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
@@ -195,8 +201,9 @@ mod integration_tests {
                 FeatureEvent::Scenario(
                     crate::event::Source::new(scenario.clone()),
                     crate::event::RetryableScenario {
-                        event: Scenario::<TestWorld>::Hook(HookType::Before, Hook::Passed),
+                        event: Scenario::<TestWorld>::hook_passed(HookType::Before, HookScope::Function),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
@@ -210,8 +217,9 @@ mod integration_tests {
                 FeatureEvent::Scenario(
                     crate::event::Source::new(scenario.clone()),
                     crate::event::RetryableScenario {
-                        event: Scenario::<TestWorld>::Finished,
+                        event: Scenario::<TestWorld>::Finished(None),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
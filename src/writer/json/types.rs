@@ -102,10 +102,19 @@ pub enum Status {
     /// [`crate::event::Step::Passed`].
     Passed,
 
-    /// [`crate::event::Step::Failed`] with an [`crate::event::StepError::Panic`].
+    /// [`crate::event::Step::Failed`] with an [`crate::event::StepError::Panic`],
+    /// an [`crate::event::StepError::ArgMismatch`], an
+    /// [`crate::event::StepError::Returned`] or an
+    /// [`crate::event::StepError::Timeout`] (the [JSON schema][1] has no
+    /// distinct status for it).
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
     Failed,
 
-    /// [`crate::event::Step::Skipped`].
+    /// [`crate::event::Step::Skipped`] or [`crate::event::Step::NotRun`] (the
+    /// [JSON schema][1] has no distinct status for the latter).
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
     Skipped,
 
     /// [`crate::event::Step::Failed`] with an [`crate::event::StepError::AmbiguousMatch`].
@@ -114,9 +123,7 @@ pub enum Status {
     /// [`crate::event::Step::Failed`] with an [`crate::event::StepError::NotFound`].
     Undefined,
 
-    /// Never constructed and is here only to fully describe [JSON schema][1].
-    ///
-    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    /// [`crate::event::Step::Failed`] with an [`crate::event::StepError::Pending`].
     Pending,
 }
 
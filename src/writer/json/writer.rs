@@ -63,6 +63,10 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
                 let feature = Feature::example_expansion_err(&e);
                 self.handler.features.push(feature);
             }
+            Err(e @ parser::Error::Fetching { .. }) => {
+                let feature = Feature::fetching_err(&e);
+                self.handler.features.push(feature);
+            }
             Ok((
                 Cucumber::Feature(f, event::Feature::Scenario(sc, ev)),
                 meta,
@@ -190,7 +194,10 @@ impl<Out: io::Write> Json<Out> {
 
 #[cfg(test)]
 mod tests {
-    use std::{io::Cursor, time::SystemTime};
+    use std::{
+        io::Cursor,
+        time::{Duration, SystemTime},
+    };
 
     use super::*;
     use crate::{
@@ -288,6 +295,7 @@ mod tests {
                     crate::event::RetryableScenario {
                         event: Scenario::Log::<TestWorld>("Test log message".to_string()),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
@@ -384,10 +392,12 @@ mod tests {
                     event::RetryableScenario {
                         event: event::Scenario::<TestWorld>::Started,
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
             at: SystemTime::UNIX_EPOCH,
+            offset: Duration::ZERO,
         };
 
         // Test that metadata can be created and used
@@ -414,10 +424,12 @@ mod tests {
                     event::RetryableScenario {
                         event: Scenario::<TestWorld>::Started,
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
             at: start_time,
+            offset: Duration::ZERO,
         };
 
         writer.handle_event(Ok(start_event), &cli::Empty).await;
@@ -467,6 +479,7 @@ mod tests {
                         event::RetryableScenario {
                             event: event::Scenario::<TestWorld>::Started,
                             retries: None,
+                            id: None,
                         },
                     ),
                 ));
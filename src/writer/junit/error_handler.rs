@@ -58,6 +58,9 @@ impl ErrorHandler {
                 ),
                 "Example Expansion Error",
             ),
+            parser::Error::Fetching { location, .. } => {
+                (format!("Feature: {location}"), "Fetching Error")
+            }
         }
     }
 }
@@ -1,8 +1,8 @@
 //! Event handling logic for JUnit XML writer.
 
-use std::{fmt::Debug, io, mem, time::SystemTime};
+use std::{fmt::Debug, io, mem, time::Duration};
 
-use junit_report::TestSuiteBuilder;
+use junit_report::{OffsetDateTime, TestSuiteBuilder};
 
 use super::{
     error_handler::ErrorHandler, test_case_builder::JUnitTestCaseBuilder,
@@ -10,6 +10,7 @@ use super::{
 use crate::{
     Event, World,
     event::{self, Scenario},
+    time::SystemTime,
     writer::basic::trim_path,
 };
 
@@ -46,7 +47,13 @@ impl<W: World + Debug, Out: io::Write> EventHandler<W, Out> {
                 .map(|path| format!(": {path}"))
                 .unwrap_or_default(),
         ))
-        .set_timestamp(meta.at.into())
+        .set_timestamp(
+            OffsetDateTime::UNIX_EPOCH
+                + meta
+                    .at
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap_or_default(),
+        )
         .build()
     }
 
@@ -58,22 +65,23 @@ impl<W: World + Debug, Out: io::Write> EventHandler<W, Out> {
         sc: &gherkin::Scenario,
         ev: event::RetryableScenario<W>,
         meta: Event<()>,
-        scenario_started_at: &mut Option<SystemTime>,
+        scenario_started_at: &mut Option<Duration>,
         events: &mut Vec<event::RetryableScenario<W>>,
         suite: &mut Option<junit_report::TestSuite>,
     ) {
         match &ev.event {
             Scenario::Started => {
-                *scenario_started_at = Some(meta.at);
+                *scenario_started_at = Some(meta.offset);
                 events.push(ev);
             }
             Scenario::Log(_)
+            | Scenario::SlaViolated(_)
             | Scenario::Hook(..)
             | Scenario::Background(..)
             | Scenario::Step(..) => {
                 events.push(ev);
             }
-            Scenario::Finished => {
+            Scenario::Finished(_) => {
                 let started_at = scenario_started_at.take().unwrap_or_else(|| {
                     panic!(
                         "no `Started` event for `Scenario` \"{}\"\n{WRAP_ADVICE}",
@@ -82,7 +90,9 @@ impl<W: World + Debug, Out: io::Write> EventHandler<W, Out> {
                 });
 
                 let duration = JUnitTestCaseBuilder::<W>::calculate_duration(
-                    started_at, meta.at, sc,
+                    started_at,
+                    meta.offset,
+                    sc,
                 );
                 let scenario_events = mem::take(events);
                 let test_case = self.test_case_builder.build_test_case(
@@ -141,7 +151,7 @@ impl<W: World + Debug, Out: io::Write> EventHandler<W, Out> {
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{path::PathBuf, time::SystemTime};
 
     use gherkin::{Feature, LineCol, Scenario};
     use junit_report::Report;
@@ -194,7 +204,11 @@ mod tests {
     }
 
     fn create_test_event() -> Event<()> {
-        Event { value: (), at: SystemTime::UNIX_EPOCH }
+        Event {
+            value: (),
+            at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
+        }
     }
 
     #[test]
@@ -234,6 +248,7 @@ mod tests {
         let event = event::RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         };
 
         let mut scenario_started_at = None;
@@ -254,7 +269,7 @@ mod tests {
             &mut suite,
         );
 
-        assert_eq!(scenario_started_at, Some(SystemTime::UNIX_EPOCH));
+        assert_eq!(scenario_started_at, Some(Duration::ZERO));
         assert_eq!(events.len(), 1);
         
         // Test that handler state was properly modified
@@ -285,12 +300,14 @@ mod tests {
                         .unwrap()
                         .capture_locations(),
                     location: None,
+                    timing: event::StepTiming::default(),
                 },
             ),
             retries: None,
+            id: None,
         };
 
-        let mut scenario_started_at = Some(SystemTime::UNIX_EPOCH);
+        let mut scenario_started_at = Some(Duration::ZERO);
         let mut events = vec![];
         let mut suite =
             Some(EventHandler::<TestWorld, Vec<u8>>::handle_feature_started(
@@ -325,16 +342,19 @@ mod tests {
         let meta = Event {
             value: (),
             at: SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(100),
+            offset: std::time::Duration::from_millis(100),
         };
         let finished_event = event::RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries: None,
+            id: None,
         };
 
-        let mut scenario_started_at = Some(SystemTime::UNIX_EPOCH);
+        let mut scenario_started_at = Some(Duration::ZERO);
         let mut events = vec![event::RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }];
         let mut suite =
             Some(EventHandler::<TestWorld, Vec<u8>>::handle_feature_started(
@@ -439,8 +459,9 @@ mod tests {
         let scenario = create_test_scenario();
         let meta = create_test_event();
         let finished_event = event::RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries: None,
+            id: None,
         };
 
         let mut scenario_started_at = None;
@@ -472,11 +493,12 @@ mod tests {
         let scenario = create_test_scenario();
         let meta = create_test_event();
         let finished_event = event::RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries: None,
+            id: None,
         };
 
-        let mut scenario_started_at = Some(SystemTime::UNIX_EPOCH);
+        let mut scenario_started_at = Some(Duration::ZERO);
         let mut events = vec![];
         let mut suite = None;
 
@@ -87,14 +87,18 @@ mod tests {
         let cli = Cli::default();
 
         // Start Cucumber
-        let cucumber_start =
-            Ok(Event { value: Cucumber::Started, at: SystemTime::UNIX_EPOCH });
+        let cucumber_start = Ok(Event {
+            value: Cucumber::Started,
+            at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
+        });
         writer.handle_event(cucumber_start, &cli).await;
 
         // Start Feature
         let feature_start = Ok(Event {
             value: Cucumber::Feature(event::Source::new(feature.clone()), FeatureEvent::Started),
             at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
         });
         writer.handle_event(feature_start, &cli).await;
 
@@ -107,10 +111,12 @@ mod tests {
                     event::RetryableScenario {
                         event: ScenarioEvent::Started,
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
             at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
         });
         writer.handle_event(scenario_start, &cli).await;
 
@@ -137,13 +143,16 @@ mod tests {
                                     .unwrap()
                                     .capture_locations(),
                                 location: None,
+                                timing: event::StepTiming::default(),
                             },
                         ),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
             at: SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(50),
+            offset: std::time::Duration::from_millis(50),
         });
         writer.handle_event(step_event, &cli).await;
 
@@ -154,12 +163,14 @@ mod tests {
                 FeatureEvent::Scenario(
                     event::Source::new(scenario.clone()),
                     event::RetryableScenario {
-                        event: ScenarioEvent::Finished,
+                        event: ScenarioEvent::Finished(None),
                         retries: None,
+                        id: None,
                     },
                 ),
             ),
             at: SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(100),
+            offset: std::time::Duration::from_millis(100),
         });
         writer.handle_event(scenario_finish, &cli).await;
 
@@ -167,6 +178,7 @@ mod tests {
         let feature_finish = Ok(Event {
             value: Cucumber::Feature(event::Source::new(feature.clone()), FeatureEvent::Finished),
             at: SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(150),
+            offset: std::time::Duration::from_millis(150),
         });
         writer.handle_event(feature_finish, &cli).await;
 
@@ -174,6 +186,7 @@ mod tests {
         let cucumber_finish = Ok(Event {
             value: Cucumber::Finished,
             at: SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(200),
+            offset: std::time::Duration::from_millis(200),
         });
         writer.handle_event(cucumber_finish, &cli).await;
 
@@ -198,6 +211,7 @@ mod tests {
                         FeatureEvent::Started,
                     ),
                     at: SystemTime::UNIX_EPOCH,
+                    offset: std::time::Duration::ZERO,
                 }),
                 &cli,
             )
@@ -213,10 +227,12 @@ mod tests {
                             event::RetryableScenario {
                                 event: ScenarioEvent::Started,
                                 retries: None,
+                                id: None,
                             },
                         ),
                     ),
                     at: SystemTime::UNIX_EPOCH,
+                    offset: std::time::Duration::ZERO,
                 }),
                 &cli,
             )
@@ -245,17 +261,23 @@ mod tests {
                                     Step::Failed {
                                         captures: None,
                                         location: None,
+                                        matched: None,
                                         world: None,
                                         error:
                                             crate::event::StepError::NotFound,
+                                        owner: None,
+                                        timing: event::StepTiming::default(),
+                                        backtrace: None,
                                     },
                                 ),
                                 retries: None,
+                                id: None,
                             },
                         ),
                     ),
                     at: SystemTime::UNIX_EPOCH
                         + std::time::Duration::from_millis(50),
+                    offset: std::time::Duration::from_millis(50),
                 }),
                 &cli,
             )
@@ -270,13 +292,15 @@ mod tests {
                         FeatureEvent::Scenario(
                             event::Source::new(scenario.clone()),
                             event::RetryableScenario {
-                                event: ScenarioEvent::Finished,
+                                event: ScenarioEvent::Finished(None),
                                 retries: None,
+                                id: None,
                             },
                         ),
                     ),
                     at: SystemTime::UNIX_EPOCH
                         + std::time::Duration::from_millis(100),
+                    offset: std::time::Duration::from_millis(100),
                 }),
                 &cli,
             )
@@ -291,6 +315,7 @@ mod tests {
                     ),
                     at: SystemTime::UNIX_EPOCH
                         + std::time::Duration::from_millis(150),
+                    offset: std::time::Duration::from_millis(150),
                 }),
                 &cli,
             )
@@ -302,6 +327,7 @@ mod tests {
                     value: Cucumber::Finished,
                     at: SystemTime::UNIX_EPOCH
                         + std::time::Duration::from_millis(200),
+                    offset: std::time::Duration::from_millis(200),
                 }),
                 &cli,
             )
@@ -335,6 +361,7 @@ mod tests {
                 Ok(Event {
                     value: Cucumber::Finished,
                     at: SystemTime::UNIX_EPOCH,
+                    offset: std::time::Duration::ZERO,
                 }),
                 &cli,
             )
@@ -1,12 +1,12 @@
 //! Test case building utilities for JUnit XML writer.
 
-use std::{fmt::Debug, io, mem, time::SystemTime};
+use std::{fmt::Debug, io, mem, time::Duration as StdDuration};
 
 use junit_report::{Duration, TestCase, TestCaseBuilder};
 
 use crate::{
     World,
-    event::{self, Hook, HookType, Scenario, Step},
+    event::{self, Hook, HookType, Scenario, SlaViolation, Step},
     writer::{
         Verbosity,
         basic::{Coloring, coerce_error, trim_path},
@@ -53,30 +53,27 @@ impl<W: World + Debug> JUnitTestCaseBuilder<W> {
         case
     }
 
-    /// Calculates scenario duration from start and end times.
+    /// Calculates scenario duration from the monotonic [`Event::offset`]s of
+    /// its start and end.
+    ///
+    /// [`Event::offset`]: crate::Event::offset
     pub fn calculate_duration(
-        started_at: SystemTime,
-        ended_at: SystemTime,
+        started_at: StdDuration,
+        ended_at: StdDuration,
         sc: &gherkin::Scenario,
     ) -> Duration {
-        Duration::try_from(ended_at.duration_since(started_at).unwrap_or_else(
+        Duration::try_from(ended_at.saturating_sub(started_at)).unwrap_or_else(
             |e| {
                 panic!(
-                    "failed to compute duration for scenario '{}' between {ended_at:?} and \
-                     {started_at:?}: {e}",
+                    "cannot convert `std::time::Duration` to `time::Duration` for scenario '{}': {e}",
                     sc.name
                 )
             },
-        ))
-        .unwrap_or_else(|e| {
-            panic!(
-                "cannot convert `std::time::Duration` to `time::Duration` for scenario '{}': {e}",
-                sc.name
-            )
-        })
+        )
     }
 
-    /// Finds the last meaningful event (excluding logs and after hooks).
+    /// Finds the last meaningful event (excluding logs, after hooks and not
+    /// run steps).
     fn find_last_meaningful_event<'a>(
         &self,
         events: &'a [event::RetryableScenario<W>],
@@ -89,8 +86,25 @@ impl<W: World + Debug> JUnitTestCaseBuilder<W> {
                 !matches!(
                     ev.event,
                     Scenario::Log(_)
+                        | Scenario::Background(
+                            _,
+                            Step::Heartbeat { .. }
+                            | Step::Published(_)
+                            | Step::NotRun,
+                        )
+                        | Scenario::Step(
+                            _,
+                            Step::Heartbeat { .. }
+                            | Step::Published(_)
+                            | Step::NotRun,
+                        )
+                        | Scenario::SlaViolated(SlaViolation {
+                            fails_scenario: false,
+                            ..
+                        })
                         | Scenario::Hook(
                             HookType::After,
+                            _,
                             Hook::Passed | Hook::Started,
                         ),
                 )
@@ -134,33 +148,61 @@ impl<W: World + Debug> JUnitTestCaseBuilder<W> {
         match &last_event.event {
             Scenario::Started
             | Scenario::Log(_)
-            | Scenario::Hook(_, Hook::Started | Hook::Passed)
-            | Scenario::Background(_, Step::Started | Step::Passed { .. })
-            | Scenario::Step(_, Step::Started | Step::Passed { .. }) => {
+            | Scenario::SlaViolated(SlaViolation {
+                fails_scenario: false, ..
+            })
+            | Scenario::Hook(_, _, Hook::Started | Hook::Passed)
+            | Scenario::Background(
+                _,
+                Step::Started
+                | Step::Heartbeat { .. }
+                | Step::Published(_)
+                | Step::Passed { .. },
+            )
+            | Scenario::Step(
+                _,
+                Step::Started
+                | Step::Heartbeat { .. }
+                | Step::Published(_)
+                | Step::Passed { .. },
+            ) => {
                 TestCaseBuilder::success(case_name, duration).build()
             }
-            Scenario::Background(_, Step::Skipped)
-            | Scenario::Step(_, Step::Skipped) => {
+            Scenario::Background(_, Step::Skipped(_) | Step::NotRun)
+            | Scenario::Step(_, Step::Skipped(_) | Step::NotRun) => {
                 TestCaseBuilder::skipped(case_name).build()
             }
-            Scenario::Hook(_, Hook::Failed(_, e)) => TestCaseBuilder::failure(
+            Scenario::Hook(_, _, Hook::Failed(_, e)) => TestCaseBuilder::failure(
                 case_name,
                 duration,
                 "Hook Panicked",
                 coerce_error(e).as_ref(),
             )
             .build(),
+            Scenario::SlaViolated(v) => TestCaseBuilder::failure(
+                case_name,
+                duration,
+                "SLA Violated",
+                &v.to_string(),
+            )
+            .build(),
             Scenario::Background(_, Step::Failed { error: e, .. })
             | Scenario::Step(_, Step::Failed { error: e, .. }) => {
                 TestCaseBuilder::failure(
                     case_name,
                     duration,
-                    "Step Panicked",
+                    if e.is_timeout() {
+                        "Step Timed Out"
+                    } else if e.is_pending() {
+                        "Step Pending"
+                    } else {
+                        "Step Panicked"
+                    },
                     &e.to_string(),
                 )
                 .build()
             }
-            Scenario::Finished => {
+            Scenario::Finished(_) => {
                 panic!(
                     "Duplicated `Finished` event for `Scenario`: \"{}\"\n\
                      {WRAP_ADVICE}",
@@ -208,7 +250,7 @@ mod tests {
     use super::*;
     use crate::{
         Event,
-        event::{self, Hook, HookType, Step, StepError},
+        event::{self, Hook, HookScope, HookType, Step, StepError},
         writer::Verbosity,
     };
 
@@ -261,6 +303,7 @@ mod tests {
             event::RetryableScenario {
                 event: event::Scenario::Started,
                 retries: None,
+                id: None,
             },
             event::RetryableScenario {
                 event: event::Scenario::Step(
@@ -278,9 +321,11 @@ mod tests {
                             .unwrap()
                             .capture_locations(),
                         location: None,
+                        timing: event::StepTiming::default(),
                     },
                 ),
                 retries: None,
+                id: None,
             },
         ];
 
@@ -319,11 +364,16 @@ mod tests {
                 Step::Failed {
                     captures: None,
                     location: None,
+                    matched: None,
                     world: None,
                     error: StepError::NotFound,
+                    owner: None,
+                    timing: event::StepTiming::default(),
+                    backtrace: None,
                 },
             ),
             retries: None,
+            id: None,
         }];
 
         let test_case = builder.build_test_case(
@@ -356,9 +406,10 @@ mod tests {
                     position: LineCol { line: 8, col: 5 },
                     span: gherkin::Span { start: 0, end: 0 },
                 }),
-                Step::Skipped,
+                Step::Skipped(None),
             ),
             retries: None,
+            id: None,
         }];
 
         let test_case = builder.build_test_case(
@@ -393,6 +444,7 @@ mod tests {
         let events = vec![event::RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }];
 
         let test_case = builder.build_test_case(
@@ -412,8 +464,8 @@ mod tests {
 
     #[test]
     fn calculates_duration_correctly() {
-        let start = SystemTime::UNIX_EPOCH;
-        let end = start + std::time::Duration::from_millis(500);
+        let start = StdDuration::from_millis(100);
+        let end = StdDuration::from_millis(600);
         let scenario = create_test_scenario();
 
         let duration = JUnitTestCaseBuilder::<TestWorld>::calculate_duration(
@@ -451,14 +503,25 @@ mod tests {
             event::RetryableScenario {
                 event: event::Scenario::Started,
                 retries: None,
+                id: None,
             },
             event::RetryableScenario {
-                event: event::Scenario::Hook(HookType::Before, Hook::Started),
+                event: event::Scenario::Hook(
+                    HookType::Before,
+                    HookScope::Function,
+                    Hook::Started,
+                ),
                 retries: None,
+                id: None,
             },
             event::RetryableScenario {
-                event: event::Scenario::Hook(HookType::Before, Hook::Passed),
+                event: event::Scenario::Hook(
+                    HookType::Before,
+                    HookScope::Function,
+                    Hook::Passed,
+                ),
                 retries: None,
+                id: None,
             },
         ];
 
@@ -485,13 +548,16 @@ mod tests {
             event::RetryableScenario {
                 event: event::Scenario::Started,
                 retries: None,
+                id: None,
             },
             event::RetryableScenario {
                 event: event::Scenario::Hook(
                     HookType::After,
+                    HookScope::Function,
                     Hook::Failed(None, std::sync::Arc::new(hook_error)),
                 ),
                 retries: None,
+                id: None,
             },
         ];
 
@@ -519,6 +585,7 @@ mod tests {
         let events = vec![event::RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }];
 
         let test_case = builder.build_test_case(
@@ -1,6 +1,6 @@
 //! Main JUnit XML writer implementation.
 
-use std::{fmt::Debug, io, time::SystemTime};
+use std::{fmt::Debug, io, time::Duration};
 
 use junit_report::Report;
 
@@ -39,10 +39,10 @@ pub struct JUnit<W, Out: io::Write> {
     /// [1]: https://llg.cubic.org/docs/junit
     suit: Option<junit_report::TestSuite>,
 
-    /// [`SystemTime`] when the current [`gherkin::Scenario`] has started.
+    /// [`Event::offset`] of the current [`gherkin::Scenario`] start.
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
-    scenario_started_at: Option<SystemTime>,
+    scenario_started_at: Option<Duration>,
 
     /// Current [`gherkin::Scenario`] [events][1].
     ///
@@ -100,7 +100,12 @@ where
                     &err,
                 );
             }
-            Ok((Cucumber::Started | Cucumber::ParsingFinished { .. }, _)) => {}
+            Ok((
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::Report(_),
+                _,
+            )) => {}
             Ok((Cucumber::Feature(feat, ev), meta)) => match ev {
                 Feature::Started => {
                     self.suit =
@@ -114,7 +119,7 @@ where
                         &feat,
                         Some(&r),
                         &sc,
-                        ev,
+                        *ev,
                         meta,
                         &mut self.scenario_started_at,
                         &mut self.events,
@@ -291,6 +296,7 @@ mod tests {
         let event = Ok(Event {
             value: Cucumber::Feature(event::Source::new(feature.clone()), FeatureEvent::Started),
             at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
         });
         let cli = Cli::default();
 
@@ -312,6 +318,7 @@ mod tests {
         let start_event = Ok(Event {
             value: Cucumber::Feature(event::Source::new(feature.clone()), FeatureEvent::Started),
             at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
         });
         writer.handle_event(start_event, &cli).await;
 
@@ -319,6 +326,7 @@ mod tests {
         let finish_event = Ok(Event {
             value: Cucumber::Feature(event::Source::new(feature.clone()), FeatureEvent::Finished),
             at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
         });
         writer.handle_event(finish_event, &cli).await;
 
@@ -330,8 +338,11 @@ mod tests {
     async fn handles_cucumber_finished_event() {
         let output = Vec::new();
         let mut writer = JUnit::<TestWorld, _>::raw(output, Verbosity::Default);
-        let event =
-            Ok(Event { value: Cucumber::Finished, at: SystemTime::UNIX_EPOCH });
+        let event = Ok(Event {
+            value: Cucumber::Finished,
+            at: SystemTime::UNIX_EPOCH,
+            offset: std::time::Duration::ZERO,
+        });
         let cli = Cli::default();
 
         writer.handle_event(event, &cli).await;
@@ -12,31 +12,90 @@
 
 use std::str::FromStr;
 
+use crate::writer::basic::{self, SHOW_OUTPUT};
+
 /// CLI options of a [`Libtest`] [`crate::Writer`].
 ///
+/// The `--show-output` CLI option is shared with the one of the
+/// [`writer::Basic`], if it's declared already (like when using
+/// [`Libtest::or_basic()`]).
+///
 /// [`Libtest`]: crate::writer::Libtest
+/// [`Libtest::or_basic()`]: crate::writer::Libtest::or_basic
 /// [`crate::Writer`]: crate::Writer
-#[derive(Clone, Debug, Default, clap::Args)]
-#[group(skip)]
+/// [`writer::Basic`]: crate::writer::Basic
+#[derive(Clone, Debug, Default)]
 pub struct Cli {
     /// Formatting of the output.
-    #[arg(long, value_name = "json")]
     pub format: Option<Format>,
 
     /// Show captured stdout of successful tests. Currently, outputs only step
     /// function location.
-    #[arg(long)]
     pub show_output: bool,
 
     /// Show execution time of each test.
-    #[arg(long, value_name = "plain|colored", default_missing_value = "plain")]
     pub report_time: Option<ReportTime>,
 
     /// Enable nightly-only flags.
-    #[arg(short = 'Z')]
     pub nightly: Option<String>,
 }
 
+/// [`Cli`] options, except the `--show-output` one.
+#[derive(clap::Args)]
+#[group(skip)]
+struct Options {
+    /// Formatting of the output.
+    #[arg(long, value_name = "json")]
+    format: Option<Format>,
+
+    /// Show execution time of each test.
+    #[arg(long, value_name = "plain|colored", default_missing_value = "plain")]
+    report_time: Option<ReportTime>,
+
+    /// Enable nightly-only flags.
+    #[arg(short = 'Z')]
+    nightly: Option<String>,
+}
+
+impl clap::FromArgMatches for Cli {
+    fn from_arg_matches(
+        matches: &clap::ArgMatches,
+    ) -> clap::error::Result<Self> {
+        let Options { format, report_time, nightly } =
+            Options::from_arg_matches(matches)?;
+        Ok(Self {
+            format,
+            // Not removed from the `matches`, as may be shared with the
+            // `basic::Cli`.
+            show_output: matches.get_flag(SHOW_OUTPUT),
+            report_time,
+            nightly,
+        })
+    }
+
+    fn update_from_arg_matches(
+        &mut self,
+        matches: &clap::ArgMatches,
+    ) -> clap::error::Result<()> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl clap::Args for Cli {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        basic::augment_show_output(
+            Options::augment_args(cmd),
+            "Show captured stdout of successful tests. Currently, outputs \
+             only step function location",
+        )
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
 /// Output formats.
 ///
 /// Currently, supports only JSON.
@@ -230,6 +289,34 @@ mod tests {
             assert_eq!(cli2.nightly, Some("unstable".to_string()));
         }
 
+        #[test]
+        fn cli_shares_show_output_with_basic() {
+            use clap::Parser as _;
+
+            use crate::cli;
+
+            let opts = cli::Opts::<
+                cli::Empty,
+                cli::Empty,
+                cli::Compose<basic::Cli, Cli>,
+            >::try_parse_from([
+                "test",
+                "--format=json",
+                "--show-output",
+            ])
+            .unwrap();
+            assert!(opts.writer.left.show_output);
+            assert!(opts.writer.right.show_output);
+
+            let opts =
+                cli::Opts::<cli::Empty, cli::Empty, Cli>::try_parse_from([
+                    "test",
+                    "--show-output",
+                ])
+                .unwrap();
+            assert!(opts.writer.show_output);
+        }
+
         #[test]
         fn cli_debug() {
             let cli = Cli::default();
@@ -101,7 +101,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
 
         match event.map(Event::split) {
             Ok((Cucumber::Started, meta)) => {
-                self.started_at = Some(meta.at);
+                self.started_at = Some(meta.offset);
                 Vec::new()
             }
             Ok((Cucumber::ParsingFinished { steps, parser_errors, .. }, _)) => {
@@ -110,15 +110,18 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                         .into(),
                 ]
             }
+            Ok((Cucumber::Report(_), _)) => Vec::new(),
             Ok((Cucumber::Finished, meta)) => {
                 let exec_time = self
                     .started_at
-                    .and_then(|started| meta.at.duration_since(started).ok())
+                    .map(|started| meta.offset.saturating_sub(started))
                     .as_ref()
                     .map(std::time::Duration::as_secs_f64);
 
-                let failed =
-                    self.failed + self.parsing_errors + self.hook_errors;
+                let failed = self.failed
+                    + self.parsing_errors
+                    + self.hook_errors
+                    + self.sla_violations;
                 let results = SuiteResults {
                     passed: self.passed,
                     failed,
@@ -153,11 +156,16 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
         let path = match &e {
             parser::Error::Parsing(e) => match &**e {
                 gherkin::ParseFileError::Parsing { path, .. }
-                | gherkin::ParseFileError::Reading { path, .. } => Some(path),
+                | gherkin::ParseFileError::Reading { path, .. } => {
+                    path.to_str()
+                }
             },
-            parser::Error::ExampleExpansion(e) => e.path.as_ref(),
+            parser::Error::ExampleExpansion(e) => {
+                e.path.as_ref().and_then(|p| p.to_str())
+            }
+            parser::Error::Fetching { location, .. } => Some(location.as_str()),
         };
-        let name = path.and_then(|p| p.to_str()).map_or_else(
+        let name = path.map_or_else(
             || self.parsing_errors.to_string(),
             |p| p.escape_default().to_string(),
         );
@@ -188,7 +196,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     feature,
                     Some(&rule),
                     &scenario,
-                    ev,
+                    *ev,
                     meta,
                     cli,
                 ),
@@ -211,8 +219,8 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
 
         let retries = ev.retries;
         match ev.event {
-            Scenario::Started | Scenario::Finished => Vec::new(),
-            Scenario::Hook(ty, ev) => self.expand_hook_event(
+            Scenario::Started | Scenario::Finished(_) => Vec::new(),
+            Scenario::Hook(ty, _, ev) => self.expand_hook_event(
                 feature, rule, scenario, ty, ev, retries, meta, cli,
             ),
             Scenario::Background(step, ev) => self.expand_step_event(
@@ -234,6 +242,16 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 print!("{msg}");
                 vec![]
             }
+            // See `Scenario::Log` above for why `print!()` is used.
+            #[expect( // intentional
+                clippy::print_stdout,
+                reason = "supporting `libtest` output capturing properly"
+            )]
+            Scenario::SlaViolated(violation) => {
+                self.sla_violations += 1;
+                println!("{violation}");
+                vec![]
+            }
         }
     }
 
@@ -317,6 +335,16 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 LibtestUtils::step_started_at(self, meta, cli);
                 TestEvent::started(name)
             }
+            Step::Heartbeat { .. } => return vec![],
+            // See `Scenario::Log` above for why `print!()` is used.
+            #[expect( // intentional
+                clippy::print_stdout,
+                reason = "supporting `libtest` output capturing properly"
+            )]
+            Step::Published(ev) => {
+                println!("{ev}");
+                return vec![];
+            }
             Step::Passed { location, .. } => {
                 self.passed += 1;
 
@@ -345,7 +373,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     event
                 }
             }
-            Step::Skipped => {
+            Step::Skipped(_) | Step::NotRun => {
                 self.ignored += 1;
 
                 let event = TestEvent::ignored(
@@ -367,7 +395,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     event
                 }
             }
-            Step::Failed { location, world, error, .. } => {
+            Step::Failed { location, world, error, owner, .. } => {
                 if retries.is_some_and(|r| {
                     r.left > 0 && !matches!(error, event::StepError::NotFound)
                 }) {
@@ -381,7 +409,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     LibtestUtils::step_exec_time(self, meta, cli),
                 )
                 .with_stdout(format!(
-                    "{}:{}:{} (defined){}\n{error}{}",
+                    "{}:{}:{} (defined){}{}\n{error}{}",
                     feature
                         .path
                         .as_ref()
@@ -395,6 +423,10 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                             l.path, l.line, l.column,
                         ))
                         .unwrap_or_default(),
+                    owner
+                        .as_ref()
+                        .map(|o| format!("\n{o} (owner)"))
+                        .unwrap_or_default(),
                     world.map(|w| format!("\n{w:#?}")).unwrap_or_default(),
                 ))
             }
@@ -429,7 +461,11 @@ mod tests {
             let cli = Cli::default();
 
             // Create a mock Started event
-            let meta = Event { value: (), at: SystemTime::now() };
+            let meta = Event {
+                value: (),
+                at: SystemTime::now(),
+                offset: Duration::ZERO,
+            };
             let event = Ok(meta.insert(event::Cucumber::Started));
 
             writer.handle_cucumber_event(event, &cli);
@@ -444,15 +480,16 @@ mod tests {
             let mut writer = Libtest::<MockWorld, Vec<u8>>::raw(Vec::new());
             let cli = Cli::default();
 
-            let start_time = SystemTime::now();
-            let meta = Event { value: (), at: start_time };
+            let start = Duration::from_secs(3);
+            let meta =
+                Event { value: (), at: SystemTime::now(), offset: start };
             let event = Ok(meta.insert(event::Cucumber::Started));
 
             // Simulate parsing finished to trigger processing
             writer.parsed_all = true;
             writer.handle_cucumber_event(event, &cli);
 
-            assert_eq!(writer.started_at, Some(start_time));
+            assert_eq!(writer.started_at, Some(start));
         }
 
         #[test]
@@ -469,6 +506,7 @@ mod tests {
                     parser_errors: 2,
                 },
                 at: SystemTime::now(),
+                offset: Duration::ZERO,
             });
 
             let events = writer.expand_cucumber_event(event, &cli);
@@ -496,13 +534,12 @@ mod tests {
             writer.parsing_errors = 0;
             writer.hook_errors = 0;
 
-            let start_time = SystemTime::now();
-            writer.started_at = Some(start_time);
-            let finish_time = start_time + Duration::from_secs(1);
+            writer.started_at = Some(Duration::ZERO);
 
             let event = Ok(Event {
                 value: event::Cucumber::Finished,
-                at: finish_time,
+                at: SystemTime::now(),
+                offset: Duration::from_secs(1),
             });
 
             let events = writer.expand_cucumber_event(event, &cli);
@@ -534,6 +571,7 @@ mod tests {
             let event = Ok(Event {
                 value: event::Cucumber::Finished,
                 at: SystemTime::now(),
+                offset: Duration::ZERO,
             });
 
             let events = writer.expand_cucumber_event(event, &cli);
@@ -618,7 +656,11 @@ mod tests {
             let cli = Cli::default();
 
             // Add some events before parsing is finished
-            let meta = Event { value: (), at: SystemTime::now() };
+            let meta = Event {
+                value: (),
+                at: SystemTime::now(),
+                offset: Duration::ZERO,
+            };
             let event1 = Ok(meta.insert(event::Cucumber::Started));
             let event2 = Ok(meta.insert(event::Cucumber::Started));
 
@@ -636,7 +678,11 @@ mod tests {
             let cli = Cli::default();
 
             // Add some events before parsing is finished
-            let meta = Event { value: (), at: SystemTime::now() };
+            let meta = Event {
+                value: (),
+                at: SystemTime::now(),
+                offset: Duration::ZERO,
+            };
             let started_event = Ok(meta.insert(event::Cucumber::Started));
             writer.handle_cucumber_event(started_event, &cli);
 
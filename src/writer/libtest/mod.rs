@@ -37,7 +37,7 @@ pub use writer::{Libtest, Or, OrBasic};
 
 #[cfg(test)]
 mod integration_tests {
-    use std::{io, time::SystemTime};
+    use std::io;
 
     use super::*;
     use crate::{Event, World, Writer, event};
@@ -58,7 +58,7 @@ mod integration_tests {
         let cli = Cli::default();
 
         // Test basic event handling
-        let meta = Event { value: (), at: SystemTime::now() };
+        let meta = Event::new(());
         let started_event = Ok(meta.insert(event::Cucumber::Started));
 
         writer.handle_event(started_event, &cli).await;
@@ -188,7 +188,7 @@ mod integration_tests {
         // Simulate a complete workflow
 
         // 1. Start cucumber
-        let meta = Event { value: (), at: SystemTime::now() };
+        let meta = Event::new(());
         let started_event = Ok(meta.insert(event::Cucumber::Started));
         writer.handle_event(started_event, &cli).await;
 
@@ -10,10 +10,7 @@
 
 //! Utility functions for the libtest writer.
 
-use std::{
-    io,
-    time::{Duration, SystemTime},
-};
+use std::{io, time::Duration};
 
 use either::Either;
 use itertools::Itertools as _;
@@ -21,6 +18,7 @@ use itertools::Itertools as _;
 use super::{cli::{Cli, ReportTime}, writer::Libtest};
 use crate::{
     event::{self, Metadata, Retries},
+    time::SystemTime,
     writer::basic::trim_path,
 };
 
@@ -105,7 +103,9 @@ impl LibtestUtils {
             .join("::")
     }
 
-    /// Saves [`crate::step::Step`] starting [`SystemTime`].
+    /// Saves [`crate::step::Step`] starting [`Event::offset`].
+    ///
+    /// [`Event::offset`]: crate::Event::offset
     ///
     /// [`crate::step::Step`]: gherkin::Step
     pub fn step_started_at<W, Out: io::Write>(
@@ -114,7 +114,7 @@ impl LibtestUtils {
         cli: &Cli,
     ) {
         writer.step_started_at =
-            Some(meta.at).filter(|_| cli.report_time.is_some());
+            Some(meta.offset).filter(|_| cli.report_time.is_some());
     }
 
     /// Retrieves [`Duration`] since the last [`LibtestUtils::step_started_at()`]
@@ -125,9 +125,7 @@ impl LibtestUtils {
         cli: &Cli,
     ) -> Option<Duration> {
         let started = writer.step_started_at.take()?;
-        meta.at
-            .duration_since(started)
-            .ok()
+        Some(meta.offset.saturating_sub(started))
             .filter(|_| cli.report_time.is_some())
     }
 
@@ -460,19 +458,23 @@ mod tests {
                 report_time: Some(ReportTime::Plain),
                 ..Default::default()
             };
-            let time = SystemTime::now();
-            let meta = Event { value: (), at: time };
+            let offset = Duration::from_secs(2);
+            let meta = Event { value: (), at: SystemTime::now(), offset };
 
             LibtestUtils::step_started_at(&mut writer, meta, &cli);
 
-            assert_eq!(writer.step_started_at, Some(time));
+            assert_eq!(writer.step_started_at, Some(offset));
         }
 
         #[test]
         fn step_started_at_without_timing() {
             let mut writer = Libtest::<MockWorld, Vec<u8>>::raw(Vec::new());
             let cli = Cli { report_time: None, ..Default::default() };
-            let meta = Event { value: (), at: SystemTime::now() };
+            let meta = Event {
+                value: (),
+                at: SystemTime::now(),
+                offset: Duration::ZERO,
+            };
 
             LibtestUtils::step_started_at(&mut writer, meta, &cli);
 
@@ -487,11 +489,13 @@ mod tests {
                 ..Default::default()
             };
 
-            let start_time = SystemTime::now();
-            writer.step_started_at = Some(start_time);
+            writer.step_started_at = Some(Duration::from_secs(1));
 
-            let end_time = start_time + Duration::from_millis(500);
-            let meta = Event { value: (), at: end_time };
+            let meta = Event {
+                value: (),
+                at: SystemTime::now(),
+                offset: Duration::from_millis(1500),
+            };
 
             let exec_time =
                 LibtestUtils::step_exec_time(&mut writer, meta, &cli);
@@ -10,7 +10,7 @@
 
 //! Core libtest writer structure and implementation.
 
-use std::{fmt::Debug, io, time::SystemTime};
+use std::{fmt::Debug, io, time::Duration};
 
 use super::cli::{Cli, Format};
 use crate::{
@@ -92,6 +92,11 @@ pub struct Libtest<W, Out: io::Write = io::Stdout> {
     /// [`Hook`]: event::Hook
     pub(super) hook_errors: usize,
 
+    /// Number of [`SlaViolation`]s.
+    ///
+    /// [`SlaViolation`]: event::SlaViolation
+    pub(super) sla_violations: usize,
+
     /// Number of [`Feature`]s with [`path`] set to [`None`].
     ///
     /// This value is used to generate a unique name for each [`Feature`] to
@@ -101,17 +106,17 @@ pub struct Libtest<W, Out: io::Write = io::Stdout> {
     /// [`path`]: gherkin::Feature::path
     pub(super) features_without_path: usize,
 
-    /// [`SystemTime`] when the [`Started`] event was received.
+    /// [`Event::offset`] of the received [`Started`] event.
     ///
     /// [`Started`]: event::Cucumber::Started
-    pub(super) started_at: Option<SystemTime>,
+    pub(super) started_at: Option<Duration>,
 
-    /// [`SystemTime`] when the [`Step::Started`]/[`Hook::Started`] event was
-    /// received.
+    /// [`Event::offset`] of the received [`Step::Started`]/[`Hook::Started`]
+    /// event.
     ///
     /// [`Hook::Started`]: event::Hook::Started
     /// [`Step::Started`]: event::Step::Started
-    pub(super) step_started_at: Option<SystemTime>,
+    pub(super) step_started_at: Option<Duration>,
 
     /// Consolidated statistics tracking.
     pub(super) stats: WriterStats,
@@ -131,6 +136,7 @@ impl<World, Out: Clone + io::Write> Clone for Libtest<World, Out> {
             ignored: self.ignored,
             parsing_errors: self.parsing_errors,
             hook_errors: self.hook_errors,
+            sla_violations: self.sla_violations,
             features_without_path: self.features_without_path,
             started_at: self.started_at,
             step_started_at: self.step_started_at,
@@ -241,6 +247,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
             retried: 0,
             parsing_errors: 0,
             hook_errors: 0,
+            sla_violations: 0,
             ignored: 0,
             features_without_path: 0,
             started_at: None,
@@ -280,6 +287,10 @@ where
     fn hook_errors(&self) -> usize {
         self.hook_errors
     }
+
+    fn sla_violations(&self) -> usize {
+        self.sla_violations
+    }
 }
 
 impl<W, Val, Out> writer::Arbitrary<W, Val> for Libtest<W, Out>
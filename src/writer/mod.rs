@@ -46,8 +46,11 @@ pub mod types;
 // Writer implementations
 pub mod basic;
 pub mod common;
+pub mod deterministic;
 pub mod discard;
 pub mod fail_on_skipped;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 #[cfg(feature = "output-json")]
 pub mod json;
 #[cfg(feature = "output-junit")]
@@ -57,12 +60,19 @@ pub mod libtest;
 pub mod normalize;
 pub mod or;
 pub mod out;
+#[cfg(feature = "record")]
+pub mod record;
 pub mod repeat;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod summarize;
 pub mod tee;
 
 // Re-export core traits and types for backward compatibility
 // Re-export specific writer implementations
+#[cfg(feature = "grpc")]
+#[doc(inline)]
+pub use self::grpc::Grpc;
 #[cfg(feature = "output-json")]
 #[doc(inline)]
 pub use self::json::Json;
@@ -72,6 +82,12 @@ pub use self::junit::JUnit;
 #[cfg(feature = "libtest")]
 #[doc(inline)]
 pub use self::libtest::Libtest;
+#[cfg(feature = "record")]
+#[doc(inline)]
+pub use self::record::Record;
+#[cfg(feature = "server")]
+#[doc(inline)]
+pub use self::server::Server;
 // Re-export writer utilities and combinators
 #[doc(inline)]
 pub use self::{
@@ -80,6 +96,7 @@ pub use self::{
         ErrorFormatter, OutputFormatter, ScenarioContext, StepContext,
         WorldFormatter, WriterExt as CommonWriterExt, WriterStats,
     },
+    deterministic::Deterministic,
     fail_on_skipped::FailOnSkipped,
     normalize::{AssertNormalized, Normalize, Normalized},
     or::Or,
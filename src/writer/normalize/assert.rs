@@ -111,6 +111,18 @@ where
         self.0.retried_steps()
     }
 
+    fn timed_out_steps(&self) -> usize {
+        self.0.timed_out_steps()
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.0.undefined_steps()
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.0.pending_steps()
+    }
+
     fn parsing_errors(&self) -> usize {
         self.0.parsing_errors()
     }
@@ -119,6 +131,10 @@ where
         self.0.hook_errors()
     }
 
+    fn sla_violations(&self) -> usize {
+        self.0.sla_violations()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.0.execution_has_failed()
     }
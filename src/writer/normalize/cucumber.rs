@@ -317,6 +317,7 @@ mod tests {
                 let event_name = match ev.value {
                     Cucumber::Started => "Started",
                     Cucumber::ParsingFinished { .. } => "ParsingFinished",
+                    Cucumber::Report(_) => "Report",
                     Cucumber::Finished => "Finished",
                     Cucumber::Feature(_, feature_event) => {
                         match feature_event {
@@ -469,6 +470,7 @@ mod tests {
         let scenario_event = Event::new(RetryableScenario {
             event: event::Scenario::<TestWorld>::Started,
             retries: None,
+            id: None,
         });
         queue.insert_scenario_event(
             &feature,
@@ -555,6 +557,7 @@ mod tests {
         let scenario_event = Event::new(RetryableScenario {
             event: event::Scenario::<TestWorld>::Started,
             retries: None,
+            id: None,
         });
         feature_queue.insert_scenario_event(
             Some(rule.clone()),
@@ -580,6 +583,7 @@ mod tests {
         let scenario_event = Event::new(RetryableScenario {
             event: event::Scenario::<TestWorld>::Started,
             retries: None,
+            id: None,
         });
         feature_queue.insert_scenario_event(
             None,
@@ -147,6 +147,7 @@ mod tests {
                 let event_name = match ev.value {
                     Cucumber::Started => "Started",
                     Cucumber::ParsingFinished { .. } => "ParsingFinished",
+                    Cucumber::Report(_) => "Report",
                     Cucumber::Finished => "Finished",
                     Cucumber::Feature(_, _) => "Feature",
                 };
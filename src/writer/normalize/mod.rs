@@ -113,6 +113,7 @@ mod integration_tests {
                     event::Cucumber::ParsingFinished { .. } => {
                         "ParsingFinished".to_string()
                     }
+                    event::Cucumber::Report(_) => "Report".to_string(),
                     event::Cucumber::Finished => "CucumberFinished".to_string(),
                     event::Cucumber::Feature(_, feature_event) => {
                         match feature_event {
@@ -127,7 +128,7 @@ mod integration_tests {
                                     event::Scenario::Started => {
                                         "ScenarioStarted".to_string()
                                     }
-                                    event::Scenario::Finished => {
+                                    event::Scenario::Finished(_) => {
                                         "ScenarioFinished".to_string()
                                     }
                                     _ => "Scenario".to_string(),
@@ -148,7 +149,7 @@ mod integration_tests {
                                         event::Scenario::Started => {
                                             "RuleScenarioStarted".to_string()
                                         }
-                                        event::Scenario::Finished => {
+                                        event::Scenario::Finished(_) => {
                                             "RuleScenarioFinished".to_string()
                                         }
                                         _ => "RuleScenario".to_string(),
@@ -316,6 +317,7 @@ mod integration_tests {
         queue.0.push(Event::new(event::RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }));
 
         // Should now have a current item
@@ -268,8 +268,9 @@ mod tests {
         // Add a scenario that will finish immediately
         let mut scenarios_queue = ScenariosQueue::new();
         scenarios_queue.0.push(Event::new(RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries: None,
+            id: None,
         }));
         queue.fifo.insert((scenario.clone(), None), scenarios_queue);
 
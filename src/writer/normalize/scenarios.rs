@@ -61,7 +61,7 @@ impl<World> Emitter<World> for &mut ScenariosQueue<World> {
     ) -> Option<Self::Emitted> {
         while let Some((ev, meta)) = self.current_item().map(Event::split) {
             let should_be_removed =
-                matches!(ev.event, event::Scenario::Finished)
+                matches!(ev.event, event::Scenario::Finished(_))
                     .then(|| ev.retries);
 
             let ev = meta.wrap(event::Cucumber::scenario(
@@ -123,7 +123,7 @@ mod tests {
                                     event::Scenario::Started => {
                                         "ScenarioStarted"
                                     }
-                                    event::Scenario::Finished => {
+                                    event::Scenario::Finished(_) => {
                                         "ScenarioFinished"
                                     }
                                     _ => "Scenario",
@@ -138,7 +138,7 @@ mod tests {
                                         event::Scenario::Started => {
                                             "ScenarioStarted"
                                         }
-                                        event::Scenario::Finished => {
+                                        event::Scenario::Finished(_) => {
                                             "ScenarioFinished"
                                         }
                                         _ => "Scenario",
@@ -210,6 +210,7 @@ mod tests {
         queue.0.push(Event::new(RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }));
 
         let cloned = queue.clone();
@@ -231,6 +232,7 @@ mod tests {
         queue.0.push(Event::new(RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }));
 
         let current = (&mut queue).current_item();
@@ -254,6 +256,7 @@ mod tests {
         queue.0.push(Event::new(RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }));
 
         let result = (&mut queue)
@@ -274,8 +277,9 @@ mod tests {
         let scenario = create_test_scenario();
 
         queue.0.push(Event::new(RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries: None,
+            id: None,
         }));
 
         let result = (&mut queue)
@@ -299,6 +303,7 @@ mod tests {
         queue.0.push(Event::new(RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }));
 
         let result = (&mut queue)
@@ -319,8 +324,9 @@ mod tests {
         let retries = Some(Retries { current: 1, left: 3 });
 
         queue.0.push(Event::new(RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries,
+            id: None,
         }));
 
         let result = (&mut queue)
@@ -342,10 +348,12 @@ mod tests {
         queue.0.push(Event::new(RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }));
         queue.0.push(Event::new(RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries: None,
+            id: None,
         }));
 
         let result = (&mut queue)
@@ -387,10 +395,12 @@ mod tests {
         queue.0.push(Event::new(RetryableScenario {
             event: event::Scenario::Started,
             retries: None,
+            id: None,
         }));
         queue.0.push(Event::new(RetryableScenario {
-            event: event::Scenario::Finished,
+            event: event::Scenario::Finished(None),
             retries: None,
+            id: None,
         }));
 
         // Should get events in FIFO order
@@ -403,7 +413,7 @@ mod tests {
         let second = (&mut queue).current_item();
         assert!(second.is_some());
         if let Some(event) = second {
-            assert!(matches!(event.value.event, event::Scenario::Finished));
+            assert!(matches!(event.value.event, event::Scenario::Finished(_)));
         }
 
         // Should be empty now
@@ -92,7 +92,9 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
         match event.map(Event::split) {
             res @ (Err(_)
             | Ok((
-                Cucumber::Started | Cucumber::ParsingFinished { .. },
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::Report(_),
                 _,
             ))) => {
                 self.writer
@@ -128,7 +130,7 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
                             &f,
                             Some(r),
                             s,
-                            meta.wrap(ev),
+                            meta.wrap(*ev),
                         ) {
                             eprintln!("Warning: Failed to insert scenario event in rule: {}", e);
                         }
@@ -188,6 +190,18 @@ where
         self.writer.retried_steps()
     }
 
+    fn timed_out_steps(&self) -> usize {
+        self.writer.timed_out_steps()
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.writer.undefined_steps()
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
     fn parsing_errors(&self) -> usize {
         self.writer.parsing_errors()
     }
@@ -196,6 +210,10 @@ where
         self.writer.hook_errors()
     }
 
+    fn sla_violations(&self) -> usize {
+        self.writer.sla_violations()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
@@ -249,6 +267,7 @@ mod tests {
                     Cucumber::ParsingFinished { .. } => {
                         "ParsingFinished".to_string()
                     }
+                    Cucumber::Report(_) => "Report".to_string(),
                     Cucumber::Finished => "Finished".to_string(),
                     Cucumber::Feature(_, _) => "Feature".to_string(),
                 };
@@ -102,6 +102,18 @@ where
         self.left.retried_steps() + self.right.retried_steps()
     }
 
+    fn timed_out_steps(&self) -> usize {
+        self.left.timed_out_steps() + self.right.timed_out_steps()
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.left.undefined_steps() + self.right.undefined_steps()
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.left.pending_steps() + self.right.pending_steps()
+    }
+
     fn parsing_errors(&self) -> usize {
         self.left.parsing_errors() + self.right.parsing_errors()
     }
@@ -109,6 +121,10 @@ where
     fn hook_errors(&self) -> usize {
         self.left.hook_errors() + self.right.hook_errors()
     }
+
+    fn sla_violations(&self) -> usize {
+        self.left.sla_violations() + self.right.sla_violations()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -12,8 +12,7 @@
 
 use std::{
     borrow::Cow,
-    io::{self, IsTerminal as _},
-    mem, str,
+    io, mem, str,
 };
 
 use console::Style;
@@ -62,12 +61,40 @@ impl Default for Styles {
             retry: Style::new().magenta(),
             header: Style::new().blue(),
             bold: Style::new().bold(),
-            term_width: console::Term::stdout().size_checked().map(|(_h, w)| w),
-            is_present: io::stdout().is_terminal() && console::colors_enabled(),
+            term_width: terminal_width(),
+            is_present: is_terminal_present(),
         }
     }
 }
 
+/// Detects the width of the terminal attached to [`io::Stdout`].
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+fn terminal_width() -> Option<u16> {
+    console::Term::stdout().size_checked().map(|(_h, w)| w)
+}
+
+/// Detects whether a colored terminal is attached to [`io::Stdout`].
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+fn is_terminal_present() -> bool {
+    use std::io::IsTerminal as _;
+
+    io::stdout().is_terminal() && console::colors_enabled()
+}
+
+/// There is no terminal on `wasm32-unknown-unknown` target, so nothing to
+/// detect.
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+const fn terminal_width() -> Option<u16> {
+    None
+}
+
+/// There is no terminal on `wasm32-unknown-unknown` target, so nothing to
+/// detect.
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+const fn is_terminal_present() -> bool {
+    false
+}
+
 impl Styles {
     /// Creates new [`Styles`].
     #[must_use]
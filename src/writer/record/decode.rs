@@ -0,0 +1,171 @@
+//! Decoding of [`event::Cucumber`]s from their [`wire`] representation.
+
+use std::{collections::HashMap, io, sync::Arc};
+
+use super::{ReplayError, wire};
+use crate::{
+    event::{self, Source},
+    feature::ExpandExamplesError,
+    parser,
+};
+
+/// Decoder of [`event::Cucumber`]s, remembering the already replayed
+/// [`Source`]s.
+#[derive(Debug, Default)]
+pub(super) struct Decoder {
+    /// Replayed [`gherkin::Feature`]s by their identifiers.
+    features: HashMap<u64, Source<gherkin::Feature>>,
+
+    /// Replayed [`gherkin::Rule`]s by their identifiers.
+    rules: HashMap<u64, Source<gherkin::Rule>>,
+
+    /// Replayed [`gherkin::Scenario`]s by their identifiers.
+    scenarios: HashMap<u64, Source<gherkin::Scenario>>,
+
+    /// Replayed [`gherkin::Step`]s by their identifiers.
+    steps: HashMap<u64, Source<gherkin::Step>>,
+}
+
+/// Returns the [`Source`] of the provided `id`.
+fn get<T>(
+    sources: &HashMap<u64, Source<T>>,
+    id: u64,
+) -> Result<Source<T>, ReplayError> {
+    sources.get(&id).cloned().ok_or(ReplayError::UnknownSource(id))
+}
+
+impl Decoder {
+    /// Remembers the provided [`wire::Source`] for the following events.
+    pub(super) fn define(&mut self, source: wire::Source) {
+        match source {
+            wire::Source::Feature(id, f) => {
+                drop(self.features.insert(id, Source::new(f)));
+            }
+            wire::Source::Rule(id, r) => {
+                drop(self.rules.insert(id, Source::new(r)));
+            }
+            wire::Source::Scenario(id, sc) => {
+                drop(self.scenarios.insert(id, Source::new(sc)));
+            }
+            wire::Source::Step(id, s) => {
+                drop(self.steps.insert(id, Source::new(s)));
+            }
+        }
+    }
+
+    /// Decodes the provided [`wire::Cucumber`] event.
+    ///
+    /// # Errors
+    ///
+    /// If the event refers to a [`Source`] not defined before.
+    pub(super) fn decode<W>(
+        &self,
+        event: wire::Cucumber<W>,
+    ) -> Result<event::Cucumber<W>, ReplayError> {
+        Ok(match event {
+            wire::Cucumber::Started => event::Cucumber::Started,
+            wire::Cucumber::Feature(id, ev) => {
+                let feature = get(&self.features, id)?;
+                let ev = match ev {
+                    wire::Feature::Started => event::Feature::Started,
+                    wire::Feature::Rule(rule_id, ev) => {
+                        let rule = get(&self.rules, rule_id)?;
+                        let ev = match ev {
+                            wire::Rule::Started => event::Rule::Started,
+                            wire::Rule::Scenario(sc_id, ev) => {
+                                let (sc, ev) = self.scenario(sc_id, *ev)?;
+                                event::Rule::Scenario(sc, Box::new(ev))
+                            }
+                            wire::Rule::Finished => event::Rule::Finished,
+                        };
+                        event::Feature::Rule(rule, ev)
+                    }
+                    wire::Feature::Scenario(sc_id, ev) => {
+                        let (sc, ev) = self.scenario(sc_id, *ev)?;
+                        event::Feature::Scenario(sc, ev)
+                    }
+                    wire::Feature::Finished => event::Feature::Finished,
+                };
+                event::Cucumber::Feature(feature, ev)
+            }
+            wire::Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+            } => event::Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+            },
+            wire::Cucumber::Report(r) => event::Cucumber::Report(r),
+            wire::Cucumber::Finished => event::Cucumber::Finished,
+        })
+    }
+
+    /// Decodes the provided [`wire::RetryableScenario`] of the
+    /// [`gherkin::Scenario`] with the provided `id`.
+    fn scenario<W>(
+        &self,
+        id: u64,
+        event: wire::RetryableScenario<W>,
+    ) -> Result<
+        (Source<gherkin::Scenario>, event::RetryableScenario<W>),
+        ReplayError,
+    > {
+        let scenario = get(&self.scenarios, id)?;
+        let ev = match event.event {
+            wire::Scenario::Started => event::Scenario::Started,
+            wire::Scenario::Hook(ty, scope, ev) => {
+                event::Scenario::Hook(ty, scope, ev)
+            }
+            wire::Scenario::Background(step_id, ev) => {
+                event::Scenario::Background(get(&self.steps, step_id)?, ev)
+            }
+            wire::Scenario::Step(step_id, ev) => {
+                event::Scenario::Step(get(&self.steps, step_id)?, ev)
+            }
+            wire::Scenario::Log(msg) => event::Scenario::Log(msg),
+            wire::Scenario::SlaViolated(violation) => {
+                event::Scenario::SlaViolated(violation)
+            }
+            wire::Scenario::Finished(usage) => {
+                event::Scenario::Finished(usage)
+            }
+        };
+        let (retries, scenario_id) = (event.retries, event.id);
+        Ok((
+            scenario,
+            event::RetryableScenario { event: ev, retries, id: scenario_id },
+        ))
+    }
+}
+
+/// Decodes the provided [`wire::ParserError`].
+///
+/// As [`gherkin::ParseFileError`] cannot be reconstructed, it's replayed as
+/// [`gherkin::ParseFileError::Reading`] with the recorded message.
+pub(super) fn decode_parser_error(error: wire::ParserError) -> parser::Error {
+    match error {
+        wire::ParserError::Parsing { path, message } => {
+            gherkin::ParseFileError::Reading {
+                path,
+                source: io::Error::other(message),
+            }
+            .into()
+        }
+        wire::ParserError::ExampleExpansion { pos, name, path } => {
+            ExpandExamplesError { pos, name, path }.into()
+        }
+        wire::ParserError::Fetching { location, cached, message } => {
+            parser::Error::Fetching {
+                location,
+                cached,
+                source: Arc::new(io::Error::other(message)),
+            }
+        }
+    }
+}
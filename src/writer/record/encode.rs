@@ -0,0 +1,195 @@
+//! Encoding of [`event::Cucumber`]s into their [`wire`] representation.
+
+use std::{collections::HashMap, error::Error as _};
+
+use super::wire;
+use crate::{
+    event::{self, Source},
+    parser,
+};
+
+/// Encoder of [`event::Cucumber`]s, remembering the already recorded
+/// [`Source`]s.
+#[derive(Debug, Default)]
+pub(super) struct Encoder {
+    /// Identifiers of the recorded [`gherkin::Feature`]s.
+    features: HashMap<Source<gherkin::Feature>, u64>,
+
+    /// Identifiers of the recorded [`gherkin::Rule`]s.
+    rules: HashMap<Source<gherkin::Rule>, u64>,
+
+    /// Identifiers of the recorded [`gherkin::Scenario`]s.
+    scenarios: HashMap<Source<gherkin::Scenario>, u64>,
+
+    /// Identifiers of the recorded [`gherkin::Step`]s.
+    steps: HashMap<Source<gherkin::Step>, u64>,
+
+    /// Identifier of the next recorded [`Source`].
+    next_id: u64,
+}
+
+/// Returns the identifier of the provided [`Source`], pushing it into the
+/// `lines` (via the `wrap` function) if it's not recorded yet.
+fn intern<T: Clone, W>(
+    ids: &mut HashMap<Source<T>, u64>,
+    next_id: &mut u64,
+    source: &Source<T>,
+    lines: &mut Vec<wire::Line<W>>,
+    wrap: fn(u64, T) -> wire::Source,
+) -> u64 {
+    *ids.entry(source.clone()).or_insert_with(|| {
+        let id = *next_id;
+        *next_id += 1;
+        lines.push(wire::Line::Source(Box::new(wrap(id, (**source).clone()))));
+        id
+    })
+}
+
+impl Encoder {
+    /// Encodes the provided [`event::Cucumber`], pushing the [`Source`]s it
+    /// refers to into the `lines`, if they're not recorded yet.
+    pub(super) fn encode<W>(
+        &mut self,
+        event: &event::Cucumber<W>,
+        lines: &mut Vec<wire::Line<W>>,
+    ) -> wire::Cucumber<W> {
+        match event {
+            event::Cucumber::Started => wire::Cucumber::Started,
+            event::Cucumber::Feature(f, ev) => {
+                let id = intern(
+                    &mut self.features,
+                    &mut self.next_id,
+                    f,
+                    lines,
+                    wire::Source::Feature,
+                );
+                wire::Cucumber::Feature(id, self.feature(ev, lines))
+            }
+            event::Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+            } => wire::Cucumber::ParsingFinished {
+                features: *features,
+                rules: *rules,
+                scenarios: *scenarios,
+                steps: *steps,
+                parser_errors: *parser_errors,
+            },
+            event::Cucumber::Report(r) => wire::Cucumber::Report(r.clone()),
+            event::Cucumber::Finished => wire::Cucumber::Finished,
+        }
+    }
+
+    /// Encodes the provided [`event::Feature`].
+    fn feature<W>(
+        &mut self,
+        event: &event::Feature<W>,
+        lines: &mut Vec<wire::Line<W>>,
+    ) -> wire::Feature<W> {
+        match event {
+            event::Feature::Started => wire::Feature::Started,
+            event::Feature::Rule(r, ev) => {
+                let id = intern(
+                    &mut self.rules,
+                    &mut self.next_id,
+                    r,
+                    lines,
+                    wire::Source::Rule,
+                );
+                let ev = match ev {
+                    event::Rule::Started => wire::Rule::Started,
+                    event::Rule::Scenario(sc, ev) => {
+                        let (sc_id, ev) = self.scenario(sc, ev, lines);
+                        wire::Rule::Scenario(sc_id, Box::new(ev))
+                    }
+                    event::Rule::Finished => wire::Rule::Finished,
+                };
+                wire::Feature::Rule(id, ev)
+            }
+            event::Feature::Scenario(sc, ev) => {
+                let (id, ev) = self.scenario(sc, ev, lines);
+                wire::Feature::Scenario(id, Box::new(ev))
+            }
+            event::Feature::Finished => wire::Feature::Finished,
+        }
+    }
+
+    /// Encodes the provided [`event::RetryableScenario`] of the `scenario`,
+    /// returning the identifier of the `scenario` along.
+    fn scenario<W>(
+        &mut self,
+        scenario: &Source<gherkin::Scenario>,
+        event: &event::RetryableScenario<W>,
+        lines: &mut Vec<wire::Line<W>>,
+    ) -> (u64, wire::RetryableScenario<W>) {
+        let id = intern(
+            &mut self.scenarios,
+            &mut self.next_id,
+            scenario,
+            lines,
+            wire::Source::Scenario,
+        );
+        let mut step = |s: &Source<gherkin::Step>| {
+            intern(
+                &mut self.steps,
+                &mut self.next_id,
+                s,
+                lines,
+                wire::Source::Step,
+            )
+        };
+        let ev = match &event.event {
+            event::Scenario::Started => wire::Scenario::Started,
+            event::Scenario::Hook(ty, scope, ev) => {
+                wire::Scenario::Hook(*ty, *scope, ev.clone())
+            }
+            event::Scenario::Background(s, ev) => {
+                wire::Scenario::Background(step(s), ev.clone())
+            }
+            event::Scenario::Step(s, ev) => {
+                wire::Scenario::Step(step(s), ev.clone())
+            }
+            event::Scenario::Log(msg) => wire::Scenario::Log(msg.clone()),
+            event::Scenario::SlaViolated(violation) => {
+                wire::Scenario::SlaViolated(violation.clone())
+            }
+            event::Scenario::Finished(usage) => {
+                wire::Scenario::Finished(*usage)
+            }
+        };
+        let (retries, scenario_id) = (event.retries, event.id);
+        (id, wire::RetryableScenario { event: ev, retries, id: scenario_id })
+    }
+}
+
+/// Encodes the provided [`parser::Error`].
+pub(super) fn encode_parser_error(error: &parser::Error) -> wire::ParserError {
+    match error {
+        parser::Error::Parsing(e) => {
+            let path = match &**e {
+                gherkin::ParseFileError::Reading { path, .. }
+                | gherkin::ParseFileError::Parsing { path, .. } => path.clone(),
+            };
+            let message =
+                e.source().map_or_else(|| e.to_string(), ToString::to_string);
+            wire::ParserError::Parsing { path, message }
+        }
+        parser::Error::ExampleExpansion(e) => {
+            wire::ParserError::ExampleExpansion {
+                pos: e.pos,
+                name: e.name.clone(),
+                path: e.path.clone(),
+            }
+        }
+        parser::Error::Fetching { location, cached, source } => {
+            wire::ParserError::Fetching {
+                location: location.clone(),
+                cached: *cached,
+                message: source.to_string(),
+            }
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Recording of raw [`event::Cucumber`] streams to disk and their replaying.
+//!
+//! [`Record`] [`Writer`] serializes the complete stream of [`event::Cucumber`]s
+//! (without any [`World`]s) as [JSON Lines][1], and [`replay()`] feeds a
+//! recorded stream into any [`Writer`], so reports (like JSON or JUnit ones)
+//! may be regenerated from an old run without re-executing it.
+//!
+//! Panic payloads of the recorded events are stringified, and the
+//! [`gherkin::ParseFileError`]s are replayed as
+//! [`gherkin::ParseFileError::Reading`] ones with the recorded message.
+//!
+//! [1]: https://jsonlines.org
+
+mod decode;
+mod encode;
+mod wire;
+
+use std::io::{self, BufRead};
+
+use derive_more::with_trait::{Display, Error, From};
+
+use self::{decode::Decoder, encode::Encoder};
+use crate::{
+    Event, World, Writer, cli, event, parser,
+    writer::{self, Ext as _},
+};
+
+/// [`Writer`] recording the raw [`event::Cucumber`] stream into the provided
+/// [`io::Write`] implementor, to be [`replay()`]ed later.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::fs::File;
+/// #
+/// # use cucumber::{World, WriterExt as _, writer};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let recording = File::create("run.jsonl").unwrap();
+/// MyWorld::cucumber()
+///     .with_writer(
+///         writer::Basic::stdout()
+///             .summarized()
+///             .tee::<MyWorld, _>(writer::Record::for_tee(recording))
+///             .normalized(),
+///     )
+///     .run("tests/features")
+///     .await;
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Record<Out: io::Write> {
+    /// [`io::Write`] implementor to record the events into.
+    output: Out,
+
+    /// [`Encoder`] of the recorded events.
+    encoder: Encoder,
+
+    /// Indicator whether the header of the recording has been written.
+    started: bool,
+}
+
+impl<Out: io::Write> Record<Out> {
+    /// Creates a new [`Record`] [`Writer`] recording into the given `output`.
+    #[must_use]
+    pub fn new(output: Out) -> Self {
+        Self { output, encoder: Encoder::default(), started: false }
+    }
+
+    /// Creates a new [`Record`] [`Writer`] recording into the given
+    /// `output`, and suitable for feeding into [`tee()`].
+    ///
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(
+        output: Out,
+    ) -> writer::discard::Arbitrary<writer::discard::Stats<Self>> {
+        Self::new(output).discard_stats_writes().discard_arbitrary_writes()
+    }
+
+    /// Writes the provided [`wire::Line`]s into the `output`.
+    fn write<W>(&mut self, lines: &[wire::Line<W>]) -> io::Result<()> {
+        for line in lines {
+            serde_json::to_writer(&mut self.output, line)?;
+            self.output.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: World, Out: io::Write> Writer<W> for Record<Out> {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        let mut lines = Vec::new();
+        if !self.started {
+            self.started = true;
+            lines.push(wire::Line::Header { version: wire::VERSION });
+        }
+        let finished = match event {
+            Ok(ev) => {
+                let (value, meta) = ev.split();
+                let encoded = self.encoder.encode(&value, &mut lines);
+                lines.push(wire::Line::Event {
+                    at: meta.at,
+                    offset: meta.offset,
+                    event: encoded,
+                });
+                matches!(value, event::Cucumber::Finished)
+            }
+            Err(e) => {
+                lines.push(wire::Line::Error(encode::encode_parser_error(&e)));
+                false
+            }
+        };
+
+        let res = self.write(&lines);
+        let res =
+            if finished { res.and_then(|()| self.output.flush()) } else { res };
+        if let Err(e) = res {
+            #[expect(clippy::print_stderr, reason = "no other way to report")]
+            {
+                eprintln!("Warning: Failed to record event: {e}");
+            }
+        }
+    }
+}
+
+impl<Out: io::Write> writer::NonTransforming for Record<Out> {}
+
+/// Error of [`replay()`]ing a recorded [`event::Cucumber`] stream.
+#[derive(Debug, Display, Error, From)]
+pub enum ReplayError {
+    /// Failed to read the recording.
+    #[display("Failed to read recording: {_0}")]
+    Io(io::Error),
+
+    /// Failed to deserialize a line of the recording.
+    #[display("Malformed recording: {_0}")]
+    Malformed(serde_json::Error),
+
+    /// Recording has no header or has an unsupported version of the format.
+    #[display("Unsupported recording version: {_0:?}")]
+    #[from(ignore)]
+    UnsupportedVersion(#[error(not(source))] Option<u32>),
+
+    /// Recorded event refers to an unknown [`gherkin`] value.
+    #[display("Recorded event refers to unknown source #{_0}")]
+    #[from(ignore)]
+    UnknownSource(#[error(not(source))] u64),
+}
+
+/// Replays the [`event::Cucumber`] stream recorded by a [`Record`] [`Writer`]
+/// from the provided `input` into the provided [`Writer`].
+///
+/// Recorded events are fed as they are, so a [`Writer`] requiring
+/// [`Normalized`] events should be wrapped into a [`writer::Normalize`].
+///
+/// # Errors
+///
+/// If the `input` cannot be read or isn't a valid recording. The events
+/// replayed before the error remain fed into the [`Writer`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::{fs::File, io::BufReader};
+/// #
+/// # use cucumber::{World, cli, writer};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let recording = BufReader::new(File::open("run.jsonl")?);
+/// let mut json = writer::Json::new(File::create("report.json")?);
+/// writer::record::replay::<MyWorld, _>(recording, &mut json, &cli::Empty)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Normalized`]: writer::Normalized
+pub async fn replay<W, Wr>(
+    input: impl BufRead,
+    writer: &mut Wr,
+    cli: &Wr::Cli,
+) -> Result<(), ReplayError>
+where
+    W: World,
+    Wr: Writer<W>,
+{
+    let mut decoder = Decoder::default();
+    let mut lines = input.lines();
+
+    let version = match lines.next().transpose()? {
+        Some(header) => match serde_json::from_str::<wire::Line<W>>(&header)? {
+            wire::Line::Header { version } => Some(version),
+            wire::Line::Source(_)
+            | wire::Line::Event { .. }
+            | wire::Line::Error(_) => None,
+        },
+        None => None,
+    };
+    if version != Some(wire::VERSION) {
+        return Err(ReplayError::UnsupportedVersion(version));
+    }
+
+    for line in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event = match serde_json::from_str::<wire::Line<W>>(&line)? {
+            wire::Line::Header { version: other } => {
+                return Err(ReplayError::UnsupportedVersion(Some(other)));
+            }
+            wire::Line::Source(source) => {
+                decoder.define(*source);
+                continue;
+            }
+            wire::Line::Event { at, offset, event } => {
+                Ok(Event { at, offset, value: decoder.decode(event)? })
+            }
+            wire::Line::Error(e) => Err(decode::decode_parser_error(e)),
+        };
+        writer.handle_event(event, cli).await;
+    }
+    Ok(())
+}
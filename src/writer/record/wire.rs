@@ -0,0 +1,242 @@
+//! Serializable representation of the recorded [`event::Cucumber`] stream.
+//!
+//! Every [`Source`] is recorded only once (before the first event referring
+//! to it), and then is referred by its identifier, so the replayed events
+//! share the same [`Source`]s, as the original ones did.
+//!
+//! Other values are represented via their [`serde`] implementations of the
+//! [`event`] module, so the [`World`]s are omitted.
+//!
+//! [`event`]: crate::event
+//! [`event::Cucumber`]: crate::event::Cucumber
+//! [`Source`]: crate::event::Source
+//! [`World`]: crate::World
+
+use std::{path::PathBuf, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{event, runner::basic::ScenarioId, time::SystemTime};
+
+/// Version of the recording format, following the [`event::SCHEMA_VERSION`]
+/// of the recorded values.
+pub(super) const VERSION: u32 = event::SCHEMA_VERSION;
+
+/// Single line of a recording.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(bound = "", rename_all = "snake_case")]
+pub(super) enum Line<W> {
+    /// Header of a recording, being its first line.
+    Header {
+        /// [`VERSION`] of the recording format.
+        version: u32,
+    },
+
+    /// [`Source`] referred by the following events.
+    Source(Box<Source>),
+
+    /// Recorded [`event::Cucumber`].
+    ///
+    /// [`event::Cucumber`]: crate::event::Cucumber
+    Event {
+        /// Time when the [`event::Cucumber`] has happened.
+        ///
+        /// [`event::Cucumber`]: crate::event::Cucumber
+        at: SystemTime,
+
+        /// Monotonic offset of the [`event::Cucumber`] from the start of the
+        /// run.
+        ///
+        /// [`event::Cucumber`]: crate::event::Cucumber
+        #[serde(default)]
+        offset: Duration,
+
+        /// The [`event::Cucumber`] itself.
+        ///
+        /// [`event::Cucumber`]: crate::event::Cucumber
+        event: Cucumber<W>,
+    },
+
+    /// Recorded [`parser::Error`].
+    ///
+    /// [`parser::Error`]: crate::parser::Error
+    Error(ParserError),
+}
+
+/// [`gherkin`] value of an [`event::Source`] along with its identifier.
+///
+/// [`event::Source`]: crate::event::Source
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum Source {
+    /// [`gherkin::Feature`].
+    Feature(u64, gherkin::Feature),
+
+    /// [`gherkin::Rule`].
+    Rule(u64, gherkin::Rule),
+
+    /// [`gherkin::Scenario`].
+    Scenario(u64, gherkin::Scenario),
+
+    /// [`gherkin::Step`].
+    Step(u64, gherkin::Step),
+}
+
+/// Recorded [`event::Cucumber`].
+///
+/// [`event::Cucumber`]: crate::event::Cucumber
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(bound = "", rename_all = "snake_case")]
+pub(super) enum Cucumber<W> {
+    /// Execution being started.
+    Started,
+
+    /// Event of the [`gherkin::Feature`] with the provided identifier.
+    Feature(u64, Feature<W>),
+
+    /// All [`gherkin::Feature`]s have been parsed.
+    ParsingFinished {
+        /// Number of parsed [`gherkin::Feature`]s.
+        features: usize,
+
+        /// Number of parsed [`gherkin::Rule`]s.
+        rules: usize,
+
+        /// Number of parsed [`gherkin::Scenario`]s.
+        scenarios: usize,
+
+        /// Number of parsed [`gherkin::Step`]s.
+        steps: usize,
+
+        /// Number of happened parser errors.
+        parser_errors: usize,
+    },
+
+    /// [`event::Report`] about the whole run.
+    Report(event::Report),
+
+    /// Execution being finished.
+    Finished,
+}
+
+/// Recorded [`event::Feature`].
+///
+/// [`event::Feature`]: crate::event::Feature
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(bound = "", rename_all = "snake_case")]
+pub(super) enum Feature<W> {
+    /// [`gherkin::Feature`] execution being started.
+    Started,
+
+    /// Event of the [`gherkin::Rule`] with the provided identifier.
+    Rule(u64, Rule<W>),
+
+    /// Event of the [`gherkin::Scenario`] with the provided identifier.
+    Scenario(u64, Box<RetryableScenario<W>>),
+
+    /// [`gherkin::Feature`] execution being finished.
+    Finished,
+}
+
+/// Recorded [`event::Rule`].
+///
+/// [`event::Rule`]: crate::event::Rule
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(bound = "", rename_all = "snake_case")]
+pub(super) enum Rule<W> {
+    /// [`gherkin::Rule`] execution being started.
+    Started,
+
+    /// Event of the [`gherkin::Scenario`] with the provided identifier.
+    Scenario(u64, Box<RetryableScenario<W>>),
+
+    /// [`gherkin::Rule`] execution being finished.
+    Finished,
+}
+
+/// Recorded [`event::RetryableScenario`].
+///
+/// [`event::RetryableScenario`]: crate::event::RetryableScenario
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub(super) struct RetryableScenario<W> {
+    /// Happened [`Scenario`] event.
+    pub(super) event: Scenario<W>,
+
+    /// Number of [`event::Retries`].
+    pub(super) retries: Option<event::Retries>,
+
+    /// [`ScenarioId`] of the [`gherkin::Scenario`] attempt, if any.
+    pub(super) id: Option<ScenarioId>,
+}
+
+/// Recorded [`event::Scenario`].
+///
+/// [`event::Scenario`]: crate::event::Scenario
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(bound = "", rename_all = "snake_case")]
+pub(super) enum Scenario<W> {
+    /// [`gherkin::Scenario`] execution being started.
+    Started,
+
+    /// [`event::Hook`] event of the [`event::HookScope`].
+    Hook(event::HookType, event::HookScope, event::Hook<W>),
+
+    /// Event of the background [`gherkin::Step`] with the provided
+    /// identifier.
+    Background(u64, event::Step<W>),
+
+    /// Event of the [`gherkin::Step`] with the provided identifier.
+    Step(u64, event::Step<W>),
+
+    /// Log entry emitted by the [`gherkin::Scenario`].
+    Log(String),
+
+    /// Duration SLA of the [`gherkin::Scenario`] being violated.
+    SlaViolated(event::SlaViolation),
+
+    /// [`gherkin::Scenario`] execution being finished, with the sampled
+    /// [`event::ResourceUsage`].
+    Finished(Option<event::ResourceUsage>),
+}
+
+/// Recorded [`parser::Error`], with the [`gherkin::ParseFileError`] being
+/// stringified.
+///
+/// [`parser::Error`]: crate::parser::Error
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum ParserError {
+    /// Failed to parse a [`gherkin::Feature`].
+    Parsing {
+        /// Path to the `.feature` file.
+        path: PathBuf,
+
+        /// Message of the parsing error.
+        message: String,
+    },
+
+    /// Failed to expand [`gherkin::Examples`].
+    ExampleExpansion {
+        /// Position of the unknown template.
+        pos: gherkin::LineCol,
+
+        /// Name of the unknown template.
+        name: String,
+
+        /// Path to the `.feature` file, if present.
+        path: Option<PathBuf>,
+    },
+
+    /// Failed to fetch a remote input.
+    Fetching {
+        /// Location of the fetched input.
+        location: String,
+
+        /// Indicator whether the previously cached `.feature` files were used.
+        cached: bool,
+
+        /// Message of the fetching error.
+        message: String,
+    },
+}
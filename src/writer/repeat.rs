@@ -121,6 +121,18 @@ where
         self.writer.retried_steps()
     }
 
+    fn timed_out_steps(&self) -> usize {
+        self.writer.timed_out_steps()
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.writer.undefined_steps()
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
     fn parsing_errors(&self) -> usize {
         self.writer.parsing_errors()
     }
@@ -129,6 +141,10 @@ where
         self.writer.hook_errors()
     }
 
+    fn sla_violations(&self) -> usize {
+        self.writer.sla_violations()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
@@ -153,42 +169,20 @@ impl<W, Wr> Repeat<W, Wr> {
     /// Creates [`crate::Writer`] for re-outputting [`Skipped`] events at the end of
     /// an output.
     ///
-    /// [`Skipped`]: event::Step::Skipped
+    /// [`Skipped`]: event::Step::Skipped(_)
     #[must_use]
     pub fn skipped(writer: Wr) -> Self {
-        use event::{
-            Cucumber, Feature, RetryableScenario, Rule, Scenario, Step,
-        };
+        use event::{Scenario, Step};
 
         Self {
             writer,
             filter: |ev| {
                 matches!(
-                    ev.as_deref(),
-                    Ok(Cucumber::Feature(
-                        _,
-                        Feature::Rule(
-                            _,
-                            Rule::Scenario(
-                                _,
-                                RetryableScenario {
-                                    event: Scenario::Step(_, Step::Skipped)
-                                        | Scenario::Background(
-                                            _,
-                                            Step::Skipped
-                                        ),
-                                    ..
-                                }
-                            )
-                        ) | Feature::Scenario(
-                            _,
-                            RetryableScenario {
-                                event: Scenario::Step(_, Step::Skipped)
-                                    | Scenario::Background(_, Step::Skipped),
-                                ..
-                            }
-                        )
-                    )),
+                    scenario_event(ev),
+                    Some(
+                        Scenario::Step(_, Step::Skipped(_))
+                            | Scenario::Background(_, Step::Skipped(_))
+                    ),
                 )
             },
             events: Vec::new(),
@@ -202,49 +196,20 @@ impl<W, Wr> Repeat<W, Wr> {
     /// [`Parser`]: crate::Parser
     #[must_use]
     pub fn failed(writer: Wr) -> Self {
-        use event::{
-            Cucumber, Feature, Hook, RetryableScenario, Rule, Scenario, Step,
-        };
+        use event::{Hook, Scenario, Step};
 
         Self {
             writer,
             filter: |ev| {
-                matches!(
-                    ev.as_deref(),
-                    Ok(Cucumber::Feature(
-                        _,
-                        Feature::Rule(
-                            _,
-                            Rule::Scenario(
-                                _,
-                                RetryableScenario {
-                                    event: Scenario::Step(
-                                        _,
-                                        Step::Failed { .. }
-                                    ) | Scenario::Background(
-                                        _,
-                                        Step::Failed { .. },
-                                    ) | Scenario::Hook(
-                                        _,
-                                        Hook::Failed(..)
-                                    ),
-                                    ..
-                                }
-                            )
-                        ) | Feature::Scenario(
-                            _,
-                            RetryableScenario {
-                                event: Scenario::Step(_, Step::Failed { .. })
-                                    | Scenario::Background(
-                                        _,
-                                        Step::Failed { .. }
-                                    )
-                                    | Scenario::Hook(_, Hook::Failed(..)),
-                                ..
-                            },
-                        )
-                    )) | Err(_),
-                )
+                ev.is_err()
+                    || matches!(
+                        scenario_event(ev),
+                        Some(
+                            Scenario::Step(_, Step::Failed { .. })
+                                | Scenario::Background(_, Step::Failed { .. })
+                                | Scenario::Hook(_, _, Hook::Failed(..))
+                        ),
+                    )
             },
             events: Vec::new(),
         }
@@ -256,3 +221,18 @@ impl<W, Wr> Repeat<W, Wr> {
         &self.writer
     }
 }
+
+/// Returns the [`event::Scenario`] of the provided event, if it's the one.
+fn scenario_event<W>(
+    ev: &parser::Result<Event<event::Cucumber<W>>>,
+) -> Option<&event::Scenario<W>> {
+    use event::{Cucumber, Feature, Rule};
+
+    match ev.as_deref() {
+        Ok(Cucumber::Feature(_, Feature::Scenario(_, sc))) => Some(&sc.event),
+        Ok(Cucumber::Feature(_, Feature::Rule(_, Rule::Scenario(_, sc)))) => {
+            Some(&sc.event)
+        }
+        Ok(_) | Err(_) => None,
+    }
+}
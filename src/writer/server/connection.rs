@@ -0,0 +1,132 @@
+//! Connections of the [`Server`] clients.
+//!
+//! [`Server`]: super::Server
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::{
+    io::{self, Write},
+    net::{Shutdown, TcpStream},
+    sync::{Arc, mpsc},
+    thread,
+    time::Duration,
+};
+
+/// Timeout of writing into a client, after which it's disconnected, so a stuck
+/// client doesn't hold its writing thread.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum number of lines queued for a client, after which it's disconnected,
+/// so a slow client neither blocks the run, nor makes it buffer endlessly.
+const QUEUE_CAPACITY: usize = 1024;
+
+/// Bidirectional connection with a client.
+pub(super) trait Connection:
+    io::Read + Write + Send + Sized + 'static
+{
+    /// Creates a new independent handle to this [`Connection`].
+    fn try_clone(&self) -> io::Result<Self>;
+
+    /// Makes this [`Connection`] blocking, with its writes timing out after
+    /// the provided `timeout`.
+    fn set_blocking(&self, timeout: Duration) -> io::Result<()>;
+
+    /// Shuts down both halves of this [`Connection`].
+    fn close(&self);
+}
+
+impl Connection for TcpStream {
+    fn try_clone(&self) -> io::Result<Self> {
+        Self::try_clone(self)
+    }
+
+    fn set_blocking(&self, timeout: Duration) -> io::Result<()> {
+        // Accepted connections inherit the non-blocking mode of the listener
+        // on some platforms.
+        self.set_nonblocking(false)?;
+        self.set_write_timeout(Some(timeout))
+    }
+
+    fn close(&self) {
+        drop(self.shutdown(Shutdown::Both));
+    }
+}
+
+#[cfg(unix)]
+impl Connection for UnixStream {
+    fn try_clone(&self) -> io::Result<Self> {
+        Self::try_clone(self)
+    }
+
+    fn set_blocking(&self, timeout: Duration) -> io::Result<()> {
+        // Accepted connections inherit the non-blocking mode of the listener
+        // on some platforms.
+        self.set_nonblocking(false)?;
+        self.set_write_timeout(Some(timeout))
+    }
+
+    fn close(&self) {
+        drop(self.shutdown(Shutdown::Both));
+    }
+}
+
+/// Closable [`Connection`] of a [`Client`].
+trait Close: Send {
+    /// Closes this [`Connection`].
+    fn close(&self);
+}
+
+impl<C: Connection> Close for C {
+    fn close(&self) {
+        Connection::close(self);
+    }
+}
+
+/// Writing half of a client [`Connection`], fed through a bounded queue, which
+/// is drained by a separate thread.
+pub(super) struct Client {
+    /// Queue of the lines to be written.
+    queue: mpsc::SyncSender<Arc<[u8]>>,
+
+    /// [`Connection`] being written into.
+    conn: Box<dyn Close>,
+}
+
+impl Client {
+    /// Spawns a new thread writing the queued lines into the provided `conn`,
+    /// until the returned [`Client`] is dropped, or a write fails.
+    ///
+    /// The `conn` is closed once all the queued lines are written.
+    ///
+    /// # Errors
+    ///
+    /// If the `conn` cannot be configured or cloned.
+    pub(super) fn spawn(mut conn: impl Connection) -> io::Result<Self> {
+        conn.set_blocking(WRITE_TIMEOUT)?;
+        let handle = conn.try_clone()?;
+
+        let (queue, lines) = mpsc::sync_channel::<Arc<[u8]>>(QUEUE_CAPACITY);
+        drop(thread::spawn(move || {
+            for line in lines {
+                if conn.write_all(&line).is_err() {
+                    break;
+                }
+            }
+            Connection::close(&conn);
+        }));
+
+        Ok(Self { queue, conn: Box::new(handle) })
+    }
+
+    /// Enqueues the provided `line` to be written.
+    ///
+    /// Returns `false` and disconnects this [`Client`], if it has fallen
+    /// behind the queue capacity or has been disconnected already.
+    pub(super) fn send(&self, line: Arc<[u8]>) -> bool {
+        let sent = self.queue.try_send(line).is_ok();
+        if !sent {
+            self.conn.close();
+        }
+        sent
+    }
+}
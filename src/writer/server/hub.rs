@@ -0,0 +1,190 @@
+//! [`Hub`] of the [`Server`] clients and their commands handling.
+//!
+//! [`Server`]: super::Server
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead as _, BufReader},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use super::{
+    Progress,
+    connection::{Client, Connection},
+};
+use crate::runner::basic::StopSignal;
+
+/// Interval of polling the listener for new clients and the [`Hub`] for being
+/// closed.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Maximum backoff after consecutive failures to accept a client.
+const MAX_ACCEPT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Reply to a client command.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Reply {
+    /// Current [`Progress`] of the run.
+    Progress(Progress),
+
+    /// Graceful stop of the run has been requested.
+    Stopping,
+
+    /// Command cannot be handled.
+    Error {
+        /// Description of the error.
+        message: String,
+    },
+}
+
+/// State shared between the [`Server`] and its client threads.
+///
+/// [`Server`]: super::Server
+pub(super) struct Hub {
+    /// Connected clients by their IDs.
+    clients: HashMap<usize, Client>,
+
+    /// ID of the next connected client.
+    next_id: usize,
+
+    /// [`Progress`] of the run, excluding the [`Progress::stopping`].
+    pub(super) progress: Progress,
+
+    /// [`StopSignal`] requested by the `stop` command.
+    stop: StopSignal,
+
+    /// Indicator whether the [`Server`] has been dropped.
+    ///
+    /// [`Server`]: super::Server
+    closed: bool,
+}
+
+impl Hub {
+    /// Creates a new [`Hub`] requesting the provided [`StopSignal`].
+    pub(super) fn new(stop: StopSignal) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self {
+            clients: HashMap::new(),
+            next_id: 0,
+            progress: Progress::default(),
+            stop,
+            closed: false,
+        }))
+    }
+
+    /// Returns the current [`Progress`] of the run.
+    pub(super) fn progress(&self) -> Progress {
+        Progress { stopping: self.stop.is_requested(), ..self.progress }
+    }
+
+    /// Enqueues the `line` to all the clients, disconnecting the ones falling
+    /// behind.
+    pub(super) fn broadcast(&mut self, line: &[u8]) {
+        let line = Arc::<[u8]>::from(line);
+        self.clients.retain(|_, client| client.send(Arc::clone(&line)));
+    }
+
+    /// Stops accepting new clients and disconnects the connected ones, once
+    /// they receive the already enqueued lines.
+    pub(super) fn close(&mut self) {
+        self.closed = true;
+        self.clients.clear();
+    }
+
+    /// Enqueues the `reply` to the client with the provided `id`.
+    fn reply(&mut self, id: usize, reply: &Reply) {
+        let Some(client) = self.clients.get(&id) else { return };
+        let sent = to_line(reply).is_ok_and(|line| client.send(line.into()));
+        if !sent {
+            drop(self.clients.remove(&id));
+        }
+    }
+}
+
+/// Locks the provided [`Hub`], ignoring the poisoning, as it always remains
+/// consistent.
+pub(super) fn lock(hub: &Mutex<Hub>) -> MutexGuard<'_, Hub> {
+    hub.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Serializes the provided `message` as a single JSON line.
+pub(super) fn to_line(message: &impl Serialize) -> io::Result<Vec<u8>> {
+    let mut line = serde_json::to_vec(message)?;
+    line.push(b'\n');
+    Ok(line)
+}
+
+/// Spawns a thread accepting new clients from the non-blocking `listener` via
+/// the provided `accept` function, and serving each of them in a separate
+/// thread, until the [`Hub`] is closed.
+pub(super) fn spawn_accepting<L, C>(
+    listener: L,
+    accept: fn(&L) -> io::Result<C>,
+    hub: Arc<Mutex<Hub>>,
+) -> JoinHandle<()>
+where
+    L: Send + 'static,
+    C: Connection,
+{
+    thread::spawn(move || {
+        let mut backoff = ACCEPT_POLL_INTERVAL;
+        while !lock(&hub).closed {
+            let conn = match accept(&listener) {
+                Ok(conn) => conn,
+                Err(e) => {
+                    // Persistent failures (like running out of file
+                    // descriptors) are retried less and less often.
+                    backoff = if e.kind() == io::ErrorKind::WouldBlock {
+                        ACCEPT_POLL_INTERVAL
+                    } else {
+                        (backoff * 2).min(MAX_ACCEPT_BACKOFF)
+                    };
+                    thread::sleep(backoff);
+                    continue;
+                }
+            };
+            backoff = ACCEPT_POLL_INTERVAL;
+
+            let Ok((reader, client)) =
+                conn.try_clone().and_then(|r| Ok((r, Client::spawn(conn)?)))
+            else {
+                continue;
+            };
+            let mut locked = lock(&hub);
+            let id = locked.next_id;
+            locked.next_id += 1;
+            drop(locked.clients.insert(id, client));
+            drop(locked);
+
+            let hub = Arc::clone(&hub);
+            drop(thread::spawn(move || serve(reader, id, &hub)));
+        }
+    })
+}
+
+/// Handles the commands of the client with the provided `id`, read line by
+/// line from its `conn`ection, until it disconnects.
+fn serve(conn: impl Connection, id: usize, hub: &Mutex<Hub>) {
+    for line in BufReader::new(conn).lines() {
+        let Ok(line) = line else { break };
+        let reply = match line.trim() {
+            "" => continue,
+            "progress" => Reply::Progress(lock(hub).progress()),
+            "stop" => {
+                lock(hub).stop.request();
+                Reply::Stopping
+            }
+            cmd => Reply::Error {
+                message: format!(
+                    "unknown command `{cmd}`, expected `progress` or `stop`",
+                ),
+            },
+        };
+        lock(hub).reply(id, &reply);
+    }
+    drop(lock(hub).clients.remove(&id));
+}
@@ -0,0 +1,288 @@
+//! Serving the live [`event::Cucumber`] stream to external processes.
+//!
+//! [`Server`] [`Writer`] listens on a Unix socket or a localhost TCP port,
+//! which external processes (like dashboards or orchestrators) may connect to
+//! at any moment of the run. Every connected client receives the events
+//! happened since its connection, and may issue simple commands.
+//!
+//! The protocol is line-based: the [`Server`] writes [JSON Lines][1], each of
+//! them being an object tagged with its `type`:
+//! - `event`: a [serialized][2] [`Event`];
+//! - `parser_error`: a [`parser::Error`] with its `message`;
+//! - `progress`: the [`Progress`] of the run, replied to the `progress`
+//!   command;
+//! - `stopping`: reply to the `stop` command, requesting the
+//!   [`StopSignal`] of the [`Server`];
+//! - `error`: an unknown command with its `message`.
+//!
+//! [1]: https://jsonlines.org
+//! [2]: crate::event::SCHEMA_VERSION
+
+mod connection;
+mod hub;
+mod progress;
+
+#[cfg(unix)]
+use std::{
+    fs,
+    os::unix::{
+        fs::{DirBuilderExt as _, PermissionsExt as _},
+        net::UnixListener,
+    },
+    path::{Path, PathBuf},
+    process,
+};
+use std::{
+    io,
+    net::{SocketAddr, TcpListener, ToSocketAddrs},
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+};
+
+use derive_more::with_trait::Debug;
+use serde::Serialize;
+
+use self::hub::Hub;
+#[doc(inline)]
+pub use self::progress::Progress;
+use crate::{
+    Event, World, Writer, cli, event, parser,
+    runner::basic::StopSignal,
+    writer::{self, Ext as _},
+};
+
+/// [`Writer`] serving the live [`event::Cucumber`] stream and control commands
+/// to external processes over a Unix socket or a localhost TCP port.
+///
+/// See the [module-level documentation](self) for the protocol details.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use cucumber::{World, WriterExt as _, writer};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let server = writer::Server::tcp("127.0.0.1:7878").unwrap();
+/// MyWorld::cucumber()
+///     .stop_signal(server.stop_signal())
+///     .with_writer(
+///         writer::Basic::stdout()
+///             .summarized()
+///             .tee::<MyWorld, _>(server.for_tee())
+///             .normalized(),
+///     )
+///     .run("tests/features")
+///     .await;
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Server {
+    /// State shared with the client threads.
+    #[debug(ignore)]
+    hub: Arc<Mutex<Hub>>,
+
+    /// [`StopSignal`] requested by the `stop` command.
+    stop: StopSignal,
+
+    /// Address of the TCP port being listened on, if any.
+    local_addr: Option<SocketAddr>,
+
+    /// Thread accepting new clients.
+    #[debug(ignore)]
+    accepting: Option<JoinHandle<()>>,
+
+    /// Path of the Unix socket being listened on, if any.
+    #[cfg(unix)]
+    socket_path: Option<PathBuf>,
+}
+
+/// Line written by a [`Server`] to its clients.
+#[derive(Serialize)]
+#[serde(bound = "", tag = "type", rename_all = "snake_case")]
+enum Line<'e, W> {
+    /// Happened [`Event`].
+    Event(&'e Event<event::Cucumber<W>>),
+
+    /// Happened [`parser::Error`].
+    ParserError {
+        /// Description of the [`parser::Error`].
+        message: String,
+    },
+}
+
+impl Server {
+    /// Creates a new [`Server`] listening on the provided localhost TCP `addr`.
+    ///
+    /// # Errors
+    ///
+    /// If the `addr` isn't a loopback one, or cannot be bound.
+    pub fn tcp(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        if !local_addr.ip().is_loopback() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("`{local_addr}` is not a loopback address"),
+            ));
+        }
+
+        listener.set_nonblocking(true)?;
+
+        let mut server = Self::new(Some(local_addr));
+        server.accepting = Some(hub::spawn_accepting(
+            listener,
+            |l| l.accept().map(|(conn, _)| conn),
+            Arc::clone(&server.hub),
+        ));
+        Ok(server)
+    }
+
+    /// Creates a new [`Server`] listening on the Unix socket at the provided
+    /// `path`, which is accessible by its owner only, and is removed once the
+    /// [`Server`] is dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the socket cannot be bound (if the `path` exists already, for
+    /// example).
+    #[cfg(unix)]
+    pub fn unix(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let listener = bind_private(&path)?;
+
+        let mut server = Self::new(None);
+        server.socket_path = Some(path);
+        listener.set_nonblocking(true)?;
+        server.accepting = Some(hub::spawn_accepting(
+            listener,
+            |l| l.accept().map(|(conn, _)| conn),
+            Arc::clone(&server.hub),
+        ));
+        Ok(server)
+    }
+
+    /// Creates a new [`Server`] without listening yet.
+    fn new(local_addr: Option<SocketAddr>) -> Self {
+        let stop = StopSignal::new();
+        Self {
+            hub: Hub::new(stop.clone()),
+            stop,
+            local_addr,
+            accepting: None,
+            #[cfg(unix)]
+            socket_path: None,
+        }
+    }
+
+    /// Wraps this [`Server`] to be suitable for feeding into [`tee()`].
+    ///
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(
+        self,
+    ) -> writer::discard::Arbitrary<writer::discard::Stats<Self>> {
+        self.discard_stats_writes().discard_arbitrary_writes()
+    }
+
+    /// Returns the [`StopSignal`] requested by the `stop` command of the
+    /// clients, to be passed into [`Cucumber::stop_signal()`].
+    ///
+    /// [`Cucumber::stop_signal()`]: crate::Cucumber::stop_signal
+    #[must_use]
+    pub fn stop_signal(&self) -> StopSignal {
+        self.stop.clone()
+    }
+
+    /// Returns the address of the TCP port this [`Server`] listens on, if any.
+    #[must_use]
+    pub const fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// Returns the current [`Progress`] of the run.
+    #[must_use]
+    pub fn progress(&self) -> Progress {
+        hub::lock(&self.hub).progress()
+    }
+}
+
+/// Binds a [`UnixListener`] at the provided `path`, being accessible by its
+/// owner only since the very creation.
+///
+/// The socket is bound inside a private directory first, so no other user
+/// can connect to it before its permissions are tightened, and only then is
+/// linked to the `path` (failing if the `path` exists already).
+#[cfg(unix)]
+fn bind_private(path: &Path) -> io::Result<UnixListener> {
+    let name = path.file_name().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "socket path has no name")
+    })?;
+    let mut dir_name = name.to_owned();
+    dir_name.push(format!(".{}.tmp", process::id()));
+    let dir = path.with_file_name(dir_name);
+
+    fs::DirBuilder::new().mode(0o700).create(&dir)?;
+    let private = dir.join("socket");
+    let bound = UnixListener::bind(&private).and_then(|listener| {
+        fs::set_permissions(&private, fs::Permissions::from_mode(0o600))?;
+        fs::hard_link(&private, path)?;
+        Ok(listener)
+    });
+    drop(fs::remove_file(&private));
+    drop(fs::remove_dir(&dir));
+    bound
+}
+
+impl<W: World> Writer<W> for Server {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        let line = match &event {
+            Ok(ev) => {
+                hub::lock(&self.hub).progress.update(&ev.value);
+                hub::to_line(&Line::Event(ev))
+            }
+            Err(e) => {
+                let line: Line<'_, W> =
+                    Line::ParserError { message: e.to_string() };
+                hub::to_line(&line)
+            }
+        };
+        match line {
+            Ok(line) => hub::lock(&self.hub).broadcast(&line),
+            Err(e) => {
+                #[expect(
+                    clippy::print_stderr,
+                    reason = "no other way to report"
+                )]
+                {
+                    eprintln!("Warning: Failed to serialize event: {e}");
+                }
+            }
+        }
+    }
+}
+
+impl writer::NonTransforming for Server {}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        hub::lock(&self.hub).close();
+        // Notices the closing within a single poll interval.
+        if let Some(accepting) = self.accepting.take() {
+            drop(accepting.join());
+        }
+        #[cfg(unix)]
+        if let Some(path) = self.socket_path.take() {
+            drop(fs::remove_file(path));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Progress of the run reported by a [`Server`].
+//!
+//! [`Server`]: super::Server
+
+use serde::Serialize;
+
+use crate::event::{self, Cucumber, Feature, Rule, Scenario, Step};
+
+/// Progress of the run, reported by a [`Server`] to its clients on the
+/// `progress` command.
+///
+/// Retried [`gherkin::Scenario`]s are counted on every attempt.
+///
+/// [`Server`]: super::Server
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct Progress {
+    /// Total number of the parsed [`gherkin::Scenario`]s, known once all the
+    /// [`gherkin::Feature`]s are parsed.
+    pub scenarios: Option<usize>,
+
+    /// Number of the started [`gherkin::Scenario`]s.
+    pub scenarios_started: usize,
+
+    /// Number of the finished [`gherkin::Scenario`]s.
+    pub scenarios_finished: usize,
+
+    /// Number of the passed [`gherkin::Step`]s.
+    pub steps_passed: usize,
+
+    /// Number of the skipped [`gherkin::Step`]s.
+    pub steps_skipped: usize,
+
+    /// Number of the failed [`gherkin::Step`]s.
+    pub steps_failed: usize,
+
+    /// Indicator whether a graceful stop of the run has been requested.
+    pub stopping: bool,
+
+    /// Indicator whether the run has finished.
+    pub finished: bool,
+}
+
+impl Progress {
+    /// Accounts the provided [`event::Cucumber`] in this [`Progress`].
+    pub(super) const fn update<W>(&mut self, event: &event::Cucumber<W>) {
+        let scenario = match event {
+            Cucumber::ParsingFinished { scenarios, .. } => {
+                self.scenarios = Some(*scenarios);
+                return;
+            }
+            Cucumber::Finished => {
+                self.finished = true;
+                return;
+            }
+            Cucumber::Feature(_, Feature::Scenario(_, ev)) => &ev.event,
+            Cucumber::Feature(_, Feature::Rule(_, Rule::Scenario(_, ev))) => {
+                &ev.event
+            }
+            Cucumber::Started | Cucumber::Report(_) | Cucumber::Feature(..) => {
+                return;
+            }
+        };
+
+        match scenario {
+            Scenario::Started => self.scenarios_started += 1,
+            Scenario::Finished(_) => self.scenarios_finished += 1,
+            Scenario::Background(_, step) | Scenario::Step(_, step) => {
+                match step {
+                    Step::Passed { .. } => self.steps_passed += 1,
+                    Step::Skipped(_) | Step::NotRun => {
+                        self.steps_skipped += 1;
+                    }
+                    Step::Failed { .. } => self.steps_failed += 1,
+                    Step::Started
+                    | Step::Heartbeat { .. }
+                    | Step::Published(_) => {}
+                }
+            }
+            Scenario::Hook(..)
+            | Scenario::Log(_)
+            | Scenario::SlaViolated(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::{
+        Cucumber, Feature, RetryableScenario, Scenario, Source, Step,
+    };
+
+    use super::Progress;
+
+    /// Wraps the provided [`Scenario`] event into a [`Cucumber`] one.
+    fn scenario(event: Scenario<()>) -> Cucumber<()> {
+        let scenario = gherkin::Scenario {
+            keyword: "Scenario".into(),
+            name: "test".into(),
+            description: None,
+            tags: vec![],
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+            steps: vec![],
+            examples: vec![],
+        };
+        Cucumber::Feature(
+            Source::new(gherkin::Feature {
+                tags: vec![],
+                keyword: "Feature".into(),
+                name: "test".into(),
+                span: gherkin::Span { start: 0, end: 0 },
+                position: gherkin::LineCol { line: 1, col: 1 },
+                path: None,
+                description: None,
+                background: None,
+                scenarios: vec![],
+                rules: vec![],
+            }),
+            Feature::Scenario(
+                Source::new(scenario),
+                RetryableScenario { event, retries: None, id: None },
+            ),
+        )
+    }
+
+    #[test]
+    fn counts_events() {
+        let step = Source::new(gherkin::Step {
+            keyword: "Given".into(),
+            ty: gherkin::StepType::Given,
+            value: "a step".into(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 1, col: 1 },
+        });
+        let mut progress = Progress::default();
+        for ev in [
+            Cucumber::Started,
+            Cucumber::ParsingFinished {
+                features: 1,
+                rules: 0,
+                scenarios: 2,
+                steps: 2,
+                parser_errors: 0,
+            },
+            scenario(Scenario::Started),
+            scenario(Scenario::Step(step.clone(), Step::Started)),
+            scenario(Scenario::Step(step.clone(), Step::Skipped(None))),
+            scenario(Scenario::Finished(None)),
+        ] {
+            progress.update(&ev);
+        }
+        assert_eq!(
+            progress,
+            Progress {
+                scenarios: Some(2),
+                scenarios_started: 1,
+                scenarios_finished: 1,
+                steps_skipped: 1,
+                ..Progress::default()
+            },
+        );
+
+        progress.update(&Cucumber::<()>::Finished);
+        assert!(progress.finished);
+    }
+}
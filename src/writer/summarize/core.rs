@@ -13,7 +13,7 @@ use crate::{
     Event, World, Writer,
     cli::Colored,
     event::{self, Retries, Source},
-    parser,
+    parser, scenario,
     writer::{self, out::Styles},
 };
 
@@ -63,6 +63,11 @@ pub struct Summarize<Writer> {
     /// [`gherkin::Scenario`]: gherkin::Scenario
     pub(super) failed_hooks: usize,
 
+    /// Number of [`gherkin::Scenario`] duration SLA violations.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    pub(super) sla_violations: usize,
+
     /// Current [`State`] of this [`crate::Writer`].
     state: State,
 
@@ -70,6 +75,12 @@ pub struct Summarize<Writer> {
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
     handled_scenarios: HandledScenarios,
+
+    /// Indicates whether passed and skipped warm-up [`gherkin::Scenario`]s
+    /// should be counted in [`Stats`].
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    count_warmups: bool,
 }
 
 impl<W, Wr> Writer<W> for Summarize<Wr>
@@ -99,7 +110,9 @@ where
                     Feature::Rule(_, Rule::Started) => {
                         self.rules += 1;
                     }
-                    Feature::Rule(rule, Rule::Scenario(sc, ev)) => {
+                    Feature::Rule(rule, Rule::Scenario(sc, ev))
+                        if self.is_counted(feat, Some(&**rule), sc, ev) =>
+                    {
                         self.handle_scenario(
                             feat.clone(),
                             Some(rule.clone()),
@@ -107,7 +120,9 @@ where
                             ev,
                         );
                     }
-                    Feature::Scenario(sc, ev) => {
+                    Feature::Scenario(sc, ev)
+                        if self.is_counted(feat, None, sc, ev) =>
+                    {
                         self.handle_scenario(
                             feat.clone(),
                             None,
@@ -115,12 +130,18 @@ where
                             ev,
                         );
                     }
-                    Feature::Finished | Feature::Rule(..) => {}
+                    Feature::Finished
+                    | Feature::Rule(..)
+                    | Feature::Scenario(..) => {}
                 },
                 Ok(Cucumber::Finished) => {
                     self.state = State::FinishedButNotOutput;
                 }
-                Ok(Cucumber::Started | Cucumber::ParsingFinished { .. }) => {}
+                Ok(
+                    Cucumber::Started
+                    | Cucumber::ParsingFinished { .. }
+                    | Cucumber::Report(_),
+                ) => {}
             }
         }
 
@@ -169,6 +190,18 @@ where
         self.steps.retried
     }
 
+    fn timed_out_steps(&self) -> usize {
+        self.steps.timed_out
+    }
+
+    fn undefined_steps(&self) -> usize {
+        self.steps.undefined
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.steps.pending
+    }
+
     fn parsing_errors(&self) -> usize {
         self.parsing_errors
     }
@@ -176,6 +209,10 @@ where
     fn hook_errors(&self) -> usize {
         self.failed_hooks
     }
+
+    fn sla_violations(&self) -> usize {
+        self.sla_violations
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -194,8 +231,10 @@ impl<Writer> From<Writer> for Summarize<Writer> {
             steps: Stats::new(),
             parsing_errors: 0,
             failed_hooks: 0,
+            sla_violations: 0,
             state: State::InProgress,
             handled_scenarios: HashMap::new(),
+            count_warmups: false,
         }
     }
 }
@@ -207,6 +246,18 @@ impl<Writer> Summarize<Writer> {
         Self::from(writer)
     }
 
+    /// Makes passed and skipped warm-up [`gherkin::Scenario`]s to be counted
+    /// in [`Stats`], which are excluded by default.
+    ///
+    /// See [`scenario::WARMUP_TAG`] for details.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn count_warmups(mut self) -> Self {
+        self.count_warmups = true;
+        self
+    }
+
     /// Returns the original [`crate::Writer`], wrapped by this [`Summarize`]d one.
     #[must_use]
     pub const fn inner_writer(&self) -> &Writer {
@@ -254,12 +305,44 @@ impl<Writer> Summarize<Writer> {
         self.failed_hooks
     }
 
+    /// Returns the number of duration SLA violations encountered.
+    #[must_use]
+    pub const fn sla_violations_count(&self) -> usize {
+        self.sla_violations
+    }
+
     /// Returns the current state of the summarize writer.
     #[must_use]
     pub const fn current_state(&self) -> State {
         self.state
     }
 
+    /// Indicates whether the provided [`gherkin::Scenario`] event should be
+    /// counted in [`Stats`].
+    ///
+    /// Warm-up [`gherkin::Scenario`]s are counted only on failures, unless
+    /// [`Summarize::count_warmups()`] is set.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    fn is_counted<W>(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        ev: &event::RetryableScenario<W>,
+    ) -> bool {
+        use event::{Hook, Scenario, Step};
+
+        self.count_warmups
+            || !scenario::is_warmup(feature, rule, scenario)
+            || matches!(
+                ev.event,
+                Scenario::Hook(_, _, Hook::Failed(..))
+                    | Scenario::Background(_, Step::Failed { .. })
+                    | Scenario::Step(_, Step::Failed { .. }),
+            )
+    }
+
     /// Keeps track of [`crate::step::Step`]'s [`Stats`].
     ///
     /// [`crate::step::Step`]: gherkin::Step
@@ -275,10 +358,24 @@ impl<Writer> Summarize<Writer> {
         use event::Step;
 
         match ev {
-            Step::Started => {}
+            Step::Started | Step::Heartbeat { .. } | Step::Published(_) => {}
+            // The `Scenario` is already tracked as failed by the previous
+            // `Step`.
+            Step::NotRun => self.steps.increment_skipped(),
             Step::Passed { .. } => {
                 self.steps.increment_passed();
-                if scenario.steps.last().filter(|s| *s == step).is_some() {
+                // `Scenario` continuing after a failed `Step` (see
+                // `runner::basic::AfterPanic::Continue`) stays failed.
+                let is_failed = ScenarioTracker::get_scenario_indicator(
+                    &self.handled_scenarios,
+                    &feature,
+                    &rule,
+                    &scenario,
+                )
+                .is_some_and(|ind| *ind == Indicator::Failed);
+                if !is_failed
+                    && scenario.steps.last().filter(|s| *s == step).is_some()
+                {
                     ScenarioTracker::remove_scenario(
                         &mut self.handled_scenarios,
                         feature,
@@ -287,16 +384,27 @@ impl<Writer> Summarize<Writer> {
                     );
                 }
             }
-            Step::Skipped => {
+            Step::Skipped(_) => {
                 self.steps.increment_skipped();
-                self.scenarios.increment_skipped();
-                ScenarioTracker::update_scenario(
-                    &mut self.handled_scenarios,
-                    feature,
-                    rule,
-                    scenario,
-                    Indicator::Skipped,
-                );
+                // `Scenario` is already tracked, if its rest `Step`s are
+                // skipped after a failed (or skipped) one.
+                let is_tracked = ScenarioTracker::get_scenario_indicator(
+                    &self.handled_scenarios,
+                    &feature,
+                    &rule,
+                    &scenario,
+                )
+                .is_some();
+                if !is_tracked {
+                    self.scenarios.increment_skipped();
+                    ScenarioTracker::update_scenario(
+                        &mut self.handled_scenarios,
+                        feature,
+                        rule,
+                        scenario,
+                        Indicator::Skipped,
+                    );
+                }
             }
             Step::Failed { error, .. } => {
                 if retries
@@ -320,16 +428,37 @@ impl<Writer> Summarize<Writer> {
                         self.scenarios.increment_retried();
                     }
                 } else {
-                    self.steps.increment_failed();
-                    self.scenarios.increment_failed();
-
-                    ScenarioTracker::update_scenario(
+                    // `Scenario` may have failed already, if it continues
+                    // after a failed `Step`.
+                    let failed_before = ScenarioTracker::update_scenario(
                         &mut self.handled_scenarios,
                         feature,
                         rule,
                         scenario,
                         Indicator::Failed,
-                    );
+                    ) == Some(Indicator::Failed);
+
+                    if error.is_timeout() {
+                        self.steps.increment_timed_out();
+                        if !failed_before {
+                            self.scenarios.increment_timed_out();
+                        }
+                    } else if error.is_undefined() {
+                        self.steps.increment_undefined();
+                        if !failed_before {
+                            self.scenarios.increment_undefined();
+                        }
+                    } else if error.is_pending() {
+                        self.steps.increment_pending();
+                        if !failed_before {
+                            self.scenarios.increment_pending();
+                        }
+                    } else {
+                        self.steps.increment_failed();
+                        if !failed_before {
+                            self.scenarios.increment_failed();
+                        }
+                    }
                 }
             }
         }
@@ -352,9 +481,9 @@ impl<Writer> Summarize<Writer> {
         let ret = ev.retries;
         match &ev.event {
             Scenario::Started
-            | Scenario::Hook(_, Hook::Passed | Hook::Started)
+            | Scenario::Hook(_, _, Hook::Passed | Hook::Started)
             | Scenario::Log(_) => {}
-            Scenario::Hook(_, Hook::Failed(..)) => {
+            Scenario::Hook(_, _, Hook::Failed(..)) => {
                 // - If Scenario's last Step failed and then After Hook failed
                 //   too, we don't need to track second failure;
                 // - If Scenario's last Step was skipped and then After Hook
@@ -380,10 +509,34 @@ impl<Writer> Summarize<Writer> {
                 }
                 self.failed_hooks += 1;
             }
+            Scenario::SlaViolated(violation) => {
+                // Only passed `Scenario`s need to be tracked as failed, the
+                // same way as with failed hooks above.
+                if violation.fails_scenario {
+                    match self.handled_scenarios.get(&path) {
+                        Some(Indicator::Failed | Indicator::Retried) => {}
+                        Some(Indicator::Skipped) => {
+                            self.scenarios.decrement_skipped();
+                            self.scenarios.increment_failed();
+                        }
+                        None => {
+                            self.scenarios.increment_failed();
+                            ScenarioTracker::update_scenario(
+                                &mut self.handled_scenarios,
+                                path.0,
+                                path.1,
+                                path.2,
+                                Indicator::Failed,
+                            );
+                        }
+                    }
+                }
+                self.sla_violations += 1;
+            }
             Scenario::Background(st, ev) | Scenario::Step(st, ev) => {
                 self.handle_step(path.0, path.1, path.2, st.as_ref(), ev, ret);
             }
-            Scenario::Finished => {
+            Scenario::Finished(_) => {
                 // We don't remove retried `Scenario`s immediately, because we
                 // want to deduplicate. For example if some `Scenario` is
                 // retried 3 times, we'll see in summary 1 retried `Scenario`
@@ -17,17 +17,17 @@ pub trait SummaryFormatter {
     ///
     /// The summary includes:
     /// - Number of features and rules processed
-    /// - Scenario statistics (passed, skipped, failed, retried)
-    /// - Step statistics (passed, skipped, failed, retried)
-    /// - Parsing and hook errors
+    /// - Scenario statistics (passed, skipped, failed, timed out, retried)
+    /// - Step statistics (passed, skipped, failed, timed out, retried)
+    /// - Parsing and hook errors, and duration SLA violations
     ///
     /// All sections are formatted with appropriate styling and colors.
     fn summary<W>(&self, summary: &Summarize<W>) -> String;
 
     /// Formats [`Stats`] for terminal output with colors and styling.
     ///
-    /// This method takes statistics (passed, skipped, failed, retried counts)
-    /// and formats them as a colored, comma-separated string suitable for
+    /// This method takes statistics (passed, skipped, failed, timed out,
+    /// retried counts) and formats them as a colored, comma-separated string suitable for
     /// display in the summary.
     ///
     /// Returns an empty string if all statistics are zero.
@@ -61,33 +61,20 @@ impl SummaryFormatter for Styles {
         let steps = self.maybe_plural("step", summary.steps_stats().total());
         let steps_stats = self.format_stats(*summary.steps_stats());
 
-        let parsing_errors =
-            if summary.parsing_errors_count() > 0 {
-                self.err(self.maybe_plural(
-                    "parsing error",
-                    summary.parsing_errors_count(),
-                ))
-            } else {
-                "".into()
-            };
-
-        let hook_errors = if summary.failed_hooks_count() > 0 {
-            self.err(
-                self.maybe_plural("hook error", summary.failed_hooks_count()),
-            )
-        } else {
-            "".into()
-        };
-
-        let comma = if !parsing_errors.is_empty() && !hook_errors.is_empty() {
-            self.err(", ")
-        } else {
-            "".into()
-        };
+        let errors = [
+            ("parsing error", summary.parsing_errors_count()),
+            ("hook error", summary.failed_hooks_count()),
+            ("SLA violation", summary.sla_violations_count()),
+        ]
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(name, count)| self.err(self.maybe_plural(name, count)))
+        .collect::<Vec<_>>()
+        .join(&self.err(", "));
 
         format!(
             "{summary}\n{features}\n{rules}{scenarios}{scenarios_stats}\n\
-             {steps}{steps_stats}\n{parsing_errors}{comma}{hook_errors}",
+             {steps}{steps_stats}\n{errors}",
             summary = self.bold(self.header("[Summary]")),
         )
         .trim_end_matches('\n')
@@ -111,6 +98,21 @@ impl SummaryFormatter for Styles {
             } else {
                 "".into()
             },
+            if stats.timed_out > 0 {
+                self.bold(self.err(format!("{} timed out", stats.timed_out)))
+            } else {
+                "".into()
+            },
+            if stats.undefined > 0 {
+                self.bold(self.err(format!("{} undefined", stats.undefined)))
+            } else {
+                "".into()
+            },
+            if stats.pending > 0 {
+                self.bold(self.skipped(format!("{} pending", stats.pending)))
+            } else {
+                "".into()
+            },
         ]
         .into_iter()
         .filter(|s| !s.is_empty())
@@ -197,21 +199,29 @@ impl SummaryUtils {
 
         let scenarios = summary.scenarios_stats();
         parts.push(format!(
-            "{} (passed: {}, skipped: {}, failed: {}, retried: {})",
+            "{} (passed: {}, skipped: {}, failed: {}, timed out: {}, \
+             undefined: {}, pending: {}, retried: {})",
             Self::format_count("scenario", scenarios.total()),
             scenarios.passed,
             scenarios.skipped,
             scenarios.failed,
+            scenarios.timed_out,
+            scenarios.undefined,
+            scenarios.pending,
             scenarios.retried,
         ));
 
         let steps = summary.steps_stats();
         parts.push(format!(
-            "{} (passed: {}, skipped: {}, failed: {}, retried: {})",
+            "{} (passed: {}, skipped: {}, failed: {}, timed out: {}, \
+             undefined: {}, pending: {}, retried: {})",
             Self::format_count("step", steps.total()),
             steps.passed,
             steps.skipped,
             steps.failed,
+            steps.timed_out,
+            steps.undefined,
+            steps.pending,
             steps.retried,
         ));
 
@@ -229,6 +239,13 @@ impl SummaryUtils {
             ));
         }
 
+        if summary.sla_violations_count() > 0 {
+            parts.push(Self::format_count(
+                "SLA violation",
+                summary.sla_violations_count(),
+            ));
+        }
+
         parts.join(", ")
     }
 }
@@ -243,7 +260,15 @@ mod tests {
     };
 
     fn create_test_stats() -> Stats {
-        Stats { passed: 5, skipped: 2, failed: 1, retried: 3 }
+        Stats {
+            passed: 5,
+            skipped: 2,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 3,
+        }
     }
 
     #[test]
@@ -268,10 +293,24 @@ mod tests {
 
     #[test]
     fn summary_utils_has_any_failures() {
-        let stats_with_failures =
-            Stats { passed: 5, skipped: 2, failed: 1, retried: 0 };
-        let stats_without_failures =
-            Stats { passed: 5, skipped: 2, failed: 0, retried: 0 };
+        let stats_with_failures = Stats {
+            passed: 5,
+            skipped: 2,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0,
+        };
+        let stats_without_failures = Stats {
+            passed: 5,
+            skipped: 2,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0,
+        };
 
         // Test with failed stats
         assert!(SummaryUtils::has_any_failures(&stats_with_failures, 0, 0));
@@ -318,12 +357,21 @@ mod tests {
         let mut styles = Styles::new();
         styles.apply_coloring(Coloring::Never); // Disable coloring for predictable output
 
-        let stats = Stats { passed: 5, skipped: 2, failed: 1, retried: 0 };
+        let stats = Stats {
+            passed: 5,
+            skipped: 2,
+            failed: 1,
+            timed_out: 3,
+            undefined: 0,
+            pending: 0,
+            retried: 0,
+        };
 
         let result = styles.format_stats(stats);
         assert!(result.contains("5 passed"));
         assert!(result.contains("2 skipped"));
         assert!(result.contains("1 failed"));
+        assert!(result.contains("3 timed out"));
         assert!(!result.contains("retries"));
     }
 
@@ -332,7 +380,15 @@ mod tests {
         let mut styles = Styles::new();
         styles.apply_coloring(Coloring::Never);
 
-        let stats = Stats { passed: 3, skipped: 0, failed: 0, retried: 1 };
+        let stats = Stats {
+            passed: 3,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 1,
+        };
 
         let result = styles.format_stats(stats);
         assert!(result.contains("3 passed"));
@@ -344,7 +400,15 @@ mod tests {
         let mut styles = Styles::new();
         styles.apply_coloring(Coloring::Never);
 
-        let stats = Stats { passed: 3, skipped: 0, failed: 0, retried: 5 };
+        let stats = Stats {
+            passed: 3,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 5,
+        };
 
         let result = styles.format_stats(stats);
         assert!(result.contains("3 passed"));
@@ -382,9 +446,24 @@ mod tests {
         // Set some test values
         summary.features = 2;
         summary.rules = 1;
-        summary.scenarios =
-            Stats { passed: 8, skipped: 2, failed: 1, retried: 3 };
-        summary.steps = Stats { passed: 15, skipped: 3, failed: 2, retried: 5 };
+        summary.scenarios = Stats {
+            passed: 8,
+            skipped: 2,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 3,
+        };
+        summary.steps = Stats {
+            passed: 15,
+            skipped: 3,
+            failed: 2,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 5,
+        };
         summary.parsing_errors = 1;
         summary.failed_hooks = 0;
 
@@ -1,8 +1,12 @@
 //! Statistics collection and management for test execution results.
 
+#[cfg(test)]
+mod tests;
+
 /// Execution statistics for tracking test results.
 ///
-/// Tracks counts of passed, skipped, failed, and retried test steps or scenarios.
+/// Tracks counts of passed, skipped, failed, timed out, undefined, pending and
+/// retried test steps or scenarios.
 /// The `retried` count represents items that were retried during execution and is
 /// not included in the total count to avoid double-counting.
 ///
@@ -28,6 +32,33 @@ pub struct Stats {
     /// [`crate::step::Step`]: gherkin::Step
     pub failed: usize,
 
+    /// Number of [`crate::step::Step`]s (or [`gherkin::Scenario`]s) failed
+    /// with an [`event::StepError::Timeout`], not included into the `failed`
+    /// ones.
+    ///
+    /// [`event::StepError::Timeout`]: crate::event::StepError::Timeout
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    /// [`crate::step::Step`]: gherkin::Step
+    pub timed_out: usize,
+
+    /// Number of [`crate::step::Step`]s (or [`gherkin::Scenario`]s) failed
+    /// with an [`event::StepError::NotFound`], not included into the `failed`
+    /// ones.
+    ///
+    /// [`event::StepError::NotFound`]: crate::event::StepError::NotFound
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    /// [`crate::step::Step`]: gherkin::Step
+    pub undefined: usize,
+
+    /// Number of [`crate::step::Step`]s (or [`gherkin::Scenario`]s) failed
+    /// with an [`event::StepError::Pending`], not included into the `failed`
+    /// ones.
+    ///
+    /// [`event::StepError::Pending`]: crate::event::StepError::Pending
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    /// [`crate::step::Step`]: gherkin::Step
+    pub pending: usize,
+
     /// Number of retried [`crate::step::Step`]s (or [`gherkin::Scenario`]s).
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
@@ -39,22 +70,37 @@ impl Stats {
     /// Creates a new [`Stats`] instance with all counts set to zero.
     #[must_use]
     pub const fn new() -> Self {
-        Self { passed: 0, skipped: 0, failed: 0, retried: 0 }
+        Self {
+            passed: 0,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0,
+        }
     }
 
     /// Returns total number of [`crate::step::Step`]s (or [`gherkin::Scenario`]s), these [`Stats`]
     /// have been collected for.
     ///
     /// Note: `retried` count is intentionally not included here, as retried
-    /// items are already counted in either `passed` or `failed`.
+    /// items are already counted in either `passed`, `failed`, `timed_out`,
+    /// `undefined` or `pending`.
     ///
     /// [`gherkin::Scenario`]: gherkin::Scenario
     /// [`crate::step::Step`]: gherkin::Step
     #[must_use]
     pub const fn total(&self) -> usize {
         // We intentionally don't include `self.retried` number here, as it's
-        // already counted either in `self.passed` or `self.failed`.
-        self.passed + self.skipped + self.failed
+        // already counted either in `self.passed`, `self.failed`,
+        // `self.timed_out`, `self.undefined` or `self.pending`.
+        self.passed
+            + self.skipped
+            + self.failed
+            + self.timed_out
+            + self.undefined
+            + self.pending
     }
 
     /// Increments the passed count by one.
@@ -72,6 +118,21 @@ impl Stats {
         self.failed += 1;
     }
 
+    /// Increments the timed out count by one.
+    pub const fn increment_timed_out(&mut self) {
+        self.timed_out += 1;
+    }
+
+    /// Increments the undefined count by one.
+    pub const fn increment_undefined(&mut self) {
+        self.undefined += 1;
+    }
+
+    /// Increments the pending count by one.
+    pub const fn increment_pending(&mut self) {
+        self.pending += 1;
+    }
+
     /// Increments the retried count by one.
     pub const fn increment_retried(&mut self) {
         self.retried += 1;
@@ -90,13 +151,18 @@ impl Stats {
         self.passed == 0
             && self.skipped == 0
             && self.failed == 0
+            && self.timed_out == 0
+            && self.undefined == 0
+            && self.pending == 0
             && self.retried == 0
     }
 
-    /// Returns `true` if there are any failed items.
+    /// Returns `true` if there are any failed (or timed out) items.
+    ///
+    /// Undefined and pending items aren't considered as failures here.
     #[must_use]
     pub const fn has_failures(&self) -> bool {
-        self.failed > 0
+        self.failed > 0 || self.timed_out > 0
     }
 }
 
@@ -105,89 +171,3 @@ impl Default for Stats {
         Self::new()
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn new_stats_all_zero() {
-        let stats = Stats::new();
-        assert_eq!(stats.passed, 0);
-        assert_eq!(stats.skipped, 0);
-        assert_eq!(stats.failed, 0);
-        assert_eq!(stats.retried, 0);
-    }
-
-    #[test]
-    fn default_stats_same_as_new() {
-        assert_eq!(Stats::default(), Stats::new());
-    }
-
-    #[test]
-    fn total_excludes_retried() {
-        let stats = Stats { passed: 5, skipped: 2, failed: 1, retried: 3 };
-        assert_eq!(stats.total(), 8); // 5 + 2 + 1, excluding retried
-    }
-
-    #[test]
-    fn increment_operations() {
-        let mut stats = Stats::new();
-
-        stats.increment_passed();
-        stats.increment_skipped();
-        stats.increment_failed();
-        stats.increment_retried();
-
-        assert_eq!(stats.passed, 1);
-        assert_eq!(stats.skipped, 1);
-        assert_eq!(stats.failed, 1);
-        assert_eq!(stats.retried, 1);
-    }
-
-    #[test]
-    fn decrement_skipped_works() {
-        let mut stats = Stats { passed: 0, skipped: 3, failed: 0, retried: 0 };
-        stats.decrement_skipped();
-        assert_eq!(stats.skipped, 2);
-    }
-
-    #[test]
-    fn decrement_skipped_at_zero_stays_zero() {
-        let mut stats = Stats::new();
-        stats.decrement_skipped();
-        assert_eq!(stats.skipped, 0);
-    }
-
-    #[test]
-    fn is_empty_true_for_new_stats() {
-        assert!(Stats::new().is_empty());
-    }
-
-    #[test]
-    fn is_empty_false_with_any_count() {
-        let stats = Stats { passed: 1, skipped: 0, failed: 0, retried: 0 };
-        assert!(!stats.is_empty());
-    }
-
-    #[test]
-    fn has_failures_detects_failures() {
-        let stats_with_failure =
-            Stats { passed: 0, skipped: 0, failed: 1, retried: 0 };
-        let stats_without_failure =
-            Stats { passed: 1, skipped: 1, failed: 0, retried: 1 };
-
-        assert!(stats_with_failure.has_failures());
-        assert!(!stats_without_failure.has_failures());
-    }
-
-    #[test]
-    fn equality_works() {
-        let stats1 = Stats { passed: 1, skipped: 2, failed: 3, retried: 4 };
-        let stats2 = Stats { passed: 1, skipped: 2, failed: 3, retried: 4 };
-        let stats3 = Stats { passed: 1, skipped: 2, failed: 3, retried: 5 };
-
-        assert_eq!(stats1, stats2);
-        assert_ne!(stats1, stats3);
-    }
-}
@@ -0,0 +1,157 @@
+//! Tests of [`Stats`] counting.
+
+use super::*;
+
+#[test]
+fn new_stats_all_zero() {
+    let stats = Stats::new();
+    assert_eq!(stats.passed, 0);
+    assert_eq!(stats.skipped, 0);
+    assert_eq!(stats.failed, 0);
+    assert_eq!(stats.retried, 0);
+}
+
+#[test]
+fn default_stats_same_as_new() {
+    assert_eq!(Stats::default(), Stats::new());
+}
+
+#[test]
+fn total_excludes_retried() {
+    let stats = Stats {
+        passed: 5,
+        skipped: 2,
+        failed: 1,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 3,
+    };
+    assert_eq!(stats.total(), 8); // 5 + 2 + 1, excluding retried
+}
+
+#[test]
+fn increment_operations() {
+    let mut stats = Stats::new();
+
+    stats.increment_passed();
+    stats.increment_skipped();
+    stats.increment_failed();
+    stats.increment_retried();
+
+    assert_eq!(stats.passed, 1);
+    assert_eq!(stats.skipped, 1);
+    assert_eq!(stats.failed, 1);
+    assert_eq!(stats.retried, 1);
+}
+
+#[test]
+fn decrement_skipped_works() {
+    let mut stats = Stats {
+        passed: 0,
+        skipped: 3,
+        failed: 0,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 0,
+    };
+    stats.decrement_skipped();
+    assert_eq!(stats.skipped, 2);
+}
+
+#[test]
+fn decrement_skipped_at_zero_stays_zero() {
+    let mut stats = Stats::new();
+    stats.decrement_skipped();
+    assert_eq!(stats.skipped, 0);
+}
+
+#[test]
+fn is_empty_true_for_new_stats() {
+    assert!(Stats::new().is_empty());
+}
+
+#[test]
+fn is_empty_false_with_any_count() {
+    let stats = Stats {
+        passed: 1,
+        skipped: 0,
+        failed: 0,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 0,
+    };
+    assert!(!stats.is_empty());
+}
+
+#[test]
+fn has_failures_detects_failures() {
+    let stats_with_failure = Stats {
+        passed: 0,
+        skipped: 0,
+        failed: 1,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 0,
+    };
+    let stats_without_failure = Stats {
+        passed: 1,
+        skipped: 1,
+        failed: 0,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 1,
+    };
+
+    assert!(stats_with_failure.has_failures());
+    assert!(!stats_without_failure.has_failures());
+}
+
+#[test]
+fn has_failures_ignores_undefined_and_pending() {
+    let undefined = Stats { undefined: 1, ..Stats::new() };
+    let pending = Stats { pending: 1, ..Stats::new() };
+
+    assert!(!undefined.has_failures());
+    assert!(!pending.has_failures());
+    assert_eq!(undefined.total(), 1);
+    assert_eq!(pending.total(), 1);
+}
+
+#[test]
+fn equality_works() {
+    let stats1 = Stats {
+        passed: 1,
+        skipped: 2,
+        failed: 3,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 4,
+    };
+    let stats2 = Stats {
+        passed: 1,
+        skipped: 2,
+        failed: 3,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 4,
+    };
+    let stats3 = Stats {
+        passed: 1,
+        skipped: 2,
+        failed: 3,
+        timed_out: 0,
+        undefined: 0,
+        pending: 0,
+        retried: 5,
+    };
+
+    assert_eq!(stats1, stats2);
+    assert_ne!(stats1, stats3);
+}
@@ -122,6 +122,21 @@ where
         cmp::max(self.left.retried_steps(), self.right.retried_steps())
     }
 
+    fn timed_out_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.timed_out_steps(), self.right.timed_out_steps())
+    }
+
+    fn undefined_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.undefined_steps(), self.right.undefined_steps())
+    }
+
+    fn pending_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.pending_steps(), self.right.pending_steps())
+    }
+
     fn parsing_errors(&self) -> usize {
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.parsing_errors(), self.right.parsing_errors())
@@ -131,6 +146,11 @@ where
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.hook_errors(), self.right.hook_errors())
     }
+
+    fn sla_violations(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.sla_violations(), self.right.sla_violations())
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
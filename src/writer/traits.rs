@@ -100,6 +100,54 @@ pub trait Stats<World>: Writer<World> {
     #[must_use]
     fn retried_steps(&self) -> usize;
 
+    /// Returns number of [`crate::step::Step`]s [`Failed`] with a
+    /// [`StepError::Timeout`].
+    ///
+    /// These aren't counted as [`failed_steps()`][1].
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`StepError::Timeout`]: event::StepError::Timeout
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [1]: Stats::failed_steps
+    #[must_use]
+    fn timed_out_steps(&self) -> usize {
+        0
+    }
+
+    /// Returns number of [`crate::step::Step`]s [`Failed`] with a
+    /// [`StepError::NotFound`].
+    ///
+    /// These aren't counted as [`failed_steps()`][1], and don't fail the
+    /// execution (see [`Stats::execution_has_failed()`]), unless
+    /// [`Cucumber::strict()`] is used.
+    ///
+    /// [`Cucumber::strict()`]: crate::Cucumber::strict
+    /// [`Failed`]: event::Step::Failed
+    /// [`StepError::NotFound`]: event::StepError::NotFound
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [1]: Stats::failed_steps
+    #[must_use]
+    fn undefined_steps(&self) -> usize {
+        0
+    }
+
+    /// Returns number of [`crate::step::Step`]s [`Failed`] with a
+    /// [`StepError::Pending`].
+    ///
+    /// These aren't counted as [`failed_steps()`][1], and don't fail the
+    /// execution (see [`Stats::execution_has_failed()`]), unless
+    /// [`Cucumber::strict()`] is used.
+    ///
+    /// [`Cucumber::strict()`]: crate::Cucumber::strict
+    /// [`Failed`]: event::Step::Failed
+    /// [`StepError::Pending`]: event::StepError::Pending
+    /// [`crate::step::Step`]: gherkin::Step
+    /// [1]: Stats::failed_steps
+    #[must_use]
+    fn pending_steps(&self) -> usize {
+        0
+    }
+
     /// Returns number of parsing errors.
     #[must_use]
     fn parsing_errors(&self) -> usize;
@@ -110,12 +158,25 @@ pub trait Stats<World>: Writer<World> {
     #[must_use]
     fn hook_errors(&self) -> usize;
 
+    /// Returns number of [`gherkin::Scenario`] duration SLA violations.
+    ///
+    /// [`gherkin::Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn sla_violations(&self) -> usize {
+        0
+    }
+
     /// Indicates whether there were failures/errors during execution.
+    ///
+    /// Neither [`Stats::undefined_steps()`] nor [`Stats::pending_steps()`] are
+    /// considered as failures here.
     #[must_use]
     fn execution_has_failed(&self) -> bool {
         self.failed_steps() > 0
+            || self.timed_out_steps() > 0
             || self.parsing_errors() > 0
             || self.hook_errors() > 0
+            || self.sla_violations() > 0
     }
 }
 
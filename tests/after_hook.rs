@@ -62,8 +62,10 @@ async fn fires_each_time() {
 
     // Check the counts
     let failed_steps = writer.failed_steps();
+    let undefined_steps = writer.undefined_steps();
     let parsing_errors = writer.parsing_errors();
     assert_eq!(failed_steps, 6, "Expected 6 failed steps");
+    assert_eq!(undefined_steps, 4, "Expected 4 undefined steps");
     assert_eq!(parsing_errors, 0, "Expected no parsing errors");
 
     // We have 16 scenarios total but only 14 create World instances (2 are completely skipped)
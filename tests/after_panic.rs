@@ -0,0 +1,130 @@
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser,
+    runner::basic::AfterPanic,
+    then, when,
+    writer::{self, summarize::Stats},
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    hungry: bool,
+    overfed: bool,
+}
+
+#[given("a hungry cat")]
+fn hungry_cat(w: &mut World) {
+    w.hungry = true;
+}
+
+#[when("the cat is overfed")]
+fn overfed(w: &mut World) {
+    w.overfed = true;
+    panic!("cat is overfed");
+}
+
+#[then("the cat is not overfed")]
+fn not_overfed(w: &mut World) {
+    assert!(!w.overfed, "cat is overfed");
+}
+
+#[then("the cat is not hungry")]
+fn not_hungry(w: &mut World) {
+    assert!(!w.hungry, "cat is hungry");
+}
+
+/// [`Writer`] collecting outcomes of the steps and `World` rebuilds.
+#[derive(Default)]
+struct Outcomes(Vec<&'static str>);
+
+impl Writer<World> for Outcomes {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Hook, HookScope, Scenario, Step};
+
+        let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(_, event::RetryableScenario { event, .. }),
+        )) = ev.map(Event::into_inner)
+        else {
+            return;
+        };
+        self.0.push(match event {
+            Scenario::Step(_, Step::Passed { .. }) => "passed",
+            Scenario::Step(_, Step::Failed { .. }) => "failed",
+            Scenario::Step(_, Step::Skipped(_)) => "skipped",
+            Scenario::Step(_, Step::NotRun) => "not run",
+            Scenario::Hook(_, HookScope::World, Hook::Passed) => "rebuilt",
+            _ => return,
+        });
+    }
+}
+
+impl writer::Arbitrary<World, String> for Outcomes {
+    async fn write(&mut self, _: String) {}
+}
+
+impl writer::NonTransforming for Outcomes {}
+
+/// Runs the `after_panic` feature with the provided [`AfterPanic`]
+/// `behavior`, returning the outcomes of its steps and the `Stats` of its
+/// scenarios.
+async fn run(behavior: AfterPanic) -> (Vec<&'static str>, Stats) {
+    let writer = World::cucumber()
+        .with_writer(Outcomes::default().normalized().summarized())
+        .after_panic(behavior)
+        .run("tests/features/after_panic")
+        .await;
+
+    (writer.inner_writer().inner_writer().0.clone(), *writer.scenarios_stats())
+}
+
+#[tokio::test]
+async fn skips_remaining_steps_by_default() {
+    let (outcomes, stats) = run(AfterPanic::default()).await;
+
+    assert_eq!(outcomes, ["passed", "failed", "skipped", "skipped"]);
+    assert_eq!(stats.failed, 1);
+}
+
+#[tokio::test]
+async fn marks_remaining_steps_as_not_run() {
+    let (outcomes, stats) = run(AfterPanic::NotRun).await;
+
+    assert_eq!(outcomes, ["passed", "failed", "not run", "not run"]);
+    assert_eq!(
+        stats,
+        Stats {
+            passed: 0,
+            skipped: 0,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+}
+
+#[tokio::test]
+async fn continues_with_rebuilt_world() {
+    let (outcomes, stats) = run(AfterPanic::Continue).await;
+
+    assert_eq!(outcomes, ["passed", "failed", "rebuilt", "passed", "passed"]);
+    assert_eq!(
+        stats,
+        Stats {
+            passed: 0,
+            skipped: 0,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+}
@@ -0,0 +1,37 @@
+use cucumber::{World as _, given, writer};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("the cat is looked up")]
+fn looked_up(_: &mut World) {
+    lookup_cat().unwrap();
+}
+
+#[given("the cat is fed tomorrow")]
+fn fed_tomorrow(_: &mut World) -> Result<(), String> {
+    Err("tomorrow never comes".into())
+}
+
+/// Helper failing deep inside a [`given`] step.
+#[inline(never)]
+fn lookup_cat() -> Option<()> {
+    None
+}
+
+#[tokio::test]
+async fn renders_forced_backtraces_of_panics_only() {
+    let mut out = Vec::new();
+    _ = World::cucumber()
+        .with_writer(writer::Basic::new(&mut out, writer::Coloring::Never, 0))
+        .force_backtraces(true)
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run("tests/features/backtrace")
+        .await;
+    let out = String::from_utf8(out).unwrap();
+
+    assert_eq!(out.matches("Backtrace:").count(), 1, "{out}");
+    assert!(out.find("Backtrace:") < out.find("returned error"), "{out}");
+    assert!(out.contains("backtrace::looked_up"), "{out}");
+}
@@ -0,0 +1,53 @@
+use std::{
+    sync::Mutex,
+    thread::{self, ThreadId},
+    time::Duration,
+};
+
+use cucumber::{
+    World as _, given,
+    runtime::{self, Runtime as _},
+    then,
+    writer::summarize::Stats,
+};
+
+/// [`ThreadId`]s the steps were run on.
+static THREADS: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    THREADS.lock().unwrap().push(thread::current().id());
+    runtime::Blocking.sleep(Duration::from_millis(ms)).await;
+}
+
+#[then("the cat is awake")]
+fn awake(_: &mut World) {
+    THREADS.lock().unwrap().push(thread::current().id());
+}
+
+#[test]
+fn runs_on_current_thread() {
+    let writer = World::cucumber()
+        .step_timeout(Duration::from_millis(200))
+        .with_default_cli()
+        .run_blocking("tests/features/timeout");
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 2,
+            skipped: 0,
+            failed: 0,
+            timed_out: 1,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    let threads = THREADS.lock().unwrap();
+    assert_eq!(threads.len(), 5, "{threads:?}");
+    assert!(threads.iter().all(|id| *id == thread::current().id()));
+}
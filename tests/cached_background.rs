@@ -0,0 +1,71 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cucumber::{World as _, given, runner, then, writer::summarize::Stats};
+use futures::FutureExt as _;
+
+static SETUPS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Debug, Default, cucumber::World)]
+struct World {
+    ready: bool,
+    hooked: Option<String>,
+}
+
+#[given("an expensive setup")]
+fn expensive_setup(w: &mut World) {
+    _ = SETUPS.fetch_add(1, Ordering::SeqCst);
+    w.ready = true;
+}
+
+#[then("setup is done")]
+fn setup_is_done(w: &mut World) {
+    assert!(w.ready, "`World` is not restored");
+}
+
+#[then(expr = "the before hook has run for the {string} scenario")]
+fn before_hook_has_run(w: &mut World, scenario: String) {
+    assert_eq!(w.hooked, Some(scenario), "`World` is restored after hook");
+}
+
+#[tokio::test]
+async fn runs_background_once() {
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .steps(World::collection())
+                .max_concurrent_scenarios(1)
+                .cached_background(World::clone)
+                .before(|_, _, sc, w| {
+                    async { w.hooked = Some(sc.name.clone()) }.boxed_local()
+                }),
+        )
+        .with_default_cli()
+        .run("tests/features/cached_background")
+        .await;
+
+    assert_eq!(SETUPS.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 3,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    assert_eq!(
+        *writer.steps_stats(),
+        Stats {
+            passed: 9,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+}
@@ -0,0 +1,68 @@
+use cucumber::{
+    World as _, cli, cli::Parser as _, step::Context, then, when, writer,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[when(regex = r"^the customer places order (\d+)$")]
+fn places(_: &mut World, #[step] ctx: &Context, id: u64) {
+    ctx.publish(format!("order {id} placed"));
+}
+
+#[then("the order is confirmed")]
+fn confirmed(_: &mut World) {}
+
+#[then("the order is rejected")]
+fn rejected(_: &mut World) {
+    panic!("order rejected");
+}
+
+async fn run(args: &[&str]) -> String {
+    let mut out = Vec::new();
+    let opts = cli::Opts::<_, _, _>::try_parse_from(
+        ["test"].into_iter().chain(args.iter().copied()),
+    )
+    .unwrap();
+    _ = World::cucumber()
+        .with_writer(writer::Basic::new(&mut out, writer::Coloring::Never, 0))
+        .with_cli(opts)
+        .run("tests/features/captured_output")
+        .await;
+    String::from_utf8(out).unwrap()
+}
+
+#[tokio::test]
+async fn outputs_captured_output_of_failed_scenarios_only() {
+    let out = run(&[]).await;
+
+    assert!(!out.contains("order 1 placed"), "{out}");
+    let placed = out.find("»  order 2 placed").expect(&out);
+    let failed = out.find("✘  Then the order is rejected").expect(&out);
+    assert!(placed < failed, "{out}");
+}
+
+#[tokio::test]
+async fn outputs_captured_output_of_passed_scenarios_if_requested() {
+    let out = run(&["--show-output"]).await;
+
+    assert!(out.contains("»  order 1 placed"), "{out}");
+    assert!(out.contains("»  order 2 placed"), "{out}");
+}
+
+#[tokio::test]
+async fn outputs_doc_strings_with_triple_verbose_flag() {
+    assert!(!run(&["-vv"]).await.contains("Thank you!"));
+    assert!(run(&["-vvv"]).await.contains("Thank you!"));
+}
+
+#[tokio::test]
+async fn outputs_nothing_in_quiet_mode() {
+    assert_eq!(run(&["--quiet"]).await, "");
+
+    let conflicting =
+        cli::Opts::<cli::Empty, cli::Empty, writer::basic::Cli>::try_parse_from(
+            ["test", "-q", "-v"],
+        );
+    assert!(conflicting.is_err(), "`--quiet` conflicts with `-v`");
+}
@@ -0,0 +1,65 @@
+use cucumber::{
+    World as _, cli, given, step::Context, then, writer::Stats as _,
+};
+
+/// User-defined CLI options of the suite.
+#[derive(Clone, Debug, Default, cli::Args)]
+struct CustomOpts {
+    /// Base URL of the tested service.
+    #[arg(long)]
+    base_url: String,
+
+    /// Indicator whether the browser runs in headless mode.
+    #[arg(long)]
+    headless: bool,
+}
+
+#[derive(Debug, cucumber::World)]
+#[world(init = Self::new)]
+struct World {
+    base_url: Option<String>,
+    page: Option<String>,
+}
+
+impl World {
+    fn new() -> Self {
+        Self {
+            base_url: cli::custom::<CustomOpts>().map(|o| o.base_url.clone()),
+            page: None,
+        }
+    }
+}
+
+#[given("a browser")]
+fn browser(world: &mut World) {
+    world.page = world.base_url.clone();
+}
+
+#[then("the main page is opened in headless mode")]
+fn main_page(world: &mut World, #[step] ctx: &Context) {
+    let opts = ctx.cli::<CustomOpts>().expect("no `CustomOpts`");
+    assert!(opts.headless, "not in headless mode");
+    assert_eq!(world.page.as_deref(), Some("http://localhost:8080"));
+    assert!(ctx.cli::<cli::Empty>().is_none(), "wrong type");
+}
+
+#[tokio::test]
+async fn provides_custom_cli_to_world_and_steps() {
+    let opts =
+        <cli::Opts<_, _, _, CustomOpts> as cli::Parser>::try_parse_from([
+            "cucumber",
+            "--base-url=http://localhost:8080",
+            "--headless",
+        ])
+        .unwrap();
+
+    let writer = World::cucumber()
+        .fail_on_skipped()
+        .with_cli(opts)
+        .run("tests/features/custom_cli")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert!(!writer.execution_has_failed());
+    assert!(cli::custom::<CustomOpts>().is_none(), "outside run");
+}
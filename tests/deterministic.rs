@@ -0,0 +1,132 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser, then,
+};
+use futures::FutureExt as _;
+use tokio::time;
+
+static WAKE_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat sleeps for (\d+) ms$")]
+async fn sleeps(_: &mut World, ms: u64) {
+    time::sleep(Duration::from_millis(ms)).await;
+}
+
+#[then("the cat wakes up on the second attempt")]
+fn wakes_up(_: &mut World) {
+    assert!(
+        WAKE_ATTEMPTS.fetch_add(1, Ordering::SeqCst) % 2 == 1,
+        "the cat is still sleeping",
+    );
+}
+
+/// [`Writer`] collecting a short description of every received event.
+#[derive(Default)]
+struct Described(Vec<String>);
+
+impl Writer<World> for Described {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{
+            Cucumber, Feature, Hook, RetryableScenario, Rule, Scenario,
+        };
+
+        let Ok(Cucumber::Feature(f, ev)) = ev.map(Event::into_inner) else {
+            return;
+        };
+        let (sc, RetryableScenario { event: ev, retries, .. }) = match ev {
+            Feature::Started => {
+                return self.0.push(format!("{} started", f.name));
+            }
+            Feature::Finished => {
+                return self.0.push(format!("{} finished", f.name));
+            }
+            Feature::Rule(r, Rule::Started) => {
+                return self.0.push(format!("rule {} started", r.name));
+            }
+            Feature::Rule(r, Rule::Finished) => {
+                return self.0.push(format!("rule {} finished", r.name));
+            }
+            Feature::Scenario(sc, ev) => (sc, ev),
+            Feature::Rule(_, Rule::Scenario(sc, ev)) => (sc, *ev),
+        };
+        let what = match ev {
+            Scenario::Started => "started".to_owned(),
+            Scenario::Hook(_, _, Hook::Passed) => "hook passed".to_owned(),
+            Scenario::Step(st, event::Step::Passed { .. }) => {
+                format!("{} passed", st.value)
+            }
+            Scenario::Step(st, event::Step::Failed { .. }) => {
+                format!("{} failed", st.value)
+            }
+            Scenario::Finished(_) => "finished".to_owned(),
+            _ => return,
+        };
+        let attempt = retries.map_or(0, |r| r.current);
+        self.0.push(format!("{}#{attempt}: {what}", sc.name));
+    }
+}
+
+async fn run() -> Vec<String> {
+    World::cucumber()
+        .before(|_, _, _, _| async {}.boxed_local())
+        .with_writer(Described::default().deterministic())
+        .with_default_cli()
+        .max_concurrent_scenarios(4)
+        .run("tests/features/deterministic")
+        .await
+        .inner_writer()
+        .0
+        .clone()
+}
+
+#[tokio::test]
+async fn outputs_events_in_source_order() {
+    let scenario = |name: &str, steps: &[&str]| {
+        let mut events =
+            vec![format!("{name}: started"), format!("{name}: hook passed")];
+        events.extend(steps.iter().map(|st| format!("{name}: {st}")));
+        events.push(format!("{name}: finished"));
+        events
+    };
+    let expected = [
+        vec!["A started".to_owned()],
+        scenario("slow#0", &["the cat sleeps for 60 ms passed"]),
+        scenario("fast#0", &["the cat sleeps for 0 ms passed"]),
+        vec!["rule flaky started".to_owned()],
+        scenario(
+            "flaky#0",
+            &[
+                "the cat sleeps for 10 ms passed",
+                "the cat wakes up on the second attempt failed",
+            ],
+        ),
+        scenario(
+            "flaky#1",
+            &[
+                "the cat sleeps for 10 ms passed",
+                "the cat wakes up on the second attempt passed",
+            ],
+        ),
+        vec!["rule flaky finished".to_owned(), "A finished".to_owned()],
+        vec!["B started".to_owned()],
+        scenario("quick#0", &["the cat sleeps for 0 ms passed"]),
+        vec!["B finished".to_owned()],
+    ]
+    .concat();
+
+    assert_eq!(run().await, expected);
+    assert_eq!(run().await, expected, "order differs between runs");
+}
@@ -0,0 +1,56 @@
+use cucumber::{Parser as _, parser};
+use futures::StreamExt as _;
+
+fn sources() -> parser::Embedded {
+    parser::Embedded::new()
+        .with(
+            "virtual/header.feature",
+            include_str!("features/language/header.feature"),
+        )
+        .with("virtual/broken.feature", "Not a feature")
+}
+
+#[tokio::test]
+async fn parses_embedded_sources_with_virtual_names() {
+    let features = parser::Basic::new()
+        .parse(sources(), parser::basic::Cli::default())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(features.len(), 2);
+    let feature = features[0].as_ref().unwrap();
+    assert_eq!(feature.keyword, "Egenskap");
+    assert_eq!(
+        feature.path.as_deref(),
+        Some("virtual/header.feature".as_ref()),
+    );
+    assert!(
+        features[1]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("virtual/broken.feature"),
+    );
+}
+
+#[tokio::test]
+async fn cli_input_overrides_embedded_sources() {
+    let features = parser::Basic::new()
+        .parse(
+            sources(),
+            parser::basic::Cli {
+                features: Some(
+                    "tests/features/language/*.feature".parse().unwrap(),
+                ),
+                ..parser::basic::Cli::default()
+            },
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(features.len(), 2);
+    assert!(features.iter().all(|f| {
+        f.as_ref()
+            .map_or(true, |f| !f.path.as_ref().unwrap().starts_with("virtual"))
+    }));
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+
+use anyhow::Context as _;
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat is fed (\d+) times$")]
+fn fed_times(_: &mut World, times: String) -> anyhow::Result<()> {
+    let times = format!("{times}00").parse::<u8>().context("too much food")?;
+    anyhow::bail!("the cat ate {times} times")
+}
+
+#[given("the cat is fed tomorrow")]
+fn fed_tomorrow(_: &mut World) -> Result<(), Tomorrow> {
+    Err(Tomorrow)
+}
+
+/// Error implementing [`fmt::Display`] only.
+#[derive(Debug)]
+struct Tomorrow;
+
+impl fmt::Display for Tomorrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tomorrow never comes")
+    }
+}
+
+/// [`Writer`] collecting errors returned from steps.
+#[derive(Default)]
+struct Returned(Vec<String>);
+
+impl Writer<World> for Returned {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(
+                _,
+                event::RetryableScenario {
+                    event:
+                        event::Scenario::Step(
+                            _,
+                            event::Step::Failed {
+                                error: event::StepError::Returned(failure),
+                                ..
+                            },
+                        ),
+                    ..
+                },
+            ),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push(failure.to_string());
+        }
+    }
+}
+
+#[tokio::test]
+async fn renders_error_chain() {
+    let writer = World::cucumber()
+        .with_writer(Returned::default().normalized())
+        .max_concurrent_scenarios(1)
+        .run("tests/features/error_chain")
+        .await;
+
+    assert_eq!(
+        writer.inner_writer().0,
+        [
+            "too much food: number too large to fit in target type",
+            "tomorrow never comes",
+        ],
+    );
+}
@@ -214,10 +214,10 @@ fn test_rule_events() {
 #[test]
 fn test_step_events() {
     let started = Step::<TestWorld>::Started;
-    let skipped = Step::<TestWorld>::Skipped;
+    let skipped = Step::<TestWorld>::Skipped(None);
 
     assert!(matches!(started, Step::Started));
-    assert!(matches!(skipped, Step::Skipped));
+    assert!(matches!(skipped, Step::Skipped(None)));
 
     // Test cloning
     let started_clone = started.clone();
@@ -229,6 +229,7 @@ fn test_step_error_types() {
     let not_found = StepError::NotFound;
     let ambiguous = StepError::AmbiguousMatch(step::AmbiguousMatchError {
         possible_matches: vec![],
+        details: vec![],
     });
     let panic_err = StepError::Panic(Arc::new("panic message"));
 
@@ -266,11 +267,14 @@ fn test_hook_events() {
 #[test]
 fn test_scenario_events() {
     let started = Scenario::<TestWorld>::Started;
-    let hook_event =
-        Scenario::<TestWorld>::Hook(HookType::Before, Hook::Started);
+    let hook_event = Scenario::<TestWorld>::Hook(
+        HookType::Before,
+        HookScope::Function,
+        Hook::Started,
+    );
 
     assert!(matches!(started, Scenario::Started));
-    assert!(matches!(hook_event, Scenario::Hook(_, _)));
+    assert!(matches!(hook_event, Scenario::Hook(_, _, _)));
 
     // Test cloning
     let started_clone = started.clone();
@@ -283,6 +287,7 @@ fn test_retryable_scenario() {
     let retryable = RetryableScenario {
         event: scenario_event,
         retries: Some(Retries::initial(2)),
+        id: None,
     };
 
     assert!(matches!(retryable.event, Scenario::Started));
@@ -328,6 +333,7 @@ fn test_step_error_coercion() {
         StepError::NotFound,
         StepError::AmbiguousMatch(step::AmbiguousMatchError {
             possible_matches: vec![],
+            details: vec![],
         }),
         StepError::Panic(Arc::new("test panic")),
     ];
@@ -349,8 +355,12 @@ fn test_step_failed_structure() {
             line: 10,
             column: 1,
         }),
+        matched: None,
         world: Some(Arc::new(TestWorld)),
         error: StepError::NotFound,
+        owner: None,
+        timing: StepTiming::default(),
+        backtrace: None,
     };
 
     if let Step::Failed { location, world, error, .. } = failed_step {
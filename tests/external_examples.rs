@@ -0,0 +1,24 @@
+use cucumber::{Parser as _, parser};
+use futures::StreamExt as _;
+
+#[tokio::test]
+async fn expands_examples_from_csv_file() {
+    let features = parser::Basic::new()
+        .parse(
+            "tests/features/external_examples",
+            parser::basic::Cli::default(),
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+    let feature = features[0].as_ref().unwrap();
+    let steps = feature
+        .scenarios
+        .iter()
+        .map(|s| s.steps[0].value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        steps,
+        ["alice signs in with s3cr3t, really", "bob signs in with hunter2",],
+    );
+}
@@ -33,12 +33,28 @@ async fn correct_stats() {
 
         assert_eq!(
             *writer.scenarios_stats(),
-            Stats { passed: p_sc, skipped: 0, failed: f_sc, retried: r_sc },
+            Stats {
+                passed: p_sc,
+                skipped: 0,
+                failed: f_sc,
+                timed_out: 0,
+                undefined: 0,
+                pending: 0,
+                retried: r_sc
+            },
             "Wrong `Stats` for `Scenario`s in `{feat}`",
         );
         assert_eq!(
             *writer.steps_stats(),
-            Stats { passed: p_st, skipped: 0, failed: f_st, retried: r_st },
+            Stats {
+                passed: p_st,
+                skipped: 0,
+                failed: f_st,
+                timed_out: 0,
+                undefined: 0,
+                pending: 0,
+                retried: r_st
+            },
             "Wrong `Stats` for `Step`s in `{feat}`",
         );
     }
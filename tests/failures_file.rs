@@ -0,0 +1,63 @@
+use std::{
+    fs,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use cucumber::{World as _, cli, given, step::Context, then};
+use tempfile::NamedTempFile;
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("the cat is hungry")]
+fn hungry(_: &mut World) {}
+
+#[then("the cat purrs")]
+fn purrs(_: &mut World, #[step] ctx: &Context) {
+    ctx.publish(
+        cucumber::event::Custom::new("screenshot").field("path", "cat.png"),
+    );
+    panic!("no purr");
+}
+
+#[then("the cat is flaky")]
+fn flaky(_: &mut World) {
+    static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+    assert!(ATTEMPTS.fetch_add(1, Ordering::SeqCst) > 0, "too early");
+}
+
+#[tokio::test]
+async fn writes_final_failures_only() {
+    let file = NamedTempFile::new().unwrap();
+    let mut cli = cli::Opts::<_, _, _>::default();
+    cli.failures.failures_file = Some(file.path().to_owned());
+
+    drop(World::cucumber().with_cli(cli).run("tests/features/failures").await);
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(file.path()).unwrap())
+            .unwrap();
+    let failures = report["failures"].as_array().unwrap();
+
+    assert_eq!(failures.len(), 1, "{report:#}");
+    let failure = &failures[0];
+    assert_eq!(failure["path"], "tests/features/failures/cat.feature");
+    assert_eq!(failure["line"], 2);
+    assert_eq!(failure["scenario"], "hungry cat");
+    assert_eq!(
+        failure["step"],
+        serde_json::json!({"keyword": "Then", "text": "the cat purrs", "line": 4}),
+    );
+    assert_eq!(failure["hook"], serde_json::Value::Null);
+    assert!(
+        failure["error"].as_str().unwrap().contains("no purr"),
+        "{failure:#}",
+    );
+    assert_eq!(failure["retries"], 0);
+    assert!(failure["scenario_id"].is_u64(), "{failure:#}");
+    assert_eq!(
+        failure["artifacts"],
+        serde_json::json!([{"name": "screenshot", "fields": {"path": "cat.png"}}]),
+    );
+}
@@ -0,0 +1,6 @@
+Feature: After panic
+  Scenario: overfeeding a cat
+    Given a hungry cat
+    When the cat is overfed
+    Then the cat is not overfed
+    And the cat is not hungry
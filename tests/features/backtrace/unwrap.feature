@@ -0,0 +1,6 @@
+Feature: Backtrace
+  Scenario: unwrapped none
+    Given the cat is looked up
+
+  Scenario: returned error
+    Given the cat is fed tomorrow
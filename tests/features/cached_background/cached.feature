@@ -0,0 +1,16 @@
+@cached-background
+Feature: Cached background
+  Background:
+    Given an expensive setup
+
+  Scenario: first
+    Then setup is done
+    And the before hook has run for the "first" scenario
+
+  Scenario: second
+    Then setup is done
+    And the before hook has run for the "second" scenario
+
+  Scenario: third
+    Then setup is done
+    And the before hook has run for the "third" scenario
@@ -0,0 +1,11 @@
+Feature: Orders
+  Scenario: placing an order
+    When the customer places order 1
+    Then the order is confirmed
+      """
+      Thank you!
+      """
+
+  Scenario: placing a rejected order
+    When the customer places order 2
+    Then the order is rejected
@@ -0,0 +1,9 @@
+Feature: Browser
+
+  Scenario: opening the main page
+    Given a browser
+    Then the main page is opened in headless mode
+
+  Scenario: opening the main page again
+    Given a browser
+    Then the main page is opened in headless mode
@@ -0,0 +1,14 @@
+Feature: A
+
+  Scenario: slow
+    Given the cat sleeps for 60 ms
+
+  Scenario: fast
+    Given the cat sleeps for 0 ms
+
+  Rule: flaky
+
+    @retry(1)
+    Scenario: flaky
+      Given the cat sleeps for 10 ms
+      Then the cat wakes up on the second attempt
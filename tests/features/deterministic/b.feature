@@ -0,0 +1,4 @@
+Feature: B
+
+  Scenario: quick
+    Given the cat sleeps for 0 ms
@@ -0,0 +1,6 @@
+Feature: Error chain
+  Scenario: anyhow error
+    Given the cat is fed 5 times
+
+  Scenario: displayable error
+    Given the cat is fed tomorrow
@@ -0,0 +1,6 @@
+Feature: External examples
+
+  Scenario Outline: signing in
+    Given <user> signs in with <password>
+
+    Examples: @file(data/users.csv)
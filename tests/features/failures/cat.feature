@@ -0,0 +1,13 @@
+Feature: Cat
+  Scenario: hungry cat
+    Given the cat is hungry
+    Then the cat purrs
+
+  @retry(1)
+  Rule: retried
+    Scenario: flaky cat
+      Given the cat is hungry
+      Then the cat is flaky
+
+  Scenario: fed cat
+    Given the cat is hungry
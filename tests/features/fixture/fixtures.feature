@@ -0,0 +1,12 @@
+@fixture:db
+Feature: Fixtures
+  @fixture:app
+  Scenario: first
+    Given the app connected to the database
+
+  @fixture:app
+  Scenario: second
+    Given the app connected to the database on port 8080
+
+  Scenario: database only
+    Given the database
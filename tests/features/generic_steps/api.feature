@@ -0,0 +1,9 @@
+Feature: Generic steps
+  Scenario: calling the API
+    Given the orders API is up
+    When the orders API is called
+    Then 2 requests are sent
+
+  Scenario: calling another API
+    When the users API is called
+    Then 1 requests are sent
@@ -0,0 +1,14 @@
+Feature: Streaming envelopes
+  Background:
+    Given a cucumber
+
+  Scenario: eating
+    When the cucumber is eaten
+    Then there are no cucumbers
+
+  Scenario: failing
+    Then there are 2 cucumbers
+    And the cucumber is eaten
+
+  Scenario: undefined
+    When the cucumber is pickled
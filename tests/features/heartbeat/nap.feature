@@ -0,0 +1,4 @@
+Feature: Heartbeat
+  Scenario: long nap
+    Given the cat naps for 250 milliseconds
+    Then the cat is awake
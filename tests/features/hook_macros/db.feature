@@ -0,0 +1,7 @@
+Feature: Hook macros
+  @db
+  Scenario: seeded database
+    Then the database is seeded
+
+  Scenario: empty database
+    Then the database is empty
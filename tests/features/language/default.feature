@@ -0,0 +1,3 @@
+Funktionalität: Standardsprache
+  Szenario: ohne Kopfzeile
+    Angenommen ein Schritt
@@ -0,0 +1,4 @@
+# language: no
+Egenskap: Overstyrt språk
+  Eksempel: med overskrift
+    Gitt et steg
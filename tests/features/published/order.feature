@@ -0,0 +1,4 @@
+Feature: Published events
+  Scenario: placing an order
+    When the customer places order 123
+    Then the order is confirmed
@@ -0,0 +1,18 @@
+Feature: Recording
+
+  Background:
+    Given 1 cucumber
+
+  Scenario: passing
+    When 2 more cucumbers are added
+    Then there are 3 cucumbers
+
+  Scenario: failing
+    When 2 more cucumbers are added
+    Then there are 4 cucumbers
+
+  Rule: rule
+
+    Scenario: skipped
+      When cucumbers are eaten
+      Then there are 0 cucumbers
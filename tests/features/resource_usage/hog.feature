@@ -0,0 +1,4 @@
+Feature: Resource usage
+  Scenario: memory hog
+    Given the cat hoards 64 megabytes of yarn
+    Then the cat naps for 50 milliseconds
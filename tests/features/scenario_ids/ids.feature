@@ -0,0 +1,7 @@
+Feature: Scenario IDs
+  Scenario: first
+    Given the step reports its scenario ID
+    And the step reports its scenario ID
+
+  Scenario: second
+    Given the step reports its scenario ID
@@ -0,0 +1,12 @@
+Feature: Graceful stop
+  Scenario: first nap
+    Given the cat naps for 200 milliseconds
+
+  Scenario: second nap
+    Given the cat naps for 200 milliseconds
+
+  Scenario: third nap
+    Given the cat naps for 200 milliseconds
+
+  Scenario: fourth nap
+    Given the cat naps for 200 milliseconds
@@ -0,0 +1,6 @@
+Feature: Shared state
+  Scenario: first
+    Given the shared config
+
+  Scenario: second
+    Given the shared config
@@ -0,0 +1,7 @@
+Feature: SLA
+  @fast
+  Scenario: long nap
+    Given the cat naps for 150 milliseconds
+
+  Scenario: untagged nap
+    Given the cat naps for 150 milliseconds
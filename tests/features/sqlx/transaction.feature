@@ -0,0 +1,9 @@
+@fixture:db
+Feature: Transaction per scenario
+  Scenario: panicking
+    Given a user alice
+    When the step panics
+
+  Scenario: passing
+    Given a user bob
+    Then there is 1 user
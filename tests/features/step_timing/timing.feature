@@ -0,0 +1,4 @@
+Feature: Step timing
+  Scenario: slow step body
+    Given the cat naps for 50 milliseconds
+    Then the cat is awake
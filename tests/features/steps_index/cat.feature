@@ -0,0 +1,11 @@
+Feature: Cat
+  Background:
+    Given a cat
+
+  Scenario: feeding
+    When the cat eats 2 fish
+    Then the cat purrs
+
+  Rule: sleeping
+    Scenario: napping
+      When the cat naps
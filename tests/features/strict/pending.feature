@@ -0,0 +1,9 @@
+Feature: Strict
+  Scenario: implemented
+    Given the cat is hungry
+    Then the cat is fed
+
+  Scenario: pending
+    Given the cat is hungry
+    Then the cat purrs
+    And the cat is fed
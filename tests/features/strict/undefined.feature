@@ -0,0 +1,4 @@
+Feature: Strict undefined
+  Scenario: undefined
+    Given the cat is hungry
+    Then the cat meows
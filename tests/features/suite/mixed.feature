@@ -0,0 +1,15 @@
+Feature: Mixed suite
+  @api
+  Scenario: request
+    Given a request
+    Then the response is received
+    And its own world is used
+
+  @browser
+  Scenario: click
+    Given a page
+    Then the button is clicked
+    And its own world is used
+
+  Scenario: unrouted
+    Given an undefined step
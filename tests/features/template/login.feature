@@ -0,0 +1,4 @@
+Feature: Templated login
+
+  Scenario: signing in
+    Given {{ user }} signs in to {{env}}
@@ -0,0 +1,12 @@
+Feature: Timeout
+  Scenario: short nap
+    Given the cat naps for 10 milliseconds
+    Then the cat is awake
+
+  Scenario: long nap
+    Given the cat naps for 5000 milliseconds
+
+  @timeout(10s)
+  Scenario: allowed long nap
+    Given the cat naps for 400 milliseconds
+    Then the cat is awake
@@ -0,0 +1,7 @@
+Feature: Flaky
+
+  Scenario: first
+    Given a flaky step
+
+  Scenario: second
+    Given a flaky step
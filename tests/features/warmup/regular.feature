@@ -0,0 +1,4 @@
+Feature: Parsed before the warm-up
+
+  Scenario: third
+    Then services are warmed up
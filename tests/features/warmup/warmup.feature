@@ -0,0 +1,11 @@
+Feature: Warm-up
+
+  Scenario: first
+    Then services are warmed up
+
+  Scenario: second
+    Then services are warmed up
+
+  @warmup
+  Scenario: warm-up
+    Given services are warming up
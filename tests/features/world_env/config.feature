@@ -0,0 +1,3 @@
+Feature: World env
+  Scenario: configuration from the environment
+    Given the configuration
@@ -0,0 +1,6 @@
+Feature: World init
+  Scenario: refused connection
+    Given a connection
+
+  Scenario: established connection
+    Given a connection
@@ -0,0 +1,10 @@
+Feature: World methods
+  Scenario: filling a basket
+    Given an empty basket
+    When 2 apples are added
+    And "pear" is added
+    Then the basket contains 3 fruits
+
+  Scenario: failing on a full basket
+    Given an empty basket
+    When 11 apples are added
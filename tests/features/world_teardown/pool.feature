@@ -0,0 +1,7 @@
+Feature: World teardown
+  Scenario: connection is released
+    Given a connection
+
+  Scenario: connection is leaked
+    Given a connection
+    And a leaked connection
@@ -0,0 +1,117 @@
+use std::{
+    convert::Infallible,
+    ptr,
+    sync::{Arc, Mutex},
+};
+
+use cucumber::{
+    World as _,
+    fixture::{Fixture, Fixtures, Scope},
+    given,
+    step::Context,
+    writer::Stats as _,
+};
+
+/// Log of the `Fixture` setups and teardowns.
+type Log = Arc<Mutex<Vec<&'static str>>>;
+
+#[derive(Debug)]
+struct Database;
+
+#[derive(Debug)]
+struct App {
+    db: Arc<Database>,
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("the database")]
+fn database(_: &mut World, #[step] ctx: &Context) {
+    assert!(ctx.fixture::<Database>().is_some(), "no `Database`");
+    assert!(ctx.fixture::<App>().is_none(), "unexpected `App`");
+}
+
+#[given("the app connected to the database")]
+fn app(_: &mut World, #[fixture] app: Arc<App>, #[fixture] db: &Database) {
+    assert!(ptr::eq(&*app.db, db), "`Database` should be shared");
+}
+
+#[given(expr = "the app connected to the database on port {int}")]
+fn app_on_port(
+    _: &mut World,
+    #[fixture] app: &App,
+    port: u16,
+    #[step] ctx: &Context,
+) {
+    assert_eq!(port, 8080);
+    let db = ctx.fixture::<Database>().expect("no `Database`");
+    assert!(Arc::ptr_eq(&app.db, &db), "`Database` should be shared");
+}
+
+fn fixtures(log: &Log, app_fails: bool) -> Fixtures {
+    let (db_setup, db_teardown) = (Arc::clone(log), Arc::clone(log));
+    let (app_setup, app_teardown) = (Arc::clone(log), Arc::clone(log));
+    Fixtures::new()
+        .fixture(
+            Fixture::new("db", move |_| {
+                db_setup.lock().unwrap().push("+db");
+                async { Ok::<_, Infallible>(Database) }
+            })
+            .scope(Scope::Feature)
+            .teardown(move |_| {
+                db_teardown.lock().unwrap().push("-db");
+                async {}
+            }),
+        )
+        .fixture(
+            Fixture::new("app", move |deps| {
+                app_setup.lock().unwrap().push("+app");
+                async move {
+                    if app_fails {
+                        return Err("port is taken");
+                    }
+                    Ok(App { db: deps.get().ok_or("no `Database`")? })
+                }
+            })
+            .depends_on("db")
+            .teardown(move |_| {
+                app_teardown.lock().unwrap().push("-app");
+                async {}
+            }),
+        )
+}
+
+#[tokio::test]
+async fn sets_up_and_tears_down_fixtures_by_scope() {
+    let log = Log::default();
+    let writer = World::cucumber()
+        .fixtures(fixtures(&log, false))
+        .max_concurrent_scenarios(1)
+        .fail_on_skipped()
+        .with_default_cli()
+        .run("tests/features/fixture")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert!(!writer.execution_has_failed());
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["+db", "+app", "-app", "+app", "-app", "-db"],
+    );
+}
+
+#[tokio::test]
+async fn fails_scenarios_on_fixture_setup_error() {
+    let log = Log::default();
+    let writer = World::cucumber()
+        .fixtures(fixtures(&log, true))
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run("tests/features/fixture")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.hook_errors(), 2);
+    assert_eq!(*log.lock().unwrap(), ["+db", "+app", "+app", "-db"]);
+}
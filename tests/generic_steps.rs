@@ -0,0 +1,94 @@
+use cucumber::{
+    World as _, given,
+    step::{Collection, Context, StepBuilder},
+    then, when,
+    writer::summarize::Stats,
+};
+use futures::future::LocalBoxFuture;
+use regex::Regex;
+
+trait HasClient {
+    fn requests(&mut self) -> &mut Vec<String>;
+}
+
+#[given(expr = "the {word} API is up")]
+#[when(expr = "the {word} API is called")]
+fn call_api<W: HasClient>(w: &mut W, api: String) {
+    w.requests().push(api);
+}
+
+#[then(expr = "{int} requests are sent")]
+async fn requests_sent<W>(w: &mut W, count: usize)
+where
+    W: HasClient,
+{
+    assert_eq!(w.requests().len(), count);
+}
+
+fn reset_client<W: HasClient>(
+    w: &mut W,
+    _: Context,
+) -> LocalBoxFuture<'_, ()> {
+    w.requests().clear();
+    Box::pin(async {})
+}
+
+struct ApiSteps;
+
+impl<W: HasClient> StepBuilder<W> for ApiSteps {
+    fn register_steps(collection: Collection<W>) -> Collection<W> {
+        requests_sent::register(call_api::register(collection))
+    }
+
+    fn domain_name() -> &'static str {
+        "API"
+    }
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    requests: Vec<String>,
+}
+
+impl HasClient for World {
+    fn requests(&mut self) -> &mut Vec<String> {
+        &mut self.requests
+    }
+}
+
+#[tokio::test]
+async fn registers_generic_steps() {
+    let steps = ApiSteps::register_steps(World::collection());
+    assert_eq!(steps.given_len(), 1);
+    assert_eq!(steps.when_len(), 1);
+    assert_eq!(steps.then_len(), 1);
+
+    let writer = World::cucumber()
+        .steps(steps)
+        .with_default_cli()
+        .run("tests/features/generic_steps")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 2,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+}
+
+#[test]
+fn coerces_generic_step_functions() {
+    let steps = Collection::<World>::new().given(
+        None,
+        Regex::new("^the client is reset$").unwrap(),
+        reset_client,
+    );
+    assert_eq!(steps.given_len(), 1);
+}
@@ -0,0 +1,128 @@
+use std::{io, net::SocketAddr};
+
+use cucumber::{
+    World as _, WriterExt as _, given, then, when,
+    writer::{
+        self, Coloring,
+        grpc::proto::{
+            Envelope, SubscribeRequest, TestStepResultStatus, envelope::Message,
+        },
+    },
+};
+use tonic::{
+    Request, Streaming, client::Grpc, codegen::http::uri::PathAndQuery,
+    transport::Endpoint,
+};
+use tonic_prost::ProstCodec;
+
+#[derive(Debug, Default, cucumber::World)]
+struct World(usize);
+
+#[given("a cucumber")]
+fn cucumber(w: &mut World) {
+    w.0 = 1;
+}
+
+#[when("the cucumber is eaten")]
+fn eaten(w: &mut World) {
+    w.0 -= 1;
+}
+
+#[then("there are no cucumbers")]
+fn none(w: &mut World) {
+    assert_eq!(w.0, 0, "cucumbers remain");
+}
+
+#[then(expr = "there are {int} cucumbers")]
+fn total(w: &mut World, n: usize) {
+    assert_eq!(w.0, n, "wrong number of cucumbers");
+}
+
+async fn subscribe(addr: SocketAddr) -> Streaming<Envelope> {
+    let channel = Endpoint::from_shared(format!("http://{addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = Grpc::new(channel);
+    client.ready().await.unwrap();
+    client
+        .server_streaming(
+            Request::new(SubscribeRequest {}),
+            PathAndQuery::from_static("/cucumber.messages.Envelopes/Subscribe"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner()
+}
+
+async fn collect(mut stream: Streaming<Envelope>) -> Vec<Message> {
+    let mut messages = vec![];
+    while let Some(env) = stream.message().await.unwrap() {
+        messages.push(env.message.unwrap());
+    }
+    messages
+}
+
+fn statuses(messages: &[Message]) -> Vec<TestStepResultStatus> {
+    messages
+        .iter()
+        .filter_map(|m| match m {
+            Message::TestStepFinished(f) => {
+                Some(f.test_step_result.as_ref().unwrap().status())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn streams_envelopes_to_early_and_late_subscribers() {
+    let grpc = writer::Grpc::bind("127.0.0.1:0").unwrap();
+    let addr = grpc.local_addr();
+    let early = tokio::spawn(collect(subscribe(addr).await));
+
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(
+            writer::Basic::raw(io::sink(), Coloring::Never, 0)
+                .summarized()
+                .tee::<World, _>(grpc.for_tee())
+                .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/grpc")
+        .await;
+
+    let late = collect(subscribe(addr).await).await;
+    drop(writer);
+    let early = early.await.unwrap();
+    assert_eq!(early, late, "subscribers received different envelopes");
+
+    let Message::Meta(meta) = &late[0] else { panic!("{late:?}") };
+    assert_eq!(meta.implementation.as_ref().unwrap().name, "cucumber-rs");
+    let Some(Message::TestRunFinished(finished)) = late.last() else {
+        panic!("{late:?}");
+    };
+    assert!(!finished.success, "run is not failed");
+
+    let count = |f: fn(&Message) -> bool| late.iter().filter(|m| f(m)).count();
+    assert_eq!(count(|m| matches!(m, Message::Pickle(_))), 3);
+    assert_eq!(count(|m| matches!(m, Message::TestCase(_))), 3);
+    assert_eq!(count(|m| matches!(m, Message::TestCaseStarted(_))), 3);
+    assert_eq!(count(|m| matches!(m, Message::TestCaseFinished(_))), 3);
+    assert_eq!(
+        count(|m| matches!(m, Message::TestStepStarted(_))),
+        count(|m| matches!(m, Message::TestStepFinished(_))),
+    );
+
+    let statuses = statuses(&late);
+    for status in [
+        TestStepResultStatus::Passed,
+        TestStepResultStatus::Failed,
+        TestStepResultStatus::Skipped,
+    ] {
+        assert!(statuses.contains(&status), "no {status:?} in {statuses:?}");
+    }
+}
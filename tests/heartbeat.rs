@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use cucumber::{World as _, given, then, writer};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+#[then("the cat is awake")]
+fn awake(_: &mut World) {}
+
+#[tokio::test]
+async fn renders_long_running_steps() {
+    let mut out = Vec::new();
+    _ = World::cucumber()
+        .with_writer(writer::Basic::new(&mut out, writer::Coloring::Never, 0))
+        .heartbeat(Duration::from_millis(100))
+        .with_default_cli()
+        .run("tests/features/heartbeat")
+        .await;
+    let out = String::from_utf8(out).unwrap();
+
+    let heartbeat = "still running Given the cat naps for 250 milliseconds";
+    assert_eq!(out.matches(heartbeat).count(), 2, "{out}");
+    assert!(out.find(heartbeat) < out.find("✔  Given the cat naps"), "{out}");
+    assert!(!out.contains("still running Then"), "{out}");
+}
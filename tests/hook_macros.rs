@@ -0,0 +1,86 @@
+use std::sync::{
+    Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use cucumber::{
+    World, after, before, event::ScenarioFinished, gherkin::Scenario, then,
+    writer::summarize::Stats,
+};
+
+static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static CLEANED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Default, World)]
+struct Db {
+    rows: Vec<String>,
+}
+
+#[before]
+fn open(db: &mut Db, scenario: &Scenario) {
+    db.rows.clear();
+    LOG.lock().unwrap().push(format!("open {}", scenario.name));
+}
+
+#[before(tags = "@db")]
+async fn seed(db: &mut Db) -> Result<(), String> {
+    db.rows.push("admin".into());
+    Ok(())
+}
+
+#[after]
+fn close(db: Option<&mut Db>, scenario: &Scenario, _: &ScenarioFinished) {
+    assert!(db.is_some(), "`World` is expected to be created");
+    LOG.lock().unwrap().push(format!("close {}", scenario.name));
+}
+
+#[after(tags = "@db")]
+async fn clean(db: &mut Db) {
+    db.rows.clear();
+    _ = CLEANED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[then("the database is seeded")]
+fn seeded(db: &mut Db) {
+    assert_eq!(db.rows, ["admin"]);
+}
+
+#[then("the database is empty")]
+fn empty(db: &mut Db) {
+    assert!(db.rows.is_empty(), "database is expected to be empty");
+}
+
+#[tokio::test]
+async fn runs_hooks() {
+    let writer = Db::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run("tests/features/hook_macros")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 2,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    assert_eq!(CLEANED.load(Ordering::SeqCst), 1);
+
+    let mut log = LOG.lock().unwrap().clone();
+    log.sort();
+    assert_eq!(
+        log,
+        [
+            "close empty database",
+            "close seeded database",
+            "open empty database",
+            "open seeded database",
+        ],
+    );
+}
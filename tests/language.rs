@@ -0,0 +1,36 @@
+use cucumber::{Parser as _, parser};
+use futures::StreamExt as _;
+
+async fn parse(
+    cli: parser::basic::Cli,
+) -> Vec<Result<gherkin::Feature, parser::Error>> {
+    parser::Basic::new()
+        .language("de")
+        .unwrap()
+        .parse("tests/features/language", cli)
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn uses_configured_language_and_honors_headers() {
+    let features = parse(parser::basic::Cli::default()).await;
+
+    let keywords = features
+        .iter()
+        .map(|f| f.as_ref().unwrap().keyword.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(keywords, ["Funktionalität", "Egenskap"]);
+}
+
+#[tokio::test]
+async fn cli_language_overrides_configured_one() {
+    let features = parse(parser::basic::Cli {
+        language: Some("en".into()),
+        ..parser::basic::Cli::default()
+    })
+    .await;
+
+    assert!(features[0].is_err(), "German keywords are parsed as English");
+    assert_eq!(features[1].as_ref().unwrap().keyword, "Egenskap");
+}
@@ -0,0 +1,81 @@
+use clap::Parser as _;
+use cucumber::{Parser as _, cli, parser, runner, writer};
+use futures::StreamExt as _;
+
+async fn lines(
+    input: &str,
+    cli: parser::basic::Cli,
+) -> Vec<(String, Vec<usize>)> {
+    parser::Basic::new()
+        .parse(input, cli)
+        .map(|f| {
+            let f = f.unwrap();
+            let lines = f
+                .scenarios
+                .iter()
+                .chain(f.rules.iter().flat_map(|r| &r.scenarios))
+                .map(|s| s.position.line)
+                .collect();
+            (f.name, lines)
+        })
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn runs_only_scenarios_at_input_lines() {
+    let features = lines(
+        "tests/features/wait/rule_outline.feature:11:14",
+        parser::basic::Cli::default(),
+    )
+    .await;
+
+    assert_eq!(features, [("Rule Outline".into(), vec![11, 14])]);
+}
+
+#[tokio::test]
+async fn cli_locations_override_inputs() {
+    let features = lines(
+        "tests/features/wait",
+        parser::basic::Cli {
+            locations: vec![
+                "tests/features/wait/rule_outline.feature:4".into(),
+                "tests/features/readme/eating.feature".into(),
+            ],
+            ..parser::basic::Cli::default()
+        },
+    )
+    .await;
+
+    assert_eq!(
+        features,
+        [
+            ("Rule Outline".into(), vec![11, 12, 13, 14]),
+            (
+                "Eating too much cucumbers may not be good for you".into(),
+                vec![3],
+            ),
+        ],
+    );
+}
+
+#[test]
+fn parses_cli_locations() {
+    let opts = cli::Opts::<
+        parser::basic::Cli,
+        runner::basic::Cli,
+        writer::basic::Cli,
+    >::try_parse_from([
+        "cucumber",
+        "--tags=@smoke",
+        "features/checkout.feature:42:77",
+        "features/cart",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        opts.parser.locations,
+        ["features/checkout.feature:42:77", "features/cart"]
+            .map(std::path::PathBuf::from),
+    );
+}
@@ -0,0 +1,84 @@
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, cli::Parser as _, event,
+    parser, step::Context, then, when, writer,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[when(regex = r"^the customer places order (\d+)$")]
+async fn places(_: &mut World, #[step] ctx: &Context, id: u64) {
+    ctx.publish(event::Custom::new("order placed").field("id", id));
+    tokio::task::yield_now().await;
+    ctx.publish("payment captured");
+}
+
+#[then("the order is confirmed")]
+fn confirmed(_: &mut World) {}
+
+/// [`Writer`] collecting [`event::Step::Published`] events along with the
+/// steps they were published from.
+#[derive(Default)]
+struct Published(Vec<(String, event::Custom)>);
+
+impl Writer<World> for Published {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(
+                _,
+                event::RetryableScenario {
+                    event:
+                        event::Scenario::Step(step, event::Step::Published(ev)),
+                    ..
+                },
+            ),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push((step.value.clone(), ev));
+        }
+    }
+}
+
+#[tokio::test]
+async fn delivers_published_events_to_writers() {
+    let writer = World::cucumber()
+        .with_writer(Published::default().normalized())
+        .with_default_cli()
+        .run("tests/features/published")
+        .await;
+
+    let step = "the customer places order 123";
+    assert_eq!(
+        writer.inner_writer().0,
+        [
+            (step.into(), event::Custom::new("order placed").field("id", 123)),
+            (step.into(), event::Custom::new("payment captured")),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn renders_published_events() {
+    let mut out = Vec::new();
+    _ = World::cucumber()
+        .with_writer(writer::Basic::new(&mut out, writer::Coloring::Never, 0))
+        .with_cli(
+            cli::Opts::<_, _, _>::try_parse_from(["test", "--show-output"])
+                .unwrap(),
+        )
+        .run("tests/features/published")
+        .await;
+    let out = String::from_utf8(out).unwrap();
+
+    let placed = out.find("»  order placed id=123").expect(&out);
+    let captured = out.find("»  payment captured").expect(&out);
+    let passed = out.find("✔  When the customer places order 123").expect(&out);
+    assert!(placed < captured && captured < passed, "{out}");
+}
@@ -0,0 +1,77 @@
+use std::io;
+
+use cucumber::{
+    World as _, WriterExt as _, cli, given, then, when,
+    writer::{self, Coloring, record},
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World(usize);
+
+#[given(expr = "{int} cucumber")]
+fn cucumber(w: &mut World, n: usize) {
+    w.0 = n;
+}
+
+#[when(expr = "{int} more cucumbers are added")]
+fn added(w: &mut World, n: usize) {
+    w.0 += n;
+}
+
+#[then(expr = "there are {int} cucumbers")]
+fn total(w: &mut World, n: usize) {
+    assert_eq!(w.0, n, "wrong number of cucumbers");
+}
+
+#[tokio::test]
+async fn replays_recorded_run_into_writers() {
+    let (mut json, mut basic, mut recording) = (vec![], vec![], vec![]);
+    _ = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(&mut basic, Coloring::Always, 0)
+                .discard_stats_writes()
+                .tee::<World, _>(writer::Json::for_tee(&mut json))
+                .tee::<World, _>(writer::Record::for_tee(&mut recording))
+                .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/record")
+        .await;
+
+    let (mut replayed_json, mut replayed_basic) = (vec![], vec![]);
+    record::replay::<World, _>(
+        recording.as_slice(),
+        &mut writer::Json::new(&mut replayed_json),
+        &cli::Empty,
+    )
+    .await
+    .unwrap();
+    record::replay::<World, _>(
+        recording.as_slice(),
+        &mut writer::Basic::raw(&mut replayed_basic, Coloring::Always, 0)
+            .normalized(),
+        &writer::basic::Cli::default(),
+    )
+    .await
+    .unwrap();
+
+    assert!(!json.is_empty(), "nothing is reported");
+    assert_eq!(String::from_utf8(replayed_json), String::from_utf8(json));
+    assert_eq!(String::from_utf8(replayed_basic), String::from_utf8(basic));
+}
+
+#[tokio::test]
+async fn rejects_unknown_versions() {
+    let err = record::replay::<World, _>(
+        io::Cursor::new(r#"{"header":{"version":0}}"#),
+        &mut writer::Json::new(io::sink()),
+        &cli::Empty,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(err, record::ReplayError::UnsupportedVersion(Some(0))),
+        "unexpected error: {err}",
+    );
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser, then,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    yarn: Vec<u8>,
+}
+
+#[given(regex = r"^the cat hoards (\d+) megabytes of yarn$")]
+fn hoards(w: &mut World, mb: usize) {
+    w.yarn = vec![1; mb * 1024 * 1024];
+}
+
+#[then(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+/// [`Writer`] collecting [`event::ResourceUsage`]s of the finished scenarios.
+#[derive(Default)]
+struct Usages(Vec<Option<event::ResourceUsage>>);
+
+impl Writer<World> for Usages {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(
+                _,
+                event::RetryableScenario {
+                    event: event::Scenario::Finished(usage),
+                    ..
+                },
+            ),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push(usage);
+        }
+    }
+}
+
+#[tokio::test]
+async fn samples_resources_only_when_enabled() {
+    let writer = World::cucumber()
+        .with_writer(Usages::default().normalized())
+        .with_default_cli()
+        .run("tests/features/resource_usage")
+        .await;
+    assert_eq!(writer.inner_writer().0, [None]);
+
+    let writer = World::cucumber()
+        .with_writer(Usages::default().normalized())
+        .resource_sampling(Duration::from_millis(10))
+        .with_default_cli()
+        .run("tests/features/resource_usage")
+        .await;
+    let usages = &writer.inner_writer().0;
+    assert_eq!(usages.len(), 1);
+
+    if cfg!(not(target_os = "linux")) {
+        assert_eq!(usages[0], None);
+        return;
+    }
+    let usage = usages[0].expect("resources are sampled");
+    assert!(usage.samples >= 2, "{usage:?}");
+    assert!(
+        usage.memory.max - usage.memory.min >= 32.0 * 1024.0 * 1024.0,
+        "{usage:?}",
+    );
+    assert!(usage.memory.min <= usage.memory.avg, "{usage:?}");
+    assert!(usage.memory.avg <= usage.memory.max, "{usage:?}");
+    assert!(usage.threads.min >= 1.0, "{usage:?}");
+}
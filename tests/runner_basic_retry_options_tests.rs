@@ -43,6 +43,15 @@ Feature: only scenarios
             retry: None,
             retry_after: None,
             retry_tag_filter: None,
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
@@ -89,6 +98,15 @@ Feature: only scenarios
             retry: Some(7),
             retry_after: None,
             retry_tag_filter: None,
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
@@ -138,6 +156,15 @@ Feature: only scenarios
             retry: Some(7),
             retry_after: Some(parse_duration("5s").unwrap()),
             retry_tag_filter: None,
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
@@ -187,6 +214,15 @@ Feature: only scenarios
             retry: Some(7),
             retry_after: None,
             retry_tag_filter: Some("@retry".parse().unwrap()),
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
@@ -233,6 +269,15 @@ Feature: only scenarios
             retry: Some(7),
             retry_after: Some(parse_duration("5s").unwrap()),
             retry_tag_filter: Some("@retry".parse().unwrap()),
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
@@ -328,6 +373,15 @@ Feature: only scenarios
             retry: None,
             retry_after: None,
             retry_tag_filter: None,
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
@@ -459,6 +513,15 @@ Feature: only scenarios
             retry: Some(7),
             retry_after: Some(parse_duration("5s").unwrap()),
             retry_tag_filter: Some("@retry".parse().unwrap()),
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
@@ -659,6 +722,15 @@ Feature: only scenarios
             retry: None,
             retry_after: None,
             retry_tag_filter: None,
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .unwrap_or_else(|e| panic!("failed to parse feature: {e}"));
@@ -828,6 +900,15 @@ Feature: only scenarios
             retry: Some(7),
             retry_after: Some(parse_duration("5s").unwrap()),
             retry_tag_filter: Some("@retry".parse().unwrap()),
+            changed_files: None,
+            duration_history: None,
+            until_failure: None,
+            until_failure_seed: None,
+            heartbeat: None,
+            step_timeout: None,
+            sample_resources: None,
+            backtrace: false,
+            after_panic: None,
         };
         let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
             .expect("failed to parse feature");
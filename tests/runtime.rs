@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use cucumber::{
+    World as _, given,
+    runtime::{self, Runtime},
+    then,
+    writer::summarize::Stats,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    runtime::Agnostic.sleep(Duration::from_millis(ms)).await;
+}
+
+#[then("the cat is awake")]
+fn awake(_: &mut World) {}
+
+/// Runs the `.feature` with step timeouts measured by the provided
+/// [`Runtime`], asserting a single [`Scenario`] to time out.
+///
+/// [`Scenario`]: gherkin::Scenario
+async fn times_out_via(runtime: impl Runtime) {
+    let writer = World::cucumber()
+        .runtime(runtime)
+        .step_timeout(Duration::from_millis(200))
+        .with_default_cli()
+        .run("tests/features/timeout")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 2,
+            skipped: 0,
+            failed: 0,
+            timed_out: 1,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+}
+
+#[test]
+fn agnostic() {
+    futures::executor::block_on(times_out_via(runtime::Agnostic));
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn tokio() {
+    times_out_via(runtime::Tokio).await;
+}
+
+#[cfg(feature = "async-std")]
+#[test]
+fn async_std() {
+    async_std::task::block_on(times_out_via(runtime::AsyncStd));
+}
+
+#[cfg(feature = "smol")]
+#[test]
+fn smol() {
+    smol::block_on(times_out_via(runtime::Smol));
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser,
+    runner::basic::ScenarioId, step::Context, writer,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("the step reports its scenario ID")]
+fn reports(_: &mut World, #[step] ctx: &Context) {
+    let id = ctx.scenario_id().expect("`ScenarioId` is provided");
+    ctx.publish(event::Custom::new("scenario").field("id", id));
+}
+
+/// [`Writer`] collecting [`ScenarioId`]s of all the [`event::Scenario`]s along
+/// with the ones reported by the steps.
+#[derive(Default)]
+struct Ids {
+    /// [`ScenarioId`]s of the [`event::Scenario`]s by the scenario names.
+    events: HashMap<String, Vec<Option<ScenarioId>>>,
+
+    /// [`ScenarioId`]s reported by the steps, by the scenario names.
+    reported: HashMap<String, Vec<String>>,
+}
+
+impl Writer<World> for Ids {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(sc, ev),
+        )) = ev.map(Event::into_inner)
+        {
+            if let event::Scenario::Step(_, event::Step::Published(p)) =
+                &ev.event
+            {
+                self.reported
+                    .entry(sc.name.clone())
+                    .or_default()
+                    .push(p.fields[0].1.clone());
+            }
+            self.events.entry(sc.name.clone()).or_default().push(ev.id);
+        }
+    }
+}
+
+#[tokio::test]
+async fn correlates_scenario_events() {
+    let writer = World::cucumber()
+        .with_writer(Ids::default().normalized())
+        .with_default_cli()
+        .run("tests/features/scenario_ids")
+        .await;
+    let Ids { events, reported } = writer.inner_writer();
+
+    let mut ids = Vec::new();
+    for name in ["first", "second"] {
+        let id = events[name][0].expect("`ScenarioId` is provided");
+        assert!(events[name].iter().all(|i| *i == Some(id)), "{events:?}");
+        assert!(reported[name].iter().all(|i| *i == id.to_string()));
+        ids.push(id);
+    }
+    assert_eq!(reported["first"].len(), 2);
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[tokio::test]
+async fn prefixes_output_with_scenario_ids() {
+    let mut out = Vec::new();
+    let mut opts = cli::Opts::<_, _, writer::basic::Cli, cli::Empty>::default();
+    opts.writer.scenario_ids = true;
+    opts.writer.show_output = true;
+
+    _ = World::cucumber()
+        .with_writer(writer::Basic::new(&mut out, writer::Coloring::Never, 0))
+        .with_cli(opts)
+        .run("tests/features/scenario_ids")
+        .await;
+    let out = String::from_utf8(out).unwrap();
+
+    let line =
+        out.lines().find(|l| l.contains("Scenario: second")).expect(&out);
+    let id = line
+        .trim_start()
+        .strip_prefix("[#")
+        .and_then(|l| l.split_once(']'))
+        .map(|(id, _)| id)
+        .expect(&out);
+    let prefix = format!("[#{id}] ");
+    // Scenario, its step and the event published by the step.
+    assert_eq!(out.lines().filter(|l| l.starts_with(&prefix)).count(), 3);
+    assert!(out.starts_with("Feature: Scenario IDs"), "{out}");
+}
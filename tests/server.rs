@@ -0,0 +1,106 @@
+#[cfg(unix)]
+use std::{
+    fs,
+    os::unix::{fs::PermissionsExt as _, net::UnixStream},
+};
+use std::{
+    io::{BufRead as _, BufReader, Write as _},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use cucumber::{World as _, WriterExt as _, given, writer};
+use serde_json::{Value, json};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+#[tokio::test]
+async fn streams_events_and_stops_gracefully() {
+    let server = writer::Server::tcp("127.0.0.1:0").unwrap();
+    assert_eq!(server.progress(), writer::server::Progress::default());
+
+    let conn = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+    let mut commands = conn.try_clone().unwrap();
+    let mut lines = BufReader::new(conn).lines();
+
+    // The reply guarantees the client is subscribed before the run starts.
+    commands.write_all(b"dance\n").unwrap();
+    let reply: Value =
+        serde_json::from_str(&lines.next().unwrap().unwrap()).unwrap();
+    assert_eq!(reply["type"], "error", "{reply}");
+
+    let client = thread::spawn(move || {
+        let (mut received, mut stopped) = (vec![], false);
+        for line in lines {
+            let line: Value = serde_json::from_str(&line.unwrap()).unwrap();
+            let started = line.pointer("/value/feature/1/scenario/1/event")
+                == Some(&json!("started"));
+            if started && !stopped {
+                commands.write_all(b"progress\nstop\n").unwrap();
+                stopped = true;
+            }
+            received.push(line);
+        }
+        received
+    });
+
+    let stop = server.stop_signal();
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .stop_signal(stop.clone())
+        .with_writer(server.normalized())
+        .with_default_cli()
+        .run("tests/features/server")
+        .await;
+    assert!(stop.is_requested(), "stop is not requested");
+
+    let progress = writer.inner_writer().progress();
+    assert_eq!(progress.scenarios, Some(4), "{progress:?}");
+    assert_eq!(progress.scenarios_started, 1, "{progress:?}");
+    assert_eq!(progress.scenarios_finished, 1, "{progress:?}");
+    assert!(progress.stopping && progress.finished, "{progress:?}");
+
+    drop(writer);
+    let received = client.join().unwrap();
+    let progress = received.iter().find(|l| l["type"] == "progress").unwrap();
+    assert_eq!(progress["scenarios_started"], 1, "{progress}");
+    assert_eq!(progress["finished"], false, "{progress}");
+    assert!(received.iter().any(|l| l["type"] == "stopping"));
+
+    let last = received.last().unwrap();
+    assert_eq!(last["type"], "event", "{last}");
+    assert_eq!(last["value"], "finished", "{last}");
+}
+
+#[test]
+fn releases_tcp_port_on_drop() {
+    let server = writer::Server::tcp("127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap();
+    drop(server);
+
+    drop(TcpListener::bind(addr).unwrap());
+}
+
+#[cfg(unix)]
+#[test]
+fn restricts_unix_socket_to_owner() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cucumber.sock");
+    let server = writer::Server::unix(&path).unwrap();
+
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600, "{mode:o}");
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1, "leftovers");
+    drop(UnixStream::connect(&path).unwrap());
+    assert!(writer::Server::unix(&path).is_err(), "socket is replaced");
+
+    drop(server);
+    assert!(!path.exists(), "socket is not removed");
+}
@@ -0,0 +1,39 @@
+use std::sync::Mutex;
+
+use cucumber::{World as _, given, step::Context, writer::Stats as _};
+
+/// Addresses of the shared `Config` seen by steps.
+static SEEN: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+/// Run-scoped state shared between scenarios.
+#[derive(Debug)]
+struct Config {
+    base_url: &'static str,
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("the shared config")]
+fn shared_config(_: &mut World, #[step] ctx: &Context) {
+    let config = ctx.shared::<Config>().expect("no shared `Config`");
+    assert_eq!(config.base_url, "http://localhost");
+    SEEN.lock().unwrap().push(config.as_ref() as *const Config as usize);
+}
+
+#[tokio::test]
+async fn shares_state_between_scenarios() {
+    let writer = World::cucumber()
+        .shared_state(Config { base_url: "http://localhost" })
+        .fail_on_skipped()
+        .with_default_cli()
+        .run("tests/features/shared_state")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert!(!writer.execution_has_failed());
+
+    let seen = SEEN.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    assert_eq!(seen[0], seen[1], "`Config` should be created once");
+}
@@ -0,0 +1,57 @@
+use std::{io, time::Duration};
+
+use cucumber::{
+    World as _, WriterExt as _, given,
+    runner::basic::Sla,
+    writer::{self, Stats as _},
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+#[tokio::test]
+async fn fails_scenarios_violating_sla() {
+    let mut out = Vec::new();
+    let writer = World::cucumber()
+        .sla(Sla::new().tag("@fast", Duration::from_millis(50)))
+        .with_writer(
+            writer::Basic::new(&mut out, writer::Coloring::Never, 0)
+                .summarized(),
+        )
+        .with_default_cli()
+        .run("tests/features/sla")
+        .await;
+
+    assert_eq!(writer.sla_violations_count(), 1);
+    assert_eq!(writer.scenarios_stats().failed, 1);
+    assert_eq!(writer.scenarios_stats().passed, 1);
+    assert_eq!(writer.steps_stats().failed, 0);
+    assert!(writer.execution_has_failed());
+    drop(writer);
+
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("⏱  @fast SLA of 50ms exceeded: took"), "{out}");
+    assert!(out.contains("1 SLA violation"), "{out}");
+}
+
+#[tokio::test]
+async fn fails_only_run_violating_sla() {
+    let writer = World::cucumber()
+        .sla(Sla::new().tag("fast", Duration::from_millis(50)).fail_run())
+        .with_writer(
+            writer::Basic::new(io::sink(), writer::Coloring::Never, 0)
+                .summarized(),
+        )
+        .with_default_cli()
+        .run("tests/features/sla")
+        .await;
+
+    assert_eq!(writer.sla_violations_count(), 1);
+    assert_eq!(writer.scenarios_stats().passed, 2);
+    assert!(writer.execution_has_failed());
+}
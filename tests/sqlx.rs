@@ -0,0 +1,71 @@
+use cucumber::{
+    World as _,
+    fixture::{
+        Fixtures,
+        sqlx::{Transaction, transaction},
+    },
+    given, then, when,
+    writer::Stats as _,
+};
+use sqlx::{Sqlite, SqlitePool, sqlite::SqlitePoolOptions};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(expr = "a user {word}")]
+async fn user(
+    _: &mut World,
+    #[fixture] tx: &Transaction<Sqlite>,
+    name: String,
+) {
+    sqlx::query("INSERT INTO users (name) VALUES (?)")
+        .bind(name)
+        .execute(&mut **tx.lock().await)
+        .await
+        .unwrap();
+}
+
+#[when("the step panics")]
+fn panics(_: &mut World) {
+    panic!("step panicked");
+}
+
+#[then(expr = "there is {int} user")]
+async fn users(_: &mut World, #[fixture] tx: &Transaction<Sqlite>, n: i64) {
+    assert_eq!(count(&mut **tx.lock().await).await, n);
+}
+
+async fn count(conn: &mut sqlx::SqliteConnection) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(conn)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn rolls_back_transaction_after_each_scenario() {
+    // Single connection keeps the same in-memory database.
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::query("CREATE TABLE users (name TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let writer = World::cucumber()
+        .fixtures(Fixtures::new().fixture(transaction("db", pool.clone())))
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run("tests/features/sqlx")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.hook_errors(), 0);
+    assert_eq!(count(&mut pool.acquire().await.unwrap()).await, 0);
+}
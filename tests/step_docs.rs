@@ -0,0 +1,51 @@
+use cucumber::{World as _, given, steps, then, when};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    hungry: bool,
+}
+
+/// A cat, which hasn't been fed yet.
+#[given("a hungry cat")]
+fn hungry_cat(world: &mut World) {
+    world.hungry = true;
+}
+
+#[steps]
+impl World {
+    /// Feeds the cat.
+    ///
+    ///   Makes it not hungry.
+    #[when("I feed the cat")]
+    fn feed(&mut self) {
+        self.hungry = false;
+    }
+}
+
+#[then("the cat is not hungry")]
+fn not_hungry(world: &mut World) {
+    assert!(!world.hungry, "the cat is hungry");
+}
+
+#[test]
+fn captures_doc_comments() {
+    let docs = World::collection()
+        .definitions()
+        .map(|def| (def.pattern.to_owned(), def.doc))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        docs,
+        [
+            (
+                "^a hungry cat$".into(),
+                Some("A cat, which hasn't been fed yet.")
+            ),
+            (
+                "^I feed the cat$".into(),
+                Some("Feeds the cat.\n\n  Makes it not hungry."),
+            ),
+            ("^the cat is not hungry$".into(), None),
+        ],
+    );
+}
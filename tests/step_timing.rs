@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser, then,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+#[then("the cat is awake")]
+fn awake(_: &mut World) {}
+
+/// [`Writer`] collecting [`event::StepTiming`]s of the passed steps.
+#[derive(Default)]
+struct Timings(Vec<(String, event::StepTiming)>);
+
+impl Writer<World> for Timings {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(
+                _,
+                event::RetryableScenario {
+                    event:
+                        event::Scenario::Step(
+                            step,
+                            event::Step::Passed { timing, .. },
+                        ),
+                    ..
+                },
+            ),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push((step.value.clone(), timing));
+        }
+    }
+}
+
+#[tokio::test]
+async fn separates_matching_from_execution() {
+    let writer = World::cucumber()
+        .with_writer(Timings::default().normalized())
+        .run("tests/features/step_timing")
+        .await;
+
+    let timings = &writer.inner_writer().0;
+    assert_eq!(timings.len(), 2);
+
+    let (slow, fast) = (&timings[0].1, &timings[1].1);
+    assert_eq!(timings[0].0, "the cat naps for 50 milliseconds");
+    assert!(slow.execution >= Duration::from_millis(50), "{slow:?}");
+    assert!(slow.matching < slow.execution, "{slow:?}");
+    assert!(fast.execution < slow.execution, "{fast:?}");
+}
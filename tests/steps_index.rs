@@ -0,0 +1,70 @@
+use cucumber::{World as _, cli, given, then, when};
+use tempfile::TempDir;
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("a cat")]
+fn cat(_: &mut World) {}
+
+#[when(regex = r"^the cat eats (\d+) fish$")]
+fn eats(_: &mut World, _count: u32) {}
+
+#[when(regex = r"^the cat (eats|naps).*$")]
+fn acts(_: &mut World) {}
+
+#[then("the cat purrs")]
+fn purrs(_: &mut World) {}
+
+#[tokio::test]
+async fn writes_steps_with_feature_locations() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("index/steps.json");
+    let mut cli = cli::Opts::<_, _, _>::default();
+    cli.steps_index.steps_index_file = Some(path.clone());
+
+    drop(
+        World::cucumber().with_cli(cli).run("tests/features/steps_index").await,
+    );
+
+    let index: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    let patterns = index["steps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["pattern"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    let definition =
+        |pattern: &str| patterns.iter().position(|p| *p == pattern).unwrap();
+
+    let feature = &index["features"][0];
+    assert_eq!(feature["path"], "tests/features/steps_index/cat.feature");
+    assert_eq!(
+        feature["scenarios"],
+        serde_json::json!([
+            {"name": "feeding", "rule": null, "line": 5, "column": 3},
+            {"name": "napping", "rule": "sleeping", "line": 10, "column": 5},
+        ]),
+    );
+
+    let steps = feature["steps"].as_array().unwrap();
+    let at = |line: u64| {
+        steps.iter().find(|s| s["line"] == line).unwrap()["definitions"].clone()
+    };
+    assert_eq!(steps.len(), 4, "{feature:#}");
+    assert_eq!(at(3), serde_json::json!([definition("^a cat$")]));
+    assert_eq!(
+        at(6),
+        serde_json::json!([
+            definition(r"^the cat (eats|naps).*$"),
+            definition(r"^the cat eats (\d+) fish$"),
+        ]),
+        "ambiguous step should point to all its definitions",
+    );
+    assert_eq!(at(7), serde_json::json!([definition("^the cat purrs$")]));
+    assert_eq!(
+        at(11),
+        serde_json::json!([definition(r"^the cat (eats|naps).*$")])
+    );
+}
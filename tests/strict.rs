@@ -0,0 +1,82 @@
+use cucumber::{
+    World as _, WriterExt as _,
+    exit_code::Outcome,
+    given, step, then,
+    writer::{self, Stats as _, summarize::Stats},
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("the cat is hungry")]
+fn hungry(_: &mut World) {}
+
+#[then("the cat is fed")]
+fn fed(_: &mut World) {}
+
+#[then("the cat purrs")]
+fn purrs(_: &mut World) {
+    step::Pending::raise("purring isn't measurable yet");
+}
+
+#[tokio::test]
+async fn counts_pending_steps_separately() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/strict/pending.feature")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 1,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 1,
+            retried: 0
+        },
+    );
+    assert_eq!(writer.pending_steps(), 1);
+    assert_eq!(writer.failed_steps(), 0);
+    assert!(!writer.execution_has_failed());
+    assert_eq!(Outcome::of(&writer, false), None);
+    assert_eq!(Outcome::of(&writer, true), Some(Outcome::Undefined));
+}
+
+#[tokio::test]
+async fn counts_undefined_steps_separately() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/strict/undefined.feature")
+        .await;
+
+    assert_eq!(writer.undefined_steps(), 1);
+    assert_eq!(writer.failed_steps(), 0);
+    assert!(!writer.execution_has_failed());
+    assert_eq!(Outcome::of(&writer, false), None);
+    assert_eq!(Outcome::of(&writer, true), Some(Outcome::Undefined));
+}
+
+#[tokio::test]
+async fn renders_pending_steps() {
+    let mut out = Vec::new();
+    _ = World::cucumber()
+        .with_writer(
+            writer::Basic::new(&mut out, writer::Coloring::Never, 0)
+                .summarized(),
+        )
+        .with_default_cli()
+        .run("tests/features/strict")
+        .await;
+    let out = String::from_utf8(out).unwrap();
+
+    assert_eq!(out.matches("Step pending:").count(), 1, "{out}");
+    assert!(
+        out.contains("Step is pending: purring isn't measurable yet"),
+        "{out}",
+    );
+    assert!(out.contains("1 undefined"), "{out}");
+    assert!(out.contains("1 pending"), "{out}");
+}
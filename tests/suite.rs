@@ -0,0 +1,69 @@
+use cucumber::{Suite, World as _, given, suite, then, writer::Stats as _};
+
+#[derive(Debug, Default, cucumber::World)]
+struct ApiWorld {
+    requested: bool,
+}
+
+#[given("a request")]
+fn request(world: &mut ApiWorld) {
+    world.requested = true;
+}
+
+#[then("the response is received")]
+fn response(world: &mut ApiWorld) {
+    assert!(world.requested, "no request");
+}
+
+#[then("its own world is used")]
+fn api_world(world: &mut ApiWorld) {
+    assert!(world.requested, "no request");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct BrowserWorld {
+    opened: bool,
+}
+
+#[given("a page")]
+fn page(world: &mut BrowserWorld) {
+    world.opened = true;
+}
+
+#[then("the button is clicked")]
+fn click(world: &mut BrowserWorld) {
+    assert!(world.opened, "no page");
+}
+
+#[then("its own world is used")]
+fn browser_world(world: &mut BrowserWorld) {
+    assert!(world.opened, "no page");
+}
+
+#[tokio::test]
+async fn routes_scenarios_to_worlds() {
+    let summary = Suite::new()
+        .world("@api", ApiWorld::collection())
+        .world("@browser", BrowserWorld::collection())
+        .run("tests/features/suite")
+        .await;
+
+    assert_eq!(
+        summary,
+        suite::Summary { passed_steps: 6, ..suite::Summary::default() },
+    );
+}
+
+#[tokio::test]
+async fn fails_on_steps_of_another_world() {
+    let writer = Suite::new()
+        .world("@api or @browser", ApiWorld::collection())
+        .cucumber()
+        .fail_on_skipped()
+        .run("tests/features/suite")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.undefined_steps(), 1);
+    assert!(writer.execution_has_failed());
+}
@@ -0,0 +1,50 @@
+use cucumber::{Parser as _, parser};
+use futures::StreamExt as _;
+
+async fn parse(
+    parser: parser::Basic,
+    cli: parser::basic::Cli,
+) -> Result<gherkin::Feature, parser::Error> {
+    let mut features =
+        parser.parse("tests/features/template", cli).collect::<Vec<_>>().await;
+    features.remove(0)
+}
+
+fn step(feature: &gherkin::Feature) -> &str {
+    &feature.scenarios[0].steps[0].value
+}
+
+#[tokio::test]
+async fn replaces_placeholders_with_configured_vars() {
+    let parser = parser::Basic::new()
+        .template_var("user", "alice")
+        .template_var("env", "staging");
+
+    let feature = parse(parser, parser::basic::Cli::default()).await.unwrap();
+
+    assert_eq!(step(&feature), "alice signs in to staging");
+}
+
+#[tokio::test]
+async fn cli_vars_override_configured_ones() {
+    let parser = parser::Basic::new()
+        .template_var("user", "alice")
+        .template_var("env", "staging");
+    let cli = parser::basic::Cli {
+        template_vars: vec!["env=production".parse().unwrap()],
+        ..parser::basic::Cli::default()
+    };
+
+    let feature = parse(parser, cli).await.unwrap();
+
+    assert_eq!(step(&feature), "alice signs in to production");
+}
+
+#[tokio::test]
+async fn fails_on_unknown_placeholder() {
+    let parser = parser::Basic::new().template_var("user", "alice");
+
+    let err = parse(parser, parser::basic::Cli::default()).await.unwrap_err();
+
+    assert!(err.to_string().contains("login.feature"), "{err}");
+}
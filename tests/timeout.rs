@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use cucumber::{
+    World as _, given, then,
+    writer::{self, Stats as _, summarize::Stats},
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+#[then("the cat is awake")]
+fn awake(_: &mut World) {}
+
+#[tokio::test]
+async fn counts_timed_out_steps_separately() {
+    let writer = World::cucumber()
+        .step_timeout(Duration::from_millis(200))
+        .with_default_cli()
+        .run("tests/features/timeout")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 2,
+            skipped: 0,
+            failed: 0,
+            timed_out: 1,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    assert_eq!(
+        *writer.steps_stats(),
+        Stats {
+            passed: 4,
+            skipped: 0,
+            failed: 0,
+            timed_out: 1,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    assert!(writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn renders_timed_out_steps() {
+    let mut out = Vec::new();
+    _ = World::cucumber()
+        .with_writer(writer::Basic::new(&mut out, writer::Coloring::Never, 0))
+        .step_timeout(Duration::from_millis(200))
+        .with_default_cli()
+        .run("tests/features/timeout")
+        .await;
+    let out = String::from_utf8(out).unwrap();
+
+    assert_eq!(out.matches("Step timed out:").count(), 1, "{out}");
+    assert!(out.contains("Step timed out after 200ms"), "{out}");
+    assert!(!out.contains("Step failed:"), "{out}");
+}
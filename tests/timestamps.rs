@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser,
+};
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+/// [`Writer`] collecting the metadata of all the [`event::Cucumber`]s along
+/// with the ones of the [`event::Scenario::Started`] and
+/// [`event::Scenario::Finished`] events.
+#[derive(Default)]
+struct Timeline {
+    /// Metadata of all the [`event::Cucumber`]s.
+    all: Vec<event::Metadata>,
+
+    /// Metadata of the [`event::Scenario::Started`] and
+    /// [`event::Scenario::Finished`] events pairs.
+    scenarios: Vec<(event::Metadata, Option<event::Metadata>)>,
+}
+
+impl Writer<World> for Timeline {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        let (ev, meta) = ev.expect("no parsing errors").split();
+        self.all.push(meta);
+        if let event::Cucumber::Feature(_, event::Feature::Scenario(_, ev)) = ev
+        {
+            match ev.event {
+                event::Scenario::Started => self.scenarios.push((meta, None)),
+                event::Scenario::Finished(_) => {
+                    self.scenarios.last_mut().unwrap().1 = Some(meta);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn offsets_events_from_run_start() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_writer(Timeline::default().normalized())
+        .with_default_cli()
+        .run("tests/features/sla")
+        .await;
+    let Timeline { all, scenarios } = writer.inner_writer();
+
+    assert_eq!(all[0].offset, Duration::ZERO);
+    assert!(all.windows(2).all(|w| w[0].offset <= w[1].offset));
+
+    assert_eq!(scenarios.len(), 2);
+    for (started, finished) in scenarios {
+        let finished = finished.expect("scenario is finished");
+        assert!(finished.duration_since(started) >= Duration::from_millis(150));
+        assert!(finished.at >= started.at);
+    }
+}
@@ -24,10 +24,15 @@ async fn main() {
     });
 
     let mut out = Vec::<u8>::new();
+    let mut basic = writer::Basic::raw(&mut out, Coloring::Never, 0);
+    basic.apply_cli(writer::basic::Cli {
+        show_output: true,
+        ..writer::basic::Cli::default()
+    });
 
     let res = World::cucumber()
         .with_writer(
-            writer::Basic::raw(&mut out, Coloring::Never, 0)
+            basic
                 .discard_stats_writes()
                 .tee::<World, _>(
                     writer::Basic::raw(io::stdout(), Coloring::Never, 0)
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cucumber::{
+    World as _, WriterExt as _, cli, given, runner,
+    writer::{self, summarize::Stats},
+};
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Debug, Default, cucumber::World)]
+struct World;
+
+#[given("a flaky step")]
+fn flaky_step(_: &mut World) {
+    let run = RUNS.fetch_add(1, Ordering::SeqCst) + 1;
+    assert_ne!(run, 5, "flaked on run {run}");
+}
+
+#[tokio::test]
+async fn reruns_until_failure() {
+    let mut opts = cli::Opts::<_, runner::basic::Cli, _, cli::Empty>::default();
+    opts.runner.until_failure = Some(10);
+    opts.runner.until_failure_seed = Some(42);
+
+    let mut out = Vec::new();
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .steps(World::collection())
+                .max_concurrent_scenarios(1),
+        )
+        .with_writer(
+            writer::Basic::new(&mut out, writer::Coloring::Never, 0)
+                .summarized(),
+        )
+        .with_cli(opts)
+        .run("tests/features/until_failure")
+        .await;
+
+    // Third iteration fails, so no more iterations are run after it.
+    assert_eq!(RUNS.load(Ordering::SeqCst), 6);
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 5,
+            skipped: 0,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    drop(writer);
+
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("Failed on iteration 3 with seed "), "{out}");
+    assert!(out.contains("until_failure/flaky.feature:3"), "{out}",);
+    assert!(out.contains("until_failure/flaky.feature:6"), "{out}",);
+}
@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use cucumber::{World as _, given, then, writer::summarize::Stats};
+
+static WARMED_UP: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given("services are warming up")]
+fn warming_up(_: &mut World) {
+    WARMED_UP.store(true, Ordering::SeqCst);
+}
+
+#[then("services are warmed up")]
+fn warmed_up(_: &mut World) {
+    assert!(WARMED_UP.load(Ordering::SeqCst), "warm-up hasn't run first");
+}
+
+#[tokio::test]
+async fn runs_warmups_first_and_excludes_them_from_stats() {
+    let writer =
+        World::cucumber().with_default_cli().run("tests/features/warmup").await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 3,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    assert_eq!(
+        *writer.steps_stats(),
+        Stats {
+            passed: 3,
+            skipped: 0,
+            failed: 0,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+}
@@ -0,0 +1,72 @@
+//! Runs via `wasm-bindgen-test` on `wasm32-unknown-unknown` target only:
+//! ```bash
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//! cargo test --target wasm32-unknown-unknown --test wasm
+//! ```
+
+#![cfg(all(target_family = "wasm", target_os = "unknown"))]
+
+use std::time::Duration;
+
+use cucumber::{
+    World as _, given, parser, then,
+    writer::{self, Stats as _, summarize::Stats},
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[given(regex = r"^the cat naps for (\d+) milliseconds$")]
+async fn naps(_: &mut World, ms: u32) {
+    gloo_timers::future::TimeoutFuture::new(ms).await;
+}
+
+#[then("the cat is awake")]
+fn awake(_: &mut World) {}
+
+/// `.feature` file to run, as there is no file system to read it from.
+fn feature() -> parser::Embedded {
+    parser::Embedded::from([(
+        "tests/features/timeout/nap.feature",
+        include_str!("features/timeout/nap.feature"),
+    )])
+}
+
+#[wasm_bindgen_test]
+async fn runs_with_timeouts() {
+    let writer = World::cucumber()
+        .step_timeout(Duration::from_millis(200))
+        .with_default_cli()
+        .run(feature())
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 2,
+            skipped: 0,
+            failed: 0,
+            timed_out: 1,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    assert!(writer.execution_has_failed());
+}
+
+#[wasm_bindgen_test]
+async fn renders_without_terminal() {
+    let mut out = Vec::new();
+    _ = World::cucumber()
+        .with_writer(writer::Basic::new(&mut out, writer::Coloring::Auto, 0))
+        .step_timeout(Duration::from_millis(200))
+        .with_default_cli()
+        .run(feature())
+        .await;
+    let out = String::from_utf8(out).unwrap();
+
+    assert!(out.contains("Step timed out after 200ms"), "{out}");
+    assert!(!out.contains('\u{1b}'), "{out}");
+}
@@ -0,0 +1,48 @@
+use cucumber::{World as _, given, writer::Stats as _};
+
+#[derive(Debug, cucumber::World)]
+struct World {
+    #[world(env = "PATH")]
+    path: String,
+
+    #[world(env = "CUCUMBER_WORLD_ENV_UNSET_PORT", default = "8080")]
+    port: u16,
+
+    visits: usize,
+}
+
+#[given("the configuration")]
+fn configuration(world: &mut World) {
+    assert!(!world.path.is_empty(), "`PATH` is empty");
+    assert_eq!(world.port, 8080);
+    assert_eq!(world.visits, 0);
+}
+
+/// `World` failing to be constructed due to an unparsable default.
+#[derive(Debug, cucumber::World)]
+struct Invalid {
+    #[world(env = "CUCUMBER_WORLD_ENV_UNSET_PORT", default = "eighty")]
+    _port: u16,
+}
+
+#[tokio::test]
+async fn populates_fields_from_env() {
+    let writer = World::cucumber()
+        .fail_on_skipped()
+        .run("tests/features/world_env")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert!(!writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn fails_on_unparsable_value() {
+    let err = <Invalid as cucumber::World>::new().await.unwrap_err();
+
+    assert_eq!(
+        format!("{err:#}"),
+        "cannot parse `eighty` value of `CUCUMBER_WORLD_ENV_UNSET_PORT` \
+         environment variable: invalid digit found in string",
+    );
+}
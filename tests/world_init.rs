@@ -0,0 +1,117 @@
+use std::{
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use cucumber::{
+    Event, World as _, Writer, WriterExt as _, cli, event, given, parser,
+    writer,
+};
+
+static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, cucumber::World)]
+#[world(init = Self::connect)]
+struct World {
+    connected: bool,
+}
+
+impl World {
+    async fn connect() -> io::Result<Self> {
+        if ATTEMPTS.fetch_add(1, Ordering::SeqCst) == 0 {
+            return Err(io::Error::other("connection refused"));
+        }
+        Ok(Self { connected: true })
+    }
+}
+
+#[given("a connection")]
+fn connection(world: &mut World) {
+    assert!(world.connected, "not connected");
+}
+
+#[derive(Debug, cucumber::World)]
+#[world(init = Self::connect)]
+struct RefusedWorld;
+
+impl RefusedWorld {
+    async fn connect() -> io::Result<Self> {
+        Err(io::Error::other("connection refused"))
+    }
+}
+
+#[given("a connection")]
+fn refused_connection(_: &mut RefusedWorld) {}
+
+/// [`Writer`] collecting failures of `Before` hooks and counting passed
+/// steps.
+#[derive(Default)]
+struct BeforeFailures(Vec<String>, usize);
+
+impl Writer<World> for BeforeFailures {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(_, event::RetryableScenario { event, .. }),
+        )) = ev.map(Event::into_inner)
+        else {
+            return;
+        };
+        match event {
+            event::Scenario::Hook(
+                event::HookType::Before,
+                event::HookScope::World,
+                event::Hook::Failed(_, info),
+            ) => self.0.extend(info.downcast_ref::<String>().cloned()),
+            event::Scenario::Step(_, event::Step::Passed { .. }) => {
+                self.1 += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[tokio::test]
+async fn reports_constructor_failure() {
+    let writer = World::cucumber()
+        .with_writer(BeforeFailures::default().normalized())
+        .max_concurrent_scenarios(1)
+        .run("tests/features/world_init")
+        .await;
+
+    assert_eq!(
+        writer.inner_writer().0,
+        ["Failed to create World: connection refused"],
+    );
+    assert_eq!(writer.inner_writer().1, 1, "second scenario should pass");
+}
+
+#[tokio::test]
+async fn renders_constructor_failure() {
+    let mut output = Vec::new();
+    _ = RefusedWorld::cucumber()
+        .with_writer(
+            writer::Basic::new(&mut output, writer::Coloring::Never, 0)
+                .normalized(),
+        )
+        .with_default_cli()
+        .filter_run("tests/features/world_init", |_, _, sc| {
+            sc.name == "refused connection"
+        })
+        .await;
+    let output = String::from_utf8(output).unwrap();
+
+    let scenario = output.find("Scenario: refused connection");
+    let failure = output.find("Scenario's World setup failed");
+    assert!(
+        scenario.is_some_and(|sc| failure.is_some_and(|f| sc < f)),
+        "no scenario with its failure in:\n{output}",
+    );
+    assert!(output.contains("Failed to create World: connection refused"));
+}
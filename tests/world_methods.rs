@@ -0,0 +1,78 @@
+use cucumber::{
+    World, gherkin::Step, given, steps, then, when, writer::summarize::Stats,
+};
+
+#[derive(Debug, Default, World)]
+struct Basket {
+    fruits: Vec<String>,
+}
+
+#[steps]
+impl Basket {
+    /// Capacity of a [`Basket`].
+    const CAPACITY: usize = 10;
+
+    #[given("an empty basket")]
+    fn empty(&mut self) {
+        self.fruits.clear();
+    }
+
+    #[when(expr = "{int} apples are added")]
+    async fn add_apples(&mut self, count: usize) -> Result<(), String> {
+        if self.fruits.len() + count > Self::CAPACITY {
+            return Err(format!("no room for {count} apples"));
+        }
+        self.fruits.extend(vec!["apple".to_owned(); count]);
+        Ok(())
+    }
+
+    #[when(expr = "{string} is added")]
+    fn add(&mut self, #[step] step: &Step, fruit: String) {
+        assert_eq!(step.value, format!("\"{fruit}\" is added"));
+        self.fruits.push(fruit);
+    }
+
+    #[then(expr = "the basket contains {int} fruits")]
+    fn contains(&mut self, count: usize) {
+        assert_eq!(self.fruits.len(), count);
+    }
+}
+
+#[tokio::test]
+async fn registers_world_methods() {
+    let writer = Basket::cucumber()
+        .with_default_cli()
+        .run("tests/features/world_methods")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 1,
+            skipped: 0,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+}
+
+#[test]
+fn keeps_methods_callable() {
+    let mut basket = Basket::default();
+    basket.add(
+        &Step {
+            keyword: "When ".into(),
+            ty: cucumber::gherkin::StepType::When,
+            value: "\"kiwi\" is added".into(),
+            docstring: None,
+            table: None,
+            span: cucumber::gherkin::Span { start: 0, end: 0 },
+            position: cucumber::gherkin::LineCol { line: 1, col: 1 },
+        },
+        "kiwi".into(),
+    );
+    basket.contains(1);
+}
@@ -0,0 +1,58 @@
+use std::{
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use cucumber::{World, given, writer::summarize::Stats};
+
+static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Default, World)]
+#[world(teardown = Self::release)]
+struct Pool {
+    connections: usize,
+    leaked: bool,
+}
+
+impl Pool {
+    async fn release(&mut self) -> io::Result<()> {
+        if self.leaked {
+            return Err(io::Error::other("connection leaked"));
+        }
+        _ = RELEASED.fetch_add(self.connections, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[given("a connection")]
+fn connect(pool: &mut Pool) {
+    pool.connections += 1;
+}
+
+#[given("a leaked connection")]
+fn leak(pool: &mut Pool) {
+    pool.leaked = true;
+}
+
+#[tokio::test]
+async fn tears_world_down() {
+    let writer = Pool::cucumber()
+        .with_default_cli()
+        .run("tests/features/world_teardown")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 1,
+            skipped: 0,
+            failed: 1,
+            timed_out: 0,
+            undefined: 0,
+            pending: 0,
+            retried: 0
+        },
+    );
+    assert_eq!(writer.failed_hooks_count(), 1);
+    assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+}